@@ -4,6 +4,15 @@ pub const EXPORTS_TO_TOP_EDGE_PIXEL_GAP: u32 = 72;
 pub const EXPORTS_TO_RIGHT_EDGE_PIXEL_GAP: u32 = 120;
 pub const IMPORTS_TO_TOP_EDGE_PIXEL_GAP: u32 = 72;
 pub const IMPORTS_TO_LEFT_EDGE_PIXEL_GAP: u32 = 120;
+// Layer thumbnails are evaluated with their longer side at this many pixels, with up to this many rendered in the background between each execution of the graph
+pub const LAYER_THUMBNAIL_RESOLUTION: f64 = 96.;
+pub const LAYER_THUMBNAILS_PER_RUN: usize = 4;
+// The preview in the properties of the Noise Pattern node shows its output within this square of document space starting at the origin, which matches its clipping square
+pub const NOISE_PREVIEW_SIZE: f64 = 100.;
+// A node is reported as slow once it has taken longer than the document's performance budget (in milliseconds, which starts out as this) in this many renders in a row,
+// until it stays within the budget for as many renders in a row
+pub const DEFAULT_PERFORMANCE_BUDGET_MILLISECONDS: f64 = 16.;
+pub const PERFORMANCE_BUDGET_RENDERS: usize = 5;
 
 // VIEWPORT
 pub const VIEWPORT_ZOOM_WHEEL_RATE: f64 = (1. / 600.) * 3.;
@@ -26,8 +35,14 @@ pub const VIEWPORT_SCROLL_RATE: f64 = 0.6;
 
 pub const VIEWPORT_ROTATE_SNAP_INTERVAL: f64 = 15.;
 
+/// Degrees of tilt per unit of scroll wheel or pen dial movement.
+pub const VIEWPORT_TILT_WHEEL_RATE: f64 = 15. / 100.;
+
 pub const VIEWPORT_ZOOM_TO_FIT_PADDING_SCALE_FACTOR: f64 = 0.95;
 
+/// The zoom level at which the pixels of the Pixels view mode become large enough to outline with the pixel grid.
+pub const VIEWPORT_PIXEL_GRID_MIN_ZOOM: f64 = 8.;
+
 pub const DRAG_BEYOND_VIEWPORT_MAX_OVEREXTENSION_PIXELS: f64 = 50.;
 pub const DRAG_BEYOND_VIEWPORT_SPEED_FACTOR: f64 = 20.;
 
@@ -74,6 +89,10 @@ pub const COMPASS_ROSE_ARROW_CLICK_TARGET_ANGLE: f64 = 20.;
 pub const ANGLE_MEASURE_RADIUS_FACTOR: f64 = 0.04;
 pub const ARC_MEASURE_RADIUS_FACTOR_RANGE: (f64, f64) = (0.05, 0.15);
 
+// POSITION PICKER
+/// The radius in viewport pixels of the gizmo drawn while picking a position input on the canvas, which is also the distance within which it can be grabbed to drag it.
+pub const POSITION_PICKER_GIZMO_RADIUS: f64 = 6.;
+
 // TRANSFORM CAGE
 pub const RESIZE_HANDLE_SIZE: f64 = 6.;
 pub const BOUNDS_SELECT_THRESHOLD: f64 = 10.;
@@ -128,9 +147,15 @@ pub const COLOR_OVERLAY_RED: &str = "#ef5454";
 pub const COLOR_OVERLAY_GRAY: &str = "#cccccc";
 pub const COLOR_OVERLAY_WHITE: &str = "#ffffff";
 pub const COLOR_OVERLAY_LABEL_BACKGROUND: &str = "#000000cc";
+pub const COLOR_OVERLAY_PIXEL_GRID: &str = "#80808066";
+pub const COLOR_OVERLAY_GUIDE: &str = "#00d8ffb3";
+pub const COLOR_OVERLAY_SEARCH_MATCH: &str = "#ffc84840";
+pub const COLOR_OVERLAY_MISSING_FONT: &str = "#ef545440";
+pub const MAX_RECENT_COLORS: usize = 8;
 
 // DOCUMENT
 pub const DEFAULT_DOCUMENT_NAME: &str = "Untitled Document";
 pub const FILE_SAVE_SUFFIX: &str = ".graphite";
 pub const MAX_UNDO_HISTORY_LEN: usize = 100; // TODO: Add this to user preferences
 pub const AUTO_SAVE_TIMEOUT_SECONDS: u64 = 15;
+pub const MAX_RECENT_FILES: usize = 10;
@@ -53,7 +53,7 @@ const SIDE_EFFECT_FREE_MESSAGES: &[MessageDiscriminant] = &[
 ];
 const DEBUG_MESSAGE_BLOCK_LIST: &[MessageDiscriminant] = &[MessageDiscriminant::Broadcast(BroadcastMessageDiscriminant::TriggerEvent(BroadcastEventDiscriminant::AnimationFrame))];
 // TODO: Find a way to combine these with the list above. We use strings for now since these are the standard variant names used by multiple messages. But having these also type-checked would be best.
-const DEBUG_MESSAGE_ENDING_BLOCK_LIST: &[&str] = &["PointerMove", "PointerOutsideViewport", "Overlays", "Draw", "CurrentTime", "Time"];
+const DEBUG_MESSAGE_ENDING_BLOCK_LIST: &[&str] = &["PointerMove", "PointerPressure", "PointerOutsideViewport", "Overlays", "Draw", "CurrentTime", "Time"];
 
 impl Dispatcher {
 	pub fn new() -> Self {
@@ -173,6 +173,9 @@ impl Dispatcher {
 
 					// Finish loading persistent data from the browser database
 					queue.add(FrontendMessage::TriggerLoadRestAutoSaveDocuments);
+
+					// Restore the rest of the workspace session around the documents
+					queue.add(FrontendMessage::TriggerLoadSession);
 				}
 				Message::Animation(message) => {
 					self.message_handlers.animation_message_handler.process_message(message, &mut queue, ());
@@ -209,10 +212,11 @@ impl Dispatcher {
 				}
 				Message::InputPreprocessor(message) => {
 					let keyboard_platform = GLOBAL_PLATFORM.get().copied().unwrap_or_default().as_keyboard_platform_layout();
+					let pressure_curve = &self.message_handlers.preferences_message_handler.pressure_curve;
 
 					self.message_handlers
 						.input_preprocessor_message_handler
-						.process_message(message, &mut queue, InputPreprocessorMessageData { keyboard_platform });
+						.process_message(message, &mut queue, InputPreprocessorMessageData { keyboard_platform, pressure_curve });
 				}
 				Message::KeyMapping(message) => {
 					let input = &self.message_handlers.input_preprocessor_message_handler;
@@ -235,6 +239,7 @@ impl Dispatcher {
 					let reset_node_definitions_on_open = self.message_handlers.portfolio_message_handler.reset_node_definitions_on_open;
 					let timing_information = self.message_handlers.animation_message_handler.timing_information();
 					let animation = &self.message_handlers.animation_message_handler;
+					let tool_message_handler = &self.message_handlers.tool_message_handler;
 
 					self.message_handlers.portfolio_message_handler.process_message(
 						message,
@@ -247,6 +252,7 @@ impl Dispatcher {
 							reset_node_definitions_on_open,
 							timing_information,
 							animation,
+							tool_message_handler,
 						},
 					);
 				}
@@ -294,7 +300,7 @@ impl Dispatcher {
 		list.extend(self.message_handlers.key_mapping_message_handler.actions());
 		list.extend(self.message_handlers.debug_message_handler.actions());
 		if let Some(document) = self.message_handlers.portfolio_message_handler.active_document() {
-			if !document.graph_view_overlay_open {
+			if !document.graph_view_overlay_open && !document.is_presenting() && !document.is_picking_position() {
 				list.extend(self.message_handlers.tool_message_handler.actions());
 			}
 		}
@@ -11,6 +11,7 @@ pub enum AnimationMessage {
 	RestartAnimation,
 	SetFrameIndex(f64),
 	SetTime(f64),
+	SetAnimationTime(f64),
 	UpdateTime,
 	IncrementFrameCounter,
 	SetAnimationTimeMode(AnimationTimeMode),
@@ -91,6 +91,18 @@ impl MessageHandler<AnimationMessage, ()> for AnimationMessageHandler {
 				self.timestamp = time;
 				responses.add(AnimationMessage::UpdateTime);
 			}
+			AnimationMessage::SetAnimationTime(time) => {
+				// Move the start of the animation so that the given number of seconds has elapsed since it
+				let start = self.timestamp - time * 1000.;
+				self.frame_index = time * self.fps;
+				self.animation_state = match self.animation_state {
+					AnimationState::Playing { .. } => AnimationState::Playing { start },
+					_ => AnimationState::Paused { start, pause_time: self.timestamp },
+				};
+				responses.add(PortfolioMessage::SubmitActiveGraphRender);
+				// Update the restart and pause/play buttons
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+			}
 			AnimationMessage::IncrementFrameCounter => {
 				if self.is_playing() {
 					self.frame_index += 1.;
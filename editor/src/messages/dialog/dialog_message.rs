@@ -7,15 +7,25 @@ pub enum DialogMessage {
 	#[child]
 	ExportDialog(ExportDialogMessage),
 	#[child]
+	FindReplaceDialog(FindReplaceDialogMessage),
+	#[child]
+	MissingFontsDialog(MissingFontsDialogMessage),
+	#[child]
 	NewDocumentDialog(NewDocumentDialogMessage),
 	#[child]
 	PreferencesDialog(PreferencesDialogMessage),
+	#[child]
+	ReplaceFontDialog(ReplaceFontDialogMessage),
+	#[child]
+	TransformEachDialog(TransformEachDialogMessage),
 
 	// Messages
 	CloseAllDocumentsWithConfirmation,
 	CloseDialogAndThen {
 		followups: Vec<Message>,
 	},
+	/// Sent by the frontend when the user closes the dialog without using one of its buttons, such as by pressing Escape.
+	Dismissed,
 	DisplayDialogError {
 		title: String,
 		description: String,
@@ -30,9 +40,13 @@ pub enum DialogMessage {
 	},
 	RequestDemoArtworkDialog,
 	RequestExportDialog,
+	RequestFindReplaceDialog,
+	RequestMissingFontsDialog,
 	RequestLicensesDialogWithLocalizedCommitDate {
 		localized_commit_year: String,
 	},
 	RequestNewDocumentDialog,
 	RequestPreferencesDialog,
+	RequestReplaceFontDialog,
+	RequestTransformEachDialog,
 }
@@ -11,8 +11,12 @@ pub struct DialogMessageData<'a> {
 #[derive(Debug, Default, Clone)]
 pub struct DialogMessageHandler {
 	export_dialog: ExportDialogMessageHandler,
+	find_replace_dialog: FindReplaceDialogMessageHandler,
+	missing_fonts_dialog: MissingFontsDialogMessageHandler,
 	new_document_dialog: NewDocumentDialogMessageHandler,
 	preferences_dialog: PreferencesDialogMessageHandler,
+	replace_font_dialog: ReplaceFontDialogMessageHandler,
+	transform_each_dialog: TransformEachDialogMessageHandler,
 }
 
 impl MessageHandler<DialogMessage, DialogMessageData<'_>> for DialogMessageHandler {
@@ -21,8 +25,12 @@ impl MessageHandler<DialogMessage, DialogMessageData<'_>> for DialogMessageHandl
 
 		match message {
 			DialogMessage::ExportDialog(message) => self.export_dialog.process_message(message, responses, ExportDialogMessageData { portfolio }),
+			DialogMessage::FindReplaceDialog(message) => self.find_replace_dialog.process_message(message, responses, FindReplaceDialogMessageData { portfolio }),
+			DialogMessage::MissingFontsDialog(message) => self.missing_fonts_dialog.process_message(message, responses, MissingFontsDialogMessageData { portfolio }),
 			DialogMessage::NewDocumentDialog(message) => self.new_document_dialog.process_message(message, responses, ()),
 			DialogMessage::PreferencesDialog(message) => self.preferences_dialog.process_message(message, responses, PreferencesDialogMessageData { preferences }),
+			DialogMessage::ReplaceFontDialog(message) => self.replace_font_dialog.process_message(message, responses, ReplaceFontDialogMessageData { portfolio }),
+			DialogMessage::TransformEachDialog(message) => self.transform_each_dialog.process_message(message, responses, ()),
 
 			DialogMessage::CloseAllDocumentsWithConfirmation => {
 				let dialog = simple_dialogs::CloseAllDocumentsDialog {
@@ -39,6 +47,10 @@ impl MessageHandler<DialogMessage, DialogMessageData<'_>> for DialogMessageHandl
 				// If it comes before, the dialog reopens (and appears to not close at all).
 				responses.add(FrontendMessage::DisplayDialogDismiss);
 			}
+			DialogMessage::Dismissed => {
+				// The Find and Replace dialog's highlights are only shown in the viewport while it's open
+				responses.add(DocumentMessage::SetTextSearchHighlight { search: None });
+			}
 			DialogMessage::DisplayDialogError { title, description } => {
 				let dialog = simple_dialogs::ErrorDialog { title, description };
 				dialog.send_dialog_to_frontend(responses);
@@ -85,10 +97,26 @@ impl MessageHandler<DialogMessage, DialogMessageData<'_>> for DialogMessageHandl
 						.collect();
 
 					self.export_dialog.artboards = artboards;
+					// Slices whose layers have since been deleted are left out
+					self.export_dialog.slices = document.export_slices.iter().filter(|slice| slice.layer.exists(document.metadata())).cloned().collect();
 					self.export_dialog.has_selection = document.network_interface.selected_nodes().selected_layers(document.metadata()).next().is_some();
+					// Print exports are separated for the document's own printing condition when it's designed in CMYK
+					self.export_dialog.print_settings.print_profile = document.print_profile;
 					self.export_dialog.send_dialog_to_frontend(responses);
 				}
 			}
+			DialogMessage::RequestFindReplaceDialog => {
+				if portfolio.active_document().is_some() {
+					self.find_replace_dialog.update_matches(portfolio, responses);
+					self.find_replace_dialog.send_dialog_to_frontend(responses);
+				}
+			}
+			DialogMessage::RequestMissingFontsDialog => {
+				self.missing_fonts_dialog.update_fonts(portfolio);
+				if !self.missing_fonts_dialog.fonts.is_empty() {
+					self.missing_fonts_dialog.send_dialog_to_frontend(responses);
+				}
+			}
 			DialogMessage::RequestLicensesDialogWithLocalizedCommitDate { localized_commit_year } => {
 				let dialog = LicensesDialog { localized_commit_year };
 
@@ -99,6 +127,8 @@ impl MessageHandler<DialogMessage, DialogMessageData<'_>> for DialogMessageHandl
 					name: portfolio.generate_new_document_name(),
 					infinite: false,
 					dimensions: glam::UVec2::new(1920, 1080),
+					custom_templates: preferences.document_templates.clone(),
+					template: None,
 				};
 				self.new_document_dialog.send_dialog_to_frontend(responses);
 			}
@@ -106,13 +136,24 @@ impl MessageHandler<DialogMessage, DialogMessageData<'_>> for DialogMessageHandl
 				self.preferences_dialog = PreferencesDialogMessageHandler {};
 				self.preferences_dialog.send_dialog_to_frontend(responses, preferences);
 			}
+			DialogMessage::RequestReplaceFontDialog => {
+				if portfolio.active_document().is_some() {
+					self.replace_font_dialog.update_fonts(portfolio);
+					self.replace_font_dialog.send_dialog_to_frontend(responses);
+				}
+			}
+			DialogMessage::RequestTransformEachDialog => {
+				self.transform_each_dialog.send_dialog_to_frontend(responses);
+			}
 		}
 	}
 
 	advertise_actions!(DialogMessageDiscriminant;
 		CloseAllDocumentsWithConfirmation,
 		RequestExportDialog,
+		RequestFindReplaceDialog,
 		RequestNewDocumentDialog,
 		RequestPreferencesDialog,
+		RequestReplaceFontDialog,
 	);
 }
@@ -1,5 +1,7 @@
 use crate::messages::frontend::utility_types::{ExportBounds, FileType};
+use crate::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
 use crate::messages::prelude::*;
+use graphene_core::raster::cmyk::PrintProfile;
 
 #[impl_message(Message, DialogMessage, ExportDialog)]
 #[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -7,7 +9,18 @@ pub enum ExportDialogMessage {
 	FileType(FileType),
 	ScaleFactor(f64),
 	TransparentBackground(bool),
+	IncludeBleed(bool),
+	CropMarks(bool),
+	RegistrationMarks(bool),
+	PrintProfile(Option<PrintProfile>),
 	ExportBounds(ExportBounds),
 
+	AddSelectedLayersAsSlices,
+	RemoveSlice { layer: LayerNodeIdentifier },
+	SliceName { layer: LayerNodeIdentifier, name: String },
+	SliceFileType { layer: LayerNodeIdentifier, file_type: FileType },
+	SliceScaleFactor { layer: LayerNodeIdentifier, scale_factor: f64 },
+
 	Submit,
+	SubmitSlices,
 }
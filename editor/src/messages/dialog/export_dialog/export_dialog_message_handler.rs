@@ -1,7 +1,10 @@
 use crate::messages::frontend::utility_types::{ExportBounds, FileType};
 use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
+use crate::messages::portfolio::document::utility_types::misc::ExportSlice;
+use crate::messages::portfolio::document::utility_types::print::PrintSettings;
 use crate::messages::prelude::*;
+use graphene_core::raster::cmyk::PrintProfile;
 
 pub struct ExportDialogMessageData<'a> {
 	pub portfolio: &'a PortfolioMessageHandler,
@@ -14,8 +17,14 @@ pub struct ExportDialogMessageHandler {
 	pub scale_factor: f64,
 	pub bounds: ExportBounds,
 	pub transparent_background: bool,
+	/// Whether exporting an artboard also includes the bleed extending past its edges, rather than trimming the export to the artboard.
+	pub include_bleed: bool,
+	/// The printer's marks and color separation used when exporting a PDF for print.
+	pub print_settings: PrintSettings,
 	pub artboards: HashMap<LayerNodeIdentifier, String>,
 	pub has_selection: bool,
+	/// The layers of the active document marked as export slices, which are edited here and saved back to the document.
+	pub slices: Vec<ExportSlice>,
 }
 
 impl Default for ExportDialogMessageHandler {
@@ -25,8 +34,11 @@ impl Default for ExportDialogMessageHandler {
 			scale_factor: 1.,
 			bounds: Default::default(),
 			transparent_background: false,
+			include_bleed: false,
+			print_settings: PrintSettings::default(),
 			artboards: Default::default(),
 			has_selection: false,
+			slices: Vec::new(),
 		}
 	}
 }
@@ -39,15 +51,66 @@ impl MessageHandler<ExportDialogMessage, ExportDialogMessageData<'_>> for Export
 			ExportDialogMessage::FileType(export_type) => self.file_type = export_type,
 			ExportDialogMessage::ScaleFactor(factor) => self.scale_factor = factor,
 			ExportDialogMessage::TransparentBackground(transparent_background) => self.transparent_background = transparent_background,
+			ExportDialogMessage::IncludeBleed(include_bleed) => self.include_bleed = include_bleed,
+			ExportDialogMessage::CropMarks(crop_marks) => self.print_settings.crop_marks = crop_marks,
+			ExportDialogMessage::RegistrationMarks(registration_marks) => self.print_settings.registration_marks = registration_marks,
+			ExportDialogMessage::PrintProfile(print_profile) => self.print_settings.print_profile = print_profile,
 			ExportDialogMessage::ExportBounds(export_area) => self.bounds = export_area,
 
+			ExportDialogMessage::AddSelectedLayersAsSlices => {
+				let Some(document) = portfolio.active_document() else { return };
+
+				for layer in document.network_interface.selected_nodes().selected_layers(document.metadata()) {
+					if self.slices.iter().any(|slice| slice.layer == layer) {
+						continue;
+					}
+
+					let name = document.network_interface.display_name(&layer.to_node(), &[]);
+					self.slices.push(ExportSlice {
+						layer,
+						name,
+						file_type: self.file_type,
+						scale_factor: self.scale_factor,
+					});
+				}
+				responses.add(DocumentMessage::SetExportSlices { slices: self.slices.clone() });
+			}
+			ExportDialogMessage::RemoveSlice { layer } => {
+				self.slices.retain(|slice| slice.layer != layer);
+				responses.add(DocumentMessage::SetExportSlices { slices: self.slices.clone() });
+			}
+			ExportDialogMessage::SliceName { layer, name } => {
+				self.update_slice(layer, |slice| slice.name = name, responses);
+			}
+			ExportDialogMessage::SliceFileType { layer, file_type } => {
+				self.update_slice(layer, |slice| slice.file_type = file_type, responses);
+			}
+			ExportDialogMessage::SliceScaleFactor { layer, scale_factor } => {
+				self.update_slice(layer, |slice| slice.scale_factor = scale_factor, responses);
+			}
+
 			ExportDialogMessage::Submit => responses.add_front(PortfolioMessage::SubmitDocumentExport {
 				file_name: portfolio.active_document().map(|document| document.name.clone()).unwrap_or_default(),
 				file_type: self.file_type,
 				scale_factor: self.scale_factor,
 				bounds: self.bounds,
-				transparent_background: self.file_type != FileType::Jpg && self.transparent_background,
+				transparent_background: self.file_type.supports_transparency() && self.transparent_background,
+				include_bleed: self.include_bleed,
+				print_settings: self.print_settings,
 			}),
+			ExportDialogMessage::SubmitSlices => {
+				for slice in &self.slices {
+					responses.add(PortfolioMessage::SubmitDocumentExport {
+						file_name: slice.name.clone(),
+						file_type: slice.file_type,
+						scale_factor: slice.scale_factor,
+						bounds: ExportBounds::Slice(slice.layer),
+						transparent_background: slice.file_type.supports_transparency() && self.transparent_background,
+						include_bleed: false,
+						print_settings: PrintSettings::default(),
+					});
+				}
+			}
 		}
 
 		self.send_dialog_to_frontend(responses);
@@ -56,6 +119,138 @@ impl MessageHandler<ExportDialogMessage, ExportDialogMessageData<'_>> for Export
 	advertise_actions! {ExportDialogUpdate;}
 }
 
+impl ExportDialogMessageHandler {
+	fn update_slice(&mut self, layer: LayerNodeIdentifier, update: impl FnOnce(&mut ExportSlice), responses: &mut VecDeque<Message>) {
+		let Some(slice) = self.slices.iter_mut().find(|slice| slice.layer == layer) else { return };
+		update(slice);
+		responses.add(DocumentMessage::SetExportSlices { slices: self.slices.clone() });
+	}
+
+	/// The options for the printer's marks and color separation, shown when exporting a PDF for print.
+	fn print_layout(&self) -> Vec<LayoutGroup> {
+		let crop_marks = vec![
+			TextLabel::new("Crop Marks").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			CheckboxInput::new(self.print_settings.crop_marks)
+				.tooltip("Draw lines outside each corner of the page, beyond the bleed, showing where the printed sheet is trimmed")
+				.on_update(|value: &CheckboxInput| ExportDialogMessage::CropMarks(value.checked).into())
+				.widget_holder(),
+		];
+
+		let registration_marks = vec![
+			TextLabel::new("Registration").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			CheckboxInput::new(self.print_settings.registration_marks)
+				.tooltip("Draw targets outside the middle of each side of the page, printed in every ink, for the print shop to line up the printing plates")
+				.on_update(|value: &CheckboxInput| ExportDialogMessage::RegistrationMarks(value.checked).into())
+				.widget_holder(),
+		];
+
+		let entries = [None]
+			.into_iter()
+			.chain(PrintProfile::ALL.into_iter().map(Some))
+			.map(|print_profile| {
+				let label = match print_profile {
+					Some(print_profile) => format!("CMYK ({print_profile})"),
+					None => "RGB".to_string(),
+				};
+				MenuListEntry::new(format!("{print_profile:?}"))
+					.label(label)
+					.on_commit(move |_| ExportDialogMessage::PrintProfile(print_profile).into())
+			})
+			.collect();
+		let selected_index = match self.print_settings.print_profile {
+			Some(print_profile) => PrintProfile::ALL.iter().position(|&profile| profile == print_profile).map(|index| index as u32 + 1),
+			None => Some(0),
+		};
+		let color = vec![
+			TextLabel::new("Color").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries])
+				.selected_index(selected_index)
+				.tooltip("Keep the artwork in RGB, or separate it into CMYK inks for the printing condition of the print shop")
+				.widget_holder(),
+		];
+
+		vec![
+			LayoutGroup::Row {
+				widgets: vec![TextLabel::new("Print").bold(true).widget_holder()],
+			},
+			LayoutGroup::Row { widgets: crop_marks },
+			LayoutGroup::Row { widgets: registration_marks },
+			LayoutGroup::Row { widgets: color },
+		]
+	}
+
+	fn slices_layout(&self) -> Vec<LayoutGroup> {
+		let mut rows = vec![LayoutGroup::Row {
+			widgets: vec![TextLabel::new("Slices").bold(true).widget_holder()],
+		}];
+
+		rows.extend(self.slices.iter().map(|slice| {
+			let layer = slice.layer;
+			let file_types = [(FileType::Png, "PNG"), (FileType::Jpg, "JPG"), (FileType::Svg, "SVG")]
+				.into_iter()
+				.map(|(file_type, name)| {
+					RadioEntryData::new(format!("{file_type:?}"))
+						.label(name)
+						.on_update(move |_| ExportDialogMessage::SliceFileType { layer, file_type }.into())
+				})
+				.collect();
+
+			LayoutGroup::Row {
+				widgets: vec![
+					TextInput::new(&slice.name)
+						.tooltip("File name of the exported slice")
+						.on_update(move |text_input: &TextInput| {
+							ExportDialogMessage::SliceName {
+								layer,
+								name: text_input.value.clone(),
+							}
+							.into()
+						})
+						.min_width(100)
+						.widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					RadioInput::new(file_types).selected_index(Some(slice.file_type as u32)).widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					NumberInput::new(Some(slice.scale_factor))
+						.tooltip("Scale Factor")
+						.unit("x")
+						.min(0.)
+						.max((1_u64 << f64::MANTISSA_DIGITS) as f64)
+						.disabled(slice.file_type == FileType::Svg)
+						.on_update(move |number_input: &NumberInput| {
+							ExportDialogMessage::SliceScaleFactor {
+								layer,
+								scale_factor: number_input.value.unwrap(),
+							}
+							.into()
+						})
+						.min_width(80)
+						.widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					IconButton::new("Trash", 24)
+						.tooltip("Remove Slice")
+						.on_update(move |_| ExportDialogMessage::RemoveSlice { layer }.into())
+						.widget_holder(),
+				],
+			}
+		}));
+
+		rows.push(LayoutGroup::Row {
+			widgets: vec![
+				TextButton::new("Add Selected Layers as Slices")
+					.disabled(!self.has_selection)
+					.on_update(|_| ExportDialogMessage::AddSelectedLayersAsSlices.into())
+					.widget_holder(),
+			],
+		});
+
+		rows
+	}
+}
+
 impl DialogLayoutHolder for ExportDialogMessageHandler {
 	const ICON: &'static str = "File";
 	const TITLE: &'static str = "Export";
@@ -71,6 +266,16 @@ impl DialogLayoutHolder for ExportDialogMessageHandler {
 					.into()
 				})
 				.widget_holder(),
+			TextButton::new("Export All Slices")
+				.tooltip("Export every slice as its own file, each using its own file type and scale factor")
+				.disabled(self.slices.is_empty())
+				.on_update(|_| {
+					DialogMessage::CloseDialogAndThen {
+						followups: vec![ExportDialogMessage::SubmitSlices.into()],
+					}
+					.into()
+				})
+				.widget_holder(),
 			TextButton::new("Cancel").on_update(|_| FrontendMessage::DisplayDialogDismiss.into()).widget_holder(),
 		];
 
@@ -80,7 +285,7 @@ impl DialogLayoutHolder for ExportDialogMessageHandler {
 
 impl LayoutHolder for ExportDialogMessageHandler {
 	fn layout(&self) -> Layout {
-		let entries = [(FileType::Png, "PNG"), (FileType::Jpg, "JPG"), (FileType::Svg, "SVG")]
+		let entries = [(FileType::Png, "PNG"), (FileType::Jpg, "JPG"), (FileType::Svg, "SVG"), (FileType::Pdf, "PDF")]
 			.into_iter()
 			.map(|(val, name)| RadioEntryData::new(format!("{val:?}")).label(name).on_update(move |_| ExportDialogMessage::FileType(val).into()))
 			.collect();
@@ -147,16 +352,33 @@ impl LayoutHolder for ExportDialogMessageHandler {
 			TextLabel::new("Transparency").table_align(true).min_width(100).widget_holder(),
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
 			CheckboxInput::new(self.transparent_background)
-				.disabled(self.file_type == FileType::Jpg)
+				.disabled(!self.file_type.supports_transparency())
 				.on_update(move |value: &CheckboxInput| ExportDialogMessage::TransparentBackground(value.checked).into())
 				.widget_holder(),
 		];
 
-		Layout::WidgetLayout(WidgetLayout::new(vec![
+		let include_bleed = vec![
+			TextLabel::new("Bleed").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			CheckboxInput::new(self.include_bleed)
+				.tooltip("Include the bleed extending past the edges of artboards, instead of trimming the export to the artboard")
+				.disabled(!matches!(current_bounds, ExportBounds::Artboard(_)))
+				.on_update(move |value: &CheckboxInput| ExportDialogMessage::IncludeBleed(value.checked).into())
+				.widget_holder(),
+		];
+
+		let mut layout = vec![
 			LayoutGroup::Row { widgets: export_type },
 			LayoutGroup::Row { widgets: resolution },
 			LayoutGroup::Row { widgets: export_area },
 			LayoutGroup::Row { widgets: transparent_background },
-		]))
+			LayoutGroup::Row { widgets: include_bleed },
+		];
+		if self.file_type == FileType::Pdf {
+			layout.extend(self.print_layout());
+		}
+		layout.extend(self.slices_layout());
+
+		Layout::WidgetLayout(WidgetLayout::new(layout))
 	}
 }
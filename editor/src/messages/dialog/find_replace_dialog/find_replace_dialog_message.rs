@@ -0,0 +1,12 @@
+use crate::messages::prelude::*;
+
+#[impl_message(Message, DialogMessage, FindReplaceDialog)]
+#[derive(PartialEq, Eq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum FindReplaceDialogMessage {
+	Query(String),
+	Replacement(String),
+	MatchCase(bool),
+	WholeWord(bool),
+
+	ReplaceAll,
+}
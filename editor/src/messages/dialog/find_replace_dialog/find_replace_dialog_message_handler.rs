@@ -0,0 +1,141 @@
+use crate::messages::layout::utility_types::widget_prelude::*;
+use crate::messages::portfolio::document::utility_types::text_search::TextSearch;
+use crate::messages::prelude::*;
+
+pub struct FindReplaceDialogMessageData<'a> {
+	pub portfolio: &'a PortfolioMessageHandler,
+}
+
+/// A dialog to search the text of every text layer in the document, highlighting the layers with matches in the viewport, and replace the matches.
+/// The search is kept between uses so it can be repeated.
+#[derive(Debug, Clone, Default)]
+pub struct FindReplaceDialogMessageHandler {
+	pub search: TextSearch,
+	pub replacement: String,
+	/// The number of matches, and the number of text layers they're found in, for the current search in the active document.
+	pub match_counts: (usize, usize),
+}
+
+impl MessageHandler<FindReplaceDialogMessage, FindReplaceDialogMessageData<'_>> for FindReplaceDialogMessageHandler {
+	fn process_message(&mut self, message: FindReplaceDialogMessage, responses: &mut VecDeque<Message>, data: FindReplaceDialogMessageData) {
+		let FindReplaceDialogMessageData { portfolio } = data;
+
+		match message {
+			FindReplaceDialogMessage::Query(query) => self.search.query = query,
+			FindReplaceDialogMessage::Replacement(replacement) => self.replacement = replacement,
+			FindReplaceDialogMessage::MatchCase(match_case) => self.search.match_case = match_case,
+			FindReplaceDialogMessage::WholeWord(whole_word) => self.search.whole_word = whole_word,
+
+			FindReplaceDialogMessage::ReplaceAll => {
+				responses.add(DocumentMessage::ReplaceText {
+					search: self.search.clone(),
+					replacement: self.replacement.clone(),
+				});
+				responses.add(DocumentMessage::SetTextSearchHighlight { search: None });
+				return;
+			}
+		}
+
+		self.update_matches(portfolio, responses);
+		self.send_dialog_to_frontend(responses);
+	}
+
+	advertise_actions! {FindReplaceDialogUpdate;}
+}
+
+impl FindReplaceDialogMessageHandler {
+	/// Counts the matches for the search in the active document and highlights the text layers containing them.
+	pub fn update_matches(&mut self, portfolio: &PortfolioMessageHandler, responses: &mut VecDeque<Message>) {
+		let matches = portfolio.active_document().map(|document| document.text_search_matches(&self.search)).unwrap_or_default();
+		self.match_counts = (matches.iter().map(|&(_, count)| count).sum(), matches.len());
+
+		responses.add(DocumentMessage::SetTextSearchHighlight { search: Some(self.search.clone()) });
+	}
+}
+
+impl DialogLayoutHolder for FindReplaceDialogMessageHandler {
+	const ICON: &'static str = "NodeText";
+	const TITLE: &'static str = "Find and Replace";
+
+	fn layout_buttons(&self) -> Layout {
+		let widgets = vec![
+			TextButton::new("Replace All")
+				.emphasized(true)
+				.disabled(self.match_counts.0 == 0)
+				.on_update(|_| {
+					DialogMessage::CloseDialogAndThen {
+						followups: vec![FindReplaceDialogMessage::ReplaceAll.into()],
+					}
+					.into()
+				})
+				.widget_holder(),
+			TextButton::new("Close")
+				.on_update(|_| {
+					DialogMessage::CloseDialogAndThen {
+						followups: vec![DocumentMessage::SetTextSearchHighlight { search: None }.into()],
+					}
+					.into()
+				})
+				.widget_holder(),
+		];
+
+		Layout::WidgetLayout(WidgetLayout::new(vec![LayoutGroup::Row { widgets }]))
+	}
+}
+
+impl LayoutHolder for FindReplaceDialogMessageHandler {
+	fn layout(&self) -> Layout {
+		let find = vec![
+			TextLabel::new("Find").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			TextInput::new(&self.search.query)
+				.min_width(200)
+				.on_update(|text_input: &TextInput| FindReplaceDialogMessage::Query(text_input.value.clone()).into())
+				.widget_holder(),
+		];
+
+		let replace = vec![
+			TextLabel::new("Replace With").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			TextInput::new(&self.replacement)
+				.min_width(200)
+				.on_update(|text_input: &TextInput| FindReplaceDialogMessage::Replacement(text_input.value.clone()).into())
+				.widget_holder(),
+		];
+
+		let match_case = vec![
+			TextLabel::new("Match Case").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			CheckboxInput::new(self.search.match_case)
+				.tooltip("Only find text with the same capitalization")
+				.on_update(|checkbox_input: &CheckboxInput| FindReplaceDialogMessage::MatchCase(checkbox_input.checked).into())
+				.widget_holder(),
+		];
+
+		let whole_word = vec![
+			TextLabel::new("Whole Word").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			CheckboxInput::new(self.search.whole_word)
+				.tooltip("Only find text that isn't part of a longer word")
+				.on_update(|checkbox_input: &CheckboxInput| FindReplaceDialogMessage::WholeWord(checkbox_input.checked).into())
+				.widget_holder(),
+		];
+
+		let summary = match self.match_counts {
+			_ if self.search.query.is_empty() => "Type the text to search for in every text layer".to_string(),
+			(0, _) => "No matches".to_string(),
+			(1, _) => "1 match in 1 text layer".to_string(),
+			(matches, 1) => format!("{matches} matches in 1 text layer"),
+			(matches, layers) => format!("{matches} matches in {layers} text layers"),
+		};
+		let summary = vec![TextLabel::new(summary).italic(true).widget_holder()];
+
+		Layout::WidgetLayout(WidgetLayout::new(vec![
+			LayoutGroup::Row { widgets: find },
+			LayoutGroup::Row { widgets: replace },
+			LayoutGroup::Row { widgets: match_case },
+			LayoutGroup::Row { widgets: whole_word },
+			LayoutGroup::Row { widgets: summary },
+		]))
+	}
+}
@@ -0,0 +1,10 @@
+use crate::messages::prelude::*;
+use graphene_core::text::Font;
+
+#[impl_message(Message, DialogMessage, MissingFontsDialog)]
+#[derive(PartialEq, Eq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum MissingFontsDialogMessage {
+	Substitute { missing: Font, substitute: Font },
+
+	SubstituteAll,
+}
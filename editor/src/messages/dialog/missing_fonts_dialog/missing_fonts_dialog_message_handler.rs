@@ -0,0 +1,146 @@
+use crate::messages::layout::utility_types::widget_prelude::*;
+use crate::messages::prelude::*;
+use graphene_core::text::Font;
+
+pub struct MissingFontsDialogMessageData<'a> {
+	pub portfolio: &'a PortfolioMessageHandler,
+}
+
+/// A dialog listing the fonts used by the document which couldn't be found, where each can be substituted with another font or located as a font file.
+/// Until then, the text layers using them are drawn with the default font and marked in the viewport.
+#[derive(Debug, Clone, Default)]
+pub struct MissingFontsDialogMessageHandler {
+	/// Each font used in the active document which couldn't be found, with the number of text layers using it.
+	pub fonts: Vec<(Font, usize)>,
+	/// The font chosen to take the place of each missing font, kept so the choices aren't lost when the dialog is shown again.
+	pub substitutes: HashMap<Font, Font>,
+}
+
+impl MessageHandler<MissingFontsDialogMessage, MissingFontsDialogMessageData<'_>> for MissingFontsDialogMessageHandler {
+	fn process_message(&mut self, message: MissingFontsDialogMessage, responses: &mut VecDeque<Message>, data: MissingFontsDialogMessageData) {
+		let MissingFontsDialogMessageData { portfolio } = data;
+
+		match message {
+			MissingFontsDialogMessage::Substitute { missing, substitute } => {
+				self.substitutes.insert(missing, substitute);
+			}
+			MissingFontsDialogMessage::SubstituteAll => {
+				let replacements = self.fonts.iter().map(|(font, _)| (font.clone(), self.substitute(font))).collect();
+				responses.add(DocumentMessage::ReplaceFonts { replacements });
+				return;
+			}
+		}
+
+		self.update_fonts(portfolio);
+		self.send_dialog_to_frontend(responses);
+	}
+
+	advertise_actions! {MissingFontsDialogUpdate;}
+}
+
+impl MissingFontsDialogMessageHandler {
+	/// Lists the fonts used in the active document which couldn't be found.
+	pub fn update_fonts(&mut self, portfolio: &PortfolioMessageHandler) {
+		self.fonts = portfolio
+			.active_document()
+			.map(|document| document.missing_fonts(&portfolio.persistent_data.unavailable_fonts))
+			.unwrap_or_default();
+	}
+
+	fn substitute(&self, font: &Font) -> Font {
+		self.substitutes.get(font).cloned().unwrap_or_default()
+	}
+}
+
+impl DialogLayoutHolder for MissingFontsDialogMessageHandler {
+	const ICON: &'static str = "Warning";
+	const TITLE: &'static str = "Missing Fonts";
+
+	fn layout_buttons(&self) -> Layout {
+		let widgets = vec![
+			TextButton::new("Substitute All")
+				.emphasized(true)
+				.disabled(self.fonts.is_empty())
+				.on_update(|_| {
+					DialogMessage::CloseDialogAndThen {
+						followups: vec![MissingFontsDialogMessage::SubstituteAll.into()],
+					}
+					.into()
+				})
+				.widget_holder(),
+			TextButton::new("Keep Missing")
+				.tooltip("Keep drawing the text with the default font, marked in the viewport, until the fonts are substituted or located")
+				.on_update(|_| FrontendMessage::DisplayDialogDismiss.into())
+				.widget_holder(),
+		];
+
+		Layout::WidgetLayout(WidgetLayout::new(vec![LayoutGroup::Row { widgets }]))
+	}
+}
+
+impl LayoutHolder for MissingFontsDialogMessageHandler {
+	fn layout(&self) -> Layout {
+		let mut layout = vec![LayoutGroup::Row {
+			widgets: vec![
+				TextLabel::new("These fonts couldn't be found, so the text using them is drawn with the default font. Choose a substitute for each, or locate its font file.")
+					.multiline(true)
+					.widget_holder(),
+			],
+		}];
+
+		for (font, count) in &self.fonts {
+			let font = font.clone();
+			let substitute = self.substitute(&font);
+
+			let located_font = font.clone();
+			let missing = vec![
+				TextLabel::new(format!("{} {}", font.font_family, font.font_style))
+					.bold(true)
+					.table_align(true)
+					.min_width(200)
+					.widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				TextLabel::new(if *count == 1 { "1 text layer".to_string() } else { format!("{count} text layers") })
+					.italic(true)
+					.widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				TextButton::new("Locate File…")
+					.tooltip("Load this font from a font file on this computer")
+					.on_update(move |_| {
+						DialogMessage::CloseDialogAndThen {
+							followups: vec![FrontendMessage::TriggerLocateFontFile { font: located_font.clone() }.into()],
+						}
+						.into()
+					})
+					.widget_holder(),
+			];
+
+			let substitute_font = move |font_input: &FontInput| -> Message {
+				MissingFontsDialogMessage::Substitute {
+					missing: font.clone(),
+					substitute: Font::new(font_input.font_family.clone(), font_input.font_style.clone()),
+				}
+				.into()
+			};
+			let family = vec![
+				TextLabel::new("Substitute").table_align(true).min_width(200).widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				FontInput::new(substitute.font_family.clone(), substitute.font_style.clone())
+					.on_update(substitute_font.clone())
+					.widget_holder(),
+			];
+			let style = vec![
+				TextLabel::new("").table_align(true).min_width(200).widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				FontInput::new(substitute.font_family, substitute.font_style)
+					.is_style_picker(true)
+					.on_update(substitute_font)
+					.widget_holder(),
+			];
+
+			layout.extend([LayoutGroup::Row { widgets: missing }, LayoutGroup::Row { widgets: family }, LayoutGroup::Row { widgets: style }]);
+		}
+
+		Layout::WidgetLayout(WidgetLayout::new(layout))
+	}
+}
@@ -0,0 +1,7 @@
+mod missing_fonts_dialog_message;
+mod missing_fonts_dialog_message_handler;
+
+#[doc(inline)]
+pub use missing_fonts_dialog_message::{MissingFontsDialogMessage, MissingFontsDialogMessageDiscriminant};
+#[doc(inline)]
+pub use missing_fonts_dialog_message_handler::{MissingFontsDialogMessageData, MissingFontsDialogMessageHandler};
@@ -9,9 +9,13 @@ mod dialog_message;
 mod dialog_message_handler;
 
 pub mod export_dialog;
+pub mod find_replace_dialog;
+pub mod missing_fonts_dialog;
 pub mod new_document_dialog;
 pub mod preferences_dialog;
+pub mod replace_font_dialog;
 pub mod simple_dialogs;
+pub mod transform_each_dialog;
 
 #[doc(inline)]
 pub use dialog_message::{DialogMessage, DialogMessageDiscriminant};
@@ -0,0 +1,197 @@
+use crate::messages::portfolio::document::utility_types::guides::Guide;
+use crate::messages::portfolio::document::utility_types::swatches::Swatch;
+use glam::IVec2;
+use graphene_core::Color;
+
+/// The groups that templates are listed under in the New Document dialog.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum TemplateCategory {
+	Print,
+	SocialMedia,
+	IconGrid,
+	DeviceFrame,
+	/// Saved by the user from one of their own documents.
+	Custom,
+}
+
+impl TemplateCategory {
+	pub fn label(&self) -> &'static str {
+		match self {
+			Self::Print => "Print",
+			Self::SocialMedia => "Social Media",
+			Self::IconGrid => "Icon Grid",
+			Self::DeviceFrame => "Device Frame",
+			Self::Custom => "Custom",
+		}
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct TemplateArtboard {
+	pub name: String,
+	pub location: IVec2,
+	pub dimensions: IVec2,
+}
+
+impl TemplateArtboard {
+	fn new(name: &str, location: IVec2, dimensions: IVec2) -> Self {
+		Self {
+			name: name.to_string(),
+			location,
+			dimensions,
+		}
+	}
+}
+
+/// A starting point for a new document, with the artboards, guides, and swatches it's created with.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct DocumentTemplate {
+	pub name: String,
+	pub category: TemplateCategory,
+	pub artboards: Vec<TemplateArtboard>,
+	pub guides: Vec<Guide>,
+	pub swatches: Vec<Swatch>,
+}
+
+impl DocumentTemplate {
+	fn new(name: &str, category: TemplateCategory) -> Self {
+		Self {
+			name: name.to_string(),
+			category,
+			artboards: Vec::new(),
+			guides: Vec::new(),
+			swatches: Vec::new(),
+		}
+	}
+
+	/// Adds an artboard with guides inset from each of its edges by the given margin.
+	fn artboard(mut self, name: &str, location: IVec2, dimensions: IVec2, margin: i32) -> Self {
+		if margin > 0 {
+			let [min, max] = [location + margin, location + dimensions - margin].map(|corner| corner.as_dvec2());
+			self.guides.extend([Guide::vertical(min.x), Guide::vertical(max.x), Guide::horizontal(min.y), Guide::horizontal(max.y)]);
+		}
+		self.artboards.push(TemplateArtboard::new(name, location, dimensions));
+		self
+	}
+
+	fn guides(mut self, guides: impl IntoIterator<Item = Guide>) -> Self {
+		self.guides.extend(guides);
+		self
+	}
+
+	fn swatches(mut self, swatches: &[(&str, &str)]) -> Self {
+		self.swatches.extend(swatches.iter().filter_map(|&(name, hex)| Some(Swatch::new(name, Color::from_rgb_str(hex)?))));
+		self
+	}
+
+	/// Describes the contents of the template, to be shown once it's chosen.
+	pub fn summary(&self) -> String {
+		let count = |number: usize, singular: &str, plural: &str| format!("{number} {}", if number == 1 { singular } else { plural });
+		let artboards = match self.artboards.as_slice() {
+			[artboard] => format!("{} × {} px artboard", artboard.dimensions.x, artboard.dimensions.y),
+			artboards => count(artboards.len(), "artboard", "artboards"),
+		};
+		let guides = count(self.guides.len(), "guide", "guides");
+		let swatches = count(self.swatches.len(), "swatch", "swatches");
+		format!("{artboards}, {guides}, {swatches}")
+	}
+}
+
+const PRINT_SWATCHES: &[(&str, &str)] = &[("Process Cyan", "00aeef"), ("Process Magenta", "ec008c"), ("Process Yellow", "fff200"), ("Rich Black", "231f20")];
+const INTERFACE_SWATCHES: &[(&str, &str)] = &[("Accent", "0a84ff"), ("Background", "ffffff"), ("Surface", "f2f2f7"), ("Text", "1c1c1e")];
+
+/// The templates that come with Graphite, in the order they're listed in the New Document dialog.
+pub fn built_in_templates() -> Vec<DocumentTemplate> {
+	use TemplateCategory::*;
+
+	// Pages are sized at 96 pixels per inch, with margins of half an inch
+	let page = |name: &str, width: i32, height: i32, margin: i32| {
+		DocumentTemplate::new(name, Print)
+			.artboard(name, IVec2::ZERO, IVec2::new(width, height), margin)
+			.swatches(PRINT_SWATCHES)
+	};
+	let square_center = |size: i32| [Guide::vertical(size as f64 / 2.), Guide::horizontal(size as f64 / 2.)];
+	// A row of icon artboards with a gap between them, and keylines inset from the edges of each icon by the padding
+	let icon_set = |name: &str, sizes: &[i32], padding: i32| {
+		let mut x = 0;
+		sizes.iter().fold(DocumentTemplate::new(name, IconGrid), |template, &size| {
+			let template = template.artboard(&format!("{size} px"), IVec2::new(x, 0), IVec2::splat(size), padding);
+			x += size + size.max(32);
+			template
+		})
+	};
+	// Device screens are marked with the areas covered by the system status bar and navigation controls
+	let device = |name: &str, width: i32, height: i32, top: i32, bottom: i32, margin: i32| {
+		DocumentTemplate::new(name, DeviceFrame)
+			.artboard(name, IVec2::ZERO, IVec2::new(width, height), 0)
+			.guides([
+				Guide::horizontal(top as f64),
+				Guide::horizontal((height - bottom) as f64),
+				Guide::vertical(margin as f64),
+				Guide::vertical((width - margin) as f64),
+			])
+			.swatches(INTERFACE_SWATCHES)
+	};
+
+	vec![
+		page("A4", 794, 1123, 48),
+		page("US Letter", 816, 1056, 48),
+		page("Business Card", 336, 192, 12),
+		page("Poster (18 × 24 in)", 1728, 2304, 96),
+		DocumentTemplate::new("Instagram Post", SocialMedia)
+			.artboard("Instagram Post", IVec2::ZERO, IVec2::splat(1080), 0)
+			.guides(square_center(1080)),
+		DocumentTemplate::new("Instagram Story", SocialMedia)
+			.artboard("Instagram Story", IVec2::ZERO, IVec2::new(1080, 1920), 0)
+			// Covered by the profile name at the top and the reply field at the bottom
+			.guides([Guide::horizontal(250.), Guide::horizontal(1670.)]),
+		DocumentTemplate::new("YouTube Thumbnail", SocialMedia).artboard("YouTube Thumbnail", IVec2::ZERO, IVec2::new(1280, 720), 0),
+		DocumentTemplate::new("X Header", SocialMedia).artboard("X Header", IVec2::ZERO, IVec2::new(1500, 500), 0),
+		DocumentTemplate::new("Social Media Kit", SocialMedia)
+			.artboard("Post", IVec2::ZERO, IVec2::splat(1080), 0)
+			.artboard("Story", IVec2::new(1180, 0), IVec2::new(1080, 1920), 0)
+			.artboard("Thumbnail", IVec2::new(2360, 0), IVec2::new(1280, 720), 0),
+		DocumentTemplate::new("App Icon", IconGrid)
+			.artboard("App Icon", IVec2::ZERO, IVec2::splat(1024), 100)
+			.guides(square_center(1024)),
+		icon_set("Icon Set (24 px)", &[24; 8], 2),
+		icon_set("Favicons", &[16, 32, 48, 180], 0),
+		device("iPhone", 393, 852, 59, 34, 16),
+		device("Android Phone", 412, 915, 24, 48, 16),
+		device("iPad", 820, 1180, 24, 20, 20),
+		DocumentTemplate::new("Desktop", DeviceFrame)
+			.artboard("Desktop", IVec2::ZERO, IVec2::new(1440, 1024), 0)
+			.guides([Guide::vertical(80.), Guide::vertical(720.), Guide::vertical(1360.)])
+			.swatches(INTERFACE_SWATCHES),
+	]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::messages::portfolio::document::utility_types::guides::GuideDirection;
+
+	#[test]
+	fn template_names_are_unique() {
+		let templates = built_in_templates();
+		for (index, template) in templates.iter().enumerate() {
+			assert!(templates[..index].iter().all(|other| other.name != template.name), "Duplicate template name {}", template.name);
+		}
+	}
+
+	#[test]
+	fn margins_are_inset_from_each_artboard() {
+		let template = DocumentTemplate::new("Two Pages", TemplateCategory::Print)
+			.artboard("First", IVec2::ZERO, IVec2::new(100, 200), 10)
+			.artboard("Second", IVec2::new(150, 0), IVec2::new(100, 200), 10);
+
+		let vertical = template
+			.guides
+			.iter()
+			.filter(|guide| guide.direction == GuideDirection::Vertical)
+			.map(|guide| guide.position)
+			.collect::<Vec<_>>();
+		assert_eq!(vertical, [10., 90., 160., 240.]);
+		assert_eq!(template.summary(), "2 artboards, 8 guides, 0 swatches");
+	}
+}
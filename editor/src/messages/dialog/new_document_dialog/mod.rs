@@ -1,3 +1,4 @@
+pub mod document_templates;
 mod new_document_dialog_message;
 mod new_document_dialog_message_handler;
 
@@ -7,6 +7,8 @@ pub enum NewDocumentDialogMessage {
 	Infinite(bool),
 	DimensionsX(f64),
 	DimensionsY(f64),
+	Template(Option<usize>),
+	DeleteCustomTemplate,
 
 	Submit,
 }
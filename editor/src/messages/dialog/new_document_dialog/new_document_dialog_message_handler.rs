@@ -1,14 +1,29 @@
+use super::document_templates::{DocumentTemplate, TemplateCategory, built_in_templates};
 use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::prelude::*;
 use glam::{IVec2, UVec2};
 use graph_craft::document::NodeId;
 
-/// A dialog to allow users to set some initial options about a new document.
+/// A dialog to allow users to set some initial options about a new document, or to start it from a template.
 #[derive(Debug, Clone, Default)]
 pub struct NewDocumentDialogMessageHandler {
 	pub name: String,
 	pub infinite: bool,
 	pub dimensions: UVec2,
+	/// The templates saved by the user from their own documents, which are listed after the built-in ones.
+	pub custom_templates: Vec<DocumentTemplate>,
+	/// The index of the chosen template among the built-in templates followed by the custom ones, or `None` to start from a blank document.
+	pub template: Option<usize>,
+}
+
+impl NewDocumentDialogMessageHandler {
+	fn templates(&self) -> Vec<DocumentTemplate> {
+		built_in_templates().into_iter().chain(self.custom_templates.iter().cloned()).collect()
+	}
+
+	fn selected_template(&self) -> Option<DocumentTemplate> {
+		self.templates().into_iter().nth(self.template?)
+	}
 }
 
 impl MessageHandler<NewDocumentDialogMessage, ()> for NewDocumentDialogMessageHandler {
@@ -18,16 +33,50 @@ impl MessageHandler<NewDocumentDialogMessage, ()> for NewDocumentDialogMessageHa
 			NewDocumentDialogMessage::Infinite(infinite) => self.infinite = infinite,
 			NewDocumentDialogMessage::DimensionsX(x) => self.dimensions.x = x as u32,
 			NewDocumentDialogMessage::DimensionsY(y) => self.dimensions.y = y as u32,
+			NewDocumentDialogMessage::Template(template) => {
+				self.template = template;
+				if let Some(template) = self.selected_template() {
+					self.name = template.name;
+				}
+			}
+			NewDocumentDialogMessage::DeleteCustomTemplate => {
+				let Some(index) = self.template.and_then(|index| index.checked_sub(built_in_templates().len())) else {
+					return;
+				};
+				if index < self.custom_templates.len() {
+					let template = self.custom_templates.remove(index);
+					responses.add(PreferencesMessage::DeleteDocumentTemplate { name: template.name });
+					self.template = None;
+				}
+			}
 			NewDocumentDialogMessage::Submit => {
 				responses.add(PortfolioMessage::NewDocumentWithName { name: self.name.clone() });
 
-				let create_artboard = !self.infinite && self.dimensions.x > 0 && self.dimensions.y > 0;
-				if create_artboard {
+				if let Some(template) = self.selected_template() {
 					responses.add(Message::StartBuffer);
-					responses.add(GraphOperationMessage::NewArtboard {
-						id: NodeId::new(),
-						artboard: graphene_core::Artboard::new(IVec2::ZERO, self.dimensions.as_ivec2()),
-					});
+					for artboard in template.artboards {
+						let id = NodeId::new();
+						responses.add(GraphOperationMessage::NewArtboard {
+							id,
+							artboard: graphene_core::Artboard::new(artboard.location, artboard.dimensions),
+						});
+						responses.add(NodeGraphMessage::SetDisplayName {
+							node_id: id,
+							alias: artboard.name,
+							skip_adding_history_step: true,
+						});
+					}
+					responses.add(DocumentMessage::SetGuides { guides: template.guides });
+					responses.add(DocumentMessage::SetSwatches { swatches: template.swatches });
+				} else {
+					let create_artboard = !self.infinite && self.dimensions.x > 0 && self.dimensions.y > 0;
+					if create_artboard {
+						responses.add(Message::StartBuffer);
+						responses.add(GraphOperationMessage::NewArtboard {
+							id: NodeId::new(),
+							artboard: graphene_core::Artboard::new(IVec2::ZERO, self.dimensions.as_ivec2()),
+						});
+					}
 				}
 
 				// TODO: Figure out how to get StartBuffer to work here so we can delete this and use `DocumentMessage::ZoomCanvasToFitAll` instead
@@ -68,6 +117,45 @@ impl DialogLayoutHolder for NewDocumentDialogMessageHandler {
 
 impl LayoutHolder for NewDocumentDialogMessageHandler {
 	fn layout(&self) -> Layout {
+		let templates = self.templates();
+		let template = self.template.and_then(|index| templates.get(index));
+
+		// The blank document comes first, followed by the templates grouped by their category, so the index of each entry is one more than that of its template
+		let mut entries = vec![vec![MenuListEntry::new("Blank").label("Blank").on_update(|_| NewDocumentDialogMessage::Template(None).into())]];
+		let mut index = 0;
+		for category in templates.chunk_by(|a, b| a.category == b.category) {
+			entries.push(
+				category
+					.iter()
+					.map(|template| {
+						let template_index = index;
+						index += 1;
+						MenuListEntry::new(&template.name)
+							.label(format!("{}: {}", template.category.label(), template.name))
+							.on_update(move |_| NewDocumentDialogMessage::Template(Some(template_index)).into())
+					})
+					.collect(),
+			);
+		}
+
+		let mut template_row = vec![
+			TextLabel::new("Template").table_align(true).min_width(90).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(entries)
+				.selected_index(Some(self.template.map_or(0, |index| index as u32 + 1)))
+				.searchable(true)
+				.widget_holder(),
+		];
+		if template.is_some_and(|template| template.category == TemplateCategory::Custom) {
+			template_row.extend([
+				Separator::new(SeparatorType::Related).widget_holder(),
+				IconButton::new("Trash", 24)
+					.tooltip("Delete this template")
+					.on_update(|_| NewDocumentDialogMessage::DeleteCustomTemplate.into())
+					.widget_holder(),
+			]);
+		}
+
 		let name = vec![
 			TextLabel::new("Name").table_align(true).min_width(90).widget_holder(),
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
@@ -77,10 +165,13 @@ impl LayoutHolder for NewDocumentDialogMessageHandler {
 				.widget_holder(),
 		];
 
+		let from_template = template.is_some();
+
 		let infinite = vec![
 			TextLabel::new("Infinite Canvas").table_align(true).min_width(90).widget_holder(),
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
 			CheckboxInput::new(self.infinite)
+				.disabled(from_template)
 				.on_update(|checkbox_input: &CheckboxInput| NewDocumentDialogMessage::Infinite(checkbox_input.checked).into())
 				.widget_holder(),
 		];
@@ -94,7 +185,7 @@ impl LayoutHolder for NewDocumentDialogMessageHandler {
 				.min(0.)
 				.max((1_u64 << f64::MANTISSA_DIGITS) as f64)
 				.is_integer(true)
-				.disabled(self.infinite)
+				.disabled(self.infinite || from_template)
 				.min_width(100)
 				.on_update(|number_input: &NumberInput| NewDocumentDialogMessage::DimensionsX(number_input.value.unwrap()).into())
 				.widget_holder(),
@@ -105,16 +196,24 @@ impl LayoutHolder for NewDocumentDialogMessageHandler {
 				.min(0.)
 				.max((1_u64 << f64::MANTISSA_DIGITS) as f64)
 				.is_integer(true)
-				.disabled(self.infinite)
+				.disabled(self.infinite || from_template)
 				.min_width(100)
 				.on_update(|number_input: &NumberInput| NewDocumentDialogMessage::DimensionsY(number_input.value.unwrap()).into())
 				.widget_holder(),
 		];
 
-		Layout::WidgetLayout(WidgetLayout::new(vec![
+		let mut layout = vec![
+			LayoutGroup::Row { widgets: template_row },
 			LayoutGroup::Row { widgets: name },
 			LayoutGroup::Row { widgets: infinite },
 			LayoutGroup::Row { widgets: scale },
-		]))
+		];
+		if let Some(template) = template {
+			layout.push(LayoutGroup::Row {
+				widgets: vec![TextLabel::new(template.summary()).italic(true).widget_holder()],
+			});
+		}
+
+		Layout::WidgetLayout(WidgetLayout::new(layout))
 	}
 }
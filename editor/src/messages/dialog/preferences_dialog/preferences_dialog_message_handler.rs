@@ -131,6 +131,27 @@ impl PreferencesDialogMessageHandler {
 			selection_mode,
 		];
 
+		// ======
+		// STYLUS
+		// ======
+
+		let stylus_header = vec![TextLabel::new("Stylus").italic(true).widget_holder()];
+
+		let pressure_curve_tooltip = "Remap the pressure of a pen tablet's stylus (horizontal axis) to the pressure used by tools (vertical axis), to suit how firmly your tablet must be pressed";
+		let pressure_curve_label = vec![
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			TextLabel::new("Pressure Response").tooltip(pressure_curve_tooltip).widget_holder(),
+		];
+		let pressure_curve = vec![
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			CurveInput::new(preferences.pressure_curve.clone())
+				.tooltip(pressure_curve_tooltip)
+				.on_update(|curve_input: &CurveInput| PreferencesMessage::PressureCurve { curve: curve_input.value.clone() }.into())
+				.widget_holder(),
+		];
+
 		// ============
 		// EXPERIMENTAL
 		// ============
@@ -143,17 +164,18 @@ impl PreferencesDialogMessageHandler {
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
 			TextLabel::new("Node Graph Wires").tooltip(node_graph_section_tooltip).widget_holder(),
 		];
-		let graph_wire_style = RadioInput::new(vec![
-			RadioEntryData::new(GraphWireStyle::Direct.to_string())
-				.label(GraphWireStyle::Direct.to_string())
-				.tooltip(GraphWireStyle::Direct.tooltip_description())
-				.on_update(move |_| PreferencesMessage::GraphWireStyle { style: GraphWireStyle::Direct }.into()),
-			RadioEntryData::new(GraphWireStyle::GridAligned.to_string())
-				.label(GraphWireStyle::GridAligned.to_string())
-				.tooltip(GraphWireStyle::GridAligned.tooltip_description())
-				.on_update(move |_| PreferencesMessage::GraphWireStyle { style: GraphWireStyle::GridAligned }.into()),
-		])
-		.selected_index(Some(preferences.graph_wire_style as u32))
+		let graph_wire_style = RadioInput::new(
+			GraphWireStyle::ALL
+				.into_iter()
+				.map(|style| {
+					RadioEntryData::new(style.to_string())
+						.label(style.to_string())
+						.tooltip(style.tooltip_description())
+						.on_update(move |_| PreferencesMessage::GraphWireStyle { style }.into())
+				})
+				.collect(),
+		)
+		.selected_index(GraphWireStyle::ALL.iter().position(|&style| style == preferences.graph_wire_style).map(|index| index as u32))
 		.widget_holder();
 		let graph_wire_style = vec![
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
@@ -217,6 +239,9 @@ impl PreferencesDialogMessageHandler {
 			LayoutGroup::Row { widgets: editing_header },
 			LayoutGroup::Row { widgets: selection_label },
 			LayoutGroup::Row { widgets: selection_mode },
+			LayoutGroup::Row { widgets: stylus_header },
+			LayoutGroup::Row { widgets: pressure_curve_label },
+			LayoutGroup::Row { widgets: pressure_curve },
 			LayoutGroup::Row { widgets: experimental_header },
 			LayoutGroup::Row { widgets: node_graph_wires_label },
 			LayoutGroup::Row { widgets: graph_wire_style },
@@ -0,0 +1,7 @@
+mod replace_font_dialog_message;
+mod replace_font_dialog_message_handler;
+
+#[doc(inline)]
+pub use replace_font_dialog_message::{ReplaceFontDialogMessage, ReplaceFontDialogMessageDiscriminant};
+#[doc(inline)]
+pub use replace_font_dialog_message_handler::{ReplaceFontDialogMessageData, ReplaceFontDialogMessageHandler};
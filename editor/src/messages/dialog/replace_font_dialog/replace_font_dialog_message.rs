@@ -0,0 +1,11 @@
+use crate::messages::prelude::*;
+use graphene_core::text::Font;
+
+#[impl_message(Message, DialogMessage, ReplaceFontDialog)]
+#[derive(PartialEq, Eq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum ReplaceFontDialogMessage {
+	Find(usize),
+	Replacement(Font),
+
+	ReplaceAll,
+}
@@ -0,0 +1,163 @@
+use crate::messages::layout::utility_types::widget_prelude::*;
+use crate::messages::prelude::*;
+use graphene_core::text::Font;
+
+pub struct ReplaceFontDialogMessageData<'a> {
+	pub portfolio: &'a PortfolioMessageHandler,
+}
+
+/// A dialog listing every font used by the text layers of the document, where one of them can be swapped for another font in all the text layers using it.
+#[derive(Debug, Clone, Default)]
+pub struct ReplaceFontDialogMessageHandler {
+	/// Each font used in the active document, with the number of text layers using it and whether it's missing because it couldn't be loaded.
+	pub fonts: Vec<(Font, usize, bool)>,
+	/// The index in `fonts` of the font to be replaced.
+	pub find: usize,
+	pub replacement: Font,
+}
+
+impl MessageHandler<ReplaceFontDialogMessage, ReplaceFontDialogMessageData<'_>> for ReplaceFontDialogMessageHandler {
+	fn process_message(&mut self, message: ReplaceFontDialogMessage, responses: &mut VecDeque<Message>, data: ReplaceFontDialogMessageData) {
+		let ReplaceFontDialogMessageData { portfolio } = data;
+
+		match message {
+			ReplaceFontDialogMessage::Find(index) => self.find = index,
+			ReplaceFontDialogMessage::Replacement(font) => self.replacement = font,
+
+			ReplaceFontDialogMessage::ReplaceAll => {
+				if let Some((find, _, _)) = self.fonts.get(self.find) {
+					responses.add(DocumentMessage::ReplaceFonts {
+						replacements: vec![(find.clone(), self.replacement.clone())],
+					});
+				}
+				return;
+			}
+		}
+
+		self.update_fonts(portfolio);
+		self.send_dialog_to_frontend(responses);
+	}
+
+	advertise_actions! {ReplaceFontDialogUpdate;}
+}
+
+impl ReplaceFontDialogMessageHandler {
+	/// Lists the fonts used in the active document, keeping the font to be replaced chosen if it's still in use.
+	pub fn update_fonts(&mut self, portfolio: &PortfolioMessageHandler) {
+		let previous = self.fonts.get(self.find).map(|(font, _, _)| font.clone());
+
+		let font_cache = &portfolio.persistent_data.font_cache;
+		self.fonts = portfolio
+			.active_document()
+			.map(|document| document.fonts_in_use())
+			.unwrap_or_default()
+			.into_iter()
+			.map(|(font, count)| {
+				let missing = !font_cache.loaded_font(&font);
+				(font, count, missing)
+			})
+			.collect();
+
+		self.find = previous.and_then(|previous| self.fonts.iter().position(|(font, _, _)| *font == previous)).unwrap_or_default();
+	}
+
+	fn font_name(font: &Font) -> String {
+		format!("{} {}", font.font_family, font.font_style)
+	}
+}
+
+impl DialogLayoutHolder for ReplaceFontDialogMessageHandler {
+	const ICON: &'static str = "NodeText";
+	const TITLE: &'static str = "Replace Font";
+
+	fn layout_buttons(&self) -> Layout {
+		let unchanged = self.fonts.get(self.find).is_none_or(|(font, _, _)| *font == self.replacement);
+
+		let widgets = vec![
+			TextButton::new("Replace All")
+				.emphasized(true)
+				.disabled(unchanged)
+				.on_update(|_| {
+					DialogMessage::CloseDialogAndThen {
+						followups: vec![ReplaceFontDialogMessage::ReplaceAll.into()],
+					}
+					.into()
+				})
+				.widget_holder(),
+			TextButton::new("Cancel").on_update(|_| FrontendMessage::DisplayDialogDismiss.into()).widget_holder(),
+		];
+
+		Layout::WidgetLayout(WidgetLayout::new(vec![LayoutGroup::Row { widgets }]))
+	}
+}
+
+impl LayoutHolder for ReplaceFontDialogMessageHandler {
+	fn layout(&self) -> Layout {
+		if self.fonts.is_empty() {
+			return Layout::WidgetLayout(WidgetLayout::new(vec![LayoutGroup::Row {
+				widgets: vec![TextLabel::new("No text layers use a font in this document").italic(true).widget_holder()],
+			}]));
+		}
+
+		let mut layout = vec![LayoutGroup::Row {
+			widgets: vec![TextLabel::new("Fonts in Use").bold(true).widget_holder()],
+		}];
+
+		for (font, count, missing) in &self.fonts {
+			let mut widgets = vec![
+				TextLabel::new(Self::font_name(font)).table_align(true).min_width(200).widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				TextLabel::new(if *count == 1 { "1 text layer".to_string() } else { format!("{count} text layers") })
+					.italic(true)
+					.widget_holder(),
+			];
+			if *missing {
+				widgets.extend([
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					TextLabel::new("Missing")
+						.bold(true)
+						.tooltip("This font couldn't be loaded, so its text is drawn with the default font until it's replaced")
+						.widget_holder(),
+				]);
+			}
+			layout.push(LayoutGroup::Row { widgets });
+		}
+
+		let entries = self
+			.fonts
+			.iter()
+			.enumerate()
+			.map(|(index, (font, _, _))| {
+				MenuListEntry::new(format!("{index}"))
+					.label(Self::font_name(font))
+					.on_commit(move |_| ReplaceFontDialogMessage::Find(index).into())
+			})
+			.collect::<Vec<_>>();
+		let find = vec![
+			TextLabel::new("Replace").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(self.find as u32)).widget_holder(),
+		];
+
+		let from_font_input = |font_input: &FontInput| -> Message { ReplaceFontDialogMessage::Replacement(Font::new(font_input.font_family.clone(), font_input.font_style.clone())).into() };
+		let family = vec![
+			TextLabel::new("With").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			FontInput::new(self.replacement.font_family.clone(), self.replacement.font_style.clone())
+				.on_update(from_font_input)
+				.widget_holder(),
+		];
+		let style = vec![
+			TextLabel::new("").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			FontInput::new(self.replacement.font_family.clone(), self.replacement.font_style.clone())
+				.is_style_picker(true)
+				.on_update(from_font_input)
+				.widget_holder(),
+		];
+
+		layout.extend([LayoutGroup::Row { widgets: find }, LayoutGroup::Row { widgets: family }, LayoutGroup::Row { widgets: style }]);
+
+		Layout::WidgetLayout(WidgetLayout::new(layout))
+	}
+}
@@ -0,0 +1,7 @@
+mod transform_each_dialog_message;
+mod transform_each_dialog_message_handler;
+
+#[doc(inline)]
+pub use transform_each_dialog_message::{TransformEachDialogMessage, TransformEachDialogMessageDiscriminant};
+#[doc(inline)]
+pub use transform_each_dialog_message_handler::TransformEachDialogMessageHandler;
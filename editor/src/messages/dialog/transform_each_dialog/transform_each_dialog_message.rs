@@ -0,0 +1,17 @@
+use crate::messages::prelude::*;
+
+#[impl_message(Message, DialogMessage, TransformEachDialog)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum TransformEachDialogMessage {
+	TranslationX(f64),
+	TranslationY(f64),
+	Rotation(f64),
+	ScaleX(f64),
+	ScaleY(f64),
+	RandomTranslation(f64),
+	RandomRotation(f64),
+	RandomScale(f64),
+	Seed(u32),
+
+	Submit,
+}
@@ -0,0 +1,166 @@
+use crate::messages::layout::utility_types::widget_prelude::*;
+use crate::messages::portfolio::document::utility_types::misc::TransformEachOptions;
+use crate::messages::prelude::*;
+
+/// A dialog to transform every selected layer about its own pivot, rather than about the combined bounds of the selection.
+/// The options are kept between uses so the same transformation can be repeated.
+#[derive(Debug, Clone, Default)]
+pub struct TransformEachDialogMessageHandler {
+	pub options: TransformEachOptions,
+}
+
+impl MessageHandler<TransformEachDialogMessage, ()> for TransformEachDialogMessageHandler {
+	fn process_message(&mut self, message: TransformEachDialogMessage, responses: &mut VecDeque<Message>, _data: ()) {
+		match message {
+			TransformEachDialogMessage::TranslationX(x) => self.options.translation.x = x,
+			TransformEachDialogMessage::TranslationY(y) => self.options.translation.y = y,
+			TransformEachDialogMessage::Rotation(degrees) => self.options.rotation = degrees,
+			TransformEachDialogMessage::ScaleX(percent) => self.options.scale.x = percent / 100.,
+			TransformEachDialogMessage::ScaleY(percent) => self.options.scale.y = percent / 100.,
+			TransformEachDialogMessage::RandomTranslation(distance) => self.options.random_translation = distance,
+			TransformEachDialogMessage::RandomRotation(degrees) => self.options.random_rotation = degrees,
+			TransformEachDialogMessage::RandomScale(percent) => self.options.random_scale = percent / 100.,
+			TransformEachDialogMessage::Seed(seed) => self.options.seed = seed,
+			TransformEachDialogMessage::Submit => responses.add(DocumentMessage::TransformEachSelectedLayer { options: self.options }),
+		}
+
+		self.send_dialog_to_frontend(responses);
+	}
+
+	advertise_actions! {TransformEachDialogUpdate;}
+}
+
+impl DialogLayoutHolder for TransformEachDialogMessageHandler {
+	const ICON: &'static str = "TurnPositive90";
+	const TITLE: &'static str = "Transform Each";
+
+	fn layout_buttons(&self) -> Layout {
+		let widgets = vec![
+			TextButton::new("OK")
+				.emphasized(true)
+				.on_update(|_| {
+					DialogMessage::CloseDialogAndThen {
+						followups: vec![TransformEachDialogMessage::Submit.into()],
+					}
+					.into()
+				})
+				.widget_holder(),
+			TextButton::new("Cancel").on_update(|_| FrontendMessage::DisplayDialogDismiss.into()).widget_holder(),
+		];
+
+		Layout::WidgetLayout(WidgetLayout::new(vec![LayoutGroup::Row { widgets }]))
+	}
+}
+
+impl LayoutHolder for TransformEachDialogMessageHandler {
+	fn layout(&self) -> Layout {
+		let options = &self.options;
+
+		let translation = vec![
+			TextLabel::new("Move").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			NumberInput::new(Some(options.translation.x))
+				.label("X")
+				.unit(" px")
+				.min_width(100)
+				.on_update(|number_input: &NumberInput| TransformEachDialogMessage::TranslationX(number_input.value.unwrap()).into())
+				.widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			NumberInput::new(Some(options.translation.y))
+				.label("Y")
+				.unit(" px")
+				.min_width(100)
+				.on_update(|number_input: &NumberInput| TransformEachDialogMessage::TranslationY(number_input.value.unwrap()).into())
+				.widget_holder(),
+		];
+
+		let rotation = vec![
+			TextLabel::new("Rotate").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			NumberInput::new(Some(options.rotation))
+				.unit("°")
+				.min_width(204) // Matches the 100px of both NumberInputs above + the 4px of the Related-type separator
+				.on_update(|number_input: &NumberInput| TransformEachDialogMessage::Rotation(number_input.value.unwrap()).into())
+				.widget_holder(),
+		];
+
+		let scale = vec![
+			TextLabel::new("Scale").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			NumberInput::new(Some(options.scale.x * 100.))
+				.label("W")
+				.unit("%")
+				.min_width(100)
+				.on_update(|number_input: &NumberInput| TransformEachDialogMessage::ScaleX(number_input.value.unwrap()).into())
+				.widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			NumberInput::new(Some(options.scale.y * 100.))
+				.label("H")
+				.unit("%")
+				.min_width(100)
+				.on_update(|number_input: &NumberInput| TransformEachDialogMessage::ScaleY(number_input.value.unwrap()).into())
+				.widget_holder(),
+		];
+
+		let random_translation = vec![
+			TextLabel::new("Random Move").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			NumberInput::new(Some(options.random_translation))
+				.unit(" px")
+				.min(0.)
+				.min_width(204)
+				.tooltip("Moves each layer by up to this distance along each axis, in addition to the movement above")
+				.on_update(|number_input: &NumberInput| TransformEachDialogMessage::RandomTranslation(number_input.value.unwrap()).into())
+				.widget_holder(),
+		];
+
+		let random_rotation = vec![
+			TextLabel::new("Random Rotate").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			NumberInput::new(Some(options.random_rotation))
+				.unit("°")
+				.min(0.)
+				.max(180.)
+				.min_width(204)
+				.tooltip("Rotates each layer by up to this angle in either direction, in addition to the rotation above")
+				.on_update(|number_input: &NumberInput| TransformEachDialogMessage::RandomRotation(number_input.value.unwrap()).into())
+				.widget_holder(),
+		];
+
+		let random_scale = vec![
+			TextLabel::new("Random Scale").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			NumberInput::new(Some(options.random_scale * 100.))
+				.unit("%")
+				.min(0.)
+				.max(100.)
+				.min_width(204)
+				.tooltip("Grows or shrinks each layer by up to this percentage, in addition to the scale above")
+				.on_update(|number_input: &NumberInput| TransformEachDialogMessage::RandomScale(number_input.value.unwrap()).into())
+				.widget_holder(),
+		];
+
+		let seed = vec![
+			TextLabel::new("Seed").table_align(true).min_width(100).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			NumberInput::new(Some(options.seed as f64))
+				.int()
+				.min(0.)
+				.max(u32::MAX as f64)
+				.min_width(204)
+				.tooltip("The seed used for the random values, so the same result can be reproduced")
+				.on_update(|number_input: &NumberInput| TransformEachDialogMessage::Seed(number_input.value.unwrap() as u32).into())
+				.widget_holder(),
+		];
+
+		Layout::WidgetLayout(WidgetLayout::new(vec![
+			LayoutGroup::Row { widgets: translation },
+			LayoutGroup::Row { widgets: rotation },
+			LayoutGroup::Row { widgets: scale },
+			LayoutGroup::Row { widgets: random_translation },
+			LayoutGroup::Row { widgets: random_rotation },
+			LayoutGroup::Row { widgets: random_scale },
+			LayoutGroup::Row { widgets: seed },
+		]))
+	}
+}
@@ -1,14 +1,18 @@
 use super::utility_types::{FrontendDocumentDetails, MouseCursorIcon};
 use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::portfolio::document::node_graph::utility_types::{
-	BoxSelection, ContextMenuInformation, FrontendClickTargets, FrontendGraphInput, FrontendGraphOutput, FrontendNode, FrontendNodeType, FrontendNodeWire, Transform, WirePath,
+	BoxSelection, ContextMenuInformation, FrontendClickTargets, FrontendGraphFrame, FrontendGraphInput, FrontendGraphNote, FrontendGraphOutput, FrontendNode, FrontendNodeProfile, FrontendNodeType,
+	FrontendNodeWire, FrontendPinnedWireProbe, FrontendWireProbe, GraphWireStyle, Transform, WirePath,
 };
 use crate::messages::portfolio::document::utility_types::nodes::{JsRawBuffer, LayerPanelEntry, RawBuffer};
+use crate::messages::portfolio::document::utility_types::print::PrintJob;
+use crate::messages::portfolio::document::utility_types::swatches::Swatch;
 use crate::messages::prelude::*;
 use crate::messages::tool::utility_types::HintData;
 use graph_craft::document::NodeId;
+use graphene_core::raster::cmyk::PrintProfile;
 use graphene_core::raster::color::Color;
-use graphene_core::text::Font;
+use graphene_core::text::{Font, TextAlign};
 
 #[impl_message(Message, Frontend)]
 #[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize, specta::Type)]
@@ -36,6 +40,7 @@ pub enum FrontendMessage {
 		max_width: Option<f64>,
 		#[serde(rename = "maxHeight")]
 		max_height: Option<f64>,
+		align: TextAlign,
 	},
 	DisplayEditableTextboxTransform {
 		transform: [f64; 6],
@@ -74,22 +79,47 @@ pub enum FrontendMessage {
 		font: Font,
 	},
 	TriggerImport,
+	TriggerLocateFontFile {
+		font: Font,
+	},
 	TriggerIndexedDbRemoveDocument {
 		#[serde(rename = "documentId")]
 		document_id: DocumentId,
 	},
+	TriggerIndexedDbRemoveRecentFiles {
+		keys: Vec<u64>,
+	},
 	TriggerIndexedDbWriteDocument {
 		document: String,
 		details: FrontendDocumentDetails,
 	},
+	TriggerIndexedDbWriteRecentFile {
+		key: u64,
+		content: String,
+	},
 	TriggerLoadFirstAutoSaveDocument,
 	TriggerLoadRestAutoSaveDocuments,
 	TriggerLoadPreferences,
+	TriggerLoadSession,
 	TriggerOpenDocument,
+	TriggerOpenRecentFile {
+		name: String,
+		key: u64,
+	},
 	TriggerPaste,
+	TriggerRasterizeForPrint {
+		svg: String,
+		name: String,
+		size: (f64, f64),
+		#[serde(rename = "printJob")]
+		print_job: PrintJob,
+	},
 	TriggerSavePreferences {
 		preferences: PreferencesMessageHandler,
 	},
+	TriggerSaveSession {
+		session: String,
+	},
 	TriggerSaveActiveDocument {
 		#[serde(rename = "documentId")]
 		document_id: DocumentId,
@@ -145,9 +175,113 @@ pub enum FrontendMessage {
 		#[serde(rename = "clickTargets")]
 		click_targets: Option<FrontendClickTargets>,
 	},
+	/// The nodes imported into the active document, which the node catalog lists after the built-in nodes.
+	UpdateCustomNodeTypes {
+		#[serde(rename = "nodeTypes")]
+		node_types: Vec<FrontendNodeType>,
+	},
+	UpdateAlignPanelState {
+		open: bool,
+	},
+	UpdateAlignPanelLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
 	UpdateGraphViewOverlay {
 		open: bool,
 	},
+	UpdateNavigatorPanelState {
+		open: bool,
+	},
+	UpdateNavigatorPanelLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
+	UpdateNavigatorPanelOverview {
+		/// The layers whose thumbnails together show the whole document, ordered from the bottom of the stack to the top.
+		layers: Vec<NodeId>,
+		/// The bounds of all the artwork in document space.
+		bounds: Option<[(f64, f64); 2]>,
+		/// The corners of the viewport in document space, which form a rotated rectangle when the canvas is tilted.
+		viewport: Vec<(f64, f64)>,
+	},
+	UpdateBulkEditPanelState {
+		open: bool,
+	},
+	UpdateBulkEditPanelLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
+	UpdateCommentsPanelState {
+		open: bool,
+	},
+	UpdateCommentsPanelLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
+	UpdateNodeAssetsPanelState {
+		open: bool,
+	},
+	UpdateNodeAssetsPanelLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
+	UpdateSwatchesPanelState {
+		open: bool,
+	},
+	/// The swatches of the active document and the colors recently chosen in any color picker, which color pickers offer for reuse.
+	UpdateColorPickerSwatches {
+		swatches: Vec<Swatch>,
+		#[serde(rename = "recentColors")]
+		recent_colors: Vec<Color>,
+	},
+	/// The printing condition of the active document when it's in CMYK mode, for which color pickers show the ink coverages of their color.
+	UpdateColorPickerPrintProfile {
+		#[serde(rename = "printProfile")]
+		print_profile: Option<PrintProfile>,
+	},
+	UpdateSwatchesPanelLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
+	UpdateTimelinePanelState {
+		open: bool,
+	},
+	UpdateTimelinePanelLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
+	UpdateDiagnosticsPanelState {
+		open: bool,
+	},
+	UpdateDiagnosticsPanelLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
+	UpdateGraphFindPanelState {
+		open: bool,
+	},
+	UpdateGraphFindPanelLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
+	UpdateHistogramState {
+		open: bool,
+	},
+	UpdateHistogramLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
 	UpdateSpreadsheetState {
 		open: bool,
 		node: Option<NodeId>,
@@ -188,9 +322,24 @@ pub enum FrontendMessage {
 		layout_target: LayoutTarget,
 		diff: Vec<WidgetDiff>,
 	},
+	UpdateCompareSnapshotArtwork {
+		svg: String,
+	},
+	UpdateCompareSnapshotState {
+		visible: bool,
+		transform: String,
+		#[serde(rename = "swipePosition")]
+		swipe_position: Option<f64>,
+	},
 	UpdateDocumentArtwork {
 		svg: String,
 	},
+	UpdateDocumentArtworkPixelPreview {
+		svg: String,
+		width: u32,
+		height: u32,
+		transform: String,
+	},
 	UpdateDocumentBarLayout {
 		#[serde(rename = "layoutTarget")]
 		layout_target: LayoutTarget,
@@ -256,14 +405,19 @@ pub enum FrontendMessage {
 	UpdateNodeGraph {
 		nodes: Vec<FrontendNode>,
 		wires: Vec<FrontendNodeWire>,
-		#[serde(rename = "wiresDirectNotGridAligned")]
-		wires_direct_not_grid_aligned: bool,
+		frames: Vec<FrontendGraphFrame>,
+		notes: Vec<FrontendGraphNote>,
+		#[serde(rename = "wireStyle")]
+		wire_style: GraphWireStyle,
 	},
 	UpdateNodeGraphControlBarLayout {
 		#[serde(rename = "layoutTarget")]
 		layout_target: LayoutTarget,
 		diff: Vec<WidgetDiff>,
 	},
+	UpdateNodeGraphProfile {
+		profile: Option<Vec<FrontendNodeProfile>>,
+	},
 	UpdateNodeGraphSelection {
 		selected: Vec<NodeId>,
 	},
@@ -278,11 +432,33 @@ pub enum FrontendMessage {
 		#[serde(rename = "openDocuments")]
 		open_documents: Vec<FrontendDocumentDetails>,
 	},
+	UpdatePinnedWireProbes {
+		#[serde(rename = "pinnedWireProbes")]
+		pinned_wire_probes: Vec<FrontendPinnedWireProbe>,
+	},
+	UpdatePresentationState {
+		active: bool,
+		/// The viewport area of the slide being presented, outside of which the viewport is blacked out.
+		frame: Option<[(f64, f64); 2]>,
+	},
+	UpdateProfilerPanelState {
+		open: bool,
+	},
+	UpdateProfilerPanelLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
 	UpdatePropertyPanelSectionsLayout {
 		#[serde(rename = "layoutTarget")]
 		layout_target: LayoutTarget,
 		diff: Vec<WidgetDiff>,
 	},
+	UpdateStatusBarLayout {
+		#[serde(rename = "layoutTarget")]
+		layout_target: LayoutTarget,
+		diff: Vec<WidgetDiff>,
+	},
 	UpdateToolOptionsLayout {
 		#[serde(rename = "layoutTarget")]
 		layout_target: LayoutTarget,
@@ -297,6 +473,10 @@ pub enum FrontendMessage {
 		#[serde(rename = "wirePath")]
 		wire_path: Option<WirePath>,
 	},
+	UpdateWireProbe {
+		#[serde(rename = "wireProbe")]
+		wire_probe: Option<FrontendWireProbe>,
+	},
 	UpdateWorkingColorsLayout {
 		#[serde(rename = "layoutTarget")]
 		layout_target: LayoutTarget,
@@ -35,14 +35,21 @@ pub enum FileType {
 	Png,
 	Jpg,
 	Svg,
+	Pdf,
 }
 
 impl FileType {
+	/// Whether the file keeps transparent areas, rather than filling them in with white.
+	pub fn supports_transparency(self) -> bool {
+		!matches!(self, FileType::Jpg | FileType::Pdf)
+	}
+
 	pub fn to_mime(self) -> &'static str {
 		match self {
 			FileType::Png => "image/png",
 			FileType::Jpg => "image/jpeg",
 			FileType::Svg => "image/svg+xml",
+			FileType::Pdf => "application/pdf",
 		}
 	}
 }
@@ -53,4 +60,6 @@ pub enum ExportBounds {
 	AllArtwork,
 	Selection,
 	Artboard(LayerNodeIdentifier),
+	/// The bounds of a layer which has been marked as an export slice.
+	Slice(LayerNodeIdentifier),
 }
@@ -73,9 +73,14 @@ pub fn input_mappings() -> Mapping {
 		entry!(KeyDown(KeyD); modifiers=[Accel], action_dispatch=NodeGraphMessage::DuplicateSelectedNodes),
 		entry!(KeyDown(KeyH); modifiers=[Accel], action_dispatch=NodeGraphMessage::ToggleSelectedVisibility),
 		entry!(KeyDown(KeyL); modifiers=[Accel], action_dispatch=NodeGraphMessage::ToggleSelectedLocked),
+		entry!(KeyDown(KeyB); modifiers=[Accel], action_dispatch=NodeGraphMessage::ToggleSelectedBypass),
 		entry!(KeyDown(KeyL); modifiers=[Alt], action_dispatch=NodeGraphMessage::ToggleSelectedAsLayersOrNodes),
 		entry!(KeyDown(KeyC); modifiers=[Alt], action_dispatch=NodeGraphMessage::SendClickTargets),
 		entry!(KeyDown(KeyM); modifiers=[Accel], action_dispatch=NodeGraphMessage::MergeSelectedNodes),
+		entry!(KeyDown(KeyM); modifiers=[Accel, Shift], action_dispatch=NodeGraphMessage::ExpandSelectedNodes),
+		entry!(KeyDown(KeyF); modifiers=[Shift], action_dispatch=NodeGraphMessage::AddFrame),
+		entry!(KeyDown(KeyN); modifiers=[Shift], action_dispatch=NodeGraphMessage::AddNote),
+		entry!(KeyDownNoRepeat(Tab); action_dispatch=NodeGraphMessage::OpenNodeSearch),
 		entry!(KeyUp(KeyC); action_dispatch=NodeGraphMessage::EndSendClickTargets),
 		entry!(KeyDown(ArrowUp); action_dispatch=NodeGraphMessage::ShiftSelectedNodes { direction: Direction::Up, rubber_band: false }),
 		entry!(KeyDown(ArrowRight); action_dispatch=NodeGraphMessage::ShiftSelectedNodes { direction: Direction::Right, rubber_band: false }),
@@ -144,6 +149,9 @@ pub fn input_mappings() -> Mapping {
 		entry!(KeyUp(MouseLeft); action_dispatch=NavigateToolMessage::PointerUp { zoom_in: true }),
 		entry!(KeyUp(MouseLeft); modifiers=[Shift], action_dispatch=NavigateToolMessage::PointerUp { zoom_in: false }),
 		//
+		// CommentToolMessage
+		entry!(KeyDown(MouseLeft); action_dispatch=CommentToolMessage::PlacePin),
+		//
 		// EyedropperToolMessage
 		entry!(KeyDown(MouseLeft); action_dispatch=EyedropperToolMessage::SamplePrimaryColorBegin),
 		entry!(KeyDown(MouseLeft); modifiers=[Shift], action_dispatch=EyedropperToolMessage::SampleSecondaryColorBegin),
@@ -290,7 +298,7 @@ pub fn input_mappings() -> Mapping {
 		//
 		// BrushToolMessage
 		entry!(PointerMove; action_dispatch=BrushToolMessage::PointerMove),
-		entry!(KeyDown(MouseLeft); action_dispatch=BrushToolMessage::DragStart),
+		entry!(KeyDown(MouseLeft); action_dispatch=BrushToolMessage::DragStart { set_clone_source: Alt }),
 		entry!(KeyUp(MouseLeft); action_dispatch=BrushToolMessage::DragStop),
 		entry!(KeyDown(BracketLeft); action_dispatch=BrushToolMessage::UpdateOptions(BrushToolMessageOptionsUpdate::ChangeDiameter(-BRUSH_SIZE_CHANGE_KEYBOARD))),
 		entry!(KeyDown(BracketRight); action_dispatch=BrushToolMessage::UpdateOptions(BrushToolMessageOptionsUpdate::ChangeDiameter(BRUSH_SIZE_CHANGE_KEYBOARD))),
@@ -342,6 +350,22 @@ pub fn input_mappings() -> Mapping {
 		entry!(KeyDown(BracketRight); modifiers=[Alt], action_dispatch=DocumentMessage::SelectionStepForward),
 		entry!(KeyDown(MouseBack); action_dispatch=DocumentMessage::SelectionStepBack),
 		entry!(KeyDown(MouseForward); action_dispatch=DocumentMessage::SelectionStepForward),
+		entry!(KeyDown(KeyP); modifiers=[Alt], action_dispatch=DocumentMessage::StartPresentation),
+		entry!(KeyDown(ArrowRight); action_dispatch=DocumentMessage::PresentNextSlide),
+		entry!(KeyDown(ArrowDown); action_dispatch=DocumentMessage::PresentNextSlide),
+		entry!(KeyDown(PageDown); action_dispatch=DocumentMessage::PresentNextSlide),
+		entry!(KeyDown(Space); action_dispatch=DocumentMessage::PresentNextSlide),
+		entry!(KeyDown(MouseLeft); action_dispatch=DocumentMessage::PresentNextSlide),
+		entry!(KeyDown(ArrowLeft); action_dispatch=DocumentMessage::PresentPreviousSlide),
+		entry!(KeyDown(ArrowUp); action_dispatch=DocumentMessage::PresentPreviousSlide),
+		entry!(KeyDown(PageUp); action_dispatch=DocumentMessage::PresentPreviousSlide),
+		entry!(KeyDown(Backspace); action_dispatch=DocumentMessage::PresentPreviousSlide),
+		entry!(KeyDown(Escape); action_dispatch=DocumentMessage::StopPresentation),
+		entry!(KeyDown(MouseLeft); action_dispatch=DocumentMessage::PickPositionPointerDown),
+		entry!(PointerMove; action_dispatch=DocumentMessage::PickPositionPointerMove),
+		entry!(KeyUp(MouseLeft); action_dispatch=DocumentMessage::PickPositionPointerUp),
+		entry!(KeyDown(Escape); action_dispatch=DocumentMessage::StopPickingPosition),
+		entry!(KeyDown(Enter); action_dispatch=DocumentMessage::StopPickingPosition),
 		entry!(KeyDown(Digit0); modifiers=[Accel], action_dispatch=DocumentMessage::ZoomCanvasToFitAll),
 		entry!(KeyDown(Digit1); modifiers=[Accel], action_dispatch=DocumentMessage::ZoomCanvasTo100Percent),
 		entry!(KeyDown(Digit2); modifiers=[Accel], action_dispatch=DocumentMessage::ZoomCanvasTo200Percent),
@@ -396,9 +420,14 @@ pub fn input_mappings() -> Mapping {
 		entry!(KeyDown(MouseLeft); modifiers=[Control, Space], action_dispatch=NavigationMessage::BeginCanvasZoom),
 		entry!(KeyDown(MouseMiddle); action_dispatch=NavigationMessage::BeginCanvasPan),
 		entry!(KeyDown(MouseLeft); modifiers=[Space], action_dispatch=NavigationMessage::BeginCanvasPan),
+		entry!(KeyDown(Digit6); action_dispatch=NavigationMessage::CanvasTiltClockwise),
+		entry!(KeyDown(Digit4); action_dispatch=NavigationMessage::CanvasTiltCounterclockwise),
+		entry!(KeyDown(Digit5); action_dispatch=NavigationMessage::CanvasTiltSet { angle_radians: 0. }),
+		entry!(KeyDown(KeyH); modifiers=[Shift], action_dispatch=NavigationMessage::CanvasFlip),
 		entry!(KeyDown(NumpadAdd); modifiers=[Accel], action_dispatch=NavigationMessage::CanvasZoomIncrease { center_on_mouse: false }),
 		entry!(KeyDown(Equal); modifiers=[Accel], action_dispatch=NavigationMessage::CanvasZoomIncrease { center_on_mouse: false }),
 		entry!(KeyDown(Minus); modifiers=[Accel], action_dispatch=NavigationMessage::CanvasZoomDecrease { center_on_mouse: false }),
+		entry!(WheelScroll; modifiers=[Alt], action_dispatch=NavigationMessage::CanvasTiltMouseWheel),
 		entry!(WheelScroll; modifiers=[Control], action_dispatch=NavigationMessage::CanvasZoomMouseWheel),
 		entry!(WheelScroll; modifiers=[Shift], action_dispatch=NavigationMessage::CanvasPanMouseWheel { use_y_as_x: true }),
 		entry!(WheelScroll; action_dispatch=NavigationMessage::CanvasPanMouseWheel { use_y_as_x: false }),
@@ -424,6 +453,7 @@ pub fn input_mappings() -> Mapping {
 		//
 		// DialogMessage
 		entry!(KeyDown(KeyE); modifiers=[Accel], action_dispatch=DialogMessage::RequestExportDialog),
+		entry!(KeyDown(KeyF); modifiers=[Accel], action_dispatch=DialogMessage::RequestFindReplaceDialog),
 		entry!(KeyDown(KeyN); modifiers=[Accel], action_dispatch=DialogMessage::RequestNewDocumentDialog),
 		entry!(KeyDown(Comma); modifiers=[Accel], action_dispatch=DialogMessage::RequestPreferencesDialog),
 		//
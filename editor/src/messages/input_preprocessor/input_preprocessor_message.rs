@@ -11,6 +11,7 @@ pub enum InputPreprocessorMessage {
 	KeyUp { key: Key, key_repeat: bool, modifier_keys: ModifierKeys },
 	PointerDown { editor_mouse_state: EditorMouseState, modifier_keys: ModifierKeys },
 	PointerMove { editor_mouse_state: EditorMouseState, modifier_keys: ModifierKeys },
+	PointerPressure { pressure: Option<f64> },
 	PointerUp { editor_mouse_state: EditorMouseState, modifier_keys: ModifierKeys },
 	CurrentTime { timestamp: u64 },
 	WheelScroll { editor_mouse_state: EditorMouseState, modifier_keys: ModifierKeys },
@@ -4,10 +4,12 @@ use crate::messages::input_mapper::utility_types::misc::FrameTimeInfo;
 use crate::messages::portfolio::utility_types::KeyboardPlatformLayout;
 use crate::messages::prelude::*;
 use glam::DVec2;
+use graphene_core::raster::curve::Curve;
 use std::time::Duration;
 
-pub struct InputPreprocessorMessageData {
+pub struct InputPreprocessorMessageData<'a> {
 	pub keyboard_platform: KeyboardPlatformLayout,
+	pub pressure_curve: &'a Curve,
 }
 
 #[derive(Debug, Default)]
@@ -16,12 +18,14 @@ pub struct InputPreprocessorMessageHandler {
 	pub time: u64,
 	pub keyboard: KeyStates,
 	pub mouse: MouseState,
+	/// The pressure of the stylus from 0 to 1 after being remapped by the pressure curve preference, or `None` for pointing devices without pressure sensitivity such as a mouse.
+	pub pressure: Option<f64>,
 	pub viewport_bounds: ViewportBounds,
 }
 
-impl MessageHandler<InputPreprocessorMessage, InputPreprocessorMessageData> for InputPreprocessorMessageHandler {
+impl MessageHandler<InputPreprocessorMessage, InputPreprocessorMessageData<'_>> for InputPreprocessorMessageHandler {
 	fn process_message(&mut self, message: InputPreprocessorMessage, responses: &mut VecDeque<Message>, data: InputPreprocessorMessageData) {
-		let InputPreprocessorMessageData { keyboard_platform } = data;
+		let InputPreprocessorMessageData { keyboard_platform, pressure_curve } = data;
 
 		match message {
 			InputPreprocessorMessage::BoundsOfViewports { bounds_of_viewports } => {
@@ -33,6 +37,7 @@ impl MessageHandler<InputPreprocessorMessage, InputPreprocessorMessageData> for
 
 					responses.add(NavigationMessage::CanvasPan { delta: DVec2::ZERO });
 					responses.add(NodeGraphMessage::SetGridAlignedEdges);
+					responses.add(DocumentMessage::FitPresentationSlide);
 				}
 			}
 			InputPreprocessorMessage::DoubleClick { editor_mouse_state, modifier_keys } => {
@@ -87,6 +92,9 @@ impl MessageHandler<InputPreprocessorMessage, InputPreprocessorMessageData> for
 				// While any pointer button is already down, additional button down events are not reported, but they are sent as `pointermove` events
 				self.translate_mouse_event(mouse_state, false, responses);
 			}
+			InputPreprocessorMessage::PointerPressure { pressure } => {
+				self.pressure = pressure.map(|pressure| pressure_curve.evaluate(pressure));
+			}
 			InputPreprocessorMessage::PointerUp { editor_mouse_state, modifier_keys } => {
 				self.update_states_of_modifier_keys(modifier_keys, keyboard_platform, responses);
 
@@ -197,6 +205,7 @@ mod test {
 	use crate::messages::input_mapper::utility_types::input_mouse::{EditorMouseState, MouseKeys, ScrollDelta};
 	use crate::messages::portfolio::utility_types::KeyboardPlatformLayout;
 	use crate::messages::prelude::*;
+	use graphene_core::raster::curve::Curve;
 
 	#[test]
 	fn process_action_mouse_move_handle_modifier_keys() {
@@ -214,6 +223,7 @@ mod test {
 
 		let data = InputPreprocessorMessageData {
 			keyboard_platform: KeyboardPlatformLayout::Standard,
+			pressure_curve: &Curve::default(),
 		};
 		input_preprocessor.process_message(message, &mut responses, data);
 
@@ -233,6 +243,7 @@ mod test {
 
 		let data = InputPreprocessorMessageData {
 			keyboard_platform: KeyboardPlatformLayout::Standard,
+			pressure_curve: &Curve::default(),
 		};
 		input_preprocessor.process_message(message, &mut responses, data);
 
@@ -252,6 +263,7 @@ mod test {
 
 		let data = InputPreprocessorMessageData {
 			keyboard_platform: KeyboardPlatformLayout::Standard,
+			pressure_curve: &Curve::default(),
 		};
 		input_preprocessor.process_message(message, &mut responses, data);
 
@@ -273,6 +285,7 @@ mod test {
 
 		let data = InputPreprocessorMessageData {
 			keyboard_platform: KeyboardPlatformLayout::Standard,
+			pressure_curve: &Curve::default(),
 		};
 		input_preprocessor.process_message(message, &mut responses, data);
 
@@ -293,6 +306,7 @@ mod test {
 
 		let data = InputPreprocessorMessageData {
 			keyboard_platform: KeyboardPlatformLayout::Standard,
+			pressure_curve: &Curve::default(),
 		};
 		input_preprocessor.process_message(message, &mut responses, data);
 
@@ -3,7 +3,7 @@ use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::prelude::*;
 use graphene_core::raster::color::Color;
 use graphene_core::text::Font;
-use graphene_std::vector::style::{FillChoice, GradientStops};
+use graphene_std::vector::style::{FillChoice, GradientSegment, GradientStops};
 use serde_json::Value;
 
 #[derive(Debug, Clone, Default)]
@@ -139,18 +139,26 @@ impl LayoutMessageHandler {
 							// Gradient
 							let gradient = update_value.get("stops").and_then(|x| x.as_array());
 							if let Some(stops) = gradient {
-								let gradient_stops = stops
+								let (gradient_stops, segments) = stops
 									.iter()
 									.filter_map(|stop| {
 										stop.as_object().and_then(|stop| {
 											let position = stop.get("position").and_then(|x| x.as_f64());
 											let color = stop.get("color").and_then(|x| x.as_object()).and_then(decode_color);
-											if let (Some(position), Some(color)) = (position, color) { Some((position, color)) } else { None }
+											let segment = GradientSegment {
+												interpolation: stop.get("interpolation").and_then(|x| serde_json::from_value(x.clone()).ok()).unwrap_or_default(),
+												midpoint: stop.get("midpoint").and_then(|x| x.as_f64()).unwrap_or(0.5),
+											};
+											if let (Some(position), Some(color)) = (position, color) {
+												Some(((position, color), segment))
+											} else {
+												None
+											}
 										})
 									})
-									.collect::<Vec<_>>();
+									.unzip();
 
-								color_button.value = FillChoice::Gradient(GradientStops::new(gradient_stops));
+								color_button.value = FillChoice::Gradient(GradientStops::new_with_segments(gradient_stops, segments));
 								return (color_button.on_update.callback)(color_button);
 							}
 
@@ -188,6 +196,18 @@ impl LayoutMessageHandler {
 
 				responses.add(callback_message);
 			}
+			Widget::FilePathInput(file_path_input) => {
+				let callback_message = match action {
+					WidgetValueAction::Commit => (file_path_input.on_commit.callback)(&()),
+					WidgetValueAction::Update => {
+						let update_value = value.as_str().expect("FilePathInput update was not of type: string");
+						file_path_input.value = update_value.into();
+						(file_path_input.on_update.callback)(file_path_input)
+					}
+				};
+
+				responses.add(callback_message);
+			}
 			Widget::FontInput(font_input) => {
 				let callback_message = match action {
 					WidgetValueAction::Commit => (font_input.on_commit.callback)(&()),
@@ -211,6 +231,7 @@ impl LayoutMessageHandler {
 
 				responses.add(callback_message);
 			}
+			Widget::DashPatternLabel(_) | Widget::HistogramLabel(_) => {}
 			Widget::IconButton(icon_button) => {
 				let callback_message = match action {
 					WidgetValueAction::Commit => (icon_button.on_commit.callback)(&()),
@@ -265,6 +286,15 @@ impl LayoutMessageHandler {
 						"Decrement" => responses.add((number_input.increment_callback_decrease.callback)(number_input)),
 						_ => panic!("Invalid string found when updating `NumberInput`"),
 					},
+					// A value previewed while dragging the slider, which isn't kept as the widget's value
+					Value::Object(object) => {
+						let Some(preview_value) = object.get("preview").and_then(Value::as_f64) else { return };
+						let preview = NumberInput {
+							value: Some(preview_value),
+							..number_input.clone()
+						};
+						responses.add((number_input.on_preview.callback)(&preview));
+					}
 					_ => {}
 				},
 			},
@@ -299,6 +329,18 @@ impl LayoutMessageHandler {
 
 				responses.add(callback_message);
 			}
+			Widget::RangeSliderInput(range_slider_input) => {
+				let callback_message = match action {
+					WidgetValueAction::Commit => (range_slider_input.on_commit.callback)(&()),
+					WidgetValueAction::Update => {
+						let range = serde_json::from_value(value).expect("RangeSliderInput event data could not be deserialized");
+						range_slider_input.value = range;
+						(range_slider_input.on_update.callback)(range_slider_input)
+					}
+				};
+
+				responses.add(callback_message);
+			}
 			Widget::Separator(_) => {}
 			Widget::TextAreaInput(text_area_input) => {
 				let callback_message = match action {
@@ -332,7 +374,26 @@ impl LayoutMessageHandler {
 
 				responses.add(callback_message);
 			}
-			Widget::TextLabel(_) => {}
+			Widget::TextLabel(text_label) => {
+				let entry_index = value.as_u64().unwrap_or_else(|| panic!("TextLabel context menu entry was not of type `u64`, found {value:?}"));
+				let Some(entry) = text_label.context_menu.iter().flatten().nth(entry_index as usize) else {
+					return;
+				};
+				let callback_message = match action {
+					WidgetValueAction::Commit => (entry.on_commit.callback)(&()),
+					WidgetValueAction::Update => (entry.on_update.callback)(&()),
+				};
+
+				responses.add(callback_message);
+			}
+			Widget::ThumbnailButton(thumbnail_button) => {
+				let callback_message = match action {
+					WidgetValueAction::Commit => (thumbnail_button.on_commit.callback)(&()),
+					WidgetValueAction::Update => (thumbnail_button.on_update.callback)(&()),
+				};
+
+				responses.add(callback_message);
+			}
 			Widget::WorkingColorsInput(_) => {}
 		};
 	}
@@ -419,16 +480,28 @@ impl LayoutMessageHandler {
 		diff.iter_mut().for_each(|diff| diff.new_value.apply_keyboard_shortcut(action_input_mapping));
 
 		let message = match layout_target {
+			LayoutTarget::AlignPanel => FrontendMessage::UpdateAlignPanelLayout { layout_target, diff },
+			LayoutTarget::BulkEditPanel => FrontendMessage::UpdateBulkEditPanelLayout { layout_target, diff },
+			LayoutTarget::CommentsPanel => FrontendMessage::UpdateCommentsPanelLayout { layout_target, diff },
+			LayoutTarget::DiagnosticsPanel => FrontendMessage::UpdateDiagnosticsPanelLayout { layout_target, diff },
 			LayoutTarget::DialogButtons => FrontendMessage::UpdateDialogButtons { layout_target, diff },
 			LayoutTarget::DialogColumn1 => FrontendMessage::UpdateDialogColumn1 { layout_target, diff },
 			LayoutTarget::DialogColumn2 => FrontendMessage::UpdateDialogColumn2 { layout_target, diff },
 			LayoutTarget::DocumentBar => FrontendMessage::UpdateDocumentBarLayout { layout_target, diff },
 			LayoutTarget::DocumentMode => FrontendMessage::UpdateDocumentModeLayout { layout_target, diff },
+			LayoutTarget::GraphFindPanel => FrontendMessage::UpdateGraphFindPanelLayout { layout_target, diff },
+			LayoutTarget::Histogram => FrontendMessage::UpdateHistogramLayout { layout_target, diff },
 			LayoutTarget::LayersPanelControlBar => FrontendMessage::UpdateLayersPanelControlBarLayout { layout_target, diff },
 			LayoutTarget::MenuBar => unreachable!("Menu bar is not diffed"),
+			LayoutTarget::NavigatorPanel => FrontendMessage::UpdateNavigatorPanelLayout { layout_target, diff },
 			LayoutTarget::NodeGraphControlBar => FrontendMessage::UpdateNodeGraphControlBarLayout { layout_target, diff },
+			LayoutTarget::ProfilerPanel => FrontendMessage::UpdateProfilerPanelLayout { layout_target, diff },
 			LayoutTarget::PropertiesSections => FrontendMessage::UpdatePropertyPanelSectionsLayout { layout_target, diff },
 			LayoutTarget::Spreadsheet => FrontendMessage::UpdateSpreadsheetLayout { layout_target, diff },
+			LayoutTarget::StatusBar => FrontendMessage::UpdateStatusBarLayout { layout_target, diff },
+			LayoutTarget::SwatchesPanel => FrontendMessage::UpdateSwatchesPanelLayout { layout_target, diff },
+			LayoutTarget::NodeAssetsPanel => FrontendMessage::UpdateNodeAssetsPanelLayout { layout_target, diff },
+			LayoutTarget::TimelinePanel => FrontendMessage::UpdateTimelinePanelLayout { layout_target, diff },
 			LayoutTarget::ToolOptions => FrontendMessage::UpdateToolOptionsLayout { layout_target, diff },
 			LayoutTarget::ToolShelf => FrontendMessage::UpdateToolShelfLayout { layout_target, diff },
 			LayoutTarget::WorkingColors => FrontendMessage::UpdateWorkingColorsLayout { layout_target, diff },
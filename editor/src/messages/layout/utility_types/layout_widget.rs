@@ -21,6 +21,14 @@ impl core::fmt::Display for WidgetId {
 #[derive(PartialEq, Clone, Debug, Hash, Eq, Copy, serde::Serialize, serde::Deserialize, specta::Type)]
 #[repr(u8)]
 pub enum LayoutTarget {
+	/// The Align panel contains the buttons which line up and distribute the selected layers.
+	AlignPanel,
+	/// The Bulk Edit panel lists every node of a chosen type in a table for editing them together.
+	BulkEditPanel,
+	/// The Comments panel lists the threads of the comment pins left on the canvas in review mode.
+	CommentsPanel,
+	/// The Diagnostics panel lists the nodes which have consistently taken longer to evaluate than the document's performance budget.
+	DiagnosticsPanel,
 	/// Contains the action buttons at the bottom of the dialog. Must be shown with the `FrontendMessage::DisplayDialog` message.
 	DialogButtons,
 	/// Contains the contents of the dialog's primary column. Must be shown with the `FrontendMessage::DisplayDialog` message.
@@ -31,16 +39,32 @@ pub enum LayoutTarget {
 	DocumentBar,
 	/// Contains the dropdown for design / select / guide mode found on the top left of the canvas.
 	DocumentMode,
+	/// The Find in Graph panel lists the nodes whose names, types, or text inputs contain the searched text.
+	GraphFindPanel,
+	/// The histogram panel shows the tonal distribution of the raster content in the graph.
+	Histogram,
 	/// Options for opacity seen at the top of the Layers panel.
 	LayersPanelControlBar,
 	/// The dropdown menu at the very top of the application: File, Edit, etc.
 	MenuBar,
+	/// The Navigator panel contains the zoom controls shown below the overview of the whole document.
+	NavigatorPanel,
+	/// The Node Assets panel lists the nodes saved for reuse in any document.
+	NodeAssetsPanel,
 	/// Bar at the top of the node graph containing the location and the "Preview" and "Hide" buttons.
 	NodeGraphControlBar,
 	/// The body of the Properties panel containing many collapsable sections.
+	/// The Profiler panel lists the nodes of the viewed network with the time each took to evaluate in the most recent render.
+	ProfilerPanel,
 	PropertiesSections,
 	/// The spredsheet panel allows for the visualisation of data in the graph.
 	Spreadsheet,
+	/// The warning about slow nodes shown at the right of the status bar along the bottom of the window.
+	StatusBar,
+	/// The Swatches panel lists the named colors saved with the document.
+	SwatchesPanel,
+	/// The Timeline panel contains the playback controls and a row of keyframes for every animated node input.
+	TimelinePanel,
 	/// The bar directly above the canvas, left-aligned and to the right of the document mode dropdown.
 	ToolOptions,
 	/// The vertical buttons for all of the tools on the left of the canvas.
@@ -329,8 +353,13 @@ pub enum LayoutGroup {
 		name: String,
 		description: String,
 		visible: bool,
+		/// Whether the node with this ID is bypassed, which dims the header of the section.
+		bypassed: bool,
 		pinned: bool,
 		id: u64,
+		/// Whether the section shows the properties of the node with this ID, so its header has buttons to pin, delete, and hide the node.
+		#[serde(rename = "nodeControls")]
+		node_controls: bool,
 		layout: SubLayout,
 	},
 }
@@ -360,18 +389,23 @@ impl LayoutGroup {
 				Widget::CheckboxInput(x) => &mut x.tooltip,
 				Widget::ColorInput(x) => &mut x.tooltip,
 				Widget::CurveInput(x) => &mut x.tooltip,
+				Widget::DashPatternLabel(x) => &mut x.tooltip,
 				Widget::DropdownInput(x) => &mut x.tooltip,
+				Widget::FilePathInput(x) => &mut x.tooltip,
 				Widget::FontInput(x) => &mut x.tooltip,
+				Widget::HistogramLabel(x) => &mut x.tooltip,
 				Widget::IconButton(x) => &mut x.tooltip,
 				Widget::IconLabel(x) => &mut x.tooltip,
 				Widget::ImageButton(x) => &mut x.tooltip,
 				Widget::NumberInput(x) => &mut x.tooltip,
 				Widget::ParameterExposeButton(x) => &mut x.tooltip,
 				Widget::PopoverButton(x) => &mut x.tooltip,
+				Widget::RangeSliderInput(x) => &mut x.tooltip,
 				Widget::TextAreaInput(x) => &mut x.tooltip,
 				Widget::TextButton(x) => &mut x.tooltip,
 				Widget::TextInput(x) => &mut x.tooltip,
 				Widget::TextLabel(x) => &mut x.tooltip,
+				Widget::ThumbnailButton(x) => &mut x.tooltip,
 				Widget::BreadcrumbTrailButtons(x) => &mut x.tooltip,
 				Widget::InvisibleStandinInput(_) | Widget::PivotInput(_) | Widget::RadioInput(_) | Widget::Separator(_) | Widget::WorkingColorsInput(_) | Widget::NodeCatalog(_) => continue,
 			};
@@ -411,16 +445,20 @@ impl LayoutGroup {
 					name: current_name,
 					description: current_description,
 					visible: current_visible,
+					bypassed: current_bypassed,
 					pinned: current_pinned,
 					id: current_id,
+					node_controls: current_node_controls,
 					layout: current_layout,
 				},
 				Self::Section {
 					name: new_name,
 					description: new_description,
 					visible: new_visible,
+					bypassed: new_bypassed,
 					pinned: new_pinned,
 					id: new_id,
+					node_controls: new_node_controls,
 					layout: new_layout,
 				},
 			) => {
@@ -430,15 +468,19 @@ impl LayoutGroup {
 					|| *current_name != new_name
 					|| *current_description != new_description
 					|| *current_visible != new_visible
+					|| *current_bypassed != new_bypassed
 					|| *current_pinned != new_pinned
 					|| *current_id != new_id
+					|| *current_node_controls != new_node_controls
 				{
 					// Update self to reflect new changes
 					current_name.clone_from(&new_name);
 					current_description.clone_from(&new_description);
 					*current_visible = new_visible;
+					*current_bypassed = new_bypassed;
 					*current_pinned = new_pinned;
 					*current_id = new_id;
+					*current_node_controls = new_node_controls;
 					current_layout.clone_from(&new_layout);
 
 					// Push an update layout group to the diff
@@ -446,8 +488,10 @@ impl LayoutGroup {
 						name: new_name,
 						description: new_description,
 						visible: new_visible,
+						bypassed: new_bypassed,
 						pinned: new_pinned,
 						id: new_id,
+						node_controls: new_node_controls,
 						layout: new_layout,
 					});
 					let widget_path = widget_path.to_vec();
@@ -537,8 +581,11 @@ pub enum Widget {
 	CheckboxInput(CheckboxInput),
 	ColorInput(ColorInput),
 	CurveInput(CurveInput),
+	DashPatternLabel(DashPatternLabel),
 	DropdownInput(DropdownInput),
+	FilePathInput(FilePathInput),
 	FontInput(FontInput),
+	HistogramLabel(HistogramLabel),
 	IconButton(IconButton),
 	IconLabel(IconLabel),
 	ImageButton(ImageButton),
@@ -549,11 +596,13 @@ pub enum Widget {
 	PivotInput(PivotInput),
 	PopoverButton(PopoverButton),
 	RadioInput(RadioInput),
+	RangeSliderInput(RangeSliderInput),
 	Separator(Separator),
 	TextAreaInput(TextAreaInput),
 	TextButton(TextButton),
 	TextInput(TextInput),
 	TextLabel(TextLabel),
+	ThumbnailButton(ThumbnailButton),
 	WorkingColorsInput(WorkingColorsInput),
 }
 
@@ -617,12 +666,17 @@ impl DiffUpdate {
 				Widget::PopoverButton(widget) => Some((&mut widget.tooltip, &mut widget.tooltip_shortcut)),
 				Widget::TextButton(widget) => Some((&mut widget.tooltip, &mut widget.tooltip_shortcut)),
 				Widget::ImageButton(widget) => Some((&mut widget.tooltip, &mut widget.tooltip_shortcut)),
+				Widget::ThumbnailButton(widget) => Some((&mut widget.tooltip, &mut widget.tooltip_shortcut)),
 				Widget::IconLabel(_)
 				| Widget::CurveInput(_)
+				| Widget::DashPatternLabel(_)
+				| Widget::FilePathInput(_)
+				| Widget::HistogramLabel(_)
 				| Widget::InvisibleStandinInput(_)
 				| Widget::NodeCatalog(_)
 				| Widget::PivotInput(_)
 				| Widget::RadioInput(_)
+				| Widget::RangeSliderInput(_)
 				| Widget::Separator(_)
 				| Widget::TextAreaInput(_)
 				| Widget::TextInput(_)
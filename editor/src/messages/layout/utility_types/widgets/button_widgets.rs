@@ -144,6 +144,30 @@ pub struct ImageButton {
 	pub on_commit: WidgetCallback<()>,
 }
 
+/// A clickable preview of some graphical data, drawn from an SVG rendered by the editor.
+#[derive(Clone, serde::Serialize, serde::Deserialize, Derivative, Default, WidgetBuilder, specta::Type)]
+#[derivative(Debug, PartialEq)]
+pub struct ThumbnailButton {
+	#[widget_builder(constructor)]
+	pub svg: String,
+
+	pub disabled: bool,
+
+	pub tooltip: String,
+
+	#[serde(skip)]
+	pub tooltip_shortcut: Option<ActionKeys>,
+
+	// Callbacks
+	#[serde(skip)]
+	#[derivative(Debug = "ignore", PartialEq = "ignore")]
+	pub on_update: WidgetCallback<()>,
+
+	#[serde(skip)]
+	#[derivative(Debug = "ignore", PartialEq = "ignore")]
+	pub on_commit: WidgetCallback<()>,
+}
+
 #[derive(Clone, Derivative, serde::Serialize, serde::Deserialize, WidgetBuilder, specta::Type)]
 #[derivative(Debug, PartialEq, Default)]
 pub struct ColorInput {
@@ -162,6 +186,13 @@ pub struct ColorInput {
 	#[derivative(Default(value = "true"))]
 	pub allow_none: bool,
 
+	/// Shows an eyedropper beside the color which samples the rendered artwork in the viewport.
+	pub eyedropper: bool,
+
+	/// Shows a button beside the color which samples a background color from the rendered artwork in the viewport and reports the WCAG contrast ratio against it.
+	#[serde(rename = "contrastCheck")]
+	pub contrast_check: bool,
+
 	// TODO: Implement
 	// pub disabled: bool,
 	//
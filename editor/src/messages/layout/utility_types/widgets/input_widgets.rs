@@ -1,5 +1,6 @@
 use crate::messages::input_mapper::utility_types::misc::ActionKeys;
 use crate::messages::layout::utility_types::widget_prelude::*;
+use crate::messages::portfolio::document::utility_types::misc::LengthUnit;
 use derivative::*;
 use glam::DVec2;
 use graphene_core::Color;
@@ -12,6 +13,9 @@ pub struct CheckboxInput {
 	#[widget_builder(constructor)]
 	pub checked: bool,
 
+	/// Shown as neither checked nor unchecked, such as for a setting which differs between several things being edited together.
+	pub indeterminate: bool,
+
 	pub disabled: bool,
 
 	pub icon: String,
@@ -35,6 +39,7 @@ impl Default for CheckboxInput {
 	fn default() -> Self {
 		Self {
 			checked: false,
+			indeterminate: false,
 			disabled: false,
 			icon: "Checkmark".into(),
 			tooltip: Default::default(),
@@ -61,6 +66,9 @@ pub struct DropdownInput {
 	#[derivative(Default(value = "true"))]
 	pub interactive: bool,
 
+	/// Shows a search box at the top of the opened menu for filtering the entries, useful for long lists.
+	pub searchable: bool,
+
 	pub disabled: bool,
 
 	pub tooltip: String,
@@ -188,6 +196,16 @@ pub struct NumberInput {
 	#[derivative(Default(value = "true"))]
 	pub unit_is_hidden_when_editing: bool,
 
+	// The value is always in pixels, but it's shown (and typed, with any unit suffix converted) in this unit
+	#[serde(rename = "lengthUnit")]
+	#[widget_builder(skip)]
+	pub length_unit: Option<LengthUnit>,
+
+	#[serde(rename = "unitScale")]
+	#[widget_builder(skip)]
+	#[derivative(Default(value = "1."))]
+	pub unit_scale: f64,
+
 	// Mode behavior
 	pub mode: NumberInputMode,
 
@@ -204,6 +222,14 @@ pub struct NumberInput {
 	#[serde(rename = "rangeMax")]
 	pub range_max: Option<f64>,
 
+	// Dragging the label scrubs the value, in steps 10x coarser with Shift or 10x finer with Ctrl, and this shows a grip as the label of inputs without one
+	#[serde(rename = "scrubLabel")]
+	pub scrub_label: bool,
+
+	// Dragging the slider sends its values through `on_preview` and only sends the value it's released at through `on_update`, so the drag can be canceled without having changed anything
+	#[serde(rename = "previewWhileDragging")]
+	pub preview_while_dragging: bool,
+
 	// Styling
 	#[serde(rename = "minWidth")]
 	pub min_width: u32,
@@ -221,6 +247,10 @@ pub struct NumberInput {
 	#[derivative(Debug = "ignore", PartialEq = "ignore")]
 	pub on_update: WidgetCallback<NumberInput>,
 
+	#[serde(skip)]
+	#[derivative(Debug = "ignore", PartialEq = "ignore")]
+	pub on_preview: WidgetCallback<NumberInput>,
+
 	#[serde(skip)]
 	#[derivative(Debug = "ignore", PartialEq = "ignore")]
 	pub on_commit: WidgetCallback<()>,
@@ -256,6 +286,11 @@ impl NumberInput {
 	pub fn percentage(self) -> Self {
 		self.min(0.).max(100.).mode_range().unit("%").display_decimal_places(2)
 	}
+	pub fn length_unit(mut self, length_unit: LengthUnit) -> Self {
+		self.length_unit = Some(length_unit);
+		self.unit_scale = length_unit.pixels_per_unit();
+		self.unit(format!(" {}", length_unit.symbol()))
+	}
 }
 
 #[derive(Clone, serde::Serialize, serde::Deserialize, Debug, Default, PartialEq, Eq, specta::Type)]
@@ -273,6 +308,44 @@ pub enum NumberInputMode {
 	Range,
 }
 
+/// A slider with two handles for choosing the lower and upper bounds of a range, such as the black and white points of a tonal adjustment.
+#[derive(Clone, serde::Serialize, serde::Deserialize, Derivative, WidgetBuilder, specta::Type)]
+#[derivative(Debug, PartialEq, Default)]
+pub struct RangeSliderInput {
+	// The lower and upper bounds, in that order
+	#[widget_builder(constructor)]
+	pub value: [f64; 2],
+
+	pub min: f64,
+
+	#[derivative(Default(value = "100."))]
+	pub max: f64,
+
+	pub unit: String,
+
+	#[serde(rename = "displayDecimalPlaces")]
+	pub display_decimal_places: u32,
+
+	pub disabled: bool,
+
+	pub tooltip: String,
+
+	// Callbacks
+	#[serde(skip)]
+	#[derivative(Debug = "ignore", PartialEq = "ignore")]
+	pub on_update: WidgetCallback<RangeSliderInput>,
+
+	#[serde(skip)]
+	#[derivative(Debug = "ignore", PartialEq = "ignore")]
+	pub on_commit: WidgetCallback<()>,
+}
+
+impl RangeSliderInput {
+	pub fn percentage(self) -> Self {
+		self.min(0.).max(100.).unit("%")
+	}
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize, Derivative, WidgetBuilder, specta::Type)]
 #[derivative(Debug, PartialEq, Default)]
 pub struct NodeCatalog {
@@ -389,6 +462,30 @@ pub struct TextInput {
 	pub on_commit: WidgetCallback<()>,
 }
 
+/// A text field for the path or URL of a file, with a button which opens a dialog to browse for the file instead of typing its location.
+#[derive(Clone, serde::Serialize, serde::Deserialize, Derivative, WidgetBuilder, specta::Type)]
+#[derivative(Debug, PartialEq, Default)]
+pub struct FilePathInput {
+	#[widget_builder(constructor)]
+	pub value: String,
+
+	// The file types offered by the browse dialog, in the format of the `accept` attribute of an HTML file input, such as "image/*"
+	pub accept: String,
+
+	pub disabled: bool,
+
+	pub tooltip: String,
+
+	// Callbacks
+	#[serde(skip)]
+	#[derivative(Debug = "ignore", PartialEq = "ignore")]
+	pub on_update: WidgetCallback<FilePathInput>,
+
+	#[serde(skip)]
+	#[derivative(Debug = "ignore", PartialEq = "ignore")]
+	pub on_commit: WidgetCallback<()>,
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize, Derivative, WidgetBuilder, specta::Type)]
 #[derivative(Debug, PartialEq, Default)]
 pub struct CurveInput {
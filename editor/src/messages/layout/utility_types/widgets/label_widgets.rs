@@ -1,6 +1,20 @@
+use super::input_widgets::MenuListEntrySections;
 use derivative::*;
 use graphite_proc_macros::WidgetBuilder;
 
+/// A strip showing a few repetitions of a stroke's dash pattern, made of alternating dash and gap lengths.
+/// A pattern with an odd number of lengths is repeated to make whole pairs, and an empty pattern is drawn as a solid line.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, WidgetBuilder, specta::Type)]
+pub struct DashPatternLabel {
+	pub lengths: Vec<f64>,
+
+	pub offset: f64,
+
+	pub disabled: bool,
+
+	pub tooltip: String,
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize, Derivative, Debug, Default, PartialEq, Eq, WidgetBuilder, specta::Type)]
 pub struct IconLabel {
 	#[widget_builder(constructor)]
@@ -11,6 +25,23 @@ pub struct IconLabel {
 	pub tooltip: String,
 }
 
+/// A bar chart of the tonal distribution of an image, drawn as overlapping translucent channels.
+/// Each channel holds its bin heights normalized to the 0 to 1 range, and an empty channel isn't drawn.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, WidgetBuilder, specta::Type)]
+pub struct HistogramLabel {
+	pub red: Vec<f64>,
+
+	pub green: Vec<f64>,
+
+	pub blue: Vec<f64>,
+
+	pub luminance: Vec<f64>,
+
+	pub disabled: bool,
+
+	pub tooltip: String,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, WidgetBuilder, specta::Type)]
 pub struct Separator {
 	pub direction: SeparatorDirection,
@@ -35,7 +66,7 @@ pub enum SeparatorType {
 	Section,
 }
 
-#[derive(Clone, serde::Serialize, serde::Deserialize, Derivative, Debug, PartialEq, Eq, Default, WidgetBuilder, specta::Type)]
+#[derive(Clone, serde::Serialize, serde::Deserialize, Derivative, Debug, PartialEq, Default, WidgetBuilder, specta::Type)]
 pub struct TextLabel {
 	pub disabled: bool,
 
@@ -56,6 +87,14 @@ pub struct TextLabel {
 
 	pub tooltip: String,
 
+	/// The entries of a menu opened by right clicking the label, if it isn't empty.
+	#[serde(rename = "contextMenu")]
+	pub context_menu: MenuListEntrySections,
+
+	/// Dragging the label scrubs the value of the number input after it in the same row, like dragging the number input's own label.
+	#[serde(rename = "scrubsNumberInput")]
+	pub scrubs_number_input: bool,
+
 	// Body
 	#[widget_builder(constructor)]
 	pub value: String,
@@ -0,0 +1,25 @@
+use crate::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
+use crate::messages::portfolio::document::utility_types::misc::{AlignAggregate, AlignAxis};
+use crate::messages::prelude::*;
+
+/// The Align panel moves the selected layers to line up with the selection, their artboard, or a key layer, and spaces them out evenly.
+#[impl_message(Message, PortfolioMessage, AlignPanel)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum AlignPanelMessage {
+	ToggleOpen,
+
+	UpdateLayout,
+
+	Align { axis: AlignAxis, aggregate: AlignAggregate },
+	Distribute { axis: AlignAxis },
+	SetAlignTo { align_to: AlignTo },
+	SetKeyLayer { layer: LayerNodeIdentifier },
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug, serde::Serialize, serde::Deserialize)]
+pub enum AlignTo {
+	#[default]
+	Selection,
+	Artboard,
+	KeyObject,
+}
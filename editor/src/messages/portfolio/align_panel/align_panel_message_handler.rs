@@ -0,0 +1,173 @@
+use super::AlignTo;
+use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
+use crate::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
+use crate::messages::portfolio::document::utility_types::misc::{AlignAggregate, AlignAxis, AlignReference};
+use crate::messages::prelude::*;
+use crate::messages::tool::tool_messages::tool_prelude::*;
+
+pub struct AlignPanelMessageData<'a> {
+	pub document: Option<&'a DocumentMessageHandler>,
+}
+
+/// The Align panel moves the selected layers to line up with the selection, their artboard, or a key layer, and spaces them out evenly.
+#[derive(Default, Debug, Clone)]
+pub struct AlignPanelMessageHandler {
+	/// Sets whether or not the Align panel is shown.
+	pub align_panel_open: bool,
+	align_to: AlignTo,
+	/// The layer chosen as the key object, which is only used while it remains selected.
+	key_layer: Option<LayerNodeIdentifier>,
+}
+
+impl MessageHandler<AlignPanelMessage, AlignPanelMessageData<'_>> for AlignPanelMessageHandler {
+	fn process_message(&mut self, message: AlignPanelMessage, responses: &mut VecDeque<Message>, data: AlignPanelMessageData) {
+		let AlignPanelMessageData { document } = data;
+
+		match message {
+			AlignPanelMessage::ToggleOpen => {
+				self.align_panel_open = !self.align_panel_open;
+
+				// Keep the list of selected layers up to date while the panel is open
+				let on = BroadcastEvent::SelectionChanged;
+				let send = Box::new(AlignPanelMessage::UpdateLayout.into());
+				if self.align_panel_open {
+					responses.add(BroadcastMessage::SubscribeEvent { on, send });
+				} else {
+					responses.add(BroadcastMessage::UnsubscribeEvent { on, message: send });
+				}
+
+				// Update checked UI state for open
+				responses.add(MenuBarMessage::SendLayout);
+			}
+			AlignPanelMessage::UpdateLayout => {}
+			AlignPanelMessage::Align { axis, aggregate } => {
+				let Some(document) = document else { return };
+				let reference = match self.align_to {
+					AlignTo::Selection => AlignReference::Selection,
+					AlignTo::Artboard => AlignReference::Artboard,
+					AlignTo::KeyObject => {
+						let Some(key_layer) = self.key_layer(document) else { return };
+						AlignReference::KeyLayer(key_layer)
+					}
+				};
+				responses.add(DocumentMessage::AlignSelectedLayers { axis, aggregate, reference });
+			}
+			AlignPanelMessage::Distribute { axis } => responses.add(DocumentMessage::DistributeSelectedLayers { axis }),
+			AlignPanelMessage::SetAlignTo { align_to } => self.align_to = align_to,
+			AlignPanelMessage::SetKeyLayer { layer } => self.key_layer = Some(layer),
+		}
+
+		self.update_layout(document, responses);
+	}
+
+	fn actions(&self) -> ActionList {
+		actions!(AlignPanelMessage;)
+	}
+}
+
+impl AlignPanelMessageHandler {
+	fn selected_layers(document: &DocumentMessageHandler) -> Vec<LayerNodeIdentifier> {
+		document.network_interface.selected_nodes().selected_visible_layers(&document.network_interface).collect()
+	}
+
+	/// The key layer if it's still selected, otherwise the first selected layer.
+	fn key_layer(&self, document: &DocumentMessageHandler) -> Option<LayerNodeIdentifier> {
+		let selected_layers = Self::selected_layers(document);
+		self.key_layer.filter(|layer| selected_layers.contains(layer)).or(selected_layers.first().copied())
+	}
+
+	fn update_layout(&self, document: Option<&DocumentMessageHandler>, responses: &mut VecDeque<Message>) {
+		responses.add(FrontendMessage::UpdateAlignPanelState { open: self.align_panel_open });
+		if !self.align_panel_open {
+			return;
+		}
+
+		let selected_layers = document.map(Self::selected_layers).unwrap_or_default();
+
+		let align_to_entries = [(AlignTo::Selection, "Selection"), (AlignTo::Artboard, "Artboard"), (AlignTo::KeyObject, "Key Object")]
+			.into_iter()
+			.map(|(align_to, label)| {
+				RadioEntryData::new(format!("{align_to:?}"))
+					.label(label)
+					.on_update(move |_| AlignPanelMessage::SetAlignTo { align_to }.into())
+			})
+			.collect();
+		let mut layout = vec![LayoutGroup::Row {
+			widgets: vec![
+				TextLabel::new("Align To").widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				RadioInput::new(align_to_entries).selected_index(Some(self.align_to as u32)).widget_holder(),
+			],
+		}];
+
+		let key_layer = document.and_then(|document| self.key_layer(document));
+		if self.align_to == AlignTo::KeyObject {
+			let entries = selected_layers
+				.iter()
+				.map(|&layer| {
+					let name = document.map(|document| document.network_interface.display_name(&layer.to_node(), &[])).unwrap_or_default();
+					MenuListEntry::new(format!("{layer:?}")).label(name).on_commit(move |_| AlignPanelMessage::SetKeyLayer { layer }.into())
+				})
+				.collect();
+			let selected_index = key_layer.and_then(|key_layer| selected_layers.iter().position(|&layer| layer == key_layer));
+
+			layout.push(LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new("Key Object").widget_holder(),
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					DropdownInput::new(vec![entries])
+						.selected_index(selected_index.map(|index| index as u32))
+						.disabled(selected_layers.is_empty())
+						.tooltip("The selected layer which stays in place while the others are aligned to it")
+						.widget_holder(),
+				],
+			});
+		}
+
+		// Aligning to the selection or a key object needs another layer to line up with, but each layer can be aligned to its own artboard
+		let align_disabled = match self.align_to {
+			AlignTo::Artboard => selected_layers.is_empty(),
+			_ => selected_layers.len() < 2,
+		};
+		let align_buttons = [AlignAxis::X, AlignAxis::Y]
+			.into_iter()
+			.flat_map(|axis| [(axis, AlignAggregate::Min), (axis, AlignAggregate::Center), (axis, AlignAggregate::Max)])
+			.map(|(axis, aggregate)| {
+				let (icon, tooltip) = match (axis, aggregate) {
+					(AlignAxis::X, AlignAggregate::Min) => ("AlignLeft", "Align Left"),
+					(AlignAxis::X, AlignAggregate::Center) => ("AlignHorizontalCenter", "Align Horizontal Center"),
+					(AlignAxis::X, AlignAggregate::Max) => ("AlignRight", "Align Right"),
+					(AlignAxis::Y, AlignAggregate::Min) => ("AlignTop", "Align Top"),
+					(AlignAxis::Y, AlignAggregate::Center) => ("AlignVerticalCenter", "Align Vertical Center"),
+					(AlignAxis::Y, AlignAggregate::Max) => ("AlignBottom", "Align Bottom"),
+				};
+				IconButton::new(icon, 24)
+					.tooltip(tooltip)
+					.on_update(move |_| AlignPanelMessage::Align { axis, aggregate }.into())
+					.disabled(align_disabled)
+					.widget_holder()
+			})
+			.collect();
+		layout.push(LayoutGroup::Row { widgets: align_buttons });
+
+		let distribute_buttons = [
+			(AlignAxis::X, "DistributeHorizontalGap", "Distribute Horizontal Spacing"),
+			(AlignAxis::Y, "DistributeVerticalGap", "Distribute Vertical Spacing"),
+		]
+		.into_iter()
+		.map(|(axis, icon, tooltip)| {
+			IconButton::new(icon, 24)
+				.tooltip(tooltip)
+				.on_update(move |_| AlignPanelMessage::Distribute { axis }.into())
+				.disabled(selected_layers.len() < 3)
+				.widget_holder()
+		})
+		.collect();
+		layout.push(LayoutGroup::Row { widgets: distribute_buttons });
+
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout { layout }),
+			layout_target: LayoutTarget::AlignPanel,
+		});
+	}
+}
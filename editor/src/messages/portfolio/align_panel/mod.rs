@@ -0,0 +1,7 @@
+mod align_panel_message;
+mod align_panel_message_handler;
+
+#[doc(inline)]
+pub use align_panel_message::*;
+#[doc(inline)]
+pub use align_panel_message_handler::*;
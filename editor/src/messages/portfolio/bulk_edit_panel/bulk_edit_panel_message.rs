@@ -0,0 +1,21 @@
+use crate::messages::prelude::*;
+use graph_craft::document::value::TaggedValue;
+
+/// The Bulk Edit panel lists every node of a chosen type in the graph in a table, where a parameter can be set for all of them at once.
+#[impl_message(Message, PortfolioMessage, BulkEditPanel)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum BulkEditPanelMessage {
+	ToggleOpen,
+
+	UpdateLayout,
+
+	/// Chooses the type of node listed in the table, by the name of its definition.
+	SetNodeType {
+		reference: String,
+	},
+	/// Sets the parameter at this input index to the same value in every node listed in the table.
+	SetParameterOfAll {
+		input_index: usize,
+		value: TaggedValue,
+	},
+}
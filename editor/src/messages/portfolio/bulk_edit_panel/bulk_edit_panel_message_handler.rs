@@ -0,0 +1,245 @@
+use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
+use crate::messages::portfolio::document::node_graph::node_properties::commit_value;
+use crate::messages::portfolio::document::utility_types::network_interface::{InputConnector, NodeNetworkInterface};
+use crate::messages::prelude::*;
+use crate::messages::tool::tool_messages::tool_prelude::*;
+use graph_craft::document::value::TaggedValue;
+use graph_craft::document::{NodeId, NodeInput};
+use graphene_std::vector::style::FillChoice;
+
+pub struct BulkEditPanelMessageData<'a> {
+	pub document: Option<&'a DocumentMessageHandler>,
+}
+
+/// The Bulk Edit panel lists every node of a chosen type in the graph in a table, where a parameter can be set for all of them at once.
+#[derive(Debug, Clone, Default)]
+pub struct BulkEditPanelMessageHandler {
+	/// Sets whether or not the Bulk Edit panel is shown.
+	pub bulk_edit_panel_open: bool,
+	/// The name of the definition of the nodes listed in the table, like "Stroke".
+	node_type: Option<String>,
+}
+
+impl MessageHandler<BulkEditPanelMessage, BulkEditPanelMessageData<'_>> for BulkEditPanelMessageHandler {
+	fn process_message(&mut self, message: BulkEditPanelMessage, responses: &mut VecDeque<Message>, data: BulkEditPanelMessageData) {
+		let BulkEditPanelMessageData { document } = data;
+
+		match message {
+			BulkEditPanelMessage::ToggleOpen => {
+				self.bulk_edit_panel_open = !self.bulk_edit_panel_open;
+
+				// Update checked UI state for open
+				responses.add(MenuBarMessage::SendLayout);
+			}
+			BulkEditPanelMessage::UpdateLayout => {}
+			BulkEditPanelMessage::SetNodeType { reference } => {
+				self.node_type = Some(reference);
+			}
+			BulkEditPanelMessage::SetParameterOfAll { input_index, value } => {
+				let Some(document) = document else { return };
+				let Some(node_type) = &self.node_type else { return };
+
+				for node_id in nodes_of_type(&document.network_interface, document.selection_network_path(), node_type) {
+					responses.add(NodeGraphMessage::SetInputValue {
+						node_id,
+						input_index,
+						value: value.clone(),
+					});
+				}
+			}
+		}
+
+		self.update_layout(document, responses);
+	}
+
+	fn actions(&self) -> ActionList {
+		actions!(BulkEditPanelMessage;)
+	}
+}
+
+impl BulkEditPanelMessageHandler {
+	fn update_layout(&self, document: Option<&DocumentMessageHandler>, responses: &mut VecDeque<Message>) {
+		responses.add(FrontendMessage::UpdateBulkEditPanelState { open: self.bulk_edit_panel_open });
+		if !self.bulk_edit_panel_open {
+			return;
+		}
+
+		let layout = document.map(|document| self.table(&document.network_interface, document.selection_network_path())).unwrap_or_default();
+
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout { layout }),
+			layout_target: LayoutTarget::BulkEditPanel,
+		});
+	}
+
+	fn table(&self, network_interface: &NodeNetworkInterface, network_path: &[NodeId]) -> Vec<LayoutGroup> {
+		// Offer each type of node found in the graph, with how many of it there are
+		let mut node_types = network_interface
+			.nested_network(network_path)
+			.into_iter()
+			.flat_map(|network| network.nodes.keys())
+			.filter(|node_id| !network_interface.is_layer(node_id, network_path))
+			.filter_map(|node_id| network_interface.reference(node_id, network_path).cloned().flatten())
+			.fold(Vec::<(String, usize)>::new(), |mut node_types, reference| {
+				match node_types.iter_mut().find(|(node_type, _)| *node_type == reference) {
+					Some((_, count)) => *count += 1,
+					None => node_types.push((reference, 1)),
+				}
+				node_types
+			});
+		node_types.sort();
+
+		let entries = node_types
+			.iter()
+			.map(|(reference, count)| {
+				let message_reference = reference.clone();
+				MenuListEntry::new(reference.clone())
+					.label(format!("{reference} ({count})"))
+					.on_update(move |_| BulkEditPanelMessage::SetNodeType { reference: message_reference.clone() }.into())
+			})
+			.collect();
+		let selected_index = self.node_type.as_ref().and_then(|node_type| node_types.iter().position(|(reference, _)| reference == node_type));
+
+		let mut layout = vec![LayoutGroup::Row {
+			widgets: vec![
+				TextLabel::new("Node Type").widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				DropdownInput::new(vec![entries])
+					.selected_index(selected_index.map(|index| index as u32))
+					.tooltip("The type of node whose instances in the graph are listed below for editing together")
+					.widget_holder(),
+			],
+		}];
+
+		let Some(node_type) = selected_index.map(|index| &node_types[index].0) else {
+			let text = if node_types.is_empty() {
+				"The graph has no nodes to edit"
+			} else {
+				"Choose a type of node to edit all of them"
+			};
+			layout.push(LayoutGroup::Row {
+				widgets: vec![TextLabel::new(text).italic(true).widget_holder()],
+			});
+			return layout;
+		};
+
+		let nodes = nodes_of_type(network_interface, network_path, node_type);
+		let value = |node_id: NodeId, input_index: usize| match network_interface.input_from_connector(&InputConnector::node(node_id, input_index), network_path) {
+			Some(NodeInput::Value { tagged_value, exposed: false }) => Some(&**tagged_value),
+			_ => None,
+		};
+
+		// The parameters with a value which can be edited here, skipping the primary input
+		let parameters = nodes
+			.first()
+			.map(|&first| {
+				(1..network_interface.number_of_inputs(&first, network_path))
+					.filter(|&input_index| value(first, input_index).is_some_and(|value| value_widget(value, false, |_| Message::NoOp).is_some()))
+					.collect::<Vec<_>>()
+			})
+			.unwrap_or_default();
+		if parameters.is_empty() {
+			layout.push(LayoutGroup::Row {
+				widgets: vec![TextLabel::new("This type of node has no parameters that can be edited here").italic(true).widget_holder()],
+			});
+			return layout;
+		}
+
+		let mut header = vec![TextLabel::new("Node").bold(true).widget_holder()];
+		header.extend(parameters.iter().map(|&input_index| {
+			let name = network_interface.input_name(&nodes[0], input_index, network_path).unwrap_or_default();
+			TextLabel::new(name).bold(true).widget_holder()
+		}));
+
+		// The first row edits every node at once, showing a value only where all the nodes share it
+		let mut all = vec![
+			TextLabel::new(format!("All {}", nodes.len()))
+				.tooltip("Edit these values to set them in every node below")
+				.widget_holder(),
+		];
+		all.extend(parameters.iter().map(|&input_index| {
+			let values = nodes.iter().filter_map(|&node_id| value(node_id, input_index)).collect::<Vec<_>>();
+			let mixed = values.windows(2).any(|pair| pair[0] != pair[1]);
+			values
+				.first()
+				.and_then(|value| value_widget(value, mixed, move |value| BulkEditPanelMessage::SetParameterOfAll { input_index, value }.into()))
+				.unwrap_or_else(|| TextLabel::new("—").widget_holder())
+		}));
+
+		let mut rows = vec![header, all];
+		rows.extend(nodes.iter().map(|&node_id| {
+			let mut row = vec![
+				TextButton::new(network_interface.display_name(&node_id, network_path))
+					.tooltip("Select this node and focus the graph on it")
+					.on_update(move |_| {
+						Message::Batched(Box::new([
+							NodeGraphMessage::SelectedNodesSet { nodes: vec![node_id] }.into(),
+							DocumentMessage::GraphViewOverlay { open: true }.into(),
+							NavigationMessage::FitViewportToSelection.into(),
+						]))
+					})
+					.widget_holder(),
+			];
+			row.extend(parameters.iter().map(|&input_index| {
+				value(node_id, input_index)
+					.and_then(|value| value_widget(value, false, move |value| NodeGraphMessage::SetInputValue { node_id, input_index, value }.into()))
+					.unwrap_or_else(|| TextLabel::new("—").tooltip("This parameter is connected to another node in the graph").widget_holder())
+			}));
+			row
+		}));
+		layout.push(LayoutGroup::Table { rows });
+
+		layout
+	}
+}
+
+/// The nodes in the network with this definition, ordered by their names.
+fn nodes_of_type(network_interface: &NodeNetworkInterface, network_path: &[NodeId], node_type: &str) -> Vec<NodeId> {
+	let Some(network) = network_interface.nested_network(network_path) else { return Vec::new() };
+
+	let mut nodes = network
+		.nodes
+		.keys()
+		.copied()
+		.filter(|node_id| !network_interface.is_layer(node_id, network_path))
+		.filter(|node_id| network_interface.reference(node_id, network_path).is_some_and(|reference| reference.as_deref() == Some(node_type)))
+		.collect::<Vec<_>>();
+	nodes.sort_by_cached_key(|node_id| (network_interface.display_name(node_id, network_path), *node_id));
+	nodes
+}
+
+/// A compact widget for editing a value in a cell of the table, or `None` for types of values which are only edited in the Properties panel.
+/// A mixed value, which differs between the nodes it edits, is shown blank where the widget allows it.
+fn value_widget(value: &TaggedValue, mixed: bool, set_value: impl Fn(TaggedValue) -> Message + 'static + Send + Sync) -> Option<WidgetHolder> {
+	let widget = match *value {
+		TaggedValue::F64(x) => NumberInput::new((!mixed).then_some(x))
+			.on_update(move |number_input: &NumberInput| number_input.value.map_or(Message::NoOp, |x| set_value(TaggedValue::F64(x))))
+			.on_commit(commit_value)
+			.widget_holder(),
+		TaggedValue::U32(x) => NumberInput::new((!mixed).then_some(x as f64))
+			.int()
+			.min(0.)
+			.on_update(move |number_input: &NumberInput| number_input.value.map_or(Message::NoOp, |x| set_value(TaggedValue::U32(x as u32))))
+			.on_commit(commit_value)
+			.widget_holder(),
+		TaggedValue::Bool(x) => CheckboxInput::new(x && !mixed)
+			.on_update(move |checkbox_input: &CheckboxInput| set_value(TaggedValue::Bool(checkbox_input.checked)))
+			.on_commit(commit_value)
+			.widget_holder(),
+		TaggedValue::String(ref x) => TextInput::new(if mixed { "" } else { x.as_str() })
+			.on_update(move |text_input: &TextInput| set_value(TaggedValue::String(text_input.value.clone())))
+			.on_commit(commit_value)
+			.widget_holder(),
+		TaggedValue::Color(x) => ColorInput::new(FillChoice::Solid(x))
+			.allow_none(false)
+			.on_update(move |color_input: &ColorInput| set_value(TaggedValue::Color(color_input.value.as_solid().unwrap_or_default())))
+			.on_commit(commit_value)
+			.widget_holder(),
+		TaggedValue::OptionalColor(x) => ColorInput::new(x.map_or(FillChoice::None, FillChoice::Solid))
+			.on_update(move |color_input: &ColorInput| set_value(TaggedValue::OptionalColor(color_input.value.as_solid())))
+			.on_commit(commit_value)
+			.widget_holder(),
+		_ => return None,
+	};
+	Some(widget)
+}
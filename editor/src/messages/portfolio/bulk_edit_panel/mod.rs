@@ -0,0 +1,7 @@
+mod bulk_edit_panel_message;
+mod bulk_edit_panel_message_handler;
+
+#[doc(inline)]
+pub use bulk_edit_panel_message::*;
+#[doc(inline)]
+pub use bulk_edit_panel_message_handler::*;
@@ -0,0 +1,24 @@
+use crate::messages::prelude::*;
+
+/// The Comments panel lists the threads of every comment pin in the document, where they can be replied to, resolved, and deleted.
+#[impl_message(Message, PortfolioMessage, CommentsPanel)]
+#[derive(PartialEq, Eq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum CommentsPanelMessage {
+	ToggleOpen,
+
+	UpdateLayout,
+
+	/// Opens the panel, if it isn't already, to the thread of the given pin so a comment can be written.
+	FocusComment {
+		number: u32,
+	},
+	/// Stores the not-yet-posted text typed into the reply field of a pin's thread.
+	SetDraft {
+		number: u32,
+		text: String,
+	},
+	/// Adds the drafted reply to the end of the pin's thread.
+	PostReply {
+		number: u32,
+	},
+}
@@ -0,0 +1,155 @@
+use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
+use crate::messages::portfolio::document::utility_types::comments::{CommentAnchor, CommentPin};
+use crate::messages::prelude::*;
+use crate::messages::tool::tool_messages::tool_prelude::*;
+
+pub struct CommentsPanelMessageData<'a> {
+	pub document: Option<&'a DocumentMessageHandler>,
+}
+
+/// The Comments panel lists the threads of every comment pin in the document, where they can be replied to, resolved, and deleted.
+#[derive(Default, Debug, Clone)]
+pub struct CommentsPanelMessageHandler {
+	/// Sets whether or not the Comments panel is shown.
+	pub comments_panel_open: bool,
+	/// The pin most recently dropped or clicked on the canvas, whose reply button is emphasized.
+	focused: Option<u32>,
+	/// The text typed into the reply field of each pin's thread which hasn't been posted yet.
+	drafts: HashMap<u32, String>,
+}
+
+impl MessageHandler<CommentsPanelMessage, CommentsPanelMessageData<'_>> for CommentsPanelMessageHandler {
+	fn process_message(&mut self, message: CommentsPanelMessage, responses: &mut VecDeque<Message>, data: CommentsPanelMessageData) {
+		let CommentsPanelMessageData { document } = data;
+
+		match message {
+			CommentsPanelMessage::ToggleOpen => {
+				self.comments_panel_open = !self.comments_panel_open;
+
+				// Update checked UI state for open
+				responses.add(MenuBarMessage::SendLayout);
+			}
+			CommentsPanelMessage::UpdateLayout => {}
+			CommentsPanelMessage::FocusComment { number } => {
+				self.focused = Some(number);
+
+				if !self.comments_panel_open {
+					self.comments_panel_open = true;
+					responses.add(MenuBarMessage::SendLayout);
+				}
+			}
+			CommentsPanelMessage::SetDraft { number, text } => {
+				self.drafts.insert(number, text);
+			}
+			CommentsPanelMessage::PostReply { number } => {
+				let text = self.drafts.remove(&number).unwrap_or_default();
+				responses.add(DocumentMessage::ReplyToComment { number, text });
+				// The document sends a layout update once the reply has been added to the thread
+				return;
+			}
+		}
+
+		self.update_layout(document, responses);
+	}
+
+	fn actions(&self) -> ActionList {
+		actions!(CommentsPanelMessage;)
+	}
+}
+
+impl CommentsPanelMessageHandler {
+	fn anchor_description(pin: &CommentPin, document: &DocumentMessageHandler) -> String {
+		match pin.anchor {
+			CommentAnchor::Document(position) => format!("At {:.0}, {:.0}", position.x, position.y),
+			CommentAnchor::Layer { layer, .. } if document.metadata().layer_exists(layer) => format!("On {}", document.network_interface.display_name(&layer.to_node(), &[])),
+			CommentAnchor::Layer { .. } => "On a deleted layer".into(),
+		}
+	}
+
+	fn update_layout(&self, document: Option<&DocumentMessageHandler>, responses: &mut VecDeque<Message>) {
+		responses.add(FrontendMessage::UpdateCommentsPanelState { open: self.comments_panel_open });
+		if !self.comments_panel_open {
+			return;
+		}
+
+		let mut layout = Vec::new();
+
+		let pins = document.map(|document| document.comments.pins().collect::<Vec<_>>()).unwrap_or_default();
+		if pins.is_empty() {
+			layout.push(LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new("Start a review by dropping a numbered comment pin on the canvas with the Comment tool")
+						.multiline(true)
+						.widget_holder(),
+				],
+			});
+		}
+
+		for pin in pins {
+			let Some(document) = document else { break };
+			let number = pin.number;
+
+			layout.push(LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new(format!("#{number}")).bold(true).disabled(pin.resolved).widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					TextLabel::new(Self::anchor_description(pin, document)).disabled(pin.resolved).widget_holder(),
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					CheckboxInput::new(pin.resolved)
+						.icon("Checkmark")
+						.tooltip(if pin.resolved { "Reopen Thread" } else { "Resolve Thread" })
+						.on_update(move |checkbox_input: &CheckboxInput| {
+							DocumentMessage::SetCommentResolved {
+								number,
+								resolved: checkbox_input.checked,
+							}
+							.into()
+						})
+						.widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					IconButton::new("Trash", 16)
+						.tooltip("Delete Comment Pin")
+						.on_update(move |_| DocumentMessage::DeleteComment { number }.into())
+						.widget_holder(),
+				],
+			});
+
+			for text in &pin.thread {
+				layout.push(LayoutGroup::Row {
+					widgets: vec![TextLabel::new(text).multiline(true).disabled(pin.resolved).widget_holder()],
+				});
+			}
+
+			let draft = self.drafts.get(&number).cloned().unwrap_or_default();
+			layout.push(LayoutGroup::Row {
+				widgets: vec![
+					TextInput::new(draft.clone())
+						.tooltip(if pin.thread.is_empty() {
+							"Write the first comment of this thread"
+						} else {
+							"Reply to this thread"
+						})
+						.on_update(move |text_input: &TextInput| {
+							CommentsPanelMessage::SetDraft {
+								number,
+								text: text_input.value.clone(),
+							}
+							.into()
+						})
+						.widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					TextButton::new(if pin.thread.is_empty() { "Comment" } else { "Reply" })
+						.emphasized(self.focused == Some(number))
+						.disabled(draft.trim().is_empty())
+						.on_update(move |_| CommentsPanelMessage::PostReply { number }.into())
+						.widget_holder(),
+				],
+			});
+		}
+
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout { layout }),
+			layout_target: LayoutTarget::CommentsPanel,
+		});
+	}
+}
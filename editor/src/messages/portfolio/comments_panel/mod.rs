@@ -0,0 +1,7 @@
+mod comments_panel_message;
+mod comments_panel_message_handler;
+
+#[doc(inline)]
+pub use comments_panel_message::*;
+#[doc(inline)]
+pub use comments_panel_message_handler::*;
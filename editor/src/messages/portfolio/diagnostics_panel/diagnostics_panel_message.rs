@@ -0,0 +1,16 @@
+use crate::messages::prelude::*;
+use graph_craft::document::NodeId;
+
+/// The Diagnostics panel reports the nodes which keep taking longer to evaluate than the document's performance budget, with suggestions for speeding them up.
+#[impl_message(Message, PortfolioMessage, DiagnosticsPanel)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum DiagnosticsPanelMessage {
+	ToggleOpen,
+
+	UpdateLayout,
+
+	/// Selects this node of the document network and focuses the graph on it.
+	SelectNode {
+		node_id: NodeId,
+	},
+}
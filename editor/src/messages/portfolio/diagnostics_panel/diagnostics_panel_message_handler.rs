@@ -0,0 +1,187 @@
+use crate::consts::{DEFAULT_PERFORMANCE_BUDGET_MILLISECONDS, PERFORMANCE_BUDGET_RENDERS};
+use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
+use crate::messages::prelude::*;
+use crate::messages::tool::tool_messages::tool_prelude::*;
+use graph_craft::document::NodeId;
+
+pub struct DiagnosticsPanelMessageData<'a> {
+	pub document: Option<&'a DocumentMessageHandler>,
+}
+
+/// The Diagnostics panel lists the nodes of the document network which have consistently exceeded the document's performance budget.
+/// Whether or not it's open, a warning about those nodes is shown in the status bar, which opens the panel.
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticsPanelMessageHandler {
+	/// Sets whether or not the Diagnostics panel is shown.
+	pub diagnostics_panel_open: bool,
+}
+
+impl MessageHandler<DiagnosticsPanelMessage, DiagnosticsPanelMessageData<'_>> for DiagnosticsPanelMessageHandler {
+	fn process_message(&mut self, message: DiagnosticsPanelMessage, responses: &mut VecDeque<Message>, data: DiagnosticsPanelMessageData) {
+		let DiagnosticsPanelMessageData { document } = data;
+
+		match message {
+			DiagnosticsPanelMessage::ToggleOpen => {
+				self.diagnostics_panel_open = !self.diagnostics_panel_open;
+
+				// Update checked UI state for open
+				responses.add(MenuBarMessage::SendLayout);
+			}
+			DiagnosticsPanelMessage::UpdateLayout => {}
+			DiagnosticsPanelMessage::SelectNode { node_id } => {
+				let Some(document) = document else { return };
+
+				// The slow nodes are those of the document network, so the graph is returned to it from any subgraph
+				let depth = document.selection_network_path().len();
+				if depth > 0 {
+					responses.add(DocumentMessage::ExitNestedNetwork { steps_back: depth });
+				}
+				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: vec![node_id] });
+				responses.add(DocumentMessage::GraphViewOverlay { open: true });
+				responses.add(NavigationMessage::FitViewportToSelection);
+			}
+		}
+
+		self.update_layout(document, responses);
+	}
+
+	fn actions(&self) -> ActionList {
+		actions!(DiagnosticsPanelMessage;)
+	}
+}
+
+impl DiagnosticsPanelMessageHandler {
+	/// The nodes which have consistently exceeded the budget and are still in the document network, with their names and average times in milliseconds.
+	fn slow_nodes(document: &DocumentMessageHandler) -> Vec<(NodeId, String, f64)> {
+		let network_interface = &document.network_interface;
+		document
+			.performance_budget
+			.slow_nodes()
+			.into_iter()
+			.filter(|(node_id, _)| network_interface.document_network().nodes.contains_key(node_id))
+			.map(|(node_id, milliseconds)| (node_id, network_interface.display_name(&node_id, &[]), milliseconds))
+			.collect()
+	}
+
+	fn update_layout(&self, document: Option<&DocumentMessageHandler>, responses: &mut VecDeque<Message>) {
+		let slow_nodes = document.map(Self::slow_nodes).unwrap_or_default();
+
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout::new(self.status_bar_layout(&slow_nodes))),
+			layout_target: LayoutTarget::StatusBar,
+		});
+
+		responses.add(FrontendMessage::UpdateDiagnosticsPanelState { open: self.diagnostics_panel_open });
+		if !self.diagnostics_panel_open {
+			return;
+		}
+
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout {
+				layout: self.layout(document, &slow_nodes),
+			}),
+			layout_target: LayoutTarget::DiagnosticsPanel,
+		});
+	}
+
+	fn status_bar_layout(&self, slow_nodes: &[(NodeId, String, f64)]) -> Vec<LayoutGroup> {
+		if slow_nodes.is_empty() {
+			return Vec::new();
+		}
+
+		let label = match slow_nodes.len() {
+			1 => format!("{} is slow", slow_nodes[0].1),
+			count => format!("{count} nodes are slow"),
+		};
+		let tooltip = format!(
+			"Over the performance budget in the last {PERFORMANCE_BUDGET_RENDERS} renders:\n{}\n\nClick to {} the Diagnostics panel",
+			slow_nodes.iter().map(|(_, name, milliseconds)| format!("{name} ({milliseconds:.1} ms)")).collect::<Vec<_>>().join("\n"),
+			if self.diagnostics_panel_open { "close" } else { "open" },
+		);
+		vec![LayoutGroup::Row {
+			widgets: vec![
+				IconLabel::new("Warning").tooltip(&tooltip).widget_holder(),
+				TextButton::new(label)
+					.flush(true)
+					.tooltip(tooltip)
+					.on_update(|_| DiagnosticsPanelMessage::ToggleOpen.into())
+					.widget_holder(),
+			],
+		}]
+	}
+
+	fn layout(&self, document: Option<&DocumentMessageHandler>, slow_nodes: &[(NodeId, String, f64)]) -> Vec<LayoutGroup> {
+		let budget = document.map(|document| &document.performance_budget);
+
+		let mut layout = vec![
+			LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new("Budget per Node").table_align(true).min_width(100).widget_holder(),
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					NumberInput::new(budget.map(|budget| budget.milliseconds()))
+						.unit(" ms")
+						.min(0.1)
+						.range_max(Some(100.))
+						.disabled(document.is_none())
+						.tooltip(format!(
+							"How long any one node of the document may take to evaluate in each render. A node over this in {PERFORMANCE_BUDGET_RENDERS} renders in a row is reported as slow.\n\nStarts out as {DEFAULT_PERFORMANCE_BUDGET_MILLISECONDS} ms, about a frame at 60 FPS"
+						))
+						.on_update(|number_input: &NumberInput| {
+							number_input
+								.value
+								.map_or(Message::NoOp, |milliseconds| DocumentMessage::SetPerformanceBudget { milliseconds }.into())
+						})
+						.widget_holder(),
+				],
+			},
+			LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new("Last Render").table_align(true).min_width(100).widget_holder(),
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					TextLabel::new(budget.map_or("—".to_string(), |budget| format!("{:.1} ms", budget.render_milliseconds())))
+						.tooltip("The time spent evaluating all the nodes of the document in the most recent render of the viewport")
+						.widget_holder(),
+				],
+			},
+		];
+
+		if slow_nodes.is_empty() {
+			layout.push(LayoutGroup::Row {
+				widgets: vec![TextLabel::new("No nodes have consistently exceeded the budget").italic(true).widget_holder()],
+			});
+			return layout;
+		}
+
+		for (node_id, name, milliseconds) in slow_nodes {
+			let node_id = *node_id;
+			layout.push(LayoutGroup::Row {
+				widgets: vec![
+					IconLabel::new("Warning").widget_holder(),
+					TextButton::new(name)
+						.tooltip("Select this node and focus the graph on it")
+						.on_update(move |_| DiagnosticsPanelMessage::SelectNode { node_id }.into())
+						.widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					TextLabel::new(format!("{milliseconds:.1} ms"))
+						.tooltip(format!("The average time this node took in the last {PERFORMANCE_BUDGET_RENDERS} renders"))
+						.widget_holder(),
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					TextButton::new("Bake")
+						.tooltip("Replace the output of this node with a frozen copy of it, so it's no longer evaluated in each render")
+						.on_update(move |_| NodeGraphMessage::BakeNode { node_id }.into())
+						.widget_holder(),
+				],
+			});
+			layout.push(LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new("Bake it to cache its output once it's done being edited, or lower the Preview Quality of the document while working")
+						.italic(true)
+						.multiline(true)
+						.widget_holder(),
+				],
+			});
+		}
+
+		layout
+	}
+}
@@ -0,0 +1,7 @@
+mod diagnostics_panel_message;
+mod diagnostics_panel_message_handler;
+
+#[doc(inline)]
+pub use diagnostics_panel_message::*;
+#[doc(inline)]
+pub use diagnostics_panel_message_handler::*;
@@ -0,0 +1,19 @@
+use crate::messages::prelude::*;
+use glam::DAffine2;
+
+/// Stores render snapshots of the document which can be shown over the live artwork to compare the before and after of an edit.
+#[impl_message(Message, DocumentMessage, Compare)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum CompareMessage {
+	CaptureSnapshot,
+	CaptureUnsupported,
+	StoreSnapshot { svg: String, render_to_document: DAffine2 },
+	SelectSnapshot { index: usize },
+	RenameSnapshot { name: String },
+	DeleteSnapshot,
+	SetVisible { visible: bool },
+	SetSwipe { swipe: bool },
+	SetSwipePosition { position: f64 },
+	Refresh,
+	UpdateOverlay,
+}
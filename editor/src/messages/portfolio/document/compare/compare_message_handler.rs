@@ -0,0 +1,227 @@
+use super::utility_types::CompareSnapshot;
+use crate::messages::prelude::*;
+use glam::DAffine2;
+use graphene_std::renderer::format_transform_matrix;
+
+pub struct CompareMessageData {
+	pub document_to_viewport: DAffine2,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompareMessageHandler {
+	snapshots: Vec<CompareSnapshot>,
+	selected_snapshot: Option<usize>,
+	/// Counts the captured snapshots so each new one gets a unique default name.
+	snapshots_captured: usize,
+	/// Whether the selected snapshot is shown over the live artwork.
+	pub visible: bool,
+	/// Whether the snapshot only covers the part of the viewport left of the swipe position, instead of all of it.
+	pub swipe: bool,
+	/// The fraction of the viewport width, from the left, where the snapshot gives way to the live artwork while swiping.
+	swipe_position: f64,
+	/// Set while waiting for the next render of the document to be stored as a snapshot.
+	pub capture_pending: bool,
+	/// Whether the frontend already has the selected snapshot's artwork, so it isn't resent each time the view changes.
+	artwork_sent: bool,
+}
+
+impl Default for CompareMessageHandler {
+	fn default() -> Self {
+		Self {
+			snapshots: Vec::new(),
+			selected_snapshot: None,
+			snapshots_captured: 0,
+			visible: false,
+			swipe: false,
+			swipe_position: 0.5,
+			capture_pending: false,
+			artwork_sent: false,
+		}
+	}
+}
+
+impl MessageHandler<CompareMessage, CompareMessageData> for CompareMessageHandler {
+	fn process_message(&mut self, message: CompareMessage, responses: &mut VecDeque<Message>, data: CompareMessageData) {
+		let CompareMessageData { document_to_viewport } = data;
+
+		match message {
+			CompareMessage::CaptureSnapshot => {
+				self.capture_pending = true;
+				responses.add(NodeGraphMessage::ForceRunDocumentGraph);
+			}
+			CompareMessage::CaptureUnsupported => {
+				self.capture_pending = false;
+				responses.add(DialogMessage::DisplayDialogError {
+					title: "Unable to capture snapshot".to_string(),
+					description: "Snapshots can only be captured while the document is rendered as SVG.".to_string(),
+				});
+			}
+			CompareMessage::StoreSnapshot { svg, render_to_document } => {
+				self.capture_pending = false;
+				self.snapshots_captured += 1;
+				self.snapshots.push(CompareSnapshot {
+					name: format!("Snapshot {}", self.snapshots_captured),
+					svg,
+					render_to_document,
+				});
+				self.selected_snapshot = Some(self.snapshots.len() - 1);
+				self.artwork_sent = false;
+
+				responses.add(CompareMessage::UpdateOverlay);
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+			}
+			CompareMessage::SelectSnapshot { index } => {
+				if index >= self.snapshots.len() {
+					return;
+				}
+				self.selected_snapshot = Some(index);
+				self.artwork_sent = false;
+
+				responses.add(CompareMessage::UpdateOverlay);
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+			}
+			CompareMessage::RenameSnapshot { name } => {
+				let Some(snapshot) = self.selected_snapshot.and_then(|index| self.snapshots.get_mut(index)) else {
+					return;
+				};
+				snapshot.name = name;
+
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+			}
+			CompareMessage::DeleteSnapshot => {
+				let Some(index) = self.selected_snapshot else { return };
+				self.snapshots.remove(index);
+				self.selected_snapshot = if self.snapshots.is_empty() { None } else { Some(index.min(self.snapshots.len() - 1)) };
+				self.artwork_sent = false;
+				if self.selected_snapshot.is_none() {
+					self.visible = false;
+				}
+
+				responses.add(CompareMessage::UpdateOverlay);
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+			}
+			CompareMessage::SetVisible { visible } => {
+				self.visible = visible && self.selected_snapshot.is_some();
+
+				responses.add(CompareMessage::UpdateOverlay);
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+			}
+			CompareMessage::SetSwipe { swipe } => {
+				self.swipe = swipe;
+
+				responses.add(CompareMessage::UpdateOverlay);
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+			}
+			CompareMessage::SetSwipePosition { position } => {
+				self.swipe_position = position.clamp(0., 1.);
+
+				responses.add(CompareMessage::UpdateOverlay);
+			}
+			CompareMessage::Refresh => {
+				// The frontend may be showing another document's snapshot, so resend this document's artwork
+				self.artwork_sent = false;
+
+				responses.add(CompareMessage::UpdateOverlay);
+			}
+			CompareMessage::UpdateOverlay => {
+				let snapshot = self.selected_snapshot.and_then(|index| self.snapshots.get(index)).filter(|_| self.visible);
+				let Some(snapshot) = snapshot else {
+					responses.add(FrontendMessage::UpdateCompareSnapshotState {
+						visible: false,
+						transform: String::new(),
+						swipe_position: None,
+					});
+					return;
+				};
+
+				if !self.artwork_sent {
+					responses.add(FrontendMessage::UpdateCompareSnapshotArtwork { svg: snapshot.svg.clone() });
+					self.artwork_sent = true;
+				}
+
+				responses.add(FrontendMessage::UpdateCompareSnapshotState {
+					visible: true,
+					transform: format_transform_matrix(document_to_viewport * snapshot.render_to_document),
+					swipe_position: self.swipe.then_some(self.swipe_position),
+				});
+			}
+		}
+	}
+
+	advertise_actions!(CompareMessage;);
+}
+
+impl CompareMessageHandler {
+	pub fn has_snapshots(&self) -> bool {
+		!self.snapshots.is_empty()
+	}
+
+	/// Whether a snapshot is shown over the artwork, so it needs to follow the view after each render.
+	pub fn is_showing_snapshot(&self) -> bool {
+		self.visible && self.selected_snapshot.is_some()
+	}
+
+	/// The widgets in the document bar's popover menu for capturing and managing snapshots.
+	pub fn popover_layout(&self) -> Vec<LayoutGroup> {
+		let selected = self.selected_snapshot.and_then(|index| self.snapshots.get(index));
+
+		let mut layout = vec![
+			LayoutGroup::Row {
+				widgets: vec![TextLabel::new("Compare").bold(true).widget_holder()],
+			},
+			LayoutGroup::Row {
+				widgets: vec![
+					TextButton::new("Capture Snapshot")
+						.tooltip("Store the current render of the document to compare against as it's edited")
+						.on_update(|_| CompareMessage::CaptureSnapshot.into())
+						.widget_holder(),
+				],
+			},
+		];
+
+		if let Some(selected) = selected {
+			let entries = self
+				.snapshots
+				.iter()
+				.enumerate()
+				.map(|(index, snapshot)| {
+					MenuListEntry::new(format!("{index}"))
+						.label(snapshot.name.clone())
+						.on_commit(move |_| CompareMessage::SelectSnapshot { index }.into())
+				})
+				.collect();
+
+			layout.extend([
+				LayoutGroup::Row {
+					widgets: vec![
+						DropdownInput::new(vec![entries]).selected_index(self.selected_snapshot.map(|index| index as u32)).widget_holder(),
+						Separator::new(SeparatorType::Related).widget_holder(),
+						IconButton::new("Trash", 24)
+							.tooltip("Delete Snapshot")
+							.on_update(|_| CompareMessage::DeleteSnapshot.into())
+							.widget_holder(),
+					],
+				},
+				LayoutGroup::Row {
+					widgets: vec![
+						TextInput::new(selected.name.clone())
+							.tooltip("Name of the selected snapshot")
+							.on_update(|text_input: &TextInput| CompareMessage::RenameSnapshot { name: text_input.value.clone() }.into())
+							.widget_holder(),
+					],
+				},
+				LayoutGroup::Row {
+					widgets: vec![
+						CheckboxInput::new(self.swipe)
+							.tooltip("Show the snapshot left of a divider which can be dragged across the viewport, instead of over the whole viewport")
+							.on_update(|optional_input: &CheckboxInput| CompareMessage::SetSwipe { swipe: optional_input.checked }.into())
+							.widget_holder(),
+						TextLabel::new("Swipe").widget_holder(),
+					],
+				},
+			]);
+		}
+
+		layout
+	}
+}
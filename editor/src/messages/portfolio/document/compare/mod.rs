@@ -0,0 +1,9 @@
+mod compare_message;
+mod compare_message_handler;
+
+pub mod utility_types;
+
+#[doc(inline)]
+pub use compare_message::{CompareMessage, CompareMessageDiscriminant};
+#[doc(inline)]
+pub use compare_message_handler::{CompareMessageData, CompareMessageHandler};
@@ -0,0 +1,11 @@
+use glam::DAffine2;
+
+/// A render of the document stored so it can be compared against the live document in the viewport.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompareSnapshot {
+	pub name: String,
+	/// The rendered SVG artwork, in the coordinate space of the render it was captured from.
+	pub svg: String,
+	/// Maps the coordinate space of the captured render to document space, so the snapshot stays aligned as the view is navigated.
+	pub render_to_document: DAffine2,
+}
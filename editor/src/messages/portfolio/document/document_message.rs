@@ -1,8 +1,13 @@
 use super::utility_types::misc::{GroupFolderType, SnappingState};
 use crate::messages::input_mapper::utility_types::input_keyboard::Key;
+use crate::messages::portfolio::document::node_graph::utility_types::GraphWireStyle;
 use crate::messages::portfolio::document::overlays::utility_types::OverlayContext;
+use crate::messages::portfolio::document::utility_types::comments::CommentAnchor;
 use crate::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
-use crate::messages::portfolio::document::utility_types::misc::{AlignAggregate, AlignAxis, FlipAxis, GridSnapping};
+use crate::messages::portfolio::document::utility_types::guides::Guide;
+use crate::messages::portfolio::document::utility_types::misc::{AlignAggregate, AlignAxis, AlignReference, ExportSlice, FlipAxis, GridSnapping, LengthUnit, PreviewQuality, TransformEachOptions};
+use crate::messages::portfolio::document::utility_types::swatches::Swatch;
+use crate::messages::portfolio::document::utility_types::text_search::TextSearch;
 use crate::messages::portfolio::utility_types::PanelType;
 use crate::messages::prelude::*;
 use glam::DAffine2;
@@ -10,6 +15,9 @@ use graph_craft::document::NodeId;
 use graphene_core::Color;
 use graphene_core::raster::BlendMode;
 use graphene_core::raster::Image;
+use graphene_core::raster::cmyk::PrintProfile;
+use graphene_core::raster::histogram::AutoAdjustment;
+use graphene_core::text::Font;
 use graphene_core::vector::style::ViewMode;
 use graphene_std::renderer::ClickTarget;
 use graphene_std::transform::Footprint;
@@ -20,6 +28,8 @@ pub enum DocumentMessage {
 	Noop,
 	// Sub-messages
 	#[child]
+	Compare(CompareMessage),
+	#[child]
 	GraphOperation(GraphOperationMessage),
 	#[child]
 	Navigation(NavigationMessage),
@@ -31,17 +41,39 @@ pub enum DocumentMessage {
 	PropertiesPanel(PropertiesPanelMessage),
 
 	// Messages
+	AddComment {
+		anchor: CommentAnchor,
+	},
+	AddSwatch {
+		swatch: Swatch,
+	},
 	AlignSelectedLayers {
 		axis: AlignAxis,
 		aggregate: AlignAggregate,
+		reference: AlignReference,
+	},
+	AutoAdjustSelectedLayer {
+		adjustment: AutoAdjustment,
+	},
+	DistributeSelectedLayers {
+		axis: AlignAxis,
 	},
 	RemoveArtboards,
 	ClearLayersPanel,
+	CreateAdjustmentLayer {
+		node_type: String,
+	},
 	CreateEmptyFolder,
+	DeleteComment {
+		number: u32,
+	},
 	DeleteNode {
 		node_id: NodeId,
 	},
 	DeleteSelectedLayers,
+	DeleteSwatch {
+		index: usize,
+	},
 	DeselectAllLayers,
 	DocumentHistoryBackward,
 	DocumentHistoryForward,
@@ -55,12 +87,19 @@ pub enum DocumentMessage {
 	ExitNestedNetwork {
 		steps_back: usize,
 	},
+	/// Adds the given number of colors used most often by the fills and strokes throughout the graph as swatches, skipping any already saved as swatches.
+	ExtractPaletteSwatches {
+		count: u32,
+	},
 	FlipSelectedLayers {
 		flip_axis: FlipAxis,
 	},
 	RotateSelectedLayers {
 		degrees: f64,
 	},
+	TransformEachSelectedLayer {
+		options: TransformEachOptions,
+	},
 	GraphViewOverlay {
 		open: bool,
 	},
@@ -71,6 +110,10 @@ pub enum DocumentMessage {
 	GroupSelectedLayers {
 		group_folder_type: GroupFolderType,
 	},
+	/// Adds the node saved in the contents of a `.graphite-node` file to the node catalog of the document.
+	ImportCustomNode {
+		content: String,
+	},
 	// ImaginateGenerate {
 	// 	imaginate_node: Vec<NodeId>,
 	// },
@@ -103,12 +146,27 @@ pub enum DocumentMessage {
 		mouse: Option<(f64, f64)>,
 		parent_and_insert_index: Option<(LayerNodeIdentifier, usize)>,
 	},
+	PasteGeoJson {
+		name: Option<String>,
+		geojson: String,
+		mouse: Option<(f64, f64)>,
+	},
 	Redo,
 	RenameDocument {
 		new_name: String,
 	},
 	RenderRulers,
 	RenderScrollbars,
+	/// Swaps the font of every text layer using each of the fonts to find for its replacement, as a single step in the undo history.
+	ReplaceFonts {
+		replacements: Vec<(Font, Font)>,
+	},
+	/// Replaces every match for the search in the text of text layers, as a single step in the undo history.
+	ReplaceText {
+		search: TextSearch,
+		replacement: String,
+	},
+	SaveAsTemplate,
 	SaveDocument,
 	SelectParentLayer,
 	SelectAllLayers,
@@ -128,25 +186,80 @@ pub enum DocumentMessage {
 	SetActivePanel {
 		active_panel: PanelType,
 	},
+	SetCommentResolved {
+		number: u32,
+		resolved: bool,
+	},
 	SetBlendModeForSelectedLayers {
 		blend_mode: BlendMode,
 	},
+	/// Shows the selected layers with the blend mode in the viewport without changing them, until it's set to their current blend mode.
+	PreviewBlendModeForSelectedLayers {
+		blend_mode: BlendMode,
+	},
+	SetExportSlices {
+		slices: Vec<ExportSlice>,
+	},
+	SetGuides {
+		guides: Vec<Guide>,
+	},
 	SetGraphFadeArtwork {
 		percentage: f64,
 	},
+	SetGraphWireStyle {
+		style: Option<GraphWireStyle>,
+	},
+	SetLengthUnit {
+		length_unit: LengthUnit,
+	},
+	SetPerformanceBudget {
+		milliseconds: f64,
+	},
+	SetPreviewQuality {
+		preview_quality: PreviewQuality,
+	},
+	SetPrintProfile {
+		print_profile: Option<PrintProfile>,
+	},
+	SetSoftProof {
+		soft_proof: bool,
+	},
 	SetNodePinned {
 		node_id: NodeId,
 		pinned: bool,
 	},
+	SetInputPinned {
+		node_id: NodeId,
+		input_index: usize,
+		pinned: bool,
+	},
 	SetOpacityForSelectedLayers {
 		opacity: f64,
 	},
+	/// Shows the selected layers with the opacity in the viewport without changing them, until it's set to their current opacity.
+	PreviewOpacityForSelectedLayers {
+		opacity: f64,
+	},
 	SetOverlaysVisibility {
 		visible: bool,
 	},
+	SetPixelGridVisibility {
+		visible: bool,
+	},
 	SetRangeSelectionLayer {
 		new_layer: Option<LayerNodeIdentifier>,
 	},
+	SetSwatches {
+		swatches: Vec<Swatch>,
+	},
+	/// Highlights the text layers with matches for the search in the viewport, or stops doing so if `None`.
+	SetTextSearchHighlight {
+		search: Option<TextSearch>,
+	},
+	UpdateSwatch {
+		index: usize,
+		swatch: Swatch,
+	},
 	SetSnapping {
 		#[serde(skip)]
 		closure: Option<for<'a> fn(&'a mut SnappingState) -> &'a mut bool>,
@@ -167,12 +280,26 @@ pub enum DocumentMessage {
 	RepeatedAbortTransaction {
 		undo_count: usize,
 	},
+	ReplyToComment {
+		number: u32,
+		text: String,
+	},
+	SetDriver {
+		node_id: NodeId,
+		input_index: usize,
+		expression: Option<String>,
+	},
+	ToggleKeyframe {
+		node_id: NodeId,
+		input_index: usize,
+	},
 	ToggleLayerExpansion {
 		id: NodeId,
 		recursive: bool,
 	},
 	ToggleSelectedVisibility,
 	ToggleSelectedLocked,
+	ExitSolo,
 	ToggleGridVisibility,
 	ToggleOverlaysVisibility,
 	ToggleSnapping,
@@ -183,6 +310,8 @@ pub enum DocumentMessage {
 	UpdateClickTargets {
 		click_targets: HashMap<NodeId, Vec<ClickTarget>>,
 	},
+	/// Shows or hides the viewport overlays marking the text layers drawn with the default font in place of a font that couldn't be found.
+	UpdateMissingFontOverlays,
 	UpdateClipTargets {
 		clip_targets: HashSet<NodeId>,
 	},
@@ -197,6 +326,23 @@ pub enum DocumentMessage {
 	WrapContentInArtboard {
 		place_artboard_at_origin: bool,
 	},
+	StartPresentation,
+	StopPresentation,
+	PresentNextSlide,
+	PresentPreviousSlide,
+	FitPresentationSlide,
+	SendPresentationState,
+	StartPickingPosition {
+		node_id: NodeId,
+		input_index: usize,
+	},
+	StopPickingPosition,
+	PickPositionPointerDown,
+	PickPositionPointerMove,
+	PickPositionPointerUp,
+	PositionPickerOverlays(OverlayContext),
+	TextSearchOverlays(OverlayContext),
+	MissingFontOverlays(OverlayContext),
 	ZoomCanvasTo100Percent,
 	ZoomCanvasTo200Percent,
 	ZoomCanvasToFitAll,
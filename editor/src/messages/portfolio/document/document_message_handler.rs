@@ -1,40 +1,67 @@
-use super::node_graph::document_node_definitions;
 use super::node_graph::utility_types::Transform;
-use super::overlays::utility_types::Pivot;
+use super::node_graph::{document_node_definitions, node_properties};
+use super::overlays::utility_types::{OverlayProvider, Pivot};
 use super::utility_types::error::EditorError;
-use super::utility_types::misc::{GroupFolderType, SNAP_FUNCTIONS_FOR_BOUNDING_BOXES, SNAP_FUNCTIONS_FOR_PATHS, SnappingOptions, SnappingState};
+use super::utility_types::misc::{GroupFolderType, SNAP_FUNCTIONS_FOR_BOUNDING_BOXES, SNAP_FUNCTIONS_FOR_PATHS, SNAP_FUNCTIONS_FOR_PIXELS, SnappingOptions, SnappingState};
 use super::utility_types::network_interface::{self, NodeNetworkInterface, TransactionStatus};
 use super::utility_types::nodes::{CollapsedLayers, SelectedNodes};
 use crate::application::{GRAPHITE_GIT_COMMIT_HASH, generate_uuid};
-use crate::consts::{ASYMPTOTIC_EFFECT, COLOR_OVERLAY_GRAY, DEFAULT_DOCUMENT_NAME, FILE_SAVE_SUFFIX, SCALE_EFFECT, SCROLLBAR_SPACING, VIEWPORT_ROTATE_SNAP_INTERVAL};
+use crate::consts::{
+	ASYMPTOTIC_EFFECT, COLOR_OVERLAY_BLUE, COLOR_OVERLAY_GRAY, COLOR_OVERLAY_GUIDE, COLOR_OVERLAY_LABEL_BACKGROUND, COLOR_OVERLAY_MISSING_FONT, COLOR_OVERLAY_RED, COLOR_OVERLAY_SEARCH_MATCH,
+	COLOR_OVERLAY_WHITE, DEFAULT_DOCUMENT_NAME, FILE_SAVE_SUFFIX, POSITION_PICKER_GIZMO_RADIUS, SCALE_EFFECT, SCROLLBAR_SPACING, VIEWPORT_ROTATE_SNAP_INTERVAL,
+};
+use crate::messages::dialog::new_document_dialog::document_templates::{DocumentTemplate, TemplateArtboard, TemplateCategory};
+use crate::messages::frontend::utility_types::MouseCursorIcon;
+use crate::messages::input_mapper::utility_types::input_keyboard::MouseMotion;
 use crate::messages::input_mapper::utility_types::macros::action_keys;
 use crate::messages::layout::utility_types::widget_prelude::*;
-use crate::messages::portfolio::document::graph_operation::utility_types::TransformIn;
+use crate::messages::portfolio::document::graph_operation::utility_types::{ModifyInputsContext, TransformIn};
 use crate::messages::portfolio::document::node_graph::NodeGraphHandlerData;
-use crate::messages::portfolio::document::overlays::grid_overlays::{grid_overlay, overlay_options};
+use crate::messages::portfolio::document::node_graph::utility_types::GraphWireStyle;
+use crate::messages::portfolio::document::overlays::grid_overlays::{grid_overlay, guide_overlay, overlay_options, pixel_grid_overlay};
 use crate::messages::portfolio::document::properties_panel::utility_types::PropertiesPanelMessageHandlerData;
+use crate::messages::portfolio::document::utility_types::comments::Comments;
+use crate::messages::portfolio::document::utility_types::custom_nodes::{self, CustomNode};
 use crate::messages::portfolio::document::utility_types::document_metadata::{DocumentMetadata, LayerNodeIdentifier};
-use crate::messages::portfolio::document::utility_types::misc::{AlignAggregate, AlignAxis, DocumentMode, FlipAxis, PTZ};
+use crate::messages::portfolio::document::utility_types::drivers::Drivers;
+use crate::messages::portfolio::document::utility_types::guides::Guide;
+use crate::messages::portfolio::document::utility_types::keyframes::Keyframes;
+use crate::messages::portfolio::document::utility_types::misc::{
+	AlignAggregate, AlignAxis, AlignReference, DocumentMode, ExportSlice, FlipAxis, LengthUnit, PTZ, PositionPicker, Presentation, PreviewQuality,
+};
 use crate::messages::portfolio::document::utility_types::network_interface::{FlowType, InputConnector, NodeTemplate};
+use crate::messages::portfolio::document::utility_types::node_profile::NodeProfile;
 use crate::messages::portfolio::document::utility_types::nodes::RawBuffer;
+use crate::messages::portfolio::document::utility_types::performance_budget::PerformanceBudget;
+use crate::messages::portfolio::document::utility_types::swatches::{self, Swatch};
+use crate::messages::portfolio::document::utility_types::text_search::TextSearch;
 use crate::messages::portfolio::utility_types::PersistentData;
 use crate::messages::prelude::*;
 use crate::messages::tool::common_functionality::graph_modification_utils::{self, get_blend_mode, get_opacity};
 use crate::messages::tool::tool_messages::select_tool::SelectToolPointerKeys;
 use crate::messages::tool::tool_messages::tool_prelude::Key;
-use crate::messages::tool::utility_types::ToolType;
+use crate::messages::tool::utility_types::{HintData, HintGroup, HintInfo, ToolType};
 use crate::node_graph_executor::NodeGraphExecutor;
 use bezier_rs::Subpath;
 use glam::{DAffine2, DVec2, IVec2};
 use graph_craft::document::value::TaggedValue;
 use graph_craft::document::{NodeId, NodeInput, NodeNetwork, OldNodeNetwork};
+use graphene_core::Color;
 use graphene_core::raster::BlendMode;
+use graphene_core::raster::cmyk::PrintProfile;
 use graphene_core::raster::image::ImageFrameTable;
-use graphene_core::vector::style::ViewMode;
+use graphene_core::text::Font;
+use graphene_core::vector::geojson::{geojson_bounds, parse_geojson};
+use graphene_core::vector::style::{Stroke, ViewMode};
 use graphene_std::renderer::{ClickTarget, Quad};
 use graphene_std::vector::{PointId, path_bool_lib};
+use rand::SeedableRng;
 use std::time::Duration;
 
+const POSITION_PICKER_OVERLAY_PROVIDER: OverlayProvider = |context| DocumentMessage::PositionPickerOverlays(context).into();
+const TEXT_SEARCH_OVERLAY_PROVIDER: OverlayProvider = |context| DocumentMessage::TextSearchOverlays(context).into();
+const MISSING_FONT_OVERLAY_PROVIDER: OverlayProvider = |context| DocumentMessage::MissingFontOverlays(context).into();
+
 pub struct DocumentMessageData<'a> {
 	pub document_id: DocumentId,
 	pub ipp: &'a InputPreprocessorMessageHandler,
@@ -53,6 +80,8 @@ pub struct DocumentMessageHandler {
 	// ======================
 	//
 	#[serde(skip)]
+	pub compare_handler: CompareMessageHandler,
+	#[serde(skip)]
 	pub navigation_handler: NavigationMessageHandler,
 	#[serde(skip)]
 	pub node_graph_handler: NodeGraphMessageHandler,
@@ -87,12 +116,40 @@ pub struct DocumentMessageHandler {
 	pub overlays_visible: bool,
 	/// Sets whether or not the rulers should be drawn along the top and left edges of the viewport area.
 	pub rulers_visible: bool,
+	/// Sets whether or not the outlines of the enlarged pixels are drawn over the artwork while zoomed in with the Pixels view mode.
+	pub pixel_grid_visible: bool,
+	/// The unit that lengths are shown and entered in throughout the numeric fields of the Properties panel, while the document itself stores them in pixels.
+	pub length_unit: LengthUnit,
+	/// How much detail expensive nodes are computed with in the viewport, which exports ignore by always rendering at full quality.
+	pub preview_quality: PreviewQuality,
+	/// The printing condition that colors are separated into CMYK inks for when the document is in CMYK mode, or `None` when it's in RGB mode.
+	pub print_profile: Option<PrintProfile>,
+	/// Sets whether or not the viewport simulates how the artwork would look printed under the document's CMYK printing condition.
+	pub soft_proof: bool,
 	/// The current user choices for snapping behavior, including whether snapping is enabled at all.
 	pub snapping_state: SnappingState,
 	/// Sets whether or not the node graph is drawn (as an overlay) on top of the viewport area, or otherwise if it's hidden.
 	pub graph_view_overlay_open: bool,
 	/// The current opacity of the faded node graph background that covers up the artwork.
 	pub graph_fade_artwork_percentage: f64,
+	/// How the wires of this document's node graph are drawn, or `None` to follow the style chosen in the preferences.
+	pub graph_wire_style: Option<GraphWireStyle>,
+	/// The layers marked as export slices, which are each exported as their own file when exporting all slices.
+	pub export_slices: Vec<ExportSlice>,
+	/// The keyframes of every animated node input, which are interpolated to set the inputs' values before the graph is evaluated at each point in time.
+	pub keyframes: Keyframes,
+	/// The expressions driving node inputs, which are evaluated to set the inputs' values before each evaluation of the graph.
+	pub drivers: Drivers,
+	/// The numbered comment pins left on the canvas in review mode (with the Comment tool), which are listed in the Comments panel.
+	pub comments: Comments,
+	/// Lines across the canvas drawn over the artwork to help lay it out, such as the margins set up by a document template.
+	pub guides: Vec<Guide>,
+	/// The named colors saved with the document for reuse throughout its artwork.
+	pub swatches: Vec<Swatch>,
+	/// The nodes imported from `.graphite-node` files, which are offered in the node catalog alongside the built-in nodes.
+	pub custom_nodes: Vec<CustomNode>,
+	/// The time each node may take to evaluate in a render before it's reported as slow, along with the recent times of the nodes that it's checked against.
+	pub performance_budget: PerformanceBudget,
 
 	// =============================================
 	// Fields omitted from the saved document format
@@ -120,6 +177,21 @@ pub struct DocumentMessageHandler {
 	/// If the user clicks or Ctrl-clicks one layer, it becomes the start of the range selection and then Shift-clicking another layer selects all layers between the start and end.
 	#[serde(skip)]
 	layer_range_selection_reference: Option<LayerNodeIdentifier>,
+	/// Set while the document is presented full screen as a slideshow of its artboards.
+	#[serde(skip)]
+	presentation: Option<Presentation>,
+	/// Set while the viewport is used to pick the value of a node's position input from the Properties panel.
+	#[serde(skip)]
+	position_picker: Option<PositionPicker>,
+	/// Set while the Find and Replace dialog is open, so the text layers with matches are highlighted in the viewport.
+	#[serde(skip)]
+	text_search_highlight: Option<TextSearch>,
+	/// Set while any text layers use a font that couldn't be found, so they're marked in the viewport until the font is substituted or located.
+	#[serde(skip)]
+	missing_font_overlays: bool,
+	/// The time spent in each node of the graph in the most recent render of the viewport, shown by the Profiler panel and its overlay in the graph.
+	#[serde(skip)]
+	pub node_profile: NodeProfile,
 	/// Whether or not the editor has executed the network to render the document yet. If this is opened as an inactive tab, it won't be loaded initially because the active tab is prioritized.
 	#[serde(skip)]
 	pub is_loaded: bool,
@@ -131,6 +203,7 @@ impl Default for DocumentMessageHandler {
 			// ======================
 			// Child message handlers
 			// ======================
+			compare_handler: CompareMessageHandler::default(),
 			navigation_handler: NavigationMessageHandler::default(),
 			node_graph_handler: NodeGraphMessageHandler::default(),
 			overlays_message_handler: OverlaysMessageHandler::default(),
@@ -147,9 +220,23 @@ impl Default for DocumentMessageHandler {
 			view_mode: ViewMode::default(),
 			overlays_visible: true,
 			rulers_visible: true,
+			pixel_grid_visible: true,
+			length_unit: LengthUnit::default(),
+			preview_quality: PreviewQuality::default(),
+			print_profile: None,
+			soft_proof: false,
 			graph_view_overlay_open: false,
 			snapping_state: SnappingState::default(),
 			graph_fade_artwork_percentage: 80.,
+			graph_wire_style: None,
+			export_slices: Vec::new(),
+			keyframes: Keyframes::default(),
+			drivers: Drivers::default(),
+			comments: Comments::default(),
+			guides: Vec::new(),
+			swatches: Vec::new(),
+			custom_nodes: Vec::new(),
+			performance_budget: PerformanceBudget::default(),
 			// =============================================
 			// Fields omitted from the saved document format
 			// =============================================
@@ -160,6 +247,11 @@ impl Default for DocumentMessageHandler {
 			saved_hash: None,
 			auto_saved_hash: None,
 			layer_range_selection_reference: None,
+			presentation: None,
+			position_picker: None,
+			text_search_highlight: None,
+			missing_font_overlays: false,
+			node_profile: NodeProfile::default(),
 			is_loaded: false,
 		}
 	}
@@ -181,6 +273,10 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 		let selected_visible_layers_bounding_box_viewport = self.selected_visible_layers_bounding_box_viewport();
 		match message {
 			// Sub-messages
+			DocumentMessage::Compare(message) => {
+				let document_to_viewport = self.metadata().document_to_viewport;
+				self.compare_handler.process_message(message, responses, CompareMessageData { document_to_viewport });
+			}
 			DocumentMessage::Navigation(message) => {
 				let data = NavigationMessageData {
 					network_interface: &mut self.network_interface,
@@ -215,12 +311,24 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 					network_interface: &mut self.network_interface,
 					selection_network_path: &self.selection_network_path,
 					document_name: self.name.as_str(),
+					length_unit: self.length_unit,
+					preview_quality: self.preview_quality,
+					keyframes: &self.keyframes,
+					drivers: &self.drivers,
+					position_picker_target: self.position_picker_target(),
 					executor,
+					preferences,
 				};
 				self.properties_panel_message_handler
 					.process_message(message, responses, (persistent_data, properties_panel_message_handler_data));
 			}
 			DocumentMessage::NodeGraph(message) => {
+				if let NodeGraphMessage::SetInputValue { node_id, input_index, value } = &message {
+					for node_id in NodeGraphMessageHandler::multi_edit_targets(&self.network_interface, &self.selection_network_path, *node_id) {
+						self.keyframes.input_value_edited(&self.selection_network_path, node_id, *input_index, value);
+					}
+				}
+
 				self.node_graph_handler.process_message(
 					message,
 					responses,
@@ -233,8 +341,10 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 						ipp,
 						graph_view_overlay_open: self.graph_view_overlay_open,
 						graph_fade_artwork_percentage: self.graph_fade_artwork_percentage,
+						graph_wire_style: self.graph_wire_style,
 						navigation_handler: &self.navigation_handler,
 						preferences,
+						custom_nodes: &self.custom_nodes,
 					},
 				);
 			}
@@ -247,32 +357,49 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 				let mut graph_operation_message_handler = GraphOperationMessageHandler {};
 				graph_operation_message_handler.process_message(message, responses, data);
 			}
-			DocumentMessage::AlignSelectedLayers { axis, aggregate } => {
+			DocumentMessage::AddComment { anchor } => {
+				let number = self.comments.add(anchor);
+				responses.add(CommentsPanelMessage::FocusComment { number });
+				responses.add(OverlaysMessage::Draw);
+			}
+			DocumentMessage::AddSwatch { swatch } => {
+				self.swatches.push(swatch);
+				responses.add(SwatchesPanelMessage::UpdateLayout);
+			}
+			DocumentMessage::AlignSelectedLayers { axis, aggregate, reference } => {
 				let axis = match axis {
 					AlignAxis::X => DVec2::X,
 					AlignAxis::Y => DVec2::Y,
 				};
-				let Some(combined_box) = self.selected_visible_layers_bounding_box_viewport() else {
-					return;
+				let aggregated = |[min, max]: [DVec2; 2]| match aggregate {
+					AlignAggregate::Min => min,
+					AlignAggregate::Max => max,
+					AlignAggregate::Center => (min + max) / 2.,
 				};
 
-				let aggregated = match aggregate {
-					AlignAggregate::Min => combined_box[0],
-					AlignAggregate::Max => combined_box[1],
-					AlignAggregate::Center => (combined_box[0] + combined_box[1]) / 2.,
+				let combined_box = match reference {
+					AlignReference::Selection => self.selected_visible_layers_bounding_box_viewport(),
+					AlignReference::KeyLayer(key_layer) => self.metadata().bounding_box_viewport(key_layer),
+					// Each layer is aligned to its own artboard
+					AlignReference::Artboard => None,
 				};
+				if reference != AlignReference::Artboard && combined_box.is_none() {
+					return;
+				}
 
 				let mut added_transaction = false;
 				for layer in self.network_interface.selected_nodes().selected_unlocked_layers(&self.network_interface) {
-					let Some(bbox) = self.metadata().bounding_box_viewport(layer) else {
+					if reference == AlignReference::KeyLayer(layer) {
 						continue;
+					}
+					let target_box = match reference {
+						AlignReference::Artboard => self.containing_artboard(layer).and_then(|artboard| self.metadata().bounding_box_viewport(artboard)),
+						_ => combined_box,
 					};
-					let center = match aggregate {
-						AlignAggregate::Min => bbox[0],
-						AlignAggregate::Max => bbox[1],
-						_ => (bbox[0] + bbox[1]) / 2.,
+					let (Some(target_box), Some(bbox)) = (target_box, self.metadata().bounding_box_viewport(layer)) else {
+						continue;
 					};
-					let translation = (aggregated - center) * axis;
+					let translation = (aggregated(target_box) - aggregated(bbox)) * axis;
 					if !added_transaction {
 						responses.add(DocumentMessage::AddTransaction);
 						added_transaction = true;
@@ -285,6 +412,52 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 					});
 				}
 			}
+			DocumentMessage::AutoAdjustSelectedLayer { adjustment } => {
+				let Some(layer) = self.network_interface.selected_nodes().selected_layers(self.metadata()).next() else {
+					return;
+				};
+				responses.add(NodeGraphMessage::AutoAdjust {
+					input_connector: InputConnector::node(layer.to_node(), 1),
+					adjustment,
+				});
+			}
+			DocumentMessage::DistributeSelectedLayers { axis } => {
+				let axis = match axis {
+					AlignAxis::X => DVec2::X,
+					AlignAxis::Y => DVec2::Y,
+				};
+
+				let mut layers = self
+					.network_interface
+					.selected_nodes()
+					.selected_unlocked_layers(&self.network_interface)
+					.filter_map(|layer| self.metadata().bounding_box_viewport(layer).map(|bbox| (layer, bbox)))
+					.collect::<Vec<_>>();
+				// The outermost layers stay in place, so at least one layer must be in between them
+				if layers.len() < 3 {
+					return;
+				}
+				layers.sort_by(|(_, a), (_, b)| a[0].dot(axis).total_cmp(&b[0].dot(axis)));
+
+				let start = layers[0].1[0].dot(axis);
+				let end = layers.iter().map(|(_, bbox)| bbox[1].dot(axis)).fold(f64::NEG_INFINITY, f64::max);
+				let total_size = layers.iter().map(|(_, bbox)| (bbox[1] - bbox[0]).dot(axis)).sum::<f64>();
+				let gap = (end - start - total_size) / (layers.len() - 1) as f64;
+
+				responses.add(DocumentMessage::AddTransaction);
+				let mut position = start;
+				for (layer, bbox) in layers {
+					let translation = (position - bbox[0].dot(axis)) * axis;
+					position += (bbox[1] - bbox[0]).dot(axis) + gap;
+
+					responses.add(GraphOperationMessage::TransformChange {
+						layer,
+						transform: DAffine2::from_translation(translation),
+						transform_in: TransformIn::Viewport,
+						skip_rerender: false,
+					});
+				}
+			}
 			DocumentMessage::RemoveArtboards => {
 				responses.add(GraphOperationMessage::RemoveArtboards);
 			}
@@ -299,6 +472,21 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 					layout_target: LayoutTarget::LayersPanelControlBar,
 				});
 			}
+			DocumentMessage::CreateAdjustmentLayer { node_type } => {
+				let selected_nodes = self.network_interface.selected_nodes();
+				let id = NodeId::new();
+
+				// Insert above the selected layers so they, and everything below them, are affected by the adjustment
+				let parent = self
+					.network_interface
+					.deepest_common_ancestor(&selected_nodes, &self.selection_network_path, true)
+					.unwrap_or(LayerNodeIdentifier::ROOT_PARENT);
+
+				let insert_index = DocumentMessageHandler::get_calculated_insert_index(self.metadata(), &self.network_interface.selected_nodes(), parent);
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(GraphOperationMessage::NewAdjustmentLayer { id, node_type, parent, insert_index });
+				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: vec![id] });
+			}
 			DocumentMessage::CreateEmptyFolder => {
 				let selected_nodes = self.network_interface.selected_nodes();
 				let id = NodeId::new();
@@ -318,6 +506,11 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 				});
 				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: vec![id] });
 			}
+			DocumentMessage::DeleteComment { number } => {
+				self.comments.delete(number);
+				responses.add(CommentsPanelMessage::UpdateLayout);
+				responses.add(OverlaysMessage::Draw);
+			}
 			DocumentMessage::DeleteNode { node_id } => {
 				responses.add(DocumentMessage::StartTransaction);
 
@@ -333,6 +526,12 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 			DocumentMessage::DeleteSelectedLayers => {
 				responses.add(NodeGraphMessage::DeleteSelectedNodes { delete_children: true });
 			}
+			DocumentMessage::DeleteSwatch { index } => {
+				if index < self.swatches.len() {
+					self.swatches.remove(index);
+				}
+				responses.add(SwatchesPanelMessage::UpdateLayout);
+			}
 			DocumentMessage::DeselectAllLayers => {
 				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: vec![] });
 				self.layer_range_selection_reference = None;
@@ -361,6 +560,21 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 						* DAffine2::from_translation(-DVec2::Y * 4.);
 
 					overlay_context.text(&name, COLOR_OVERLAY_GRAY, None, transform, 0., [Pivot::Start, Pivot::End]);
+
+					// Outline the bleed around the artboard and the margin within it
+					let bleed = graph_modification_utils::get_artboard_bleed(layer, &self.network_interface).unwrap_or_default();
+					let margin = graph_modification_utils::get_artboard_margin(layer, &self.network_interface).unwrap_or_default();
+					for (inset, color) in [(-bleed, COLOR_OVERLAY_RED), (margin, COLOR_OVERLAY_GUIDE)] {
+						let [min, max] = [bounds[0].min(bounds[1]) + inset, bounds[0].max(bounds[1]) - inset];
+						if inset == 0. || min.cmpge(max).any() {
+							continue;
+						}
+
+						let quad = self.metadata().document_to_viewport * Quad::from_box([min, max]);
+						for (start, end) in quad.0.into_iter().zip(quad.0.into_iter().cycle().skip(1)) {
+							overlay_context.dashed_line(start, end, Some(color), None, Some(4.), Some(4.), None);
+						}
+					}
 				}
 			}
 			DocumentMessage::DuplicateSelectedLayers => {
@@ -411,6 +625,7 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 				responses.add(NodeGraphMessage::SendGraph);
 				responses.add(DocumentMessage::ZoomCanvasToFitAll);
 				responses.add(NodeGraphMessage::SetGridAlignedEdges);
+				responses.add(ProfilerPanelMessage::UpdateLayout);
 			}
 			DocumentMessage::Escape => {
 				if self.node_graph_handler.drag_start.is_some() {
@@ -440,6 +655,18 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 				responses.add(DocumentMessage::PTZUpdate);
 				responses.add(NodeGraphMessage::SetGridAlignedEdges);
 				responses.add(NodeGraphMessage::SendGraph);
+				responses.add(ProfilerPanelMessage::UpdateLayout);
+			}
+			DocumentMessage::ExtractPaletteSwatches { count } => {
+				let colors = swatches::network_colors(self.network_interface.document_network());
+				let new_colors = swatches::most_used_colors(colors, usize::MAX)
+					.into_iter()
+					.filter(|&color| !self.swatches.iter().any(|swatch| swatch.matches(color)))
+					.take(count as usize)
+					.collect::<Vec<_>>();
+				self.swatches
+					.extend(new_colors.into_iter().map(|color| Swatch::new(format!("#{}", color.to_rgb_hex_srgb_from_gamma()), color)));
+				responses.add(SwatchesPanelMessage::UpdateLayout);
 			}
 			DocumentMessage::FlipSelectedLayers { flip_axis } => {
 				let scale = match flip_axis {
@@ -488,9 +715,39 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 					}
 				}
 			}
+			DocumentMessage::TransformEachSelectedLayer { options } => {
+				let document_to_viewport = self.metadata().document_to_viewport;
+				let mut rng = rand::rngs::StdRng::seed_from_u64(options.seed.into());
+
+				let mut added_transaction = false;
+				for layer in self.network_interface.selected_nodes().selected_unlocked_layers(&self.network_interface) {
+					if !added_transaction {
+						responses.add(DocumentMessage::AddTransaction);
+						added_transaction = true;
+					}
+
+					// Each layer is transformed about its own pivot, computed in document space so the result is independent of the view's tilt and zoom
+					let pivot = document_to_viewport
+						.inverse()
+						.transform_point2(graph_modification_utils::get_viewport_pivot(layer, &self.network_interface));
+					let transform = options.transform_about(pivot, &mut rng);
+
+					responses.add(GraphOperationMessage::TransformChange {
+						layer,
+						transform: document_to_viewport * transform * document_to_viewport.inverse(),
+						transform_in: TransformIn::Viewport,
+						skip_rerender: false,
+					});
+				}
+			}
 			DocumentMessage::GraphViewOverlay { open } => {
 				self.graph_view_overlay_open = open;
 
+				// The gizmo of a position being picked isn't shown over the graph
+				if open {
+					responses.add(DocumentMessage::StopPickingPosition);
+				}
+
 				responses.add(FrontendMessage::UpdateGraphViewOverlay { open });
 				responses.add(FrontendMessage::UpdateGraphFadeArtwork {
 					percentage: self.graph_fade_artwork_percentage,
@@ -523,9 +780,13 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 				responses.add(PortfolioMessage::UpdateDocumentWidgets);
 			}
 			DocumentMessage::GridOverlays(mut overlay_context) => {
+				if self.view_mode == ViewMode::Pixels && self.pixel_grid_visible {
+					pixel_grid_overlay(self, &mut overlay_context)
+				}
 				if self.snapping_state.grid_snapping {
 					grid_overlay(self, &mut overlay_context)
 				}
+				guide_overlay(self, &mut overlay_context);
 			}
 			DocumentMessage::GridVisibility(enabled) => {
 				self.snapping_state.grid_snapping = enabled;
@@ -610,6 +871,20 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 			// 		responses.add(DocumentMessage::ImaginateGenerate { imaginate_node });
 			// 	}
 			// }
+			DocumentMessage::ImportCustomNode { content } => {
+				let Ok(custom_node) = serde_json::from_str::<CustomNode>(&content) else {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: "Cannot import node".to_string(),
+						description: "The file doesn't contain a node exported from Graphite, or it was exported from an incompatible version.".to_string(),
+					});
+					return;
+				};
+
+				custom_nodes::register_custom_node(&mut self.custom_nodes, custom_node);
+				responses.add(FrontendMessage::UpdateCustomNodeTypes {
+					node_types: self.custom_nodes.iter().map(CustomNode::frontend_node_type).collect(),
+				});
+			}
 			DocumentMessage::MoveSelectedLayersTo { parent, insert_index } => {
 				if !self.selection_network_path.is_empty() {
 					log::error!("Moving selected layers is only supported for the Document Network");
@@ -723,6 +998,9 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 			} => {
 				responses.add(DocumentMessage::AddTransaction);
 
+				// Mirror the horizontal direction while the view is flipped
+				let delta_x = if self.document_ptz.flip() { -delta_x } else { delta_x };
+
 				let resize = ipp.keyboard.key(resize);
 				let resize_opposite_corner = ipp.keyboard.key(resize_opposite_corner);
 
@@ -752,13 +1030,22 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 
 				// Swap and negate coordinates as needed to match the resize direction that's closest to the current tilt angle
 				let tilt = (self.document_ptz.tilt() + std::f64::consts::TAU) % std::f64::consts::TAU;
-				let (delta_x, delta_y, opposite_x, opposite_y) = match ((tilt + std::f64::consts::FRAC_PI_4) / std::f64::consts::FRAC_PI_2).floor() as i32 % 4 {
+				let quadrant = ((tilt + std::f64::consts::FRAC_PI_4) / std::f64::consts::FRAC_PI_2).floor() as i32 % 4;
+				let (delta_x, delta_y, mut opposite_x, mut opposite_y) = match quadrant {
 					0 => (delta_x, delta_y, false, false),
 					1 => (delta_y, -delta_x, false, true),
 					2 => (-delta_x, -delta_y, true, true),
 					3 => (-delta_y, delta_x, true, false),
 					_ => unreachable!(),
 				};
+				// While flipped, the edge on the right side of the viewport is the opposite edge in document space
+				if self.document_ptz.flip() {
+					if quadrant % 2 == 0 {
+						opposite_x = !opposite_x;
+					} else {
+						opposite_y = !opposite_y;
+					}
+				}
 
 				let size = existing_bottom_right - existing_top_left;
 				// TODO: This is a hacky band-aid. It still results in the shape becoming zero-sized. Properly fix this using the correct math.
@@ -890,6 +1177,68 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: vec![layer.to_node()] });
 				responses.add(ToolMessage::ActivateTool { tool_type: ToolType::Select });
 			}
+			DocumentMessage::PasteGeoJson { name, geojson, mouse } => {
+				/// The width or height, whichever is larger, that an imported map is initially scaled to.
+				const IMPORTED_MAP_SIZE: f64 = 500.;
+
+				let Some(shapes) = parse_geojson(&geojson) else {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: "Unable to import GeoJSON".to_string(),
+						description: "The file is not valid JSON.".to_string(),
+					});
+					return;
+				};
+				let Some([min, max]) = geojson_bounds(&shapes) else {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: "Unable to import GeoJSON".to_string(),
+						description: "The file does not contain any geometry.".to_string(),
+					});
+					return;
+				};
+
+				// Center the map on its features and size it to fit in view, which can be changed afterwards in the node's parameters
+				let center = (min + max) / 2.;
+				let extent = (max - min).max_element();
+				let scale = if extent > 0. { IMPORTED_MAP_SIZE / extent } else { 1. };
+
+				let document_to_viewport = self.navigation_handler.calculate_offset_transform(ipp.viewport_bounds.center(), &self.document_ptz);
+				let viewport_location = mouse.map_or(ipp.viewport_bounds.center() + ipp.viewport_bounds.top_left, |pos| pos.into());
+				let center_in_document = document_to_viewport.inverse().transform_point2(viewport_location - ipp.viewport_bounds.top_left);
+
+				responses.add(DocumentMessage::AddTransaction);
+
+				let node_type = document_node_definitions::resolve_document_node_type("GeoJSON").expect("GeoJSON node does not exist");
+				let node = node_type.node_template_input_override([
+					None,
+					Some(NodeInput::value(TaggedValue::String(geojson), false)),
+					None,
+					Some(NodeInput::value(TaggedValue::DVec2(center), false)),
+					Some(NodeInput::value(TaggedValue::F64(scale), false)),
+				]);
+				let layer = graph_modification_utils::new_custom(NodeId::new(), vec![(NodeId(0), node)], self.new_layer_parent(true), responses);
+
+				responses.add(GraphOperationMessage::TransformSet {
+					layer,
+					transform: DAffine2::from_translation(center_in_document),
+					transform_in: TransformIn::Local,
+					skip_rerender: false,
+				});
+				responses.add(GraphOperationMessage::StrokeSet {
+					layer,
+					stroke: Stroke::new(Some(Color::BLACK), 1.),
+				});
+
+				if let Some(name) = name {
+					responses.add(NodeGraphMessage::SetDisplayName {
+						node_id: layer.to_node(),
+						alias: name,
+						skip_adding_history_step: false,
+					});
+				}
+
+				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: vec![layer.to_node()] });
+				responses.add(ToolMessage::ActivateTool { tool_type: ToolType::Select });
+			}
 			DocumentMessage::Redo => {
 				if self.network_interface.transaction_status() != TransactionStatus::Finished {
 					return;
@@ -973,6 +1322,29 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 					multiplier: scrollbar_multiplier.into(),
 				});
 			}
+			DocumentMessage::SaveAsTemplate => {
+				let artboards = self
+					.metadata()
+					.all_layers()
+					.filter(|layer| self.network_interface.is_artboard(&layer.to_node(), &[]))
+					.filter_map(|layer| {
+						let [min, max] = self.metadata().bounding_box_document(layer)?;
+						Some(TemplateArtboard {
+							name: self.network_interface.display_name(&layer.to_node(), &[]),
+							location: min.round().as_ivec2(),
+							dimensions: (max - min).round().as_ivec2(),
+						})
+					})
+					.collect();
+				let template = DocumentTemplate {
+					name: self.name.trim_end_matches(FILE_SAVE_SUFFIX).to_string(),
+					category: TemplateCategory::Custom,
+					artboards,
+					guides: self.guides.clone(),
+					swatches: self.swatches.clone(),
+				};
+				responses.add(PreferencesMessage::SaveDocumentTemplate { template });
+			}
 			DocumentMessage::SaveDocument => {
 				self.set_save_state(true);
 				responses.add(PortfolioMessage::AutoSaveActiveDocument);
@@ -983,10 +1355,12 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 					true => self.name.clone(),
 					false => self.name.clone() + FILE_SAVE_SUFFIX,
 				};
-				responses.add(FrontendMessage::TriggerDownloadTextFile {
-					document: self.serialize_document(),
-					name,
-				})
+				let document = self.serialize_document();
+				responses.add(PortfolioMessage::RememberRecentFile {
+					name: name.clone(),
+					content: document.clone(),
+				});
+				responses.add(FrontendMessage::TriggerDownloadTextFile { document, name })
 			}
 			DocumentMessage::SelectParentLayer => {
 				let selected_nodes = self.network_interface.selected_nodes();
@@ -1111,15 +1485,34 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 				responses.add(NodeGraphMessage::UpdateLayerPanel);
 				responses.add(NodeGraphMessage::UpdateInSelectedNetwork);
 			}
+			DocumentMessage::SetCommentResolved { number, resolved } => {
+				self.comments.set_resolved(number, resolved);
+				responses.add(CommentsPanelMessage::UpdateLayout);
+				responses.add(OverlaysMessage::Draw);
+			}
 			DocumentMessage::SetBlendModeForSelectedLayers { blend_mode } => {
+				responses.add(NodeGraphMessage::ClearProvisionalInputValues);
 				for layer in self.network_interface.selected_nodes().selected_layers_except_artboards(&self.network_interface) {
 					responses.add(GraphOperationMessage::BlendModeSet { layer, blend_mode });
 				}
 			}
+			DocumentMessage::PreviewBlendModeForSelectedLayers { blend_mode } => {
+				self.preview_selected_layers_input("Blend Mode", TaggedValue::BlendMode(blend_mode), blend_mode != BlendMode::default(), responses);
+			}
+			DocumentMessage::SetExportSlices { slices } => self.export_slices = slices,
+			DocumentMessage::SetGuides { guides } => {
+				self.guides = guides;
+				responses.add(OverlaysMessage::Draw);
+			}
 			DocumentMessage::SetGraphFadeArtwork { percentage } => {
 				self.graph_fade_artwork_percentage = percentage;
 				responses.add(FrontendMessage::UpdateGraphFadeArtwork { percentage });
 			}
+			DocumentMessage::SetGraphWireStyle { style } => {
+				self.graph_wire_style = style;
+				responses.add(NodeGraphMessage::SendGraph);
+				responses.add(NodeGraphMessage::UpdateGraphBarRight);
+			}
 			DocumentMessage::SetNodePinned { node_id, pinned } => {
 				responses.add(DocumentMessage::AddTransaction);
 				responses.add(NodeGraphMessage::SetPinned { node_id, pinned });
@@ -1127,20 +1520,76 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 				responses.add(NodeGraphMessage::SelectedNodesUpdated);
 				responses.add(NodeGraphMessage::SendGraph);
 			}
+			DocumentMessage::SetInputPinned { node_id, input_index, pinned } => {
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::SetInputPinned { node_id, input_index, pinned });
+			}
 			DocumentMessage::SetOpacityForSelectedLayers { opacity } => {
 				let opacity = opacity.clamp(0., 1.);
+				responses.add(NodeGraphMessage::ClearProvisionalInputValues);
 				for layer in self.network_interface.selected_nodes().selected_layers_except_artboards(&self.network_interface) {
 					responses.add(GraphOperationMessage::OpacitySet { layer, opacity });
 				}
 			}
+			DocumentMessage::PreviewOpacityForSelectedLayers { opacity } => {
+				let opacity = opacity.clamp(0., 1.);
+				self.preview_selected_layers_input("Opacity", TaggedValue::F64(opacity * 100.), opacity < 1., responses);
+			}
 			DocumentMessage::SetOverlaysVisibility { visible } => {
 				self.overlays_visible = visible;
 				responses.add(BroadcastEvent::ToolAbort);
 				responses.add(OverlaysMessage::Draw);
 			}
+			DocumentMessage::SetLengthUnit { length_unit } => {
+				self.length_unit = length_unit;
+				responses.add(PropertiesPanelMessage::Refresh);
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+			}
+			DocumentMessage::SetPrintProfile { print_profile } => {
+				self.print_profile = print_profile;
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+				responses.add(FrontendMessage::UpdateColorPickerPrintProfile { print_profile });
+				if self.soft_proof {
+					responses.add(NodeGraphMessage::RunDocumentGraph);
+				}
+			}
+			DocumentMessage::SetSoftProof { soft_proof } => {
+				self.soft_proof = soft_proof;
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+			}
+			DocumentMessage::SetPerformanceBudget { milliseconds } => {
+				self.performance_budget.set_milliseconds(milliseconds);
+				responses.add(DiagnosticsPanelMessage::UpdateLayout);
+			}
+			DocumentMessage::SetPreviewQuality { preview_quality } => {
+				self.preview_quality = preview_quality;
+				responses.add(PropertiesPanelMessage::Refresh);
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+			}
+			DocumentMessage::SetPixelGridVisibility { visible } => {
+				self.pixel_grid_visible = visible;
+				responses.add(OverlaysMessage::Draw);
+			}
 			DocumentMessage::SetRangeSelectionLayer { new_layer } => {
 				self.layer_range_selection_reference = new_layer;
 			}
+			DocumentMessage::SetSwatches { swatches } => {
+				self.swatches = swatches;
+				responses.add(SwatchesPanelMessage::UpdateLayout);
+			}
+			DocumentMessage::SetTextSearchHighlight { search } => {
+				if self.text_search_highlight.is_none() && search.is_none() {
+					return;
+				}
+				match (&self.text_search_highlight, &search) {
+					(None, Some(_)) => responses.add(OverlaysMessage::AddProvider(TEXT_SEARCH_OVERLAY_PROVIDER)),
+					(Some(_), None) => responses.add(OverlaysMessage::RemoveProvider(TEXT_SEARCH_OVERLAY_PROVIDER)),
+					_ => {}
+				}
+				self.text_search_highlight = search;
+				responses.add(OverlaysMessage::Draw);
+			}
 			DocumentMessage::SetSnapping { closure, snapping_state } => {
 				if let Some(closure) = closure {
 					*closure(&mut self.snapping_state) = snapping_state;
@@ -1154,6 +1603,8 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 			DocumentMessage::SetViewMode { view_mode } => {
 				self.view_mode = view_mode;
 				responses.add_front(NodeGraphMessage::RunDocumentGraph);
+				responses.add(OverlaysMessage::Draw);
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
 			}
 			DocumentMessage::AddTransaction => {
 				// Reverse order since they are added to the front
@@ -1203,6 +1654,35 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 				self.network_interface.finish_transaction();
 				responses.add(OverlaysMessage::Draw);
 			}
+			DocumentMessage::ReplyToComment { number, text } => {
+				self.comments.reply(number, text);
+				responses.add(CommentsPanelMessage::UpdateLayout);
+			}
+			DocumentMessage::SetDriver { node_id, input_index, expression } => {
+				for node_id in NodeGraphMessageHandler::multi_edit_targets(&self.network_interface, &self.selection_network_path, node_id) {
+					self.drivers.set_expression(&self.selection_network_path, node_id, input_index, expression.clone());
+				}
+				responses.add(PropertiesPanelMessage::Refresh);
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+			}
+			DocumentMessage::ToggleKeyframe { node_id, input_index } => {
+				// Every node edited together with this one is keyed the same way it is, so their keyframes don't end up toggled out of step
+				let time = self.keyframes.time();
+				let keyed = |keyframes: &Keyframes, node_id| {
+					keyframes
+						.animated_input(&self.selection_network_path, node_id, input_index)
+						.and_then(|animated_input| animated_input.keyframe_at(time))
+						.is_some()
+				};
+				let was_keyed = keyed(&self.keyframes, node_id);
+				for node_id in NodeGraphMessageHandler::multi_edit_targets(&self.network_interface, &self.selection_network_path, node_id) {
+					if keyed(&self.keyframes, node_id) == was_keyed {
+						self.keyframes.toggle_keyframe(&self.network_interface, &self.selection_network_path, node_id, input_index);
+					}
+				}
+				responses.add(PropertiesPanelMessage::Refresh);
+				responses.add(TimelinePanelMessage::UpdateLayout);
+			}
 			DocumentMessage::ToggleLayerExpansion { id, recursive } => {
 				let layer = LayerNodeIdentifier::new(id, &self.network_interface, &[]);
 				let metadata = self.metadata();
@@ -1230,6 +1710,7 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 			DocumentMessage::ToggleSelectedVisibility => {
 				responses.add(NodeGraphMessage::ToggleSelectedVisibility);
 			}
+			DocumentMessage::ExitSolo => responses.add(NodeGraphMessage::ExitSolo),
 			DocumentMessage::ToggleGridVisibility => {
 				self.snapping_state.grid_snapping = !self.snapping_state.grid_snapping;
 				responses.add(OverlaysMessage::Draw);
@@ -1269,6 +1750,22 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 			DocumentMessage::UpdateClipTargets { clip_targets } => {
 				self.network_interface.update_clip_targets(clip_targets);
 			}
+			DocumentMessage::UpdateMissingFontOverlays => {
+				let missing_font_overlays = !self.missing_fonts(&persistent_data.unavailable_fonts).is_empty();
+				match (self.missing_font_overlays, missing_font_overlays) {
+					(false, true) => responses.add(OverlaysMessage::AddProvider(MISSING_FONT_OVERLAY_PROVIDER)),
+					(true, false) => responses.add(OverlaysMessage::RemoveProvider(MISSING_FONT_OVERLAY_PROVIDER)),
+					_ => return,
+				}
+				self.missing_font_overlays = missing_font_overlays;
+				responses.add(OverlaysMessage::Draw);
+			}
+			DocumentMessage::UpdateSwatch { index, swatch } => {
+				if let Some(existing) = self.swatches.get_mut(index) {
+					*existing = swatch;
+				}
+				responses.add(SwatchesPanelMessage::UpdateLayout);
+			}
 			DocumentMessage::Undo => {
 				if self.network_interface.transaction_status() != TransactionStatus::Finished {
 					return;
@@ -1444,11 +1941,310 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 					warn!("Cannot zoom due to no bounds")
 				}
 			}
+			DocumentMessage::StartPresentation => {
+				if self.presentation.is_some() || self.graph_view_overlay_open || self.presentation_slides().is_empty() {
+					return;
+				}
+
+				self.presentation = Some(Presentation {
+					slide: 0,
+					overlays_visible: self.overlays_visible,
+					view_mode: self.view_mode,
+				});
+				self.overlays_visible = false;
+				if self.view_mode != ViewMode::Normal {
+					responses.add(DocumentMessage::SetViewMode { view_mode: ViewMode::Normal });
+				}
+
+				// The frontend shows the viewport full screen, and the slide is fit to it once its new bounds are sent back
+				responses.add(FrontendMessage::UpdatePresentationState { active: true, frame: None });
+				responses.add(OverlaysMessage::Draw);
+				responses.add(DocumentMessage::FitPresentationSlide);
+			}
+			DocumentMessage::StopPresentation => {
+				let Some(presentation) = self.presentation.take() else { return };
+
+				self.overlays_visible = presentation.overlays_visible;
+				if self.view_mode != presentation.view_mode {
+					responses.add(DocumentMessage::SetViewMode { view_mode: presentation.view_mode });
+				}
+
+				responses.add(FrontendMessage::UpdatePresentationState { active: false, frame: None });
+				responses.add(OverlaysMessage::Draw);
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+			}
+			DocumentMessage::PresentNextSlide => {
+				let slide_count = self.presentation_slides().len();
+				let Some(presentation) = &mut self.presentation else { return };
+				if presentation.slide + 1 < slide_count {
+					presentation.slide += 1;
+					responses.add(DocumentMessage::FitPresentationSlide);
+				}
+			}
+			DocumentMessage::PresentPreviousSlide => {
+				let Some(presentation) = &mut self.presentation else { return };
+				if presentation.slide > 0 {
+					presentation.slide -= 1;
+					responses.add(DocumentMessage::FitPresentationSlide);
+				}
+			}
+			DocumentMessage::FitPresentationSlide => {
+				let slides = self.presentation_slides();
+				let Some(presentation) = &mut self.presentation else { return };
+
+				// The artboards may have changed since the slide was chosen
+				presentation.slide = presentation.slide.min(slides.len().saturating_sub(1));
+				let Some(&bounds) = slides.get(presentation.slide) else {
+					responses.add(DocumentMessage::StopPresentation);
+					return;
+				};
+
+				responses.add(NavigationMessage::CanvasTiltSet { angle_radians: 0. });
+				responses.add(NavigationMessage::FitViewportToBounds { bounds, prevent_zoom_past_100: false });
+				responses.add(DocumentMessage::SendPresentationState);
+			}
+			DocumentMessage::SendPresentationState => {
+				let Some(presentation) = self.presentation else { return };
+				let Some(&bounds) = self.presentation_slides().get(presentation.slide) else { return };
+
+				// The area of the viewport showing the slide, outside of which everything is blacked out
+				let document_to_viewport = self.metadata().document_to_viewport;
+				let [min, max] = bounds.map(|point| document_to_viewport.transform_point2(point));
+				let [min, max] = [min.min(max), min.max(max)];
+				responses.add(FrontendMessage::UpdatePresentationState {
+					active: true,
+					frame: Some([(min.x, min.y), (max.x, max.y)]),
+				});
+			}
+			DocumentMessage::StartPickingPosition { node_id, input_index } => {
+				// Clicking the button of the input being picked again finishes picking it
+				let already_picking = self.position_picker.is_some_and(|picker| picker.node_id == node_id && picker.input_index == input_index);
+				if already_picking {
+					responses.add(DocumentMessage::StopPickingPosition);
+					return;
+				}
+				if self.graph_view_overlay_open || self.presentation.is_some() || self.picked_position(node_id, input_index).is_none() {
+					return;
+				}
+
+				if self.position_picker.is_none() {
+					responses.add(OverlaysMessage::AddProvider(POSITION_PICKER_OVERLAY_PROVIDER));
+				}
+				self.position_picker = Some(PositionPicker {
+					node_id,
+					input_index,
+					drag_offset: None,
+				});
+
+				responses.add(FrontendMessage::UpdateMouseCursor { cursor: MouseCursorIcon::Crosshair });
+				responses.add(FrontendMessage::UpdateInputHints {
+					hint_data: HintData(vec![
+						HintGroup(vec![HintInfo::mouse(MouseMotion::Lmb, "Pick Position"), HintInfo::mouse(MouseMotion::LmbDrag, "Drag Gizmo")]),
+						HintGroup(vec![HintInfo::keys([Key::Escape], "Done")]),
+					]),
+				});
+				responses.add(PropertiesPanelMessage::Refresh);
+				responses.add(OverlaysMessage::Draw);
+			}
+			DocumentMessage::StopPickingPosition => {
+				let Some(picker) = self.position_picker.take() else { return };
+				if picker.drag_offset.is_some() {
+					responses.add(DocumentMessage::EndTransaction);
+				}
+
+				responses.add(OverlaysMessage::RemoveProvider(POSITION_PICKER_OVERLAY_PROVIDER));
+				responses.add(ToolMessage::RefreshToolOptions);
+				responses.add(ToolMessage::UpdateHints);
+				responses.add(ToolMessage::UpdateCursor);
+				responses.add(PropertiesPanelMessage::Refresh);
+				responses.add(OverlaysMessage::Draw);
+			}
+			DocumentMessage::PickPositionPointerDown => {
+				let Some(picker) = self.position_picker else { return };
+				let Some(position) = self.picked_position(picker.node_id, picker.input_index) else {
+					responses.add(DocumentMessage::StopPickingPosition);
+					return;
+				};
+
+				// Grabbing the gizmo drags it from where it is, while clicking anywhere else moves it to the pointer
+				let document_to_viewport = self.metadata().document_to_viewport;
+				let offset = document_to_viewport.transform_point2(position) - ipp.mouse.position;
+				let drag_offset = if offset.length() <= POSITION_PICKER_GIZMO_RADIUS { offset } else { DVec2::ZERO };
+				self.position_picker = Some(PositionPicker {
+					drag_offset: Some(drag_offset),
+					..picker
+				});
+
+				responses.add(DocumentMessage::StartTransaction);
+				responses.add(DocumentMessage::PickPositionPointerMove);
+			}
+			DocumentMessage::PickPositionPointerMove => {
+				let Some(PositionPicker {
+					node_id,
+					input_index,
+					drag_offset: Some(drag_offset),
+				}) = self.position_picker
+				else {
+					return;
+				};
+
+				let position = self.metadata().document_to_viewport.inverse().transform_point2(ipp.mouse.position + drag_offset);
+				if self.picked_position(node_id, input_index) != Some(position) {
+					responses.add(NodeGraphMessage::SetInputValue {
+						node_id,
+						input_index,
+						value: TaggedValue::DVec2(position),
+					});
+					responses.add(OverlaysMessage::Draw);
+				}
+			}
+			DocumentMessage::PickPositionPointerUp => {
+				let Some(picker) = &mut self.position_picker else { return };
+				if picker.drag_offset.take().is_some() {
+					responses.add(DocumentMessage::EndTransaction);
+				}
+			}
+			DocumentMessage::ReplaceFonts { replacements } => {
+				let text_nodes = self
+					.metadata()
+					.all_layers()
+					.filter_map(|layer| {
+						let (_, font, _) = graph_modification_utils::get_text(layer, &self.network_interface)?;
+						let (_, replacement) = replacements.iter().find(|(find, replacement)| find == font && find != replacement)?;
+						Some((graph_modification_utils::get_text_id(layer, &self.network_interface)?, replacement.clone()))
+					})
+					.collect::<Vec<_>>();
+				if text_nodes.is_empty() {
+					return;
+				}
+
+				responses.add(DocumentMessage::AddTransaction);
+				for (node_id, font) in text_nodes {
+					responses.add(NodeGraphMessage::SetInput {
+						input_connector: InputConnector::node(node_id, 2),
+						input: NodeInput::value(TaggedValue::Font(font), false),
+					});
+				}
+				for (_, font) in replacements {
+					responses.add(PortfolioMessage::LoadFont { font });
+				}
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+				responses.add(DocumentMessage::UpdateMissingFontOverlays);
+			}
+			DocumentMessage::ReplaceText { search, replacement } => {
+				let replacements = self
+					.text_search_matches(&search)
+					.into_iter()
+					.filter_map(|(layer, _)| {
+						let node_id = graph_modification_utils::get_text_id(layer, &self.network_interface)?;
+						let (text, _, _) = graph_modification_utils::get_text(layer, &self.network_interface)?;
+						Some((node_id, search.replace_all(text, &replacement)))
+					})
+					.collect::<Vec<_>>();
+				if replacements.is_empty() {
+					return;
+				}
+
+				responses.add(DocumentMessage::AddTransaction);
+				for (node_id, text) in replacements {
+					responses.add(NodeGraphMessage::SetInput {
+						input_connector: InputConnector::node(node_id, 1),
+						input: NodeInput::value(TaggedValue::String(text), false),
+					});
+				}
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+				responses.add(OverlaysMessage::Draw);
+			}
+			DocumentMessage::PositionPickerOverlays(mut overlay_context) => {
+				let Some(picker) = self.position_picker else { return };
+				let Some(position) = self.picked_position(picker.node_id, picker.input_index) else { return };
+
+				let position = self.metadata().document_to_viewport.transform_point2(position);
+				let fill = if picker.drag_offset.is_some() { COLOR_OVERLAY_BLUE } else { COLOR_OVERLAY_WHITE };
+				for axis in [DVec2::X, DVec2::Y] {
+					let extent = axis * POSITION_PICKER_GIZMO_RADIUS * 2.;
+					overlay_context.line(position - extent, position + extent, None, None);
+				}
+				overlay_context.circle(position, POSITION_PICKER_GIZMO_RADIUS, Some(fill), Some(COLOR_OVERLAY_BLUE));
+			}
+			DocumentMessage::TextSearchOverlays(mut overlay_context) => {
+				let Some(search) = &self.text_search_highlight else { return };
+
+				for (layer, count) in self.text_search_matches(search) {
+					let Some(bounds) = self.metadata().bounding_box_with_transform(layer, DAffine2::IDENTITY) else {
+						continue;
+					};
+					let quad = self.metadata().transform_to_viewport(layer) * Quad::from_box(bounds);
+					overlay_context.quad(quad, Some(COLOR_OVERLAY_SEARCH_MATCH));
+
+					let label = if count == 1 { "1 match".to_string() } else { format!("{count} matches") };
+					let [top_left, _] = quad.bounding_box();
+					overlay_context.text(
+						&label,
+						COLOR_OVERLAY_WHITE,
+						Some(COLOR_OVERLAY_LABEL_BACKGROUND),
+						DAffine2::from_translation(top_left),
+						4.,
+						[Pivot::Start, Pivot::End],
+					);
+				}
+			}
+			DocumentMessage::MissingFontOverlays(mut overlay_context) => {
+				let unavailable_fonts = &persistent_data.unavailable_fonts;
+
+				for layer in self.metadata().all_layers() {
+					let Some((_, font, _)) = graph_modification_utils::get_text(layer, &self.network_interface) else {
+						continue;
+					};
+					if !unavailable_fonts.contains(font) {
+						continue;
+					}
+					let Some(bounds) = self.metadata().bounding_box_with_transform(layer, DAffine2::IDENTITY) else {
+						continue;
+					};
+					let quad = self.metadata().transform_to_viewport(layer) * Quad::from_box(bounds);
+					overlay_context.dashed_quad(quad, Some(COLOR_OVERLAY_MISSING_FONT), Some(4.), Some(4.), None);
+
+					let label = format!("Missing font: {} {}", font.font_family, font.font_style);
+					let [top_left, _] = quad.bounding_box();
+					overlay_context.text(
+						&label,
+						COLOR_OVERLAY_WHITE,
+						Some(COLOR_OVERLAY_LABEL_BACKGROUND),
+						DAffine2::from_translation(top_left),
+						4.,
+						[Pivot::Start, Pivot::End],
+					);
+				}
+			}
 			DocumentMessage::Noop => (),
 		}
 	}
 
 	fn actions(&self) -> ActionList {
+		// While presenting, the viewport only responds to stepping through the slides
+		if self.presentation.is_some() {
+			return actions!(DocumentMessageDiscriminant;
+				PresentNextSlide,
+				PresentPreviousSlide,
+				StopPresentation,
+			);
+		}
+
+		// While picking a position, the viewport only responds to placing and dragging its gizmo, besides navigating the canvas
+		if self.position_picker.is_some() {
+			let mut picking = actions!(DocumentMessageDiscriminant;
+				StopPickingPosition,
+				PickPositionPointerDown,
+				PickPositionPointerMove,
+				PickPositionPointerUp,
+				Undo,
+				Redo,
+			);
+			picking.extend(self.navigation_handler.actions());
+			return picking;
+		}
+
 		let mut common = actions!(DocumentMessageDiscriminant;
 			CreateEmptyFolder,
 			DeselectAllLayers,
@@ -1458,6 +2254,7 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 			SaveDocument,
 			SelectAllLayers,
 			SetSnapping,
+			StartPresentation,
 			ToggleGridVisibility,
 			ToggleOverlaysVisibility,
 			ToggleSnapping,
@@ -1507,6 +2304,82 @@ impl MessageHandler<DocumentMessage, DocumentMessageData<'_>> for DocumentMessag
 }
 
 impl DocumentMessageHandler {
+	/// Whether the document is being presented full screen as a slideshow of its artboards.
+	pub fn is_presenting(&self) -> bool {
+		self.presentation.is_some()
+	}
+
+	/// Whether the viewport is being used to pick the value of a node's position input, during which tools are unavailable.
+	pub fn is_picking_position(&self) -> bool {
+		self.position_picker.is_some()
+	}
+
+	/// The node input whose position is being picked in the viewport, if any.
+	pub fn position_picker_target(&self) -> Option<(NodeId, usize)> {
+		self.position_picker.map(|picker| (picker.node_id, picker.input_index))
+	}
+
+	/// Each font used by the text layers in the document, with the number of text layers using it, sorted by family and style.
+	pub fn fonts_in_use(&self) -> Vec<(Font, usize)> {
+		let mut fonts = HashMap::<Font, usize>::new();
+		for layer in self.metadata().all_layers() {
+			if let Some((_, font, _)) = graph_modification_utils::get_text(layer, &self.network_interface) {
+				*fonts.entry(font.clone()).or_default() += 1;
+			}
+		}
+
+		let mut fonts = fonts.into_iter().collect::<Vec<_>>();
+		fonts.sort_by(|(a, _), (b, _)| (&a.font_family, &a.font_style).cmp(&(&b.font_family, &b.font_style)));
+		fonts
+	}
+
+	/// Each font that couldn't be found which is used by the text layers in the document, with the number of text layers using it.
+	pub fn missing_fonts(&self, unavailable_fonts: &HashSet<Font>) -> Vec<(Font, usize)> {
+		self.fonts_in_use().into_iter().filter(|(font, _)| unavailable_fonts.contains(font)).collect()
+	}
+
+	/// Each text layer whose text contains matches for the search, with the number of matches it contains, in layer order.
+	pub fn text_search_matches(&self, search: &TextSearch) -> Vec<(LayerNodeIdentifier, usize)> {
+		self.metadata()
+			.all_layers()
+			.filter_map(|layer| {
+				let (text, _, _) = graph_modification_utils::get_text(layer, &self.network_interface)?;
+				let count = search.find_matches(text).len();
+				(count > 0).then_some((layer, count))
+			})
+			.collect()
+	}
+
+	/// The node whose output is introspected to preview the vector data coming into the selected node in the Properties panel, if any.
+	pub fn vector_preview_inspect_node(&self) -> Option<NodeId> {
+		node_properties::vector_preview_inspect_node(&self.network_interface, self.properties_panel_message_handler.vector_preview_source())
+	}
+
+	/// The current value of a node's position input in the selection network, if it's an unexposed `DVec2` value.
+	fn picked_position(&self, node_id: NodeId, input_index: usize) -> Option<DVec2> {
+		let input = self.network_interface.input_from_connector(&InputConnector::node(node_id, input_index), &self.selection_network_path)?;
+		match input.as_non_exposed_value()? {
+			&TaggedValue::DVec2(position) => Some(position),
+			_ => None,
+		}
+	}
+
+	/// The document space bounds of each slide shown while presenting: every visible artboard in reading order, or all the artwork if there are no artboards.
+	fn presentation_slides(&self) -> Vec<[DVec2; 2]> {
+		let mut slides = self
+			.metadata()
+			.all_layers()
+			.filter(|layer| self.network_interface.is_artboard(&layer.to_node(), &[]) && self.network_interface.is_visible(&layer.to_node(), &[]))
+			.filter_map(|layer| self.metadata().bounding_box_document(layer))
+			.collect::<Vec<_>>();
+		slides.sort_by(|a, b| a[0].y.total_cmp(&b[0].y).then(a[0].x.total_cmp(&b[0].x)));
+
+		if slides.is_empty() {
+			slides.extend(self.network_interface.document_bounds_document_space(true));
+		}
+		slides
+	}
+
 	/// Runs an intersection test with all layers and a viewport space quad
 	pub fn intersect_quad<'a>(&'a self, viewport_quad: graphene_core::renderer::Quad, ipp: &InputPreprocessorMessageHandler) -> impl Iterator<Item = LayerNodeIdentifier> + use<'a> {
 		let document_to_viewport = self.navigation_handler.calculate_offset_transform(ipp.viewport_bounds.center(), &self.document_ptz);
@@ -1623,6 +2496,14 @@ impl DocumentMessageHandler {
 			.reduce(graphene_core::renderer::Quad::combine_bounds)
 	}
 
+	/// The closest artboard which contains the layer, excluding the layer itself.
+	pub fn containing_artboard(&self, layer: LayerNodeIdentifier) -> Option<LayerNodeIdentifier> {
+		layer
+			.ancestors(self.metadata())
+			.skip(1)
+			.find(|ancestor| *ancestor != LayerNodeIdentifier::ROOT_PARENT && self.network_interface.is_artboard(&ancestor.to_node(), &[]))
+	}
+
 	pub fn selected_visible_and_unlock_layers_bounding_box_viewport(&self) -> Option<[DVec2; 2]> {
 		self.network_interface
 			.selected_nodes()
@@ -2037,6 +2918,7 @@ impl DocumentMessageHandler {
 
 		let mut snapping_state = self.snapping_state.clone();
 		let mut snapping_state2 = self.snapping_state.clone();
+		let mut snapping_state3 = self.snapping_state.clone();
 
 		let mut widgets = vec![
 			IconButton::new("PlaybackToStart", 24)
@@ -2112,6 +2994,18 @@ impl DocumentMessageHandler {
 									TextLabel::new(name).tooltip(tooltip).widget_holder(),
 								],
 					}))
+					.chain([LayoutGroup::Row {
+						widgets: vec![TextLabel::new(SnappingOptions::Pixels.to_string()).widget_holder()],
+					}])
+					.chain(SNAP_FUNCTIONS_FOR_PIXELS.into_iter().map(|(name, closure, tooltip)| LayoutGroup::Row {
+						widgets: vec![
+									CheckboxInput::new(*closure(&mut snapping_state3))
+										.on_update(move |input: &CheckboxInput| DocumentMessage::SetSnapping { closure: Some(closure), snapping_state: input.checked }.into())
+										.tooltip(tooltip)
+										.widget_holder(),
+									TextLabel::new(name).tooltip(tooltip).widget_holder(),
+								],
+					}))
 					.collect(),
 				)
 				.widget_holder(),
@@ -2139,23 +3033,64 @@ impl DocumentMessageHandler {
 				RadioEntryData::new("pixels")
 					.icon("ViewModePixels")
 					.tooltip("View Mode: Pixels")
-					.on_update(|_| DialogMessage::RequestComingSoonDialog { issue: Some(320) }.into()),
+					.on_update(|_| DocumentMessage::SetViewMode { view_mode: ViewMode::Pixels }.into()),
 			])
 			.selected_index(match self.view_mode {
 				ViewMode::Normal => Some(0),
-				_ => Some(1),
+				ViewMode::Outline => Some(1),
+				ViewMode::Pixels => Some(2),
 			})
 			.widget_holder(),
-			// PopoverButton::new()
-			// 	.popover_layout(vec![
-			// 		LayoutGroup::Row {
-			// 			widgets: vec![TextLabel::new("View Mode").bold(true).widget_holder()],
-			// 		},
-			// 		LayoutGroup::Row {
-			// 			widgets: vec![TextLabel::new("Coming soon").widget_holder()],
-			// 		},
-			// 	])
-			// 	.widget_holder(),
+			PopoverButton::new()
+				.popover_layout(vec![
+					LayoutGroup::Row {
+						widgets: vec![TextLabel::new("View Mode").bold(true).widget_holder()],
+					},
+					LayoutGroup::Row {
+						widgets: vec![
+							CheckboxInput::new(self.pixel_grid_visible)
+								.tooltip("Outline each pixel when zoomed in far enough in the Pixels view mode")
+								.on_update(|optional_input: &CheckboxInput| DocumentMessage::SetPixelGridVisibility { visible: optional_input.checked }.into())
+								.disabled(self.view_mode != ViewMode::Pixels)
+								.widget_holder(),
+							TextLabel::new("Pixel Grid")
+								.tooltip("Outline each pixel when zoomed in far enough in the Pixels view mode")
+								.disabled(self.view_mode != ViewMode::Pixels)
+								.widget_holder(),
+						],
+					},
+				])
+				.widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			CheckboxInput::new(self.compare_handler.is_showing_snapshot())
+				.icon("CompareSnapshot")
+				.tooltip("Compare Snapshot")
+				.disabled(!self.compare_handler.has_snapshots())
+				.on_update(|optional_input: &CheckboxInput| CompareMessage::SetVisible { visible: optional_input.checked }.into())
+				.widget_holder(),
+			PopoverButton::new().popover_layout(self.compare_handler.popover_layout()).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			CheckboxInput::new(self.soft_proof && self.print_profile.is_some())
+				.icon("NodeColorCorrection")
+				.tooltip("Soft Proof (Simulate Printed CMYK Colors)")
+				.disabled(self.print_profile.is_none())
+				.on_update(|optional_input: &CheckboxInput| DocumentMessage::SetSoftProof { soft_proof: optional_input.checked }.into())
+				.widget_holder(),
+			PopoverButton::new().popover_layout(self.color_space_popover_layout()).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![
+				LengthUnit::ALL
+					.into_iter()
+					.map(|length_unit| {
+						MenuListEntry::new(format!("{length_unit:?}"))
+							.label(length_unit.to_string())
+							.on_commit(move |_| DocumentMessage::SetLengthUnit { length_unit }.into())
+					})
+					.collect(),
+			])
+			.selected_index(LengthUnit::ALL.iter().position(|&length_unit| length_unit == self.length_unit).map(|index| index as u32))
+			.tooltip("Units that lengths are shown and typed in, where values may also be typed with any unit like \"2cm\" to convert them")
+			.widget_holder(),
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
 		];
 
@@ -2193,6 +3128,17 @@ impl DocumentMessageHandler {
 			]);
 		}
 
+		if self.document_ptz.flip() {
+			widgets.extend([
+				Separator::new(SeparatorType::Related).widget_holder(),
+				IconButton::new("FlipHorizontal", 24)
+					.tooltip("Unflip View")
+					.tooltip_shortcut(action_keys!(NavigationMessageDiscriminant::CanvasFlip))
+					.on_update(|_| NavigationMessage::CanvasFlip.into())
+					.widget_holder(),
+			]);
+		}
+
 		widgets.extend([
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
 			TextButton::new("Node Graph")
@@ -2212,6 +3158,81 @@ impl DocumentMessageHandler {
 		});
 	}
 
+	/// The popover of the soft proof toggle, where the document is switched between RGB and CMYK along with the printing condition it's separated for.
+	fn color_space_popover_layout(&self) -> Vec<LayoutGroup> {
+		let entries = [None]
+			.into_iter()
+			.chain(PrintProfile::ALL.into_iter().map(Some))
+			.map(|print_profile| {
+				let label = match print_profile {
+					Some(print_profile) => format!("CMYK ({print_profile})"),
+					None => "RGB".to_string(),
+				};
+				MenuListEntry::new(format!("{print_profile:?}"))
+					.label(label)
+					.on_commit(move |_| DocumentMessage::SetPrintProfile { print_profile }.into())
+			})
+			.collect();
+		let selected_index = match self.print_profile {
+			Some(print_profile) => PrintProfile::ALL.iter().position(|&profile| profile == print_profile).map(|index| index as u32 + 1),
+			None => Some(0),
+		};
+
+		vec![
+			LayoutGroup::Row {
+				widgets: vec![TextLabel::new("Color Space").bold(true).widget_holder()],
+			},
+			LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new("Mode").table_align(true).widget_holder(),
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					DropdownInput::new(vec![entries])
+						.selected_index(selected_index)
+						.tooltip("The color space the document is designed for, where CMYK documents are separated into inks for the chosen printing condition")
+						.widget_holder(),
+				],
+			},
+			LayoutGroup::Row {
+				widgets: vec![
+					CheckboxInput::new(self.soft_proof)
+						.tooltip("Show the artwork in the viewport as it would look printed, while exports keep its RGB colors")
+						.disabled(self.print_profile.is_none())
+						.on_update(|optional_input: &CheckboxInput| DocumentMessage::SetSoftProof { soft_proof: optional_input.checked }.into())
+						.widget_holder(),
+					TextLabel::new("Soft Proof")
+						.tooltip("Show the artwork in the viewport as it would look printed, while exports keep its RGB colors")
+						.disabled(self.print_profile.is_none())
+						.widget_holder(),
+				],
+			},
+		]
+	}
+
+	/// The printing condition that the viewport simulates, if soft-proofing is turned on for a CMYK document.
+	pub fn soft_proof_profile(&self) -> Option<PrintProfile> {
+		self.print_profile.filter(|_| self.soft_proof)
+	}
+
+	/// Previews the value of the second input of the node with the given reference in the chain of each selected layer.
+	/// Layers without that node only get it if it's needed to preview a value other than its neutral one, and since it's created with the neutral value, that doesn't change the artwork.
+	fn preview_selected_layers_input(&mut self, reference: &'static str, value: TaggedValue, create_if_nonexistent: bool, responses: &mut VecDeque<Message>) {
+		let layers = self.network_interface.selected_nodes().selected_layers_except_artboards(&self.network_interface).collect::<Vec<_>>();
+		for layer in layers {
+			let Some(mut modify_inputs) = ModifyInputsContext::new_with_layer(layer, &mut self.network_interface, responses) else {
+				continue;
+			};
+			let Some(node_id) = modify_inputs.existing_node_id(reference, create_if_nonexistent) else {
+				continue;
+			};
+
+			responses.add(NodeGraphMessage::SetInputValueProvisional {
+				node_id,
+				input_index: 1,
+				value: value.clone(),
+			});
+		}
+	}
+
 	pub fn update_layers_panel_control_bar_widgets(&self, responses: &mut VecDeque<Message>) {
 		// Get an iterator over the selected layers (excluding artboards which don't have an opacity or blend mode).
 		let selected_nodes = self.network_interface.selected_nodes();
@@ -2259,8 +3280,8 @@ impl DocumentMessageHandler {
 					.map(|&blend_mode| {
 						MenuListEntry::new(format!("{blend_mode:?}"))
 							.label(blend_mode.to_string())
-							.on_update(move |_| DocumentMessage::SetBlendModeForSelectedLayers { blend_mode }.into())
-							.on_commit(|_| DocumentMessage::AddTransaction.into())
+							.on_update(move |_| DocumentMessage::PreviewBlendModeForSelectedLayers { blend_mode }.into())
+							.on_commit(move |_| Message::Batched(Box::new([DocumentMessage::AddTransaction.into(), DocumentMessage::SetBlendModeForSelectedLayers { blend_mode }.into()])))
 					})
 					.collect()
 			})
@@ -2284,6 +3305,7 @@ impl DocumentMessageHandler {
 					.selected_index(blend_mode.and_then(|blend_mode| blend_mode.index_in_list_svg_subset()).map(|index| index as u32))
 					.disabled(disabled)
 					.draw_icon(false)
+					.searchable(true)
 					.widget_holder(),
 				Separator::new(SeparatorType::Related).widget_holder(),
 				NumberInput::new(opacity)
@@ -2296,6 +3318,7 @@ impl DocumentMessageHandler {
 					.range_min(Some(0.))
 					.range_max(Some(100.))
 					.mode_range()
+					.preview_while_dragging(true)
 					.on_update(|number_input: &NumberInput| {
 						if let Some(value) = number_input.value {
 							DocumentMessage::SetOpacityForSelectedLayers { opacity: value / 100. }.into()
@@ -2303,6 +3326,13 @@ impl DocumentMessageHandler {
 							Message::NoOp
 						}
 					})
+					.on_preview(|number_input: &NumberInput| {
+						if let Some(value) = number_input.value {
+							DocumentMessage::PreviewOpacityForSelectedLayers { opacity: value / 100. }.into()
+						} else {
+							Message::NoOp
+						}
+					})
 					.on_commit(|_| DocumentMessage::AddTransaction.into())
 					.widget_holder(),
 				//
@@ -2313,6 +3343,25 @@ impl DocumentMessageHandler {
 					.tooltip_shortcut(action_keys!(DocumentMessageDiscriminant::CreateEmptyFolder))
 					.on_update(|_| DocumentMessage::CreateEmptyFolder.into())
 					.widget_holder(),
+				PopoverButton::new()
+					.icon(Some("NodeColorCorrection".into()))
+					.tooltip("New Adjustment Layer")
+					.popover_layout(
+						[LayoutGroup::Row {
+							widgets: vec![TextLabel::new("New Adjustment Layer").bold(true).widget_holder()],
+						}]
+						.into_iter()
+						.chain(document_node_definitions::ADJUSTMENT_LAYER_NODE_TYPES.into_iter().map(|node_type| LayoutGroup::Row {
+							widgets: vec![
+								TextButton::new(node_type)
+									.flush(true)
+									.on_update(move |_| DocumentMessage::CreateAdjustmentLayer { node_type: node_type.to_string() }.into())
+									.widget_holder(),
+							],
+						}))
+						.collect(),
+					)
+					.widget_holder(),
 				IconButton::new("Folder", 24)
 					.tooltip("Group Selected")
 					.tooltip_shortcut(action_keys!(DocumentMessageDiscriminant::GroupSelectedLayers))
@@ -2484,6 +3533,11 @@ impl DocumentMessageHandler {
 	pub fn graph_view_overlay_open(&self) -> bool {
 		self.graph_view_overlay_open
 	}
+
+	/// Path to the network whose nodes are edited by the Properties panel.
+	pub fn selection_network_path(&self) -> &[NodeId] {
+		&self.selection_network_path
+	}
 }
 
 /// Create a network interface with a single export
@@ -2711,6 +3765,7 @@ impl Iterator for ClickXRayIter<'_> {
 #[cfg(test)]
 mod document_message_handler_tests {
 	use super::*;
+	use crate::messages::portfolio::document::utility_types::misc::TransformEachOptions;
 	use crate::test_utils::test_prelude::*;
 
 	#[tokio::test]
@@ -2859,4 +3914,154 @@ mod document_message_handler_tests {
 		editor.handle_message(DocumentMessage::CreateEmptyFolder).await;
 		assert!(true, "Application didn't crash after folder move operation");
 	}
+
+	#[tokio::test]
+	async fn test_align_to_key_layer() {
+		let mut editor = EditorTestUtils::create();
+		editor.new_document().await;
+		editor.drag_tool(ToolType::Rectangle, 0., 0., 100., 100., ModifierKeys::empty()).await;
+		editor.drag_tool(ToolType::Rectangle, 200., 50., 250., 100., ModifierKeys::empty()).await;
+
+		let layers: Vec<_> = editor.active_document().metadata().all_layers().collect();
+		let key_layer = layers[0];
+		let key_bounds = editor.active_document().metadata().bounding_box_viewport(key_layer).unwrap();
+		editor
+			.handle_message(NodeGraphMessage::SelectedNodesSet {
+				nodes: layers.iter().map(|layer| layer.to_node()).collect(),
+			})
+			.await;
+		editor
+			.handle_message(DocumentMessage::AlignSelectedLayers {
+				axis: AlignAxis::X,
+				aggregate: AlignAggregate::Min,
+				reference: AlignReference::KeyLayer(key_layer),
+			})
+			.await;
+
+		// The key layer stays in place while the other layer moves to its left edge
+		let document = editor.active_document();
+		assert_eq!(document.metadata().bounding_box_viewport(key_layer), Some(key_bounds));
+		let other_bounds = document.metadata().bounding_box_viewport(layers[1]).unwrap();
+		assert!((other_bounds[0].x - key_bounds[0].x).abs() < 1e-6, "Incorrect bounds {other_bounds:?}");
+	}
+
+	#[tokio::test]
+	async fn test_distribute_equal_gaps() {
+		let mut editor = EditorTestUtils::create();
+		editor.new_document().await;
+		editor.drag_tool(ToolType::Rectangle, 0., 0., 100., 100., ModifierKeys::empty()).await;
+		editor.drag_tool(ToolType::Rectangle, 120., 0., 170., 100., ModifierKeys::empty()).await;
+		editor.drag_tool(ToolType::Rectangle, 300., 0., 400., 100., ModifierKeys::empty()).await;
+
+		let layers: Vec<_> = editor.active_document().metadata().all_layers().collect();
+		editor
+			.handle_message(NodeGraphMessage::SelectedNodesSet {
+				nodes: layers.iter().map(|layer| layer.to_node()).collect(),
+			})
+			.await;
+		editor.handle_message(DocumentMessage::DistributeSelectedLayers { axis: AlignAxis::X }).await;
+
+		// The 150 px of empty space between the outer layers is split into two 75 px gaps
+		let document = editor.active_document();
+		let mut bounds: Vec<_> = layers.iter().filter_map(|&layer| document.metadata().bounding_box_viewport(layer)).collect();
+		bounds.sort_by(|a, b| a[0].x.total_cmp(&b[0].x));
+		assert!((bounds[1][0].x - 175.).abs() < 1e-6, "Incorrect bounds {:?}", bounds[1]);
+		assert!((bounds[2][0].x - bounds[1][1].x - 75.).abs() < 1e-6, "Incorrect bounds {:?}", bounds[2]);
+	}
+
+	#[tokio::test]
+	async fn test_transform_each_scales_about_each_pivot() {
+		let mut editor = EditorTestUtils::create();
+		editor.new_document().await;
+		editor.drag_tool(ToolType::Rectangle, 0., 0., 100., 100., ModifierKeys::empty()).await;
+		editor.drag_tool(ToolType::Rectangle, 200., 0., 300., 100., ModifierKeys::empty()).await;
+
+		let layers: Vec<_> = editor.active_document().metadata().all_layers().collect();
+		editor
+			.handle_message(NodeGraphMessage::SelectedNodesSet {
+				nodes: layers.iter().map(|layer| layer.to_node()).collect(),
+			})
+			.await;
+		editor
+			.handle_message(DocumentMessage::TransformEachSelectedLayer {
+				options: TransformEachOptions {
+					scale: DVec2::splat(2.),
+					..Default::default()
+				},
+			})
+			.await;
+
+		// Each rectangle doubles in size about its own center instead of the center of the combined selection
+		let document = editor.active_document();
+		let mut bounds: Vec<_> = layers.iter().filter_map(|&layer| document.metadata().bounding_box_viewport(layer)).collect();
+		bounds.sort_by(|a, b| a[0].x.total_cmp(&b[0].x));
+		assert_eq!(bounds.len(), 2);
+		assert!(bounds[0][0].abs_diff_eq(DVec2::new(-50., -50.), 1e-6), "Incorrect bounds {:?}", bounds[0]);
+		assert!(bounds[0][1].abs_diff_eq(DVec2::new(150., 150.), 1e-6), "Incorrect bounds {:?}", bounds[0]);
+		assert!(bounds[1][0].abs_diff_eq(DVec2::new(150., -50.), 1e-6), "Incorrect bounds {:?}", bounds[1]);
+		assert!(bounds[1][1].abs_diff_eq(DVec2::new(350., 150.), 1e-6), "Incorrect bounds {:?}", bounds[1]);
+	}
+
+	#[tokio::test]
+	async fn test_create_adjustment_layer_above_selection() {
+		let mut editor = EditorTestUtils::create();
+		editor.new_document().await;
+		editor.drag_tool(ToolType::Rectangle, 0., 0., 100., 100., ModifierKeys::empty()).await;
+		let rectangle = editor.active_document().metadata().all_layers().next().unwrap();
+
+		editor.handle_message(NodeGraphMessage::SelectedNodesSet { nodes: vec![rectangle.to_node()] }).await;
+		editor.handle_message(DocumentMessage::CreateAdjustmentLayer { node_type: "Invert".to_string() }).await;
+
+		// The adjustment layer is placed directly above the selected layer in the same stack, and can't contain other layers
+		let document = editor.active_document();
+		let adjustment_layer = document.network_interface.selected_nodes().selected_layers(document.metadata()).next().unwrap();
+		assert!(document.network_interface.is_adjustment_layer(&adjustment_layer.to_node(), &[]));
+		assert!(!adjustment_layer.has_children(document.metadata()));
+		assert_eq!(adjustment_layer.next_sibling(document.metadata()), Some(rectangle));
+	}
+
+	#[tokio::test]
+	async fn test_flip_view_mirrors_about_viewport_center() {
+		let mut editor = EditorTestUtils::create();
+		editor.new_document().await;
+		let unflipped = editor.active_document().metadata().document_to_viewport;
+
+		editor.handle_message(NavigationMessage::CanvasFlip).await;
+		let flipped = editor.active_document().metadata().document_to_viewport;
+
+		// Every point keeps its height and moves to the opposite side of the same vertical line through the viewport center
+		let points = [DVec2::new(0., 0.), DVec2::new(100., 50.), DVec2::new(-30., 200.)];
+		let mirror_line_x = unflipped.transform_point2(points[0]).x + flipped.transform_point2(points[0]).x;
+		for point in points {
+			let (unflipped, flipped) = (unflipped.transform_point2(point), flipped.transform_point2(point));
+			assert!((unflipped.x + flipped.x - mirror_line_x).abs() < 1e-6, "Point {point} is not mirrored");
+			assert!((unflipped.y - flipped.y).abs() < 1e-6, "Point {point} changed height");
+		}
+
+		// Flipping again restores the original view
+		editor.handle_message(NavigationMessage::CanvasFlip).await;
+		assert!(editor.active_document().metadata().document_to_viewport.abs_diff_eq(unflipped, 1e-6));
+	}
+
+	#[tokio::test]
+	async fn test_pixel_snapping_rounds_drawn_shape_to_whole_pixels() {
+		let mut editor = EditorTestUtils::create();
+		editor.new_document().await;
+		editor
+			.handle_message(DocumentMessage::SetSnapping {
+				closure: Some(|snapping_state| &mut snapping_state.pixel_snapping),
+				snapping_state: true,
+			})
+			.await;
+		editor.drag_tool(ToolType::Rectangle, 10.3, 10.4, 60.6, 30.2, ModifierKeys::empty()).await;
+
+		let document = editor.active_document();
+		let rectangle = document.metadata().all_layers().next().unwrap();
+		let vector_data = document.network_interface.compute_modified_vector(rectangle).unwrap();
+		let transform = document.metadata().transform_to_document(rectangle);
+		for point in vector_data.point_domain.positions() {
+			let point = transform.transform_point2(*point);
+			assert!(point.abs_diff_eq(point.round(), 1e-6), "Corner {point} isn't on a whole pixel");
+		}
+	}
 }
@@ -57,6 +57,10 @@ pub enum GraphOperationMessage {
 		layer: LayerNodeIdentifier,
 		strokes: Vec<BrushStroke>,
 	},
+	BrushStrokes {
+		layer: LayerNodeIdentifier,
+		strokes: Vec<BrushStroke>,
+	},
 	SetUpstreamToChain {
 		layer: LayerNodeIdentifier,
 	},
@@ -64,6 +68,12 @@ pub enum GraphOperationMessage {
 		id: NodeId,
 		artboard: Artboard,
 	},
+	NewAdjustmentLayer {
+		id: NodeId,
+		node_type: String,
+		parent: LayerNodeIdentifier,
+		insert_index: usize,
+	},
 	NewBitmapLayer {
 		id: NodeId,
 		image_frame: ImageFrameTable<Color>,
@@ -10,7 +10,7 @@ use graph_craft::document::{NodeId, NodeInput};
 use graphene_core::Color;
 use graphene_core::renderer::Quad;
 use graphene_core::text::{Font, TypesettingConfig};
-use graphene_core::vector::style::{Fill, Gradient, GradientStops, GradientType, LineCap, LineJoin, Stroke};
+use graphene_core::vector::style::{Fill, Gradient, GradientStops, GradientType, LineCap, LineJoin, PaintOrder, Stroke, StrokeAlign, StrokeMarker};
 use graphene_std::vector::convert_usvg_path;
 
 #[derive(Debug, Clone)]
@@ -100,6 +100,11 @@ impl MessageHandler<GraphOperationMessage, GraphOperationMessageData<'_>> for Gr
 					modify_inputs.brush_modify(strokes);
 				}
 			}
+			GraphOperationMessage::BrushStrokes { layer, strokes } => {
+				if let Some(mut modify_inputs) = ModifyInputsContext::new_with_layer(layer, network_interface, responses) {
+					modify_inputs.brush_strokes_modify(strokes);
+				}
+			}
 			GraphOperationMessage::SetUpstreamToChain { layer } => {
 				let Some(OutputConnector::Node { node_id: first_chain_node, .. }) = network_interface.upstream_output_connector(&InputConnector::node(layer.to_node(), 1), &[]) else {
 					return;
@@ -130,6 +135,12 @@ impl MessageHandler<GraphOperationMessage, GraphOperationMessageData<'_>> for Gr
 				responses.add_front(NodeGraphMessage::SelectedNodesSet { nodes: vec![id] });
 				responses.add(NodeGraphMessage::RunDocumentGraph);
 			}
+			GraphOperationMessage::NewAdjustmentLayer { id, node_type, parent, insert_index } => {
+				let mut modify_inputs = ModifyInputsContext::new(network_interface, responses);
+				let Some(layer) = modify_inputs.create_adjustment_layer(id, &node_type) else { return };
+				network_interface.move_layer_to_stack(layer, parent, insert_index, &[]);
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+			}
 			GraphOperationMessage::NewBitmapLayer {
 				id,
 				image_frame,
@@ -373,6 +384,8 @@ fn apply_usvg_stroke(stroke: &usvg::Stroke, modify_inputs: &mut ModifyInputsCont
 			weight: stroke.width().get() as f64,
 			dash_lengths: stroke.dasharray().as_ref().map(|lengths| lengths.iter().map(|&length| length as f64).collect()).unwrap_or_default(),
 			dash_offset: stroke.dashoffset() as f64,
+			dash_align_corners: false,
+			dash_fit_length: false,
 			line_cap: match stroke.linecap() {
 				usvg::LineCap::Butt => LineCap::Butt,
 				usvg::LineCap::Round => LineCap::Round,
@@ -385,6 +398,11 @@ fn apply_usvg_stroke(stroke: &usvg::Stroke, modify_inputs: &mut ModifyInputsCont
 				usvg::LineJoin::Bevel => LineJoin::Bevel,
 			},
 			line_join_miter_limit: stroke.miterlimit().get() as f64,
+			align: StrokeAlign::Center,
+			paint_order: PaintOrder::StrokeAbove,
+			start_marker: StrokeMarker::None,
+			mid_marker: StrokeMarker::None,
+			end_marker: StrokeMarker::None,
 			transform,
 			non_scaling: false,
 		})
@@ -120,6 +120,19 @@ impl<'a> ModifyInputsContext<'a> {
 		LayerNodeIdentifier::new(new_id, self.network_interface, &[])
 	}
 
+	/// Creates an adjustment layer, which is the adjustment node itself displayed as a layer so its primary input receives the graphic group of the layers below it in the stack
+	pub fn create_adjustment_layer(&mut self, new_id: NodeId, node_type: &str) -> Option<LayerNodeIdentifier> {
+		let Some(definition) = resolve_document_node_type(node_type) else {
+			log::error!("Adjustment node '{node_type}' does not exist");
+			return None;
+		};
+		let mut node_template = definition.node_template_input_override([Some(NodeInput::value(TaggedValue::GraphicGroup(GraphicGroupTable::default()), true))]);
+		node_template.persistent_node_metadata.node_type_metadata = network_interface::NodeTypePersistentMetadata::layer(IVec2::new(0, 0));
+
+		self.network_interface.insert_node(new_id, node_template, &[]);
+		Some(LayerNodeIdentifier::new(new_id, self.network_interface, &[]))
+	}
+
 	/// Creates an artboard as the primary export for the document network
 	pub fn create_artboard(&mut self, new_id: NodeId, artboard: Artboard) -> LayerNodeIdentifier {
 		let artboard_node_template = resolve_document_node_type("Artboard").expect("Node").node_template_input_override([
@@ -129,6 +142,8 @@ impl<'a> ModifyInputsContext<'a> {
 			Some(NodeInput::value(TaggedValue::IVec2(artboard.dimensions), false)),
 			Some(NodeInput::value(TaggedValue::Color(artboard.background), false)),
 			Some(NodeInput::value(TaggedValue::Bool(artboard.clip), false)),
+			Some(NodeInput::value(TaggedValue::F64(artboard.bleed), false)),
+			Some(NodeInput::value(TaggedValue::F64(artboard.margin), false)),
 		]);
 		self.network_interface.insert_node(new_id, artboard_node_template, &[]);
 		LayerNodeIdentifier::new(new_id, self.network_interface, &[])
@@ -190,6 +205,8 @@ impl<'a> ModifyInputsContext<'a> {
 			Some(NodeInput::value(TaggedValue::F64(typesetting.character_spacing), false)),
 			Some(NodeInput::value(TaggedValue::OptionalF64(typesetting.max_width), false)),
 			Some(NodeInput::value(TaggedValue::OptionalF64(typesetting.max_height), false)),
+			Some(NodeInput::value(TaggedValue::TextAlign(typesetting.align), false)),
+			Some(NodeInput::value(TaggedValue::VerticalAlign(typesetting.vertical_align), false)),
 		]);
 
 		let text_id = NodeId::new();
@@ -328,6 +345,8 @@ impl<'a> ModifyInputsContext<'a> {
 				let input_connector = InputConnector::node(fill_node_id, backup_gradient_index);
 				self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::Gradient(gradient.clone()), false), true);
 			}
+			// The pattern's tile graphic is fed into its own input of the Fill node
+			Fill::Pattern(_) => {}
 		}
 		let input_connector = InputConnector::node(fill_node_id, fill_index);
 		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::Fill(fill), false), false);
@@ -355,15 +374,29 @@ impl<'a> ModifyInputsContext<'a> {
 		let input_connector = InputConnector::node(stroke_node_id, 2);
 		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::F64(stroke.weight), false), true);
 		let input_connector = InputConnector::node(stroke_node_id, 3);
-		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::VecF64(stroke.dash_lengths), false), true);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::StrokeAlign(stroke.align), false), true);
 		let input_connector = InputConnector::node(stroke_node_id, 4);
-		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::F64(stroke.dash_offset), false), true);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::VecF64(stroke.dash_lengths), false), true);
 		let input_connector = InputConnector::node(stroke_node_id, 5);
-		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::LineCap(stroke.line_cap), false), true);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::F64(stroke.dash_offset), false), true);
 		let input_connector = InputConnector::node(stroke_node_id, 6);
-		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::LineJoin(stroke.line_join), false), true);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::Bool(stroke.dash_align_corners), false), true);
 		let input_connector = InputConnector::node(stroke_node_id, 7);
-		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::F64(stroke.line_join_miter_limit), false), false);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::Bool(stroke.dash_fit_length), false), true);
+		let input_connector = InputConnector::node(stroke_node_id, 8);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::LineCap(stroke.line_cap), false), true);
+		let input_connector = InputConnector::node(stroke_node_id, 9);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::LineJoin(stroke.line_join), false), true);
+		let input_connector = InputConnector::node(stroke_node_id, 10);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::F64(stroke.line_join_miter_limit), false), true);
+		let input_connector = InputConnector::node(stroke_node_id, 11);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::PaintOrder(stroke.paint_order), false), true);
+		let input_connector = InputConnector::node(stroke_node_id, 12);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::StrokeMarker(stroke.start_marker), false), true);
+		let input_connector = InputConnector::node(stroke_node_id, 13);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::StrokeMarker(stroke.mid_marker), false), true);
+		let input_connector = InputConnector::node(stroke_node_id, 14);
+		self.set_input_with_refresh(input_connector, NodeInput::value(TaggedValue::StrokeMarker(stroke.end_marker), false), false);
 	}
 
 	/// Update the transform value of the upstream Transform node based a change to its existing value and the given parent transform.
@@ -456,6 +489,11 @@ impl<'a> ModifyInputsContext<'a> {
 		self.set_input_with_refresh(InputConnector::node(brush_node_id, 2), NodeInput::value(TaggedValue::BrushStrokes(strokes), false), false);
 	}
 
+	pub fn brush_strokes_modify(&mut self, strokes: Vec<BrushStroke>) {
+		let Some(brush_strokes_node_id) = self.existing_node_id("Brush Strokes", true) else { return };
+		self.set_input_with_refresh(InputConnector::node(brush_strokes_node_id, 1), NodeInput::value(TaggedValue::BrushStrokes(strokes), false), false);
+	}
+
 	pub fn resize_artboard(&mut self, location: IVec2, dimensions: IVec2) {
 		let Some(artboard_node_id) = self.existing_node_id("Artboard", true) else {
 			return;
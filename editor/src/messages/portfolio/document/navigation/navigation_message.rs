@@ -9,11 +9,16 @@ pub enum NavigationMessage {
 	BeginCanvasPan,
 	BeginCanvasTilt { was_dispatched_from_menu: bool },
 	BeginCanvasZoom,
+	CanvasFlip,
 	CanvasPan { delta: DVec2 },
 	CanvasPanAbortPrepare { x_not_y_axis: bool },
 	CanvasPanAbort { x_not_y_axis: bool },
 	CanvasPanByViewportFraction { delta: DVec2 },
 	CanvasPanMouseWheel { use_y_as_x: bool },
+	CanvasTiltBy { angle_radians: f64 },
+	CanvasTiltClockwise,
+	CanvasTiltCounterclockwise,
+	CanvasTiltMouseWheel,
 	CanvasTiltResetAndZoomTo100Percent,
 	CanvasTiltSet { angle_radians: f64 },
 	CanvasZoomDecrease { center_on_mouse: bool },
@@ -1,6 +1,6 @@
 use crate::consts::{
-	VIEWPORT_ROTATE_SNAP_INTERVAL, VIEWPORT_SCROLL_RATE, VIEWPORT_ZOOM_LEVELS, VIEWPORT_ZOOM_MIN_FRACTION_COVER, VIEWPORT_ZOOM_MOUSE_RATE, VIEWPORT_ZOOM_SCALE_MAX, VIEWPORT_ZOOM_SCALE_MIN,
-	VIEWPORT_ZOOM_TO_FIT_PADDING_SCALE_FACTOR,
+	VIEWPORT_ROTATE_SNAP_INTERVAL, VIEWPORT_SCROLL_RATE, VIEWPORT_TILT_WHEEL_RATE, VIEWPORT_ZOOM_LEVELS, VIEWPORT_ZOOM_MIN_FRACTION_COVER, VIEWPORT_ZOOM_MOUSE_RATE, VIEWPORT_ZOOM_SCALE_MAX,
+	VIEWPORT_ZOOM_SCALE_MIN, VIEWPORT_ZOOM_TO_FIT_PADDING_SCALE_FACTOR,
 };
 use crate::messages::frontend::utility_types::MouseCursorIcon;
 use crate::messages::input_mapper::utility_types::input_keyboard::{Key, MouseMotion};
@@ -130,6 +130,18 @@ impl MessageHandler<NavigationMessage, NavigationMessageData<'_>> for Navigation
 				};
 				self.mouse_position = ipp.mouse.position;
 			}
+			NavigationMessage::CanvasFlip => {
+				// Flipping only applies to the canvas, not the node graph
+				if graph_view_overlay_open {
+					return;
+				}
+
+				document_ptz.set_flip(!document_ptz.flip());
+				responses.add(DocumentMessage::PTZUpdate);
+				responses.add(BroadcastEvent::CanvasTransformed);
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+				responses.add(MenuBarMessage::SendLayout);
+			}
 			NavigationMessage::CanvasPan { delta } => {
 				let Some(ptz) = get_ptz_mut(document_ptz, network_interface, graph_view_overlay_open, breadcrumb_network_path) else {
 					log::error!("Could not get PTZ in CanvasPan");
@@ -180,6 +192,36 @@ impl MessageHandler<NavigationMessage, NavigationMessageData<'_>> for Navigation
 				responses.add(NavigationMessage::CanvasPan { delta });
 				responses.add(NodeGraphMessage::SetGridAlignedEdges);
 			}
+			NavigationMessage::CanvasTiltBy { angle_radians } => {
+				if graph_view_overlay_open {
+					return;
+				}
+
+				// The angle is as seen in the viewport, which turns the opposite way in document space while the view is flipped
+				let angle_radians = if document_ptz.flip() { -angle_radians } else { angle_radians };
+				responses.add(NavigationMessage::CanvasTiltSet {
+					angle_radians: document_ptz.tilt() + angle_radians,
+				});
+			}
+			NavigationMessage::CanvasTiltClockwise | NavigationMessage::CanvasTiltCounterclockwise => {
+				if graph_view_overlay_open {
+					return;
+				}
+
+				// Step to the next snap interval so an arbitrary tilt angle lines back up with the increments
+				let increment_radians = VIEWPORT_ROTATE_SNAP_INTERVAL.to_radians();
+				let one = 1. + f64::EPSILON * 100.;
+				let increments = document_ptz.tilt() / increment_radians;
+				let clockwise = matches!(message, NavigationMessage::CanvasTiltClockwise) != document_ptz.flip();
+				let increments = if clockwise { (increments + one).floor() } else { (increments - one).ceil() };
+				responses.add(NavigationMessage::CanvasTiltSet {
+					angle_radians: increments * increment_radians,
+				});
+			}
+			NavigationMessage::CanvasTiltMouseWheel => {
+				let angle_radians = (ipp.mouse.scroll_delta.y as f64 * VIEWPORT_TILT_WHEEL_RATE).to_radians();
+				responses.add(NavigationMessage::CanvasTiltBy { angle_radians });
+			}
 			NavigationMessage::CanvasTiltResetAndZoomTo100Percent => {
 				let Some(ptz) = get_ptz_mut(document_ptz, network_interface, graph_view_overlay_open, breadcrumb_network_path) else {
 					log::error!("Could not get mutable PTZ in CanvasTiltResetAndZoomTo100Percent");
@@ -397,6 +439,7 @@ impl MessageHandler<NavigationMessage, NavigationMessageData<'_>> for Navigation
 							let start_offset = self.mouse_position - half_viewport;
 							let end_offset = ipp.mouse.position - half_viewport;
 							let angle = start_offset.angle_to(end_offset);
+							let angle = if document_ptz.flip() { -angle } else { angle };
 
 							tilt_raw_not_snapped + angle
 						};
@@ -464,9 +507,13 @@ impl MessageHandler<NavigationMessage, NavigationMessageData<'_>> for Navigation
 			BeginCanvasPan,
 			BeginCanvasTilt,
 			BeginCanvasZoom,
+			CanvasFlip,
 			CanvasPan,
 			CanvasPanByViewportFraction,
 			CanvasPanMouseWheel,
+			CanvasTiltClockwise,
+			CanvasTiltCounterclockwise,
+			CanvasTiltMouseWheel,
 			CanvasTiltSet,
 			CanvasZoomDecrease,
 			CanvasZoomIncrease,
@@ -522,9 +569,10 @@ impl NavigationMessageHandler {
 		// TODO: replace with DAffine2::from_scale_angle_translation and fix the errors
 		let offset_transform = DAffine2::from_translation(scaled_center);
 		let scale_transform = DAffine2::from_scale(DVec2::splat(scale));
+		let flip_transform = DAffine2::from_scale(DVec2::new(if ptz.flip() { -1. } else { 1. }, 1.));
 		let angle_transform = DAffine2::from_angle(self.snapped_tilt(tilt));
 		let translation_transform = DAffine2::from_translation(rounded_pan);
-		scale_transform * offset_transform * angle_transform * translation_transform
+		scale_transform * offset_transform * flip_transform * angle_transform * translation_transform
 	}
 
 	pub fn center_zoom(&self, viewport_bounds: DVec2, zoom_factor: f64, mouse: DVec2) -> Message {
@@ -0,0 +1,164 @@
+use super::node_properties::add_blank_assist;
+use crate::messages::layout::utility_types::widget_prelude::*;
+use crate::messages::prelude::*;
+use graph_craft::document::value::TaggedValue;
+use graph_craft::document::{DocumentNode, NodeId};
+use std::ops::Range;
+
+/// A named set of input values for an adjustment node, which are applied together when it's chosen from the presets dropdown in the node's properties.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct AdjustmentPreset {
+	pub name: String,
+	/// The identifier of the node definition whose inputs this preset sets.
+	pub reference: String,
+	/// The index of each input set by this preset, and the value it's set to.
+	#[specta(skip)]
+	pub values: Vec<(usize, TaggedValue)>,
+}
+
+impl AdjustmentPreset {
+	fn new(name: &str, reference: &str, values: impl IntoIterator<Item = (usize, TaggedValue)>) -> Self {
+		Self {
+			name: name.to_string(),
+			reference: reference.to_string(),
+			values: values.into_iter().collect(),
+		}
+	}
+
+	/// Whether the node's inputs are currently set to the values of this preset.
+	fn matches(&self, document_node: &DocumentNode) -> bool {
+		self.values
+			.iter()
+			.all(|(index, value)| document_node.inputs.get(*index).and_then(|input| input.as_non_exposed_value()) == Some(value))
+	}
+}
+
+/// Offers the built-in presets of an adjustment node along with those saved by the user in their preferences, and saves the node's current values as a new preset.
+pub struct PresetManager<'a> {
+	reference: &'a str,
+	/// The inputs whose values make up a preset of this node, leaving out those like channel choices which only change which values are shown.
+	inputs: Range<usize>,
+	built_in: Vec<AdjustmentPreset>,
+	saved: Vec<&'a AdjustmentPreset>,
+}
+
+impl<'a> PresetManager<'a> {
+	pub fn new(reference: &'a str, preferences: &'a PreferencesMessageHandler) -> Self {
+		let (inputs, built_in) = built_in_presets(reference);
+		Self {
+			reference,
+			inputs,
+			built_in,
+			saved: preferences.adjustment_presets.iter().filter(|preset| preset.reference == reference).collect(),
+		}
+	}
+
+	/// The row with the presets dropdown, which shows the preset matching the node's current values, and buttons for saving the current values as a preset or deleting the chosen saved preset.
+	pub fn presets_widget(&self, document_node: &DocumentNode, node_id: NodeId) -> LayoutGroup {
+		let mut widgets = vec![TextLabel::new("Presets").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
+		add_blank_assist(&mut widgets);
+
+		let entry = |preset: &AdjustmentPreset| {
+			let values = preset.values.clone();
+			MenuListEntry::new(preset.name.clone()).label(preset.name.clone()).on_update(move |_| {
+				let set_values = values.iter().map(|(input_index, value)| {
+					NodeGraphMessage::SetInputValue {
+						node_id,
+						input_index: *input_index,
+						value: value.clone(),
+					}
+					.into()
+				});
+				Message::Batched(set_values.chain([DocumentMessage::AddTransaction.into()]).collect())
+			})
+		};
+		let mut entries = vec![self.built_in.iter().map(entry).collect::<Vec<_>>()];
+		if !self.saved.is_empty() {
+			entries.push(self.saved.iter().copied().map(entry).collect());
+		}
+
+		let mut presets = self.built_in.iter().chain(self.saved.iter().copied());
+		let selected_index = presets.position(|preset| preset.matches(document_node));
+		let selected_saved = selected_index.and_then(|index| index.checked_sub(self.built_in.len())).map(|index| self.saved[index]);
+
+		let new_preset = AdjustmentPreset::new(&self.unused_name(), self.reference, self.current_values(document_node));
+		let deleted_name = selected_saved.map(|preset| preset.name.clone()).unwrap_or_default();
+		let reference = self.reference.to_string();
+
+		widgets.extend([
+			DropdownInput::new(entries).selected_index(selected_index.map(|index| index as u32)).widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			IconButton::new("Save", 16)
+				.tooltip("Save the Current Values as a Preset")
+				.disabled(selected_index.is_some())
+				.on_update(move |_| PreferencesMessage::SaveAdjustmentPreset { preset: new_preset.clone() }.into())
+				.widget_holder(),
+			IconButton::new("Trash", 16)
+				.tooltip("Delete the Chosen Saved Preset")
+				.disabled(selected_saved.is_none())
+				.on_update(move |_| {
+					PreferencesMessage::DeleteAdjustmentPreset {
+						reference: reference.clone(),
+						name: deleted_name.clone(),
+					}
+					.into()
+				})
+				.widget_holder(),
+		]);
+
+		LayoutGroup::Row { widgets }
+	}
+
+	/// The values of the node's preset inputs which aren't exposed in the graph.
+	fn current_values(&self, document_node: &DocumentNode) -> Vec<(usize, TaggedValue)> {
+		(self.inputs.start..self.inputs.end.min(document_node.inputs.len()))
+			.filter_map(|index| Some((index, document_node.inputs.get(index)?.as_non_exposed_value()?.clone())))
+			.collect()
+	}
+
+	/// The first name of the form "Custom N" which isn't taken by a saved preset of this node.
+	fn unused_name(&self) -> String {
+		(1..)
+			.map(|number| format!("Custom {number}"))
+			.find(|name| self.saved.iter().all(|preset| preset.name != *name))
+			.unwrap_or_default()
+	}
+}
+
+/// The range of inputs that presets of the given node definition set, and the presets that come with Graphite for it in the order they're listed in its presets dropdown.
+fn built_in_presets(reference: &str) -> (Range<usize>, Vec<AdjustmentPreset>) {
+	match reference {
+		// The output channel choice is left out, since it only changes which of the channel mixes is shown
+		"Channel Mixer" => (1..18, channel_mixer_presets()),
+		_ => (1..usize::MAX, Vec::new()),
+	}
+}
+
+fn channel_mixer_presets() -> Vec<AdjustmentPreset> {
+	// The percentages of the red, green, and blue channels mixed into the output, and a constant added to it
+	type Mix = [f64; 4];
+	const RED: Mix = [100., 0., 0., 0.];
+	const GREEN: Mix = [0., 100., 0., 0.];
+	const BLUE: Mix = [0., 0., 100., 0.];
+
+	let preset = |name: &str, monochrome: Option<Mix>, [red, green, blue]: [Mix; 3]| {
+		// Monochrome presets leave the color channel mixes at their defaults, and vice versa
+		let monochrome_mix = monochrome.unwrap_or([40., 40., 20., 0.]);
+		let mixes = [monochrome_mix, red, green, blue].into_iter().flatten().map(TaggedValue::F64);
+		let values = [(1, TaggedValue::Bool(monochrome.is_some()))].into_iter().chain((2..).zip(mixes));
+		AdjustmentPreset::new(name, "Channel Mixer", values)
+	};
+	let monochrome = |name: &str, mix: Mix| preset(name, Some(mix), [RED, GREEN, BLUE]);
+
+	vec![
+		preset("Default", None, [RED, GREEN, BLUE]),
+		monochrome("Black & White (Red Filter)", [100., 0., 0., 0.]),
+		monochrome("Black & White (Orange Filter)", [50., 50., 0., 0.]),
+		monochrome("Black & White (Yellow Filter)", [34., 66., 0., 0.]),
+		monochrome("Black & White (Green Filter)", [0., 100., 0., 0.]),
+		monochrome("Black & White (Blue Filter)", [0., 0., 100., 0.]),
+		monochrome("Black & White (Infrared)", [-70., 200., -30., 0.]),
+		preset("Swap Red and Blue", None, [BLUE, GREEN, RED]),
+		preset("Swap Red and Green", None, [GREEN, RED, BLUE]),
+	]
+}
@@ -1,19 +1,26 @@
 use super::node_properties;
+use super::node_versions;
 use super::utility_types::FrontendNodeType;
 use crate::messages::layout::utility_types::widget_prelude::*;
+use crate::messages::portfolio::document::utility_types::drivers::Drivers;
+use crate::messages::portfolio::document::utility_types::keyframes::Keyframes;
+use crate::messages::portfolio::document::utility_types::misc::{LengthUnit, PreviewQuality};
 use crate::messages::portfolio::document::utility_types::network_interface::{
 	DocumentNodeMetadata, DocumentNodePersistentMetadata, NodeNetworkInterface, NodeNetworkMetadata, NodeNetworkPersistentMetadata, NodeTemplate, NodeTypePersistentMetadata, NumberInputSettings,
 	PropertiesRow, Vec2InputSettings, WidgetOverride,
 };
 use crate::messages::portfolio::utility_types::PersistentData;
-use crate::messages::prelude::Message;
+use crate::messages::prelude::{Message, PreferencesMessageHandler};
 use crate::node_graph_executor::NodeGraphExecutor;
 use glam::DVec2;
 use graph_craft::ProtoNodeIdentifier;
 use graph_craft::concrete;
 use graph_craft::document::value::*;
 use graph_craft::document::*;
+use graphene_core::application_io::FilePath;
 use graphene_core::raster::brush_cache::BrushCache;
+use graphene_core::raster::curve::CurveChannel;
+use graphene_core::raster::histogram::Histogram;
 use graphene_core::raster::image::ImageFrameTable;
 use graphene_core::raster::{Color, RedGreenBlue, RedGreenBlueAlpha};
 use graphene_core::text::{Font, TypesettingConfig};
@@ -32,6 +39,26 @@ pub struct NodePropertiesContext<'a> {
 	pub network_interface: &'a mut NodeNetworkInterface,
 	pub selection_network_path: &'a [NodeId],
 	pub document_name: &'a str,
+	/// The unit that lengths are shown and entered in, as chosen for the document.
+	pub length_unit: LengthUnit,
+	/// How much detail expensive nodes are computed with in the viewport, as chosen in the document's render settings.
+	pub preview_quality: PreviewQuality,
+	/// The animated inputs of the document, used to show whether each input has a keyframe at the current time.
+	pub keyframes: &'a Keyframes,
+	/// The expressions driving node inputs, shown below the rows of the inputs they drive.
+	pub drivers: &'a Drivers,
+	/// The node input whose position is being picked in the viewport, whose pick button is shown as active.
+	pub position_picker_target: Option<(NodeId, usize)>,
+	/// The node most recently introspected for the histogram shown above the properties of tonal adjustment nodes, and the histogram of its output.
+	pub inspected_histogram: Option<&'a (NodeId, Option<Histogram>)>,
+	/// The node whose output is previewed in the properties of the selected node, and its SVG thumbnail once it has been rendered.
+	pub vector_preview: Option<&'a (NodeId, Option<String>)>,
+	/// The Noise Pattern node most recently rendered for the preview shown in its properties, and the SVG of its output.
+	pub noise_preview: Option<&'a (NodeId, String)>,
+	/// The channel whose curve is shown for editing in each Curves input, by node and input index.
+	pub curve_channels: &'a HashMap<(NodeId, usize), CurveChannel>,
+	/// Holds the presets saved by the user for adjustment nodes.
+	pub preferences: &'a PreferencesMessageHandler,
 }
 
 impl NodePropertiesContext<'_> {
@@ -128,6 +155,26 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 			description: Cow::Borrowed("The identity node passes its data through. You can use this to organize your node graph."),
 			properties: Some("identity_properties"),
 		},
+		DocumentNodeDefinition {
+			identifier: "Reroute",
+			category: "General",
+			node_template: NodeTemplate {
+				document_node: DocumentNode {
+					implementation: DocumentNodeImplementation::proto("graphene_core::ops::IdentityNode"),
+					inputs: vec![NodeInput::value(TaggedValue::None, true)],
+					..Default::default()
+				},
+				persistent_node_metadata: DocumentNodePersistentMetadata {
+					input_properties: vec![("In", "TODO").into()],
+					output_names: vec!["Out".to_string()],
+					..Default::default()
+				},
+			},
+			description: Cow::Borrowed(
+				"A point which a wire is routed through, to lay out wires cleanly. Double click a wire to insert one. It passes its data through unchanged, and is removed from the graph when it's compiled.",
+			),
+			properties: None,
+		},
 		// TODO: Auto-generate this from its proto node macro
 		DocumentNodeDefinition {
 			identifier: "Monitor",
@@ -275,6 +322,8 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 									NodeInput::network(concrete!(TaggedValue), 3),
 									NodeInput::network(concrete!(TaggedValue), 4),
 									NodeInput::network(concrete!(TaggedValue), 5),
+									NodeInput::network(concrete!(TaggedValue), 6),
+									NodeInput::network(concrete!(TaggedValue), 7),
 								],
 								..Default::default()
 							},
@@ -311,6 +360,8 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 						NodeInput::value(TaggedValue::IVec2(glam::IVec2::new(1920, 1080)), false),
 						NodeInput::value(TaggedValue::Color(Color::WHITE), false),
 						NodeInput::value(TaggedValue::Bool(false), false),
+						NodeInput::value(TaggedValue::F64(0.), false),
+						NodeInput::value(TaggedValue::F64(0.), false),
 					],
 					..Default::default()
 				},
@@ -340,6 +391,24 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 						),
 						PropertiesRow::with_override("Background", "TODO", WidgetOverride::Custom("artboard_background".to_string())),
 						("Clip", "TODO").into(),
+						PropertiesRow::with_override(
+							"Bleed",
+							"Distance the background extends past the edges of the artboard, so artwork reaching the edges can be trimmed after printing. It's left out of exports unless included in the export dialog.",
+							WidgetOverride::Number(NumberInputSettings {
+								min: Some(0.),
+								unit: Some(" px".to_string()),
+								..Default::default()
+							}),
+						),
+						PropertiesRow::with_override(
+							"Margin",
+							"Distance inset from the edges of the artboard marking the safe area that important artwork should stay within. It's shown as a guide in the viewport but never exported.",
+							WidgetOverride::Number(NumberInputSettings {
+								min: Some(0.),
+								unit: Some(" px".to_string()),
+								..Default::default()
+							}),
+						),
 					],
 					output_names: vec!["Out".to_string()],
 					node_type_metadata: NodeTypePersistentMetadata::layer(IVec2::new(0, 0)),
@@ -394,7 +463,7 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 						exports: vec![NodeInput::node(NodeId(2), 0)],
 						nodes: [
 							DocumentNode {
-								inputs: vec![NodeInput::value(TaggedValue::None, false), NodeInput::scope("editor-api"), NodeInput::network(concrete!(String), 1)],
+								inputs: vec![NodeInput::value(TaggedValue::None, false), NodeInput::scope("editor-api"), NodeInput::network(concrete!(FilePath), 1)],
 								manual_composition: Some(concrete!(Context)),
 								implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_std::wasm_application_io::LoadResourceNode")),
 								..Default::default()
@@ -418,11 +487,21 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 						.collect(),
 						..Default::default()
 					}),
-					inputs: vec![NodeInput::value(TaggedValue::None, false), NodeInput::value(TaggedValue::String("graphite:null".to_string()), false)],
+					inputs: vec![
+						NodeInput::value(TaggedValue::None, false),
+						NodeInput::value(TaggedValue::FilePath(FilePath("graphite:null".to_string())), false),
+					],
 					..Default::default()
 				},
 				persistent_node_metadata: DocumentNodePersistentMetadata {
-					input_properties: vec![("Empty", "TODO").into(), ("URL", "TODO").into()],
+					input_properties: vec![
+						("Empty", "TODO").into(),
+						PropertiesRow::with_override(
+							"URL",
+							"The location of the image file, or browse for an image file to embed in the document.",
+							WidgetOverride::Custom("image_file_path".to_string()),
+						),
+					],
 					output_names: vec!["Image".to_string()],
 					network_metadata: Some(NodeNetworkMetadata {
 						persistent_metadata: NodeNetworkPersistentMetadata {
@@ -1941,31 +2020,6 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 			description: Cow::Borrowed("TODO"),
 			properties: None,
 		},
-		// Aims for interoperable compatibility with:
-		// https://www.adobe.com/devnet-apps/photoshop/fileformatashtml/#:~:text=levl%27%20%3D%20Levels-,%27curv%27%20%3D%20Curves,-%27expA%27%20%3D%20Exposure
-		// https://www.adobe.com/devnet-apps/photoshop/fileformatashtml/#:~:text=Max%20input%20range-,Curves,-Curves%20settings%20files
-		// TODO: Fix this, it's currently broken
-		// DocumentNodeDefinition {
-		// 	identifier: "Curves",
-		// 	category: "Raster: Adjustment",
-		// 	node_template: NodeTemplate {
-		// 		document_node: DocumentNode {
-		// 			implementation: DocumentNodeImplementation::proto("graphene_core::raster::CurvesNode"),
-		// 			inputs: vec![
-		// 				NodeInput::value(TaggedValue::ImageFrame(ImageFrameTable::empty()), true),
-		// 				NodeInput::value(TaggedValue::Curve(Default::default()), false),
-		// 			],
-		// 			..Default::default()
-		// 		},
-		// 		persistent_node_metadata: DocumentNodePersistentMetadata {
-		// 			input_properties: vec![("Image", "TODO").into(), ("Curve", "TODO").into()],
-		// 			output_names: vec!["Image".to_string()],
-		// 			..Default::default()
-		// 		},
-		// 	},
-		// 	description: Cow::Borrowed("TODO"),
-		// 	properties: None,
-		// },
 		// (*IMAGINATE_NODE).clone(),
 		DocumentNodeDefinition {
 			identifier: "Line",
@@ -2102,6 +2156,8 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 						NodeInput::value(TaggedValue::F64(TypesettingConfig::default().character_spacing), false),
 						NodeInput::value(TaggedValue::OptionalF64(TypesettingConfig::default().max_width), false),
 						NodeInput::value(TaggedValue::OptionalF64(TypesettingConfig::default().max_height), false),
+						NodeInput::value(TaggedValue::TextAlign(TypesettingConfig::default().align), false),
+						NodeInput::value(TaggedValue::VerticalAlign(TypesettingConfig::default().vertical_align), false),
 					],
 					..Default::default()
 				},
@@ -2155,13 +2211,15 @@ fn static_nodes() -> Vec<DocumentNodeDefinition> {
 								..Default::default()
 							}),
 						),
+						("Align", "TODO").into(),
+						("Vertical Align", "TODO").into(),
 					],
 					output_names: vec!["Vector".to_string()],
 					..Default::default()
 				},
 			},
 			description: Cow::Borrowed("TODO"),
-			properties: None,
+			properties: Some("text_properties"),
 		},
 		DocumentNodeDefinition {
 			identifier: "Transform",
@@ -2995,8 +3053,15 @@ fn static_node_properties() -> NodeProperties {
 	map.insert("fill_properties".to_string(), Box::new(node_properties::fill_properties));
 	map.insert("stroke_properties".to_string(), Box::new(node_properties::stroke_properties));
 	map.insert("offset_path_properties".to_string(), Box::new(node_properties::offset_path_properties));
+	map.insert("instance_on_rows_properties".to_string(), Box::new(node_properties::instance_on_rows_properties));
+	map.insert("randomize_transform_properties".to_string(), Box::new(node_properties::randomize_transform_properties));
+	map.insert("fit_to_bounds_properties".to_string(), Box::new(node_properties::fit_to_bounds_properties));
 	map.insert("selective_color_properties".to_string(), Box::new(node_properties::selective_color_properties));
+	map.insert("color_balance_properties".to_string(), Box::new(node_properties::color_balance_properties));
+	map.insert("hsl_properties".to_string(), Box::new(node_properties::hsl_properties));
 	map.insert("exposure_properties".to_string(), Box::new(node_properties::exposure_properties));
+	map.insert("levels_properties".to_string(), Box::new(node_properties::levels_properties));
+	map.insert("text_properties".to_string(), Box::new(node_properties::text_properties));
 	map.insert("math_properties".to_string(), Box::new(node_properties::math_properties));
 	map.insert("rectangle_properties".to_string(), Box::new(node_properties::rectangle_properties));
 	map.insert("grid_properties".to_string(), Box::new(node_properties::grid_properties));
@@ -3011,6 +3076,37 @@ fn static_node_properties() -> NodeProperties {
 	map
 }
 
+type RegisteredNodeProperties = HashMap<String, std::sync::Arc<dyn Fn(NodeId, &mut NodePropertiesContext) -> Vec<LayoutGroup> + Send + Sync>>;
+
+static REGISTERED_NODE_PROPERTIES: once_cell::sync::Lazy<std::sync::RwLock<RegisteredNodeProperties>> = once_cell::sync::Lazy::new(Default::default);
+
+/// Registers a custom Properties panel layout for every node with the given reference, which takes precedence over the layout chosen by its definition.
+/// This lets Rust code compiled into the editor, such as that adding nodes from custom node packages, provide their layouts at runtime. It isn't exposed to the frontend.
+/// Registering a layout for a reference which already has one replaces it.
+pub fn register_node_properties(reference: impl Into<String>, properties: impl Fn(NodeId, &mut NodePropertiesContext) -> Vec<LayoutGroup> + Send + Sync + 'static) {
+	let Ok(mut registered) = REGISTERED_NODE_PROPERTIES.write() else {
+		log::error!("Could not lock the registered node properties in register_node_properties");
+		return;
+	};
+	registered.insert(reference.into(), std::sync::Arc::new(properties));
+}
+
+/// Removes the custom Properties panel layout registered for the given reference, returning whether there was one.
+pub fn unregister_node_properties(reference: &str) -> bool {
+	let Ok(mut registered) = REGISTERED_NODE_PROPERTIES.write() else {
+		log::error!("Could not lock the registered node properties in unregister_node_properties");
+		return false;
+	};
+	registered.remove(reference).is_some()
+}
+
+/// Builds the Properties panel layout registered at runtime for the given reference, if there is one.
+pub(crate) fn call_registered_node_properties(reference: &str, node_id: NodeId, context: &mut NodePropertiesContext) -> Option<Vec<LayoutGroup>> {
+	// The lock is released before building the layout so that the layout may itself register or look up layouts
+	let properties = REGISTERED_NODE_PROPERTIES.read().ok()?.get(reference).cloned()?;
+	Some(properties(node_id, context))
+}
+
 type InputProperties = HashMap<String, Box<dyn Fn(NodeId, usize, &mut NodePropertiesContext) -> Result<Vec<LayoutGroup>, String> + Send + Sync>>;
 
 static INPUT_OVERRIDES: once_cell::sync::Lazy<InputProperties> = once_cell::sync::Lazy::new(static_input_properties);
@@ -3148,6 +3244,7 @@ fn static_input_properties() -> InputProperties {
 				unit,
 				min,
 				node_properties::add_blank_assist,
+				Some(context.position_picker_target == Some((node_id, index))),
 			)])
 		}),
 	);
@@ -3421,7 +3518,7 @@ fn static_input_properties() -> InputProperties {
 		Box::new(|node_id, index, context| {
 			let (document_node, input_name, input_description) = node_properties::query_node_and_input_info(node_id, index, context)?;
 			let randomize_enabled = node_properties::query_assign_colors_randomize(node_id, context)?;
-			let seed_row = node_properties::number_widget(
+			let seed_row = node_properties::seed_widget(
 				document_node,
 				node_id,
 				index,
@@ -3553,6 +3650,15 @@ fn static_input_properties() -> InputProperties {
 			}])
 		}),
 	);
+	map.insert(
+		"image_file_path".to_string(),
+		Box::new(|node_id, index, context| {
+			let (document_node, input_name, input_description) = node_properties::query_node_and_input_info(node_id, index, context)?;
+			Ok(vec![LayoutGroup::Row {
+				widgets: node_properties::file_path_widget(document_node, node_id, index, input_name, input_description, "image/*", true),
+			}])
+		}),
+	);
 	map.insert(
 		"text_font".to_string(),
 		Box::new(|node_id, index, context| {
@@ -3583,6 +3689,28 @@ fn static_input_properties() -> InputProperties {
 	map
 }
 
+/// The adjustment nodes that can be applied to a whole graphic group, so they can be used as adjustment layers affecting every layer below them.
+pub const ADJUSTMENT_LAYER_NODE_TYPES: [&str; 15] = [
+	"Levels",
+	"Exposure",
+	"Vibrance",
+	"Hue/Saturation",
+	"HSL",
+	"Color Overlay",
+	"Black & White",
+	"Channel Mixer",
+	"Selective Color",
+	"Color Balance",
+	"Gradient Map",
+	"Invert",
+	"Posterize",
+	"Threshold",
+	"Luminance",
+];
+
+/// The tonal adjustment nodes whose properties begin with a histogram of the image coming into them, to show its distribution while adjusting it.
+pub const HISTOGRAM_NODE_TYPES: [&str; 3] = ["Levels", "Exposure", "Curves"];
+
 pub fn resolve_document_node_type(identifier: &str) -> Option<&DocumentNodeDefinition> {
 	DOCUMENT_NODE_TYPES.iter().find(|definition| definition.identifier == identifier)
 }
@@ -3621,8 +3749,15 @@ pub fn collect_node_types() -> Vec<FrontendNodeType> {
 				.into_iter()
 				.collect::<Vec<String>>();
 
+			let output_types = implementations
+				.iter()
+				.map(|(_, node_io)| node_io.return_value.nested_type().to_string())
+				.collect::<HashSet<String>>()
+				.into_iter()
+				.collect::<Vec<String>>();
+
 			// Create a FrontendNodeType
-			let node_type = FrontendNodeType::with_owned_strings_and_input_types(identifier, category, input_types);
+			let node_type = FrontendNodeType::with_owned_strings_and_input_types(identifier, category, input_types).with_output_types(output_types);
 
 			// Store the created node_type
 			extracted_node_types.push(node_type);
@@ -3748,6 +3883,8 @@ impl DocumentNodeDefinition {
 
 		// Set the reference to the node definition
 		template.persistent_node_metadata.reference = Some(self.identifier.to_string());
+		// Pin new nodes to the latest version of their definition
+		template.persistent_node_metadata.definition_version = node_versions::current_version(self.identifier);
 		template
 	}
 
@@ -3772,3 +3909,60 @@ impl DocumentNodeDefinition {
 // 	);
 // 	network
 // }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn registered_node_properties_take_precedence_over_the_definition() {
+		let mut network_interface = NodeNetworkInterface::default();
+		let node_id = NodeId(0);
+		let template = resolve_document_node_type("Identity").expect("Identity node does not exist").default_node_template();
+		network_interface.insert_node(node_id, template, &[]);
+
+		let (_runtime, mut executor) = NodeGraphExecutor::new_with_local_runtime();
+		let persistent_data = PersistentData::default();
+		let preferences = PreferencesMessageHandler::default();
+		let (keyframes, drivers, curve_channels) = (Keyframes::default(), Drivers::default(), HashMap::new());
+		let mut responses = VecDeque::new();
+		let mut context = NodePropertiesContext {
+			persistent_data: &persistent_data,
+			responses: &mut responses,
+			executor: &mut executor,
+			network_interface: &mut network_interface,
+			selection_network_path: &[],
+			document_name: "",
+			length_unit: LengthUnit::default(),
+			preview_quality: PreviewQuality::default(),
+			keyframes: &keyframes,
+			drivers: &drivers,
+			position_picker_target: None,
+			inspected_histogram: None,
+			vector_preview: None,
+			noise_preview: None,
+			curve_channels: &curve_channels,
+			preferences: &preferences,
+		};
+		let labels = |context: &mut NodePropertiesContext| {
+			let mut section = node_properties::generate_node_properties(node_id, context);
+			section
+				.iter_mut()
+				.filter_map(|widget_holder| match &widget_holder.widget {
+					Widget::TextLabel(text_label) => Some(text_label.value.clone()),
+					_ => None,
+				})
+				.collect::<Vec<_>>()
+		};
+		let definition_layout = "The identity node simply passes its data through.".to_string();
+
+		register_node_properties("Identity", |_node_id, _context| node_properties::string_properties("Registered layout"));
+		let registered_labels = labels(&mut context);
+		assert!(registered_labels.contains(&"Registered layout".to_string()));
+		assert!(!registered_labels.contains(&definition_layout));
+
+		assert!(unregister_node_properties("Identity"));
+		assert!(labels(&mut context).contains(&definition_layout));
+		assert!(!unregister_node_properties("Identity"));
+	}
+}
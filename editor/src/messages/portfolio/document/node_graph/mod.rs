@@ -1,7 +1,10 @@
+pub mod adjustment_presets;
 pub mod document_node_definitions;
 mod node_graph_message;
 mod node_graph_message_handler;
 pub mod node_properties;
+pub mod node_versions;
+pub mod parameter_clipboard;
 pub mod utility_types;
 
 #[doc(inline)]
@@ -1,12 +1,15 @@
-use super::utility_types::Direction;
+use super::utility_types::{Direction, WireProbeValue};
 use crate::messages::input_mapper::utility_types::input_keyboard::Key;
 use crate::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
-use crate::messages::portfolio::document::utility_types::network_interface::{ImportOrExport, InputConnector, NodeTemplate, OutputConnector};
+use crate::messages::portfolio::document::utility_types::network_interface::{ImportOrExport, InputConnector, NodeAssetReference, NodeGraphFrame, NodeGraphNote, NodeTemplate, OutputConnector};
 use crate::messages::prelude::*;
+use crate::node_graph_executor::InspectResult;
 use glam::IVec2;
 use graph_craft::document::value::TaggedValue;
 use graph_craft::document::{NodeId, NodeInput};
 use graph_craft::proto::GraphErrors;
+use graphene_core::Color;
+use graphene_core::raster::histogram::{AutoAdjustment, AutoLevels};
 use interpreted_executor::dynamic_executor::ResolvedDocumentNodeTypesDelta;
 
 #[impl_message(Message, DocumentMessage, NodeGraph)]
@@ -18,9 +21,33 @@ pub enum NodeGraphMessage {
 	},
 	AddImport,
 	AddExport,
+	AddFrame,
+	AddNote,
+	ApplyAutoLevels {
+		input_connector: InputConnector,
+		levels: Vec<AutoLevels>,
+	},
+	AutoAdjust {
+		input_connector: InputConnector,
+		adjustment: AutoAdjustment,
+	},
 	Init,
 	SelectedNodesUpdated,
+	BakeNode {
+		node_id: NodeId,
+	},
+	CompleteBake {
+		#[serde(skip)]
+		inspect_result: InspectResult,
+	},
+	CompleteAutoAdjust {
+		#[serde(skip)]
+		inspect_result: InspectResult,
+	},
 	Copy,
+	CopyParameterValues {
+		node_id: NodeId,
+	},
 	CreateNodeInLayerNoTransaction {
 		node_type: String,
 		layer: LayerNodeIdentifier,
@@ -50,26 +77,62 @@ pub enum NodeGraphMessage {
 	DeleteSelectedNodes {
 		delete_children: bool,
 	},
+	DeleteFrame {
+		frame_id: u64,
+	},
+	DeleteFrameImpl {
+		frame_id: u64,
+	},
+	DeleteNote {
+		note_id: u64,
+	},
+	DeleteNoteImpl {
+		note_id: u64,
+	},
 	DisconnectInput {
 		input_connector: InputConnector,
 	},
 	DisconnectRootNode,
 	EnterNestedNetwork,
 	DuplicateSelectedNodes,
+	ExpandNode {
+		node_id: NodeId,
+	},
+	ExpandSelectedNodes,
+	/// Downloads the selected node, with any network of nodes inside it, as a `.graphite-node` file which can be imported into other documents.
+	ExportSelectedNode,
 	ExposeInput {
 		input_connector: InputConnector,
 		set_to_exposed: bool,
 		start_transaction: bool,
 	},
+	InsertFrame {
+		frame: NodeGraphFrame,
+	},
+	InsertNote {
+		note: NodeGraphNote,
+	},
 	InsertNode {
 		node_id: NodeId,
 		node_template: NodeTemplate,
 	},
+	/// Inserts an instance of a node asset in the middle of the graph view.
+	InsertNodeAsset {
+		node_template: NodeTemplate,
+	},
 	InsertNodeBetween {
 		node_id: NodeId,
 		input_connector: InputConnector,
 		insert_node_input_index: usize,
 	},
+	InsertRerouteOnWire {
+		input_connector: InputConnector,
+		position: IVec2,
+	},
+	/// Shows the value carried by the wire whose context menu is open in a tooltip, which stays open until the next click in the graph.
+	InspectContextMenuWire,
+	/// Pins a readout of the value carried by the wire whose context menu is open along the wire, or unpins it if it's already pinned.
+	PinContextMenuWire,
 	MergeSelectedNodes,
 	MoveLayerToStack {
 		layer: LayerNodeIdentifier,
@@ -80,9 +143,14 @@ pub enum NodeGraphMessage {
 		node_id: NodeId,
 		parent: LayerNodeIdentifier,
 	},
+	OpenNodeSearch,
 	PasteNodes {
 		serialized_nodes: String,
 	},
+	PasteParameterValues {
+		node_id: NodeId,
+		serialized_parameters: String,
+	},
 	PointerDown {
 		shift_click: bool,
 		control_click: bool,
@@ -110,8 +178,14 @@ pub enum NodeGraphMessage {
 		start_index: usize,
 		end_index: usize,
 	},
+	/// Evaluates the values of the probed wire and the pinned wires again, such as once the graph has run since they were last evaluated.
+	RefreshWireProbe,
 	RunDocumentGraph,
 	ForceRunDocumentGraph,
+	/// Saves the selected node, which must be made of other nodes, to the node asset library as a new asset, or as the newest version of an existing one.
+	SaveSelectedAsNodeAsset {
+		asset_id: Option<u64>,
+	},
 	SelectedNodesAdd {
 		nodes: Vec<NodeId>,
 	},
@@ -125,11 +199,27 @@ pub enum NodeGraphMessage {
 	EndSendClickTargets,
 	SendGraph,
 	SetGridAlignedEdges,
+	/// Sets the value of an input, and of the same input of every other node being edited together with it in the Properties panel.
 	SetInputValue {
 		node_id: NodeId,
 		input_index: usize,
 		value: TaggedValue,
 	},
+	/// Previews a value for an input of a node in the document network in the rendered artwork, without changing the document or its history.
+	/// Setting it back to the input's actual value ends the preview.
+	SetInputValueProvisional {
+		node_id: NodeId,
+		input_index: usize,
+		value: TaggedValue,
+	},
+	ClearProvisionalInputValues,
+	/// Stores editor-only data about how an input is presented, such as which mode its widget is shown in.
+	SetInputMetadata {
+		node_id: NodeId,
+		input_index: usize,
+		field: String,
+		value: serde_json::Value,
+	},
 	SetInput {
 		input_connector: InputConnector,
 		input: NodeInput,
@@ -143,6 +233,10 @@ pub enum NodeGraphMessage {
 		node_id: NodeId,
 		alias: String,
 	},
+	SetNodeAsset {
+		node_id: NodeId,
+		asset: Option<NodeAssetReference>,
+	},
 	SetToNodeOrLayer {
 		node_id: NodeId,
 		is_layer: bool,
@@ -166,6 +260,38 @@ pub enum NodeGraphMessage {
 	TogglePreviewImpl {
 		node_id: NodeId,
 	},
+	SetFrameColor {
+		frame_id: u64,
+		color: Color,
+	},
+	SetFrameColorImpl {
+		frame_id: u64,
+		color: Color,
+	},
+	SetFrameTitle {
+		frame_id: u64,
+		title: String,
+	},
+	SetFrameTitleImpl {
+		frame_id: u64,
+		title: String,
+	},
+	SetNoteText {
+		note_id: u64,
+		text: String,
+	},
+	SetNoteTextImpl {
+		note_id: u64,
+		text: String,
+	},
+	SetWireProbePinned {
+		input_connector: InputConnector,
+		pinned: bool,
+	},
+	SetWireProbePinnedImpl {
+		input_connector: InputConnector,
+		pinned: bool,
+	},
 	SetImportExportName {
 		name: String,
 		index: ImportOrExport,
@@ -188,19 +314,52 @@ pub enum NodeGraphMessage {
 	ToggleVisibility {
 		node_id: NodeId,
 	},
+	/// Bypasses the selected nodes, or stops bypassing them if they all are.
+	ToggleSelectedBypass,
+	ToggleBypass {
+		node_id: NodeId,
+	},
+	ToggleSolo {
+		node_id: NodeId,
+	},
+	ExitSolo,
 	SetPinned {
 		node_id: NodeId,
 		pinned: bool,
 	},
+	SetInputPinned {
+		node_id: NodeId,
+		input_index: usize,
+		pinned: bool,
+	},
 	SetVisibility {
 		node_id: NodeId,
 		visible: bool,
 	},
+	SetBypassed {
+		node_id: NodeId,
+		bypassed: bool,
+	},
 	SetLockedOrVisibilitySideEffects {
 		node_ids: Vec<NodeId>,
 	},
 	UpdateEdges,
+	/// Receives the evaluated output of the node at the given path, which is shown if it's the value of the probed wire or a pinned wire.
+	UpdateWireProbe {
+		node_path: Vec<NodeId>,
+		value: WireProbeValue,
+	},
+	UpgradeNode {
+		node_id: NodeId,
+	},
+	UpgradeNodeImpl {
+		node_id: NodeId,
+	},
 	UpdateBoxSelection,
+	/// Replaces the contents of every instance of the node asset in the document with the given version of the asset.
+	UpdateNodeAssetInstances {
+		node_template: NodeTemplate,
+	},
 	UpdateImportsExports,
 	UpdateLayerPanel,
 	UpdateNewNodeGraph,
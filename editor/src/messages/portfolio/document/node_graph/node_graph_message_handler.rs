@@ -1,5 +1,9 @@
-use super::utility_types::{BoxSelection, ContextMenuInformation, DragStart, FrontendGraphInput, FrontendGraphOutput, FrontendNode, FrontendNodeWire, WirePath};
-use super::{document_node_definitions, node_properties};
+use super::parameter_clipboard::{CopiedParameters, PARAMETER_VALUES_CLIPBOARD_PREFIX, PastedParameter};
+use super::utility_types::{
+	BoxSelection, ContextMenuInformation, DragStart, FrontendGraphFrame, FrontendGraphInput, FrontendGraphNote, FrontendGraphOutput, FrontendNode, FrontendNodeWire, FrontendPinnedWireProbe,
+	FrontendWireProbe, GraphWireStyle, WirePath, WireProbe, WireProbeValue,
+};
+use super::{document_node_definitions, node_properties, node_versions};
 use crate::consts::GRID_SIZE;
 use crate::messages::input_mapper::utility_types::macros::action_keys;
 use crate::messages::layout::utility_types::widget_prelude::*;
@@ -7,19 +11,24 @@ use crate::messages::portfolio::document::document_message_handler::navigation_c
 use crate::messages::portfolio::document::graph_operation::utility_types::ModifyInputsContext;
 use crate::messages::portfolio::document::node_graph::document_node_definitions::NodePropertiesContext;
 use crate::messages::portfolio::document::node_graph::utility_types::{ContextMenuData, Direction, FrontendGraphDataType};
-use crate::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
-use crate::messages::portfolio::document::utility_types::misc::GroupFolderType;
+use crate::messages::portfolio::document::utility_types::custom_nodes::{CustomNode, NODE_FILE_EXTENSION};
+use crate::messages::portfolio::document::utility_types::document_metadata::{DocumentMetadata, LayerNodeIdentifier};
+use crate::messages::portfolio::document::utility_types::misc::{GroupFolderType, PreviewQuality};
 use crate::messages::portfolio::document::utility_types::network_interface::{
-	self, InputConnector, NodeNetworkInterface, NodeTemplate, NodeTypePersistentMetadata, OutputConnector, Previewing, TypeSource,
+	self, FrameClickTarget, ImportOrExport, InputConnector, NodeAssetReference, NodeGraphFrame, NodeGraphNote, NodeNetworkInterface, NodeTemplate, NodeTypePersistentMetadata, OutputConnector,
+	Previewing, TypeSource,
 };
 use crate::messages::portfolio::document::utility_types::nodes::{CollapsedLayers, LayerPanelEntry};
+use crate::messages::portfolio::histogram::compute_histogram;
 use crate::messages::prelude::*;
 use crate::messages::tool::common_functionality::auto_panning::AutoPanning;
 use crate::messages::tool::tool_messages::tool_prelude::{Key, MouseMotion};
 use crate::messages::tool::utility_types::{HintData, HintGroup, HintInfo};
 use glam::{DAffine2, DVec2, IVec2};
+use graph_craft::document::value::TaggedValue;
 use graph_craft::document::{DocumentNodeImplementation, NodeId, NodeInput};
 use graph_craft::proto::GraphErrors;
+use graphene_core::raster::histogram::AutoAdjustment;
 use graphene_core::*;
 use renderer::Quad;
 use std::cmp::Ordering;
@@ -34,8 +43,12 @@ pub struct NodeGraphHandlerData<'a> {
 	pub ipp: &'a InputPreprocessorMessageHandler,
 	pub graph_view_overlay_open: bool,
 	pub graph_fade_artwork_percentage: f64,
+	/// How the document draws the wires of its node graph, overriding the style chosen in the preferences when set.
+	pub graph_wire_style: Option<GraphWireStyle>,
 	pub navigation_handler: &'a NavigationMessageHandler,
 	pub preferences: &'a PreferencesMessageHandler,
+	/// The nodes imported into the document, which can be created from the node catalog by name like the built-in nodes.
+	pub custom_nodes: &'a [CustomNode],
 }
 
 #[derive(Debug, Clone)]
@@ -57,6 +70,8 @@ pub struct NodeGraphMessageHandler {
 	box_selection_start: Option<(DVec2, bool)>,
 	/// Restore the selection before box selection if it is aborted
 	selection_before_pointer_down: Vec<NodeId>,
+	/// If dragging a frame or sticky note by its title bar or resize grip, this stores what is dragged and the nodes it carries along
+	annotation_drag: Option<AnnotationDrag>,
 	/// If the grip icon is held during a drag, then shift without pushing other nodes
 	shift_without_push: bool,
 	disconnecting: Option<InputConnector>,
@@ -81,6 +96,18 @@ pub struct NodeGraphMessageHandler {
 	reordering_export: Option<usize>,
 	// The end index of the moved port
 	end_index: Option<usize>,
+	/// The node whose output is waiting to be captured by the next graph evaluation so it can be baked
+	pub baking_node: Option<NodeId>,
+	/// The node whose output is waiting to be captured by the next graph evaluation so its histogram can choose the settings of the Levels nodes feeding the input
+	pub auto_adjusting: Option<(NodeId, InputConnector, AutoAdjustment)>,
+	/// The layer shown on its own while every other layer is temporarily hidden, without changing the visibility saved in the document.
+	pub solo_layer: Option<LayerNodeIdentifier>,
+	/// Values previewed in place of the inputs of nodes in the document network, which are only applied to the network that's rendered.
+	pub provisional_inputs: HashMap<(NodeId, usize), TaggedValue>,
+	/// The wire whose value is shown in a tooltip, because it's hovered or was inspected from its context menu.
+	wire_probe: Option<WireProbe>,
+	/// The most recently evaluated values of the nodes whose outputs are carried by the pinned wires of the viewed network, by their document node paths.
+	pinned_wire_probe_values: HashMap<Vec<NodeId>, WireProbeValue>,
 }
 
 /// NodeGraphMessageHandler always modifies the network which the selected nodes are in. No GraphOperationMessages should be added here, since those messages will always affect the document network.
@@ -95,9 +122,12 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 			ipp,
 			graph_view_overlay_open,
 			graph_fade_artwork_percentage,
+			graph_wire_style,
 			navigation_handler,
 			preferences,
+			custom_nodes,
 		} = data;
+		let wire_style = graph_wire_style.unwrap_or(preferences.graph_wire_style);
 
 		match message {
 			// TODO: automatically remove broadcast messages.
@@ -118,6 +148,130 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				network_interface.add_export(graph_craft::document::value::TaggedValue::None, -1, "", breadcrumb_network_path);
 				responses.add(NodeGraphMessage::SendGraph);
 			}
+			NodeGraphMessage::AddFrame => {
+				let Some(selected_nodes) = network_interface.selected_nodes_in_nested_network(selection_network_path) else {
+					log::error!("Could not get selected nodes in AddFrame");
+					return;
+				};
+				let Some([top_left, bottom_right]) = selected_nodes
+					.selected_nodes()
+					.cloned()
+					.collect::<Vec<_>>()
+					.into_iter()
+					.filter_map(|node_id| network_interface.node_bounding_box(&node_id, selection_network_path))
+					.reduce(|[a_min, a_max], [b_min, b_max]| [a_min.min(b_min), a_max.max(b_max)])
+				else {
+					return;
+				};
+
+				// Leave a grid cell of padding around the nodes, plus a row above them for the title bar
+				let position = (top_left / 24.).floor().as_ivec2() - IVec2::new(1, 2);
+				let dimensions = ((bottom_right / 24.).ceil().as_ivec2() + IVec2::ONE - position).max(NodeGraphFrame::MIN_DIMENSIONS);
+				let frame = NodeGraphFrame {
+					id: graph_craft::document::generate_uuid(),
+					title: "Frame".to_string(),
+					color: Color::from_rgb8_srgb(0x66, 0x66, 0x66),
+					position,
+					dimensions,
+				};
+
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::InsertFrame { frame });
+				responses.add(NodeGraphMessage::SendGraph);
+			}
+			NodeGraphMessage::AddNote => {
+				let Some(network_metadata) = network_interface.network_metadata(breadcrumb_network_path) else {
+					log::error!("Could not get network metadata in AddNote");
+					return;
+				};
+
+				// Center the new note in the visible area of the graph
+				let center = network_metadata
+					.persistent_metadata
+					.navigation_metadata
+					.node_graph_to_viewport
+					.inverse()
+					.transform_point2(ipp.viewport_bounds.center());
+				let note = NodeGraphNote {
+					id: graph_craft::document::generate_uuid(),
+					text: String::new(),
+					position: (center / 24.).round().as_ivec2() - NodeGraphNote::DEFAULT_DIMENSIONS / 2,
+					dimensions: NodeGraphNote::DEFAULT_DIMENSIONS,
+				};
+
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::InsertNote { note });
+				responses.add(NodeGraphMessage::SendGraph);
+			}
+			NodeGraphMessage::ApplyAutoLevels { input_connector, levels } => {
+				let (mut chain, _) = levels_chain(network_interface, &input_connector, selection_network_path);
+				let set_levels = |network_interface: &mut NodeNetworkInterface, node_id: NodeId, [shadows, midtones, highlights]: [f64; 3]| {
+					for (input_index, value) in [(1, shadows), (2, midtones), (3, highlights), (4, 0.), (5, 100.)] {
+						network_interface.set_input(&InputConnector::node(node_id, input_index), NodeInput::value(TaggedValue::F64(value), false), selection_network_path);
+					}
+				};
+
+				for levels in levels {
+					let existing = chain.iter().position(|node_id| {
+						let channel = InputConnector::node(*node_id, 6);
+						network_interface.input_from_connector(&channel, selection_network_path).and_then(NodeInput::as_non_exposed_value) == Some(&TaggedValue::LevelsChannel(levels.channel))
+					});
+					let node_id = match existing {
+						Some(index) => chain.remove(index),
+						None => {
+							let Some(levels_definition) = document_node_definitions::resolve_document_node_type("Levels") else {
+								log::error!("Could not resolve the Levels node definition");
+								return;
+							};
+							let node_id = NodeId::new();
+							let channel = NodeInput::value(TaggedValue::LevelsChannel(levels.channel), false);
+							let node_template = levels_definition.node_template_input_override([None, None, None, None, None, None, Some(channel)]);
+							network_interface.insert_node(node_id, node_template, selection_network_path);
+
+							let downstream_node = input_connector.node_id();
+							let downstream_position = downstream_node.and_then(|downstream_node| network_interface.position(&downstream_node, selection_network_path));
+							network_interface.insert_node_between(&node_id, &input_connector, 0, selection_network_path);
+							if downstream_node.is_some_and(|downstream_node| {
+								network_interface.is_layer(&downstream_node, selection_network_path) || network_interface.is_chain(&downstream_node, selection_network_path)
+							}) {
+								network_interface.force_set_upstream_to_chain(&node_id, selection_network_path);
+							} else if let Some((downstream_position, position)) = downstream_position.zip(network_interface.position(&node_id, selection_network_path)) {
+								network_interface.shift_node(&node_id, downstream_position - position - IVec2::new(8, 0), selection_network_path);
+							}
+							node_id
+						}
+					};
+					set_levels(network_interface, node_id, [levels.shadows, levels.midtones, levels.highlights]);
+				}
+				// Levels nodes left over from a previous adjustment of other channels are reset so their effect doesn't compound with the new one
+				for node_id in chain {
+					set_levels(network_interface, node_id, [0., 50., 100.]);
+				}
+
+				responses.add(PropertiesPanelMessage::Refresh);
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+				responses.add(NodeGraphMessage::SendGraph);
+			}
+			NodeGraphMessage::AutoAdjust { input_connector, adjustment } => {
+				// The inspection which captures the image is only able to monitor nodes in the document network
+				if !selection_network_path.is_empty() {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: format!("Unable to apply {adjustment}"),
+						description: "Only images in the document network can be adjusted automatically.\nExit the subgraph first.".to_string(),
+					});
+					return;
+				}
+				let (_, Some(source)) = levels_chain(network_interface, &input_connector, selection_network_path) else {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: format!("Unable to apply {adjustment}"),
+						description: "There is no image to analyze.".to_string(),
+					});
+					return;
+				};
+
+				self.auto_adjusting = Some((source, input_connector, adjustment));
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+			}
 			NodeGraphMessage::Init => {
 				responses.add(BroadcastMessage::SubscribeEvent {
 					on: BroadcastEvent::SelectionChanged,
@@ -125,6 +279,9 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				});
 				network_interface.load_structure();
 				collapsed.0.retain(|&layer| network_interface.document_metadata().layer_exists(layer));
+				responses.add(FrontendMessage::UpdateCustomNodeTypes {
+					node_types: custom_nodes.iter().map(CustomNode::frontend_node_type).collect(),
+				});
 			}
 			NodeGraphMessage::SelectedNodesUpdated => {
 				let selected_layers = network_interface.selected_nodes().selected_layers(network_interface.document_metadata()).collect::<Vec<_>>();
@@ -149,15 +306,117 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				}
 				network_interface.create_wire(&output_connector, &input_connector, selection_network_path);
 			}
+			NodeGraphMessage::BakeNode { node_id } => {
+				// The inspection which captures the output is only able to monitor nodes in the document network
+				if !selection_network_path.is_empty() {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: "Unable to bake node".to_string(),
+						description: "Only nodes in the document network can be baked.\nExit the subgraph and bake the node which contains it instead.".to_string(),
+					});
+					return;
+				}
+
+				self.baking_node = Some(node_id);
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+			}
+			NodeGraphMessage::CompleteBake { mut inspect_result } => {
+				if self.baking_node != Some(inspect_result.inspect_node) {
+					return;
+				}
+				let Some(node_id) = self.baking_node.take() else { return };
+				if !selection_network_path.is_empty() {
+					log::warn!("The network was changed before the bake of {node_id} completed");
+					return;
+				}
+
+				let Some(baked) = inspect_result.take_data().and_then(|data| baked_value(data.as_ref())) else {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: "Unable to bake node".to_string(),
+						description: "Only nodes which output vector, raster, or group data and contribute to the document can be baked.".to_string(),
+					});
+					return;
+				};
+				let Some(bake_definition) = document_node_definitions::resolve_document_node_type("Bake") else {
+					log::error!("Could not resolve the Bake node definition");
+					return;
+				};
+				let Some(position) = network_interface.position(&node_id, selection_network_path) else {
+					log::error!("Could not get the position of {node_id} in CompleteBake");
+					return;
+				};
+				let output_connector = OutputConnector::node(node_id, 0);
+				let downstream_inputs = network_interface
+					.outward_wires(selection_network_path)
+					.and_then(|outward_wires| outward_wires.get(&output_connector))
+					.cloned()
+					.unwrap_or_default();
+
+				let bake_node_id = NodeId::new();
+				let node_template = bake_definition.node_template_input_override([None, Some(NodeInput::value(baked, false)), Some(NodeInput::value(TaggedValue::Bool(true), false))]);
+
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::InsertNode { node_id: bake_node_id, node_template });
+				responses.add(NodeGraphMessage::ShiftNodePosition {
+					node_id: bake_node_id,
+					x: position.x + 8,
+					y: position.y,
+				});
+				// Everything that read the source's output now reads the baked value instead, while the source stays connected so it can be unfrozen
+				for input_connector in downstream_inputs {
+					responses.add(NodeGraphMessage::CreateWire {
+						output_connector: OutputConnector::node(bake_node_id, 0),
+						input_connector,
+					});
+				}
+				responses.add(NodeGraphMessage::CreateWire {
+					output_connector,
+					input_connector: InputConnector::node(bake_node_id, 0),
+				});
+				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: vec![bake_node_id] });
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+				responses.add(NodeGraphMessage::SendGraph);
+			}
+			NodeGraphMessage::CompleteAutoAdjust { mut inspect_result } => {
+				if self.auto_adjusting.is_none_or(|(source, _, _)| source != inspect_result.inspect_node) {
+					return;
+				}
+				let Some((_, input_connector, adjustment)) = self.auto_adjusting.take() else { return };
+
+				// The histogram is measured from the image before any of the Levels nodes which are about to be changed
+				let histogram = inspect_result.take_data().as_ref().and_then(compute_histogram);
+				let levels = histogram.map(|histogram| adjustment.levels(&histogram)).unwrap_or_default();
+				if levels.is_empty() {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: format!("Unable to apply {adjustment}"),
+						description: "Only images with visible raster content can be adjusted automatically.".to_string(),
+					});
+					return;
+				}
+
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::ApplyAutoLevels { input_connector, levels });
+			}
 			NodeGraphMessage::Copy => {
 				let all_selected_nodes = network_interface.upstream_chain_nodes(selection_network_path);
 				// Collect the selected nodes
 				let new_ids = &all_selected_nodes.iter().enumerate().map(|(new, old)| (*old, NodeId(new as u64))).collect();
-				let copied_nodes = network_interface.copy_nodes(new_ids, selection_network_path).collect::<Vec<_>>();
+				let nodes = network_interface.copy_nodes(new_ids, selection_network_path).collect::<Vec<_>>();
+				// Sticky notes placed over the selected nodes are copied along with them
+				let notes = Self::notes_over_nodes(network_interface, &all_selected_nodes, selection_network_path);
 
 				// Prefix to show that these are nodes
 				let mut copy_text = String::from("graphite/nodes: ");
-				copy_text += &serde_json::to_string(&copied_nodes).expect("Could not serialize copy");
+				copy_text += &serde_json::to_string(&CopiedNodes { nodes, notes }).expect("Could not serialize copy");
+
+				responses.add(FrontendMessage::TriggerTextCopy { copy_text });
+			}
+			NodeGraphMessage::CopyParameterValues { node_id } => {
+				let Some(copied_parameters) = CopiedParameters::from_node(network_interface, node_id, selection_network_path) else {
+					return;
+				};
+
+				let mut copy_text = String::from(PARAMETER_VALUES_CLIPBOARD_PREFIX);
+				copy_text += &serde_json::to_string(&copied_parameters).expect("Could not serialize parameter values");
 
 				responses.add(FrontendMessage::TriggerTextCopy { copy_text });
 			}
@@ -184,15 +443,17 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 
 				let node_id = node_id.unwrap_or_else(NodeId::new);
 
-				let Some(document_node_type) = document_node_definitions::resolve_document_node_type(&node_type) else {
+				let node_template = if let Some(document_node_type) = document_node_definitions::resolve_document_node_type(&node_type) {
+					document_node_type.default_node_template()
+				} else if let Some(custom_node) = custom_nodes.iter().find(|custom_node| custom_node.name == node_type) {
+					custom_node.node_template.clone()
+				} else {
 					responses.add(DialogMessage::DisplayDialogError {
 						title: "Cannot insert node".to_string(),
 						description: format!("The document node '{node_type}' does not exist in the document node list"),
 					});
 					return;
 				};
-
-				let node_template = document_node_type.default_node_template();
 				self.context_menu = None;
 
 				responses.add(DocumentMessage::AddTransaction);
@@ -217,14 +478,25 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 						return;
 					};
 
-					// Ensure connection is to correct input of new node. If it does not have an input then do not connect
-					if let Some((input_index, _)) = node_template
-						.document_node
-						.inputs
-						.iter()
-						.enumerate()
-						.find(|(_, input)| input.is_exposed_to_frontend(selection_network_path.is_empty()))
-					{
+					// Ensure connection is to correct input of new node, preferring the first input accepting the type of the dragged wire. If it does not have an input then do not connect
+					let output_type = output_connector
+						.node_id()
+						.and_then(|node_id| network_interface.output_types(&node_id, selection_network_path).into_iter().nth(output_connector.index()).flatten())
+						.map(|(output_type, _)| output_type.nested_type().clone());
+					let exposed_inputs = || {
+						node_template
+							.document_node
+							.inputs
+							.iter()
+							.enumerate()
+							.filter(|(_, input)| input.is_exposed_to_frontend(selection_network_path.is_empty()))
+					};
+					let compatible_input = exposed_inputs().find(|(_, input)| {
+						input
+							.as_value()
+							.is_some_and(|value| output_type.as_ref().is_some_and(|output_type| value.ty().nested_type() == output_type))
+					});
+					if let Some((input_index, _)) = compatible_input.or_else(|| exposed_inputs().next()) {
 						responses.add(NodeGraphMessage::CreateWire {
 							output_connector: *output_connector,
 							input_connector: InputConnector::node(node_id, input_index),
@@ -253,8 +525,15 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				});
 			}
 			NodeGraphMessage::Cut => {
+				let all_selected_nodes = network_interface.upstream_chain_nodes(selection_network_path);
+				let notes = Self::notes_over_nodes(network_interface, &all_selected_nodes, selection_network_path);
+
 				responses.add(NodeGraphMessage::Copy);
 				responses.add(NodeGraphMessage::DeleteSelectedNodes { delete_children: true });
+				for note in notes {
+					responses.add(NodeGraphMessage::DeleteNoteImpl { note_id: note.id });
+				}
+				responses.add(NodeGraphMessage::SendGraph);
 			}
 			NodeGraphMessage::DeleteNodes { node_ids, delete_children } => {
 				network_interface.delete_nodes(node_ids, delete_children, selection_network_path);
@@ -275,6 +554,22 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				responses.add(NodeGraphMessage::SelectedNodesUpdated);
 				responses.add(NodeGraphMessage::SendGraph);
 			}
+			NodeGraphMessage::DeleteFrame { frame_id } => {
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::DeleteFrameImpl { frame_id });
+				responses.add(NodeGraphMessage::SendGraph);
+			}
+			NodeGraphMessage::DeleteFrameImpl { frame_id } => {
+				network_interface.remove_frame(frame_id, selection_network_path);
+			}
+			NodeGraphMessage::DeleteNote { note_id } => {
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::DeleteNoteImpl { note_id });
+				responses.add(NodeGraphMessage::SendGraph);
+			}
+			NodeGraphMessage::DeleteNoteImpl { note_id } => {
+				network_interface.remove_note(note_id, selection_network_path);
+			}
 			NodeGraphMessage::DisconnectInput { input_connector } => {
 				network_interface.disconnect_input(&input_connector, selection_network_path);
 			}
@@ -303,6 +598,23 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				}
 
 				let Some(node_id) = network_interface.node_from_click(ipp.mouse.position, selection_network_path) else {
+					// Double clicking a wire instead routes it through a new reroute node placed where it was clicked
+					let Some(input_connector) = Self::wire_from_click(network_interface, ipp.mouse.position, selection_network_path, wire_style) else {
+						return;
+					};
+					let Some(network_metadata) = network_interface.network_metadata(selection_network_path) else {
+						log::error!("Could not get network metadata in EnterNestedNetwork");
+						return;
+					};
+					let node_graph_point = network_metadata
+						.persistent_metadata
+						.navigation_metadata
+						.node_graph_to_viewport
+						.inverse()
+						.transform_point2(ipp.mouse.position);
+					// Offset the node so its input port, one row down from its top left corner, lands on the wire
+					let position = (node_graph_point / GRID_SIZE as f64).round().as_ivec2() - IVec2::new(0, 1);
+					responses.add(NodeGraphMessage::InsertRerouteOnWire { input_connector, position });
 					return;
 				};
 				if network_interface
@@ -315,6 +627,190 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 					responses.add(DocumentMessage::EnterNestedNetwork { node_id });
 				}
 			}
+			NodeGraphMessage::ExpandNode { node_id } => {
+				let inner_network_path = [selection_network_path, &[node_id]].concat();
+				let Some(inner_network) = network_interface.nested_network(&inner_network_path) else {
+					log::error!("Could not get nested network in ExpandNode");
+					return;
+				};
+				let inner_node_ids = inner_network.nodes.keys().copied().collect::<Vec<_>>();
+				let export_count = inner_network.exports.len();
+
+				// What feeds each of the node's inputs from outside, which is either a wire or the value it was given
+				let outer_inputs = (0..network_interface.number_of_inputs(&node_id, selection_network_path))
+					.map(|input_index| {
+						let input_connector = InputConnector::node(node_id, input_index);
+						let upstream = network_interface.upstream_output_connector(&input_connector, selection_network_path);
+						let value = network_interface.input_from_connector(&input_connector, selection_network_path).and_then(NodeInput::as_value).cloned();
+						(upstream, value)
+					})
+					.collect::<Vec<_>>();
+				// What feeds each export inside, and everything each of the node's outputs is connected to outside
+				let export_sources = (0..export_count)
+					.map(|export_index| {
+						let input_connector = InputConnector::Export(export_index);
+						let upstream = network_interface.upstream_output_connector(&input_connector, &inner_network_path);
+						let value = network_interface.input_from_connector(&input_connector, &inner_network_path).and_then(NodeInput::as_value).cloned();
+						(upstream, value)
+					})
+					.collect::<Vec<_>>();
+				let Some(outward_wires) = network_interface.outward_wires(selection_network_path) else {
+					log::error!("Could not get outward wires in ExpandNode");
+					return;
+				};
+				let downstream_connections = (0..export_count)
+					.map(|output_index| outward_wires.get(&OutputConnector::node(node_id, output_index)).cloned().unwrap_or_default())
+					.collect::<Vec<_>>();
+
+				// Center the contents where the node was, keeping their layout
+				let Some(node_position) = network_interface.position(&node_id, selection_network_path) else {
+					log::error!("Could not get position of node {node_id} in ExpandNode");
+					return;
+				};
+				let inner_positions = inner_node_ids
+					.iter()
+					.filter_map(|inner_node_id| network_interface.position(inner_node_id, &inner_network_path))
+					.collect::<Vec<_>>();
+				let Some((min, max)) = inner_positions
+					.iter()
+					.map(|&position| (position, position))
+					.reduce(|(a_min, a_max), (b_min, b_max)| (a_min.min(b_min), a_max.max(b_max)))
+				else {
+					return;
+				};
+				let inner_center = (min + max) / 2;
+
+				// The inner nodes' inputs which were connected to the imports
+				let Some(inner_outward_wires) = network_interface.outward_wires(&inner_network_path) else {
+					log::error!("Could not get outward wires of the nested network in ExpandNode");
+					return;
+				};
+				let import_connections = (0..outer_inputs.len())
+					.flat_map(|import_index| {
+						let input_connectors = inner_outward_wires.get(&OutputConnector::Import(import_index)).cloned().unwrap_or_default();
+						input_connectors.into_iter().filter_map(move |input_connector| match input_connector {
+							InputConnector::Node { node_id, input_index } => Some((node_id, input_index, import_index)),
+							InputConnector::Export(_) => None,
+						})
+					})
+					.collect::<Vec<_>>();
+
+				let copy_ids = inner_node_ids.iter().map(|&inner_node_id| (inner_node_id, inner_node_id)).collect::<HashMap<_, _>>();
+				let mut copied_nodes = network_interface.copy_nodes(&copy_ids, &inner_network_path).collect::<Vec<_>>();
+				let new_ids = inner_node_ids.iter().map(|&inner_node_id| (inner_node_id, NodeId::new())).collect::<HashMap<_, _>>();
+
+				// Inputs that came from an import given a value take that value, while those given a wire get connected to it below
+				for &(inner_node_id, input_index, import_index) in &import_connections {
+					let Some((None, Some(value))) = outer_inputs.get(import_index) else { continue };
+					let Some(input) = copied_nodes
+						.iter_mut()
+						.find(|(id, _)| *id == inner_node_id)
+						.and_then(|(_, node_template)| node_template.document_node.inputs.get_mut(input_index))
+					else {
+						continue;
+					};
+					*input = NodeInput::value(value.clone(), true);
+				}
+
+				responses.add(NodeGraphMessage::DeleteNodes {
+					node_ids: vec![node_id],
+					delete_children: false,
+				});
+				responses.add(NodeGraphMessage::AddNodes {
+					nodes: copied_nodes,
+					new_ids: new_ids.clone(),
+				});
+				responses.add(NodeGraphMessage::SelectedNodesSet {
+					nodes: new_ids.values().copied().collect(),
+				});
+				// The copied nodes are offset by two grid spaces, so shift them back by that too
+				responses.add(NodeGraphMessage::ShiftSelectedNodesByAmount {
+					graph_delta: node_position - inner_center - IVec2::new(2, 2),
+					rubber_band: false,
+				});
+
+				for (inner_node_id, input_index, import_index) in import_connections {
+					if let (Some((Some(output_connector), _)), Some(&node_id)) = (outer_inputs.get(import_index), new_ids.get(&inner_node_id)) {
+						responses.add(NodeGraphMessage::CreateWire {
+							output_connector: *output_connector,
+							input_connector: InputConnector::node(node_id, input_index),
+						});
+					}
+				}
+				for ((upstream, value), input_connectors) in export_sources.into_iter().zip(downstream_connections) {
+					// An export fed straight from an import passes along whatever fed the node's input
+					let (upstream, value) = match upstream {
+						Some(OutputConnector::Node { node_id, output_index }) => (new_ids.get(&node_id).map(|&node_id| OutputConnector::node(node_id, output_index)), None),
+						Some(OutputConnector::Import(import_index)) => outer_inputs.get(import_index).cloned().unwrap_or_default(),
+						None => (None, value),
+					};
+					for input_connector in input_connectors {
+						if let Some(output_connector) = upstream {
+							responses.add(NodeGraphMessage::CreateWire { output_connector, input_connector });
+						} else if let Some(value) = value.clone() {
+							responses.add(NodeGraphMessage::SetInput {
+								input_connector,
+								input: NodeInput::value(value, false),
+							});
+						}
+					}
+				}
+			}
+			NodeGraphMessage::ExpandSelectedNodes => {
+				let Some(selected_nodes) = network_interface.selected_nodes_in_nested_network(selection_network_path) else {
+					log::error!("Could not get selected nodes in ExpandSelectedNodes");
+					return;
+				};
+				// Only nodes made of a network of other nodes have contents to expand, and layers are kept since they form the layer stack
+				let node_ids = selected_nodes
+					.selected_nodes()
+					.copied()
+					.filter(|node_id| !network_interface.is_layer(node_id, selection_network_path))
+					.filter(|node_id| matches!(network_interface.implementation(node_id, selection_network_path), Some(DocumentNodeImplementation::Network(_))))
+					.collect::<Vec<_>>();
+				if node_ids.is_empty() {
+					return;
+				}
+
+				responses.add(DocumentMessage::AddTransaction);
+				for node_id in node_ids {
+					responses.add(NodeGraphMessage::ExpandNode { node_id });
+				}
+				responses.add(NodeGraphMessage::SendGraph);
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+			}
+			NodeGraphMessage::ExportSelectedNode => {
+				let Some(selected_nodes) = network_interface.selected_nodes_in_nested_network(selection_network_path) else {
+					log::error!("Could not get selected nodes in ExportSelectedNode");
+					return;
+				};
+				let node_id = match selected_nodes.selected_nodes().copied().collect::<Vec<_>>()[..] {
+					[node_id] if !network_interface.is_layer(&node_id, selection_network_path) => node_id,
+					_ => {
+						responses.add(DialogMessage::DisplayDialogError {
+							title: "Cannot export node".to_string(),
+							description: "Select a single node, which isn't a layer, to export it as a file.".to_string(),
+						});
+						return;
+					}
+				};
+				let Some(node_template) = Self::standalone_node_template(network_interface, node_id, selection_network_path) else {
+					log::error!("Could not create node template in ExportSelectedNode");
+					return;
+				};
+
+				let custom_node = CustomNode {
+					name: network_interface.display_name(&node_id, selection_network_path),
+					node_template,
+				};
+				match serde_json::to_string(&custom_node) {
+					Ok(document) => responses.add(FrontendMessage::TriggerDownloadTextFile {
+						document,
+						name: format!("{}.{NODE_FILE_EXTENSION}", custom_node.name),
+					}),
+					Err(error) => log::error!("Could not serialize the node in ExportSelectedNode: {error}"),
+				}
+			}
 			NodeGraphMessage::ExposeInput {
 				input_connector,
 				set_to_exposed,
@@ -369,9 +865,29 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				responses.add(NodeGraphMessage::SendGraph);
 				responses.add(NodeGraphMessage::RunDocumentGraph);
 			}
+			NodeGraphMessage::InsertFrame { frame } => {
+				network_interface.add_frame(frame, selection_network_path);
+			}
+			NodeGraphMessage::InsertNote { note } => {
+				network_interface.add_note(note, selection_network_path);
+			}
 			NodeGraphMessage::InsertNode { node_id, node_template } => {
 				network_interface.insert_node(node_id, node_template, selection_network_path);
 			}
+			NodeGraphMessage::InsertNodeAsset { mut node_template } => {
+				let position = network_interface
+					.node_graph_ptz(breadcrumb_network_path)
+					.map(|node_graph_ptz| (-node_graph_ptz.pan / GRID_SIZE as f64).as_ivec2())
+					.unwrap_or_default();
+				node_template.persistent_node_metadata.node_type_metadata = NodeTypePersistentMetadata::node(position);
+
+				let node_id = NodeId::new();
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::InsertNode { node_id, node_template });
+				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: vec![node_id] });
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+				responses.add(NodeGraphMessage::SendGraph);
+			}
 			NodeGraphMessage::InsertNodeBetween {
 				node_id,
 				input_connector,
@@ -379,6 +895,56 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 			} => {
 				network_interface.insert_node_between(&node_id, &input_connector, insert_node_input_index, selection_network_path);
 			}
+			NodeGraphMessage::InspectContextMenuWire => {
+				let Some(ContextMenuInformation {
+					context_menu_coordinates: (x, y),
+					context_menu_data: ContextMenuData::InspectWire { input_connector, .. },
+				}) = self.context_menu.take()
+				else {
+					return;
+				};
+				responses.add(FrontendMessage::UpdateContextMenuInformation { context_menu_information: None });
+
+				self.probe_wire(input_connector, DVec2::new(x as f64, y as f64), true, network_interface, selection_network_path, responses);
+			}
+			NodeGraphMessage::PinContextMenuWire => {
+				let Some(ContextMenuInformation {
+					context_menu_data: ContextMenuData::InspectWire { input_connector, pinned },
+					..
+				}) = self.context_menu.take()
+				else {
+					return;
+				};
+				responses.add(FrontendMessage::UpdateContextMenuInformation { context_menu_information: None });
+
+				responses.add(NodeGraphMessage::SetWireProbePinned { input_connector, pinned: !pinned });
+			}
+			NodeGraphMessage::InsertRerouteOnWire { input_connector, position } => {
+				let Some(reroute_definition) = document_node_definitions::resolve_document_node_type("Reroute") else {
+					log::error!("Could not resolve the Reroute node definition");
+					return;
+				};
+				let node_id = NodeId::new();
+
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::InsertNode {
+					node_id,
+					node_template: reroute_definition.default_node_template(),
+				});
+				responses.add(NodeGraphMessage::ShiftNodePosition {
+					node_id,
+					x: position.x,
+					y: position.y,
+				});
+				responses.add(NodeGraphMessage::InsertNodeBetween {
+					node_id,
+					input_connector,
+					insert_node_input_index: 0,
+				});
+				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: vec![node_id] });
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+				responses.add(NodeGraphMessage::SendGraph);
+			}
 			NodeGraphMessage::MergeSelectedNodes => {
 				let new_ids = network_interface
 					.selected_nodes_in_nested_network(breadcrumb_network_path)
@@ -473,6 +1039,29 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 					}
 				}
 
+				// Name each import after the first input it feeds, and each export after the node it comes from, so the new node's connectors say what they are
+				let import_names = (0..input_connections.len())
+					.map(|import_index| {
+						import_connections
+							.iter()
+							.find(|(_, index)| *index == import_index)
+							.and_then(|(input_connector, _)| {
+								let node_id = input_connector.node_id()?;
+								network_interface.input_name(&node_id, input_connector.input_index(), breadcrumb_network_path).map(str::to_string)
+							})
+							.unwrap_or_default()
+					})
+					.collect::<Vec<_>>();
+				let export_names = export_connections
+					.iter()
+					.map(|output_connector| {
+						output_connector
+							.node_id()
+							.map(|node_id| network_interface.display_name(&node_id, breadcrumb_network_path))
+							.unwrap_or_default()
+					})
+					.collect::<Vec<_>>();
+
 				// Use the network interface to add a default node, then set the imports, exports, paste the nodes inside, and connect them to the imports/exports
 				let encapsulating_node_id = NodeId::new();
 				let mut default_node_template = document_node_definitions::resolve_document_node_type("Default Network")
@@ -501,6 +1090,18 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				for _ in 0..output_connections.len() {
 					responses.add(NodeGraphMessage::AddExport);
 				}
+				for (import_index, name) in import_names.into_iter().enumerate().filter(|(_, name)| !name.is_empty()) {
+					responses.add(NodeGraphMessage::SetImportExportNameImpl {
+						name,
+						index: ImportOrExport::Import(import_index),
+					});
+				}
+				for (export_index, name) in export_names.into_iter().enumerate().filter(|(_, name)| !name.is_empty()) {
+					responses.add(NodeGraphMessage::SetImportExportNameImpl {
+						name,
+						index: ImportOrExport::Export(export_index),
+					});
+				}
 				responses.add(NodeGraphMessage::AddNodes { nodes: copied_nodes, new_ids });
 				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: selected_node_ids_vec.clone() });
 
@@ -551,25 +1152,117 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 			NodeGraphMessage::MoveNodeToChainStart { node_id, parent } => {
 				network_interface.move_node_to_chain_start(&node_id, parent, selection_network_path);
 			}
+			NodeGraphMessage::OpenNodeSearch => {
+				// If the add node menu is already open, we don't want to open it again
+				if self.context_menu.is_some() {
+					return;
+				}
+				let Some(network_metadata) = network_interface.network_metadata(breadcrumb_network_path) else {
+					log::error!("Could not get network metadata in OpenNodeSearch");
+					return;
+				};
+				let node_graph_to_viewport = network_metadata.persistent_metadata.navigation_metadata.node_graph_to_viewport;
+				let click = ipp.mouse.position;
+				let node_graph_point = node_graph_to_viewport.inverse().transform_point2(click);
+
+				// While dragging a wire out of an output, only show the nodes which can accept its type, and connect it to the chosen node
+				let compatible_type = self
+					.wire_in_progress_from_connector
+					.and_then(|wire_start| network_interface.output_connector_from_click(node_graph_to_viewport.transform_point2(wire_start), breadcrumb_network_path))
+					.and_then(|output_connector| Self::compatible_type_search(network_interface, &output_connector, selection_network_path));
+
+				let appear_right_of_mouse = if click.x > ipp.viewport_bounds.size().x - 180. { -180. } else { 0. };
+				let appear_above_mouse = if click.y > ipp.viewport_bounds.size().y - 200. { -200. } else { 0. };
+				let node_graph_shift = DVec2::new(appear_right_of_mouse, appear_above_mouse) / node_graph_to_viewport.matrix2.x_axis.x;
+
+				self.context_menu = Some(ContextMenuInformation {
+					context_menu_coordinates: ((node_graph_point.x + node_graph_shift.x) as i32, (node_graph_point.y + node_graph_shift.y) as i32),
+					context_menu_data: ContextMenuData::CreateNode { compatible_type },
+				});
+
+				responses.add(FrontendMessage::UpdateContextMenuInformation {
+					context_menu_information: self.context_menu.clone(),
+				});
+			}
 			NodeGraphMessage::PasteNodes { serialized_nodes } => {
-				let data = match serde_json::from_str::<Vec<(NodeId, NodeTemplate)>>(&serialized_nodes) {
+				let CopiedNodes { nodes, notes } = match serde_json::from_str::<CopiedNodes>(&serialized_nodes) {
 					Ok(d) => d,
 					Err(e) => {
 						warn!("Invalid node data {e:?}");
 						return;
 					}
 				};
-				if data.is_empty() {
+				if nodes.is_empty() && notes.is_empty() {
 					return;
 				}
 
 				responses.add(DocumentMessage::AddTransaction);
 
-				let new_ids: HashMap<_, _> = data.iter().map(|(id, _)| (*id, NodeId::new())).collect();
-				responses.add(NodeGraphMessage::AddNodes {
-					nodes: data,
-					new_ids: new_ids.clone(),
-				});
+				if !nodes.is_empty() {
+					let new_ids: HashMap<_, _> = nodes.iter().map(|(id, _)| (*id, NodeId::new())).collect();
+					responses.add(NodeGraphMessage::AddNodes { nodes, new_ids });
+				}
+				for note in notes {
+					let note = NodeGraphNote {
+						id: graph_craft::document::generate_uuid(),
+						..note
+					};
+					responses.add(NodeGraphMessage::InsertNote { note });
+				}
+				responses.add(NodeGraphMessage::SendGraph);
+			}
+			NodeGraphMessage::PasteParameterValues { node_id, serialized_parameters } => {
+				let copied_parameters = match serde_json::from_str::<CopiedParameters>(&serialized_parameters) {
+					Ok(copied_parameters) => copied_parameters,
+					Err(e) => {
+						warn!("Invalid parameter values {e:?}");
+						return;
+					}
+				};
+
+				let reference = network_interface.reference(&node_id, selection_network_path).cloned().flatten();
+				if copied_parameters.reference != reference {
+					let copied_from = copied_parameters.reference.unwrap_or_else(|| "custom".to_string());
+					responses.add(DialogMessage::DisplayDialogError {
+						title: "Unable to paste parameter values".to_string(),
+						description: format!("These values were copied from a {copied_from} node, so they can only be pasted into another node of that type."),
+					});
+					return;
+				}
+
+				let Some(node) = network_interface.document_node(&node_id, selection_network_path) else { return };
+				let target_inputs = node
+					.inputs
+					.iter()
+					.enumerate()
+					.map(|(index, input)| {
+						let name = network_interface.input_name(&node_id, index, selection_network_path).unwrap_or_default().to_string();
+						(name, input.as_non_exposed_value())
+					})
+					.collect::<Vec<_>>();
+
+				let mut set_values = Vec::new();
+				let mut skipped = Vec::new();
+				for pasted in copied_parameters.paste_into(&target_inputs) {
+					match pasted {
+						PastedParameter::Set(input_index, value) => set_values.push(NodeGraphMessage::SetInputValue { node_id, input_index, value }),
+						PastedParameter::Unchanged => {}
+						PastedParameter::Skipped { name, reason } => skipped.push(format!("• {name}: {reason}")),
+					}
+				}
+
+				if !set_values.is_empty() {
+					responses.add(DocumentMessage::AddTransaction);
+					for message in set_values {
+						responses.add(message);
+					}
+				}
+				if !skipped.is_empty() {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: "Some parameter values weren't pasted".to_string(),
+						description: format!("These values were left out:\n{}", skipped.join("\n")),
+					});
+				}
 			}
 			NodeGraphMessage::PointerDown {
 				shift_click,
@@ -590,6 +1283,11 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 
 				let node_graph_point = network_metadata.persistent_metadata.navigation_metadata.node_graph_to_viewport.inverse().transform_point2(click);
 
+				// Any click closes the wire probe tooltip, including one kept open by inspecting the wire
+				if self.wire_probe.take().is_some() {
+					self.send_wire_probe(responses);
+				}
+
 				if network_interface
 					.layer_click_target_from_click(click, network_interface::LayerClickTargetTypes::Grip, selection_network_path)
 					.is_some()
@@ -613,6 +1311,11 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 						});
 						return;
 					}
+					// Abort dragging or resizing a frame or sticky note
+					if self.annotation_drag.take().is_some() {
+						responses.add(DocumentMessage::AbortTransaction);
+						return;
+					}
 					// Abort a box selection
 					if self.box_selection_start.is_some() {
 						self.box_selection_start = None;
@@ -634,6 +1337,9 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 					let context_menu_data = if let Some(node_id) = clicked_id {
 						let currently_is_node = !network_interface.is_layer(&node_id, selection_network_path);
 						ContextMenuData::ToggleLayer { node_id, currently_is_node }
+					} else if let Some(input_connector) = Self::wire_from_click(network_interface, click, selection_network_path, wire_style) {
+						let pinned = network_interface.pinned_wire_probes(selection_network_path).contains(&input_connector);
+						ContextMenuData::InspectWire { input_connector, pinned }
 					} else {
 						ContextMenuData::CreateNode { compatible_type: None }
 					};
@@ -805,6 +1511,47 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 					return;
 				}
 
+				// Begin moving a sticky note by its header, or resizing it by its grip
+				if let Some((note_id, target)) = network_interface.note_from_click(node_graph_point, selection_network_path) {
+					let Some(note) = network_interface.notes(selection_network_path).iter().find(|note| note.id == note_id) else {
+						return;
+					};
+
+					self.annotation_drag = Some(AnnotationDrag {
+						annotation: GraphAnnotation::Note(note_id),
+						target,
+						start: node_graph_point,
+						round: IVec2::ZERO,
+						start_dimensions: note.dimensions,
+						contained_nodes: Vec::new(),
+					});
+					responses.add(DocumentMessage::StartTransaction);
+					return;
+				}
+
+				// Begin moving a frame by its title bar, or resizing it by its grip
+				if let Some((frame_id, target)) = network_interface.frame_from_click(node_graph_point, selection_network_path) {
+					let Some(frame) = network_interface.frames(selection_network_path).iter().find(|frame| frame.id == frame_id) else {
+						return;
+					};
+					let start_dimensions = frame.dimensions;
+					let contained_nodes = match target {
+						FrameClickTarget::TitleBar => network_interface.nodes_in_frame(frame_id, selection_network_path),
+						FrameClickTarget::ResizeGrip => Vec::new(),
+					};
+
+					self.annotation_drag = Some(AnnotationDrag {
+						annotation: GraphAnnotation::Frame(frame_id),
+						target,
+						start: node_graph_point,
+						round: IVec2::ZERO,
+						start_dimensions,
+						contained_nodes,
+					});
+					responses.add(DocumentMessage::StartTransaction);
+					return;
+				}
+
 				// Clicked on the graph background so we box select
 				if !shift_click {
 					responses.add(NodeGraphMessage::SelectedNodesSet { nodes: Vec::new() })
@@ -892,7 +1639,13 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 							}
 						});
 						let wire_path = WirePath {
-							path_string: Self::build_wire_path_string(wire_in_progress_from_connector, wire_in_progress_to_connector, from_connector_is_layer, to_connector_is_layer),
+							path_string: Self::build_wire_path_string(
+								wire_in_progress_from_connector,
+								wire_in_progress_to_connector,
+								from_connector_is_layer,
+								to_connector_is_layer,
+								wire_style,
+							),
 							data_type: FrontendGraphDataType::General,
 							thick: false,
 							dashed: false,
@@ -931,6 +1684,22 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 					responses.add(NodeGraphMessage::ShiftSelectedNodesByAmount { graph_delta, rubber_band: true });
 
 					self.update_node_graph_hints(responses);
+				} else if let Some(annotation_drag) = &mut self.annotation_drag {
+					let round = ((point - annotation_drag.start) / 24.).round().as_ivec2();
+					if round == annotation_drag.round {
+						return;
+					}
+
+					let shift = round - annotation_drag.round;
+					let dimensions = annotation_drag.start_dimensions + round;
+					match (annotation_drag.annotation, annotation_drag.target) {
+						(GraphAnnotation::Frame(frame_id), FrameClickTarget::TitleBar) => network_interface.shift_frame(frame_id, shift, &annotation_drag.contained_nodes, selection_network_path),
+						(GraphAnnotation::Frame(frame_id), FrameClickTarget::ResizeGrip) => network_interface.resize_frame(frame_id, dimensions, selection_network_path),
+						(GraphAnnotation::Note(note_id), FrameClickTarget::TitleBar) => network_interface.shift_note(note_id, shift, selection_network_path),
+						(GraphAnnotation::Note(note_id), FrameClickTarget::ResizeGrip) => network_interface.resize_note(note_id, dimensions, selection_network_path),
+					}
+					annotation_drag.round = round;
+					responses.add(NodeGraphMessage::SendGraph);
 				} else if let Some((_, box_selection_dragged)) = &mut self.box_selection_start {
 					*box_selection_dragged = true;
 					responses.add(NodeGraphMessage::UpdateBoxSelection);
@@ -975,6 +1744,21 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 							.unwrap_or(modify_import_export.reorder_imports_exports.input_ports().count()),
 					);
 					responses.add(FrontendMessage::UpdateExportReorderIndex { index: self.end_index });
+				} else if self.context_menu.is_none() && !self.wire_probe.as_ref().is_some_and(|probe| probe.inspected) {
+					// Show the value carried by the wire under the pointer, unless it's over a node drawn on top of the wire
+					let hovered_wire = match network_interface.node_from_click(viewport_location, selection_network_path) {
+						Some(_) => None,
+						None => Self::wire_from_click(network_interface, viewport_location, selection_network_path, wire_style),
+					};
+					match hovered_wire {
+						Some(input_connector) if self.wire_probe.as_ref().is_some_and(|probe| probe.input_connector == input_connector) => {}
+						Some(input_connector) => self.probe_wire(input_connector, point, false, network_interface, selection_network_path, responses),
+						None => {
+							if self.wire_probe.take().is_some() {
+								self.send_wire_probe(responses);
+							}
+						}
+					}
 				}
 			}
 			NodeGraphMessage::PointerUp => {
@@ -1035,19 +1819,7 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 							return;
 						};
 						// Get the compatible type from the output connector
-						let compatible_type = output_connector.and_then(|output_connector| {
-							output_connector.node_id().and_then(|node_id| {
-								let output_index = output_connector.index();
-								// Get the output types from the network interface
-								let output_types = network_interface.output_types(&node_id, selection_network_path);
-
-								// Extract the type if available
-								output_types.get(output_index).and_then(|type_option| type_option.as_ref()).map(|(output_type, _)| {
-									// Create a search term based on the type
-									format!("type:{}", output_type.clone().nested_type())
-								})
-							})
-						});
+						let compatible_type = output_connector.and_then(|output_connector| Self::compatible_type_search(network_interface, &output_connector, selection_network_path));
 						let appear_right_of_mouse = if ipp.mouse.position.x > ipp.viewport_bounds.size().x - 173. { -173. } else { 0. };
 						let appear_above_mouse = if ipp.mouse.position.y > ipp.viewport_bounds.size().y - 34. { -34. } else { 0. };
 						let node_graph_shift = DVec2::new(appear_right_of_mouse, appear_above_mouse) / network_metadata.persistent_metadata.navigation_metadata.node_graph_to_viewport.matrix2.x_axis.x;
@@ -1281,6 +2053,7 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				self.drag_start = None;
 				self.begin_dragging = false;
 				self.box_selection_start = None;
+				self.annotation_drag = None;
 				self.wire_in_progress_from_connector = None;
 				self.wire_in_progress_to_connector = None;
 				self.reordering_export = None;
@@ -1293,7 +2066,8 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				self.update_node_graph_hints(responses);
 			}
 			NodeGraphMessage::PointerOutsideViewport { shift } => {
-				if self.drag_start.is_some() || self.box_selection_start.is_some() || (self.wire_in_progress_from_connector.is_some() && self.context_menu.is_none()) {
+				if self.drag_start.is_some() || self.box_selection_start.is_some() || self.annotation_drag.is_some() || (self.wire_in_progress_from_connector.is_some() && self.context_menu.is_none())
+				{
 					let _ = self.auto_panning.shift_viewport(ipp, responses);
 				} else {
 					// Auto-panning
@@ -1321,12 +2095,60 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				responses.add(NodeGraphMessage::SendGraph);
 				responses.add(NodeGraphMessage::RunDocumentGraph);
 			}
+			NodeGraphMessage::RefreshWireProbe => {
+				// The values shown by the pinned wires are kept until their new values arrive
+				for node_path in Self::pinned_wire_node_paths(network_interface, selection_network_path) {
+					responses.add(PortfolioMessage::ProbeNode { node_path });
+				}
+
+				let Some(probe) = self.wire_probe.clone() else { return };
+
+				// The wire is gone if it was deleted or its subgraph was left since it was probed
+				let wire_exists = network_interface.upstream_output_connector(&probe.input_connector, selection_network_path).is_some();
+				if probe.network_path != selection_network_path || !wire_exists {
+					self.wire_probe = None;
+					self.send_wire_probe(responses);
+					return;
+				}
+
+				self.probe_wire(probe.input_connector, probe.position, probe.inspected, network_interface, selection_network_path, responses);
+			}
 			NodeGraphMessage::RunDocumentGraph => {
 				responses.add(PortfolioMessage::SubmitGraphRender { document_id, ignore_hash: false });
 			}
 			NodeGraphMessage::ForceRunDocumentGraph => {
 				responses.add(PortfolioMessage::SubmitGraphRender { document_id, ignore_hash: true });
 			}
+			NodeGraphMessage::SaveSelectedAsNodeAsset { asset_id } => {
+				let Some(selected_nodes) = network_interface.selected_nodes_in_nested_network(selection_network_path) else {
+					log::error!("Could not get selected nodes in SaveSelectedAsNodeAsset");
+					return;
+				};
+				let selected_nodes = selected_nodes.selected_nodes().copied().collect::<Vec<_>>();
+				let node_id = match selected_nodes[..] {
+					[node_id] if !network_interface.is_layer(&node_id, selection_network_path) => Some(node_id),
+					_ => None,
+				}
+				.filter(|node_id| matches!(network_interface.implementation(node_id, selection_network_path), Some(DocumentNodeImplementation::Network(_))));
+				let Some(node_id) = node_id else {
+					responses.add(DialogMessage::DisplayDialogError {
+						title: "Cannot save node asset".to_string(),
+						description: "Select a single node made of other nodes, such as one created with Merge Selected Nodes, to save it as a node asset.".to_string(),
+					});
+					return;
+				};
+				let Some(node_template) = Self::standalone_node_template(network_interface, node_id, selection_network_path) else {
+					log::error!("Could not create node template in SaveSelectedAsNodeAsset");
+					return;
+				};
+
+				responses.add(NodeAssetsPanelMessage::SaveAsset {
+					id: asset_id.unwrap_or_else(graph_craft::document::generate_uuid),
+					name: network_interface.display_name(&node_id, selection_network_path),
+					node_id,
+					node_template,
+				});
+			}
 			NodeGraphMessage::SelectedNodesAdd { nodes } => {
 				let Some(selected_nodes) = network_interface.selected_nodes_mut(selection_network_path) else {
 					log::error!("Could not get selected nodes in NodeGraphMessage::SelectedNodesAdd");
@@ -1362,16 +2184,20 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				responses.add(PropertiesPanelMessage::Refresh);
 				if breadcrumb_network_path == selection_network_path && graph_view_overlay_open {
 					// TODO: Implement culling of nodes and wires whose bounding boxes are outside of the viewport
+					self.send_pinned_wire_probes(network_interface, breadcrumb_network_path, wire_style, responses);
 					let wires = Self::collect_wires(network_interface, breadcrumb_network_path);
 					let nodes = self.collect_nodes(network_interface, breadcrumb_network_path);
+					let frames = Self::collect_frames(network_interface, breadcrumb_network_path);
+					let notes = Self::collect_notes(network_interface, breadcrumb_network_path);
 					let (layer_widths, chain_widths, has_left_input_wire) = network_interface.collect_layer_widths(breadcrumb_network_path);
-					let wires_direct_not_grid_aligned = preferences.graph_wire_style.is_direct();
 
 					responses.add(NodeGraphMessage::UpdateImportsExports);
 					responses.add(FrontendMessage::UpdateNodeGraph {
 						nodes,
 						wires,
-						wires_direct_not_grid_aligned,
+						frames,
+						notes,
+						wire_style,
 					});
 					responses.add(FrontendMessage::UpdateLayerWidths {
 						layer_widths,
@@ -1389,22 +2215,57 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 					responses.add(NodeGraphMessage::UpdateImportsExports);
 				}
 			}
+			NodeGraphMessage::SetInputValueProvisional { node_id, input_index, value } => {
+				let mut changed = false;
+				for node_id in Self::multi_edit_targets(network_interface, &[], node_id) {
+					let actual_value = network_interface
+						.document_network()
+						.nodes
+						.get(&node_id)
+						.and_then(|node| node.inputs.get(input_index))
+						.and_then(|input| input.as_value());
+
+					changed |= if actual_value == Some(&value) {
+						self.provisional_inputs.remove(&(node_id, input_index)).is_some()
+					} else {
+						self.provisional_inputs.insert((node_id, input_index), value.clone()).as_ref() != Some(&value)
+					};
+				}
+				if changed {
+					responses.add(NodeGraphMessage::RunDocumentGraph);
+				}
+			}
+			NodeGraphMessage::ClearProvisionalInputValues => {
+				if !self.provisional_inputs.is_empty() {
+					self.provisional_inputs.clear();
+					responses.add(NodeGraphMessage::RunDocumentGraph);
+				}
+			}
 			NodeGraphMessage::SetInputValue { node_id, input_index, value } => {
-				let input = NodeInput::value(value, false);
-				responses.add(NodeGraphMessage::SetInput {
-					input_connector: InputConnector::node(node_id, input_index),
-					input,
-				});
+				let mut run_document_graph = false;
+				for node_id in Self::multi_edit_targets(network_interface, selection_network_path, node_id) {
+					let input = NodeInput::value(value.clone(), false);
+					responses.add(NodeGraphMessage::SetInput {
+						input_connector: InputConnector::node(node_id, input_index),
+						input,
+					});
+					run_document_graph |= (network_interface
+						.reference(&node_id, selection_network_path)
+						.is_none_or(|reference| *reference != Some("Imaginate".to_string())) // TODO: Potentially remove the reference to Imaginate
+						|| input_index == 0)
+						&& network_interface.connected_to_output(&node_id, selection_network_path);
+				}
 				responses.add(PropertiesPanelMessage::Refresh);
-				if (network_interface
-					.reference(&node_id, selection_network_path)
-					.is_none_or(|reference| *reference != Some("Imaginate".to_string())) // TODO: Potentially remove the reference to Imaginate
-					|| input_index == 0)
-					&& network_interface.connected_to_output(&node_id, selection_network_path)
-				{
+				if run_document_graph {
 					responses.add(NodeGraphMessage::RunDocumentGraph);
 				}
 			}
+			NodeGraphMessage::SetInputMetadata { node_id, input_index, field, value } => {
+				for node_id in Self::multi_edit_targets(network_interface, selection_network_path, node_id) {
+					network_interface.set_input_metadata(&node_id, input_index, &field, value.clone(), selection_network_path);
+				}
+				responses.add(PropertiesPanelMessage::Refresh);
+			}
 			NodeGraphMessage::SetInput { input_connector, input } => {
 				network_interface.set_input(&input_connector, input, selection_network_path);
 			}
@@ -1479,6 +2340,9 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 			NodeGraphMessage::ShiftNodePosition { node_id, x, y } => {
 				network_interface.shift_absolute_node_position(&node_id, IVec2::new(x, y), selection_network_path);
 			}
+			NodeGraphMessage::SetNodeAsset { node_id, asset } => {
+				network_interface.set_node_asset(&node_id, selection_network_path, asset);
+			}
 			NodeGraphMessage::SetToNodeOrLayer { node_id, is_layer } => {
 				if is_layer && !network_interface.is_eligible_to_be_layer(&node_id, selection_network_path) {
 					return;
@@ -1512,6 +2376,42 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 			NodeGraphMessage::SetDisplayNameImpl { node_id, alias } => {
 				network_interface.set_display_name(&node_id, alias, selection_network_path);
 			}
+			NodeGraphMessage::SetFrameColor { frame_id, color } => {
+				responses.add(DocumentMessage::StartTransaction);
+				responses.add(NodeGraphMessage::SetFrameColorImpl { frame_id, color });
+				responses.add(DocumentMessage::EndTransaction);
+				responses.add(NodeGraphMessage::SendGraph);
+			}
+			NodeGraphMessage::SetFrameColorImpl { frame_id, color } => {
+				network_interface.set_frame_color(frame_id, color, selection_network_path);
+			}
+			NodeGraphMessage::SetFrameTitle { frame_id, title } => {
+				responses.add(DocumentMessage::StartTransaction);
+				responses.add(NodeGraphMessage::SetFrameTitleImpl { frame_id, title });
+				responses.add(DocumentMessage::EndTransaction);
+				responses.add(NodeGraphMessage::SendGraph);
+			}
+			NodeGraphMessage::SetFrameTitleImpl { frame_id, title } => {
+				network_interface.set_frame_title(frame_id, title, selection_network_path);
+			}
+			NodeGraphMessage::SetNoteText { note_id, text } => {
+				responses.add(DocumentMessage::StartTransaction);
+				responses.add(NodeGraphMessage::SetNoteTextImpl { note_id, text });
+				responses.add(DocumentMessage::EndTransaction);
+				responses.add(NodeGraphMessage::SendGraph);
+			}
+			NodeGraphMessage::SetNoteTextImpl { note_id, text } => {
+				network_interface.set_note_text(note_id, text, selection_network_path);
+			}
+			NodeGraphMessage::SetWireProbePinned { input_connector, pinned } => {
+				responses.add(DocumentMessage::StartTransaction);
+				responses.add(NodeGraphMessage::SetWireProbePinnedImpl { input_connector, pinned });
+				responses.add(DocumentMessage::EndTransaction);
+				responses.add(NodeGraphMessage::SendGraph);
+			}
+			NodeGraphMessage::SetWireProbePinnedImpl { input_connector, pinned } => {
+				network_interface.set_wire_probe_pinned(input_connector, pinned, selection_network_path);
+			}
 			NodeGraphMessage::SetImportExportName { name, index } => {
 				responses.add(DocumentMessage::StartTransaction);
 				responses.add(NodeGraphMessage::SetImportExportNameImpl { name, index });
@@ -1600,12 +2500,59 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				responses.add(NodeGraphMessage::SetVisibility { node_id, visible });
 				responses.add(NodeGraphMessage::SetLockedOrVisibilitySideEffects { node_ids: vec![node_id] });
 			}
+			NodeGraphMessage::ToggleSelectedBypass => {
+				let Some(selected_nodes) = network_interface.selected_nodes_in_nested_network(selection_network_path) else {
+					log::error!("Could not get selected nodes in NodeGraphMessage::ToggleSelectedBypass");
+					return;
+				};
+				let node_ids = selected_nodes.selected_nodes().cloned().collect::<Vec<_>>();
+
+				// If any of the selected nodes aren't bypassed, bypass them all. Otherwise, stop bypassing them all.
+				let bypassed = !node_ids.iter().all(|node_id| network_interface.is_bypassed(node_id, selection_network_path));
+
+				responses.add(DocumentMessage::AddTransaction);
+				for node_id in &node_ids {
+					responses.add(NodeGraphMessage::SetBypassed { node_id: *node_id, bypassed });
+				}
+				responses.add(NodeGraphMessage::SetLockedOrVisibilitySideEffects { node_ids });
+			}
+			NodeGraphMessage::ToggleBypass { node_id } => {
+				let bypassed = !network_interface.is_bypassed(&node_id, selection_network_path);
+
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::SetBypassed { node_id, bypassed });
+				responses.add(NodeGraphMessage::SetLockedOrVisibilitySideEffects { node_ids: vec![node_id] });
+			}
+			NodeGraphMessage::ToggleSolo { node_id } => {
+				let layer = LayerNodeIdentifier::new(node_id, network_interface, &[]);
+				self.solo_layer = if self.solo_layer == Some(layer) { None } else { Some(layer) };
+
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+				responses.add(NodeGraphMessage::UpdateLayerPanel);
+				responses.add(MenuBarMessage::SendLayout);
+			}
+			NodeGraphMessage::ExitSolo => {
+				if self.solo_layer.take().is_none() {
+					return;
+				}
+
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+				responses.add(NodeGraphMessage::UpdateLayerPanel);
+				responses.add(MenuBarMessage::SendLayout);
+			}
 			NodeGraphMessage::SetPinned { node_id, pinned } => {
 				network_interface.set_pinned(&node_id, selection_network_path, pinned);
 			}
+			NodeGraphMessage::SetInputPinned { node_id, input_index, pinned } => {
+				network_interface.set_input_pinned(&node_id, input_index, selection_network_path, pinned);
+				responses.add(PropertiesPanelMessage::Refresh);
+			}
 			NodeGraphMessage::SetVisibility { node_id, visible } => {
 				network_interface.set_visibility(&node_id, selection_network_path, visible);
 			}
+			NodeGraphMessage::SetBypassed { node_id, bypassed } => {
+				network_interface.set_bypassed(&node_id, selection_network_path, bypassed);
+			}
 			NodeGraphMessage::SetLockedOrVisibilitySideEffects { node_ids } => {
 				if node_ids.iter().any(|node_id| network_interface.connected_to_output(node_id, selection_network_path)) {
 					responses.add(NodeGraphMessage::RunDocumentGraph);
@@ -1615,6 +2562,15 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 
 				responses.add(PropertiesPanelMessage::Refresh);
 			}
+			NodeGraphMessage::UpdateNodeAssetInstances { node_template } => {
+				let Some(NodeAssetReference { id, .. }) = node_template.persistent_node_metadata.asset else {
+					log::error!("The node template in UpdateNodeAssetInstances is not a node asset");
+					return;
+				};
+				for (node_id, network_path) in network_interface.node_asset_instances(id) {
+					network_interface.update_node_asset_instance(&node_id, &network_path, &node_template);
+				}
+			}
 			NodeGraphMessage::UpdateBoxSelection => {
 				if let Some((box_selection_start, _)) = self.box_selection_start {
 					// The mouse button was released but we missed the pointer up event
@@ -1703,7 +2659,36 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 			}
 
 			NodeGraphMessage::UpdateLayerPanel => {
-				Self::update_layer_panel(network_interface, selection_network_path, collapsed, responses);
+				self.update_layer_panel(network_interface, selection_network_path, collapsed, responses);
+			}
+			NodeGraphMessage::UpdateWireProbe { node_path, value } => {
+				if self.pinned_wire_probe_values.get(&node_path) != Some(&value) && Self::pinned_wire_node_paths(network_interface, selection_network_path).contains(&node_path) {
+					self.pinned_wire_probe_values.insert(node_path.clone(), value.clone());
+					if graph_view_overlay_open {
+						self.send_pinned_wire_probes(network_interface, selection_network_path, wire_style, responses);
+					}
+				}
+
+				let Some(probe) = &mut self.wire_probe else { return };
+				if probe.node_path.as_ref() != Some(&node_path) || probe.value.as_ref() == Some(&value) {
+					return;
+				}
+
+				probe.value = Some(value);
+				self.send_wire_probe(responses);
+			}
+			NodeGraphMessage::UpgradeNode { node_id } => {
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::UpgradeNodeImpl { node_id });
+			}
+			NodeGraphMessage::UpgradeNodeImpl { node_id } => {
+				if !node_versions::upgrade_node(network_interface, &node_id, selection_network_path) {
+					return;
+				}
+
+				responses.add(PropertiesPanelMessage::Refresh);
+				responses.add(NodeGraphMessage::SendGraph);
+				responses.add(NodeGraphMessage::RunDocumentGraph);
 			}
 			NodeGraphMessage::UpdateEdges => {
 				// Update the import/export UI edges whenever the PTZ changes or the bounding box of all nodes changes
@@ -1734,7 +2719,14 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 				}
 			}
 			NodeGraphMessage::UpdateGraphBarRight => {
-				self.update_graph_bar_right(graph_fade_artwork_percentage, network_interface, breadcrumb_network_path, navigation_handler);
+				self.update_graph_bar_right(
+					graph_fade_artwork_percentage,
+					graph_wire_style,
+					preferences,
+					network_interface,
+					breadcrumb_network_path,
+					navigation_handler,
+				);
 				self.send_node_bar_layout(responses);
 			}
 			NodeGraphMessage::UpdateInSelectedNetwork => responses.add(FrontendMessage::UpdateInSelectedNetwork {
@@ -1770,16 +2762,20 @@ impl<'a> MessageHandler<NodeGraphMessage, NodeGraphHandlerData<'a>> for NodeGrap
 impl NodeGraphMessageHandler {
 	/// Similar to [`NodeGraphMessageHandler::actions`], but this provides additional actions if the node graph is open and should only be called in that circumstance.
 	pub fn actions_additional_if_node_graph_is_open(&self) -> ActionList {
-		let mut common = actions!(NodeGraphMessageDiscriminant; EnterNestedNetwork, PointerDown, PointerMove, PointerUp, SendClickTargets, EndSendClickTargets);
+		let mut common = actions!(NodeGraphMessageDiscriminant; AddNote, EnterNestedNetwork, OpenNodeSearch, PointerDown, PointerMove, PointerUp, SendClickTargets, EndSendClickTargets);
 
 		if self.has_selection {
 			common.extend(actions!(NodeGraphMessageDiscriminant;
+				AddFrame,
 				Copy,
 				Cut,
 				DeleteSelectedNodes,
 				DuplicateSelectedNodes,
+				ExpandSelectedNodes,
+				ExportSelectedNode,
 				MergeSelectedNodes,
 				ToggleSelectedAsLayersOrNodes,
+				ToggleSelectedBypass,
 				ToggleSelectedLocked,
 				ToggleSelectedVisibility,
 				ShiftSelectedNodes,
@@ -1871,6 +2867,17 @@ impl NodeGraphMessageHandler {
 				.on_update(|_| DocumentMessage::DeleteSelectedLayers.into())
 				.disabled(!has_selection)
 				.widget_holder(),
+			IconButton::new("Artboard", 24)
+				.tooltip("Frame Selected")
+				.tooltip_shortcut(action_keys!(NodeGraphMessageDiscriminant::AddFrame))
+				.on_update(|_| NodeGraphMessage::AddFrame.into())
+				.disabled(!has_selection)
+				.widget_holder(),
+			IconButton::new("Edit", 24)
+				.tooltip("Add Sticky Note")
+				.tooltip_shortcut(action_keys!(NodeGraphMessageDiscriminant::AddNote))
+				.on_update(|_| NodeGraphMessage::AddNote.into())
+				.widget_holder(),
 			//
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
 			//
@@ -1945,6 +2952,8 @@ impl NodeGraphMessageHandler {
 	fn update_graph_bar_right(
 		&mut self,
 		graph_fade_artwork_percentage: f64,
+		graph_wire_style: Option<GraphWireStyle>,
+		preferences: &PreferencesMessageHandler,
 		network_interface: &NodeNetworkInterface,
 		breadcrumb_network_path: &[NodeId],
 		navigation_handler: &NavigationMessageHandler,
@@ -1954,7 +2963,33 @@ impl NodeGraphMessageHandler {
 			return;
 		};
 
+		// The first entry follows the style chosen in the preferences, while the others override it for this document
+		let wire_style_entries = [None]
+			.into_iter()
+			.chain(GraphWireStyle::ALL.into_iter().map(Some))
+			.map(|style| {
+				let label = match style {
+					Some(style) => style.to_string(),
+					None => format!("Preference ({})", preferences.graph_wire_style),
+				};
+				MenuListEntry::new(format!("{style:?}"))
+					.label(label)
+					.on_update(move |_| DocumentMessage::SetGraphWireStyle { style }.into())
+			})
+			.collect();
+		let wire_style_index = match graph_wire_style {
+			Some(style) => GraphWireStyle::ALL.iter().position(|&other| other == style).map(|index| index as u32 + 1),
+			None => Some(0),
+		};
+
 		let mut widgets = vec![
+			TextLabel::new("Wires").tooltip("How the wires between nodes are drawn in this document").widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			DropdownInput::new(vec![wire_style_entries])
+				.selected_index(wire_style_index)
+				.tooltip(graph_wire_style.unwrap_or(preferences.graph_wire_style).tooltip_description())
+				.widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
 			NumberInput::new(Some(graph_fade_artwork_percentage))
 				.percentage()
 				.display_decimal_places(0)
@@ -1984,8 +3019,89 @@ impl NodeGraphMessageHandler {
 		self.widgets[1] = LayoutGroup::Row { widgets };
 	}
 
-	/// Collate the properties panel sections for a node graph
+	/// A copy of the node kept apart from any graph, as when it's saved outside the document, so it isn't connected to other nodes and has no position.
+	fn standalone_node_template(network_interface: &NodeNetworkInterface, node_id: NodeId, network_path: &[NodeId]) -> Option<NodeTemplate> {
+		let node_template = network_interface.create_node_template(&node_id, network_path)?;
+
+		let mut node_template = network_interface.map_ids(node_template, &node_id, &HashMap::new(), network_path);
+		node_template.persistent_node_metadata.node_type_metadata = NodeTypePersistentMetadata::default();
+		node_template.persistent_node_metadata.pinned = false;
+		node_template.persistent_node_metadata.locked = false;
+		Some(node_template)
+	}
+
+	/// The nodes edited together in the Properties panel, which are the selected nodes when there are several of them, none are layers, and all share the same definition.
+	pub fn multi_edit_selection(network_interface: &NodeNetworkInterface, network_path: &[NodeId]) -> Option<Vec<NodeId>> {
+		let selected_nodes = network_interface.selected_nodes_in_nested_network(network_path)?.selected_nodes().cloned().collect::<Vec<_>>();
+		let (first, rest) = selected_nodes.split_first()?;
+		if rest.is_empty() {
+			return None;
+		}
+
+		let reference = network_interface.reference(first, network_path)?.clone()?;
+		let number_of_inputs = network_interface.number_of_inputs(first, network_path);
+		let same_type = |node_id: &NodeId| {
+			!network_interface.is_layer(node_id, network_path)
+				&& network_interface.reference(node_id, network_path).is_some_and(|other| other.as_ref() == Some(&reference))
+				&& network_interface.number_of_inputs(node_id, network_path) == number_of_inputs
+		};
+
+		selected_nodes.iter().all(same_type).then_some(selected_nodes)
+	}
+
+	/// The nodes that an edit to an input of the given node applies to, which includes every node being edited together with it.
+	/// Edits are made to one node and fanned out here, so every message editing an input, from any widget, applies to the whole selection.
+	pub fn multi_edit_targets(network_interface: &NodeNetworkInterface, network_path: &[NodeId], node_id: NodeId) -> Vec<NodeId> {
+		Self::multi_edit_selection(network_interface, network_path)
+			.filter(|nodes| nodes.contains(&node_id))
+			.unwrap_or_else(|| vec![node_id])
+	}
+
+	/// Collate the properties panel sections for a node graph, beneath any parameters which have been pinned to the top
 	pub fn collate_properties(context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+		let pinned_parameters = node_properties::generate_pinned_parameters(context);
+		let mut properties = Self::collate_selection_properties(context);
+		if let Some(pinned_parameters) = pinned_parameters {
+			properties.insert(0, pinned_parameters);
+		}
+		properties
+	}
+
+	/// The document's settings for how its artwork is rendered in the viewport, shown while nothing is selected.
+	fn render_settings_section(preview_quality: PreviewQuality) -> LayoutGroup {
+		let tooltip = "How much detail expensive nodes, like the octaves of a Noise Pattern or the points of Scatter Points, are computed with in the viewport.\n\
+			Lower quality keeps the viewport responsive while editing. Exports are always rendered at full quality.";
+		let entries = PreviewQuality::ALL
+			.iter()
+			.map(|&preview_quality| {
+				RadioEntryData::new(format!("{preview_quality:?}"))
+					.label(preview_quality.to_string())
+					.tooltip(format!("{preview_quality} Quality ({}% Detail)", (preview_quality.detail() * 100.) as u32))
+					.on_update(move |_| DocumentMessage::SetPreviewQuality { preview_quality }.into())
+			})
+			.collect();
+
+		LayoutGroup::Section {
+			name: "Render Settings".to_string(),
+			description: "How the artwork of the document is rendered in the viewport".to_string(),
+			visible: true,
+			bypassed: false,
+			pinned: false,
+			id: 0,
+			node_controls: false,
+			layout: vec![LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new("Preview Quality").tooltip(tooltip).widget_holder(),
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					RadioInput::new(entries)
+						.selected_index(PreviewQuality::ALL.iter().position(|&quality| quality == preview_quality).map(|index| index as u32))
+						.widget_holder(),
+				],
+			}],
+		}
+	}
+
+	fn collate_selection_properties(context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 		// If the selected nodes are in the document network, use the document network. Otherwise, use the nested network
 		let Some(selected_nodes) = context.network_interface.selected_nodes_in_nested_network(context.selection_network_path) else {
 			warn!("No selected nodes in collate_properties");
@@ -2012,6 +3128,11 @@ impl NodeGraphMessageHandler {
 		match layers.len() {
 			// If no layers are selected, show properties for all selected nodes
 			0 => {
+				// Several nodes of the same type share one section, with edits applying to all of them
+				if let Some(nodes) = Self::multi_edit_selection(context.network_interface, context.selection_network_path) {
+					return vec![node_properties::generate_multi_node_properties(&nodes, context)];
+				}
+
 				let selected_nodes = nodes.iter().map(|node_id| node_properties::generate_node_properties(*node_id, context)).collect::<Vec<_>>();
 				if !selected_nodes.is_empty() {
 					return selected_nodes;
@@ -2031,6 +3152,7 @@ impl NodeGraphMessageHandler {
 						Separator::new(SeparatorType::Related).widget_holder(),
 					],
 				}];
+				properties.push(Self::render_settings_section(context.preview_quality));
 
 				let Some(network) = context.network_interface.nested_network(context.selection_network_path) else {
 					warn!("No network in collate_properties");
@@ -2130,6 +3252,54 @@ impl NodeGraphMessageHandler {
 		}
 	}
 
+	fn collect_frames(network_interface: &NodeNetworkInterface, breadcrumb_network_path: &[NodeId]) -> Vec<FrontendGraphFrame> {
+		network_interface
+			.frames(breadcrumb_network_path)
+			.iter()
+			.map(|frame| FrontendGraphFrame {
+				id: frame.id,
+				title: frame.title.clone(),
+				color: format!("#{}", frame.color.to_rgb_hex_srgb()),
+				position: frame.position.into(),
+				dimensions: frame.dimensions.into(),
+			})
+			.collect()
+	}
+
+	/// Get the search term which filters the node catalog to the nodes with an input accepting the type of data from the given output
+	fn compatible_type_search(network_interface: &NodeNetworkInterface, output_connector: &OutputConnector, network_path: &[NodeId]) -> Option<String> {
+		let node_id = output_connector.node_id()?;
+		let output_types = network_interface.output_types(&node_id, network_path);
+		let (output_type, _) = output_types.get(output_connector.index())?.as_ref()?;
+
+		Some(format!("type:{}", output_type.nested_type()))
+	}
+
+	fn collect_notes(network_interface: &NodeNetworkInterface, breadcrumb_network_path: &[NodeId]) -> Vec<FrontendGraphNote> {
+		network_interface
+			.notes(breadcrumb_network_path)
+			.iter()
+			.map(|note| FrontendGraphNote {
+				id: note.id,
+				text: note.text.clone(),
+				position: note.position.into(),
+				dimensions: note.dimensions.into(),
+			})
+			.collect()
+	}
+
+	/// Get the sticky notes overlapping the area spanned by the given nodes
+	fn notes_over_nodes(network_interface: &mut NodeNetworkInterface, node_ids: &[NodeId], network_path: &[NodeId]) -> Vec<NodeGraphNote> {
+		let Some(bounds) = node_ids
+			.iter()
+			.filter_map(|node_id| network_interface.node_bounding_box(node_id, network_path))
+			.reduce(|[a_min, a_max], [b_min, b_max]| [a_min.min(b_min), a_max.max(b_max)])
+		else {
+			return Vec::new();
+		};
+		network_interface.notes_overlapping(bounds, network_path)
+	}
+
 	fn collect_wires(network_interface: &NodeNetworkInterface, breadcrumb_network_path: &[NodeId]) -> Vec<FrontendNodeWire> {
 		let Some(network) = network_interface.nested_network(breadcrumb_network_path) else {
 			log::error!("Could not get network when collecting wires");
@@ -2339,6 +3509,7 @@ impl NodeGraphMessageHandler {
 				position,
 				previewed,
 				visible: node.visible,
+				bypassed: node.bypassed,
 				locked,
 				errors,
 				ui_only: false,
@@ -2363,7 +3534,15 @@ impl NodeGraphMessageHandler {
 		Some(subgraph_names)
 	}
 
-	fn update_layer_panel(network_interface: &NodeNetworkInterface, selection_network_path: &[NodeId], collapsed: &CollapsedLayers, responses: &mut VecDeque<Message>) {
+	/// Whether the layer is hidden while another layer is soloed, because it's neither that layer nor one of its ancestors or descendants.
+	pub fn hidden_by_solo(&self, layer: LayerNodeIdentifier, metadata: &DocumentMetadata) -> bool {
+		let Some(solo_layer) = self.solo_layer.filter(|&solo_layer| metadata.layer_exists(solo_layer)) else {
+			return false;
+		};
+		!layer.ancestors(metadata).any(|ancestor| ancestor == solo_layer) && !solo_layer.ancestors(metadata).any(|ancestor| ancestor == layer)
+	}
+
+	fn update_layer_panel(&self, network_interface: &NodeNetworkInterface, selection_network_path: &[NodeId], collapsed: &CollapsedLayers, responses: &mut VecDeque<Message>) {
 		let selected_layers = network_interface
 			.selected_nodes()
 			.selected_layers(network_interface.document_metadata())
@@ -2425,6 +3604,7 @@ impl NodeGraphMessageHandler {
 					in_selected_network: selection_network_path.is_empty(),
 					children_allowed,
 					children_present: layer.has_children(network_interface.document_metadata()),
+					adjustment: network_interface.is_adjustment_layer(&node_id, &[]),
 					expanded: layer.has_children(network_interface.document_metadata()) && !collapsed.0.contains(&layer),
 					depth: layer.ancestors(network_interface.document_metadata()).count() - 1,
 					visible: network_interface.is_visible(&node_id, &[]),
@@ -2437,13 +3617,19 @@ impl NodeGraphMessageHandler {
 					selected: selected_layers.contains(&node_id),
 					ancestor_of_selected: ancestors_of_selected.contains(&node_id),
 					descendant_of_selected: descendants_of_selected.contains(&node_id),
+					soloed: self.solo_layer == Some(layer),
+					hidden_by_solo: self.hidden_by_solo(layer, network_interface.document_metadata()),
 				};
 				responses.add(FrontendMessage::UpdateDocumentLayerDetails { data });
 			}
 		}
 	}
 
-	fn build_wire_path_string(output_position: DVec2, input_position: DVec2, vertical_out: bool, vertical_in: bool) -> String {
+	fn build_wire_path_string(output_position: DVec2, input_position: DVec2, vertical_out: bool, vertical_in: bool, wire_style: GraphWireStyle) -> String {
+		if wire_style == GraphWireStyle::Straight {
+			return format!("M{},{} L{},{}", output_position.x, output_position.y, input_position.x, input_position.y);
+		}
+
 		let locations = Self::build_wire_path_locations(output_position, input_position, vertical_out, vertical_in);
 		let smoothing = 0.5;
 		let delta01 = DVec2::new((locations[1].x - locations[0].x) * smoothing, (locations[1].y - locations[0].y) * smoothing);
@@ -2465,6 +3651,159 @@ impl NodeGraphMessageHandler {
 		)
 	}
 
+	/// Shows the value carried by a wire in a tooltip at the given point in node graph coordinates, evaluating the output the wire comes from.
+	/// The value already shown is kept until the new one arrives if the wire still comes from the same output.
+	fn probe_wire(&mut self, input_connector: InputConnector, position: DVec2, inspected: bool, network_interface: &NodeNetworkInterface, network_path: &[NodeId], responses: &mut VecDeque<Message>) {
+		let (node_path, value) = match Self::probed_node_path(network_interface.upstream_output_connector(&input_connector, network_path), network_path) {
+			Ok(node_path) => {
+				responses.add(PortfolioMessage::ProbeNode { node_path: node_path.clone() });
+
+				let value = self
+					.wire_probe
+					.as_ref()
+					.filter(|probe| probe.node_path.as_ref() == Some(&node_path))
+					.and_then(|probe| probe.value.clone());
+				(Some(node_path), value)
+			}
+			Err(unavailable) => (None, Some(unavailable)),
+		};
+
+		self.wire_probe = Some(WireProbe {
+			input_connector,
+			network_path: network_path.to_vec(),
+			node_path,
+			position,
+			inspected,
+			value,
+		});
+		self.send_wire_probe(responses);
+	}
+
+	fn send_wire_probe(&self, responses: &mut VecDeque<Message>) {
+		let wire_probe = self.wire_probe.as_ref().map(|probe| FrontendWireProbe {
+			position: (probe.position.x, probe.position.y),
+			inspected: probe.inspected,
+			value: probe.value.clone(),
+		});
+		responses.add(FrontendMessage::UpdateWireProbe { wire_probe });
+	}
+
+	/// The document node path of the node whose value is carried by a wire from the given output, or the reason the value can't be shown.
+	fn probed_node_path(output_connector: Option<OutputConnector>, network_path: &[NodeId]) -> Result<Vec<NodeId>, WireProbeValue> {
+		match output_connector {
+			Some(OutputConnector::Node { node_id, output_index: 0 }) => Ok([network_path, &[node_id]].concat()),
+			Some(OutputConnector::Node { .. }) => Err(WireProbeValue::unavailable("Only the primary output of a node can be inspected")),
+			_ => Err(WireProbeValue::unavailable("The value of an import can be inspected where the subgraph is used")),
+		}
+	}
+
+	/// The document node paths of the nodes whose values are carried by the pinned wires of the network.
+	fn pinned_wire_node_paths(network_interface: &NodeNetworkInterface, network_path: &[NodeId]) -> Vec<Vec<NodeId>> {
+		network_interface
+			.pinned_wire_probes(network_path)
+			.iter()
+			.filter_map(|input_connector| Self::probed_node_path(network_interface.upstream_output_connector(input_connector, network_path), network_path).ok())
+			.collect()
+	}
+
+	/// Sends the readouts pinned along the wires of the network, leaving out the wires which no longer exist.
+	/// The values which haven't been evaluated yet are requested, and arrive with `NodeGraphMessage::UpdateWireProbe`.
+	fn send_pinned_wire_probes(&mut self, network_interface: &mut NodeNetworkInterface, network_path: &[NodeId], wire_style: GraphWireStyle, responses: &mut VecDeque<Message>) {
+		let node_paths = Self::pinned_wire_node_paths(network_interface, network_path);
+		self.pinned_wire_probe_values.retain(|node_path, _| node_paths.contains(node_path));
+
+		let mut pinned_wire_probes = Vec::new();
+		for input_connector in network_interface.pinned_wire_probes(network_path).to_vec() {
+			let Some(output_connector) = network_interface.upstream_output_connector(&input_connector, network_path) else {
+				continue;
+			};
+			let Some(position) = Self::wire_midpoint(network_interface, &input_connector, &output_connector, network_path, wire_style) else {
+				continue;
+			};
+
+			let value = match Self::probed_node_path(Some(output_connector), network_path) {
+				Ok(node_path) => {
+					let value = self.pinned_wire_probe_values.get(&node_path).cloned();
+					if value.is_none() {
+						responses.add(PortfolioMessage::ProbeNode { node_path });
+					}
+					value
+				}
+				Err(unavailable) => Some(unavailable),
+			};
+			pinned_wire_probes.push(FrontendPinnedWireProbe {
+				input_connector,
+				position: (position.x, position.y),
+				value,
+			});
+		}
+		responses.add(FrontendMessage::UpdatePinnedWireProbes { pinned_wire_probes });
+	}
+
+	/// The point halfway along the wire from the output to the input, in node graph coordinates.
+	fn wire_midpoint(
+		network_interface: &mut NodeNetworkInterface,
+		input_connector: &InputConnector,
+		output_connector: &OutputConnector,
+		network_path: &[NodeId],
+		wire_style: GraphWireStyle,
+	) -> Option<DVec2> {
+		let input_position = network_interface.input_position(input_connector, network_path)?;
+		let output_position = network_interface.output_position(output_connector, network_path)?;
+		if wire_style == GraphWireStyle::Straight {
+			return Some(output_position.lerp(input_position, 0.5));
+		}
+
+		let start_node_is_layer = output_connector.node_id().is_some_and(|node_id| network_interface.is_layer(&node_id, network_path));
+		let end_node_is_layer = input_connector.node_id().is_some_and(|node_id| network_interface.is_layer(&node_id, network_path));
+		let locations = Self::build_wire_path_locations(output_position, input_position, start_node_is_layer, end_node_is_layer);
+		let bezier = bezier_rs::Bezier::from_cubic_dvec2(locations[0], locations[1], locations[2], locations[3]);
+		Some(bezier.evaluate(bezier_rs::TValue::Parametric(0.5)))
+	}
+
+	/// The input at the end of the wire passing under the click, or `None` if there is no wire there or several wires overlap at the click.
+	fn wire_from_click(network_interface: &NodeNetworkInterface, click: DVec2, network_path: &[NodeId], wire_style: GraphWireStyle) -> Option<InputConnector> {
+		let network_metadata = network_interface.network_metadata(network_path)?;
+		let node_graph_to_viewport = network_metadata.persistent_metadata.navigation_metadata.node_graph_to_viewport;
+		let node_graph_point = node_graph_to_viewport.inverse().transform_point2(click);
+		// Wires are thin, so they are hit within a few pixels of the click regardless of the zoom level
+		let tolerance = DVec2::splat(4. / node_graph_to_viewport.matrix2.x_axis.length().max(f64::EPSILON));
+
+		let mut clicked_wires = Self::collect_wires(network_interface, network_path).into_iter().filter(|wire| {
+			let (Some(input_position), Some(output_position)) = (
+				network_interface.input_position(&wire.wire_end, network_path),
+				network_interface.output_position(&wire.wire_start, network_path),
+			) else {
+				return false;
+			};
+			let start_node_is_layer = wire.wire_start.node_id().is_some_and(|wire_start_id| network_interface.is_layer(&wire_start_id, network_path));
+			let end_node_is_layer = wire.wire_end.node_id().is_some_and(|wire_end_id| network_interface.is_layer(&wire_end_id, network_path));
+
+			let bezier = if wire_style == GraphWireStyle::Straight {
+				bezier_rs::Bezier::from_linear_dvec2(output_position, input_position)
+			} else {
+				let locations = Self::build_wire_path_locations(output_position, input_position, start_node_is_layer, end_node_is_layer);
+				bezier_rs::Bezier::from_cubic_dvec2(locations[0], locations[1], locations[2], locations[3])
+			};
+			!bezier.rectangle_intersections(node_graph_point - tolerance, node_graph_point + tolerance).is_empty()
+		});
+		let wire = clicked_wires.next()?;
+		if clicked_wires.next().is_some() {
+			return None;
+		}
+
+		// The wire ends at the index among the node's exposed inputs, which is converted to the index among all its inputs
+		let Some(end_node_id) = wire.wire_end.node_id() else { return Some(wire.wire_end) };
+		let input_index = (0..network_interface.number_of_inputs(&end_node_id, network_path))
+			.filter(|&input_index| {
+				network_interface
+					.input_from_connector(&InputConnector::node(end_node_id, input_index), network_path)
+					.is_some_and(|input| input.is_exposed_to_frontend(network_path.is_empty()))
+			})
+			.nth(wire.wire_end.input_index())?;
+		Some(InputConnector::node(end_node_id, input_index))
+	}
+
 	fn build_wire_path_locations(output_position: DVec2, input_position: DVec2, vertical_out: bool, vertical_in: bool) -> Vec<DVec2> {
 		let horizontal_gap = (output_position.x - input_position.x).abs();
 		let vertical_gap = (output_position.y - input_position.y).abs();
@@ -2617,6 +3956,39 @@ fn frontend_inputs_lookup(breadcrumb_network_path: &[NodeId], network_interface:
 	frontend_inputs_lookup
 }
 
+/// The run of Levels nodes feeding the input one after another through their primary inputs, from downstream to upstream,
+/// and the node beyond them which provides the image they adjust, if there is one.
+fn levels_chain(network_interface: &NodeNetworkInterface, input_connector: &InputConnector, network_path: &[NodeId]) -> (Vec<NodeId>, Option<NodeId>) {
+	let mut chain = Vec::new();
+	let mut input_connector = *input_connector;
+	loop {
+		let Some(node_id) = network_interface.upstream_output_connector(&input_connector, network_path).and_then(|output| output.node_id()) else {
+			return (chain, None);
+		};
+		let is_levels = network_interface.reference(&node_id, network_path).cloned().flatten().as_deref() == Some("Levels");
+		if !is_levels {
+			return (chain, Some(node_id));
+		}
+		chain.push(node_id);
+		input_connector = InputConnector::node(node_id, 0);
+	}
+}
+
+/// Converts the output captured by inspecting a node into a value which can be embedded in the Bake node.
+fn baked_value(introspected_data: &dyn std::any::Any) -> Option<TaggedValue> {
+	use crate::node_graph_executor::introspected_output;
+	use graphene_core::raster::image::ImageFrameTable;
+	use graphene_core::vector::VectorDataTable;
+
+	if let Some(vector_data) = introspected_output::<VectorDataTable>(introspected_data) {
+		Some(TaggedValue::VectorData(vector_data.clone()))
+	} else if let Some(image_frames) = introspected_output::<ImageFrameTable<Color>>(introspected_data) {
+		Some(TaggedValue::ImageFrame(image_frames.clone()))
+	} else {
+		introspected_output::<GraphicGroupTable>(introspected_data).map(|graphic_group| TaggedValue::GraphicGroup(graphic_group.clone()))
+	}
+}
+
 impl Default for NodeGraphMessageHandler {
 	fn default() -> Self {
 		Self {
@@ -2630,6 +4002,7 @@ impl Default for NodeGraphMessageHandler {
 			shift_without_push: false,
 			box_selection_start: None,
 			selection_before_pointer_down: Vec::new(),
+			annotation_drag: None,
 			disconnecting: None,
 			initial_disconnecting: false,
 			select_if_not_dragged: None,
@@ -2642,6 +4015,12 @@ impl Default for NodeGraphMessageHandler {
 			reordering_export: None,
 			reordering_import: None,
 			end_index: None,
+			baking_node: None,
+			auto_adjusting: None,
+			solo_layer: None,
+			provisional_inputs: HashMap::new(),
+			wire_probe: None,
+			pinned_wire_probe_values: HashMap::new(),
 		}
 	}
 }
@@ -2663,3 +4042,33 @@ impl PartialEq for NodeGraphMessageHandler {
 			&& self.context_menu == other.context_menu
 	}
 }
+
+/// The contents of the clipboard when copying nodes, along with the sticky notes placed over them
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct CopiedNodes {
+	nodes: Vec<(NodeId, NodeTemplate)>,
+	#[serde(default)]
+	notes: Vec<NodeGraphNote>,
+}
+
+/// A frame or sticky note drawn in the node graph alongside the nodes
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum GraphAnnotation {
+	Frame(u64),
+	Note(u64),
+}
+
+/// State of a frame or sticky note being moved by its title bar or resized by its grip
+#[derive(Debug, Clone, PartialEq)]
+struct AnnotationDrag {
+	annotation: GraphAnnotation,
+	target: FrameClickTarget,
+	/// Where the drag began, in node graph coordinates
+	start: DVec2,
+	/// How many grid cells the frame has been moved or resized so far
+	round: IVec2,
+	/// The size of the frame or note when the drag began, in grid units
+	start_dimensions: IVec2,
+	/// The nodes which were inside the frame when the drag began, and which move along with it (always empty for notes)
+	contained_nodes: Vec<NodeId>,
+}
@@ -1,32 +1,39 @@
 #![allow(clippy::too_many_arguments)]
 
-use super::document_node_definitions::{NODE_OVERRIDES, NodePropertiesContext};
+use super::adjustment_presets::PresetManager;
+use super::document_node_definitions::{HISTOGRAM_NODE_TYPES, NODE_OVERRIDES, NodePropertiesContext};
 use super::utility_types::FrontendGraphDataType;
 use crate::messages::layout::utility_types::widget_prelude::*;
-use crate::messages::portfolio::document::utility_types::network_interface::InputConnector;
+use crate::messages::portfolio::document::graph_operation::transform_utils::compute_scale_angle_translation_shear;
+use crate::messages::portfolio::document::utility_types::drivers::{DriverVariables, driven_value};
+use crate::messages::portfolio::document::utility_types::misc::evaluate_math_expression;
+use crate::messages::portfolio::document::utility_types::network_interface::{FlowType, InputConnector, NodeNetworkInterface, OutputConnector};
 use crate::messages::prelude::*;
 use dyn_any::DynAny;
 use glam::{DAffine2, DVec2, IVec2, UVec2};
 use graph_craft::Type;
 use graph_craft::document::value::TaggedValue;
-use graph_craft::document::{DocumentNode, DocumentNodeImplementation, NodeId, NodeInput};
-use graphene_core::raster::curve::Curve;
+use graph_craft::document::{DocumentNode, DocumentNodeImplementation, NodeId, NodeInput, generate_uuid};
+use graphene_core::application_io::FilePath;
+use graphene_core::raster::curve::{Curve, CurveChannel, Curves};
+use graphene_core::raster::histogram::AutoAdjustment;
 use graphene_core::raster::image::ImageFrameTable;
+use graphene_core::raster::statistics::{ColorStatistic, LuminanceStatistic};
 use graphene_core::raster::{
-	BlendMode, CellularDistanceFunction, CellularReturnType, Color, DomainWarpType, FractalType, LuminanceCalculation, NoiseType, RedGreenBlue, RedGreenBlueAlpha, RelativeAbsolute,
-	SelectiveColorChoice,
+	BlendMode, CellularDistanceFunction, CellularReturnType, Color, DomainWarpType, FractalType, HueBand, LevelsChannel, LuminanceCalculation, NoiseType, RedGreenBlue, RedGreenBlueAlpha,
+	RelativeAbsolute, SelectiveColorChoice, TonalRange,
 };
-use graphene_core::text::Font;
+use graphene_core::text::{Font, TextAlign, VerticalAlign};
 use graphene_core::vector::misc::CentroidType;
-use graphene_core::vector::style::{GradientType, LineCap, LineJoin};
-use graphene_std::animation::RealTimeMode;
+use graphene_core::vector::style::{GradientType, LineCap, LineJoin, PaintOrder, StrokeAlign, StrokeMarker};
+use graphene_std::animation::{CharacterAnimation, RealTimeMode};
 use graphene_std::application_io::TextureFrameTable;
 use graphene_std::ops::XY;
-use graphene_std::transform::Footprint;
+use graphene_std::transform::{FitMode, Footprint, RandomDistribution};
 use graphene_std::vector::VectorDataTable;
 use graphene_std::vector::misc::ArcType;
-use graphene_std::vector::misc::{BooleanOperation, GridType};
-use graphene_std::vector::style::{Fill, FillChoice, FillType, GradientStops};
+use graphene_std::vector::misc::{BarcodeFormat, BooleanOperation, GridType, MapProjection, PatternType, QrErrorCorrection, RowColorColumn, RowValueColumn};
+use graphene_std::vector::style::{Fill, FillChoice, FillType, GradientStops, Pattern};
 use graphene_std::{GraphicGroupTable, RasterFrame};
 
 pub(crate) fn string_properties(text: &str) -> Vec<LayoutGroup> {
@@ -112,6 +119,12 @@ pub(crate) fn property_from_type(
 		return Err(vec![]);
 	};
 
+	let raw_matrix = context
+		.network_interface
+		.input_metadata(&node_id, index, "raw_matrix", context.selection_network_path)
+		.and_then(|raw_matrix| raw_matrix.as_bool())
+		.unwrap_or_default();
+
 	let (mut number_min, mut number_max, range) = number_options;
 	let mut number_input = NumberInput::default();
 	if let Some((range_start, range_end)) = range {
@@ -140,12 +153,11 @@ pub(crate) fn property_from_type(
 					true,
 				)
 				.into(),
-				Some("PixelLength") => number_widget(document_node, node_id, index, name, description, number_input.min(min(0.)).unit(" px"), true).into(),
-				Some("Length") => number_widget(document_node, node_id, index, name, description, number_input.min(min(0.)), true).into(),
+				Some("PixelLength") | Some("Length") => number_widget(document_node, node_id, index, name, description, number_input.min(min(0.)).length_unit(context.length_unit), true).into(),
 				Some("Fraction") => number_widget(document_node, node_id, index, name, description, number_input.mode_range().min(min(0.)).max(max(1.)), true).into(),
 				Some("IntegerCount") => number_widget(document_node, node_id, index, name, description, number_input.int().min(min(1.)), true).into(),
-				Some("SeedValue") => number_widget(document_node, node_id, index, name, description, number_input.int().min(min(0.)), true).into(),
-				Some("Resolution") => vec2_widget(document_node, node_id, index, name, description, "W", "H", " px", Some(64.), add_blank_assist),
+				Some("SeedValue") => seed_widget(document_node, node_id, index, name, description, number_input.int().min(min(0.)), true).into(),
+				Some("Resolution") => vec2_widget(document_node, node_id, index, name, description, "W", "H", " px", Some(64.), add_blank_assist, None),
 
 				// For all other types, use TypeId-based matching
 				_ => {
@@ -160,24 +172,53 @@ pub(crate) fn property_from_type(
 						}
 						Some(x) if x == TypeId::of::<u64>() => number_widget(document_node, node_id, index, name, description, number_input.int().min(min(0.)), true).into(),
 						Some(x) if x == TypeId::of::<String>() => text_widget(document_node, node_id, index, name, description, true).into(),
+						Some(x) if x == TypeId::of::<FilePath>() => file_path_widget(document_node, node_id, index, name, description, "", true).into(),
 						Some(x) if x == TypeId::of::<Color>() => color_widget(document_node, node_id, index, name, description, ColorInput::default().allow_none(false), true),
 						Some(x) if x == TypeId::of::<Option<Color>>() => color_widget(document_node, node_id, index, name, description, ColorInput::default().allow_none(true), true),
-						Some(x) if x == TypeId::of::<DVec2>() => vec2_widget(document_node, node_id, index, name, description, "X", "Y", "", None, add_blank_assist),
-						Some(x) if x == TypeId::of::<UVec2>() => vec2_widget(document_node, node_id, index, name, description, "X", "Y", "", Some(0.), add_blank_assist),
-						Some(x) if x == TypeId::of::<IVec2>() => vec2_widget(document_node, node_id, index, name, description, "X", "Y", "", None, add_blank_assist),
+						Some(x) if x == TypeId::of::<DVec2>() => {
+							let picking = context.position_picker_target == Some((node_id, index));
+							vec2_widget(document_node, node_id, index, name, description, "X", "Y", "", None, add_blank_assist, Some(picking))
+						}
+						Some(x) if x == TypeId::of::<UVec2>() => vec2_widget(document_node, node_id, index, name, description, "X", "Y", "", Some(0.), add_blank_assist, None),
+						Some(x) if x == TypeId::of::<IVec2>() => vec2_widget(document_node, node_id, index, name, description, "X", "Y", "", None, add_blank_assist, None),
 						Some(x) if x == TypeId::of::<Vec<f64>>() => vec_f64_input(document_node, node_id, index, name, description, TextInput::default(), true).into(),
 						Some(x) if x == TypeId::of::<Vec<DVec2>>() => vec_dvec2_input(document_node, node_id, index, name, description, TextInput::default(), true).into(),
 						Some(x) if x == TypeId::of::<Font>() => {
 							let (font_widgets, style_widgets) = font_inputs(document_node, node_id, index, name, description, false);
 							font_widgets.into_iter().chain(style_widgets.unwrap_or_default()).collect::<Vec<_>>().into()
 						}
-						Some(x) if x == TypeId::of::<Curve>() => curves_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<Curve>() || x == TypeId::of::<Curves>() => {
+							let channel = context.curve_channels.get(&(node_id, index)).copied().unwrap_or_default();
+							let widgets = curves_widget(document_node, node_id, index, name, description, channel, true);
+							let (last, rest) = widgets.split_last().expect("Curves widget should return at least one row");
+							extra_widgets = rest.to_vec();
+							last.clone()
+						}
+						Some(x) if x == TypeId::of::<Vec<Color>>() => {
+							let widgets = vec_color_input(document_node, node_id, index, name, description, true);
+							let (last, rest) = widgets.split_last().expect("Palette widget should return at least one row");
+							extra_widgets = rest.to_vec();
+							last.clone()
+						}
 						Some(x) if x == TypeId::of::<GradientStops>() => color_widget(document_node, node_id, index, name, description, ColorInput::default().allow_none(false), true),
-						Some(x) if x == TypeId::of::<VectorDataTable>() => vector_widget(document_node, node_id, index, name, description, true).into(),
+						Some(x) if x == TypeId::of::<VectorDataTable>() => {
+							let preview = if context.selection_network_path.is_empty() {
+								vector_preview(node_id, index, context.network_interface, context.vector_preview, context.responses)
+							} else {
+								None
+							};
+							vector_widget(document_node, node_id, index, name, description, preview, true).into()
+						}
 						Some(x) if x == TypeId::of::<RasterFrame>() || x == TypeId::of::<ImageFrameTable<Color>>() || x == TypeId::of::<TextureFrameTable>() => {
 							raster_widget(document_node, node_id, index, name, description, true).into()
 						}
 						Some(x) if x == TypeId::of::<GraphicGroupTable>() => group_widget(document_node, node_id, index, name, description, true).into(),
+						Some(x) if x == TypeId::of::<DAffine2>() => {
+							let widgets = transform_widget(document_node, node_id, index, name, description, raw_matrix);
+							let (last, rest) = widgets.split_last().expect("Transform widget should return multiple rows");
+							extra_widgets = rest.to_vec();
+							last.clone()
+						}
 						Some(x) if x == TypeId::of::<Footprint>() => {
 							let widgets = footprint_widget(document_node, node_id, index);
 							let (last, rest) = widgets.split_last().expect("Footprint widget should return multiple rows");
@@ -186,8 +227,10 @@ pub(crate) fn property_from_type(
 						}
 						Some(x) if x == TypeId::of::<BlendMode>() => blend_mode(document_node, node_id, index, name, description, true),
 						Some(x) if x == TypeId::of::<RealTimeMode>() => real_time_mode(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<CharacterAnimation>() => character_animation_widget(document_node, node_id, index, name, description, true),
 						Some(x) if x == TypeId::of::<RedGreenBlue>() => color_channel(document_node, node_id, index, name, description, true),
 						Some(x) if x == TypeId::of::<RedGreenBlueAlpha>() => rgba_channel(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<LevelsChannel>() => levels_channel(document_node, node_id, index, name, description, true),
 						Some(x) if x == TypeId::of::<XY>() => xy_components(document_node, node_id, index, name, description, true),
 						Some(x) if x == TypeId::of::<NoiseType>() => noise_type(document_node, node_id, index, name, description, true),
 						Some(x) if x == TypeId::of::<FractalType>() => fractal_type(document_node, node_id, index, name, description, true, false),
@@ -209,7 +252,22 @@ pub(crate) fn property_from_type(
 						Some(x) if x == TypeId::of::<GridType>() => grid_type_widget(document_node, node_id, index, name, description, true),
 						Some(x) if x == TypeId::of::<LineCap>() => line_cap_widget(document_node, node_id, index, name, description, true),
 						Some(x) if x == TypeId::of::<LineJoin>() => line_join_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<StrokeAlign>() => stroke_align_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<PaintOrder>() => paint_order_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<StrokeMarker>() => stroke_marker_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<TextAlign>() => text_align_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<VerticalAlign>() => vertical_align_widget(document_node, node_id, index, name, description, true),
 						Some(x) if x == TypeId::of::<ArcType>() => arc_type_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<FitMode>() => fit_mode_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<RandomDistribution>() => random_distribution_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<RowValueColumn>() => row_value_column_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<RowColorColumn>() => row_color_column_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<PatternType>() => pattern_type_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<QrErrorCorrection>() => qr_error_correction_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<BarcodeFormat>() => barcode_format_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<MapProjection>() => map_projection_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<ColorStatistic>() => color_statistic_widget(document_node, node_id, index, name, description, true),
+						Some(x) if x == TypeId::of::<LuminanceStatistic>() => luminance_statistic_widget(document_node, node_id, index, name, description, true),
 						Some(x) if x == TypeId::of::<FillType>() => vec![
 							DropdownInput::new(vec![vec![
 								MenuListEntry::new("Solid")
@@ -292,11 +350,122 @@ pub(crate) fn property_from_type(
 		Type::Future(out) => return property_from_type(node_id, index, out, number_options, context),
 	};
 
-	extra_widgets.push(widgets);
+	extra_widgets.extend(driver_assist(keyframe_assist(widgets, document_node, node_id, index, context), document_node, node_id, index, context));
 
 	Ok(extra_widgets)
 }
 
+/// Replaces the blank assist in a property's row with a keyframe diamond button which adds or removes a keyframe for the input at the current time.
+fn keyframe_assist(row: LayoutGroup, document_node: &DocumentNode, node_id: NodeId, index: usize, context: &NodePropertiesContext) -> LayoutGroup {
+	let LayoutGroup::Row { mut widgets } = row else { return row };
+
+	// Only inputs holding their own value, rather than being fed by a wire, can be animated
+	let has_value = document_node.inputs.get(index).and_then(|input| input.as_non_exposed_value()).is_some();
+	let blank_assist = widgets
+		.iter_mut()
+		.find(|widget_holder| matches!(&widget_holder.widget, Widget::Separator(separator) if separator.separator_type == SeparatorType::Section));
+	let (true, Some(blank_assist)) = (has_value, blank_assist) else {
+		return LayoutGroup::Row { widgets };
+	};
+
+	let animated_input = context.keyframes.animated_input(context.selection_network_path, node_id, index);
+	let (icon, tooltip) = match animated_input.map(|animated_input| animated_input.keyframe_at(context.keyframes.time()).is_some()) {
+		Some(true) => ("KeyframeSet", "Remove the Keyframe at the Current Time"),
+		Some(false) => ("KeyframeAnimated", "Add a Keyframe at the Current Time\n\nThis input is animated, so editing it also adds a keyframe."),
+		None => ("KeyframeUnset", "Add a Keyframe at the Current Time to Animate This Input"),
+	};
+	*blank_assist = IconButton::new(icon, 24)
+		.tooltip(tooltip)
+		.on_update(move |_| DocumentMessage::ToggleKeyframe { node_id, input_index: index }.into())
+		.widget_holder();
+
+	LayoutGroup::Row { widgets }
+}
+
+/// Ends the row of a numeric input with an "fx" badge which drives the input with an expression, and for a driven input, adds the row below it where the expression is edited.
+fn driver_assist(row: LayoutGroup, document_node: &DocumentNode, node_id: NodeId, index: usize, context: &NodePropertiesContext) -> Vec<LayoutGroup> {
+	let LayoutGroup::Row { mut widgets } = row else { return vec![row] };
+
+	// Only inputs holding their own number, rather than being fed by a wire, can be driven
+	let Some(value) = document_node
+		.inputs
+		.get(index)
+		.and_then(|input| input.as_non_exposed_value())
+		.filter(|value| driven_value(value, 0.).is_some())
+	else {
+		return vec![LayoutGroup::Row { widgets }];
+	};
+
+	let driver = context.drivers.driver(context.selection_network_path, node_id, index);
+	let (tooltip, new_expression) = match driver {
+		Some(_) => ("Stop Driving This Input With an Expression\n\nThe input keeps the value it was last driven to.", None),
+		// A new driver starts out as the input's current value, so nothing changes until its expression is edited
+		None => (
+			"Drive This Input With an Expression\n\nIts value is then computed from the expression each time the graph is evaluated, which may use the node's other inputs (like \"width / 2\"), the outputs of other nodes by their names, and the animation time.",
+			Some(match value {
+				TaggedValue::Bool(boolean) => (*boolean as u8).to_string(),
+				TaggedValue::U32(number) => number.to_string(),
+				TaggedValue::U64(number) => number.to_string(),
+				TaggedValue::F64(number) => number.to_string(),
+				_ => String::new(),
+			}),
+		),
+	};
+	widgets.extend([
+		Separator::new(SeparatorType::Related).widget_holder(),
+		TextButton::new("fx")
+			.emphasized(driver.is_some())
+			.tooltip(tooltip)
+			.on_update(move |_| {
+				DocumentMessage::SetDriver {
+					node_id,
+					input_index: index,
+					expression: new_expression.clone(),
+				}
+				.into()
+			})
+			.widget_holder(),
+	]);
+	let Some(driver) = driver else { return vec![LayoutGroup::Row { widgets }] };
+
+	let time = context.keyframes.time();
+	let animated_values = context.keyframes.values_at(context.network_interface, time);
+	let variables = DriverVariables::new(
+		context.network_interface,
+		&animated_values,
+		context.drivers.node_outputs(),
+		context.selection_network_path,
+		node_id,
+		time,
+	);
+	let result = match variables.evaluate(driver) {
+		Ok(number) => TextLabel::new(format!("= {}", (number * 1000.).round() / 1000.)),
+		Err(error) => TextLabel::new(error).italic(true),
+	};
+
+	let mut expression_widgets = vec![TextLabel::new("").widget_holder()];
+	add_blank_assist(&mut expression_widgets);
+	expression_widgets.extend([
+		TextInput::new(&driver.expression)
+			.tooltip(format!("The expression driving this input, which may use:\n{}", variables.names().join(", ")))
+			.on_update(move |text_input: &TextInput| {
+				// Clearing the expression stops driving the input
+				let expression = Some(text_input.value.trim().to_string()).filter(|expression| !expression.is_empty());
+				DocumentMessage::SetDriver {
+					node_id,
+					input_index: index,
+					expression,
+				}
+				.into()
+			})
+			.widget_holder(),
+		Separator::new(SeparatorType::Related).widget_holder(),
+		result.widget_holder(),
+	]);
+
+	vec![LayoutGroup::Row { widgets }, LayoutGroup::Row { widgets: expression_widgets }]
+}
+
 pub fn text_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> Vec<WidgetHolder> {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
 
@@ -316,6 +485,27 @@ pub fn text_widget(document_node: &DocumentNode, node_id: NodeId, index: usize,
 	widgets
 }
 
+/// The `accept` argument limits the file types offered when browsing, in the format of the `accept` attribute of an HTML file input, or offers any file if it's empty.
+pub fn file_path_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, accept: &str, blank_assist: bool) -> Vec<WidgetHolder> {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return vec![];
+	};
+	if let Some(TaggedValue::FilePath(FilePath(path))) = &input.as_non_exposed_value() {
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			FilePathInput::new(path.clone())
+				.accept(accept)
+				.on_update(update_value(|x: &FilePathInput| TaggedValue::FilePath(FilePath(x.value.clone())), node_id, index))
+				.on_commit(commit_value)
+				.widget_holder(),
+		])
+	}
+	widgets
+}
+
 pub fn text_area_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> Vec<WidgetHolder> {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
 
@@ -355,6 +545,99 @@ pub fn bool_widget(document_node: &DocumentNode, node_id: NodeId, index: usize,
 	widgets
 }
 
+/// Edits a transform as its translation, scale, rotation, and skew, or as the six coefficients of its matrix when `raw_matrix` is chosen for this input.
+pub fn transform_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, raw_matrix: bool) -> Vec<LayoutGroup> {
+	let mut mode_widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, true);
+
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return vec![];
+	};
+	let Some(&TaggedValue::DAffine2(transform)) = input.as_non_exposed_value() else {
+		return vec![LayoutGroup::Row { widgets: mode_widgets }];
+	};
+
+	let entries = [
+		("Components", false, "Edit the translation, scale, rotation, and skew"),
+		("Matrix", true, "Edit the coefficients of the matrix directly"),
+	]
+	.into_iter()
+	.map(|(label, raw_matrix, tooltip)| {
+		RadioEntryData::new(label).label(label).tooltip(tooltip).on_update(move |_| {
+			NodeGraphMessage::SetInputMetadata {
+				node_id,
+				input_index: index,
+				field: "raw_matrix".to_string(),
+				value: raw_matrix.into(),
+			}
+			.into()
+		})
+	})
+	.collect();
+	mode_widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		RadioInput::new(entries).selected_index(Some(raw_matrix as u32)).widget_holder(),
+	]);
+
+	let number_input = |label: &str, unit: &str, value: f64, modify: Box<dyn Fn(f64) -> DAffine2 + Send + Sync>| {
+		NumberInput::new(Some(value))
+			.label(label)
+			.unit(unit)
+			.on_update(update_value(move |input: &NumberInput| TaggedValue::DAffine2(modify(input.value.unwrap())), node_id, index))
+			.on_commit(commit_value)
+			.widget_holder()
+	};
+	let row = |inputs: Vec<WidgetHolder>| {
+		let mut widgets = vec![TextLabel::new("").widget_holder()];
+		add_blank_assist(&mut widgets);
+		widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+		for (i, input) in inputs.into_iter().enumerate() {
+			if i > 0 {
+				widgets.push(Separator::new(SeparatorType::Related).widget_holder());
+			}
+			widgets.push(input);
+		}
+		LayoutGroup::Row { widgets }
+	};
+
+	let mut rows = vec![LayoutGroup::Row { widgets: mode_widgets }];
+	if raw_matrix {
+		// The coefficients are laid out as in the SVG `matrix(a b c d e f)` notation, with each row computing one axis of the transformed point
+		let [a, b, c, d, e, f] = transform.to_cols_array();
+		let coefficient = |label: &str, value: f64, position: usize| {
+			let modify = move |value: f64| {
+				let mut coefficients = transform.to_cols_array();
+				coefficients[position] = value;
+				DAffine2::from_cols_array(&coefficients)
+			};
+			number_input(label, "", value, Box::new(modify))
+		};
+		rows.push(row(vec![coefficient("A", a, 0), coefficient("C", c, 2), coefficient("E", e, 4)]));
+		rows.push(row(vec![coefficient("B", b, 1), coefficient("D", d, 3), coefficient("F", f, 5)]));
+	} else {
+		let (scale, angle, translation, shear) = compute_scale_angle_translation_shear(transform);
+		let skew = shear.x.atan().to_degrees();
+		let compose = move |translation: DVec2, scale: DVec2, angle: f64, skew: f64| {
+			DAffine2::from_scale_angle_translation(scale, angle, translation) * DAffine2::from_cols_array(&[1., 0., skew.to_radians().tan(), 1., 0., 0.])
+		};
+
+		rows.push(row(vec![
+			number_input("X", " px", translation.x, Box::new(move |x| compose(DVec2::new(x, translation.y), scale, angle, skew))),
+			number_input("Y", " px", translation.y, Box::new(move |y| compose(DVec2::new(translation.x, y), scale, angle, skew))),
+		]));
+		rows.push(row(vec![
+			number_input("Scale X", "x", scale.x, Box::new(move |x| compose(translation, DVec2::new(x, scale.y), angle, skew))),
+			number_input("Scale Y", "x", scale.y, Box::new(move |y| compose(translation, DVec2::new(scale.x, y), angle, skew))),
+		]));
+		rows.push(row(vec![
+			number_input("Rotation", "°", angle.to_degrees(), Box::new(move |rotation| compose(translation, scale, rotation.to_radians(), skew))),
+			number_input("Skew", "°", skew, Box::new(move |skew| compose(translation, scale, angle, skew.clamp(-89., 89.)))),
+		]));
+	}
+
+	rows
+}
+
 pub fn footprint_widget(document_node: &DocumentNode, node_id: NodeId, index: usize) -> Vec<LayoutGroup> {
 	let mut location_widgets = start_widgets(document_node, node_id, index, "Footprint", "TODO", FrontendGraphDataType::General, true);
 	location_widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
@@ -363,6 +646,10 @@ pub fn footprint_widget(document_node: &DocumentNode, node_id: NodeId, index: us
 	add_blank_assist(&mut scale_widgets);
 	scale_widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
 
+	let mut rotation_widgets = vec![TextLabel::new("").widget_holder()];
+	add_blank_assist(&mut rotation_widgets);
+	rotation_widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+
 	let mut resolution_widgets = vec![TextLabel::new("").widget_holder()];
 	add_blank_assist(&mut resolution_widgets);
 	resolution_widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
@@ -375,6 +662,7 @@ pub fn footprint_widget(document_node: &DocumentNode, node_id: NodeId, index: us
 		let top_left = footprint.transform.transform_point2(DVec2::ZERO);
 		let bounds = footprint.scale();
 		let oversample = footprint.resolution.as_dvec2() / bounds;
+		let angle = footprint.transform.matrix2.x_axis.y.atan2(footprint.transform.matrix2.x_axis.x);
 
 		location_widgets.extend_from_slice(&[
 			NumberInput::new(Some(top_left.x))
@@ -388,7 +676,7 @@ pub fn footprint_widget(document_node: &DocumentNode, node_id: NodeId, index: us
 						};
 
 						let footprint = Footprint {
-							transform: DAffine2::from_scale_angle_translation(scale, 0., offset),
+							transform: DAffine2::from_scale_angle_translation(scale, angle, offset),
 							resolution: (oversample * scale).as_uvec2(),
 							..footprint
 						};
@@ -412,7 +700,7 @@ pub fn footprint_widget(document_node: &DocumentNode, node_id: NodeId, index: us
 						};
 
 						let footprint = Footprint {
-							transform: DAffine2::from_scale_angle_translation(scale, 0., offset),
+							transform: DAffine2::from_scale_angle_translation(scale, angle, offset),
 							resolution: (oversample * scale).as_uvec2(),
 							..footprint
 						};
@@ -435,7 +723,7 @@ pub fn footprint_widget(document_node: &DocumentNode, node_id: NodeId, index: us
 						let (offset, scale) = (top_left, DVec2::new(x.value.unwrap_or_default(), bounds.y));
 
 						let footprint = Footprint {
-							transform: DAffine2::from_scale_angle_translation(scale, 0., offset),
+							transform: DAffine2::from_scale_angle_translation(scale, angle, offset),
 							resolution: (oversample * scale).as_uvec2(),
 							..footprint
 						};
@@ -456,7 +744,7 @@ pub fn footprint_widget(document_node: &DocumentNode, node_id: NodeId, index: us
 						let (offset, scale) = (top_left, DVec2::new(bounds.x, x.value.unwrap_or_default()));
 
 						let footprint = Footprint {
-							transform: DAffine2::from_scale_angle_translation(scale, 0., offset),
+							transform: DAffine2::from_scale_angle_translation(scale, angle, offset),
 							resolution: (oversample * scale).as_uvec2(),
 							..footprint
 						};
@@ -470,6 +758,28 @@ pub fn footprint_widget(document_node: &DocumentNode, node_id: NodeId, index: us
 				.widget_holder(),
 		]);
 
+		rotation_widgets.push(
+			NumberInput::new(Some(angle.to_degrees()))
+				.label("Rotation")
+				.unit("°")
+				.on_update(update_value(
+					move |x: &NumberInput| {
+						let angle = x.value.unwrap_or_default().to_radians();
+
+						let footprint = Footprint {
+							transform: DAffine2::from_scale_angle_translation(bounds, angle, top_left),
+							..footprint
+						};
+
+						TaggedValue::Footprint(footprint)
+					},
+					node_id,
+					index,
+				))
+				.on_commit(commit_value)
+				.widget_holder(),
+		);
+
 		resolution_widgets.push(
 			NumberInput::new(Some((footprint.resolution.as_dvec2() / bounds).x * 100.))
 				.label("Resolution")
@@ -492,10 +802,12 @@ pub fn footprint_widget(document_node: &DocumentNode, node_id: NodeId, index: us
 	vec![
 		LayoutGroup::Row { widgets: location_widgets },
 		LayoutGroup::Row { widgets: scale_widgets },
+		LayoutGroup::Row { widgets: rotation_widgets },
 		LayoutGroup::Row { widgets: resolution_widgets },
 	]
 }
 
+/// When `pick_on_canvas` is `Some`, a `DVec2` value gets a button to pick it by clicking and dragging a gizmo in the viewport, which is shown as active while it's being picked.
 pub fn vec2_widget(
 	document_node: &DocumentNode,
 	node_id: NodeId,
@@ -507,6 +819,7 @@ pub fn vec2_widget(
 	unit: &str,
 	min: Option<f64>,
 	mut assist: impl FnMut(&mut Vec<WidgetHolder>),
+	pick_on_canvas: Option<bool>,
 ) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::Number, false);
 
@@ -538,6 +851,20 @@ pub fn vec2_widget(
 					.on_commit(commit_value)
 					.widget_holder(),
 			]);
+			if let Some(picking) = pick_on_canvas {
+				widgets.extend_from_slice(&[
+					Separator::new(SeparatorType::Related).widget_holder(),
+					IconButton::new("Eyedropper", 24)
+						.active(picking)
+						.tooltip(if picking {
+							"Finish Picking on Canvas"
+						} else {
+							"Pick on Canvas\n\nClick in the viewport to set this position, then drag its gizmo to adjust it."
+						})
+						.on_update(move |_| DocumentMessage::StartPickingPosition { node_id, input_index: index }.into())
+						.widget_holder(),
+				]);
+			}
 		}
 		Some(&TaggedValue::IVec2(ivec2)) => {
 			let update_x = move |input: &NumberInput| TaggedValue::IVec2(IVec2::new(input.value.unwrap() as i32, ivec2.y));
@@ -622,15 +949,7 @@ pub fn vec2_widget(
 pub fn vec_f64_input(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, text_input: TextInput, blank_assist: bool) -> Vec<WidgetHolder> {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::Number, blank_assist);
 
-	let from_string = |string: &str| {
-		string
-			.split(&[',', ' '])
-			.filter(|x| !x.is_empty())
-			.map(str::parse::<f64>)
-			.collect::<Result<Vec<_>, _>>()
-			.ok()
-			.map(TaggedValue::VecF64)
-	};
+	let from_string = |string: &str| numbers_from_expressions(string).map(TaggedValue::VecF64);
 
 	let Some(input) = document_node.inputs.get(index) else {
 		log::warn!("A widget failed to be built because its node's input index is invalid.");
@@ -648,18 +967,48 @@ pub fn vec_f64_input(document_node: &DocumentNode, node_id: NodeId, index: usize
 	widgets
 }
 
+/// Reads a list of numbers typed into a text field, where the entries are separated by commas and each entry may be a math expression.
+fn numbers_from_expressions(string: &str) -> Option<Vec<f64>> {
+	if string.trim().is_empty() {
+		return Some(Vec::new());
+	}
+
+	split_top_level_commas(string)?.into_iter().map(evaluate_math_expression).collect()
+}
+
+/// Splits the string at the commas outside of any parentheses, so the commas of a function call stay within its entry.
+/// Returns `None` if the parentheses are unbalanced or an entry is empty.
+fn split_top_level_commas(string: &str) -> Option<Vec<&str>> {
+	let mut entries = Vec::new();
+	let mut depth = 0_usize;
+	let mut start = 0;
+
+	for (index, c) in string.char_indices() {
+		match c {
+			'(' => depth += 1,
+			')' => depth = depth.checked_sub(1)?,
+			',' if depth == 0 => {
+				entries.push(string[start..index].trim());
+				start = index + 1;
+			}
+			_ => {}
+		}
+	}
+	if depth != 0 {
+		return None;
+	}
+	entries.push(string[start..].trim());
+
+	if entries.iter().any(|entry| entry.is_empty()) {
+		return None;
+	}
+	Some(entries)
+}
+
 pub fn vec_dvec2_input(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, text_props: TextInput, blank_assist: bool) -> Vec<WidgetHolder> {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::Number, blank_assist);
 
-	let from_string = |string: &str| {
-		string
-			.split(|c: char| !c.is_alphanumeric() && !matches!(c, '.' | '+' | '-'))
-			.filter(|x| !x.is_empty())
-			.map(|x| x.parse::<f64>().ok())
-			.collect::<Option<Vec<_>>>()
-			.map(|numbers| numbers.chunks_exact(2).map(|values| DVec2::new(values[0], values[1])).collect())
-			.map(TaggedValue::VecDVec2)
-	};
+	let from_string = |string: &str| dvec2s_from_expressions(string).map(TaggedValue::VecDVec2);
 
 	let Some(input) = document_node.inputs.get(index) else {
 		log::warn!("A widget failed to be built because its node's input index is invalid.");
@@ -677,6 +1026,22 @@ pub fn vec_dvec2_input(document_node: &DocumentNode, node_id: NodeId, index: usi
 	widgets
 }
 
+/// Reads a list of "(x, y)" pairs typed into a text field, where the pairs are separated by commas and each component may be a math expression.
+fn dvec2s_from_expressions(string: &str) -> Option<Vec<DVec2>> {
+	if string.trim().is_empty() {
+		return Some(Vec::new());
+	}
+
+	split_top_level_commas(string)?
+		.into_iter()
+		.map(|pair| {
+			let components = split_top_level_commas(pair.strip_prefix('(')?.strip_suffix(')')?)?;
+			let [x, y] = components.as_slice() else { return None };
+			Some(DVec2::new(evaluate_math_expression(x)?, evaluate_math_expression(y)?))
+		})
+		.collect()
+}
+
 pub fn font_inputs(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> (Vec<WidgetHolder>, Option<Vec<WidgetHolder>>) {
 	let mut first_widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
 	let mut second_widgets = None;
@@ -711,15 +1076,67 @@ pub fn font_inputs(document_node: &DocumentNode, node_id: NodeId, index: usize,
 	(first_widgets, second_widgets)
 }
 
-pub fn vector_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> Vec<WidgetHolder> {
+pub fn vector_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, preview: Option<WidgetHolder>, blank_assist: bool) -> Vec<WidgetHolder> {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::VectorData, blank_assist);
 
 	widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
-	widgets.push(TextLabel::new("Vector data is supplied through the node graph").widget_holder());
+	widgets.push(preview.unwrap_or_else(|| TextLabel::new("Vector data is supplied through the node graph").widget_holder()));
 
 	widgets
 }
 
+/// The selected node and the node feeding its primary input in the document network, if exactly one node is selected.
+fn vector_preview_candidate(network_interface: &NodeNetworkInterface) -> Option<(NodeId, NodeId)> {
+	let selected_nodes = network_interface.selected_nodes().0;
+	let [node_id] = selected_nodes.as_slice() else { return None };
+	let source = network_interface.upstream_output_connector(&InputConnector::node(*node_id, 0), &[])?.node_id()?;
+	Some((*node_id, source))
+}
+
+/// The node whose output should be introspected to preview the vector data coming into the selected node, if its properties have requested it and it's still selected.
+pub fn vector_preview_inspect_node(network_interface: &NodeNetworkInterface, requested_source: Option<NodeId>) -> Option<NodeId> {
+	let (_, source) = vector_preview_candidate(network_interface)?;
+	(requested_source == Some(source)).then_some(source)
+}
+
+/// A thumbnail of the vector data coming into the primary input of the selected node, using the introspected output of the node feeding it, which focuses that node in the graph when clicked.
+fn vector_preview(
+	node_id: NodeId,
+	index: usize,
+	network_interface: &NodeNetworkInterface,
+	preview_state: Option<&(NodeId, Option<String>)>,
+	responses: &mut VecDeque<Message>,
+) -> Option<WidgetHolder> {
+	if index != 0 {
+		return None;
+	}
+	let (selected, source) = vector_preview_candidate(network_interface)?;
+	if selected != node_id {
+		return None;
+	}
+
+	let widget = match preview_state {
+		Some((previewed, Some(svg))) if *previewed == source && !svg.is_empty() => ThumbnailButton::new(svg)
+			.tooltip("Preview of the vector data coming into this node (click to focus the node supplying it)")
+			.on_update(move |_| {
+				Message::Batched(Box::new([
+					NodeGraphMessage::SelectedNodesSet { nodes: vec![source] }.into(),
+					DocumentMessage::GraphViewOverlay { open: true }.into(),
+					NavigationMessage::FitViewportToSelection.into(),
+				]))
+			})
+			.widget_holder(),
+		Some((previewed, Some(_))) if *previewed == source => TextLabel::new("No vector data").italic(true).widget_holder(),
+		Some((previewed, None)) if *previewed == source => TextLabel::new("Rendering preview…").italic(true).widget_holder(),
+		// The graph has not yet been run with the source node being introspected
+		_ => {
+			responses.add(PropertiesPanelMessage::RequestVectorPreview { source });
+			TextLabel::new("Rendering preview…").italic(true).widget_holder()
+		}
+	};
+	Some(widget)
+}
+
 pub fn raster_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> Vec<WidgetHolder> {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::Raster, blank_assist);
 
@@ -741,6 +1158,13 @@ pub fn group_widget(document_node: &DocumentNode, node_id: NodeId, index: usize,
 pub fn number_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, number_props: NumberInput, blank_assist: bool) -> Vec<WidgetHolder> {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::Number, blank_assist);
 
+	// Dragging a range slider (like those of percentages, angles, and fractions) moves it to the pointer, so its label is given a grip for scrubbing the value by small amounts
+	let number_props = if number_props.mode == NumberInputMode::Range {
+		number_props.scrub_label(true)
+	} else {
+		number_props
+	};
+
 	let Some(input) = document_node.inputs.get(index) else {
 		log::warn!("A widget failed to be built because its node's input index is invalid.");
 		return vec![];
@@ -803,6 +1227,46 @@ pub fn number_widget(document_node: &DocumentNode, node_id: NodeId, index: usize
 		_ => {}
 	}
 
+	// Dragging the name of the parameter scrubs its number, so the small number field doesn't have to be aimed at
+	if !widgets.iter().any(|widget_holder| matches!(widget_holder.widget, Widget::NumberInput(_))) {
+		return widgets;
+	}
+	if let Some(Widget::TextLabel(label)) = widgets.iter_mut().map(|widget_holder| &mut widget_holder.widget).find(|widget| matches!(widget, Widget::TextLabel(_))) {
+		label.scrubs_number_input = true;
+	}
+
+	widgets
+}
+
+/// A number input for a random seed, followed by a button which rerolls the seed to a new random value so one doesn't need to be typed in.
+pub fn seed_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, number_props: NumberInput, blank_assist: bool) -> Vec<WidgetHolder> {
+	let disabled = number_props.disabled;
+	let mut widgets = number_widget(document_node, node_id, index, name, description, number_props, blank_assist);
+
+	let reroll: fn(u64) -> TaggedValue = match document_node.inputs.get(index).and_then(|input| input.as_non_exposed_value()) {
+		Some(TaggedValue::U32(_)) => |random| TaggedValue::U32(random as u32),
+		Some(TaggedValue::U64(_)) => TaggedValue::U64,
+		_ => return widgets,
+	};
+	widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Related).widget_holder(),
+		IconButton::new("Random", 16)
+			.tooltip("Reroll the Seed to a New Random Value")
+			.disabled(disabled)
+			.on_update(move |_: &IconButton| {
+				Message::Batched(Box::new([
+					NodeGraphMessage::SetInputValue {
+						node_id,
+						input_index: index,
+						value: reroll(generate_uuid()),
+					}
+					.into(),
+					DocumentMessage::AddTransaction.into(),
+				]))
+			})
+			.widget_holder(),
+	]);
+
 	widgets
 }
 
@@ -834,6 +1298,33 @@ pub fn color_channel(document_node: &DocumentNode, node_id: NodeId, index: usize
 	LayoutGroup::Row { widgets }.with_tooltip("Color Channel")
 }
 
+pub fn levels_channel(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::LevelsChannel(mode)) = input.as_non_exposed_value() {
+		let channels = [LevelsChannel::Composite, LevelsChannel::Red, LevelsChannel::Green, LevelsChannel::Blue];
+		let mut entries = Vec::with_capacity(channels.len());
+		for channel in channels {
+			entries.push(
+				MenuListEntry::new(format!("{channel:?}"))
+					.label(channel.to_string())
+					.on_update(update_value(move |_| TaggedValue::LevelsChannel(channel), node_id, index))
+					.on_commit(commit_value),
+			);
+		}
+		let entries = vec![entries];
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(entries).selected_index(Some(mode as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Color Channel")
+}
+
 pub fn real_time_mode(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
 	let Some(input) = document_node.inputs.get(index) else {
@@ -868,6 +1359,37 @@ pub fn real_time_mode(document_node: &DocumentNode, node_id: NodeId, index: usiz
 	LayoutGroup::Row { widgets }.with_tooltip("Real Time Mode")
 }
 
+pub fn character_animation_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::CharacterAnimation(animation)) = input.as_non_exposed_value() {
+		let entries = [
+			(CharacterAnimation::Typewriter, "Reveal each character all at once when its turn comes"),
+			(CharacterAnimation::Wave, "Bob each character up and down in a wave traveling along the text"),
+			(CharacterAnimation::ScaleIn, "Grow each character from nothing about its center while fading it in"),
+			(CharacterAnimation::Custom, "Drive each character's opacity and scale with the curve"),
+		]
+		.into_iter()
+		.map(|(val, tooltip)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(val.to_string())
+				.tooltip(tooltip)
+				.on_update(update_value(move |_| TaggedValue::CharacterAnimation(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(animation as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
 pub fn rgba_channel(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
 	let Some(input) = document_node.inputs.get(index) else {
@@ -1082,6 +1604,7 @@ pub fn blend_mode(document_node: &DocumentNode, node_id: NodeId, index: usize, n
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
 			DropdownInput::new(entries)
 				.selected_index(blend_mode.index_in_list_svg_subset().map(|index| index as u32))
+				.searchable(true)
 				.widget_holder(),
 		]);
 	}
@@ -1171,6 +1694,31 @@ pub fn grid_type_widget(document_node: &DocumentNode, node_id: NodeId, index: us
 	LayoutGroup::Row { widgets }
 }
 
+pub fn map_projection_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::MapProjection(projection)) = input.as_non_exposed_value() {
+		let entries = [("Equirectangular", MapProjection::Equirectangular), ("Mercator", MapProjection::Mercator)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::MapProjection(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(projection as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
 pub fn line_cap_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
 	let Some(input) = document_node.inputs.get(index) else {
@@ -1221,47 +1769,511 @@ pub fn line_join_widget(document_node: &DocumentNode, node_id: NodeId, index: us
 	LayoutGroup::Row { widgets }
 }
 
-pub fn arc_type_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+pub fn stroke_align_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
 	let Some(input) = document_node.inputs.get(index) else {
 		log::warn!("A widget failed to be built because its node's input index is invalid.");
 		return LayoutGroup::Row { widgets: vec![] };
 	};
-	if let Some(&TaggedValue::ArcType(arc_type)) = input.as_non_exposed_value() {
-		let entries = [("Open", ArcType::Open), ("Closed", ArcType::Closed), ("Pie Slice", ArcType::PieSlice)]
-			.into_iter()
-			.map(|(name, val)| {
-				RadioEntryData::new(format!("{val:?}"))
-					.label(name)
-					.on_update(update_value(move |_| TaggedValue::ArcType(val), node_id, index))
-					.on_commit(commit_value)
-			})
-			.collect();
+	if let Some(&TaggedValue::StrokeAlign(align)) = input.as_non_exposed_value() {
+		let entries = [
+			("Center", StrokeAlign::Center, "Center the stroke on the path"),
+			("Inside", StrokeAlign::Inside, "Place the stroke inside of closed shapes"),
+			("Outside", StrokeAlign::Outside, "Place the stroke outside of closed shapes"),
+		]
+		.into_iter()
+		.map(|(name, val, tooltip)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(name)
+				.tooltip(tooltip)
+				.on_update(update_value(move |_| TaggedValue::StrokeAlign(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
 
 		widgets.extend_from_slice(&[
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			RadioInput::new(entries).selected_index(Some(arc_type as u32)).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(align as u32)).widget_holder(),
 		]);
 	}
 	LayoutGroup::Row { widgets }
 }
 
-pub fn color_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, color_button: ColorInput, blank_assist: bool) -> LayoutGroup {
+pub fn paint_order_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
-
-	// Return early with just the label if the input is exposed to the graph, meaning we don't want to show the color picker widget in the Properties panel
-	let NodeInput::Value { tagged_value, exposed: false } = &document_node.inputs[index] else {
-		return LayoutGroup::Row { widgets };
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
 	};
+	if let Some(&TaggedValue::PaintOrder(paint_order)) = input.as_non_exposed_value() {
+		let entries = [
+			("Stroke Above", PaintOrder::StrokeAbove, "Paint the stroke over the fill"),
+			("Stroke Below", PaintOrder::StrokeBelow, "Paint the stroke beneath the fill, which covers the inner half of the stroke"),
+		]
+		.into_iter()
+		.map(|(name, val, tooltip)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(name)
+				.tooltip(tooltip)
+				.on_update(update_value(move |_| TaggedValue::PaintOrder(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
 
-	// Add a separator
-	widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(paint_order as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
 
-	// Add the color input
-	match &**tagged_value {
+pub fn stroke_marker_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::StrokeMarker(marker)) = input.as_non_exposed_value() {
+		let markers = [StrokeMarker::None, StrokeMarker::Arrow, StrokeMarker::Circle, StrokeMarker::Square, StrokeMarker::Diamond];
+		let mut entries = Vec::with_capacity(markers.len());
+		for marker in markers {
+			entries.push(
+				MenuListEntry::new(format!("{marker:?}"))
+					.label(marker.to_string())
+					.on_update(update_value(move |_| TaggedValue::StrokeMarker(marker), node_id, index))
+					.on_commit(commit_value),
+			);
+		}
+		let entries = vec![entries];
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(entries).selected_index(Some(marker as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn text_align_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::TextAlign(align)) = input.as_non_exposed_value() {
+		let entries = [
+			("AlignLeft", TextAlign::Left, "Align Left"),
+			("AlignHorizontalCenter", TextAlign::Center, "Align Center"),
+			("AlignRight", TextAlign::Right, "Align Right"),
+		]
+		.into_iter()
+		.map(|(icon, val, tooltip)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.icon(icon)
+				.tooltip(tooltip)
+				.on_update(update_value(move |_| TaggedValue::TextAlign(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(align as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn vertical_align_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::VerticalAlign(align)) = input.as_non_exposed_value() {
+		let entries = [
+			("AlignTop", VerticalAlign::Top, "Align Top"),
+			("AlignVerticalCenter", VerticalAlign::Middle, "Align Middle"),
+			("AlignBottom", VerticalAlign::Bottom, "Align Bottom"),
+		]
+		.into_iter()
+		.map(|(icon, val, tooltip)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.icon(icon)
+				.tooltip(tooltip)
+				.on_update(update_value(move |_| TaggedValue::VerticalAlign(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(align as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn arc_type_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::ArcType(arc_type)) = input.as_non_exposed_value() {
+		let entries = [("Open", ArcType::Open), ("Closed", ArcType::Closed), ("Pie Slice", ArcType::PieSlice)]
+			.into_iter()
+			.map(|(name, val)| {
+				RadioEntryData::new(format!("{val:?}"))
+					.label(name)
+					.on_update(update_value(move |_| TaggedValue::ArcType(val), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(arc_type as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn random_distribution_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::RandomDistribution(distribution)) = input.as_non_exposed_value() {
+		let entries = [
+			(RandomDistribution::Uniform, "Every value in the range is equally likely"),
+			(RandomDistribution::Normal, "Values cluster around the middle of the range"),
+		]
+		.into_iter()
+		.map(|(val, tooltip)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(val.to_string())
+				.tooltip(tooltip)
+				.on_update(update_value(move |_| TaggedValue::RandomDistribution(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(distribution as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn fit_mode_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::FitMode(fit_mode)) = input.as_non_exposed_value() {
+		let entries = [
+			(FitMode::Contain, "Scale uniformly so the whole content fits inside the target"),
+			(FitMode::Cover, "Scale uniformly so the content covers the whole target"),
+			(FitMode::Stretch, "Scale each axis independently to exactly match the target"),
+		]
+		.into_iter()
+		.map(|(val, tooltip)| {
+			RadioEntryData::new(format!("{val:?}"))
+				.label(val.to_string())
+				.tooltip(tooltip)
+				.on_update(update_value(move |_| TaggedValue::FitMode(val), node_id, index))
+				.on_commit(commit_value)
+		})
+		.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(fit_mode as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+/// A row picking an edge or corner for a `DVec2` input that ranges from (0, 0) at the top left to (1, 1) at the bottom right.
+pub fn alignment_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::Number, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::DVec2(alignment)) = input.as_non_exposed_value() {
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			PivotInput::new(alignment.into())
+				.on_update(update_value(
+					move |pivot_input: &PivotInput| TaggedValue::DVec2(Option::<DVec2>::from(pivot_input.position).unwrap_or(alignment)),
+					node_id,
+					index,
+				))
+				.on_commit(commit_value)
+				.widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+/// A row editing one channel of a range whose minimum and maximum are separate inputs, either `f64` or a component of `DVec2`.
+pub fn range_widget(
+	document_node: &DocumentNode,
+	node_id: NodeId,
+	(min_index, max_index): (usize, usize),
+	component: Option<usize>,
+	name: &str,
+	description: &str,
+	number_props: NumberInput,
+) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, min_index, name, description, FrontendGraphDataType::Number, true);
+
+	let (Some(min_input), Some(max_input)) = (document_node.inputs.get(min_index), document_node.inputs.get(max_index)) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	let bound = |input: &NodeInput| match (input.as_non_exposed_value(), component) {
+		(Some(&TaggedValue::F64(value)), None) => Some(TaggedValue::F64(value)),
+		(Some(&TaggedValue::DVec2(value)), Some(_)) => Some(TaggedValue::DVec2(value)),
+		_ => None,
+	};
+	let (Some(min), Some(max)) = (bound(min_input), bound(max_input)) else {
+		return LayoutGroup::Row { widgets };
+	};
+
+	let bound_input = |bound: TaggedValue, label: &str, index: usize| {
+		let value = match (&bound, component) {
+			(TaggedValue::DVec2(value), Some(component)) => value[component],
+			(TaggedValue::F64(value), _) => *value,
+			_ => 0.,
+		};
+		number_props
+			.clone()
+			.label(label)
+			.value(Some(value))
+			.on_update(update_value(
+				move |input: &NumberInput| match (&bound, component) {
+					(TaggedValue::DVec2(value), Some(component)) => {
+						let mut value = *value;
+						value[component] = input.value.unwrap();
+						TaggedValue::DVec2(value)
+					}
+					_ => TaggedValue::F64(input.value.unwrap()),
+				},
+				node_id,
+				index,
+			))
+			.on_commit(commit_value)
+			.widget_holder()
+	};
+
+	widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		bound_input(min, "Min", min_index),
+		Separator::new(SeparatorType::Related).widget_holder(),
+		bound_input(max, "Max", max_index),
+	]);
+	LayoutGroup::Row { widgets }
+}
+
+pub fn row_value_column_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::RowValueColumn(column)) = input.as_non_exposed_value() {
+		let entries = RowValueColumn::list()
+			.into_iter()
+			.map(|column| {
+				MenuListEntry::new(format!("{column:?}"))
+					.label(column.to_string())
+					.on_update(update_value(move |_| TaggedValue::RowValueColumn(column), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(column as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn row_color_column_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::RowColorColumn(column)) = input.as_non_exposed_value() {
+		let entries = RowColorColumn::list()
+			.into_iter()
+			.map(|column| {
+				MenuListEntry::new(format!("{column:?}"))
+					.label(column.to_string())
+					.on_update(update_value(move |_| TaggedValue::RowColorColumn(column), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(column as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn pattern_type_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::PatternType(pattern_type)) = input.as_non_exposed_value() {
+		let entries = PatternType::list()
+			.into_iter()
+			.map(|pattern_type| {
+				MenuListEntry::new(format!("{pattern_type:?}"))
+					.label(pattern_type.to_string())
+					.on_update(update_value(move |_| TaggedValue::PatternType(pattern_type), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(pattern_type as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn qr_error_correction_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::QrErrorCorrection(error_correction)) = input.as_non_exposed_value() {
+		let entries = QrErrorCorrection::list()
+			.into_iter()
+			.map(|error_correction| {
+				MenuListEntry::new(format!("{error_correction:?}"))
+					.label(error_correction.to_string())
+					.on_update(update_value(move |_| TaggedValue::QrErrorCorrection(error_correction), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(error_correction as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn barcode_format_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::BarcodeFormat(format)) = input.as_non_exposed_value() {
+		let entries = BarcodeFormat::list()
+			.into_iter()
+			.map(|format| {
+				MenuListEntry::new(format!("{format:?}"))
+					.label(format.to_string())
+					.on_update(update_value(move |_| TaggedValue::BarcodeFormat(format), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(vec![entries]).selected_index(Some(format as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn color_statistic_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::ColorStatistic(statistic)) = input.as_non_exposed_value() {
+		let entries = ColorStatistic::list()
+			.into_iter()
+			.map(|statistic| {
+				RadioEntryData::new(format!("{statistic:?}"))
+					.label(statistic.to_string())
+					.on_update(update_value(move |_| TaggedValue::ColorStatistic(statistic), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(statistic as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn luminance_statistic_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return LayoutGroup::Row { widgets: vec![] };
+	};
+	if let Some(&TaggedValue::LuminanceStatistic(statistic)) = input.as_non_exposed_value() {
+		let entries = LuminanceStatistic::list()
+			.into_iter()
+			.map(|statistic| {
+				RadioEntryData::new(format!("{statistic:?}"))
+					.label(statistic.to_string())
+					.on_update(update_value(move |_| TaggedValue::LuminanceStatistic(statistic), node_id, index))
+					.on_commit(commit_value)
+			})
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(statistic as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub fn color_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, color_button: ColorInput, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+
+	// Return early with just the label if the input is exposed to the graph, meaning we don't want to show the color picker widget in the Properties panel
+	let NodeInput::Value { tagged_value, exposed: false } = &document_node.inputs[index] else {
+		return LayoutGroup::Row { widgets };
+	};
+
+	// Add a separator
+	widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+
+	// Add the color input
+	match &**tagged_value {
 		TaggedValue::Color(color) => widgets.push(
 			color_button
 				.value(FillChoice::Solid(*color))
+				.eyedropper(true)
 				.on_update(update_value(|x: &ColorInput| TaggedValue::Color(x.value.as_solid().unwrap_or_default()), node_id, index))
 				.on_commit(commit_value)
 				.widget_holder(),
@@ -1272,6 +2284,7 @@ pub fn color_widget(document_node: &DocumentNode, node_id: NodeId, index: usize,
 					Some(color) => FillChoice::Solid(*color),
 					None => FillChoice::None,
 				})
+				.eyedropper(true)
 				.on_update(update_value(|x: &ColorInput| TaggedValue::OptionalColor(x.value.as_solid()), node_id, index))
 				.on_commit(commit_value)
 				.widget_holder(),
@@ -1293,23 +2306,266 @@ pub fn color_widget(document_node: &DocumentNode, node_id: NodeId, index: usize,
 	LayoutGroup::Row { widgets }
 }
 
-pub fn curves_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> LayoutGroup {
+/// Builds the callback for a palette button which applies an edit to the colors as a single undo step.
+fn edit_palette<T>(palette: &[Color], node_id: NodeId, index: usize, edit: impl Fn(&mut Vec<Color>) + 'static + Send + Sync) -> impl Fn(&T) -> Message + 'static + Send + Sync {
+	let palette = palette.to_vec();
+	move |_: &T| {
+		let mut palette = palette.clone();
+		edit(&mut palette);
+		Message::Batched(Box::new([
+			NodeGraphMessage::SetInputValue {
+				node_id,
+				input_index: index,
+				value: TaggedValue::Palette(palette),
+			}
+			.into(),
+			DocumentMessage::AddTransaction.into(),
+		]))
+	}
+}
+
+pub fn vec_color_input(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, blank_assist: bool) -> Vec<LayoutGroup> {
 	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
 
 	let Some(input) = document_node.inputs.get(index) else {
 		log::warn!("A widget failed to be built because its node's input index is invalid.");
-		return LayoutGroup::Row { widgets: vec![] };
+		return vec![LayoutGroup::Row { widgets: vec![] }];
 	};
-	if let Some(TaggedValue::Curve(curve)) = &input.as_non_exposed_value() {
-		widgets.extend_from_slice(&[
+	let Some(TaggedValue::Palette(palette)) = input.as_non_exposed_value() else {
+		return vec![LayoutGroup::Row { widgets }];
+	};
+
+	// New colors repeat the last one, so the user can adjust it from there
+	let new_color = palette.last().copied().unwrap_or(Color::BLACK);
+	widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		TextButton::new("Add Color")
+			.icon(Some("Add".into()))
+			.tooltip("Add a color to the end of the palette")
+			.on_update(edit_palette(palette, node_id, index, move |palette| palette.push(new_color)))
+			.widget_holder(),
+	]);
+
+	let mut rows = vec![LayoutGroup::Row { widgets }];
+	let last_index = palette.len().saturating_sub(1);
+	for (color_index, &color) in palette.iter().enumerate() {
+		let mut swatch_widgets = vec![TextLabel::new("").widget_holder()];
+		add_blank_assist(&mut swatch_widgets);
+
+		let palette_for_swatch = palette.clone();
+		swatch_widgets.extend_from_slice(&[
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			CurveInput::new(curve.clone())
-				.on_update(update_value(|x: &CurveInput| TaggedValue::Curve(x.value.clone()), node_id, index))
+			ColorInput::new(FillChoice::Solid(color))
+				.allow_none(false)
+				.eyedropper(true)
+				.on_update(update_value(
+					move |x: &ColorInput| {
+						let mut palette = palette_for_swatch.clone();
+						palette[color_index] = x.value.as_solid().unwrap_or_default();
+						TaggedValue::Palette(palette)
+					},
+					node_id,
+					index,
+				))
 				.on_commit(commit_value)
 				.widget_holder(),
-		])
+			Separator::new(SeparatorType::Related).widget_holder(),
+			IconButton::new("StackRaise", 16)
+				.tooltip("Move Color Earlier")
+				.disabled(color_index == 0)
+				.on_update(edit_palette(palette, node_id, index, move |palette| palette.swap(color_index, color_index - 1)))
+				.widget_holder(),
+			IconButton::new("StackLower", 16)
+				.tooltip("Move Color Later")
+				.disabled(color_index == last_index)
+				.on_update(edit_palette(palette, node_id, index, move |palette| palette.swap(color_index, color_index + 1)))
+				.widget_holder(),
+			IconButton::new("Trash", 16)
+				.tooltip("Remove Color")
+				.on_update(edit_palette(palette, node_id, index, move |palette| {
+					palette.remove(color_index);
+				}))
+				.widget_holder(),
+		]);
+		rows.push(LayoutGroup::Row { widgets: swatch_widgets });
 	}
-	LayoutGroup::Row { widgets }
+
+	rows
+}
+
+fn edit_dash_lengths<T>(lengths: &[f64], node_id: NodeId, index: usize, edit: impl Fn(&mut Vec<f64>) + 'static + Send + Sync) -> impl Fn(&T) -> Message + 'static + Send + Sync {
+	let lengths = lengths.to_vec();
+	move |_: &T| {
+		let mut lengths = lengths.clone();
+		edit(&mut lengths);
+		Message::Batched(Box::new([
+			NodeGraphMessage::SetInputValue {
+				node_id,
+				input_index: index,
+				value: TaggedValue::VecF64(lengths),
+			}
+			.into(),
+			DocumentMessage::AddTransaction.into(),
+		]))
+	}
+}
+
+/// Edits the dash lengths of a stroke as a list of dash and gap pairs, below a preview of the pattern they make.
+pub fn dash_lengths_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, offset: f64, blank_assist: bool) -> Vec<LayoutGroup> {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::Number, blank_assist);
+
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return vec![LayoutGroup::Row { widgets: vec![] }];
+	};
+	let Some(TaggedValue::VecF64(lengths)) = input.as_non_exposed_value() else {
+		return vec![LayoutGroup::Row { widgets }];
+	};
+
+	// New pairs repeat the last one, so the user can adjust it from there, and a last dash missing its gap gets one as long as itself first
+	let new_pair = match (lengths.len() % 2 == 1, lengths.as_slice()) {
+		(true, [.., dash]) => vec![*dash; 3],
+		(false, [.., dash, gap]) => vec![*dash, *gap],
+		_ => vec![4., 4.],
+	};
+	widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		TextButton::new("Add Dash")
+			.icon(Some("Add".into()))
+			.tooltip("Add a dash and the gap after it to the end of the pattern")
+			.on_update(edit_dash_lengths(lengths, node_id, index, move |lengths| lengths.extend_from_slice(&new_pair)))
+			.widget_holder(),
+	]);
+
+	let mut preview_widgets = vec![TextLabel::new("").widget_holder()];
+	add_blank_assist(&mut preview_widgets);
+	preview_widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		DashPatternLabel::new()
+			.lengths(lengths.clone())
+			.offset(offset)
+			.tooltip(if lengths.is_empty() { "Solid line, until a dash is added" } else { "Preview of the dash pattern" })
+			.widget_holder(),
+	]);
+
+	let mut rows = vec![LayoutGroup::Row { widgets }, LayoutGroup::Row { widgets: preview_widgets }];
+	for pair_index in 0..lengths.len().div_ceil(2) {
+		let [dash_index, gap_index] = [pair_index * 2, pair_index * 2 + 1];
+
+		let length_input = |length_index: usize, label: &str| {
+			let lengths_for_input = lengths.clone();
+			NumberInput::new(lengths.get(length_index).copied())
+				.label(label)
+				.unit(" px")
+				.min(0.)
+				.on_update(update_value(
+					move |number_input: &NumberInput| {
+						let mut lengths = lengths_for_input.clone();
+						let length = number_input.value.unwrap_or_default();
+						match lengths.get_mut(length_index) {
+							Some(existing) => *existing = length,
+							None => lengths.push(length),
+						}
+						TaggedValue::VecF64(lengths)
+					},
+					node_id,
+					index,
+				))
+				.on_commit(commit_value)
+		};
+
+		let mut pair_widgets = vec![TextLabel::new("").widget_holder()];
+		add_blank_assist(&mut pair_widgets);
+		pair_widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			length_input(dash_index, "Dash").widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			length_input(gap_index, "Gap")
+				.tooltip(if gap_index < lengths.len() {
+					""
+				} else {
+					"Without its own gap, the pattern repeats from this dash's length as the gap"
+				})
+				.widget_holder(),
+			Separator::new(SeparatorType::Related).widget_holder(),
+			IconButton::new("Trash", 16)
+				.tooltip("Remove Dash")
+				.on_update(edit_dash_lengths(lengths, node_id, index, move |lengths| {
+					lengths.drain(dash_index..(gap_index + 1).min(lengths.len()));
+				}))
+				.widget_holder(),
+		]);
+		rows.push(LayoutGroup::Row { widgets: pair_widgets });
+	}
+
+	rows
+}
+
+/// The `channel` picks which of the curves of a Curves input is shown for editing.
+pub fn curves_widget(document_node: &DocumentNode, node_id: NodeId, index: usize, name: &str, description: &str, channel: CurveChannel, blank_assist: bool) -> Vec<LayoutGroup> {
+	let mut widgets = start_widgets(document_node, node_id, index, name, description, FrontendGraphDataType::General, blank_assist);
+
+	let Some(input) = document_node.inputs.get(index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return vec![LayoutGroup::Row { widgets: vec![] }];
+	};
+	match input.as_non_exposed_value() {
+		Some(TaggedValue::Curve(curve)) => {
+			widgets.extend_from_slice(&[
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				CurveInput::new(curve.clone())
+					.on_update(update_value(|x: &CurveInput| TaggedValue::Curve(x.value.clone()), node_id, index))
+					.on_commit(commit_value)
+					.widget_holder(),
+			]);
+		}
+		Some(TaggedValue::Curves(curves)) => {
+			let channels = [CurveChannel::Composite, CurveChannel::Red, CurveChannel::Green, CurveChannel::Blue];
+			let entries = channels
+				.into_iter()
+				.map(|channel| {
+					RadioEntryData::new(format!("{channel:?}"))
+						.label(channel.to_string())
+						.tooltip(match channel {
+							CurveChannel::Composite => "Edit the curve applied to all color channels",
+							CurveChannel::Red => "Edit the curve applied to the red channel",
+							CurveChannel::Green => "Edit the curve applied to the green channel",
+							CurveChannel::Blue => "Edit the curve applied to the blue channel",
+						})
+						.on_update(move |_| PropertiesPanelMessage::SelectCurveChannel { node_id, input_index: index, channel }.into())
+				})
+				.collect();
+			widgets.extend_from_slice(&[
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				RadioInput::new(entries)
+					.selected_index(channels.iter().position(|&other| other == channel).map(|index| index as u32))
+					.widget_holder(),
+			]);
+
+			let mut curve_widgets = vec![TextLabel::new("").widget_holder()];
+			add_blank_assist(&mut curve_widgets);
+			let curves = curves.clone();
+			curve_widgets.extend_from_slice(&[
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				CurveInput::new(curves.curve(channel).clone())
+					.on_update(update_value(
+						move |x: &CurveInput| {
+							let mut curves = curves.clone();
+							*curves.curve_mut(channel) = x.value.clone();
+							TaggedValue::Curves(curves)
+						},
+						node_id,
+						index,
+					))
+					.on_commit(commit_value)
+					.widget_holder(),
+			]);
+
+			return vec![LayoutGroup::Row { widgets }, LayoutGroup::Row { widgets: curve_widgets }];
+		}
+		_ => {}
+	}
+	vec![LayoutGroup::Row { widgets }]
 }
 
 pub fn centroid_widget(document_node: &DocumentNode, node_id: NodeId, index: usize) -> LayoutGroup {
@@ -1504,8 +2760,11 @@ pub(crate) fn channel_mixer_properties(node_id: NodeId, context: &mut NodeProper
 		true,
 	);
 
+	// Presets
+	let presets = PresetManager::new("Channel Mixer", context.preferences).presets_widget(document_node, node_id);
+
 	// Monochrome
-	let mut layout = vec![LayoutGroup::Row { widgets: monochrome }];
+	let mut layout = vec![presets, LayoutGroup::Row { widgets: monochrome }];
 	// Output channel choice
 	if !is_monochrome {
 		layout.push(LayoutGroup::Row { widgets: output_channel });
@@ -1539,7 +2798,7 @@ pub(crate) fn selective_color_properties(node_id: NodeId, context: &mut NodeProp
 	};
 	if let Some(&TaggedValue::SelectiveColorChoice(choice)) = input.as_non_exposed_value() {
 		use SelectiveColorChoice::*;
-		let entries = [[Reds, Yellows, Greens, Cyans, Blues, Magentas].as_slice(), [Whites, Neutrals, Blacks].as_slice()]
+		let entries = [[Reds, Yellows, Greens, Cyans, Blues, Magentas].as_slice(), [Whites, Neutrals, Blacks].as_slice(), [Custom].as_slice()]
 			.into_iter()
 			.map(|section| {
 				section
@@ -1553,7 +2812,7 @@ pub(crate) fn selective_color_properties(node_id: NodeId, context: &mut NodeProp
 					.collect()
 			})
 			.collect();
-		colors.extend([DropdownInput::new(entries).selected_index(Some(choice as u32)).widget_holder()]);
+		colors.extend([DropdownInput::new(entries).selected_index(Some(choice as u32)).searchable(true).widget_holder()]);
 	}
 
 	let colors_choice_index = match &document_node.inputs[colors_index].as_value() {
@@ -1575,46 +2834,208 @@ pub(crate) fn selective_color_properties(node_id: NodeId, context: &mut NodeProp
 		SelectiveColorChoice::Whites => ((26, "(Whites) Cyan"), (27, "(Whites) Magenta"), (28, "(Whites) Yellow"), (29, "(Whites) Black")),
 		SelectiveColorChoice::Neutrals => ((30, "(Neutrals) Cyan"), (31, "(Neutrals) Magenta"), (32, "(Neutrals) Yellow"), (33, "(Neutrals) Black")),
 		SelectiveColorChoice::Blacks => ((34, "(Blacks) Cyan"), (35, "(Blacks) Magenta"), (36, "(Blacks) Yellow"), (37, "(Blacks) Black")),
+		SelectiveColorChoice::Custom => ((41, "(Custom) Cyan"), (42, "(Custom) Magenta"), (43, "(Custom) Yellow"), (44, "(Custom) Black")),
+	};
+	let cyan = number_widget(document_node, node_id, c.0, c.1, "TODO", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+	let magenta = number_widget(document_node, node_id, m.0, m.1, "TODO", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+	let yellow = number_widget(document_node, node_id, y.0, y.1, "TODO", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+	let black = number_widget(document_node, node_id, k.0, k.1, "TODO", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
+
+	// Hue range, only shown for the custom colors choice
+	let hue_range = (*colors_choice_index == SelectiveColorChoice::Custom).then(|| {
+		let (center_index, width_index) = (39, 40);
+		let mut hue_range = vec![TextLabel::new("Hue Range").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
+		add_blank_assist(&mut hue_range);
+
+		let hue_input = |index: usize, label: &str, tooltip: &str| {
+			let value = match document_node.inputs.get(index).and_then(|input| input.as_non_exposed_value()) {
+				Some(&TaggedValue::F64(value)) => value,
+				_ => return None,
+			};
+			let widget = NumberInput::new(Some(value))
+				.label(label)
+				.tooltip(tooltip)
+				.mode_range()
+				.min(0.)
+				.max(360.)
+				.unit("°")
+				.on_update(update_value(|number_input: &NumberInput| TaggedValue::F64(number_input.value.unwrap()), node_id, index))
+				.on_commit(commit_value)
+				.widget_holder();
+			Some(widget)
+		};
+		hue_range.extend(hue_input(center_index, "Center", "The hue at the middle of the range of colors affected"));
+		hue_range.push(Separator::new(SeparatorType::Related).widget_holder());
+		hue_range.extend(hue_input(width_index, "Width", "How wide the range of affected hues is, fading out toward its edges"));
+		hue_range
+	});
+
+	// Mode
+	let mode_index = 1;
+	let mut mode = start_widgets(document_node, node_id, mode_index, "Mode", "TODO", FrontendGraphDataType::General, true);
+	mode.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+
+	let Some(input) = document_node.inputs.get(mode_index) else {
+		log::warn!("A widget failed to be built because its node's input index is invalid.");
+		return vec![];
+	};
+	if let Some(&TaggedValue::RelativeAbsolute(relative_or_absolute)) = input.as_non_exposed_value() {
+		let entries = vec![
+			RadioEntryData::new("relative")
+				.label("Relative")
+				.on_update(update_value(|_| TaggedValue::RelativeAbsolute(RelativeAbsolute::Relative), node_id, mode_index))
+				.on_commit(commit_value),
+			RadioEntryData::new("absolute")
+				.label("Absolute")
+				.on_update(update_value(|_| TaggedValue::RelativeAbsolute(RelativeAbsolute::Absolute), node_id, mode_index))
+				.on_commit(commit_value),
+		];
+		mode.push(RadioInput::new(entries).selected_index(Some(relative_or_absolute as u32)).widget_holder());
+	};
+
+	vec![
+		// Colors choice
+		LayoutGroup::Row { widgets: colors },
+	]
+	.into_iter()
+	.chain(hue_range.map(|widgets| LayoutGroup::Row { widgets }))
+	.chain([
+		// CMYK
+		LayoutGroup::Row { widgets: cyan },
+		LayoutGroup::Row { widgets: magenta },
+		LayoutGroup::Row { widgets: yellow },
+		LayoutGroup::Row { widgets: black },
+		// Mode
+		LayoutGroup::Row { widgets: mode },
+	])
+	.collect()
+}
+
+pub(crate) fn color_balance_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let document_node = match get_document_node(node_id, context) {
+		Ok(document_node) => document_node,
+		Err(err) => {
+			log::error!("Could not get document node in color_balance_properties: {err}");
+			return Vec::new();
+		}
+	};
+
+	// Tonal range choice
+	let tonal_range_index = 1;
+	let mut tonal_range = vec![TextLabel::new("Tones").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
+	add_blank_assist(&mut tonal_range);
+
+	let Some(&TaggedValue::TonalRange(choice)) = document_node.inputs.get(tonal_range_index).and_then(|input| input.as_non_exposed_value()) else {
+		warn!("Color Balance node properties panel could not be displayed.");
+		return vec![];
+	};
+	let entries = [TonalRange::Shadows, TonalRange::Midtones, TonalRange::Highlights]
+		.into_iter()
+		.map(|range| {
+			RadioEntryData::new(format!("{range:?}"))
+				.label(range.to_string())
+				.on_update(update_value(move |_| TaggedValue::TonalRange(range), node_id, tonal_range_index))
+				.on_commit(commit_value)
+		})
+		.collect();
+	tonal_range.push(RadioInput::new(entries).selected_index(Some(choice as u32)).widget_holder());
+
+	// Color shifts of the chosen tonal range
+	let first_index = match choice {
+		TonalRange::Shadows => 2,
+		TonalRange::Midtones => 5,
+		TonalRange::Highlights => 8,
+	};
+	let shift = |offset: usize, name: &str, description: &str| {
+		let number_input = NumberInput::default().mode_range().min(-100.).max(100.).unit("%");
+		LayoutGroup::Row {
+			widgets: number_widget(document_node, node_id, first_index + offset, name, description, number_input, true),
+		}
+	};
+	let cyan_red = shift(0, "Cyan–Red", "Shifts the colors of these tones toward cyan (negative) or red (positive)");
+	let magenta_green = shift(1, "Magenta–Green", "Shifts the colors of these tones toward magenta (negative) or green (positive)");
+	let yellow_blue = shift(2, "Yellow–Blue", "Shifts the colors of these tones toward yellow (negative) or blue (positive)");
+
+	// Preserve luminosity
+	let preserve_luminosity = bool_widget(
+		document_node,
+		node_id,
+		11,
+		"Preserve Luminosity",
+		"Keeps the lightness of each pixel unchanged, so only its hue and saturation are shifted",
+		CheckboxInput::default(),
+		true,
+	);
+
+	vec![
+		LayoutGroup::Row { widgets: tonal_range },
+		cyan_red,
+		magenta_green,
+		yellow_blue,
+		LayoutGroup::Row { widgets: preserve_luminosity },
+	]
+}
+
+pub(crate) fn hsl_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let document_node = match get_document_node(node_id, context) {
+		Ok(document_node) => document_node,
+		Err(err) => {
+			log::error!("Could not get document node in hsl_properties: {err}");
+			return Vec::new();
+		}
 	};
-	let cyan = number_widget(document_node, node_id, c.0, c.1, "TODO", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
-	let magenta = number_widget(document_node, node_id, m.0, m.1, "TODO", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
-	let yellow = number_widget(document_node, node_id, y.0, y.1, "TODO", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
-	let black = number_widget(document_node, node_id, k.0, k.1, "TODO", NumberInput::default().mode_range().min(-100.).max(100.).unit("%"), true);
 
-	// Mode
-	let mode_index = 1;
-	let mut mode = start_widgets(document_node, node_id, mode_index, "Mode", "TODO", FrontendGraphDataType::General, true);
-	mode.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+	// Hue band choice
+	let band_index = 1;
+	let mut band = vec![TextLabel::new("Colors").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
+	add_blank_assist(&mut band);
 
-	let Some(input) = document_node.inputs.get(mode_index) else {
-		log::warn!("A widget failed to be built because its node's input index is invalid.");
+	let Some(&TaggedValue::HueBand(choice)) = document_node.inputs.get(band_index).and_then(|input| input.as_non_exposed_value()) else {
+		warn!("HSL node properties panel could not be displayed.");
 		return vec![];
 	};
-	if let Some(&TaggedValue::RelativeAbsolute(relative_or_absolute)) = input.as_non_exposed_value() {
-		let entries = vec![
-			RadioEntryData::new("relative")
-				.label("Relative")
-				.on_update(update_value(|_| TaggedValue::RelativeAbsolute(RelativeAbsolute::Relative), node_id, mode_index))
-				.on_commit(commit_value),
-			RadioEntryData::new("absolute")
-				.label("Absolute")
-				.on_update(update_value(|_| TaggedValue::RelativeAbsolute(RelativeAbsolute::Absolute), node_id, mode_index))
-				.on_commit(commit_value),
-		];
-		mode.push(RadioInput::new(entries).selected_index(Some(relative_or_absolute as u32)).widget_holder());
+	let entries = HueBand::ALL
+		.into_iter()
+		.map(|hue_band| {
+			MenuListEntry::new(format!("{hue_band:?}"))
+				.label(hue_band.to_string())
+				.on_update(update_value(move |_| TaggedValue::HueBand(hue_band), node_id, band_index))
+				.on_commit(commit_value)
+		})
+		.collect();
+	band.extend([
+		DropdownInput::new(vec![entries]).selected_index(Some(choice as u32)).widget_holder(),
+		Separator::new(SeparatorType::Related).widget_holder(),
+		// Targeted adjustment, which chooses the band of a color sampled from the artwork or picked with the color picker
+		ColorInput::new(FillChoice::Solid(choice.color()))
+			.allow_none(false)
+			.eyedropper(true)
+			.tooltip("Sample a color from the artwork to adjust the band of hues it belongs to")
+			.on_update(update_value(
+				|input: &ColorInput| TaggedValue::HueBand(HueBand::from_color(input.value.as_solid().unwrap_or_default())),
+				node_id,
+				band_index,
+			))
+			.on_commit(commit_value)
+			.widget_holder(),
+	]);
+
+	// Shifts of the chosen band
+	let first_index = 2 + choice as usize * 3;
+	let shift = |offset: usize, name: &str, description: &str, number_input: NumberInput| LayoutGroup::Row {
+		widgets: number_widget(document_node, node_id, first_index + offset, name, description, number_input, true),
 	};
+	let hue = shift(
+		0,
+		"Hue",
+		"Rotates the hues of these colors around the color wheel",
+		NumberInput::default().mode_range().min(-180.).max(180.).unit("°"),
+	);
+	let percentage = || NumberInput::default().mode_range().min(-100.).max(100.).unit("%");
+	let saturation = shift(1, "Saturation", "Makes these colors more vivid (positive) or grayer (negative)", percentage());
+	let lightness = shift(2, "Lightness", "Makes these colors lighter (positive) or darker (negative)", percentage());
 
-	vec![
-		// Colors choice
-		LayoutGroup::Row { widgets: colors },
-		// CMYK
-		LayoutGroup::Row { widgets: cyan },
-		LayoutGroup::Row { widgets: magenta },
-		LayoutGroup::Row { widgets: yellow },
-		LayoutGroup::Row { widgets: black },
-		// Mode
-		LayoutGroup::Row { widgets: mode },
-	]
+	vec![LayoutGroup::Row { widgets: band }, hue, saturation, lightness]
 }
 
 #[cfg(feature = "gpu")]
@@ -1649,14 +3070,14 @@ pub(crate) fn grid_properties(node_id: NodeId, context: &mut NodePropertiesConte
 	if let Some(&TaggedValue::GridType(grid_type)) = grid_type_input.as_non_exposed_value() {
 		match grid_type {
 			GridType::Rectangular => {
-				let spacing = vec2_widget(document_node, node_id, spacing_index, "Spacing", "TODO", "W", "H", " px", Some(0.), add_blank_assist);
+				let spacing = vec2_widget(document_node, node_id, spacing_index, "Spacing", "TODO", "W", "H", " px", Some(0.), add_blank_assist, None);
 				widgets.push(spacing);
 			}
 			GridType::Isometric => {
 				let spacing = LayoutGroup::Row {
 					widgets: number_widget(document_node, node_id, spacing_index, "Spacing", "TODO", NumberInput::default().label("H").min(0.).unit(" px"), true),
 				};
-				let angles = vec2_widget(document_node, node_id, angles_index, "Angles", "TODO", "", "", "°", None, add_blank_assist);
+				let angles = vec2_widget(document_node, node_id, angles_index, "Angles", "TODO", "", "", "°", None, add_blank_assist, None);
 				widgets.extend([spacing, angles]);
 			}
 		}
@@ -1690,6 +3111,212 @@ pub(crate) fn exposure_properties(node_id: NodeId, context: &mut NodePropertiesC
 	]
 }
 
+/// A slider with a handle for each of two percentage inputs which bound a range, such as a black point and a white point.
+/// If either input is exposed, each one gets its own number row instead since only the other one can be dragged.
+fn percentage_range_widget(document_node: &DocumentNode, node_id: NodeId, bounds: [(usize, &str); 2], name: &str, description: &str) -> Vec<LayoutGroup> {
+	let [(lower_index, lower_name), (upper_index, upper_name)] = bounds;
+	let value = |index: usize| match document_node.inputs.get(index).and_then(|input| input.as_non_exposed_value()) {
+		Some(&TaggedValue::F64(value)) => Some(value),
+		_ => None,
+	};
+
+	let (Some(lower), Some(upper)) = (value(lower_index), value(upper_index)) else {
+		return bounds
+			.into_iter()
+			.map(|(index, name)| LayoutGroup::Row {
+				widgets: number_widget(document_node, node_id, index, name, description, NumberInput::default().percentage(), true),
+			})
+			.collect();
+	};
+
+	let mut widgets = start_widgets(document_node, node_id, lower_index, name, description, FrontendGraphDataType::Number, true);
+	widgets.extend_from_slice(&[
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		RangeSliderInput::new([lower, upper])
+			.percentage()
+			.tooltip(format!("{lower_name} and {upper_name}"))
+			.on_update(move |range_slider_input: &RangeSliderInput| {
+				let [lower, upper] = range_slider_input.value;
+				Message::Batched(Box::new([
+					NodeGraphMessage::SetInputValue {
+						node_id,
+						input_index: lower_index,
+						value: TaggedValue::F64(lower),
+					}
+					.into(),
+					NodeGraphMessage::SetInputValue {
+						node_id,
+						input_index: upper_index,
+						value: TaggedValue::F64(upper),
+					}
+					.into(),
+				]))
+			})
+			.on_commit(commit_value)
+			.widget_holder(),
+	]);
+	vec![LayoutGroup::Row { widgets }]
+}
+
+pub(crate) fn levels_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let document_node = match get_document_node(node_id, context) {
+		Ok(document_node) => document_node,
+		Err(err) => {
+			log::error!("Could not get document node in levels_properties: {err}");
+			return Vec::new();
+		}
+	};
+
+	let channel = levels_channel(document_node, node_id, 6, "Channel", "The color channels that are adjusted: all of them together, or just one.", true);
+	let input_range = percentage_range_widget(
+		document_node,
+		node_id,
+		[(1, "Shadows"), (3, "Highlights")],
+		"Input Range",
+		"The black and white points of the input. Tones at or below the black point become the output minimum, and tones at or above the white point become the output maximum.",
+	);
+	let midtones = number_widget(
+		document_node,
+		node_id,
+		2,
+		"Midtones",
+		"The gamma of the tones between the black and white points, where 50% leaves them unchanged.",
+		NumberInput::default().percentage(),
+		true,
+	);
+	let output_range = percentage_range_widget(
+		document_node,
+		node_id,
+		[(4, "Output Minimums"), (5, "Output Maximums")],
+		"Output Range",
+		"The darkest and lightest tones in the output, which the input range is remapped to.",
+	);
+
+	let auto = auto_levels_buttons(node_id, context);
+
+	let mut layout = vec![channel];
+	layout.extend(input_range);
+	layout.push(LayoutGroup::Row { widgets: midtones });
+	layout.extend(output_range);
+	layout.push(auto);
+	layout
+}
+
+/// Buttons which analyze the histogram of the image coming into the Levels node to set its input range, and that of any Levels nodes chained upstream of it.
+fn auto_levels_buttons(node_id: NodeId, context: &mut NodePropertiesContext) -> LayoutGroup {
+	// The chain of Levels nodes is found by following the wires upstream from the input this node feeds
+	let input_connector = context
+		.network_interface
+		.outward_wires(context.selection_network_path)
+		.and_then(|outward_wires| outward_wires.get(&OutputConnector::node(node_id, 0)))
+		.and_then(|inputs| inputs.first())
+		.copied();
+
+	let mut widgets = vec![
+		TextLabel::new("Auto")
+			.tooltip("Set the input range from the histogram of the image coming into this node")
+			.widget_holder(),
+	];
+	add_blank_assist(&mut widgets);
+	for (index, adjustment) in AutoAdjustment::list().into_iter().enumerate() {
+		let separator = if index == 0 { SeparatorType::Unrelated } else { SeparatorType::Related };
+		widgets.push(Separator::new(separator).widget_holder());
+		widgets.push(match input_connector {
+			Some(input_connector) => TextButton::new(format!("{adjustment:?}"))
+				.tooltip(adjustment.tooltip())
+				.on_update(move |_| NodeGraphMessage::AutoAdjust { input_connector, adjustment }.into())
+				.widget_holder(),
+			None => TextButton::new(format!("{adjustment:?}"))
+				.tooltip("Connect this node's output to use it")
+				.disabled(true)
+				.widget_holder(),
+		});
+	}
+	LayoutGroup::Row { widgets }
+}
+
+pub(crate) fn text_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let document_node = match get_document_node(node_id, context) {
+		Ok(document_node) => document_node,
+		Err(err) => {
+			log::error!("Could not get document node in text_properties: {err}");
+			return Vec::new();
+		}
+	};
+
+	let text = text_area_widget(document_node, node_id, 1, "Text", "The text to be typeset. Each new line in it starts a new line of text.", true);
+	let (font, style) = font_inputs(document_node, node_id, 2, "Font", "The font family and style used for every character.", true);
+	let size = number_widget(
+		document_node,
+		node_id,
+		3,
+		"Size",
+		"The font size, which is the height of one em.",
+		NumberInput::default().unit(" px").min(1.),
+		true,
+	);
+	let line_height = number_widget(
+		document_node,
+		node_id,
+		4,
+		"Line Height",
+		"The distance between the baselines of consecutive lines, as a multiple of the font size.",
+		NumberInput::default().min(0.).step(0.1),
+		true,
+	);
+	let character_spacing = number_widget(
+		document_node,
+		node_id,
+		5,
+		"Character Spacing",
+		"The distance each character advances the next one by, as a multiple of its normal advance.",
+		NumberInput::default().min(0.).step(0.1),
+		true,
+	);
+	let align = text_align_widget(
+		document_node,
+		node_id,
+		8,
+		"Align",
+		"How each line is placed horizontally within the max width, or within the widest line.",
+		true,
+	);
+	let vertical_align = vertical_align_widget(document_node, node_id, 9, "Vertical Align", "How the lines are placed vertically within the max height.", true);
+	let max_width = number_widget(
+		document_node,
+		node_id,
+		6,
+		"Max Width",
+		"The width of the text box, beyond which words are wrapped onto the next line.",
+		NumberInput::default().unit(" px").min(1.),
+		true,
+	);
+	let max_height = number_widget(
+		document_node,
+		node_id,
+		7,
+		"Max Height",
+		"The height of the text box, beyond which lines are clipped.",
+		NumberInput::default().unit(" px").min(1.),
+		true,
+	);
+
+	let mut layout = vec![LayoutGroup::Row { widgets: text }, LayoutGroup::Row { widgets: font }];
+	if let Some(style) = style {
+		layout.push(LayoutGroup::Row { widgets: style });
+	}
+	layout.extend([
+		LayoutGroup::Row { widgets: size },
+		LayoutGroup::Row { widgets: line_height },
+		LayoutGroup::Row { widgets: character_spacing },
+		align,
+		vertical_align,
+		LayoutGroup::Row { widgets: max_width },
+		LayoutGroup::Row { widgets: max_height },
+	]);
+	layout
+}
+
 pub(crate) fn rectangle_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let document_node = match get_document_node(node_id, context) {
 		Ok(document_node) => document_node,
@@ -1784,12 +3411,7 @@ pub(crate) fn rectangle_properties(node_id: NodeId, context: &mut NodeProperties
 		// Radius value input widget
 		let input_widget = if is_individual {
 			let from_string = |string: &str| {
-				string
-					.split(&[',', ' '])
-					.filter(|x| !x.is_empty())
-					.map(str::parse::<f64>)
-					.collect::<Result<Vec<f64>, _>>()
-					.ok()
+				numbers_from_expressions(string)
 					.map(|v| {
 						let arr: Box<[f64; 4]> = v.into_boxed_slice().try_into().unwrap_or_default();
 						*arr
@@ -2284,121 +3906,443 @@ pub(crate) fn rectangle_properties(node_id: NodeId, context: &mut NodeProperties
 // 	// 	let mask_starting_fill = {
 // 	// 		let mut widgets = start_widgets(document_node, node_id, mask_fill_index, "Mask Starting Fill", FrontendGraphDataType::General, true);
 
-// 	// 		if let Some(& TaggedValue::ImaginateMaskStartingFill(starting_fill)
-// 	//)/ 		} = &document_node.inputs[mask_fill_index].as_non_exposed_value()
-// 	// 		{
-// 	// 			let mask_fill_content_modes = ImaginateMaskStartingFill::list();
-// 	// 			let mut entries = Vec::with_capacity(mask_fill_content_modes.len());
-// 	// 			for mode in mask_fill_content_modes {
-// 	// 				entries.push(MenuListEntry::new(format!("{mode:?}")).label(mode.to_string()).on_update(update_value(move |_| TaggedValue::ImaginateMaskStartingFill(mode), node_id, mask_fill_index)));
-// 	// 			}
-// 	// 			let entries = vec![entries];
+// 	// 		if let Some(& TaggedValue::ImaginateMaskStartingFill(starting_fill)
+// 	//)/ 		} = &document_node.inputs[mask_fill_index].as_non_exposed_value()
+// 	// 		{
+// 	// 			let mask_fill_content_modes = ImaginateMaskStartingFill::list();
+// 	// 			let mut entries = Vec::with_capacity(mask_fill_content_modes.len());
+// 	// 			for mode in mask_fill_content_modes {
+// 	// 				entries.push(MenuListEntry::new(format!("{mode:?}")).label(mode.to_string()).on_update(update_value(move |_| TaggedValue::ImaginateMaskStartingFill(mode), node_id, mask_fill_index)));
+// 	// 			}
+// 	// 			let entries = vec![entries];
+
+// 	// 			widgets.extend_from_slice(&[
+// 	// 				Separator::new(SeparatorType::Unrelated).widget_holder(),
+// 	// 				DropdownInput::new(entries).selected_index(Some(starting_fill as u32)).widget_holder(),
+// 	// 			]);
+// 	// 		}
+// 	// 		LayoutGroup::Row { widgets }.with_tooltip(
+// 	// 			"Begin in/outpainting the masked areas using this fill content as the starting input image.\n\
+// 	// 			\n\
+// 	// 			Each option can be visualized by generating with 'Sampling Steps' set to 0.",
+// 	// 		)
+// 	// 	};
+// 	// 	layout.extend_from_slice(&[in_paint, blur_radius, mask_starting_fill]);
+// 	// }
+
+// 	let improve_faces = {
+// 		let widgets = bool_widget(document_node, node_id, faces_index, "Improve Faces", CheckboxInput::default(), true);
+// 		LayoutGroup::Row { widgets }.with_tooltip(
+// 			"Postprocess human (or human-like) faces to look subtly less distorted.\n\
+// 			\n\
+// 			This filter can be used on its own by enabling 'Adapt Input Image' and setting 'Sampling Steps' to 0.",
+// 		)
+// 	};
+// 	let tiling = {
+// 		let widgets = bool_widget(document_node, node_id, tiling_index, "Tiling", CheckboxInput::default(), true);
+// 		LayoutGroup::Row { widgets }.with_tooltip("Generate the image so its edges loop seamlessly to make repeatable patterns or textures")
+// 	};
+// 	layout.extend_from_slice(&[improve_faces, tiling]);
+
+// 	layout
+// }
+
+pub(crate) fn node_no_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let text = if context.network_interface.is_layer(&node_id, context.selection_network_path) {
+		"Layer has no properties"
+	} else {
+		"Node has no properties"
+	};
+	string_properties(text)
+}
+
+/// Offers to upgrade a node that is pinned to an older version of its definition, listing what the upgrade changes.
+fn outdated_node_notice(node_id: NodeId, context: &NodePropertiesContext) -> Option<LayoutGroup> {
+	let reference = context.network_interface.reference(&node_id, context.selection_network_path)?.clone()?;
+	let version = context.network_interface.definition_version(&node_id, context.selection_network_path)?;
+
+	let changes = super::node_versions::pending_changes(&reference, version)
+		.map(|change| format!("• {}", change.description))
+		.collect::<Vec<_>>();
+	if changes.is_empty() {
+		return None;
+	}
+	let tooltip = format!(
+		"This node was created with an older version of its definition and is kept behaving as it did then.\n\nUpgrading it will:\n{}",
+		changes.join("\n")
+	);
+
+	let widgets = vec![
+		TextLabel::new("Outdated node").tooltip(tooltip.clone()).widget_holder(),
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		TextButton::new("Upgrade Node")
+			.tooltip(tooltip)
+			.on_update(move |_| NodeGraphMessage::UpgradeNode { node_id }.into())
+			.widget_holder(),
+	];
+	Some(LayoutGroup::Row { widgets })
+}
+
+/// The node whose output should be introspected to show the histogram above the properties of the selected tonal adjustment node, if exactly one such node is selected in the document network.
+pub fn histogram_inspect_node(network_interface: &NodeNetworkInterface) -> Option<NodeId> {
+	let selected_nodes = network_interface.selected_nodes().0;
+	let [node_id] = selected_nodes.as_slice() else { return None };
+	histogram_source(network_interface, *node_id)
+}
+
+/// The node feeding the primary input of a tonal adjustment node in the document network, which provides the image being adjusted.
+fn histogram_source(network_interface: &NodeNetworkInterface, node_id: NodeId) -> Option<NodeId> {
+	let reference = network_interface.reference(&node_id, &[])?.clone()?;
+	if !HISTOGRAM_NODE_TYPES.contains(&reference.as_str()) {
+		return None;
+	}
+	network_interface.upstream_output_connector(&InputConnector::node(node_id, 0), &[])?.node_id()
+}
+
+/// Shows the luminance distribution of the image coming into a tonal adjustment node, using the introspected output of the node feeding it.
+fn input_histogram(node_id: NodeId, context: &mut NodePropertiesContext) -> Option<LayoutGroup> {
+	if !context.selection_network_path.is_empty() {
+		return None;
+	}
+	let source = histogram_source(context.network_interface, node_id)?;
+	if histogram_inspect_node(context.network_interface) != Some(source) {
+		return None;
+	}
+
+	let widget = match context.inspected_histogram {
+		Some((inspected, histogram)) if *inspected == source => match histogram.as_ref().filter(|histogram| histogram.sample_count() > 0) {
+			Some(histogram) => HistogramLabel::new()
+				.luminance(histogram.normalized(&histogram.luminance))
+				.tooltip("Luminance distribution of the image coming into this node")
+				.widget_holder(),
+			None => TextLabel::new("No raster data").italic(true).widget_holder(),
+		},
+		// The graph has not yet been run with the source node being introspected
+		_ => {
+			context.responses.add(NodeGraphMessage::RunDocumentGraph);
+			TextLabel::new("Computing histogram…").italic(true).widget_holder()
+		}
+	};
+	Some(LayoutGroup::Row { widgets: vec![widget] })
+}
+
+/// The Noise Pattern node whose output is rendered for the preview above its properties, if exactly one such node is selected in the document network.
+pub fn noise_preview_node(network_interface: &NodeNetworkInterface) -> Option<NodeId> {
+	let selected_nodes = network_interface.selected_nodes().0;
+	let [node_id] = selected_nodes.as_slice() else { return None };
+	let reference = network_interface.reference(node_id, &[])?.clone()?;
+	(reference == "Noise Pattern").then_some(*node_id)
+}
+
+/// Shows the output of the selected Noise Pattern node over its clipping square, rendered at thumbnail resolution each time its inputs change so it keeps up while they're scrubbed.
+fn noise_preview(node_id: NodeId, context: &mut NodePropertiesContext) -> Option<LayoutGroup> {
+	if !context.selection_network_path.is_empty() || noise_preview_node(context.network_interface) != Some(node_id) {
+		return None;
+	}
+
+	let widget = match context.noise_preview {
+		Some((previewed, svg)) if *previewed == node_id && !svg.is_empty() => ThumbnailButton::new(svg).tooltip("Preview of the noise within its 100×100 clipping square").widget_holder(),
+		Some((previewed, _)) if *previewed == node_id => TextLabel::new("No preview while the node's output is unused").italic(true).widget_holder(),
+		// The graph has not yet been run with this node being previewed
+		_ => {
+			context.responses.add(NodeGraphMessage::RunDocumentGraph);
+			TextLabel::new("Rendering preview…").italic(true).widget_holder()
+		}
+	};
+	Some(LayoutGroup::Row { widgets: vec![widget] })
+}
+
+/// The rows for a single input of a node, from its widget override if it has one, or otherwise from the type of the input.
+fn input_properties(node_id: NodeId, input_index: usize, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	context.call_widget_override(&node_id, input_index).unwrap_or_else(|| {
+		let Some(implementation) = context.network_interface.implementation(&node_id, context.selection_network_path) else {
+			log::error!("Could not get implementation for node {node_id}");
+			return Vec::new();
+		};
+
+		let mut number_options = (None, None, None);
+		let input_type = match implementation {
+			DocumentNodeImplementation::ProtoNode(proto_node_identifier) => 'early_return: {
+				if let Some(field) = graphene_core::registry::NODE_METADATA
+					.lock()
+					.unwrap()
+					.get(&proto_node_identifier.name.clone().into_owned())
+					.and_then(|metadata| metadata.fields.get(input_index))
+				{
+					number_options = (field.number_min, field.number_max, field.number_mode_range);
+					if let Some(ref default) = field.default_type {
+						break 'early_return default.clone();
+					}
+				}
+
+				let Some(implementations) = &interpreted_executor::node_registry::NODE_REGISTRY.get(proto_node_identifier) else {
+					log::error!("Could not get implementation for protonode {proto_node_identifier:?}");
+					return Vec::new();
+				};
+
+				let proto_node_identifier = proto_node_identifier.clone();
+
+				let mut input_types = implementations
+					.keys()
+					.filter_map(|item| item.inputs.get(input_index))
+					.filter(|ty| property_from_type(node_id, input_index, ty, number_options, context).is_ok())
+					.collect::<Vec<_>>();
+				input_types.sort_by_key(|ty| ty.type_name());
+				let input_type = input_types.first().cloned();
+
+				let Some(input_type) = input_type else {
+					log::error!("Could not get input type for protonode {proto_node_identifier:?} at index {input_index:?}");
+					return Vec::new();
+				};
+
+				input_type.clone()
+			}
+			_ => context.network_interface.input_type(&InputConnector::node(node_id, input_index), context.selection_network_path).0,
+		};
+
+		property_from_type(node_id, input_index, &input_type, number_options, context).unwrap_or_else(|value| value)
+	})
+}
+
+fn pin_input_widget(node_id: NodeId, input_index: usize, pinned: bool) -> WidgetHolder {
+	IconButton::new(if pinned { "PinActive" } else { "PinInactive" }, 24)
+		.tooltip(if pinned {
+			"Unpin this parameter from the Pinned Parameters section"
+		} else {
+			"Pin this parameter to the Pinned Parameters section, where it's shown whatever is selected"
+		})
+		.on_update(move |_| {
+			DocumentMessage::SetInputPinned {
+				node_id,
+				input_index,
+				pinned: !pinned,
+			}
+			.into()
+		})
+		.widget_holder()
+}
+
+/// Builds the section listing every pinned parameter of the nodes in the network, grouped by node, or `None` if nothing is pinned.
+pub(crate) fn generate_pinned_parameters(context: &mut NodePropertiesContext) -> Option<LayoutGroup> {
+	let network = context.network_interface.nested_network(context.selection_network_path)?;
+	let mut node_ids = network.nodes.keys().copied().collect::<Vec<_>>();
+	node_ids.sort_by_cached_key(|node_id| (context.network_interface.display_name(node_id, context.selection_network_path), *node_id));
+
+	let mut layout = Vec::new();
+	for node_id in node_ids {
+		let number_of_inputs = context.network_interface.number_of_inputs(&node_id, context.selection_network_path);
+		let pinned_inputs = (1..number_of_inputs)
+			.filter(|&input_index| context.network_interface.is_input_pinned(&node_id, input_index, context.selection_network_path))
+			.collect::<Vec<_>>();
+		if pinned_inputs.is_empty() {
+			continue;
+		}
+
+		let name = context.network_interface.display_name(&node_id, context.selection_network_path);
+		layout.push(LayoutGroup::Row {
+			widgets: vec![TextLabel::new(name).italic(true).widget_holder()],
+		});
+		for input_index in pinned_inputs {
+			let mut row = input_properties(node_id, input_index, context);
+			if let Some(LayoutGroup::Row { widgets }) = row.first_mut() {
+				widgets.extend_from_slice(&[Separator::new(SeparatorType::Related).widget_holder(), pin_input_widget(node_id, input_index, true)]);
+			}
+			layout.extend(row);
+		}
+	}
+	if layout.is_empty() {
+		return None;
+	}
+
+	Some(LayoutGroup::Section {
+		name: "Pinned Parameters".to_string(),
+		description: "Parameters pinned from any node in the graph, including those of layers nested deep inside groups".to_string(),
+		visible: true,
+		bypassed: false,
+		pinned: true,
+		id: 0,
+		node_controls: false,
+		layout,
+	})
+}
+
+/// Lists the parameters of every node in the graph whose names contain the search query, under the names of their nodes, which select and focus the node when clicked.
+pub(crate) fn generate_parameter_search(query: &str, context: &mut NodePropertiesContext) -> Option<LayoutGroup> {
+	let query = query.trim().to_lowercase();
+	if query.is_empty() {
+		return None;
+	}
 
-// 	// 			widgets.extend_from_slice(&[
-// 	// 				Separator::new(SeparatorType::Unrelated).widget_holder(),
-// 	// 				DropdownInput::new(entries).selected_index(Some(starting_fill as u32)).widget_holder(),
-// 	// 			]);
-// 	// 		}
-// 	// 		LayoutGroup::Row { widgets }.with_tooltip(
-// 	// 			"Begin in/outpainting the masked areas using this fill content as the starting input image.\n\
-// 	// 			\n\
-// 	// 			Each option can be visualized by generating with 'Sampling Steps' set to 0.",
-// 	// 		)
-// 	// 	};
-// 	// 	layout.extend_from_slice(&[in_paint, blur_radius, mask_starting_fill]);
-// 	// }
+	let network = context.network_interface.nested_network(context.selection_network_path)?;
+	let mut node_ids = network.nodes.keys().copied().collect::<Vec<_>>();
+	node_ids.sort_by_cached_key(|node_id| (context.network_interface.display_name(node_id, context.selection_network_path), *node_id));
 
-// 	let improve_faces = {
-// 		let widgets = bool_widget(document_node, node_id, faces_index, "Improve Faces", CheckboxInput::default(), true);
-// 		LayoutGroup::Row { widgets }.with_tooltip(
-// 			"Postprocess human (or human-like) faces to look subtly less distorted.\n\
-// 			\n\
-// 			This filter can be used on its own by enabling 'Adapt Input Image' and setting 'Sampling Steps' to 0.",
-// 		)
-// 	};
-// 	let tiling = {
-// 		let widgets = bool_widget(document_node, node_id, tiling_index, "Tiling", CheckboxInput::default(), true);
-// 		LayoutGroup::Row { widgets }.with_tooltip("Generate the image so its edges loop seamlessly to make repeatable patterns or textures")
-// 	};
-// 	layout.extend_from_slice(&[improve_faces, tiling]);
+	let mut layout = Vec::new();
+	for node_id in node_ids {
+		let number_of_inputs = context.network_interface.number_of_inputs(&node_id, context.selection_network_path);
+		let matching_inputs = (1..number_of_inputs)
+			.filter(|&input_index| {
+				context
+					.network_interface
+					.input_name(&node_id, input_index, context.selection_network_path)
+					.is_some_and(|name| name.to_lowercase().contains(&query))
+			})
+			.collect::<Vec<_>>();
+		if matching_inputs.is_empty() {
+			continue;
+		}
 
-// 	layout
-// }
+		let name = context.network_interface.display_name(&node_id, context.selection_network_path);
+		layout.push(LayoutGroup::Row {
+			widgets: vec![
+				TextButton::new(name)
+					.tooltip("Select this node and focus the graph on it")
+					.on_update(move |_| {
+						Message::Batched(Box::new([
+							NodeGraphMessage::SelectedNodesSet { nodes: vec![node_id] }.into(),
+							DocumentMessage::GraphViewOverlay { open: true }.into(),
+							NavigationMessage::FitViewportToSelection.into(),
+						]))
+					})
+					.widget_holder(),
+			],
+		});
+		for input_index in matching_inputs {
+			layout.extend(input_properties(node_id, input_index, context));
+		}
+	}
+	if layout.is_empty() {
+		layout.push(LayoutGroup::Row {
+			widgets: vec![TextLabel::new("No parameters have a matching name").italic(true).widget_holder()],
+		});
+	}
 
-pub(crate) fn node_no_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
-	let text = if context.network_interface.is_layer(&node_id, context.selection_network_path) {
-		"Layer has no properties"
-	} else {
-		"Node has no properties"
-	};
-	string_properties(text)
+	Some(LayoutGroup::Section {
+		name: "Parameter Search".to_string(),
+		description: "Parameters of any node in the graph whose names match the search".to_string(),
+		visible: true,
+		bypassed: false,
+		pinned: false,
+		id: 0,
+		node_controls: false,
+		layout,
+	})
 }
 
 pub(crate) fn generate_node_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> LayoutGroup {
-	let mut layout = Vec::new();
+	node_properties_section(node_id, context, &[])
+}
 
-	if let Some(properties_override) = context
-		.network_interface
-		.reference(&node_id, context.selection_network_path)
-		.cloned()
-		.unwrap_or_default()
-		.as_ref()
-		.and_then(|reference| super::document_node_definitions::resolve_document_node_type(reference))
-		.and_then(|definition| definition.properties)
-		.and_then(|properties| NODE_OVERRIDES.get(properties))
-	{
-		layout = properties_override(node_id, context);
-	} else {
-		let number_of_inputs = context.network_interface.number_of_inputs(&node_id, context.selection_network_path);
-		for input_index in 1..number_of_inputs {
-			let row = context.call_widget_override(&node_id, input_index).unwrap_or_else(|| {
-				let Some(implementation) = context.network_interface.implementation(&node_id, context.selection_network_path) else {
-					log::error!("Could not get implementation for node {node_id}");
-					return Vec::new();
-				};
+/// Builds one section for several selected nodes of the same type. The widgets are built for the first node, and the messages they send are applied to every node by [`NodeGraphMessageHandler::multi_edit_targets`].
+pub(crate) fn generate_multi_node_properties(node_ids: &[NodeId], context: &mut NodePropertiesContext) -> LayoutGroup {
+	let Some((&primary_node_id, other_node_ids)) = node_ids.split_first() else {
+		return LayoutGroup::Row { widgets: vec![] };
+	};
 
-				let mut number_options = (None, None, None);
-				let input_type = match implementation {
-					DocumentNodeImplementation::ProtoNode(proto_node_identifier) => 'early_return: {
-						if let Some(field) = graphene_core::registry::NODE_METADATA
-							.lock()
-							.unwrap()
-							.get(&proto_node_identifier.name.clone().into_owned())
-							.and_then(|metadata| metadata.fields.get(input_index))
-						{
-							number_options = (field.number_min, field.number_max, field.number_mode_range);
-							if let Some(ref default) = field.default_type {
-								break 'early_return default.clone();
-							}
-						}
+	// The inputs whose values differ between the nodes
+	let mixed_inputs = match context.network_interface.nested_network(context.selection_network_path) {
+		Some(network) => {
+			let value = |node_id: &NodeId, index: usize| network.nodes.get(node_id).and_then(|node| node.inputs.get(index)).and_then(|input| input.as_non_exposed_value());
+			let number_of_inputs = network.nodes.get(&primary_node_id).map_or(0, |node| node.inputs.len());
+			(0..number_of_inputs)
+				.filter(|&index| other_node_ids.iter().any(|node_id| value(node_id, index) != value(&primary_node_id, index)))
+				.collect::<Vec<_>>()
+		}
+		None => Vec::new(),
+	};
 
-						let Some(implementations) = &interpreted_executor::node_registry::NODE_REGISTRY.get(proto_node_identifier) else {
-							log::error!("Could not get implementation for protonode {proto_node_identifier:?}");
-							return Vec::new();
-						};
+	let mut section = node_properties_section(primary_node_id, context, &mixed_inputs);
+	if let LayoutGroup::Section { name, .. } = &mut section {
+		*name = format!("{name} ({} Nodes)", node_ids.len());
+	}
+	section
+}
 
-						let proto_node_identifier = proto_node_identifier.clone();
+/// Blanks the widgets showing an input's value, or shows them as indeterminate, when the nodes edited together have different values for it.
+/// Returns false if one of the widgets has no blank state to show, and would be left showing the value of the first node.
+fn show_mixed_value(widgets: &mut [WidgetHolder]) -> bool {
+	let mut blanked = true;
+	for widget_holder in widgets {
+		match &mut widget_holder.widget {
+			Widget::CheckboxInput(checkbox_input) => {
+				checkbox_input.checked = false;
+				checkbox_input.indeterminate = true;
+			}
+			Widget::ColorInput(color_input) => color_input.value = FillChoice::None,
+			Widget::DropdownInput(dropdown_input) => dropdown_input.selected_index = None,
+			Widget::FilePathInput(file_path_input) => file_path_input.value.clear(),
+			Widget::NumberInput(number_input) => number_input.value = None,
+			Widget::RadioInput(radio_input) => radio_input.selected_index = None,
+			Widget::TextAreaInput(text_area_input) => text_area_input.value.clear(),
+			Widget::TextInput(text_input) => text_input.value.clear(),
+			Widget::CurveInput(_) | Widget::FontInput(_) | Widget::PivotInput(_) | Widget::RangeSliderInput(_) => blanked = false,
+			_ => {}
+		}
+	}
+	blanked
+}
 
-						let mut input_types = implementations
-							.keys()
-							.filter_map(|item| item.inputs.get(input_index))
-							.filter(|ty| property_from_type(node_id, input_index, ty, number_options, context).is_ok())
-							.collect::<Vec<_>>();
-						input_types.sort_by_key(|ty| ty.type_name());
-						let input_type = input_types.first().cloned();
+const MIXED_VALUES_TOOLTIP: &str = "The selected nodes have different values for this parameter. Editing it sets all of them to the new value.";
 
-						let Some(input_type) = input_type else {
-							log::error!("Could not get input type for protonode {proto_node_identifier:?} at index {input_index:?}");
-							return Vec::new();
-						};
+fn node_properties_section(node_id: NodeId, context: &mut NodePropertiesContext, mixed_inputs: &[usize]) -> LayoutGroup {
+	let mut layout = Vec::new();
 
-						input_type.clone()
-					}
-					_ => context.network_interface.input_type(&InputConnector::node(node_id, input_index), context.selection_network_path).0,
-				};
+	// A layout registered at runtime for the node's reference takes precedence over the one chosen by its definition
+	let reference = context.network_interface.reference(&node_id, context.selection_network_path).cloned().unwrap_or_default();
+	let custom_layout = reference.as_deref().and_then(|reference| {
+		super::document_node_definitions::call_registered_node_properties(reference, node_id, context).or_else(|| {
+			let properties_override = super::document_node_definitions::resolve_document_node_type(reference)
+				.and_then(|definition| definition.properties)
+				.and_then(|properties| NODE_OVERRIDES.get(properties))?;
+			Some(properties_override(node_id, context))
+		})
+	});
+
+	if let Some(custom_layout) = custom_layout {
+		layout = custom_layout;
+
+		// Custom layouts don't map rows to inputs, so the parameters with mixed values are listed together
+		let mixed_names = mixed_inputs
+			.iter()
+			.filter_map(|&index| context.network_interface.input_name(&node_id, index, context.selection_network_path))
+			.collect::<Vec<_>>();
+		if !mixed_names.is_empty() {
+			let widgets = vec![TextLabel::new(format!("Mixed: {}", mixed_names.join(", "))).italic(true).tooltip(MIXED_VALUES_TOOLTIP).widget_holder()];
+			layout.insert(0, LayoutGroup::Row { widgets });
+		}
+	} else {
+		// The values the node's inputs are created with, so those which have been changed can be told apart
+		let default_inputs = reference
+			.as_deref()
+			.and_then(super::document_node_definitions::resolve_document_node_type)
+			.map(|definition| definition.default_node_template().document_node.inputs)
+			.unwrap_or_default();
 
-				property_from_type(node_id, input_index, &input_type, number_options, context).unwrap_or_else(|value| value)
-			});
+		let number_of_inputs = context.network_interface.number_of_inputs(&node_id, context.selection_network_path);
+		for input_index in 1..number_of_inputs {
+			let mut row = input_properties(node_id, input_index, context);
+			if let Some(default_value) = default_inputs.get(input_index).and_then(|input| input.as_non_exposed_value()) {
+				highlight_modified_input(&mut row, node_id, input_index, default_value, context);
+			}
+			if let Some(LayoutGroup::Row { widgets }) = row.first_mut() {
+				let pinned = context.network_interface.is_input_pinned(&node_id, input_index, context.selection_network_path);
+				widgets.extend_from_slice(&[Separator::new(SeparatorType::Related).widget_holder(), pin_input_widget(node_id, input_index, pinned)]);
+			}
+			if mixed_inputs.contains(&input_index) {
+				let mut blanked = true;
+				for group in &mut row {
+					if let LayoutGroup::Row { widgets } = group {
+						blanked &= show_mixed_value(widgets);
+					}
+				}
+				// Widgets which can't be blanked are labeled instead, so the value of the first node isn't taken to be that of every node
+				if let Some(LayoutGroup::Row { widgets }) = row.last_mut().filter(|_| !blanked) {
+					widgets.extend_from_slice(&[
+						Separator::new(SeparatorType::Related).widget_holder(),
+						TextLabel::new("Mixed").italic(true).tooltip(MIXED_VALUES_TOOLTIP).widget_holder(),
+					]);
+				}
+			}
 
 			layout.extend(row);
 		}
@@ -2407,6 +4351,15 @@ pub(crate) fn generate_node_properties(node_id: NodeId, context: &mut NodeProper
 	if layout.is_empty() {
 		layout = node_no_properties(node_id, context);
 	}
+	if let Some(histogram) = input_histogram(node_id, context) {
+		layout.insert(0, histogram);
+	}
+	if let Some(preview) = noise_preview(node_id, context) {
+		layout.insert(0, preview);
+	}
+	if let Some(outdated) = outdated_node_notice(node_id, context) {
+		layout.insert(0, outdated);
+	}
 	let name = context
 		.network_interface
 		.reference(&node_id, context.selection_network_path)
@@ -2425,17 +4378,58 @@ pub(crate) fn generate_node_properties(node_id: NodeId, context: &mut NodeProper
 		.unwrap_or("Custom Node".to_string());
 	let description = context.network_interface.description(&node_id, context.selection_network_path);
 	let visible = context.network_interface.is_visible(&node_id, context.selection_network_path);
+	let bypassed = context.network_interface.is_bypassed(&node_id, context.selection_network_path);
 	let pinned = context.network_interface.is_pinned(&node_id, context.selection_network_path);
 	LayoutGroup::Section {
 		name,
 		description,
 		visible,
+		bypassed,
 		pinned,
 		id: node_id.0,
+		node_controls: true,
 		layout,
 	}
 }
 
+/// Emboldens the label of an input whose value differs from the default of its node's definition, and gives the label a context menu for resetting it to the default.
+fn highlight_modified_input(row: &mut [LayoutGroup], node_id: NodeId, input_index: usize, default_value: &TaggedValue, context: &NodePropertiesContext) {
+	let value = context
+		.network_interface
+		.nested_network(context.selection_network_path)
+		.and_then(|network| network.nodes.get(&node_id))
+		.and_then(|document_node| document_node.inputs.get(input_index))
+		.and_then(|input| input.as_non_exposed_value());
+	// Inputs fed by a wire don't have a value of their own to compare
+	if value.is_none_or(|value| value == default_value) {
+		return;
+	}
+
+	let Some(LayoutGroup::Row { widgets }) = row.first_mut() else { return };
+	let Some(Widget::TextLabel(label)) = widgets.iter_mut().map(|widget_holder| &mut widget_holder.widget).find(|widget| matches!(widget, Widget::TextLabel(_))) else {
+		return;
+	};
+
+	let default_value = default_value.clone();
+	label.bold = true;
+	label.tooltip = [label.tooltip.as_str(), "Changed from the default. Right click to reset it."]
+		.into_iter()
+		.filter(|text| !text.is_empty())
+		.collect::<Vec<_>>()
+		.join("\n\n");
+	label.context_menu = vec![vec![MenuListEntry::new("Reset").label("Reset to Default").icon("Reset").on_update(move |_| {
+		Message::Batched(Box::new([
+			NodeGraphMessage::SetInputValue {
+				node_id,
+				input_index,
+				value: default_value.clone(),
+			}
+			.into(),
+			DocumentMessage::AddTransaction.into(),
+		]))
+	})]];
+}
+
 /// Fill Node Widgets LayoutGroup
 pub(crate) fn fill_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let document_node = match get_document_node(node_id, context) {
@@ -2448,6 +4442,18 @@ pub(crate) fn fill_properties(node_id: NodeId, context: &mut NodePropertiesConte
 	let fill_index = 1;
 	let backup_color_index = 2;
 	let backup_gradient_index = 3;
+	let pattern_tile_index = 4;
+
+	// Text is where legibility matters, so its fill offers a contrast check against the artwork behind it
+	let fills_text = context
+		.network_interface
+		.upstream_flow_back_from_nodes(vec![node_id], context.selection_network_path, FlowType::PrimaryFlow)
+		.any(|upstream_node_id| {
+			context
+				.network_interface
+				.reference(&upstream_node_id, context.selection_network_path)
+				.is_some_and(|reference| reference.as_deref() == Some("Text"))
+		});
 
 	let mut widgets_first_row = start_widgets(document_node, node_id, fill_index, "Fill", "TODO", FrontendGraphDataType::General, true);
 
@@ -2464,49 +4470,53 @@ pub(crate) fn fill_properties(node_id: NodeId, context: &mut NodePropertiesConte
 	let backup_color_fill: Fill = backup_color.into();
 	let backup_gradient_fill: Fill = backup_gradient.clone().into();
 
-	widgets_first_row.push(Separator::new(SeparatorType::Unrelated).widget_holder());
-	widgets_first_row.push(
-		ColorInput::default()
-			.value(fill.clone().into())
-			.on_update(move |x: &ColorInput| {
-				Message::Batched(Box::new([
-					match &fill2 {
-						Fill::None => NodeGraphMessage::SetInputValue {
-							node_id,
-							input_index: backup_color_index,
-							value: TaggedValue::OptionalColor(None),
-						}
-						.into(),
-						Fill::Solid(color) => NodeGraphMessage::SetInputValue {
-							node_id,
-							input_index: backup_color_index,
-							value: TaggedValue::OptionalColor(Some(*color)),
-						}
-						.into(),
-						Fill::Gradient(gradient) => NodeGraphMessage::SetInputValue {
+	// A pattern takes its colors from its tile graphic, so there's no color to pick
+	if fill.as_pattern().is_none() {
+		widgets_first_row.push(Separator::new(SeparatorType::Unrelated).widget_holder());
+		widgets_first_row.push(
+			ColorInput::default()
+				.value(fill.clone().into())
+				.contrast_check(fills_text)
+				.on_update(move |x: &ColorInput| {
+					Message::Batched(Box::new([
+						match &fill2 {
+							Fill::None | Fill::Pattern(_) => NodeGraphMessage::SetInputValue {
+								node_id,
+								input_index: backup_color_index,
+								value: TaggedValue::OptionalColor(None),
+							}
+							.into(),
+							Fill::Solid(color) => NodeGraphMessage::SetInputValue {
+								node_id,
+								input_index: backup_color_index,
+								value: TaggedValue::OptionalColor(Some(*color)),
+							}
+							.into(),
+							Fill::Gradient(gradient) => NodeGraphMessage::SetInputValue {
+								node_id,
+								input_index: backup_gradient_index,
+								value: TaggedValue::Gradient(gradient.clone()),
+							}
+							.into(),
+						},
+						NodeGraphMessage::SetInputValue {
 							node_id,
-							input_index: backup_gradient_index,
-							value: TaggedValue::Gradient(gradient.clone()),
+							input_index: fill_index,
+							value: TaggedValue::Fill(x.value.to_fill(fill2.as_gradient())),
 						}
 						.into(),
-					},
-					NodeGraphMessage::SetInputValue {
-						node_id,
-						input_index: fill_index,
-						value: TaggedValue::Fill(x.value.to_fill(fill2.as_gradient())),
-					}
-					.into(),
-				]))
-			})
-			.on_commit(commit_value)
-			.widget_holder(),
-	);
+					]))
+				})
+				.on_commit(commit_value)
+				.widget_holder(),
+		);
+	}
 	let mut widgets = vec![LayoutGroup::Row { widgets: widgets_first_row }];
 
 	let fill_type_switch = {
 		let mut row = vec![TextLabel::new("").widget_holder()];
 		match fill {
-			Fill::Solid(_) | Fill::None => add_blank_assist(&mut row),
+			Fill::Solid(_) | Fill::None | Fill::Pattern(_) => add_blank_assist(&mut row),
 			Fill::Gradient(gradient) => {
 				let reverse_button = IconButton::new("Reverse", 24)
 					.tooltip("Reverse the gradient color stops")
@@ -2537,11 +4547,21 @@ pub(crate) fn fill_properties(node_id: NodeId, context: &mut NodePropertiesConte
 				.label("Gradient")
 				.on_update(update_value(move |_| TaggedValue::Fill(backup_gradient_fill.clone()), node_id, fill_index))
 				.on_commit(commit_value),
+			RadioEntryData::new("pattern")
+				.label("Pattern")
+				.tooltip("Repeat the graphic fed into the Pattern Tile input in a grid of tiles")
+				.on_update(update_value(move |_| TaggedValue::Fill(Fill::Pattern(Pattern::default())), node_id, fill_index))
+				.on_commit(commit_value),
 		];
+		let selected_index = match fill {
+			Fill::Gradient(_) => 1,
+			Fill::Pattern(_) => 2,
+			_ => 0,
+		};
 
 		row.extend_from_slice(&[
 			Separator::new(SeparatorType::Unrelated).widget_holder(),
-			RadioInput::new(entries).selected_index(Some(if fill.as_gradient().is_some() { 1 } else { 0 })).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(selected_index)).widget_holder(),
 		]);
 
 		LayoutGroup::Row { widgets: row }
@@ -2616,9 +4636,76 @@ pub(crate) fn fill_properties(node_id: NodeId, context: &mut NodePropertiesConte
 		widgets.push(LayoutGroup::Row { widgets: row });
 	}
 
+	if let Fill::Pattern(pattern) = fill {
+		let description = "The graphic repeated in a grid of tiles to fill the shape.";
+		widgets.push(LayoutGroup::Row {
+			widgets: start_widgets(document_node, node_id, pattern_tile_index, "Pattern Tile", description, FrontendGraphDataType::Group, true),
+		});
+
+		let spacing = pattern.spacing;
+		widgets.push(pattern_row(
+			"Spacing",
+			"The gap between neighboring tiles, in addition to the size of the tile graphic",
+			vec![
+				pattern_number_input(pattern, spacing.x, node_id, fill_index, |pattern, x| pattern.spacing.x = x).label("X").unit(" px"),
+				pattern_number_input(pattern, spacing.y, node_id, fill_index, |pattern, y| pattern.spacing.y = y).label("Y").unit(" px"),
+			],
+		));
+		widgets.push(pattern_row(
+			"Rotation",
+			"The rotation of the grid of tiles",
+			vec![
+				pattern_number_input(pattern, pattern.rotation, node_id, fill_index, |pattern, rotation| pattern.rotation = rotation)
+					.unit("°")
+					.mode_range()
+					.range_min(Some(-180.))
+					.range_max(Some(180.)),
+			],
+		));
+		let offset = pattern.offset;
+		widgets.push(pattern_row(
+			"Offset",
+			"The offset of the grid of tiles from the origin of the layer",
+			vec![
+				pattern_number_input(pattern, offset.x, node_id, fill_index, |pattern, x| pattern.offset.x = x).label("X").unit(" px"),
+				pattern_number_input(pattern, offset.y, node_id, fill_index, |pattern, y| pattern.offset.y = y).label("Y").unit(" px"),
+			],
+		));
+	}
+
 	widgets
 }
 
+/// A row of the Fill node's properties which edits the settings of its pattern.
+fn pattern_row(name: &str, tooltip: &str, number_inputs: Vec<NumberInput>) -> LayoutGroup {
+	let mut widgets = vec![TextLabel::new(name).tooltip(tooltip).widget_holder()];
+	add_blank_assist(&mut widgets);
+
+	for (i, number_input) in number_inputs.into_iter().enumerate() {
+		let separator_type = if i == 0 { SeparatorType::Unrelated } else { SeparatorType::Related };
+		widgets.push(Separator::new(separator_type).widget_holder());
+		widgets.push(number_input.widget_holder());
+	}
+
+	LayoutGroup::Row { widgets }
+}
+
+/// A number input which edits one of the settings of a pattern fill.
+fn pattern_number_input(pattern: &Pattern, value: f64, node_id: NodeId, fill_index: usize, modify: fn(&mut Pattern, f64)) -> NumberInput {
+	let pattern = pattern.clone();
+	NumberInput::new(Some(value))
+		.on_update(update_value(
+			move |number_input: &NumberInput| {
+				let mut pattern = pattern.clone();
+				modify(&mut pattern, number_input.value.unwrap_or_default());
+				TaggedValue::Fill(Fill::Pattern(pattern))
+			},
+			node_id,
+			fill_index,
+		))
+		.on_commit(commit_value)
+}
+
 pub fn stroke_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let document_node = match get_document_node(node_id, context) {
 		Ok(document_node) => document_node,
@@ -2629,22 +4716,53 @@ pub fn stroke_properties(node_id: NodeId, context: &mut NodePropertiesContext) -
 	};
 	let color_index = 1;
 	let weight_index = 2;
-	let dash_lengths_index = 3;
-	let dash_offset_index = 4;
-	let line_cap_index = 5;
-	let line_join_index = 6;
-	let miter_limit_index = 7;
+	let align_index = 3;
+	let dash_lengths_index = 4;
+	let dash_offset_index = 5;
+	let dash_align_corners_index = 6;
+	let dash_fit_length_index = 7;
+	let line_cap_index = 8;
+	let line_join_index = 9;
+	let miter_limit_index = 10;
+	let paint_order_index = 11;
+	let start_marker_index = 12;
+	let mid_marker_index = 13;
+	let end_marker_index = 14;
 
 	let color = color_widget(document_node, node_id, color_index, "Color", "TODO", ColorInput::default(), true);
 	let weight = number_widget(document_node, node_id, weight_index, "Weight", "TODO", NumberInput::default().unit(" px").min(0.), true);
+	let align = stroke_align_widget(document_node, node_id, align_index, "Align", "TODO", true);
 
 	let dash_lengths_val = match &document_node.inputs[dash_lengths_index].as_value() {
 		Some(TaggedValue::VecF64(x)) => x,
 		_ => &vec![],
 	};
-	let dash_lengths = vec_f64_input(document_node, node_id, dash_lengths_index, "Dash Lengths", "TODO", TextInput::default().centered(true), true);
+	let dash_offset_val = match &document_node.inputs[dash_offset_index].as_value() {
+		Some(TaggedValue::F64(x)) => *x,
+		_ => 0.,
+	};
+	let dash_lengths = dash_lengths_widget(document_node, node_id, dash_lengths_index, "Dash Lengths", "TODO", dash_offset_val, true);
 	let number_input = NumberInput::default().unit(" px").disabled(dash_lengths_val.is_empty());
 	let dash_offset = number_widget(document_node, node_id, dash_offset_index, "Dash Offset", "TODO", number_input, true);
+	let checkbox_input = CheckboxInput::default().disabled(dash_lengths_val.is_empty());
+	let dash_align_corners = bool_widget(
+		document_node,
+		node_id,
+		dash_align_corners_index,
+		"Align Dashes to Corners",
+		"Stretch the dash pattern between corners so that each corner is centered on a dash",
+		checkbox_input.clone(),
+		true,
+	);
+	let dash_fit_length = bool_widget(
+		document_node,
+		node_id,
+		dash_fit_length_index,
+		"Fit Dashes to Length",
+		"Stretch the dash pattern so it repeats a whole number of times along the path instead of being cut off at the end",
+		checkbox_input,
+		true,
+	);
 	let line_cap = line_cap_widget(document_node, node_id, line_cap_index, "Line Cap", "TODO", true);
 	let line_join = line_join_widget(document_node, node_id, line_join_index, "Line Join", "TODO", true);
 	let line_join_val = match &document_node.inputs[line_join_index].as_value() {
@@ -2653,16 +4771,40 @@ pub fn stroke_properties(node_id: NodeId, context: &mut NodePropertiesContext) -
 	};
 	let number_input = NumberInput::default().min(0.).disabled(line_join_val != &LineJoin::Miter);
 	let miter_limit = number_widget(document_node, node_id, miter_limit_index, "Miter Limit", "TODO", number_input, true);
-
-	vec![
-		color,
-		LayoutGroup::Row { widgets: weight },
-		LayoutGroup::Row { widgets: dash_lengths },
-		LayoutGroup::Row { widgets: dash_offset },
-		line_cap,
-		line_join,
-		LayoutGroup::Row { widgets: miter_limit },
-	]
+	let paint_order = paint_order_widget(
+		document_node,
+		node_id,
+		paint_order_index,
+		"Paint Order",
+		"Whether the stroke is painted over the fill, or beneath it so the fill covers the inner half of the stroke",
+		true,
+	);
+	let start_marker = stroke_marker_widget(document_node, node_id, start_marker_index, "Start Marker", "The shape drawn at the start of open paths", true);
+	let mid_marker = stroke_marker_widget(
+		document_node,
+		node_id,
+		mid_marker_index,
+		"Mid Marker",
+		"The shape drawn at every anchor besides the ends of open paths",
+		true,
+	);
+	let end_marker = stroke_marker_widget(document_node, node_id, end_marker_index, "End Marker", "The shape drawn at the end of open paths", true);
+
+	vec![color, LayoutGroup::Row { widgets: weight }, align, paint_order]
+		.into_iter()
+		.chain(dash_lengths)
+		.chain([
+			LayoutGroup::Row { widgets: dash_offset },
+			LayoutGroup::Row { widgets: dash_align_corners },
+			LayoutGroup::Row { widgets: dash_fit_length },
+			line_cap,
+			line_join,
+			LayoutGroup::Row { widgets: miter_limit },
+			start_marker,
+			mid_marker,
+			end_marker,
+		])
+		.collect()
 }
 
 pub fn offset_path_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
@@ -2692,6 +4834,188 @@ pub fn offset_path_properties(node_id: NodeId, context: &mut NodePropertiesConte
 	vec![LayoutGroup::Row { widgets: distance }, line_join, LayoutGroup::Row { widgets: miter_limit }]
 }
 
+pub(crate) fn fit_to_bounds_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let document_node = match get_document_node(node_id, context) {
+		Ok(document_node) => document_node,
+		Err(err) => {
+			log::error!("Could not get document node in fit_to_bounds_properties: {err}");
+			return Vec::new();
+		}
+	};
+	let target_position_index = 2;
+	let target_size_index = 3;
+	let fit_index = 4;
+	let padding_index = 5;
+	let alignment_index = 6;
+
+	let target_position = vec2_widget(
+		document_node,
+		node_id,
+		target_position_index,
+		"Target Position",
+		"The top left corner of the target rectangle, used when no reference graphic is connected.",
+		"X",
+		"Y",
+		" px",
+		None,
+		add_blank_assist,
+		Some(context.position_picker_target == Some((node_id, target_position_index))),
+	);
+	let target_size = vec2_widget(
+		document_node,
+		node_id,
+		target_size_index,
+		"Target Size",
+		"The dimensions of the target rectangle, used when no reference graphic is connected.",
+		"W",
+		"H",
+		" px",
+		None,
+		add_blank_assist,
+		None,
+	);
+	let fit = fit_mode_widget(document_node, node_id, fit_index, "Fit", "How the content is scaled to fit within the target.", true);
+	let padding = number_widget(
+		document_node,
+		node_id,
+		padding_index,
+		"Padding",
+		"The space kept between the content and each edge of the target.",
+		NumberInput::default().unit(" px"),
+		true,
+	);
+	let alignment = alignment_widget(
+		document_node,
+		node_id,
+		alignment_index,
+		"Alignment",
+		"Where the content sits within the target when their proportions differ.",
+		true,
+	);
+
+	vec![target_position, target_size, fit, LayoutGroup::Row { widgets: padding }, alignment]
+}
+
+pub(crate) fn randomize_transform_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let document_node = match get_document_node(node_id, context) {
+		Ok(document_node) => document_node,
+		Err(err) => {
+			log::error!("Could not get document node in randomize_transform_properties: {err}");
+			return Vec::new();
+		}
+	};
+	let distribution_index = 1;
+	let translation_indices = (2, 3);
+	let rotation_indices = (4, 5);
+	let scale_indices = (6, 7);
+	let seed_index = 8;
+
+	let distribution = random_distribution_widget(document_node, node_id, distribution_index, "Distribution", "How the random values are spread across each range.", true);
+
+	let translation_input = NumberInput::default().unit(" px");
+	let translation_x = range_widget(
+		document_node,
+		node_id,
+		translation_indices,
+		Some(0),
+		"Translation X",
+		"The range of horizontal offsets.",
+		translation_input.clone(),
+	);
+	let translation_y = range_widget(
+		document_node,
+		node_id,
+		translation_indices,
+		Some(1),
+		"Translation Y",
+		"The range of vertical offsets.",
+		translation_input,
+	);
+
+	let rotation_input = NumberInput::default().unit("°");
+	let rotation = range_widget(document_node, node_id, rotation_indices, None, "Rotation", "The range of rotations.", rotation_input);
+
+	let scale_input = NumberInput::default().unit("x");
+	let scale_x = range_widget(document_node, node_id, scale_indices, Some(0), "Scale X", "The range of horizontal scale factors.", scale_input.clone());
+	let scale_y = range_widget(document_node, node_id, scale_indices, Some(1), "Scale Y", "The range of vertical scale factors.", scale_input);
+
+	let seed = seed_widget(
+		document_node,
+		node_id,
+		seed_index,
+		"Seed",
+		"The seed used for randomization.",
+		NumberInput::default().int().min(0.),
+		true,
+	);
+
+	vec![distribution, translation_x, translation_y, rotation, scale_x, scale_y, LayoutGroup::Row { widgets: seed }]
+}
+
+pub(crate) fn instance_on_rows_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let document_node = match get_document_node(node_id, context) {
+		Ok(document_node) => document_node,
+		Err(err) => {
+			log::error!("Could not get document node in instance_on_rows_properties: {err}");
+			return Vec::new();
+		}
+	};
+	let color_column_index = 2;
+	let scale_column_index = 3;
+	let scale_factor_index = 4;
+	let rotation_column_index = 5;
+	let rotation_factor_index = 6;
+
+	// A factor is only used while its attribute is mapped to a column
+	let is_mapped = |index: usize| {
+		!matches!(
+			document_node.inputs.get(index).and_then(|input| input.as_value()),
+			Some(TaggedValue::RowValueColumn(RowValueColumn::None))
+		)
+	};
+
+	let color_column = row_color_column_widget(
+		document_node,
+		node_id,
+		color_column_index,
+		"Color",
+		"The column of the table whose color is applied to each copy.",
+		true,
+	);
+
+	let scale_column = row_value_column_widget(document_node, node_id, scale_column_index, "Scale", "The column of the table which scales each copy.", true);
+	let number_input = NumberInput::default().disabled(!is_mapped(scale_column_index));
+	let scale_factor = number_widget(
+		document_node,
+		node_id,
+		scale_factor_index,
+		"Scale Factor",
+		"Multiplies the column value to get the scale of each copy.",
+		number_input,
+		true,
+	);
+
+	let rotation_column = row_value_column_widget(document_node, node_id, rotation_column_index, "Rotation", "The column of the table which rotates each copy.", true);
+	let number_input = NumberInput::default().unit("°").disabled(!is_mapped(rotation_column_index));
+	let rotation_factor = number_widget(
+		document_node,
+		node_id,
+		rotation_factor_index,
+		"Rotation Factor",
+		"Multiplies the column value to get the rotation of each copy in degrees.",
+		number_input,
+		true,
+	);
+
+	vec![
+		color_column,
+		scale_column,
+		LayoutGroup::Row { widgets: scale_factor },
+		rotation_column,
+		LayoutGroup::Row { widgets: rotation_factor },
+	]
+}
+
 pub fn math_properties(node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let document_node = match get_document_node(node_id, context) {
 		Ok(document_node) => document_node,
@@ -2748,3 +5072,40 @@ pub fn math_properties(node_id: NodeId, context: &mut NodePropertiesContext) ->
 		LayoutGroup::Row { widgets: operand_a_hint }.with_tooltip(r#""A" is fed by the value from the previous node in the primary data flow, or it is 0 if disconnected"#),
 	]
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn number_lists_are_separated_only_by_commas() {
+		assert_eq!(numbers_from_expressions("1, 2.5, -3"), Some(vec![1., 2.5, -3.]));
+		assert_eq!(numbers_from_expressions("10 -2"), Some(vec![8.]));
+		assert_eq!(numbers_from_expressions("10, -2"), Some(vec![10., -2.]));
+		assert_eq!(numbers_from_expressions("300/4+10, (1 + 2) * 2"), Some(vec![85., 6.]));
+		assert_eq!(numbers_from_expressions("  "), Some(vec![]));
+
+		assert_eq!(numbers_from_expressions("1, , 2"), None);
+		assert_eq!(numbers_from_expressions("1, 2)"), None);
+		assert_eq!(numbers_from_expressions("(1, 2"), None);
+		assert_eq!(numbers_from_expressions("1, two"), None);
+	}
+
+	#[test]
+	fn dvec2_lists_are_read_as_pairs() {
+		assert_eq!(dvec2s_from_expressions("(1, 2), (3, 4)"), Some(vec![DVec2::new(1., 2.), DVec2::new(3., 4.)]));
+		assert_eq!(dvec2s_from_expressions("(10 -2, 1/4), (3, (4 + 1) * 2)"), Some(vec![DVec2::new(8., 0.25), DVec2::new(3., 10.)]));
+		assert_eq!(dvec2s_from_expressions(""), Some(vec![]));
+
+		// Leftover text outside of the pairs
+		assert_eq!(dvec2s_from_expressions("(1, 2) 3 4"), None);
+		assert_eq!(dvec2s_from_expressions("(1, 2), 3"), None);
+		assert_eq!(dvec2s_from_expressions("(1, 2) + (3, 4)"), None);
+		// Unbalanced parentheses
+		assert_eq!(dvec2s_from_expressions("(1, 2)), (3, 4)"), None);
+		assert_eq!(dvec2s_from_expressions("(1, 2), (3, 4"), None);
+		// Pairs without exactly two components
+		assert_eq!(dvec2s_from_expressions("(1, 2, 3)"), None);
+		assert_eq!(dvec2s_from_expressions("(1)"), None);
+	}
+}
@@ -0,0 +1,247 @@
+use super::document_node_definitions::resolve_document_node_type;
+use crate::messages::portfolio::document::utility_types::network_interface::{InputConnector, NodeNetworkInterface};
+use graph_craft::document::NodeId;
+
+/// Rewrites a single node in place. Called with the node's ID and the path to the network containing it.
+pub type NodeRewrite = fn(&mut NodeNetworkInterface, &NodeId, &[NodeId]);
+
+/// A breaking change made to a node definition in some release.
+///
+/// Each node records the [`definition_version`](crate::messages::portfolio::document::utility_types::network_interface::DocumentNodePersistentMetadata::definition_version)
+/// of its definition, which is the number of changes listed here for that definition at the time the node was created or last upgraded.
+/// Nodes behind the current version are not silently migrated when a document is opened if that could change their output.
+/// Instead, their `shim` keeps them evaluating as they did before, and the user may choose to apply `upgrade` from the Properties panel.
+pub struct NodeDefinitionChange {
+	/// The identifier of the [`DocumentNodeDefinition`](super::document_node_definitions::DocumentNodeDefinition) that changed.
+	pub reference: &'static str,
+	/// Shown to the user to explain what upgrading the node will change.
+	pub description: &'static str,
+	/// How an outdated node is kept working when a document is opened.
+	pub shim: Option<NodeShim>,
+	/// Migrates the node to the new behavior of the definition.
+	pub upgrade: NodeRewrite,
+}
+
+/// How a node pinned to a version before a [`NodeDefinitionChange`] is kept working when a document is opened.
+pub enum NodeShim {
+	/// Adapts an outdated node so it keeps producing its old result with the new implementation. Runs every time a document is opened, so it must be idempotent.
+	Rewrite(NodeRewrite),
+	/// The upgrade keeps producing the node's old result, so it's applied when the document is opened, and the node is pinned past the change as long as no earlier change is still pending.
+	Upgrade,
+}
+
+/// Every breaking change made to a node definition, in the order they were released. New changes must be appended to the end.
+pub static NODE_DEFINITION_CHANGES: &[NodeDefinitionChange] = &[
+	NodeDefinitionChange {
+		reference: "Stroke",
+		description: "Adds the Align input for placing the stroke of a closed path inside or outside of its shape, which starts centered on the path as before",
+		shim: Some(NodeShim::Upgrade),
+		upgrade: add_stroke_align_input,
+	},
+	NodeDefinitionChange {
+		reference: "Stroke",
+		description: "Adds the Align Corners and Fit Length inputs for stretching the dash pattern, which start disabled as before",
+		shim: Some(NodeShim::Upgrade),
+		upgrade: add_stroke_dash_fitting_inputs,
+	},
+	NodeDefinitionChange {
+		reference: "Stroke",
+		description: "Adds the Paint Order and marker inputs, which start with the stroke above the fill and no markers as before",
+		shim: Some(NodeShim::Upgrade),
+		upgrade: add_stroke_paint_order_and_marker_inputs,
+	},
+];
+
+// The new inputs of each Stroke change default to the stroke's previous look, so upgrading doesn't change the result and is done when the document is opened
+
+fn add_stroke_align_input(network_interface: &mut NodeNetworkInterface, node_id: &NodeId, network_path: &[NodeId]) {
+	insert_default_inputs(network_interface, node_id, network_path, 8, 3, 1);
+}
+
+fn add_stroke_dash_fitting_inputs(network_interface: &mut NodeNetworkInterface, node_id: &NodeId, network_path: &[NodeId]) {
+	insert_default_inputs(network_interface, node_id, network_path, 9, 6, 2);
+}
+
+fn add_stroke_paint_order_and_marker_inputs(network_interface: &mut NodeNetworkInterface, node_id: &NodeId, network_path: &[NodeId]) {
+	insert_default_inputs(network_interface, node_id, network_path, 11, 11, 4);
+}
+
+/// Inserts `count` inputs at `index`, with the values of the node's definition, into a node which has `inputs_count` inputs.
+/// Nodes with any other number of inputs already have them, which keeps the rewrite idempotent.
+fn insert_default_inputs(network_interface: &mut NodeNetworkInterface, node_id: &NodeId, network_path: &[NodeId], inputs_count: usize, index: usize, count: usize) {
+	if network_interface.document_node(node_id, network_path).is_none_or(|node| node.inputs.len() != inputs_count) {
+		return;
+	}
+	let Some(node_definition) = network_interface
+		.reference(node_id, network_path)
+		.cloned()
+		.flatten()
+		.and_then(|reference| resolve_document_node_type(&reference))
+	else {
+		return;
+	};
+	let document_node = node_definition.default_node_template().document_node;
+	// Later changes to the definition only insert inputs after this one's, so the definition's inputs up to here line up with the node's once inserted
+	let Some(inputs) = document_node.inputs.get(..inputs_count + count) else {
+		return;
+	};
+	network_interface.replace_implementation(node_id, network_path, document_node.implementation.clone());
+
+	let old_inputs = network_interface.replace_inputs(node_id, inputs.to_vec(), network_path);
+	for (i, input) in old_inputs.into_iter().enumerate() {
+		let new_index = if i < index { i } else { i + count };
+		network_interface.set_input(&InputConnector::node(*node_id, new_index), input, network_path);
+	}
+}
+
+/// The version which newly created nodes of this definition are pinned to.
+pub fn current_version(reference: &str) -> u32 {
+	current_version_in(NODE_DEFINITION_CHANGES, reference)
+}
+
+/// The changes that a node of this definition at the given version has not yet been upgraded past, oldest first.
+pub fn pending_changes(reference: &str, version: u32) -> impl Iterator<Item = &'static NodeDefinitionChange> {
+	pending_changes_in(NODE_DEFINITION_CHANGES, reference, version)
+}
+
+/// Keeps an outdated node evaluating the way it did at the version it is pinned to, pinning it past the changes whose upgrades don't change its result.
+pub fn apply_shims(network_interface: &mut NodeNetworkInterface, node_id: &NodeId, network_path: &[NodeId]) {
+	let Some((reference, version)) = pinned_version(network_interface, node_id, network_path) else {
+		return;
+	};
+
+	// A node can only be pinned past a change once it's pinned past every change before it
+	let mut pinned_version = version;
+	let mut pinning = true;
+	for change in pending_changes(&reference, version) {
+		match change.shim {
+			Some(NodeShim::Upgrade) => {
+				(change.upgrade)(network_interface, node_id, network_path);
+				if pinning {
+					pinned_version += 1;
+				}
+			}
+			Some(NodeShim::Rewrite(shim)) => {
+				shim(network_interface, node_id, network_path);
+				pinning = false;
+			}
+			None => pinning = false,
+		}
+	}
+	if pinned_version != version {
+		network_interface.set_definition_version(node_id, network_path, pinned_version);
+	}
+}
+
+/// Applies every pending upgrade to the node and pins it to the current version of its definition. Returns false if the node was already up to date.
+pub fn upgrade_node(network_interface: &mut NodeNetworkInterface, node_id: &NodeId, network_path: &[NodeId]) -> bool {
+	let Some((reference, version)) = pinned_version(network_interface, node_id, network_path) else {
+		return false;
+	};
+
+	let mut upgraded = false;
+	for change in pending_changes(&reference, version) {
+		(change.upgrade)(network_interface, node_id, network_path);
+		upgraded = true;
+	}
+	if upgraded {
+		network_interface.set_definition_version(node_id, network_path, current_version(&reference));
+	}
+
+	upgraded
+}
+
+fn pinned_version(network_interface: &NodeNetworkInterface, node_id: &NodeId, network_path: &[NodeId]) -> Option<(String, u32)> {
+	let reference = network_interface.reference(node_id, network_path)?.clone()?;
+	let version = network_interface.definition_version(node_id, network_path)?;
+	Some((reference, version))
+}
+
+fn current_version_in(changes: &[NodeDefinitionChange], reference: &str) -> u32 {
+	changes.iter().filter(|change| change.reference == reference).count() as u32
+}
+
+fn pending_changes_in<'a>(changes: &'a [NodeDefinitionChange], reference: &str, version: u32) -> impl Iterator<Item = &'a NodeDefinitionChange> {
+	changes.iter().filter(move |change| change.reference == reference).skip(version as usize)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn noop(_: &mut NodeNetworkInterface, _: &NodeId, _: &[NodeId]) {}
+
+	const CHANGES: &[NodeDefinitionChange] = &[
+		NodeDefinitionChange {
+			reference: "Blur",
+			description: "First Blur change",
+			shim: None,
+			upgrade: noop,
+		},
+		NodeDefinitionChange {
+			reference: "Transform",
+			description: "Transform change",
+			shim: None,
+			upgrade: noop,
+		},
+		NodeDefinitionChange {
+			reference: "Blur",
+			description: "Second Blur change",
+			shim: Some(NodeShim::Rewrite(noop)),
+			upgrade: noop,
+		},
+	];
+
+	#[test]
+	fn versions_count_changes_per_definition() {
+		assert_eq!(current_version_in(CHANGES, "Blur"), 2);
+		assert_eq!(current_version_in(CHANGES, "Transform"), 1);
+		assert_eq!(current_version_in(CHANGES, "Merge"), 0);
+	}
+
+	#[test]
+	fn pending_changes_skip_applied_versions() {
+		let descriptions = |version| pending_changes_in(CHANGES, "Blur", version).map(|change| change.description).collect::<Vec<_>>();
+		assert_eq!(descriptions(0), ["First Blur change", "Second Blur change"]);
+		assert_eq!(descriptions(1), ["Second Blur change"]);
+		assert!(descriptions(2).is_empty());
+	}
+
+	#[test]
+	fn outdated_stroke_nodes_are_upgraded_when_opened() {
+		use graph_craft::document::NodeInput;
+		use graph_craft::document::value::TaggedValue;
+
+		let mut network_interface = NodeNetworkInterface::default();
+		let node_id = NodeId(0);
+		let template = resolve_document_node_type("Stroke").expect("Stroke node does not exist").default_node_template();
+		let current_inputs = template.document_node.inputs.clone();
+		network_interface.insert_node(node_id, template, &[]);
+
+		// A Stroke node from before its changes, without the Align, Align Corners, Fit Length, Paint Order, and marker inputs, with a set weight and miter limit
+		let mut old_inputs = current_inputs
+			.iter()
+			.enumerate()
+			.filter(|(index, _)| !matches!(index, 3 | 6 | 7 | 11..=14))
+			.map(|(_, input)| input.clone())
+			.collect::<Vec<_>>();
+		assert_eq!(old_inputs.len(), 8);
+		old_inputs[2] = NodeInput::value(TaggedValue::F64(5.), false);
+		old_inputs[7] = NodeInput::value(TaggedValue::F64(10.), false);
+		network_interface.replace_inputs(&node_id, old_inputs, &[]);
+		network_interface.set_definition_version(&node_id, &[], 0);
+
+		apply_shims(&mut network_interface, &node_id, &[]);
+		let inputs = &network_interface.document_node(&node_id, &[]).expect("Stroke node should exist").inputs;
+		assert_eq!(inputs.len(), current_inputs.len());
+		assert_eq!(inputs[2].as_value(), Some(&TaggedValue::F64(5.)), "The weight should keep its place");
+		assert_eq!(inputs[10].as_value(), Some(&TaggedValue::F64(10.)), "The miter limit should move past the inserted inputs");
+		assert_eq!(inputs[3], current_inputs[3], "The Align input should start at its default");
+		assert_eq!(network_interface.definition_version(&node_id, &[]), Some(current_version("Stroke")));
+
+		// Since opening the document already upgraded the node, there's nothing left to upgrade, and opening it again changes nothing
+		assert!(!upgrade_node(&mut network_interface, &node_id, &[]));
+		apply_shims(&mut network_interface, &node_id, &[]);
+		assert_eq!(network_interface.document_node(&node_id, &[]).expect("Stroke node should exist").inputs.len(), current_inputs.len());
+	}
+}
@@ -0,0 +1,145 @@
+use crate::messages::portfolio::document::utility_types::network_interface::NodeNetworkInterface;
+use graph_craft::document::NodeId;
+use graph_craft::document::value::TaggedValue;
+
+/// Prefix of the clipboard text holding the parameter values copied from a node, to tell it apart from copied layers and nodes.
+pub const PARAMETER_VALUES_CLIPBOARD_PREFIX: &str = "graphite/parameters: ";
+
+/// The value of one input of a node, copied along with its name so it can find the matching input even if the inputs have since been reordered.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CopiedParameter {
+	pub index: usize,
+	pub name: String,
+	pub value: TaggedValue,
+}
+
+/// The values of all the inputs of a node which aren't fed by a wire, copied from its section in the Properties panel to be pasted into another node of the same type.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CopiedParameters {
+	/// The identifier of the node definition the values were copied from.
+	pub reference: Option<String>,
+	pub parameters: Vec<CopiedParameter>,
+}
+
+/// What happens to a copied value when it's pasted into a node.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PastedParameter {
+	/// The input at this index takes the value.
+	Set(usize, TaggedValue),
+	/// The input already has the value.
+	Unchanged,
+	/// The value is left out, for the reason given.
+	Skipped { name: String, reason: &'static str },
+}
+
+impl CopiedParameters {
+	pub fn from_node(network_interface: &NodeNetworkInterface, node_id: NodeId, network_path: &[NodeId]) -> Option<Self> {
+		let node = network_interface.document_node(&node_id, network_path)?;
+		let parameters = node
+			.inputs
+			.iter()
+			.enumerate()
+			.filter_map(|(index, input)| {
+				let value = input.as_non_exposed_value()?.clone();
+				let name = network_interface.input_name(&node_id, index, network_path).unwrap_or_default().to_string();
+				Some(CopiedParameter { index, name, value })
+			})
+			.collect();
+
+		Some(Self {
+			reference: network_interface.reference(&node_id, network_path).cloned().flatten(),
+			parameters,
+		})
+	}
+
+	/// Matches each copied value with an input of the target node, given as the name of each input and its value if it isn't fed by a wire.
+	/// An input is found by its name where possible, falling back to its index, and a value is only pasted into an input holding a value of the same type.
+	pub fn paste_into(&self, target_inputs: &[(String, Option<&TaggedValue>)]) -> Vec<PastedParameter> {
+		self.parameters
+			.iter()
+			.map(|parameter| {
+				let skipped = |reason| PastedParameter::Skipped { name: parameter.name.clone(), reason };
+
+				let by_name = target_inputs.iter().position(|(name, _)| !name.is_empty() && *name == parameter.name);
+				let by_index = (parameter.index < target_inputs.len()).then_some(parameter.index);
+				let Some(index) = by_name.or(by_index) else {
+					return skipped("the node has no such input");
+				};
+
+				match target_inputs[index].1 {
+					None => skipped("the input is fed by a wire"),
+					Some(current) if current.ty() != parameter.value.ty() => skipped("the input holds a different type of value"),
+					Some(current) if *current == parameter.value => PastedParameter::Unchanged,
+					Some(_) => PastedParameter::Set(index, parameter.value.clone()),
+				}
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn values_are_matched_by_name_then_index() {
+		let copied = CopiedParameters {
+			reference: Some("Blur".to_string()),
+			parameters: vec![
+				CopiedParameter {
+					index: 1,
+					name: "Radius".to_string(),
+					value: TaggedValue::F64(8.),
+				},
+				CopiedParameter {
+					index: 2,
+					name: "Gamma".to_string(),
+					value: TaggedValue::Bool(true),
+				},
+				CopiedParameter {
+					index: 3,
+					name: "Quality".to_string(),
+					value: TaggedValue::U32(2),
+				},
+				CopiedParameter {
+					index: 5,
+					name: "Removed".to_string(),
+					value: TaggedValue::F64(1.),
+				},
+			],
+		};
+
+		let radius = TaggedValue::F64(2.);
+		let gamma = TaggedValue::F64(0.);
+		let target = [
+			("Image".to_string(), None),
+			("Gamma".to_string(), Some(&gamma)),
+			("Radius".to_string(), Some(&radius)),
+			("Quality".to_string(), None),
+		];
+		let pasted = copied.paste_into(&target);
+
+		assert_eq!(pasted[0], PastedParameter::Set(2, TaggedValue::F64(8.)));
+		assert!(matches!(
+			pasted[1],
+			PastedParameter::Skipped {
+				reason: "the input holds a different type of value",
+				..
+			}
+		));
+		assert!(matches!(
+			pasted[2],
+			PastedParameter::Skipped {
+				reason: "the input is fed by a wire",
+				..
+			}
+		));
+		assert!(matches!(
+			pasted[3],
+			PastedParameter::Skipped {
+				reason: "the node has no such input",
+				..
+			}
+		));
+	}
+}
@@ -1,4 +1,6 @@
 use crate::messages::portfolio::document::utility_types::network_interface::{InputConnector, OutputConnector, TypeSource};
+use crate::messages::portfolio::document::utility_types::node_profile::CacheStatus;
+use glam::DVec2;
 use graph_craft::document::NodeId;
 use graph_craft::document::value::TaggedValue;
 use graphene_core::Type;
@@ -89,6 +91,7 @@ pub struct FrontendNode {
 	pub exposed_outputs: Vec<FrontendGraphOutput>,
 	pub position: (i32, i32),
 	pub visible: bool,
+	pub bypassed: bool,
 	pub locked: bool,
 	pub previewed: bool,
 	pub errors: Option<String>,
@@ -105,12 +108,37 @@ pub struct FrontendNodeWire {
 	pub dashed: bool,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct FrontendGraphFrame {
+	pub id: u64,
+	pub title: String,
+	/// CSS color of the frame, as a `#RRGGBB` hex string
+	pub color: String,
+	/// Top left corner of the frame, in grid units
+	pub position: (i32, i32),
+	/// Width and height of the frame, in grid units
+	pub dimensions: (i32, i32),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct FrontendGraphNote {
+	pub id: u64,
+	/// Markdown source of the note, rendered by the frontend
+	pub text: String,
+	/// Top left corner of the note, in grid units
+	pub position: (i32, i32),
+	/// Width and height of the note, in grid units
+	pub dimensions: (i32, i32),
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
 pub struct FrontendNodeType {
 	pub name: String,
 	pub category: String,
 	#[serde(rename = "inputTypes")]
 	pub input_types: Option<Vec<String>>,
+	#[serde(rename = "outputTypes")]
+	pub output_types: Option<Vec<String>>,
 }
 
 impl FrontendNodeType {
@@ -119,6 +147,7 @@ impl FrontendNodeType {
 			name: name.to_string(),
 			category: category.to_string(),
 			input_types: None,
+			output_types: None,
 		}
 	}
 
@@ -127,6 +156,7 @@ impl FrontendNodeType {
 			name: name.to_string(),
 			category: category.to_string(),
 			input_types: Some(input_types),
+			output_types: None,
 		}
 	}
 
@@ -135,6 +165,14 @@ impl FrontendNodeType {
 			name,
 			category,
 			input_types: Some(input_types),
+			output_types: None,
+		}
+	}
+
+	pub fn with_output_types(self, output_types: Vec<String>) -> Self {
+		Self {
+			output_types: Some(output_types),
+			..self
 		}
 	}
 }
@@ -188,6 +226,12 @@ pub enum ContextMenuData {
 		#[serde(default)]
 		compatible_type: Option<String>,
 	},
+	InspectWire {
+		#[serde(rename = "inputConnector")]
+		input_connector: InputConnector,
+		/// Whether a readout of the wire's value is pinned along it, so the menu offers to unpin it instead
+		pinned: bool,
+	},
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
@@ -230,6 +274,7 @@ pub enum GraphWireStyle {
 	#[default]
 	Direct = 0,
 	GridAligned = 1,
+	Straight = 2,
 }
 
 impl std::fmt::Display for GraphWireStyle {
@@ -237,19 +282,223 @@ impl std::fmt::Display for GraphWireStyle {
 		match self {
 			GraphWireStyle::GridAligned => write!(f, "Grid-Aligned"),
 			GraphWireStyle::Direct => write!(f, "Direct"),
+			GraphWireStyle::Straight => write!(f, "Straight"),
 		}
 	}
 }
 
 impl GraphWireStyle {
+	pub const ALL: [GraphWireStyle; 3] = [GraphWireStyle::Direct, GraphWireStyle::Straight, GraphWireStyle::GridAligned];
+
 	pub fn tooltip_description(&self) -> &'static str {
 		match self {
-			GraphWireStyle::GridAligned => "Wires follow the grid, running in straight lines between nodes",
+			GraphWireStyle::GridAligned => "Wires follow the grid, running in straight lines between nodes and detouring around nodes in their way",
 			GraphWireStyle::Direct => "Wires bend to run at an angle directly between nodes",
+			GraphWireStyle::Straight => "Wires run in a straight line directly between nodes",
 		}
 	}
+}
+
+/// A summary of the value carried by a wire in the most recent evaluation of the graph, shown in a tooltip on the wire.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct WireProbeValue {
+	/// The kind of value, such as "Number" or "Vector Data"
+	#[serde(rename = "typeName")]
+	pub type_name: String,
+	/// The labeled properties of the value worth showing, in the order they're listed
+	pub details: Vec<(String, String)>,
+	/// The RGBA hex code of a color value, shown as a swatch
+	pub color: Option<String>,
+	/// An SVG rendering of a graphical value, shown as a small thumbnail
+	pub thumbnail: Option<String>,
+}
+
+impl WireProbeValue {
+	fn new(type_name: impl Into<String>, details: impl IntoIterator<Item = (&'static str, String)>) -> Self {
+		Self {
+			type_name: type_name.into(),
+			details: details.into_iter().map(|(label, value)| (label.to_string(), value)).collect(),
+			color: None,
+			thumbnail: None,
+		}
+	}
+
+	/// Used when the wire's value can't be shown, with the reason why.
+	pub fn unavailable(reason: impl Into<String>) -> Self {
+		Self::new("Unavailable", [("Reason", reason.into())])
+	}
+
+	pub fn from_tagged_value(value: &TaggedValue) -> Self {
+		fn number(value: f64) -> String {
+			let value = format!("{value:.4}");
+			value.trim_end_matches('0').trim_end_matches('.').to_string()
+		}
+		fn size([min, max]: [DVec2; 2]) -> String {
+			let size = max - min;
+			format!("{} × {}", number(size.x), number(size.y))
+		}
+		fn color(type_name: &str, color: Option<graphene_core::Color>) -> WireProbeValue {
+			let Some(color) = color else {
+				return WireProbeValue::new(type_name, [("Value", "None".to_string())]);
+			};
+			let hex = color.to_rgba_hex_srgb();
+			WireProbeValue {
+				color: Some(hex.clone()),
+				..WireProbeValue::new(type_name, [("Hex", format!("#{}", &hex[..6])), ("Alpha", format!("{}%", number(color.a() as f64 * 100.)))])
+			}
+		}
+
+		match value {
+			TaggedValue::F64(value) => Self::new("Number", [("Value", number(*value))]),
+			TaggedValue::U32(value) => Self::new("Number", [("Value", value.to_string())]),
+			TaggedValue::U64(value) => Self::new("Number", [("Value", value.to_string())]),
+			TaggedValue::OptionalF64(value) => Self::new("Number", [("Value", value.map_or("None".to_string(), number))]),
+			TaggedValue::Bool(value) => Self::new("Boolean", [("Value", value.to_string())]),
+			TaggedValue::String(value) => {
+				const MAX_CHARACTERS: usize = 100;
+				let characters = value.chars().count();
+				let mut text = value.chars().take(MAX_CHARACTERS).collect::<String>();
+				if characters > MAX_CHARACTERS {
+					text.push('…');
+				}
+				Self::new("Text", [("Value", format!("\"{text}\"")), ("Length", characters.to_string())])
+			}
+			TaggedValue::DVec2(value) => Self::new("Vec2", [("X", number(value.x)), ("Y", number(value.y))]),
+			TaggedValue::UVec2(value) => Self::new("Vec2", [("X", value.x.to_string()), ("Y", value.y.to_string())]),
+			TaggedValue::IVec2(value) => Self::new("Vec2", [("X", value.x.to_string()), ("Y", value.y.to_string())]),
+			TaggedValue::DAffine2(transform) => {
+				let (scale, angle, translation) = transform.to_scale_angle_translation();
+				Self::new(
+					"Transform",
+					[
+						("Translation", format!("{}, {}", number(translation.x), number(translation.y))),
+						("Rotation", format!("{}°", number(angle.to_degrees()))),
+						("Scale", format!("{}, {}", number(scale.x), number(scale.y))),
+					],
+				)
+			}
+			TaggedValue::Color(value) => color("Color", Some(*value)),
+			TaggedValue::OptionalColor(value) => color("Color", *value),
+			TaggedValue::VectorData(table) => {
+				let vector_data = table.instances().map(|instance| instance.instance);
+				let (points, segments, regions) = vector_data.fold((0, 0, 0), |(points, segments, regions), vector_data| {
+					(
+						points + vector_data.point_domain.ids().len(),
+						segments + vector_data.segment_domain.ids().len(),
+						regions + vector_data.region_domain.ids().len(),
+					)
+				});
+				let bounds = table
+					.instances()
+					.filter_map(|instance| instance.instance.bounding_box_with_transform(*instance.transform))
+					.reduce(|[a_min, a_max], [b_min, b_max]| [a_min.min(b_min), a_max.max(b_max)]);
+				Self::new(
+					"Vector Data",
+					[
+						("Instances", table.len().to_string()),
+						("Points", points.to_string()),
+						("Segments", segments.to_string()),
+						("Regions", regions.to_string()),
+						("Bounds", bounds.map_or("Empty".to_string(), size)),
+					],
+				)
+			}
+			TaggedValue::ImageFrame(table) => {
+				let mut details = vec![("Instances", table.len().to_string())];
+				if let Some(image) = table.instances().next().map(|instance| instance.instance) {
+					details.push(("Dimensions", format!("{} × {} px", image.width, image.height)));
+				}
+				Self::new("Raster", details)
+			}
+			TaggedValue::GraphicGroup(table) => Self::new("Group", [("Elements", table.len().to_string())]),
+			TaggedValue::ArtboardGroup(table) => Self::new("Artboards", [("Artboards", table.len().to_string())]),
+			TaggedValue::None => Self::new("Nothing", []),
+			value => Self::new(value.ty().to_string(), []),
+		}
+	}
+}
+
+/// The wire whose value is shown in a tooltip in the node graph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WireProbe {
+	pub input_connector: InputConnector,
+	/// The path of the network the wire is in
+	pub network_path: Vec<NodeId>,
+	/// The document node path of the node whose output the wire carries, if it's an output that can be evaluated on its own
+	pub node_path: Option<Vec<NodeId>>,
+	/// Where the tooltip is shown, in node graph coordinates
+	pub position: DVec2,
+	/// Whether the tooltip was opened from the wire's context menu, which keeps it open until the next click rather than only while the wire is hovered
+	pub inspected: bool,
+	pub value: Option<WireProbeValue>,
+}
+
+/// The tooltip showing the value carried by a wire, at a point along the wire in node graph coordinates.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct FrontendWireProbe {
+	pub position: (f64, f64),
+	/// Whether the tooltip was opened with the wire's "Inspect Value" context menu entry, rather than by hovering the wire
+	pub inspected: bool,
+	/// The value, or `None` while it's being evaluated
+	pub value: Option<WireProbeValue>,
+}
+
+/// A readout of the value carried by a wire which is pinned along it, so it stays in the graph and updates with each render.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct FrontendPinnedWireProbe {
+	/// The input the wire ends at, which identifies the wire when it's unpinned
+	#[serde(rename = "inputConnector")]
+	pub input_connector: InputConnector,
+	/// The point halfway along the wire, in node graph coordinates
+	pub position: (f64, f64),
+	/// The value, or `None` while it's being evaluated
+	pub value: Option<WireProbeValue>,
+}
+
+/// The time spent in a node of the viewed network in the most recent render, shown over the node by the profiler overlay.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct FrontendNodeProfile {
+	#[serde(rename = "nodeId")]
+	pub node_id: NodeId,
+	pub milliseconds: f64,
+	/// The time relative to the slowest node of the network, from 0 to 1, which sets the heatmap color of the node
+	pub heat: f64,
+	pub status: CacheStatus,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use graphene_core::vector::{VectorData, VectorDataTable};
+
+	fn detail<'a>(probe: &'a WireProbeValue, label: &str) -> Option<&'a str> {
+		probe.details.iter().find(|(detail_label, _)| detail_label == label).map(|(_, value)| value.as_str())
+	}
+
+	#[test]
+	fn probed_numbers_are_trimmed() {
+		let probe = WireProbeValue::from_tagged_value(&TaggedValue::F64(2.5));
+		assert_eq!(probe.type_name, "Number");
+		assert_eq!(detail(&probe, "Value"), Some("2.5"));
+
+		let probe = WireProbeValue::from_tagged_value(&TaggedValue::F64(1. / 3.));
+		assert_eq!(detail(&probe, "Value"), Some("0.3333"));
+	}
+
+	#[test]
+	fn probed_colors_have_a_swatch() {
+		let probe = WireProbeValue::from_tagged_value(&TaggedValue::Color(graphene_core::Color::BLACK));
+		assert_eq!(probe.color.as_deref(), Some("000000ff"));
+		assert_eq!(detail(&probe, "Alpha"), Some("100%"));
+	}
 
-	pub fn is_direct(&self) -> bool {
-		*self == GraphWireStyle::Direct
+	#[test]
+	fn probed_vector_data_is_summarized() {
+		let rectangle = VectorData::from_subpath(bezier_rs::Subpath::new_rect(DVec2::ZERO, DVec2::new(20., 10.)));
+		let probe = WireProbeValue::from_tagged_value(&TaggedValue::VectorData(VectorDataTable::new(rectangle)));
+		assert_eq!(probe.type_name, "Vector Data");
+		assert_eq!(detail(&probe, "Points"), Some("4"));
+		assert_eq!(detail(&probe, "Segments"), Some("4"));
+		assert_eq!(detail(&probe, "Bounds"), Some("20 × 10"));
 	}
 }
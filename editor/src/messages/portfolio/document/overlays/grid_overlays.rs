@@ -1,5 +1,7 @@
+use crate::consts::{COLOR_OVERLAY_GUIDE, COLOR_OVERLAY_PIXEL_GRID, VIEWPORT_PIXEL_GRID_MIN_ZOOM};
 use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::portfolio::document::overlays::utility_types::OverlayContext;
+use crate::messages::portfolio::document::utility_types::guides::GuideDirection;
 use crate::messages::portfolio::document::utility_types::misc::{GridSnapping, GridType};
 use crate::messages::prelude::*;
 use glam::DVec2;
@@ -193,6 +195,44 @@ pub fn grid_overlay(document: &DocumentMessageHandler, overlay_context: &mut Ove
 	}
 }
 
+/// Outlines each document pixel shown by the Pixels view mode once they're enlarged enough to tell apart.
+pub fn pixel_grid_overlay(document: &DocumentMessageHandler, overlay_context: &mut OverlayContext) {
+	if document.navigation_handler.snapped_zoom(document.document_ptz.zoom()) < VIEWPORT_PIXEL_GRID_MIN_ZOOM {
+		return;
+	}
+
+	let document_to_viewport = document.navigation_handler.calculate_offset_transform(overlay_context.size / 2., &document.document_ptz);
+	let [min, max] = (document_to_viewport.inverse() * Quad::from_box([DVec2::ZERO, overlay_context.size])).bounding_box();
+
+	for x in (min.x.floor() as i64)..=(max.x.ceil() as i64) {
+		let [start, end] = [DVec2::new(x as f64, min.y), DVec2::new(x as f64, max.y)].map(|point| document_to_viewport.transform_point2(point));
+		overlay_context.line(start, end, Some(COLOR_OVERLAY_PIXEL_GRID), None);
+	}
+	for y in (min.y.floor() as i64)..=(max.y.ceil() as i64) {
+		let [start, end] = [DVec2::new(min.x, y as f64), DVec2::new(max.x, y as f64)].map(|point| document_to_viewport.transform_point2(point));
+		overlay_context.line(start, end, Some(COLOR_OVERLAY_PIXEL_GRID), None);
+	}
+}
+
+/// Draws each of the document's guides as a line across the whole viewport.
+pub fn guide_overlay(document: &DocumentMessageHandler, overlay_context: &mut OverlayContext) {
+	let document_to_viewport = document.navigation_handler.calculate_offset_transform(overlay_context.size / 2., &document.document_ptz);
+	let [min, max] = (document_to_viewport.inverse() * Quad::from_box([DVec2::ZERO, overlay_context.size])).bounding_box();
+
+	for guide in &document.guides {
+		let [start, end] = match guide.direction {
+			GuideDirection::Horizontal => [DVec2::new(min.x, guide.position), DVec2::new(max.x, guide.position)],
+			GuideDirection::Vertical => [DVec2::new(guide.position, min.y), DVec2::new(guide.position, max.y)],
+		};
+		overlay_context.line(
+			document_to_viewport.transform_point2(start),
+			document_to_viewport.transform_point2(end),
+			Some(COLOR_OVERLAY_GUIDE),
+			None,
+		);
+	}
+}
+
 pub fn overlay_options(grid: &GridSnapping) -> Vec<LayoutGroup> {
 	let mut widgets = Vec::new();
 	fn update_val<I, F: Fn(&mut GridSnapping, &I)>(grid: &GridSnapping, update: F) -> impl Fn(&I) -> Message + use<I, F> {
@@ -1,4 +1,7 @@
 use crate::messages::prelude::*;
+use crate::node_graph_executor::InspectResult;
+use graph_craft::document::NodeId;
+use graphene_core::raster::curve::CurveChannel;
 
 #[impl_message(Message, DocumentMessage, PropertiesPanel)]
 #[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -6,4 +9,27 @@ pub enum PropertiesPanelMessage {
 	// Messages
 	Clear,
 	Refresh,
+	RequestVectorPreview {
+		source: NodeId,
+	},
+	SelectCurveChannel {
+		node_id: NodeId,
+		input_index: usize,
+		channel: CurveChannel,
+	},
+	SetParameterSearch {
+		query: String,
+	},
+	UpdateHistogram {
+		#[serde(skip)]
+		inspect_result: InspectResult,
+	},
+	UpdateVectorPreview {
+		#[serde(skip)]
+		inspect_result: InspectResult,
+	},
+	UpdateNoisePreview {
+		node_id: NodeId,
+		svg: String,
+	},
 }
@@ -1,11 +1,34 @@
 use super::utility_types::PropertiesPanelMessageHandlerData;
 use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::portfolio::document::node_graph::document_node_definitions::NodePropertiesContext;
+use crate::messages::portfolio::document::node_graph::node_properties;
+use crate::messages::portfolio::histogram::compute_histogram;
 use crate::messages::portfolio::utility_types::PersistentData;
 use crate::messages::prelude::*;
+use crate::node_graph_executor::introspected_output;
+use glam::DAffine2;
+use graph_craft::document::NodeId;
+use graphene_core::raster::curve::CurveChannel;
+use graphene_core::raster::histogram::Histogram;
+use graphene_core::renderer::{GraphicElementRendered, RenderParams, RenderSvgSegmentList, SvgRender};
+use graphene_core::vector::VectorDataTable;
+use graphene_core::vector::style::ViewMode;
+use std::any::Any;
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Default)]
-pub struct PropertiesPanelMessageHandler {}
+pub struct PropertiesPanelMessageHandler {
+	/// The histogram of the output of the node feeding the selected tonal adjustment node, shown above its properties.
+	inspected_histogram: Option<(NodeId, Option<Histogram>)>,
+	/// The node feeding the primary vector data input of the selected node, and the SVG thumbnail of its output once it has been rendered, which is empty if it isn't vector data.
+	vector_preview: Option<(NodeId, Option<String>)>,
+	/// The selected Noise Pattern node and the SVG of its output rendered at thumbnail resolution, which is empty if it couldn't be rendered.
+	noise_preview: Option<(NodeId, String)>,
+	/// The channel whose curve is shown for editing in each Curves input, by node and input index, which is left out of the input's value since it has no effect on the result.
+	curve_channels: HashMap<(NodeId, usize), CurveChannel>,
+	/// The text searched for among the names of the parameters of every node in the graph, whose matches are listed at the top of the panel.
+	parameter_search: String,
+}
 
 impl MessageHandler<PropertiesPanelMessage, (&PersistentData, PropertiesPanelMessageHandlerData<'_>)> for PropertiesPanelMessageHandler {
 	fn process_message(&mut self, message: PropertiesPanelMessage, responses: &mut VecDeque<Message>, (persistent_data, data): (&PersistentData, PropertiesPanelMessageHandlerData)) {
@@ -13,7 +36,13 @@ impl MessageHandler<PropertiesPanelMessage, (&PersistentData, PropertiesPanelMes
 			network_interface,
 			selection_network_path,
 			document_name,
+			length_unit,
+			preview_quality,
+			keyframes,
+			drivers,
+			position_picker_target,
 			executor,
+			preferences,
 		} = data;
 
 		match message {
@@ -30,14 +59,72 @@ impl MessageHandler<PropertiesPanelMessage, (&PersistentData, PropertiesPanelMes
 					network_interface,
 					selection_network_path,
 					document_name,
+					length_unit,
+					preview_quality,
+					keyframes,
+					drivers,
+					position_picker_target,
 					executor,
+					preferences,
+					inspected_histogram: self.inspected_histogram.as_ref(),
+					vector_preview: self.vector_preview.as_ref(),
+					noise_preview: self.noise_preview.as_ref(),
+					curve_channels: &self.curve_channels,
 				};
-				let properties_sections = NodeGraphMessageHandler::collate_properties(&mut context);
+				let mut properties_sections = NodeGraphMessageHandler::collate_properties(&mut context);
+				if let Some(search_results) = node_properties::generate_parameter_search(&self.parameter_search, &mut context) {
+					properties_sections.insert(0, search_results);
+				}
+				properties_sections.insert(0, parameter_search_row(&self.parameter_search));
 
 				context.responses.add(LayoutMessage::SendLayout {
 					layout: Layout::WidgetLayout(WidgetLayout::new(properties_sections)),
 					layout_target: LayoutTarget::PropertiesSections,
 				});
+				// The Bulk Edit and Find in Graph panels show the same node inputs, so they're kept in sync with them
+				context.responses.add(BulkEditPanelMessage::UpdateLayout);
+				context.responses.add(GraphFindPanelMessage::UpdateLayout);
+			}
+			PropertiesPanelMessage::RequestVectorPreview { source } => {
+				if self.vector_preview.as_ref().is_none_or(|(previewed, _)| *previewed != source) {
+					self.vector_preview = Some((source, None));
+					responses.add(NodeGraphMessage::RunDocumentGraph);
+				}
+			}
+			PropertiesPanelMessage::SelectCurveChannel { node_id, input_index, channel } => {
+				self.curve_channels.insert((node_id, input_index), channel);
+				responses.add(PropertiesPanelMessage::Refresh);
+			}
+			PropertiesPanelMessage::SetParameterSearch { query } => {
+				self.parameter_search = query;
+				responses.add(PropertiesPanelMessage::Refresh);
+			}
+			PropertiesPanelMessage::UpdateHistogram { mut inspect_result } => {
+				let histogram = inspect_result.take_data().as_ref().and_then(compute_histogram);
+				let inspected_histogram = Some((inspect_result.inspect_node, histogram));
+				if self.inspected_histogram != inspected_histogram {
+					self.inspected_histogram = inspected_histogram;
+					responses.add(PropertiesPanelMessage::Refresh);
+				}
+			}
+			PropertiesPanelMessage::UpdateVectorPreview { mut inspect_result } => {
+				let Some((source, thumbnail)) = &mut self.vector_preview else { return };
+				if *source != inspect_result.inspect_node {
+					return;
+				}
+
+				let rendered = inspect_result.take_data().as_ref().and_then(render_vector_preview).unwrap_or_default();
+				if thumbnail.as_ref() != Some(&rendered) {
+					*thumbnail = Some(rendered);
+					responses.add(PropertiesPanelMessage::Refresh);
+				}
+			}
+			PropertiesPanelMessage::UpdateNoisePreview { node_id, svg } => {
+				let noise_preview = Some((node_id, svg));
+				if self.noise_preview != noise_preview {
+					self.noise_preview = noise_preview;
+					responses.add(PropertiesPanelMessage::Refresh);
+				}
 			}
 		}
 	}
@@ -46,3 +133,39 @@ impl MessageHandler<PropertiesPanelMessage, (&PersistentData, PropertiesPanelMes
 		actions!(PropertiesMessageDiscriminant;)
 	}
 }
+
+impl PropertiesPanelMessageHandler {
+	/// The node whose output is previewed in the properties of the selected node, if it has been requested.
+	pub fn vector_preview_source(&self) -> Option<NodeId> {
+		self.vector_preview.as_ref().map(|(source, _)| *source)
+	}
+}
+
+/// Renders introspected vector data into an SVG fitted to its bounds, to be shown as a thumbnail.
+fn render_vector_preview(introspected_data: &Arc<dyn Any + Send + Sync + 'static>) -> Option<String> {
+	let vector_data = introspected_output::<VectorDataTable>(introspected_data.as_ref())?;
+
+	let bounds = vector_data.bounding_box(DAffine2::IDENTITY);
+	let render_params = RenderParams::new(ViewMode::Normal, bounds, true, false, false);
+	let mut render = SvgRender::new();
+	vector_data.render_svg(&mut render, &render_params);
+
+	let [min, max] = bounds.unwrap_or_default();
+	render.format_svg(min, max);
+	Some(render.svg.to_svg_string())
+}
+
+/// The search box at the top of the panel for finding parameters by name.
+fn parameter_search_row(query: &str) -> LayoutGroup {
+	let tooltip = "Find the parameters of every node in the graph with this text in their names, such as \"radius\", to edit them here";
+	LayoutGroup::Row {
+		widgets: vec![
+			TextLabel::new("Find Parameter").tooltip(tooltip).widget_holder(),
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			TextInput::new(query)
+				.tooltip(tooltip)
+				.on_update(|text_input: &TextInput| PropertiesPanelMessage::SetParameterSearch { query: text_input.value.clone() }.into())
+				.widget_holder(),
+		],
+	}
+}
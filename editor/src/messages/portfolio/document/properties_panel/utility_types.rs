@@ -1,4 +1,8 @@
+use crate::messages::portfolio::document::utility_types::drivers::Drivers;
+use crate::messages::portfolio::document::utility_types::keyframes::Keyframes;
+use crate::messages::portfolio::document::utility_types::misc::{LengthUnit, PreviewQuality};
 use crate::messages::portfolio::document::utility_types::network_interface::NodeNetworkInterface;
+use crate::messages::preferences::PreferencesMessageHandler;
 use crate::node_graph_executor::NodeGraphExecutor;
 use graph_craft::document::NodeId;
 
@@ -6,5 +10,11 @@ pub struct PropertiesPanelMessageHandlerData<'a> {
 	pub network_interface: &'a mut NodeNetworkInterface,
 	pub selection_network_path: &'a [NodeId],
 	pub document_name: &'a str,
+	pub length_unit: LengthUnit,
+	pub preview_quality: PreviewQuality,
+	pub keyframes: &'a Keyframes,
+	pub drivers: &'a Drivers,
+	pub position_picker_target: Option<(NodeId, usize)>,
 	pub executor: &'a mut NodeGraphExecutor,
+	pub preferences: &'a PreferencesMessageHandler,
 }
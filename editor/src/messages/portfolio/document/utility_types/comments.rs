@@ -0,0 +1,131 @@
+use super::document_metadata::{DocumentMetadata, LayerNodeIdentifier};
+use glam::DVec2;
+
+/// Where a comment pin is dropped on the canvas.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum CommentAnchor {
+	/// A fixed position in document space.
+	Document(DVec2),
+	/// A position in the local space of a layer, so the pin follows the layer as it's moved and transformed.
+	Layer { layer: LayerNodeIdentifier, offset: DVec2 },
+}
+
+/// A numbered pin on the canvas with a thread of review comments about the artwork at its location.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CommentPin {
+	/// Shown on the pin and in the Comments panel, and never reused within a document once a pin is deleted.
+	pub number: u32,
+	pub anchor: CommentAnchor,
+	/// The text of the comments in the thread, oldest first.
+	pub thread: Vec<String>,
+	pub resolved: bool,
+}
+
+impl CommentPin {
+	/// The position of the pin in document space, or `None` if the layer it was anchored to has been deleted.
+	pub fn document_position(&self, metadata: &DocumentMetadata) -> Option<DVec2> {
+		match self.anchor {
+			CommentAnchor::Document(position) => Some(position),
+			CommentAnchor::Layer { layer, offset } => metadata.layer_exists(layer).then(|| metadata.transform_to_document(layer).transform_point2(offset)),
+		}
+	}
+}
+
+/// The comment pins left on a document while reviewing it. They are saved with the document but aren't part of its artwork, so they never appear in exports.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Comments {
+	pins: Vec<CommentPin>,
+	/// The number given to the most recently added pin.
+	last_number: u32,
+}
+
+impl Comments {
+	pub fn pins(&self) -> impl Iterator<Item = &CommentPin> {
+		self.pins.iter()
+	}
+
+	pub fn pin(&self, number: u32) -> Option<&CommentPin> {
+		self.pins.iter().find(|pin| pin.number == number)
+	}
+
+	fn pin_mut(&mut self, number: u32) -> Option<&mut CommentPin> {
+		self.pins.iter_mut().find(|pin| pin.number == number)
+	}
+
+	/// Adds a pin with an empty thread and returns its number.
+	pub fn add(&mut self, anchor: CommentAnchor) -> u32 {
+		self.last_number += 1;
+		self.pins.push(CommentPin {
+			number: self.last_number,
+			anchor,
+			thread: Vec::new(),
+			resolved: false,
+		});
+		self.last_number
+	}
+
+	/// Appends a comment to the end of the pin's thread, ignoring comments without any text.
+	pub fn reply(&mut self, number: u32, text: String) {
+		let text = text.trim();
+		if text.is_empty() {
+			return;
+		}
+		if let Some(pin) = self.pin_mut(number) {
+			pin.thread.push(text.to_string());
+		}
+	}
+
+	pub fn set_resolved(&mut self, number: u32, resolved: bool) {
+		if let Some(pin) = self.pin_mut(number) {
+			pin.resolved = resolved;
+		}
+	}
+
+	pub fn delete(&mut self, number: u32) {
+		self.pins.retain(|pin| pin.number != number);
+	}
+
+	/// The number of the topmost pin within the given radius of a viewport position, which is where pins are drawn around their document position.
+	pub fn pin_at(&self, metadata: &DocumentMetadata, viewport_position: DVec2, radius: f64) -> Option<u32> {
+		self.pins
+			.iter()
+			.rev()
+			.find(|pin| {
+				pin.document_position(metadata)
+					.is_some_and(|position| metadata.document_to_viewport.transform_point2(position).distance(viewport_position) <= radius)
+			})
+			.map(|pin| pin.number)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn numbers_are_not_reused() {
+		let mut comments = Comments::default();
+		let first = comments.add(CommentAnchor::Document(DVec2::ZERO));
+		let second = comments.add(CommentAnchor::Document(DVec2::ONE));
+		assert_eq!((first, second), (1, 2));
+
+		comments.delete(second);
+		assert_eq!(comments.add(CommentAnchor::Document(DVec2::ONE)), 3);
+		assert_eq!(comments.pins().map(|pin| pin.number).collect::<Vec<_>>(), [1, 3]);
+	}
+
+	#[test]
+	fn replies_are_threaded() {
+		let mut comments = Comments::default();
+		let number = comments.add(CommentAnchor::Document(DVec2::ZERO));
+		comments.reply(number, "Make this bolder".into());
+		comments.reply(number, "   ".into());
+		comments.reply(number, " Done ".into());
+		comments.set_resolved(number, true);
+
+		let pin = comments.pin(number).unwrap();
+		assert_eq!(pin.thread, ["Make this bolder", "Done"]);
+		assert!(pin.resolved);
+	}
+}
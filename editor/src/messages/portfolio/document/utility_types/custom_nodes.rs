@@ -0,0 +1,71 @@
+use crate::messages::portfolio::document::node_graph::document_node_definitions::resolve_document_node_type;
+use crate::messages::portfolio::document::node_graph::utility_types::FrontendNodeType;
+use crate::messages::portfolio::document::utility_types::network_interface::NodeTemplate;
+
+/// The extension of the files which a single node, possibly made of a network of other nodes, is exported to for sharing it between documents and users.
+pub const NODE_FILE_EXTENSION: &str = "graphite-node";
+
+/// The category of the node catalog which lists the custom nodes imported into the document.
+pub const CUSTOM_NODE_CATEGORY: &str = "Custom";
+
+/// A node imported into the document from a `.graphite-node` file, which is offered in the node catalog alongside the built-in nodes.
+/// This is also the contents of the file, which the node is exported as.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CustomNode {
+	pub name: String,
+	pub node_template: NodeTemplate,
+}
+
+impl CustomNode {
+	pub fn frontend_node_type(&self) -> FrontendNodeType {
+		FrontendNodeType {
+			name: self.name.clone(),
+			category: CUSTOM_NODE_CATEGORY.to_string(),
+			input_types: None,
+			output_types: None,
+		}
+	}
+}
+
+/// Adds the node to the custom nodes of a document, replacing any with the same name so a file can be imported again to update its node.
+/// A node named like a built-in node is renamed, since the catalog would otherwise insert the built-in node in its place. Returns the name it's listed under.
+pub fn register_custom_node(custom_nodes: &mut Vec<CustomNode>, mut custom_node: CustomNode) -> String {
+	if resolve_document_node_type(&custom_node.name).is_some() {
+		custom_node.name = format!("{} ({CUSTOM_NODE_CATEGORY})", custom_node.name);
+	}
+	let name = custom_node.name.clone();
+
+	match custom_nodes.iter_mut().find(|existing| existing.name == name) {
+		Some(existing) => *existing = custom_node,
+		None => custom_nodes.push(custom_node),
+	}
+	name
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn custom_node(name: &str) -> CustomNode {
+		CustomNode {
+			name: name.to_string(),
+			node_template: resolve_document_node_type("Merge").unwrap().default_node_template(),
+		}
+	}
+
+	#[test]
+	fn importing_again_replaces_the_node() {
+		let mut custom_nodes = Vec::new();
+		assert_eq!(register_custom_node(&mut custom_nodes, custom_node("Sparkle")), "Sparkle");
+		assert_eq!(register_custom_node(&mut custom_nodes, custom_node("Sparkle")), "Sparkle");
+		assert_eq!(register_custom_node(&mut custom_nodes, custom_node("Glow")), "Glow");
+		assert_eq!(custom_nodes.iter().map(|custom_node| custom_node.name.as_str()).collect::<Vec<_>>(), ["Sparkle", "Glow"]);
+	}
+
+	#[test]
+	fn built_in_names_are_not_shadowed() {
+		let mut custom_nodes = Vec::new();
+		assert_eq!(register_custom_node(&mut custom_nodes, custom_node("Merge")), "Merge (Custom)");
+		assert_eq!(custom_nodes[0].frontend_node_type().category, CUSTOM_NODE_CATEGORY);
+	}
+}
@@ -0,0 +1,381 @@
+use super::keyframes::AnimatedValues;
+use super::network_interface::{FlowType, InputConnector, NodeNetworkInterface};
+use crate::node_graph_executor::introspected_output;
+use glam::DVec2;
+use graph_craft::document::value::TaggedValue;
+use graph_craft::document::{NodeId, NodeInput};
+use math_parser::ast::Node;
+use math_parser::context::{EvalContext, NothingMap, ValueProvider};
+use math_parser::value::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
+
+/// The nodes whose output is the number set in their input, which other nodes' drivers can refer to by the node's name.
+const NUMBER_VALUE_NODE_TYPES: &[&str] = &["Number Value", "Percentage Value"];
+
+/// A node input whose value is computed from an expression each time before the graph is evaluated.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Driver {
+	pub network_path: Vec<NodeId>,
+	pub node_id: NodeId,
+	pub input_index: usize,
+	/// A math expression like `width / 2`, which may use the variables listed by [`DriverVariables`].
+	pub expression: String,
+	#[serde(skip)]
+	parsed: ParsedExpression,
+}
+
+impl Driver {
+	fn is(&self, network_path: &[NodeId], node_id: NodeId, input_index: usize) -> bool {
+		self.network_path == network_path && self.node_id == node_id && self.input_index == input_index
+	}
+
+	/// The expression parsed the first time it's needed, and kept for every later evaluation of the graph, or `None` if it isn't valid.
+	pub fn parsed_expression(&self) -> Option<&Node> {
+		self.parsed.0.get_or_init(|| Node::try_parse_from_str(&self.expression).ok().map(|(node, _)| Arc::new(node))).as_deref()
+	}
+}
+
+/// The parsed form of a driver's expression, which is left out when comparing drivers since it only mirrors the expression.
+#[derive(Clone, Debug, Default)]
+struct ParsedExpression(OnceLock<Option<Arc<Node>>>);
+
+impl PartialEq for ParsedExpression {
+	fn eq(&self, _: &Self) -> bool {
+		true
+	}
+}
+
+/// The named numbers that a driver's expression can use:
+/// - The numeric inputs of the driven node, like `width` or `corner_radius`, with `_x` and `_y` appended for the components of vectors.
+/// - The output of another node, by the node's name, like `number_value` or `spacing` once renamed:
+///   - For Number Value and Percentage Value nodes, the number set in the node's own input.
+///   - For any other node in the document network, its output when the graph was last evaluated, as kept in [`Drivers::node_outputs`].
+///     Nodes in nested networks, and nodes fed by a driven input, can't be read this way. See [`Drivers::read_nodes`].
+///
+///   Names shared by several nodes can't be read, since it's unclear which node they refer to.
+/// - The document's `time` in seconds along its animation.
+///
+/// Inputs that are animated or driven are read at their values in `animated_values` rather than the values set in the document.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DriverVariables(HashMap<String, f64>);
+
+impl DriverVariables {
+	pub fn new(network_interface: &NodeNetworkInterface, animated_values: &AnimatedValues, node_outputs: &HashMap<NodeId, TaggedValue>, network_path: &[NodeId], node_id: NodeId, time: f64) -> Self {
+		let input_value = |node_id: NodeId, input_index: usize, input: &NodeInput| {
+			let value = input.as_non_exposed_value()?;
+			Some(animated_values.get(&(network_path.to_vec(), node_id, input_index)).unwrap_or(value).clone())
+		};
+
+		let mut variables = HashMap::new();
+		variables.insert("time".to_string(), time);
+
+		// Values from other nodes are added first so that the driven node's own inputs take precedence over them if the names collide
+		for (name, other_id) in nodes_by_variable_name(network_interface, network_path) {
+			let Some(other_id) = other_id.filter(|&other_id| other_id != node_id) else { continue };
+			let value = match number_value_input(network_interface, network_path, other_id) {
+				Some(input) => input_value(other_id, 1, input),
+				None if network_path.is_empty() => node_outputs.get(&other_id).cloned(),
+				None => None,
+			};
+			if let Some(value) = value {
+				insert_variable(&mut variables, name, &value);
+			}
+		}
+
+		if let Some(node) = network_interface.document_node(&node_id, network_path) {
+			for (input_index, input) in node.inputs.iter().enumerate() {
+				let Some(value) = input_value(node_id, input_index, input) else { continue };
+				let Some(name) = network_interface.input_name(&node_id, input_index, network_path).map(variable_name) else {
+					continue;
+				};
+				insert_variable(&mut variables, name, &value);
+			}
+		}
+
+		Self(variables)
+	}
+
+	/// The names of the variables, sorted alphabetically to be listed for the user.
+	pub fn names(&self) -> Vec<&str> {
+		let mut names = self.0.keys().map(String::as_str).collect::<Vec<_>>();
+		names.sort_unstable();
+		names
+	}
+
+	/// Evaluates the driver's expression with these variables, or describes why it couldn't be evaluated.
+	pub fn evaluate(&self, driver: &Driver) -> Result<f64, String> {
+		let node = driver.parsed_expression().ok_or_else(|| "Invalid expression".to_string())?;
+		let value = node.eval(&EvalContext::new(self, NothingMap)).map_err(|error| error.to_string())?;
+		value.as_real().ok_or_else(|| "The result is not a real number".to_string())
+	}
+}
+
+/// Adds the value as a variable, or as one variable per component with `_x` and `_y` appended for vectors.
+fn insert_variable(variables: &mut HashMap<String, f64>, name: String, value: &TaggedValue) {
+	match value {
+		TaggedValue::DVec2(vector) => {
+			variables.insert(format!("{name}_x"), vector.x);
+			variables.insert(format!("{name}_y"), vector.y);
+		}
+		value => {
+			if let Some(number) = number_from_value(value) {
+				variables.insert(name, number);
+			}
+		}
+	}
+}
+
+/// The nodes of the network by the names they're referred to by in expressions, with `None` for names shared by several nodes.
+fn nodes_by_variable_name(network_interface: &NodeNetworkInterface, network_path: &[NodeId]) -> HashMap<String, Option<NodeId>> {
+	let mut nodes = HashMap::new();
+	let Some(network) = network_interface.nested_network(network_path) else { return nodes };
+	for &node_id in network.nodes.keys() {
+		nodes
+			.entry(variable_name(&network_interface.display_name(&node_id, network_path)))
+			.and_modify(|other| *other = None)
+			.or_insert(Some(node_id));
+	}
+	nodes
+}
+
+/// The input holding the number output by a Number Value or Percentage Value node, which is read directly unless a wire is connected to it.
+fn number_value_input<'a>(network_interface: &'a NodeNetworkInterface, network_path: &[NodeId], node_id: NodeId) -> Option<&'a NodeInput> {
+	let reference = network_interface.reference(&node_id, network_path)?.as_deref()?;
+	if !NUMBER_VALUE_NODE_TYPES.contains(&reference) {
+		return None;
+	}
+	network_interface
+		.document_node(&node_id, network_path)?
+		.inputs
+		.get(1)
+		.filter(|input| input.as_non_exposed_value().is_some())
+}
+
+/// The names of the variables used by an expression.
+fn variables_in(expression: &Node, names: &mut Vec<String>) {
+	match expression {
+		Node::Lit(_) => {}
+		Node::Var(name) => names.push(name.clone()),
+		Node::FnCall { expr, .. } => expr.iter().for_each(|argument| variables_in(argument, names)),
+		Node::BinOp { lhs, rhs, .. } => {
+			variables_in(lhs, names);
+			variables_in(rhs, names);
+		}
+		Node::UnaryOp { expr, .. } => variables_in(expr, names),
+	}
+}
+
+impl ValueProvider for &DriverVariables {
+	fn get_value(&self, name: &str) -> Option<Value> {
+		self.0.get(name).copied().map(Value::from_f64)
+	}
+}
+
+/// Turns the name of an input or node, like "Corner Radius", into the name it's referred to by in expressions, like `corner_radius`.
+pub fn variable_name(name: &str) -> String {
+	let mut variable = String::with_capacity(name.len());
+	for word in name.split(|character: char| !character.is_ascii_alphanumeric()).filter(|word| !word.is_empty()) {
+		if !variable.is_empty() {
+			variable.push('_');
+		}
+		variable.push_str(&word.to_ascii_lowercase());
+	}
+	// Names can't start with a digit, since they would be read as a number
+	if variable.starts_with(|character: char| character.is_ascii_digit()) {
+		variable.insert(0, '_');
+	}
+	variable
+}
+
+/// The number held by an input's value, if it's of a type that drivers can read.
+fn number_from_value(value: &TaggedValue) -> Option<f64> {
+	match value {
+		TaggedValue::F64(number) => Some(*number),
+		TaggedValue::U32(number) => Some(*number as f64),
+		TaggedValue::U64(number) => Some(*number as f64),
+		TaggedValue::Bool(boolean) => Some(if *boolean { 1. } else { 0. }),
+		_ => None,
+	}
+}
+
+/// The output of an inspected node read by a driver, if it's of a type that drivers can read.
+pub fn node_output_value(introspected_data: &dyn std::any::Any) -> Option<TaggedValue> {
+	if let Some(&number) = introspected_output::<f64>(introspected_data) {
+		Some(TaggedValue::F64(number))
+	} else if let Some(&number) = introspected_output::<u32>(introspected_data) {
+		Some(TaggedValue::U32(number))
+	} else if let Some(&number) = introspected_output::<u64>(introspected_data) {
+		Some(TaggedValue::U64(number))
+	} else if let Some(&boolean) = introspected_output::<bool>(introspected_data) {
+		Some(TaggedValue::Bool(boolean))
+	} else {
+		introspected_output::<DVec2>(introspected_data).map(|&vector| TaggedValue::DVec2(vector))
+	}
+}
+
+/// The result of an expression converted to the type of the input it drives, or `None` if the input's type can't be driven.
+pub fn driven_value(current: &TaggedValue, number: f64) -> Option<TaggedValue> {
+	let number = if number.is_finite() { number } else { 0. };
+	match current {
+		TaggedValue::F64(_) => Some(TaggedValue::F64(number)),
+		TaggedValue::U32(_) => Some(TaggedValue::U32(number.round().clamp(0., u32::MAX as f64) as u32)),
+		TaggedValue::U64(_) => Some(TaggedValue::U64(number.round().max(0.) as u64)),
+		TaggedValue::Bool(_) => Some(TaggedValue::Bool(number != 0.)),
+		_ => None,
+	}
+}
+
+/// All the driven node inputs in a document.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Drivers {
+	drivers: Vec<Driver>,
+	/// The outputs of the nodes read by the drivers in the document network, as of the last evaluation of the graph.
+	#[serde(skip)]
+	node_outputs: HashMap<NodeId, TaggedValue>,
+}
+
+impl Drivers {
+	pub fn driver(&self, network_path: &[NodeId], node_id: NodeId, input_index: usize) -> Option<&Driver> {
+		self.drivers.iter().find(|driver| driver.is(network_path, node_id, input_index))
+	}
+
+	/// Drives the input with the expression, replacing its previous expression, or stops driving it if there's no expression.
+	pub fn set_expression(&mut self, network_path: &[NodeId], node_id: NodeId, input_index: usize, expression: Option<String>) {
+		let position = self.drivers.iter().position(|driver| driver.is(network_path, node_id, input_index));
+		match (position, expression) {
+			(Some(position), Some(expression)) => {
+				self.drivers[position].expression = expression;
+				self.drivers[position].parsed = ParsedExpression::default();
+			}
+			(Some(position), None) => {
+				self.drivers.remove(position);
+			}
+			(None, Some(expression)) => self.drivers.push(Driver {
+				network_path: network_path.to_vec(),
+				node_id,
+				input_index,
+				expression,
+				parsed: ParsedExpression::default(),
+			}),
+			(None, None) => {}
+		}
+	}
+
+	/// The nodes in the document network whose outputs are read by the drivers there, which are inspected each time the graph is evaluated.
+	/// A node fed by a driven input isn't read, since its output would change with every evaluation that reading it causes.
+	pub fn read_nodes(&self, network_interface: &NodeNetworkInterface) -> Vec<NodeId> {
+		let document_drivers = || self.drivers.iter().filter(|driver| driver.network_path.is_empty());
+		let driven_nodes = document_drivers().map(|driver| driver.node_id).collect::<HashSet<_>>();
+		let mut names = Vec::new();
+		for expression in document_drivers().filter_map(Driver::parsed_expression) {
+			variables_in(expression, &mut names);
+		}
+
+		let nodes_by_name = nodes_by_variable_name(network_interface, &[]);
+		let mut read_nodes = Vec::new();
+		// Vectors are read by the names of their components
+		let referenced_node = |name: &String| {
+			[Some(name.as_str()), name.strip_suffix("_x"), name.strip_suffix("_y")]
+				.into_iter()
+				.flatten()
+				.find_map(|name| nodes_by_name.get(name).copied().flatten())
+		};
+		for node_id in names.iter().filter_map(referenced_node) {
+			if read_nodes.contains(&node_id) || number_value_input(network_interface, &[], node_id).is_some() {
+				continue;
+			}
+			// The upstream nodes include the node itself, so a driven node isn't read either
+			if network_interface
+				.upstream_flow_back_from_nodes(vec![node_id], &[], FlowType::UpstreamFlow)
+				.any(|upstream| driven_nodes.contains(&upstream))
+			{
+				continue;
+			}
+			read_nodes.push(node_id);
+		}
+		read_nodes
+	}
+
+	/// The outputs of the nodes read by the drivers in the document network, as of the last evaluation of the graph.
+	pub fn node_outputs(&self) -> &HashMap<NodeId, TaggedValue> {
+		&self.node_outputs
+	}
+
+	/// Keeps the output of a node read by the drivers, returning whether it changed since the last evaluation of the graph.
+	pub fn set_node_output(&mut self, node_id: NodeId, value: TaggedValue) -> bool {
+		self.node_outputs.insert(node_id, value.clone()) != Some(value)
+	}
+
+	/// Forgets the outputs of the nodes which are no longer read by the drivers.
+	pub fn retain_node_outputs(&mut self, read_nodes: &[NodeId]) {
+		self.node_outputs.retain(|node_id, _| read_nodes.contains(node_id));
+	}
+
+	/// Adds the result of every driver's expression to the values which replace those of the inputs in the network that's evaluated, so the document keeps the values the user set.
+	/// Drivers are resolved in the order they were added, so a driver reading another animated or driven input sees the value it was just given.
+	pub fn resolve(&self, network_interface: &NodeNetworkInterface, time: f64, animated_values: &mut AnimatedValues) {
+		for driver in &self.drivers {
+			let input_connector = InputConnector::node(driver.node_id, driver.input_index);
+			// Skip inputs of nodes that no longer exist or have since been connected to a wire
+			let Some(current) = network_interface.input_from_connector(&input_connector, &driver.network_path).and_then(NodeInput::as_non_exposed_value) else {
+				continue;
+			};
+
+			// An expression that can't be evaluated leaves the input at its animated value, or the value set in the document
+			let variables = DriverVariables::new(network_interface, animated_values, &self.node_outputs, &driver.network_path, driver.node_id, time);
+			if let Some(value) = variables.evaluate(driver).ok().and_then(|number| driven_value(current, number)) {
+				animated_values.insert((driver.network_path.clone(), driver.node_id, driver.input_index), value);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn evaluate(variables: &DriverVariables, expression: &str) -> Result<f64, String> {
+		let mut drivers = Drivers::default();
+		drivers.set_expression(&[], NodeId(0), 0, Some(expression.to_string()));
+		variables.evaluate(drivers.driver(&[], NodeId(0), 0).unwrap())
+	}
+
+	#[test]
+	fn expressions_use_variables() {
+		let variables = DriverVariables([("width".to_string(), 300.), ("time".to_string(), 2.), ("exponent".to_string(), 3.)].into_iter().collect());
+		assert_eq!(evaluate(&variables, "width/2"), Ok(150.));
+		assert_eq!(evaluate(&variables, "width / 4 + time * 10"), Ok(95.));
+		// Names starting with a constant like "e" aren't split into the constant and the rest of the name
+		assert_eq!(evaluate(&variables, "exponent * 2"), Ok(6.));
+		assert!(evaluate(&variables, "height/2").is_err());
+		assert!(evaluate(&variables, "width/").is_err());
+	}
+
+	#[test]
+	fn parsed_expressions_are_kept_until_the_expression_changes() {
+		let mut drivers = Drivers::default();
+		drivers.set_expression(&[], NodeId(0), 0, Some("offset * 2 + sqrt(position_x)".to_string()));
+		let driver = drivers.driver(&[], NodeId(0), 0).unwrap();
+		let parsed = driver.parsed_expression().unwrap() as *const Node;
+		assert_eq!(driver.parsed_expression().unwrap() as *const Node, parsed, "The expression should only be parsed once");
+
+		let mut names = Vec::new();
+		variables_in(driver.parsed_expression().unwrap(), &mut names);
+		assert_eq!(names, ["offset", "position_x"]);
+
+		drivers.set_expression(&[], NodeId(0), 0, Some("offset +".to_string()));
+		assert!(drivers.driver(&[], NodeId(0), 0).unwrap().parsed_expression().is_none());
+	}
+
+	#[test]
+	fn results_fit_the_driven_input() {
+		assert_eq!(variable_name("Corner Radius"), "corner_radius");
+		assert_eq!(variable_name("3D Depth (Z)"), "_3d_depth_z");
+		assert_eq!(driven_value(&TaggedValue::U32(0), 2.6), Some(TaggedValue::U32(3)));
+		assert_eq!(driven_value(&TaggedValue::U32(0), -5.), Some(TaggedValue::U32(0)));
+		assert_eq!(driven_value(&TaggedValue::Bool(false), 1.), Some(TaggedValue::Bool(true)));
+		assert_eq!(driven_value(&TaggedValue::String(String::new()), 1.), None);
+	}
+}
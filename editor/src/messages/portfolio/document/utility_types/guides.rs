@@ -0,0 +1,29 @@
+/// Whether a guide is a horizontal line at a Y position or a vertical line at an X position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum GuideDirection {
+	Horizontal,
+	Vertical,
+}
+
+/// A line across the whole canvas at a position in document space, drawn over the artwork to help lay it out. Guides are saved with the document but never appear in exports.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct Guide {
+	pub direction: GuideDirection,
+	pub position: f64,
+}
+
+impl Guide {
+	pub fn horizontal(y: f64) -> Self {
+		Self {
+			direction: GuideDirection::Horizontal,
+			position: y,
+		}
+	}
+
+	pub fn vertical(x: f64) -> Self {
+		Self {
+			direction: GuideDirection::Vertical,
+			position: x,
+		}
+	}
+}
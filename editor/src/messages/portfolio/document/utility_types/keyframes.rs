@@ -0,0 +1,217 @@
+use super::network_interface::{InputConnector, NodeNetworkInterface};
+use graph_craft::document::value::TaggedValue;
+use graph_craft::document::{NodeId, NodeInput};
+use std::collections::HashMap;
+
+/// The values which replace those of node inputs in the network that's evaluated, keyed by the network path, node, and input index.
+pub type AnimatedValues = HashMap<(Vec<NodeId>, NodeId, usize), TaggedValue>;
+
+/// Keyframes closer together in time than this (in seconds) are treated as being at the same time.
+const KEYFRAME_TIME_TOLERANCE: f64 = 1e-3;
+
+/// The value that an input is set to at a point in time.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Keyframe {
+	/// The time in seconds from the start of the animation.
+	pub time: f64,
+	pub value: TaggedValue,
+}
+
+/// A node input whose value changes over time, interpolated between its keyframes.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AnimatedInput {
+	pub network_path: Vec<NodeId>,
+	pub node_id: NodeId,
+	pub input_index: usize,
+	/// Sorted by time, and never empty.
+	pub keyframes: Vec<Keyframe>,
+}
+
+impl AnimatedInput {
+	fn is(&self, network_path: &[NodeId], node_id: NodeId, input_index: usize) -> bool {
+		self.network_path == network_path && self.node_id == node_id && self.input_index == input_index
+	}
+
+	/// The index of the keyframe at the given time, if there is one.
+	pub fn keyframe_at(&self, time: f64) -> Option<usize> {
+		self.keyframes.iter().position(|keyframe| (keyframe.time - time).abs() < KEYFRAME_TIME_TOLERANCE)
+	}
+
+	/// The value at the given time, interpolated between the keyframes on either side of it and held constant before the first and after the last.
+	pub fn value_at(&self, time: f64) -> TaggedValue {
+		let next_index = self.keyframes.partition_point(|keyframe| keyframe.time <= time);
+		let (Some(previous), Some(next)) = (next_index.checked_sub(1).map(|index| &self.keyframes[index]), self.keyframes.get(next_index)) else {
+			let closest = if next_index == 0 { self.keyframes.first() } else { self.keyframes.last() };
+			return closest.expect("An animated input should have at least one keyframe").value.clone();
+		};
+
+		let factor = (time - previous.time) / (next.time - previous.time);
+		interpolate(&previous.value, &next.value, factor)
+	}
+}
+
+/// Interpolates numbers, vectors, and colors, while other types of values hold until the next keyframe is reached.
+pub fn interpolate(from: &TaggedValue, to: &TaggedValue, factor: f64) -> TaggedValue {
+	let factor = factor.clamp(0., 1.);
+	match (from, to) {
+		(TaggedValue::F64(from), TaggedValue::F64(to)) => TaggedValue::F64(from + (to - from) * factor),
+		(TaggedValue::DVec2(from), TaggedValue::DVec2(to)) => TaggedValue::DVec2(from.lerp(*to, factor)),
+		(TaggedValue::Color(from), TaggedValue::Color(to)) => TaggedValue::Color(from.lerp(to, factor as f32)),
+		(TaggedValue::OptionalColor(Some(from)), TaggedValue::OptionalColor(Some(to))) => TaggedValue::OptionalColor(Some(from.lerp(to, factor as f32))),
+		_ if factor < 1. => from.clone(),
+		_ => to.clone(),
+	}
+}
+
+/// All the animated node inputs in a document, along with the current time along the animation.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Keyframes {
+	animated_inputs: Vec<AnimatedInput>,
+	/// The time in seconds which the animated inputs are currently evaluated at.
+	#[serde(skip)]
+	time: f64,
+}
+
+impl Keyframes {
+	pub fn time(&self) -> f64 {
+		self.time
+	}
+
+	pub fn set_time(&mut self, time: f64) {
+		self.time = time;
+	}
+
+	pub fn animated_inputs(&self) -> impl Iterator<Item = &AnimatedInput> {
+		self.animated_inputs.iter()
+	}
+
+	pub fn animated_input(&self, network_path: &[NodeId], node_id: NodeId, input_index: usize) -> Option<&AnimatedInput> {
+		self.animated_inputs.iter().find(|animated_input| animated_input.is(network_path, node_id, input_index))
+	}
+
+	/// Adds a keyframe at the given time, or replaces the value of the keyframe already there.
+	pub fn set_keyframe(&mut self, network_path: &[NodeId], node_id: NodeId, input_index: usize, time: f64, value: TaggedValue) {
+		let keyframe = Keyframe { time, value };
+
+		let Some(animated_input) = self.animated_inputs.iter_mut().find(|animated_input| animated_input.is(network_path, node_id, input_index)) else {
+			self.animated_inputs.push(AnimatedInput {
+				network_path: network_path.to_vec(),
+				node_id,
+				input_index,
+				keyframes: vec![keyframe],
+			});
+			return;
+		};
+
+		match animated_input.keyframe_at(time) {
+			Some(index) => animated_input.keyframes[index].value = keyframe.value,
+			None => {
+				let index = animated_input.keyframes.partition_point(|other| other.time < time);
+				animated_input.keyframes.insert(index, keyframe);
+			}
+		}
+	}
+
+	/// Removes the keyframe at the given time, and stops animating the input once its last keyframe is gone.
+	pub fn remove_keyframe(&mut self, network_path: &[NodeId], node_id: NodeId, input_index: usize, time: f64) {
+		let Some(input_position) = self.animated_inputs.iter().position(|animated_input| animated_input.is(network_path, node_id, input_index)) else {
+			return;
+		};
+
+		let animated_input = &mut self.animated_inputs[input_position];
+		if let Some(index) = animated_input.keyframe_at(time) {
+			animated_input.keyframes.remove(index);
+		}
+		if animated_input.keyframes.is_empty() {
+			self.animated_inputs.remove(input_position);
+		}
+	}
+
+	/// Adds a keyframe with the input's current value at the current time, or removes the keyframe that's already there.
+	pub fn toggle_keyframe(&mut self, network_interface: &NodeNetworkInterface, network_path: &[NodeId], node_id: NodeId, input_index: usize) {
+		let time = self.time;
+		if self
+			.animated_input(network_path, node_id, input_index)
+			.and_then(|animated_input| animated_input.keyframe_at(time))
+			.is_some()
+		{
+			self.remove_keyframe(network_path, node_id, input_index, time);
+			return;
+		}
+
+		let input_connector = InputConnector::node(node_id, input_index);
+		let Some(value) = network_interface.input_from_connector(&input_connector, network_path).and_then(NodeInput::as_non_exposed_value) else {
+			return;
+		};
+		// An input that's already animated is keyed at the value it's animated to, since that's what's shown rather than the value in the document
+		let value = match self.animated_input(network_path, node_id, input_index) {
+			Some(animated_input) => animated_input.value_at(time),
+			None => value.clone(),
+		};
+		self.set_keyframe(network_path, node_id, input_index, time, value);
+	}
+
+	/// Keeps the keyframe at the current time in sync with an edit made by the user to an animated input, since the edit would otherwise be overwritten by the animation.
+	pub fn input_value_edited(&mut self, network_path: &[NodeId], node_id: NodeId, input_index: usize, value: &TaggedValue) {
+		if self.animated_input(network_path, node_id, input_index).is_some() {
+			self.set_keyframe(network_path, node_id, input_index, self.time, value.clone());
+		}
+	}
+
+	/// The value of every animated input at the given time, ahead of evaluating the graph.
+	/// These only replace the inputs' values in the network that's evaluated, so the document keeps the values the user set.
+	pub fn values_at(&self, network_interface: &NodeNetworkInterface, time: f64) -> AnimatedValues {
+		let mut values = AnimatedValues::new();
+		for animated_input in &self.animated_inputs {
+			let input_connector = InputConnector::node(animated_input.node_id, animated_input.input_index);
+			// Skip inputs of nodes that no longer exist or have since been connected to a wire
+			if network_interface
+				.input_from_connector(&input_connector, &animated_input.network_path)
+				.and_then(NodeInput::as_non_exposed_value)
+				.is_none()
+			{
+				continue;
+			}
+
+			let key = (animated_input.network_path.clone(), animated_input.node_id, animated_input.input_index);
+			values.insert(key, animated_input.value_at(time));
+		}
+		values
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use glam::DVec2;
+	use graphene_core::Color;
+
+	#[test]
+	fn interpolate_keyframes() {
+		let mut keyframes = Keyframes::default();
+		let node_id = NodeId(1);
+		keyframes.set_keyframe(&[], node_id, 1, 2., TaggedValue::F64(20.));
+		keyframes.set_keyframe(&[], node_id, 1, 0., TaggedValue::F64(10.));
+		keyframes.set_keyframe(&[], node_id, 2, 0., TaggedValue::DVec2(DVec2::ZERO));
+		keyframes.set_keyframe(&[], node_id, 2, 1., TaggedValue::DVec2(DVec2::new(4., 2.)));
+		keyframes.set_keyframe(&[], node_id, 3, 0., TaggedValue::Color(Color::BLACK));
+		keyframes.set_keyframe(&[], node_id, 3, 1., TaggedValue::Color(Color::WHITE));
+		keyframes.set_keyframe(&[], node_id, 4, 0., TaggedValue::Bool(false));
+		keyframes.set_keyframe(&[], node_id, 4, 1., TaggedValue::Bool(true));
+
+		let value_at = |input_index, time| keyframes.animated_input(&[], node_id, input_index).unwrap().value_at(time);
+		assert_eq!(value_at(1, -1.), TaggedValue::F64(10.));
+		assert_eq!(value_at(1, 1.), TaggedValue::F64(15.));
+		assert_eq!(value_at(1, 3.), TaggedValue::F64(20.));
+		assert_eq!(value_at(2, 0.5), TaggedValue::DVec2(DVec2::new(2., 1.)));
+		assert_eq!(value_at(3, 0.5), TaggedValue::Color(Color::BLACK.lerp(&Color::WHITE, 0.5)));
+		assert_eq!(value_at(4, 0.5), TaggedValue::Bool(false));
+		assert_eq!(value_at(4, 1.), TaggedValue::Bool(true));
+
+		keyframes.remove_keyframe(&[], node_id, 1, 0.);
+		assert_eq!(keyframes.animated_input(&[], node_id, 1).unwrap().keyframes.len(), 1);
+		keyframes.remove_keyframe(&[], node_id, 1, 2.);
+		assert!(keyframes.animated_input(&[], node_id, 1).is_none());
+	}
+}
@@ -1,6 +1,11 @@
 use crate::consts::COLOR_OVERLAY_GRAY;
-use glam::DVec2;
+use crate::messages::frontend::utility_types::FileType;
+use crate::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
+use glam::{DAffine2, DVec2};
+use graph_craft::document::NodeId;
+use graph_craft::document::value::TaggedValue;
 use graphene_core::raster::Color;
+use graphene_core::vector::style::ViewMode;
 use std::fmt;
 
 #[repr(transparent)]
@@ -26,6 +31,93 @@ pub enum AlignAggregate {
 	Center,
 }
 
+/// The bounds which selected layers are aligned relative to.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, serde::Serialize, serde::Deserialize, Hash)]
+pub enum AlignReference {
+	/// The combined bounds of all the selected layers.
+	#[default]
+	Selection,
+	/// The bounds of the artboard containing each layer. Layers outside an artboard are left in place.
+	Artboard,
+	/// The bounds of one of the selected layers, which itself stays in place.
+	KeyLayer(LayerNodeIdentifier),
+}
+
+/// A layer marked to be exported as its own file, with its bounds as the exported area, when exporting all slices of the document in one batch.
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ExportSlice {
+	pub layer: LayerNodeIdentifier,
+	/// The file name (without extension) that the slice is exported as.
+	pub name: String,
+	pub file_type: FileType,
+	pub scale_factor: f64,
+}
+
+/// The state of a document while it's presented full screen as a slideshow of its artboards.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Presentation {
+	/// The index of the artboard being shown, in reading order.
+	pub slide: usize,
+	/// The overlays visibility from before presenting, which is restored afterwards since overlays are hidden while presenting.
+	pub overlays_visible: bool,
+	/// The view mode from before presenting, which is restored afterwards since the artwork is presented in the normal view mode.
+	pub view_mode: ViewMode,
+}
+
+/// The state of a document while the viewport is used to pick the value of a node's position input, which is shown as a gizmo that can be dragged around.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct PositionPicker {
+	pub node_id: NodeId,
+	pub input_index: usize,
+	/// The offset in viewport space from the pointer to the gizmo while it's being dragged, or `None` if the pointer isn't held down.
+	pub drag_offset: Option<DVec2>,
+}
+
+/// The transformation applied by the "Transform Each" command to every selected layer about its own pivot.
+#[derive(PartialEq, Clone, Copy, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TransformEachOptions {
+	/// The offset in document space.
+	pub translation: DVec2,
+	/// The rotation in degrees.
+	pub rotation: f64,
+	/// The scale factor along each axis.
+	pub scale: DVec2,
+	/// The maximum distance each layer is randomly moved along each axis, in addition to the translation.
+	pub random_translation: f64,
+	/// The maximum angle in degrees each layer is randomly rotated, in addition to the rotation.
+	pub random_rotation: f64,
+	/// The maximum fraction by which each layer is randomly scaled up or down, uniformly on both axes.
+	pub random_scale: f64,
+	pub seed: u32,
+}
+
+impl Default for TransformEachOptions {
+	fn default() -> Self {
+		Self {
+			translation: DVec2::ZERO,
+			rotation: 0.,
+			scale: DVec2::ONE,
+			random_translation: 0.,
+			random_rotation: 0.,
+			random_scale: 0.,
+			seed: 0,
+		}
+	}
+}
+
+impl TransformEachOptions {
+	/// The transform for a single layer about its `pivot`, with the random jitter drawn from `rng` so each layer gets different values.
+	pub fn transform_about(&self, pivot: DVec2, rng: &mut impl rand::Rng) -> DAffine2 {
+		let mut jitter = |max: f64| if max > 0. { rng.random_range(-max..=max) } else { 0. };
+
+		let translation = self.translation + DVec2::new(jitter(self.random_translation), jitter(self.random_translation));
+		let rotation = (self.rotation + jitter(self.random_rotation)).to_radians();
+		let scale = self.scale * (1. + jitter(self.random_scale));
+
+		DAffine2::from_translation(pivot + translation) * DAffine2::from_angle(rotation) * DAffine2::from_scale(scale) * DAffine2::from_translation(-pivot)
+	}
+}
+
 #[derive(Default, PartialEq, Eq, Clone, Copy, Debug, serde::Serialize, serde::Deserialize)]
 pub enum DocumentMode {
 	#[default]
@@ -54,12 +146,195 @@ impl DocumentMode {
 	}
 }
 
+/// Evaluates a math expression (like "300/4+10") typed into a numeric field, using the same parser as the Math node.
+/// Expressions which can't be evaluated are expected while typing, so they're only logged for debugging.
+pub fn evaluate_math_expression(expression: &str) -> Option<f64> {
+	let value = math_parser::evaluate(expression)
+		.inspect_err(|err| log::debug!("Math parser error on \"{expression}\": {err}"))
+		.ok()?
+		.0
+		.inspect_err(|err| log::debug!("Math evaluate error on \"{expression}\": {err} "))
+		.ok()?;
+	let Some(real) = value.as_real() else {
+		log::debug!("{value} was not a real; skipping.");
+		return None;
+	};
+	Some(real)
+}
+
+/// How much detail the expensive nodes of a document are computed with while it's shown in the viewport, while exports are always rendered at full quality.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug, Hash, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum PreviewQuality {
+	#[default]
+	Full,
+	Balanced,
+	Draft,
+}
+
+impl fmt::Display for PreviewQuality {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			PreviewQuality::Full => write!(f, "Full"),
+			PreviewQuality::Balanced => write!(f, "Balanced"),
+			PreviewQuality::Draft => write!(f, "Draft"),
+		}
+	}
+}
+
+impl PreviewQuality {
+	pub const ALL: [PreviewQuality; 3] = [PreviewQuality::Full, PreviewQuality::Balanced, PreviewQuality::Draft];
+
+	/// The fraction of the full amount of work that expensive nodes do at this quality.
+	pub fn detail(&self) -> f64 {
+		match self {
+			PreviewQuality::Full => 1.,
+			PreviewQuality::Balanced => 0.5,
+			PreviewQuality::Draft => 0.25,
+		}
+	}
+
+	/// The value that an input of an expensive node is lowered to at this quality, if it's one of the inputs which control how much work the node does.
+	pub fn reduced_input(&self, reference: &str, input_index: usize, value: &TaggedValue) -> Option<TaggedValue> {
+		let detail = self.detail();
+		if detail >= 1. {
+			return None;
+		}
+
+		match (reference, input_index, value) {
+			// Fewer octaves of noise are layered together, which only loses the finest details
+			("Noise Pattern", 8, TaggedValue::U32(octaves)) => Some(TaggedValue::U32(((*octaves as f64 * detail).ceil() as u32).max(1))),
+			// Points are scattered further apart, so the number of points falls in proportion to the detail
+			("Scatter Points", 1, TaggedValue::F64(separation)) => Some(TaggedValue::F64(separation / detail.sqrt())),
+			_ => None,
+		}
+	}
+}
+
+/// The unit that lengths are displayed and entered in throughout the editor's numeric fields, while the document itself always stores lengths in pixels.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug, Hash, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum LengthUnit {
+	#[default]
+	Pixel,
+	Millimeter,
+	Centimeter,
+	Inch,
+	Point,
+}
+
+impl fmt::Display for LengthUnit {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			LengthUnit::Pixel => write!(f, "Pixels"),
+			LengthUnit::Millimeter => write!(f, "Millimeters"),
+			LengthUnit::Centimeter => write!(f, "Centimeters"),
+			LengthUnit::Inch => write!(f, "Inches"),
+			LengthUnit::Point => write!(f, "Points"),
+		}
+	}
+}
+
+impl LengthUnit {
+	pub const ALL: [LengthUnit; 5] = [LengthUnit::Pixel, LengthUnit::Millimeter, LengthUnit::Centimeter, LengthUnit::Inch, LengthUnit::Point];
+
+	/// The abbreviation shown after a value and accepted as a suffix when typing a value.
+	pub fn symbol(&self) -> &'static str {
+		match self {
+			LengthUnit::Pixel => "px",
+			LengthUnit::Millimeter => "mm",
+			LengthUnit::Centimeter => "cm",
+			LengthUnit::Inch => "in",
+			LengthUnit::Point => "pt",
+		}
+	}
+
+	/// The number of pixels in one of this unit, based on the CSS convention of 96 pixels per inch.
+	pub fn pixels_per_unit(&self) -> f64 {
+		match self {
+			LengthUnit::Pixel => 1.,
+			LengthUnit::Millimeter => 96. / 25.4,
+			LengthUnit::Centimeter => 96. / 2.54,
+			LengthUnit::Inch => 96.,
+			LengthUnit::Point => 96. / 72.,
+		}
+	}
+
+	/// Rewrites a typed math expression so every number suffixed by a unit symbol (like the "2cm" in "2cm + 5") is converted into this unit.
+	/// Numbers without a suffix are left alone, so they're interpreted as already being in this unit.
+	pub fn convert_expression(&self, expression: &str) -> String {
+		let chars = expression.chars().collect::<Vec<_>>();
+		let mut result = String::with_capacity(expression.len());
+
+		let mut index = 0;
+		while index < chars.len() {
+			let start = index;
+
+			// Skip over identifiers (like function names such as `log10`) so digits within them aren't mistaken for numbers
+			if chars[index].is_alphabetic() || chars[index] == '_' {
+				while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+					index += 1;
+				}
+				result.extend(&chars[start..index]);
+				continue;
+			}
+
+			if !chars[index].is_ascii_digit() && chars[index] != '.' {
+				result.push(chars[index]);
+				index += 1;
+				continue;
+			}
+
+			// Read the number, including any decimal point and exponent
+			while index < chars.len() && (chars[index].is_ascii_digit() || chars[index] == '.') {
+				index += 1;
+			}
+			if index < chars.len() && matches!(chars[index], 'e' | 'E') {
+				let sign = usize::from(matches!(chars.get(index + 1), Some('+' | '-')));
+				if chars.get(index + 1 + sign).is_some_and(|c| c.is_ascii_digit()) {
+					index += 1 + sign;
+					while index < chars.len() && chars[index].is_ascii_digit() {
+						index += 1;
+					}
+				}
+			}
+			let number = chars[start..index].iter().collect::<String>();
+
+			// Look for a unit symbol after the number, which must not be the start of a longer identifier
+			let mut suffix_start = index;
+			while suffix_start < chars.len() && chars[suffix_start] == ' ' {
+				suffix_start += 1;
+			}
+			let suffix_unit = Self::ALL.into_iter().find(|unit| {
+				let symbol = unit.symbol().chars().collect::<Vec<_>>();
+				let suffix_end = suffix_start + symbol.len();
+				chars.get(suffix_start..suffix_end) == Some(&symbol[..]) && !chars.get(suffix_end).is_some_and(|c| c.is_alphanumeric() || *c == '_')
+			});
+
+			match suffix_unit {
+				Some(unit) => {
+					let factor = unit.pixels_per_unit() / self.pixels_per_unit();
+					result.push_str(&format!("({number}*{factor})"));
+					index = suffix_start + unit.symbol().len();
+				}
+				None => result.push_str(&number),
+			}
+		}
+
+		result
+	}
+
+	/// Evaluates a math expression typed into a length field shown in this unit, where numbers may be suffixed by any unit (like "2cm"), and returns the length in pixels.
+	pub fn evaluate_expression(&self, expression: &str) -> Option<f64> {
+		evaluate_math_expression(&self.convert_expression(expression)).map(|value| value * self.pixels_per_unit())
+	}
+}
+
 /// SnappingState determines the current individual snapping states
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
 pub struct SnappingState {
 	pub snapping_enabled: bool,
 	pub grid_snapping: bool,
+	pub pixel_snapping: bool,
 	pub artboards: bool,
 	pub tolerance: f64,
 	pub bounding_box: BoundingBoxSnapping,
@@ -72,6 +347,7 @@ impl Default for SnappingState {
 		Self {
 			snapping_enabled: true,
 			grid_snapping: false,
+			pixel_snapping: false,
 			artboards: true,
 			tolerance: 8.,
 			bounding_box: BoundingBoxSnapping::default(),
@@ -102,6 +378,7 @@ impl SnappingState {
 				PathSnapTarget::PerpendicularToEndpoint => self.path.perpendicular_from_endpoint,
 			},
 			SnapTarget::Artboard(_) => self.artboards,
+			SnapTarget::Grid(GridSnapTarget::Pixel) => self.pixel_snapping,
 			SnapTarget::Grid(_) => self.grid_snapping,
 			SnapTarget::Alignment(AlignmentSnapTarget::AlignWithAnchorPoint) => self.path.align_with_anchor_point,
 			SnapTarget::Alignment(_) => self.bounding_box.align_with_edges,
@@ -453,6 +730,12 @@ pub const SNAP_FUNCTIONS_FOR_PATHS: [(&str, GetSnapState, &str); 7] = [
 	),
 ];
 
+pub const SNAP_FUNCTIONS_FOR_PIXELS: [(&str, GetSnapState, &str); 1] = [(
+	"Whole Pixels",
+	(|snapping_state: &mut SnappingState| &mut snapping_state.pixel_snapping) as GetSnapState,
+	"Snaps to the nearest corner of the document's pixel grid when nothing else is in range,\nkeeping edges crisp in raster exports",
+)];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum BoundingBoxSnapTarget {
 	CornerPoint,
@@ -518,6 +801,7 @@ pub enum GridSnapTarget {
 	Line,
 	LineNormal,
 	Intersection,
+	Pixel,
 }
 
 impl fmt::Display for GridSnapTarget {
@@ -526,6 +810,7 @@ impl fmt::Display for GridSnapTarget {
 			GridSnapTarget::Line => write!(f, "Grid: Along Line"),
 			GridSnapTarget::LineNormal => write!(f, "Grid: Normal to Line"),
 			GridSnapTarget::Intersection => write!(f, "Grid: Intersection Point"),
+			GridSnapTarget::Pixel => write!(f, "Grid: Pixel"),
 		}
 	}
 }
@@ -628,6 +913,7 @@ impl fmt::Display for SnapTarget {
 pub enum SnappingOptions {
 	BoundingBoxes,
 	Paths,
+	Pixels,
 }
 
 impl fmt::Display for SnappingOptions {
@@ -635,6 +921,7 @@ impl fmt::Display for SnappingOptions {
 		match self {
 			SnappingOptions::BoundingBoxes => write!(f, "Bounding Boxes"),
 			SnappingOptions::Paths => write!(f, "Paths"),
+			SnappingOptions::Pixels => write!(f, "Pixels"),
 		}
 	}
 }
@@ -648,11 +935,18 @@ pub struct PTZ {
 	tilt: f64,
 	/// Scale factor.
 	zoom: f64,
+	/// Whether the view is mirrored horizontally about the center of the viewport.
+	flip: bool,
 }
 
 impl Default for PTZ {
 	fn default() -> Self {
-		Self { pan: DVec2::ZERO, tilt: 0., zoom: 1. }
+		Self {
+			pan: DVec2::ZERO,
+			tilt: 0.,
+			zoom: 1.,
+			flip: false,
+		}
 	}
 }
 
@@ -680,6 +974,16 @@ impl PTZ {
 	pub fn set_zoom(&mut self, zoom: f64) {
 		self.zoom = zoom.clamp(crate::consts::VIEWPORT_ZOOM_SCALE_MIN, crate::consts::VIEWPORT_ZOOM_SCALE_MAX)
 	}
+
+	/// Get whether the view is mirrored horizontally.
+	pub fn flip(&self) -> bool {
+		self.flip
+	}
+
+	/// Set whether the view is mirrored horizontally.
+	pub fn set_flip(&mut self, flip: bool) {
+		self.flip = flip;
+	}
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -687,3 +991,36 @@ pub enum GroupFolderType {
 	Layer,
 	BooleanOperation(graphene_std::vector::misc::BooleanOperation),
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{LengthUnit, PreviewQuality, evaluate_math_expression};
+	use graph_craft::document::value::TaggedValue;
+
+	#[test]
+	fn evaluate_expressions() {
+		assert_eq!(evaluate_math_expression("300/4+10"), Some(85.));
+		assert_eq!(LengthUnit::Pixel.evaluate_expression("1in + 4"), Some(100.));
+		assert_eq!(LengthUnit::Inch.evaluate_expression("0.5"), Some(48.));
+	}
+
+	#[test]
+	fn convert_expression_units() {
+		assert_eq!(LengthUnit::Pixel.convert_expression("300/4+10"), "300/4+10");
+		assert_eq!(LengthUnit::Pixel.convert_expression("2in"), "(2*96)");
+		assert_eq!(LengthUnit::Pixel.convert_expression("1.5e1 mm"), format!("(1.5e1*{})", 96. / 25.4));
+		assert_eq!(LengthUnit::Inch.convert_expression("72pt + 1"), format!("(72*{}) + 1", (96. / 72.) / 96.));
+		assert_eq!(LengthUnit::Pixel.convert_expression("log10(100)"), "log10(100)");
+		assert_eq!(LengthUnit::Pixel.convert_expression("2 inf"), "2 inf");
+	}
+
+	#[test]
+	fn preview_quality_reduces_expensive_inputs() {
+		let octaves = TaggedValue::U32(3);
+		assert_eq!(PreviewQuality::Full.reduced_input("Noise Pattern", 8, &octaves), None);
+		assert_eq!(PreviewQuality::Balanced.reduced_input("Noise Pattern", 8, &octaves), Some(TaggedValue::U32(2)));
+		assert_eq!(PreviewQuality::Draft.reduced_input("Noise Pattern", 8, &octaves), Some(TaggedValue::U32(1)));
+		assert_eq!(PreviewQuality::Draft.reduced_input("Scatter Points", 1, &TaggedValue::F64(10.)), Some(TaggedValue::F64(20.)));
+		assert_eq!(PreviewQuality::Draft.reduced_input("Noise Pattern", 2, &TaggedValue::U32(3)), None);
+	}
+}
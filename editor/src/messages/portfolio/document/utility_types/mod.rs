@@ -1,7 +1,17 @@
 pub mod clipboards;
+pub mod comments;
+pub mod custom_nodes;
 pub mod document_metadata;
+pub mod drivers;
 pub mod error;
+pub mod guides;
+pub mod keyframes;
 pub mod misc;
 pub mod network_interface;
+pub mod node_profile;
 pub mod nodes;
+pub mod performance_budget;
+pub mod print;
+pub mod swatches;
+pub mod text_search;
 pub mod transformation;
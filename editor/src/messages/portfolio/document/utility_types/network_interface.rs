@@ -3,7 +3,7 @@ use super::misc::PTZ;
 use super::nodes::SelectedNodes;
 use crate::consts::{EXPORTS_TO_RIGHT_EDGE_PIXEL_GAP, EXPORTS_TO_TOP_EDGE_PIXEL_GAP, GRID_SIZE, IMPORTS_TO_LEFT_EDGE_PIXEL_GAP, IMPORTS_TO_TOP_EDGE_PIXEL_GAP};
 use crate::messages::portfolio::document::graph_operation::utility_types::ModifyInputsContext;
-use crate::messages::portfolio::document::node_graph::document_node_definitions::{DocumentNodeDefinition, resolve_document_node_type};
+use crate::messages::portfolio::document::node_graph::document_node_definitions::{ADJUSTMENT_LAYER_NODE_TYPES, DocumentNodeDefinition, resolve_document_node_type};
 use crate::messages::portfolio::document::node_graph::utility_types::{Direction, FrontendClickTargets, FrontendGraphDataType, FrontendGraphInput, FrontendGraphOutput};
 use crate::messages::tool::common_functionality::graph_modification_utils;
 use crate::messages::tool::tool_messages::tool_prelude::NumberInputMode;
@@ -12,6 +12,7 @@ use glam::{DAffine2, DVec2, IVec2};
 use graph_craft::document::value::TaggedValue;
 use graph_craft::document::{DocumentNode, DocumentNodeImplementation, NodeId, NodeInput, NodeNetwork, OldDocumentNodeImplementation, OldNodeNetwork};
 use graph_craft::{Type, concrete};
+use graphene_core::Color;
 use graphene_std::renderer::{ClickTarget, Quad};
 use graphene_std::transform::Footprint;
 use graphene_std::vector::{PointId, VectorData, VectorModificationType};
@@ -60,6 +61,73 @@ impl PartialEq for NodeNetworkInterface {
 
 // Public immutable getters for the network interface
 impl NodeNetworkInterface {
+	/// Get the frames drawn behind the nodes in the network at the network_path
+	pub fn frames(&self, network_path: &[NodeId]) -> &[NodeGraphFrame] {
+		self.network_metadata(network_path)
+			.map(|network_metadata| network_metadata.persistent_metadata.frames.as_slice())
+			.unwrap_or_default()
+	}
+
+	/// Get the frame whose title bar or resize grip is under the given point in node graph space, preferring the frame drawn on top
+	pub fn frame_from_click(&self, point: DVec2, network_path: &[NodeId]) -> Option<(u64, FrameClickTarget)> {
+		self.frames(network_path).iter().rev().find_map(|frame| {
+			let [top_left, bottom_right] = frame.bounds();
+			if point.cmplt(top_left).any() || point.cmpgt(bottom_right).any() {
+				return None;
+			}
+			if (bottom_right - point).max_element() < GRID_SIZE as f64 {
+				Some((frame.id, FrameClickTarget::ResizeGrip))
+			} else if point.y < top_left.y + GRID_SIZE as f64 {
+				Some((frame.id, FrameClickTarget::TitleBar))
+			} else {
+				None
+			}
+		})
+	}
+
+	/// Get the sticky notes placed in the network at the network_path
+	pub fn notes(&self, network_path: &[NodeId]) -> &[NodeGraphNote] {
+		self.network_metadata(network_path)
+			.map(|network_metadata| network_metadata.persistent_metadata.notes.as_slice())
+			.unwrap_or_default()
+	}
+
+	/// Get the sticky note whose header or resize grip is under the given point in node graph space, preferring the note drawn on top
+	pub fn note_from_click(&self, point: DVec2, network_path: &[NodeId]) -> Option<(u64, FrameClickTarget)> {
+		self.notes(network_path).iter().rev().find_map(|note| {
+			let [top_left, bottom_right] = note.bounds();
+			if point.cmplt(top_left).any() || point.cmpgt(bottom_right).any() {
+				return None;
+			}
+			if (bottom_right - point).max_element() < GRID_SIZE as f64 {
+				Some((note.id, FrameClickTarget::ResizeGrip))
+			} else if point.y < top_left.y + GRID_SIZE as f64 {
+				Some((note.id, FrameClickTarget::TitleBar))
+			} else {
+				None
+			}
+		})
+	}
+
+	/// Get the wires in the network at the network_path with a readout of their value pinned along them, given by the inputs they end at
+	pub fn pinned_wire_probes(&self, network_path: &[NodeId]) -> &[InputConnector] {
+		self.network_metadata(network_path)
+			.map(|network_metadata| network_metadata.persistent_metadata.pinned_wire_probes.as_slice())
+			.unwrap_or_default()
+	}
+
+	/// Get the sticky notes overlapping the given rectangle in node graph space
+	pub fn notes_overlapping(&self, [min, max]: [DVec2; 2], network_path: &[NodeId]) -> Vec<NodeGraphNote> {
+		self.notes(network_path)
+			.iter()
+			.filter(|note| {
+				let [top_left, bottom_right] = note.bounds();
+				top_left.cmplt(max).all() && bottom_right.cmpgt(min).all()
+			})
+			.cloned()
+			.collect()
+	}
+
 	// TODO: Make private and use .field_name getter methods
 	/// Gets the network of the root document
 	pub fn document_network(&self) -> &NodeNetwork {
@@ -1118,6 +1186,14 @@ impl NodeNetworkInterface {
 		Some(&node_metadata.persistent_metadata.reference)
 	}
 
+	pub fn definition_version(&self, node_id: &NodeId, network_path: &[NodeId]) -> Option<u32> {
+		let Some(node_metadata) = self.node_metadata(node_id, network_path) else {
+			log::error!("Could not get definition_version");
+			return None;
+		};
+		Some(node_metadata.persistent_metadata.definition_version)
+	}
+
 	pub fn implementation(&self, node_id: &NodeId, network_path: &[NodeId]) -> Option<&DocumentNodeImplementation> {
 		let Some(node) = self.document_node(node_id, network_path) else {
 			log::error!("Could not get implementation");
@@ -1206,6 +1282,14 @@ impl NodeNetworkInterface {
 		node_metadata.persistent_metadata.pinned
 	}
 
+	/// Whether the input is pinned to the Pinned Parameters section of the Properties panel.
+	pub fn is_input_pinned(&self, node_id: &NodeId, index: usize, network_path: &[NodeId]) -> bool {
+		self.input_properties_row(node_id, index, network_path)
+			.and_then(|input_row| input_row.input_data.get("pinned"))
+			.and_then(|pinned| pinned.as_bool())
+			.unwrap_or_default()
+	}
+
 	pub fn is_visible(&self, node_id: &NodeId, network_path: &[NodeId]) -> bool {
 		let Some(node) = self.document_node(node_id, network_path) else {
 			log::error!("Could not get node in is_visible");
@@ -1214,6 +1298,15 @@ impl NodeNetworkInterface {
 		node.visible
 	}
 
+	/// Whether the node is bypassed, passing its primary input through in place of its output.
+	pub fn is_bypassed(&self, node_id: &NodeId, network_path: &[NodeId]) -> bool {
+		let Some(node) = self.document_node(node_id, network_path) else {
+			log::error!("Could not get node in is_bypassed");
+			return false;
+		};
+		node.bypassed
+	}
+
 	pub fn is_layer(&self, node_id: &NodeId, network_path: &[NodeId]) -> bool {
 		let Some(node_metadata) = self.node_metadata(node_id, network_path) else {
 			log::error!("Could not get nested node_metadata in is_layer");
@@ -1268,6 +1361,15 @@ impl NodeNetworkInterface {
 			.is_some_and(|reference| *reference == Some("Artboard".to_string()) && self.connected_to_output(node_id, &[]))
 	}
 
+	/// An adjustment layer is an adjustment node displayed as a layer, which applies to the graphic group formed by all the layers below it in its stack.
+	pub fn is_adjustment_layer(&self, node_id: &NodeId, network_path: &[NodeId]) -> bool {
+		self.is_layer(node_id, network_path)
+			&& self
+				.reference(node_id, network_path)
+				.and_then(|reference| reference.as_deref())
+				.is_some_and(|reference| ADJUSTMENT_LAYER_NODE_TYPES.contains(&reference))
+	}
+
 	pub fn all_artboards(&self) -> HashSet<LayerNodeIdentifier> {
 		self.document_network_metadata()
 			.persistent_metadata
@@ -3298,6 +3400,92 @@ impl NodeNetworkInterface {
 		node_metadata.persistent_metadata.reference = reference;
 	}
 
+	pub fn set_definition_version(&mut self, node_id: &NodeId, network_path: &[NodeId], definition_version: u32) {
+		let Some(node_metadata) = self.node_metadata_mut(node_id, network_path) else {
+			log::error!("Could not get node_metadata in set_definition_version");
+			return;
+		};
+		node_metadata.persistent_metadata.definition_version = definition_version;
+	}
+
+	pub fn set_node_asset(&mut self, node_id: &NodeId, network_path: &[NodeId], asset: Option<NodeAssetReference>) {
+		let Some(node_metadata) = self.node_metadata_mut(node_id, network_path) else {
+			log::error!("Could not get node_metadata in set_node_asset");
+			return;
+		};
+		node_metadata.persistent_metadata.asset = asset;
+	}
+
+	/// Finds every instance of a node asset in the document, including those nested inside other nodes, as the node and the path of the network it's in.
+	pub fn node_asset_instances(&self, asset_id: u64) -> Vec<(NodeId, Vec<NodeId>)> {
+		fn collect(network_metadata: &NodeNetworkMetadata, network_path: &mut Vec<NodeId>, asset_id: u64, instances: &mut Vec<(NodeId, Vec<NodeId>)>) {
+			for (node_id, node_metadata) in &network_metadata.persistent_metadata.node_metadata {
+				let persistent_metadata = &node_metadata.persistent_metadata;
+				if persistent_metadata.asset.is_some_and(|asset| asset.id == asset_id) {
+					instances.push((*node_id, network_path.clone()));
+				}
+				if let Some(nested_network_metadata) = &persistent_metadata.network_metadata {
+					network_path.push(*node_id);
+					collect(nested_network_metadata, network_path, asset_id, instances);
+					network_path.pop();
+				}
+			}
+		}
+
+		let mut instances = Vec::new();
+		collect(self.document_network_metadata(), &mut Vec::new(), asset_id, &mut instances);
+		instances
+	}
+
+	/// Brings an instance of a node asset up to date with a version of the asset by replacing its contents.
+	/// The values given to its inputs are kept, unless the asset's version has a different number of inputs.
+	pub fn update_node_asset_instance(&mut self, node_id: &NodeId, network_path: &[NodeId], node_template: &NodeTemplate) {
+		let Some(network) = self.network_mut(network_path) else {
+			log::error!("Could not get nested network in update_node_asset_instance");
+			return;
+		};
+		let Some(node) = network.nodes.get_mut(node_id) else {
+			log::error!("Could not get node in update_node_asset_instance");
+			return;
+		};
+		node.implementation = node_template.document_node.implementation.clone();
+		let inputs_changed = node.inputs.len() != node_template.document_node.inputs.len();
+		if inputs_changed {
+			node.inputs = node_template.document_node.inputs.clone();
+		}
+		self.transaction_modified();
+
+		let Some(node_metadata) = self.node_metadata_mut(node_id, network_path) else {
+			log::error!("Could not get node_metadata in update_node_asset_instance");
+			return;
+		};
+		let asset_metadata = &node_template.persistent_node_metadata;
+		let persistent_metadata = &mut node_metadata.persistent_metadata;
+		persistent_metadata.network_metadata = asset_metadata.network_metadata.clone();
+		persistent_metadata.output_names = asset_metadata.output_names.clone();
+		persistent_metadata.has_primary_output = asset_metadata.has_primary_output;
+		persistent_metadata.asset = asset_metadata.asset;
+		if inputs_changed {
+			persistent_metadata.input_properties = asset_metadata.input_properties.clone();
+		}
+
+		self.unload_node_click_targets(node_id, network_path);
+		self.unload_all_nodes_bounding_box(network_path);
+		self.unload_outward_wires(network_path);
+	}
+
+	pub fn set_input_metadata(&mut self, node_id: &NodeId, index: usize, field: &str, value: Value, network_path: &[NodeId]) {
+		let Some(node_metadata) = self.node_metadata_mut(node_id, network_path) else {
+			log::error!("Could not get node_metadata in set_input_metadata");
+			return;
+		};
+		let Some(input_row) = node_metadata.persistent_metadata.input_properties.get_mut(index) else {
+			log::error!("Could not get input properties row in set_input_metadata");
+			return;
+		};
+		input_row.input_data.insert(field.to_string(), value);
+	}
+
 	pub fn set_transform(&mut self, transform: DAffine2, network_path: &[NodeId]) {
 		let Some(network_metadata) = self.network_metadata_mut(network_path) else {
 			log::error!("Could not get nested network in set_transform");
@@ -4457,6 +4645,11 @@ impl NodeNetworkInterface {
 		self.transaction_modified();
 	}
 
+	pub fn set_input_pinned(&mut self, node_id: &NodeId, index: usize, network_path: &[NodeId], pinned: bool) {
+		self.set_input_metadata(node_id, index, "pinned", Value::Bool(pinned), network_path);
+		self.transaction_modified();
+	}
+
 	pub fn set_visibility(&mut self, node_id: &NodeId, network_path: &[NodeId], is_visible: bool) {
 		let Some(network) = self.network_mut(network_path) else {
 			return;
@@ -4470,6 +4663,19 @@ impl NodeNetworkInterface {
 		self.transaction_modified();
 	}
 
+	pub fn set_bypassed(&mut self, node_id: &NodeId, network_path: &[NodeId], bypassed: bool) {
+		let Some(network) = self.network_mut(network_path) else {
+			return;
+		};
+		let Some(node) = network.nodes.get_mut(node_id) else {
+			log::error!("Could not get node {node_id} in set_bypassed");
+			return;
+		};
+
+		node.bypassed = bypassed;
+		self.transaction_modified();
+	}
+
 	pub fn set_locked(&mut self, node_id: &NodeId, network_path: &[NodeId], locked: bool) {
 		let Some(node_metadata) = self.node_metadata_mut(node_id, network_path) else {
 			log::error!("Could not get node {node_id} in set_visibility");
@@ -5638,6 +5844,187 @@ impl NodeNetworkInterface {
 		}
 	}
 
+	pub fn add_frame(&mut self, frame: NodeGraphFrame, network_path: &[NodeId]) {
+		let Some(network_metadata) = self.network_metadata_mut(network_path) else {
+			log::error!("Could not get nested network_metadata in add_frame");
+			return;
+		};
+		network_metadata.persistent_metadata.frames.push(frame);
+		self.transaction_modified();
+	}
+
+	pub fn remove_frame(&mut self, frame_id: u64, network_path: &[NodeId]) {
+		let Some(network_metadata) = self.network_metadata_mut(network_path) else {
+			log::error!("Could not get nested network_metadata in remove_frame");
+			return;
+		};
+		network_metadata.persistent_metadata.frames.retain(|frame| frame.id != frame_id);
+		self.transaction_modified();
+	}
+
+	fn frame_mut(&mut self, frame_id: u64, network_path: &[NodeId]) -> Option<&mut NodeGraphFrame> {
+		let network_metadata = self.network_metadata_mut(network_path)?;
+		network_metadata.persistent_metadata.frames.iter_mut().find(|frame| frame.id == frame_id)
+	}
+
+	pub fn set_frame_title(&mut self, frame_id: u64, title: String, network_path: &[NodeId]) {
+		let Some(frame) = self.frame_mut(frame_id, network_path) else {
+			log::error!("Could not get frame {frame_id} in set_frame_title");
+			return;
+		};
+		frame.title = title;
+		self.transaction_modified();
+	}
+
+	pub fn set_frame_color(&mut self, frame_id: u64, color: Color, network_path: &[NodeId]) {
+		let Some(frame) = self.frame_mut(frame_id, network_path) else {
+			log::error!("Could not get frame {frame_id} in set_frame_color");
+			return;
+		};
+		frame.color = color;
+		self.transaction_modified();
+	}
+
+	/// Resizes a frame by moving its bottom right corner, keeping it large enough to show its title bar
+	pub fn resize_frame(&mut self, frame_id: u64, dimensions: IVec2, network_path: &[NodeId]) {
+		let Some(frame) = self.frame_mut(frame_id, network_path) else {
+			log::error!("Could not get frame {frame_id} in resize_frame");
+			return;
+		};
+		let dimensions = dimensions.max(NodeGraphFrame::MIN_DIMENSIONS);
+		if dimensions == frame.dimensions {
+			return;
+		}
+		frame.dimensions = dimensions;
+		self.transaction_modified();
+	}
+
+	/// Get the nodes whose bounding boxes lie entirely within the frame
+	pub fn nodes_in_frame(&mut self, frame_id: u64, network_path: &[NodeId]) -> Vec<NodeId> {
+		let Some([frame_top_left, frame_bottom_right]) = self.frames(network_path).iter().find(|frame| frame.id == frame_id).map(NodeGraphFrame::bounds) else {
+			log::error!("Could not get frame {frame_id} in nodes_in_frame");
+			return Vec::new();
+		};
+		let Some(network) = self.nested_network(network_path) else {
+			log::error!("Could not get nested network in nodes_in_frame");
+			return Vec::new();
+		};
+		let node_ids = network.nodes.keys().cloned().collect::<Vec<_>>();
+
+		node_ids
+			.into_iter()
+			.filter(|node_id| {
+				self.node_bounding_box(node_id, network_path)
+					.is_some_and(|[top_left, bottom_right]| top_left.cmpge(frame_top_left).all() && bottom_right.cmple(frame_bottom_right).all())
+			})
+			.collect()
+	}
+
+	/// Moves a frame along with the given nodes contained within it. Only absolutely positioned nodes are shifted, since nodes in stacks and chains follow their downstream layer.
+	pub fn shift_frame(&mut self, frame_id: u64, shift: IVec2, contained_nodes: &[NodeId], network_path: &[NodeId]) {
+		if shift == IVec2::ZERO {
+			return;
+		}
+		let Some(frame) = self.frame_mut(frame_id, network_path) else {
+			log::error!("Could not get frame {frame_id} in shift_frame");
+			return;
+		};
+		frame.position += shift;
+
+		for node_id in contained_nodes {
+			let Some(node_metadata) = self.node_metadata_mut(node_id, network_path) else {
+				log::error!("Could not get node metadata for node {node_id} in shift_frame");
+				continue;
+			};
+			match &mut node_metadata.persistent_metadata.node_type_metadata {
+				NodeTypePersistentMetadata::Layer(layer_metadata) => {
+					if let LayerPosition::Absolute(layer_position) = &mut layer_metadata.position {
+						*layer_position += shift;
+					}
+				}
+				NodeTypePersistentMetadata::Node(node_metadata) => {
+					if let NodePosition::Absolute(node_position) = &mut node_metadata.position {
+						*node_position += shift;
+					}
+				}
+			}
+		}
+		self.transaction_modified();
+		self.unload_upstream_node_click_targets(contained_nodes.to_vec(), network_path);
+		self.unload_all_nodes_bounding_box(network_path);
+	}
+
+	pub fn add_note(&mut self, note: NodeGraphNote, network_path: &[NodeId]) {
+		let Some(network_metadata) = self.network_metadata_mut(network_path) else {
+			log::error!("Could not get nested network_metadata in add_note");
+			return;
+		};
+		network_metadata.persistent_metadata.notes.push(note);
+		self.transaction_modified();
+	}
+
+	pub fn remove_note(&mut self, note_id: u64, network_path: &[NodeId]) {
+		let Some(network_metadata) = self.network_metadata_mut(network_path) else {
+			log::error!("Could not get nested network_metadata in remove_note");
+			return;
+		};
+		network_metadata.persistent_metadata.notes.retain(|note| note.id != note_id);
+		self.transaction_modified();
+	}
+
+	pub fn set_wire_probe_pinned(&mut self, input_connector: InputConnector, pinned: bool, network_path: &[NodeId]) {
+		let Some(network_metadata) = self.network_metadata_mut(network_path) else {
+			log::error!("Could not get nested network_metadata in set_wire_probe_pinned");
+			return;
+		};
+		let pinned_wire_probes = &mut network_metadata.persistent_metadata.pinned_wire_probes;
+		pinned_wire_probes.retain(|pinned_input| *pinned_input != input_connector);
+		if pinned {
+			pinned_wire_probes.push(input_connector);
+		}
+		self.transaction_modified();
+	}
+
+	fn note_mut(&mut self, note_id: u64, network_path: &[NodeId]) -> Option<&mut NodeGraphNote> {
+		let network_metadata = self.network_metadata_mut(network_path)?;
+		network_metadata.persistent_metadata.notes.iter_mut().find(|note| note.id == note_id)
+	}
+
+	pub fn set_note_text(&mut self, note_id: u64, text: String, network_path: &[NodeId]) {
+		let Some(note) = self.note_mut(note_id, network_path) else {
+			log::error!("Could not get note {note_id} in set_note_text");
+			return;
+		};
+		note.text = text;
+		self.transaction_modified();
+	}
+
+	pub fn shift_note(&mut self, note_id: u64, shift: IVec2, network_path: &[NodeId]) {
+		let Some(note) = self.note_mut(note_id, network_path) else {
+			log::error!("Could not get note {note_id} in shift_note");
+			return;
+		};
+		if shift == IVec2::ZERO {
+			return;
+		}
+		note.position += shift;
+		self.transaction_modified();
+	}
+
+	/// Resizes a sticky note by moving its bottom right corner, keeping it large enough to show its header and a line of text
+	pub fn resize_note(&mut self, note_id: u64, dimensions: IVec2, network_path: &[NodeId]) {
+		let Some(note) = self.note_mut(note_id, network_path) else {
+			log::error!("Could not get note {note_id} in resize_note");
+			return;
+		};
+		let dimensions = dimensions.max(NodeGraphNote::MIN_DIMENSIONS);
+		if dimensions == note.dimensions {
+			return;
+		}
+		note.dimensions = dimensions;
+		self.transaction_modified();
+	}
+
 	pub fn iter_recursive(&self) -> NodesRecursiveIter<'_> {
 		NodesRecursiveIter {
 			stack: vec![&self.network],
@@ -6023,6 +6410,73 @@ pub struct NodeNetworkPersistentMetadata {
 	// TODO: Use `#[serde(skip)]` here instead? See above.
 	#[serde(default)]
 	pub selection_redo_history: VecDeque<SelectedNodes>,
+	/// Titled and colored boxes drawn behind groups of nodes to organize the network visually.
+	#[serde(default)]
+	pub frames: Vec<NodeGraphFrame>,
+	/// Free-floating notes of markdown text placed in the network to document it.
+	#[serde(default)]
+	pub notes: Vec<NodeGraphNote>,
+	/// The wires with a readout of their value pinned along them, given by the inputs they end at.
+	#[serde(default)]
+	pub pinned_wire_probes: Vec<InputConnector>,
+}
+
+/// A resizable rectangle drawn behind the nodes of a network, used to annotate a group of nodes with a title and color.
+/// Dragging a frame by its title bar also moves the nodes it contains.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NodeGraphFrame {
+	pub id: u64,
+	pub title: String,
+	pub color: Color,
+	/// Top left corner of the frame, in grid units
+	pub position: IVec2,
+	/// Width and height of the frame, in grid units
+	pub dimensions: IVec2,
+}
+
+impl NodeGraphFrame {
+	/// Smallest size a frame can be resized to, in grid units, which leaves room for its title bar
+	pub const MIN_DIMENSIONS: IVec2 = IVec2::new(8, 3);
+
+	/// Get the top left and bottom right corners of the frame in node graph space
+	pub fn bounds(&self) -> [DVec2; 2] {
+		let top_left = (self.position * GRID_SIZE as i32).as_dvec2();
+		[top_left, top_left + (self.dimensions * GRID_SIZE as i32).as_dvec2()]
+	}
+}
+
+/// A sticky note placed in a network, showing its markdown text rendered in a box which can be moved and resized independently of any nodes.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NodeGraphNote {
+	pub id: u64,
+	/// Markdown source of the note
+	pub text: String,
+	/// Top left corner of the note, in grid units
+	pub position: IVec2,
+	/// Width and height of the note, in grid units
+	pub dimensions: IVec2,
+}
+
+impl NodeGraphNote {
+	/// Smallest size a note can be resized to, in grid units, which leaves room for its header and a line of text
+	pub const MIN_DIMENSIONS: IVec2 = IVec2::new(4, 2);
+	/// Size of a newly created note, in grid units
+	pub const DEFAULT_DIMENSIONS: IVec2 = IVec2::new(8, 5);
+
+	/// Get the top left and bottom right corners of the note in node graph space
+	pub fn bounds(&self) -> [DVec2; 2] {
+		let top_left = (self.position * GRID_SIZE as i32).as_dvec2();
+		[top_left, top_left + (self.dimensions * GRID_SIZE as i32).as_dvec2()]
+	}
+}
+
+/// The parts of a frame or sticky note which can be dragged with the pointer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameClickTarget {
+	/// Dragging the title bar moves the frame and its contained nodes, or the header of a sticky note moves the note
+	TitleBar,
+	/// Dragging the bottom right corner resizes the frame or sticky note
+	ResizeGrip,
 }
 
 /// This is the same as Option, but more clear in the context of having cached metadata either being loaded or unloaded
@@ -6292,8 +6746,11 @@ pub struct DocumentNodePersistentMetadata {
 	/// The name of the node definition, as originally set by [`DocumentNodeDefinition`], used to display in the UI and to display the appropriate properties if no display name is set.
 	// TODO: Used during serialization/deserialization to prevent storing implementation or inputs (and possible other fields) if they are the same as the definition.
 	// TODO: The reference is removed once the node is modified, since the node now stores its own implementation and inputs.
-	// TODO: Implement node versioning so that references to old nodes can be updated to the new node definition.
 	pub reference: Option<String>,
+	/// The version of the referenced node definition that this node was created with or last upgraded to. Documents saved before versioning was introduced default to 0.
+	/// Nodes behind the current definition version keep evaluating through compatibility shims until the user explicitly upgrades them, see [`crate::messages::portfolio::document::node_graph::node_versions`].
+	#[serde(default)]
+	pub definition_version: u32,
 	/// A name chosen by the user for this instance of the node. Empty indicates no given name, in which case the reference name is displayed to the user in italics.
 	#[serde(default)]
 	pub display_name: String,
@@ -6317,12 +6774,23 @@ pub struct DocumentNodePersistentMetadata {
 	pub node_type_metadata: NodeTypePersistentMetadata,
 	/// This should always be Some for nodes with a [`DocumentNodeImplementation::Network`], and none for [`DocumentNodeImplementation::ProtoNode`]
 	pub network_metadata: Option<NodeNetworkMetadata>,
+	/// The node asset this node is an instance of, whose contents it follows when the asset is edited.
+	#[serde(default)]
+	pub asset: Option<NodeAssetReference>,
+}
+
+/// Links an instance of a node asset to the asset in the library, along with the version of the asset the instance was last updated to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NodeAssetReference {
+	pub id: u64,
+	pub version: u32,
 }
 
 impl Default for DocumentNodePersistentMetadata {
 	fn default() -> Self {
 		DocumentNodePersistentMetadata {
 			reference: None,
+			definition_version: 0,
 			display_name: String::new(),
 			input_properties: Vec::new(),
 			output_names: Vec::new(),
@@ -6331,6 +6799,7 @@ impl Default for DocumentNodePersistentMetadata {
 			locked: false,
 			node_type_metadata: NodeTypePersistentMetadata::default(),
 			network_metadata: None,
+			asset: None,
 		}
 	}
 }
@@ -6370,6 +6839,7 @@ impl From<DocumentNodePersistentMetadataInputNames> for DocumentNodePersistentMe
 
 		DocumentNodePersistentMetadata {
 			reference: old.reference,
+			definition_version: 0,
 			display_name: old.display_name,
 			input_properties,
 			output_names: old.output_names,
@@ -6378,6 +6848,7 @@ impl From<DocumentNodePersistentMetadataInputNames> for DocumentNodePersistentMe
 			pinned: old.pinned,
 			node_type_metadata: old.node_type_metadata,
 			network_metadata: old.network_metadata,
+			asset: None,
 		}
 	}
 }
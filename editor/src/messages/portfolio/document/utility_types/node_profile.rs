@@ -0,0 +1,114 @@
+use graph_craft::document::NodeId;
+use interpreted_executor::node_timing::PathTiming;
+use std::collections::HashMap;
+
+/// Whether a node was evaluated in a render, or its output was taken from a cache instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum CacheStatus {
+	/// Every part of the node was evaluated.
+	Evaluated,
+	/// Some parts of the node were evaluated while others had their output reused, such as because of a cache inside the node.
+	PartlyCached,
+	/// None of the node was evaluated, since the output of the node or of a node downstream of it was reused.
+	Cached,
+}
+
+impl CacheStatus {
+	pub fn label(&self) -> &'static str {
+		match self {
+			Self::Evaluated => "Evaluated",
+			Self::PartlyCached => "Partly Cached",
+			Self::Cached => "Cached",
+		}
+	}
+}
+
+/// How a node of the graph performed in the most recent render, counting the nodes inside its network as part of it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NodeProfileEntry {
+	pub milliseconds: f64,
+	/// The number of times the node, or the nodes inside it, were evaluated
+	pub evaluations: u64,
+	pub status: CacheStatus,
+}
+
+/// The time spent in each node of the graph in the most recent render of the viewport, used by the profiler.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeProfile {
+	timings: HashMap<Box<[NodeId]>, PathTiming>,
+}
+
+impl NodeProfile {
+	pub fn record_render(&mut self, node_timings: &HashMap<Box<[NodeId]>, PathTiming>) {
+		self.timings.clone_from(node_timings);
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.timings.is_empty()
+	}
+
+	/// The profile of each node in the network at the given path which was part of the most recent render.
+	pub fn network_profile(&self, network_path: &[NodeId]) -> HashMap<NodeId, NodeProfileEntry> {
+		let mut network_timings = HashMap::<NodeId, PathTiming>::new();
+		for (path, timing) in &self.timings {
+			let Some(node_path) = path.strip_prefix(network_path) else { continue };
+			let Some(&node_id) = node_path.first() else { continue };
+
+			let node_timing = network_timings.entry(node_id).or_default();
+			node_timing.duration += timing.duration;
+			node_timing.evaluations += timing.evaluations;
+			node_timing.proto_nodes += timing.proto_nodes;
+			node_timing.evaluated_proto_nodes += timing.evaluated_proto_nodes;
+		}
+
+		network_timings
+			.into_iter()
+			.map(|(node_id, timing)| {
+				let status = match timing.evaluated_proto_nodes {
+					0 => CacheStatus::Cached,
+					evaluated if evaluated < timing.proto_nodes => CacheStatus::PartlyCached,
+					_ => CacheStatus::Evaluated,
+				};
+				let entry = NodeProfileEntry {
+					milliseconds: timing.duration.as_secs_f64() * 1000.,
+					evaluations: timing.evaluations,
+					status,
+				};
+				(node_id, entry)
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+
+	#[test]
+	fn nested_nodes_count_towards_their_parent() {
+		let timing = |milliseconds: u64, evaluations: u64| {
+			let mut timing = PathTiming::default();
+			timing.add(Duration::from_millis(milliseconds), evaluations);
+			timing
+		};
+		let timings = [
+			(vec![NodeId(1)], timing(2, 1)),
+			(vec![NodeId(1), NodeId(10)], timing(3, 1)),
+			(vec![NodeId(1), NodeId(11)], timing(0, 0)),
+			(vec![NodeId(2)], timing(0, 0)),
+		];
+		let mut profile = NodeProfile::default();
+		profile.record_render(&timings.into_iter().map(|(path, timing)| (path.into_boxed_slice(), timing)).collect());
+
+		let network = profile.network_profile(&[]);
+		assert_eq!(network[&NodeId(1)].milliseconds, 5.);
+		assert_eq!(network[&NodeId(1)].evaluations, 2);
+		assert_eq!(network[&NodeId(1)].status, CacheStatus::PartlyCached);
+		assert_eq!(network[&NodeId(2)].status, CacheStatus::Cached);
+
+		let nested = profile.network_profile(&[NodeId(1)]);
+		assert_eq!(nested.len(), 2);
+		assert_eq!(nested[&NodeId(10)].status, CacheStatus::Evaluated);
+	}
+}
@@ -40,6 +40,7 @@ pub struct LayerPanelEntry {
 	pub children_allowed: bool,
 	#[serde(rename = "childrenPresent")]
 	pub children_present: bool,
+	pub adjustment: bool,
 	pub expanded: bool,
 	pub depth: usize,
 	pub visible: bool,
@@ -55,6 +56,11 @@ pub struct LayerPanelEntry {
 	pub ancestor_of_selected: bool,
 	#[serde(rename = "descendantOfSelected")]
 	pub descendant_of_selected: bool,
+	/// Shown on its own while every other layer is temporarily hidden.
+	pub soloed: bool,
+	/// Temporarily hidden because another layer is soloed.
+	#[serde(rename = "hiddenBySolo")]
+	pub hidden_by_solo: bool,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, PartialEq, Eq, specta::Type)]
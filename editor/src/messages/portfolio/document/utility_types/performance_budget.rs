@@ -0,0 +1,158 @@
+use crate::consts::{DEFAULT_PERFORMANCE_BUDGET_MILLISECONDS, PERFORMANCE_BUDGET_RENDERS};
+use graph_craft::document::NodeId;
+use interpreted_executor::node_timing::PathTiming;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// The time each node of the document network may take to evaluate in a render, and the nodes which have consistently taken longer than that in the recent renders.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct PerformanceBudget {
+	/// The time in milliseconds a node may spend evaluating in each render before it's reported as slow.
+	milliseconds: f64,
+	/// The time in milliseconds spent in each node of the document network in the most recent renders, oldest first.
+	/// The nodes inside a node's network are counted as part of that node.
+	#[serde(skip)]
+	recent_renders: HashMap<NodeId, VecDeque<f64>>,
+	/// The nodes which exceeded the budget in every one of the recent renders, and haven't stayed within it in every one since.
+	#[serde(skip)]
+	slow_nodes: HashSet<NodeId>,
+	/// The time in milliseconds spent evaluating all the nodes in the most recent render.
+	#[serde(skip)]
+	render_milliseconds: f64,
+}
+
+impl Default for PerformanceBudget {
+	fn default() -> Self {
+		Self {
+			milliseconds: DEFAULT_PERFORMANCE_BUDGET_MILLISECONDS,
+			recent_renders: HashMap::new(),
+			slow_nodes: HashSet::new(),
+			render_milliseconds: 0.,
+		}
+	}
+}
+
+impl PerformanceBudget {
+	pub fn milliseconds(&self) -> f64 {
+		self.milliseconds
+	}
+
+	pub fn set_milliseconds(&mut self, milliseconds: f64) {
+		self.milliseconds = milliseconds;
+
+		let node_ids = self.recent_renders.keys().copied().collect::<Vec<_>>();
+		for node_id in node_ids {
+			self.update_slow_node(node_id);
+		}
+	}
+
+	pub fn render_milliseconds(&self) -> f64 {
+		self.render_milliseconds
+	}
+
+	/// Records the time spent evaluating the proto nodes in a render, given by their document node paths, against the document network node each path starts from.
+	/// The nodes which weren't evaluated, such as because their cached output was used, count as taking no time.
+	pub fn record_render(&mut self, node_timings: &HashMap<Box<[NodeId]>, PathTiming>) {
+		let mut render = HashMap::<NodeId, f64>::new();
+		for (path, timing) in node_timings {
+			let Some(&node_id) = path.first() else { continue };
+			if timing.evaluations > 0 {
+				*render.entry(node_id).or_default() += timing.duration.as_secs_f64() * 1000.;
+			}
+		}
+		self.render_milliseconds = render.values().sum();
+
+		for node_id in self.recent_renders.keys().chain(render.keys()).copied().collect::<HashSet<_>>() {
+			let renders = self.recent_renders.entry(node_id).or_default();
+			renders.push_back(render.get(&node_id).copied().unwrap_or_default());
+			if renders.len() > PERFORMANCE_BUDGET_RENDERS {
+				renders.pop_front();
+			}
+			self.update_slow_node(node_id);
+		}
+
+		// Forget the nodes which are no longer evaluated at all, which includes the nodes that were deleted
+		self.recent_renders.retain(|_, renders| renders.iter().any(|&milliseconds| milliseconds > 0.));
+	}
+
+	/// The nodes which have consistently exceeded the budget, with the average time in milliseconds they took in the recent renders, slowest first.
+	pub fn slow_nodes(&self) -> Vec<(NodeId, f64)> {
+		let mut slow_nodes = self
+			.slow_nodes
+			.iter()
+			.filter_map(|node_id| {
+				let renders = self.recent_renders.get(node_id)?;
+				Some((*node_id, renders.iter().sum::<f64>() / renders.len() as f64))
+			})
+			.collect::<Vec<_>>();
+		slow_nodes.sort_by(|(a_id, a), (b_id, b)| b.total_cmp(a).then(a_id.cmp(b_id)));
+		slow_nodes
+	}
+
+	fn update_slow_node(&mut self, node_id: NodeId) {
+		let Some(renders) = self.recent_renders.get(&node_id) else { return };
+		if renders.len() < PERFORMANCE_BUDGET_RENDERS {
+			return;
+		}
+
+		if renders.iter().all(|&milliseconds| milliseconds > self.milliseconds) {
+			self.slow_nodes.insert(node_id);
+		} else if renders.iter().all(|&milliseconds| milliseconds <= self.milliseconds) {
+			self.slow_nodes.remove(&node_id);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+
+	fn render(budget: &mut PerformanceBudget, timings: &[(&[NodeId], u64)]) {
+		let timings = timings
+			.iter()
+			.map(|(path, milliseconds)| {
+				let mut timing = PathTiming::default();
+				timing.add(Duration::from_millis(*milliseconds), 1);
+				(path.to_vec().into_boxed_slice(), timing)
+			})
+			.collect();
+		budget.record_render(&timings);
+	}
+
+	#[test]
+	fn nodes_are_slow_once_consistently_over_budget() {
+		let mut budget = PerformanceBudget::default();
+		let slow = [NodeId(1), NodeId(10)];
+		let fast = [NodeId(2)];
+
+		for _ in 0..PERFORMANCE_BUDGET_RENDERS - 1 {
+			render(&mut budget, &[(&slow, 30), (&fast, 1)]);
+		}
+		assert!(budget.slow_nodes().is_empty(), "A node shouldn't be reported until it's been slow for enough renders");
+
+		render(&mut budget, &[(&slow, 30), (&fast, 1)]);
+		assert_eq!(budget.slow_nodes(), vec![(NodeId(1), 30.)]);
+
+		// A single fast render isn't enough to clear the warning
+		render(&mut budget, &[(&slow, 1)]);
+		assert_eq!(budget.slow_nodes().len(), 1);
+
+		for _ in 0..PERFORMANCE_BUDGET_RENDERS {
+			render(&mut budget, &[]);
+		}
+		assert!(budget.slow_nodes().is_empty(), "A node which is no longer evaluated shouldn't be reported");
+	}
+
+	#[test]
+	fn raising_the_budget_clears_slow_nodes() {
+		let mut budget = PerformanceBudget::default();
+		for _ in 0..PERFORMANCE_BUDGET_RENDERS {
+			render(&mut budget, &[(&[NodeId(1)], 30)]);
+		}
+		assert_eq!(budget.slow_nodes().len(), 1);
+
+		budget.set_milliseconds(50.);
+		assert!(budget.slow_nodes().is_empty());
+	}
+}
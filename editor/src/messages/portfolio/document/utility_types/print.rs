@@ -0,0 +1,301 @@
+use super::misc::LengthUnit;
+use graphene_core::raster::cmyk::PrintProfile;
+use graphene_core::raster::color::Color;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Length of each crop and registration mark, in points.
+const MARK_LENGTH: f64 = 18.;
+/// Gap between the outer edge of the bleed and the start of the marks, so trimming inaccuracies don't catch them, in points.
+const MARK_OFFSET: f64 = 6.;
+/// Stroke width of the marks, which is the customary hairline of a quarter point.
+const MARK_STROKE: f64 = 0.25;
+/// Radius of the circle in each registration mark, in points.
+const REGISTRATION_RADIUS: f64 = 6.;
+/// How far the sheet extends beyond the bleed to make room for the marks, in points.
+const SLUG: f64 = MARK_OFFSET + MARK_LENGTH + 6.;
+
+/// The printer's marks and color separation of a PDF exported for print.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct PrintSettings {
+	/// Draw lines outside each corner of the page showing where the sheet is trimmed.
+	#[serde(rename = "cropMarks")]
+	pub crop_marks: bool,
+	/// Draw targets outside the middle of each side, printed in every ink, for lining up the printing plates.
+	#[serde(rename = "registrationMarks")]
+	pub registration_marks: bool,
+	/// The printing condition the artwork is separated into CMYK inks for, or `None` to keep it in RGB.
+	#[serde(rename = "printProfile")]
+	pub print_profile: Option<PrintProfile>,
+}
+
+impl Default for PrintSettings {
+	fn default() -> Self {
+		Self {
+			crop_marks: true,
+			registration_marks: true,
+			print_profile: None,
+		}
+	}
+}
+
+impl PrintSettings {
+	fn has_marks(&self) -> bool {
+		self.crop_marks || self.registration_marks
+	}
+}
+
+/// An export to be laid out as a print-ready PDF page once the frontend has rasterized its artwork.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct PrintJob {
+	pub settings: PrintSettings,
+	/// Width of the bleed included around the edges of the artwork, in document pixels.
+	pub bleed: f64,
+	/// Number of rasterized pixels per document pixel.
+	#[serde(rename = "scaleFactor")]
+	pub scale_factor: f64,
+}
+
+/// Writes a single-page PDF of the rasterized artwork (RGBA pixels with gamma-encoded sRGB channels), sized to its dimensions in the document,
+/// with a trim box inset by the bleed and the printer's marks drawn around it in a slug area outside the bleed.
+///
+/// The image data is hex-encoded so the whole file is ASCII text.
+pub fn print_pdf(pixels: &[u8], width: u32, height: u32, job: &PrintJob) -> String {
+	let PrintJob { settings, bleed, scale_factor } = *job;
+	let points_per_pixel = 1. / LengthUnit::Point.pixels_per_unit();
+	let scale_factor = if scale_factor > 0. { scale_factor } else { 1. };
+
+	let artwork_width = width as f64 / scale_factor * points_per_pixel;
+	let artwork_height = height as f64 / scale_factor * points_per_pixel;
+	let bleed = (bleed * points_per_pixel).clamp(0., artwork_width.min(artwork_height) / 2.);
+	let slug = if settings.has_marks() { SLUG } else { 0. };
+
+	let sheet = [artwork_width + slug * 2., artwork_height + slug * 2.];
+	let bleed_box = [slug, slug, slug + artwork_width, slug + artwork_height];
+	let trim_box = [bleed_box[0] + bleed, bleed_box[1] + bleed, bleed_box[2] - bleed, bleed_box[3] - bleed];
+
+	// Image
+	let (color_space, samples) = match settings.print_profile {
+		Some(print_profile) => ("DeviceCMYK", separate_pixels(pixels, print_profile)),
+		None => ("DeviceRGB", flatten_pixels(pixels)),
+	};
+	let image_data = hex_encode(&samples);
+	let image = format!(
+		"<< /Type /XObject /Subtype /Image /Width {width} /Height {height} /ColorSpace /{color_space} /BitsPerComponent 8 /Filter /ASCIIHexDecode /Length {} >>\nstream\n{image_data}\nendstream",
+		image_data.len()
+	);
+
+	// Page contents
+	let mut content = format!("q\n{} 0 0 {} {} {} cm\n/Im0 Do\nQ\n", number(artwork_width), number(artwork_height), number(slug), number(slug));
+	if settings.has_marks() {
+		// Marks are drawn in the registration color, which prints on every plate
+		let registration_color = if settings.print_profile.is_some() { "1 1 1 1 K" } else { "0 G" };
+		let _ = writeln!(content, "q\n{registration_color}\n{} w", number(MARK_STROKE));
+		if settings.crop_marks {
+			write_crop_marks(&mut content, trim_box, bleed);
+		}
+		if settings.registration_marks {
+			write_registration_marks(&mut content, trim_box, bleed);
+		}
+		content.push_str("Q\n");
+	}
+	let contents = format!("<< /Length {} >>\nstream\n{content}endstream", content.len());
+
+	let page = format!(
+		"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /BleedBox {} /TrimBox {} /Resources << /XObject << /Im0 4 0 R >> >> /Contents 5 0 R >>",
+		number(sheet[0]),
+		number(sheet[1]),
+		rectangle(bleed_box),
+		rectangle(trim_box),
+	);
+	let objects = [
+		"<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+		"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+		page,
+		image,
+		contents,
+	];
+
+	// Assemble the file, recording where each object starts for the cross-reference table
+	let mut pdf = String::from("%PDF-1.4\n");
+	let mut offsets = Vec::with_capacity(objects.len());
+	for (index, object) in objects.iter().enumerate() {
+		offsets.push(pdf.len());
+		let _ = write!(pdf, "{} 0 obj\n{object}\nendobj\n", index + 1);
+	}
+	let xref_offset = pdf.len();
+	let _ = write!(pdf, "xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+	for offset in offsets {
+		let _ = write!(pdf, "{offset:010} 00000 n \n");
+	}
+	let _ = write!(pdf, "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n", objects.len() + 1);
+
+	pdf
+}
+
+/// Converts RGBA pixels to RGB samples, compositing any transparency onto white paper.
+fn flatten_pixels(pixels: &[u8]) -> Vec<u8> {
+	pixels.chunks_exact(4).flat_map(|pixel| on_white(pixel)).collect()
+}
+
+/// Converts RGBA pixels to CMYK samples separated for the printing condition, compositing any transparency onto white paper.
+fn separate_pixels(pixels: &[u8], print_profile: PrintProfile) -> Vec<u8> {
+	// Artwork tends to reuse a limited set of colors, so each is only separated once
+	let mut separations = HashMap::new();
+
+	pixels
+		.chunks_exact(4)
+		.flat_map(|pixel| {
+			*separations.entry(on_white(pixel)).or_insert_with_key(|&[red, green, blue]| {
+				let cmyk = print_profile.separate(Color::from_rgb8_srgb(red, green, blue));
+				cmyk.to_array().map(|coverage| (coverage.clamp(0., 1.) * 255.).round() as u8)
+			})
+		})
+		.collect()
+}
+
+fn on_white(pixel: &[u8]) -> [u8; 3] {
+	let alpha = pixel[3] as u32;
+	[0, 1, 2].map(|channel| ((pixel[channel] as u32 * alpha + 255 * (255 - alpha)) / 255) as u8)
+}
+
+fn hex_encode(samples: &[u8]) -> String {
+	const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+	// Lines are kept short since some PDF readers struggle with very long ones
+	const BYTES_PER_LINE: usize = 64;
+
+	let mut encoded = String::with_capacity(samples.len() * 2 + samples.len() / BYTES_PER_LINE + 1);
+	for line in samples.chunks(BYTES_PER_LINE) {
+		for &byte in line {
+			encoded.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+			encoded.push(HEX_DIGITS[(byte & 0xF) as usize] as char);
+		}
+		encoded.push('\n');
+	}
+	encoded.push('>');
+	encoded
+}
+
+/// Draws a horizontal and vertical line beyond each corner of the trim box, in line with its edges, starting outside the bleed.
+fn write_crop_marks(content: &mut String, [left, bottom, right, top]: [f64; 4], bleed: f64) {
+	let start = bleed + MARK_OFFSET;
+	let end = start + MARK_LENGTH;
+
+	for (x, y, outward_x, outward_y) in [(left, bottom, -1., -1.), (right, bottom, 1., -1.), (left, top, -1., 1.), (right, top, 1., 1.)] {
+		line(content, [x + outward_x * start, y], [x + outward_x * end, y]);
+		line(content, [x, y + outward_y * start], [x, y + outward_y * end]);
+	}
+}
+
+/// Draws a circle with a crosshair outside the middle of each side of the trim box, beyond the bleed.
+fn write_registration_marks(content: &mut String, [left, bottom, right, top]: [f64; 4], bleed: f64) {
+	let distance = bleed + MARK_OFFSET + MARK_LENGTH / 2.;
+	let [center_x, center_y] = [(left + right) / 2., (bottom + top) / 2.];
+
+	for [x, y] in [[center_x, bottom - distance], [center_x, top + distance], [left - distance, center_y], [right + distance, center_y]] {
+		let half = MARK_LENGTH / 2.;
+		line(content, [x - half, y], [x + half, y]);
+		line(content, [x, y - half], [x, y + half]);
+		circle(content, [x, y], REGISTRATION_RADIUS);
+	}
+}
+
+fn line(content: &mut String, [start_x, start_y]: [f64; 2], [end_x, end_y]: [f64; 2]) {
+	let _ = writeln!(content, "{} {} m {} {} l S", number(start_x), number(start_y), number(end_x), number(end_y));
+}
+
+/// Strokes a circle approximated by four cubic Bézier curves.
+fn circle(content: &mut String, [x, y]: [f64; 2], radius: f64) {
+	let handle = radius * 0.552_284_75;
+	let _ = writeln!(
+		content,
+		"{} {} m {} {} {} {} {} {} c {} {} {} {} {} {} c {} {} {} {} {} {} c {} {} {} {} {} {} c S",
+		number(x + radius),
+		number(y),
+		number(x + radius),
+		number(y + handle),
+		number(x + handle),
+		number(y + radius),
+		number(x),
+		number(y + radius),
+		number(x - handle),
+		number(y + radius),
+		number(x - radius),
+		number(y + handle),
+		number(x - radius),
+		number(y),
+		number(x - radius),
+		number(y - handle),
+		number(x - handle),
+		number(y - radius),
+		number(x),
+		number(y - radius),
+		number(x + handle),
+		number(y - radius),
+		number(x + radius),
+		number(y - handle),
+		number(x + radius),
+		number(y),
+	);
+}
+
+fn rectangle([left, bottom, right, top]: [f64; 4]) -> String {
+	format!("[{} {} {} {}]", number(left), number(bottom), number(right), number(top))
+}
+
+/// Formats a number for PDF syntax, which doesn't accept exponents, rounded to a thousandth of a point.
+fn number(value: f64) -> String {
+	let rounded = (value * 1000.).round() / 1000.;
+	if rounded == 0. { "0".to_string() } else { rounded.to_string() }
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn job(bleed: f64, settings: PrintSettings) -> PrintJob {
+		PrintJob { settings, bleed, scale_factor: 1. }
+	}
+
+	#[test]
+	fn trim_box_is_inset_by_the_bleed() {
+		// A 96x96 pixel page is one inch, or 72 points, square
+		let pixels = vec![255; 96 * 96 * 4];
+		let settings = PrintSettings {
+			crop_marks: false,
+			registration_marks: false,
+			print_profile: None,
+		};
+		let pdf = print_pdf(&pixels, 96, 96, &job(12., settings));
+
+		assert!(pdf.contains("/MediaBox [0 0 72 72]"));
+		assert!(pdf.contains("/BleedBox [0 0 72 72]"));
+		assert!(pdf.contains("/TrimBox [9 9 63 63]"));
+		assert!(pdf.contains("/ColorSpace /DeviceRGB"));
+	}
+
+	#[test]
+	fn cross_reference_table_points_at_objects() {
+		let pixels = [0, 0, 0, 255, 255, 0, 0, 128];
+		let settings = PrintSettings {
+			print_profile: Some(PrintProfile::Coated),
+			..Default::default()
+		};
+		let pdf = print_pdf(&pixels, 2, 1, &job(0., settings));
+
+		assert!(pdf.contains("/ColorSpace /DeviceCMYK"));
+		assert!(pdf.contains("1 1 1 1 K"));
+
+		let xref = pdf.find("xref\n").unwrap();
+		let offsets = pdf[xref..].lines().skip(3).take(5).map(|line| line[..10].parse::<usize>().unwrap());
+		for (index, offset) in offsets.enumerate() {
+			assert!(pdf[offset..].starts_with(&format!("{} 0 obj", index + 1)));
+		}
+	}
+
+	#[test]
+	fn transparency_is_composited_onto_white() {
+		assert_eq!(on_white(&[0, 0, 0, 0]), [255, 255, 255]);
+		assert_eq!(on_white(&[0, 100, 200, 255]), [0, 100, 200]);
+	}
+}
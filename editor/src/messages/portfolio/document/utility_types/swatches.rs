@@ -0,0 +1,95 @@
+use graph_craft::document::NodeNetwork;
+use graph_craft::document::value::TaggedValue;
+use graphene_core::Color;
+use graphene_core::vector::style::Fill;
+
+/// A named color saved with the document so it can be reused throughout the artwork.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct Swatch {
+	pub name: String,
+	pub color: Color,
+}
+
+impl Swatch {
+	pub fn new(name: impl Into<String>, color: Color) -> Self {
+		Self { name: name.into(), color }
+	}
+
+	/// Whether the swatch holds the same color once both are quantized to 8 bits per channel, which is as precisely as colors are shown and typed in.
+	pub fn matches(&self, color: Color) -> bool {
+		colors_match(self.color, color)
+	}
+}
+
+/// Whether the colors are the same once both are quantized to 8 bits per channel.
+pub fn colors_match(a: Color, b: Color) -> bool {
+	quantized(a) == quantized(b)
+}
+
+fn quantized(color: Color) -> [u8; 4] {
+	[color.r(), color.g(), color.b(), color.a()].map(|channel| (channel * 255.).round() as u8)
+}
+
+/// Collects the colors of every fill, stroke, and color value given to the node inputs of the network, including those of nested networks.
+/// A color is listed once for each input it's used by.
+pub fn network_colors(network: &NodeNetwork) -> Vec<Color> {
+	let mut colors = Vec::new();
+	for (_, node) in network.recursive_nodes() {
+		for tagged_value in node.inputs.iter().filter_map(|input| input.as_value()) {
+			match tagged_value {
+				TaggedValue::Color(color) | TaggedValue::OptionalColor(Some(color)) => colors.push(*color),
+				TaggedValue::Fill(Fill::Solid(color)) => colors.push(*color),
+				TaggedValue::Fill(Fill::Gradient(gradient)) => colors.extend(gradient.stops.iter().map(|(_, color)| *color)),
+				TaggedValue::Gradient(gradient) => colors.extend(gradient.stops.iter().map(|(_, color)| *color)),
+				TaggedValue::GradientStops(stops) => colors.extend(stops.iter().map(|(_, color)| *color)),
+				TaggedValue::Stroke(stroke) => colors.extend(stroke.color),
+				_ => {}
+			}
+		}
+	}
+	colors
+}
+
+/// The given number of colors which occur most often, most used first, with ties listed in the order they first occur.
+/// Colors are counted as the same if they're equal at 8 bits per channel, and fully transparent colors are skipped.
+pub fn most_used_colors(colors: impl IntoIterator<Item = Color>, count: usize) -> Vec<Color> {
+	let mut tallies: Vec<(Color, usize)> = Vec::new();
+	for color in colors.into_iter().filter(|color| color.a() > 0.) {
+		match tallies.iter_mut().find(|(tallied, _)| quantized(*tallied) == quantized(color)) {
+			Some((_, uses)) => *uses += 1,
+			None => tallies.push((color, 1)),
+		}
+	}
+
+	// The sort is stable, so equally used colors keep the order they were first found in
+	tallies.sort_by(|(_, a), (_, b)| b.cmp(a));
+	tallies.into_iter().take(count).map(|(color, _)| color).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn most_used_colors_are_ranked_by_uses() {
+		let colors = [
+			Color::RED,
+			Color::BLUE,
+			Color::TRANSPARENT,
+			Color::BLUE,
+			Color::GREEN,
+			Color::TRANSPARENT,
+			Color::TRANSPARENT,
+			Color::GREEN,
+			Color::BLUE,
+		];
+		assert_eq!(most_used_colors(colors, 2), [Color::BLUE, Color::GREEN]);
+		assert_eq!(most_used_colors(colors, 10), [Color::BLUE, Color::GREEN, Color::RED]);
+	}
+
+	#[test]
+	fn nearly_equal_colors_are_counted_together() {
+		let almost_red = Color::from_rgbaf32_unchecked(0.999_99, 0., 0., 1.);
+		assert_eq!(most_used_colors([Color::BLUE, almost_red, Color::RED], 1), [almost_red]);
+	}
+}
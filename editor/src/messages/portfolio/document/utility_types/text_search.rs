@@ -0,0 +1,103 @@
+use std::ops::Range;
+
+/// What the Find and Replace dialog looks for in the text of text layers.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TextSearch {
+	pub query: String,
+	/// Only matches text with the same capitalization as the query.
+	pub match_case: bool,
+	/// Only matches the query where it isn't part of a longer word.
+	pub whole_word: bool,
+}
+
+impl TextSearch {
+	/// The byte ranges of the non-overlapping occurrences of the query in the text, from start to end.
+	pub fn find_matches(&self, text: &str) -> Vec<Range<usize>> {
+		let mut matches = Vec::new();
+		if self.query.is_empty() {
+			return matches;
+		}
+
+		let mut search_from = 0;
+		while search_from < text.len() {
+			let Some(end) = self.match_end(&text[search_from..]).map(|length| search_from + length) else {
+				search_from += text[search_from..].chars().next().map_or(1, char::len_utf8);
+				continue;
+			};
+
+			let is_word_boundary = |character: Option<char>| !character.is_some_and(|character| character.is_alphanumeric() || character == '_');
+			if !self.whole_word || (is_word_boundary(text[..search_from].chars().next_back()) && is_word_boundary(text[end..].chars().next())) {
+				matches.push(search_from..end);
+				search_from = end;
+			} else {
+				search_from += text[search_from..].chars().next().map_or(1, char::len_utf8);
+			}
+		}
+
+		matches
+	}
+
+	/// The text with every occurrence of the query swapped for the replacement.
+	pub fn replace_all(&self, text: &str, replacement: &str) -> String {
+		let mut replaced = String::with_capacity(text.len());
+		let mut last_end = 0;
+		for range in self.find_matches(text) {
+			replaced.push_str(&text[last_end..range.start]);
+			replaced.push_str(replacement);
+			last_end = range.end;
+		}
+		replaced.push_str(&text[last_end..]);
+		replaced
+	}
+
+	/// The length in bytes of the query if the text starts with it, compared a character at a time so case folding can't change where characters begin.
+	fn match_end(&self, text: &str) -> Option<usize> {
+		let mut text_characters = text.char_indices();
+		for query_character in self.query.chars() {
+			let (_, text_character) = text_characters.next()?;
+			let equal = if self.match_case {
+				query_character == text_character
+			} else {
+				query_character.to_lowercase().eq(text_character.to_lowercase())
+			};
+			if !equal {
+				return None;
+			}
+		}
+		Some(text_characters.next().map_or(text.len(), |(index, _)| index))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn search(query: &str, match_case: bool, whole_word: bool) -> TextSearch {
+		TextSearch {
+			query: query.to_string(),
+			match_case,
+			whole_word,
+		}
+	}
+
+	#[test]
+	fn case_is_ignored_unless_matched() {
+		let text = "Graphite graphite GRAPHITE";
+		assert_eq!(search("graphite", false, false).find_matches(text), [0..8, 9..17, 18..26]);
+		assert_eq!(search("graphite", true, false).find_matches(text), [9..17]);
+	}
+
+	#[test]
+	fn whole_words_are_not_matched_inside_longer_words() {
+		let text = "cat catalog bobcat cat_2 cat.";
+		assert_eq!(search("cat", false, true).find_matches(text), [0..3, 25..28]);
+		assert_eq!(search("cat", false, false).find_matches(text).len(), 5);
+	}
+
+	#[test]
+	fn replacements_keep_the_surrounding_text() {
+		assert_eq!(search("ö", false, false).replace_all("Über Öl, öfter", "o"), "Über ol, ofter");
+		assert_eq!(search("aa", false, false).replace_all("aaa", "b"), "ba");
+		assert_eq!(search("", false, false).replace_all("unchanged", "x"), "unchanged");
+	}
+}
@@ -0,0 +1,36 @@
+use crate::messages::prelude::*;
+
+/// The Find in Graph panel searches the nodes of the graph by name, by type, and by the text of their inputs, and replaces that text across the matches.
+#[impl_message(Message, PortfolioMessage, GraphFindPanel)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum GraphFindPanelMessage {
+	ToggleOpen,
+
+	UpdateLayout,
+
+	SetQuery {
+		query: String,
+	},
+	SetReplacement {
+		replacement: String,
+	},
+	SetMatchCase {
+		match_case: bool,
+	},
+	SetWholeWord {
+		whole_word: bool,
+	},
+
+	/// Selects the matching node at this index in the list of matches and focuses the graph on it.
+	JumpToMatch {
+		index: usize,
+	},
+	/// Jumps to the match after the current one, or before it if `forward` is false, wrapping around at the ends of the list.
+	StepMatch {
+		forward: bool,
+	},
+	/// Selects every matching node, highlighting them all in the graph.
+	SelectAllMatches,
+	/// Replaces the query with the replacement in the text inputs of every matching node, including the family of font inputs.
+	ReplaceAll,
+}
@@ -0,0 +1,293 @@
+use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
+use crate::messages::portfolio::document::utility_types::network_interface::{InputConnector, NodeNetworkInterface};
+use crate::messages::portfolio::document::utility_types::text_search::TextSearch;
+use crate::messages::prelude::*;
+use crate::messages::tool::tool_messages::tool_prelude::*;
+use graph_craft::document::value::TaggedValue;
+use graph_craft::document::{NodeId, NodeInput};
+use graphene_core::text::Font;
+
+pub struct GraphFindPanelMessageData<'a> {
+	pub document: Option<&'a DocumentMessageHandler>,
+}
+
+/// The Find in Graph panel lists the nodes of the graph whose name, type, or text inputs contain the searched text, and replaces that text in their inputs.
+#[derive(Debug, Clone, Default)]
+pub struct GraphFindPanelMessageHandler {
+	/// Sets whether or not the Find in Graph panel is shown.
+	pub graph_find_panel_open: bool,
+	search: TextSearch,
+	replacement: String,
+	/// The index of the match which was last jumped to, which the previous and next buttons step from.
+	current: usize,
+}
+
+/// A node of the graph where the searched text was found.
+struct GraphMatch {
+	node_id: NodeId,
+	name: String,
+	/// Descriptions of where in the node the text was found, like "Type" or "Font".
+	places: Vec<String>,
+	/// The text inputs of the node containing the text, which are changed when it's replaced.
+	inputs: Vec<usize>,
+}
+
+impl MessageHandler<GraphFindPanelMessage, GraphFindPanelMessageData<'_>> for GraphFindPanelMessageHandler {
+	fn process_message(&mut self, message: GraphFindPanelMessage, responses: &mut VecDeque<Message>, data: GraphFindPanelMessageData) {
+		let GraphFindPanelMessageData { document } = data;
+
+		match message {
+			GraphFindPanelMessage::ToggleOpen => {
+				self.graph_find_panel_open = !self.graph_find_panel_open;
+
+				// Update checked UI state for open
+				responses.add(MenuBarMessage::SendLayout);
+			}
+			GraphFindPanelMessage::UpdateLayout => {}
+			GraphFindPanelMessage::SetQuery { query } => {
+				self.search.query = query;
+				self.current = 0;
+			}
+			GraphFindPanelMessage::SetReplacement { replacement } => self.replacement = replacement,
+			GraphFindPanelMessage::SetMatchCase { match_case } => {
+				self.search.match_case = match_case;
+				self.current = 0;
+			}
+			GraphFindPanelMessage::SetWholeWord { whole_word } => {
+				self.search.whole_word = whole_word;
+				self.current = 0;
+			}
+			GraphFindPanelMessage::JumpToMatch { index } => {
+				let Some(document) = document else { return };
+				let matches = self.find_matches(&document.network_interface, document.selection_network_path());
+				let Some(graph_match) = matches.get(index) else { return };
+
+				self.current = index;
+				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: vec![graph_match.node_id] });
+				responses.add(DocumentMessage::GraphViewOverlay { open: true });
+				responses.add(NavigationMessage::FitViewportToSelection);
+			}
+			GraphFindPanelMessage::StepMatch { forward } => {
+				let Some(document) = document else { return };
+				let count = self.find_matches(&document.network_interface, document.selection_network_path()).len();
+				if count == 0 {
+					return;
+				}
+
+				let index = if forward { (self.current + 1) % count } else { (self.current + count - 1) % count };
+				responses.add(GraphFindPanelMessage::JumpToMatch { index });
+			}
+			GraphFindPanelMessage::SelectAllMatches => {
+				let Some(document) = document else { return };
+				let nodes = self
+					.find_matches(&document.network_interface, document.selection_network_path())
+					.iter()
+					.map(|graph_match| graph_match.node_id)
+					.collect::<Vec<_>>();
+				if nodes.is_empty() {
+					return;
+				}
+
+				responses.add(NodeGraphMessage::SelectedNodesSet { nodes });
+				responses.add(DocumentMessage::GraphViewOverlay { open: true });
+			}
+			GraphFindPanelMessage::ReplaceAll => {
+				let Some(document) = document else { return };
+				let network_interface = &document.network_interface;
+				let network_path = document.selection_network_path();
+
+				let mut replacements = Vec::new();
+				for graph_match in self.find_matches(network_interface, network_path) {
+					for &input_index in &graph_match.inputs {
+						let Some(NodeInput::Value { tagged_value, .. }) = network_interface.input_from_connector(&InputConnector::node(graph_match.node_id, input_index), network_path) else {
+							continue;
+						};
+						let replaced = match &**tagged_value {
+							TaggedValue::String(text) => TaggedValue::String(self.search.replace_all(text, &self.replacement)),
+							TaggedValue::Font(font) => TaggedValue::Font(Font {
+								font_family: self.search.replace_all(&font.font_family, &self.replacement),
+								..font.clone()
+							}),
+							_ => continue,
+						};
+						replacements.push((InputConnector::node(graph_match.node_id, input_index), replaced));
+					}
+				}
+				if replacements.is_empty() {
+					return;
+				}
+
+				responses.add(DocumentMessage::AddTransaction);
+				for (input_connector, value) in replacements {
+					if let TaggedValue::Font(font) = &value {
+						responses.add(PortfolioMessage::LoadFont { font: font.clone() });
+					}
+					responses.add(NodeGraphMessage::SetInput {
+						input_connector,
+						input: NodeInput::value(value, false),
+					});
+				}
+				responses.add(NodeGraphMessage::RunDocumentGraph);
+				responses.add(PropertiesPanelMessage::Refresh);
+			}
+		}
+
+		self.update_layout(document, responses);
+	}
+
+	fn actions(&self) -> ActionList {
+		actions!(GraphFindPanelMessage;)
+	}
+}
+
+impl GraphFindPanelMessageHandler {
+	/// The nodes of the network where the searched text is found, ordered by their names.
+	fn find_matches(&self, network_interface: &NodeNetworkInterface, network_path: &[NodeId]) -> Vec<GraphMatch> {
+		let Some(network) = network_interface.nested_network(network_path) else { return Vec::new() };
+		if self.search.query.is_empty() {
+			return Vec::new();
+		}
+		let found = |text: &str| !self.search.find_matches(text).is_empty();
+
+		let mut matches = network
+			.nodes
+			.keys()
+			.filter_map(|&node_id| {
+				let name = network_interface.display_name(&node_id, network_path);
+				let mut places = Vec::new();
+				let mut inputs = Vec::new();
+
+				if found(&name) {
+					places.push("Name".to_string());
+				}
+				if network_interface.reference(&node_id, network_path).cloned().flatten().is_some_and(|reference| found(&reference)) {
+					places.push("Type".to_string());
+				}
+				for input_index in 0..network_interface.number_of_inputs(&node_id, network_path) {
+					let text = match network_interface.input_from_connector(&InputConnector::node(node_id, input_index), network_path) {
+						Some(NodeInput::Value { tagged_value, exposed: false }) => match &**tagged_value {
+							TaggedValue::String(text) => text,
+							TaggedValue::Font(font) => &font.font_family,
+							_ => continue,
+						},
+						_ => continue,
+					};
+					if found(text) {
+						places.push(network_interface.input_name(&node_id, input_index, network_path).unwrap_or_default().to_string());
+						inputs.push(input_index);
+					}
+				}
+
+				(!places.is_empty()).then_some(GraphMatch { node_id, name, places, inputs })
+			})
+			.collect::<Vec<_>>();
+		matches.sort_by(|a, b| a.name.cmp(&b.name).then(a.node_id.cmp(&b.node_id)));
+		matches
+	}
+
+	fn update_layout(&self, document: Option<&DocumentMessageHandler>, responses: &mut VecDeque<Message>) {
+		responses.add(FrontendMessage::UpdateGraphFindPanelState { open: self.graph_find_panel_open });
+		if !self.graph_find_panel_open {
+			return;
+		}
+
+		let matches = document
+			.map(|document| self.find_matches(&document.network_interface, document.selection_network_path()))
+			.unwrap_or_default();
+
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout { layout: self.layout(&matches) }),
+			layout_target: LayoutTarget::GraphFindPanel,
+		});
+	}
+
+	fn layout(&self, matches: &[GraphMatch]) -> Vec<LayoutGroup> {
+		let mut layout = vec![
+			LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new("Find").table_align(true).min_width(100).widget_holder(),
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					TextInput::new(&self.search.query)
+						.tooltip("Find the nodes with this text in their names, their types, or the text of their inputs")
+						.on_update(|text_input: &TextInput| GraphFindPanelMessage::SetQuery { query: text_input.value.clone() }.into())
+						.widget_holder(),
+				],
+			},
+			LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new("Replace With").table_align(true).min_width(100).widget_holder(),
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					TextInput::new(&self.replacement)
+						.tooltip("The text put in place of the found text in the text inputs of the matching nodes, such as their font family")
+						.on_update(|text_input: &TextInput| {
+							GraphFindPanelMessage::SetReplacement {
+								replacement: text_input.value.clone(),
+							}
+							.into()
+						})
+						.widget_holder(),
+				],
+			},
+			LayoutGroup::Row {
+				widgets: vec![
+					CheckboxInput::new(self.search.match_case)
+						.tooltip("Only find text with the same capitalization")
+						.on_update(|checkbox_input: &CheckboxInput| GraphFindPanelMessage::SetMatchCase { match_case: checkbox_input.checked }.into())
+						.widget_holder(),
+					TextLabel::new("Match Case").widget_holder(),
+					Separator::new(SeparatorType::Unrelated).widget_holder(),
+					CheckboxInput::new(self.search.whole_word)
+						.tooltip("Only find text that isn't part of a longer word")
+						.on_update(|checkbox_input: &CheckboxInput| GraphFindPanelMessage::SetWholeWord { whole_word: checkbox_input.checked }.into())
+						.widget_holder(),
+					TextLabel::new("Whole Word").widget_holder(),
+				],
+			},
+		];
+
+		let summary = match matches.len() {
+			_ if self.search.query.is_empty() => "Type the text to search for in the graph".to_string(),
+			0 => "No matches".to_string(),
+			count => format!("{} of {count} matches", self.current.min(count - 1) + 1),
+		};
+		let replaceable = matches.iter().any(|graph_match| !graph_match.inputs.is_empty());
+		layout.push(LayoutGroup::Row {
+			widgets: vec![
+				TextLabel::new(summary).italic(true).widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				TextButton::new("Previous")
+					.disabled(matches.is_empty())
+					.on_update(|_| GraphFindPanelMessage::StepMatch { forward: false }.into())
+					.widget_holder(),
+				TextButton::new("Next")
+					.disabled(matches.is_empty())
+					.on_update(|_| GraphFindPanelMessage::StepMatch { forward: true }.into())
+					.widget_holder(),
+				Separator::new(SeparatorType::Related).widget_holder(),
+				TextButton::new("Select All")
+					.tooltip("Select every matching node in the graph")
+					.disabled(matches.is_empty())
+					.on_update(|_| GraphFindPanelMessage::SelectAllMatches.into())
+					.widget_holder(),
+				TextButton::new("Replace All")
+					.tooltip("Replace the found text in the text inputs of every matching node")
+					.disabled(!replaceable)
+					.on_update(|_| GraphFindPanelMessage::ReplaceAll.into())
+					.widget_holder(),
+			],
+		});
+
+		layout.extend(matches.iter().enumerate().map(|(index, graph_match)| LayoutGroup::Row {
+			widgets: vec![
+				TextButton::new(&graph_match.name)
+					.tooltip("Select this node and focus the graph on it")
+					.on_update(move |_| GraphFindPanelMessage::JumpToMatch { index }.into())
+					.widget_holder(),
+				Separator::new(SeparatorType::Related).widget_holder(),
+				TextLabel::new(graph_match.places.join(", ")).bold(index == self.current).widget_holder(),
+			],
+		}));
+
+		layout
+	}
+}
@@ -0,0 +1,7 @@
+mod graph_find_panel_message;
+mod graph_find_panel_message_handler;
+
+#[doc(inline)]
+pub use graph_find_panel_message::*;
+#[doc(inline)]
+pub use graph_find_panel_message_handler::*;
@@ -0,0 +1,26 @@
+use crate::messages::prelude::*;
+use crate::node_graph_executor::InspectResult;
+
+/// The histogram UI shows the tonal distribution of the raster content of the selected layer, or the whole document.
+#[impl_message(Message, PortfolioMessage, Histogram)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum HistogramMessage {
+	ToggleOpen,
+
+	UpdateLayout {
+		#[serde(skip)]
+		inspect_result: InspectResult,
+	},
+
+	ViewChannels {
+		channels: HistogramChannels,
+	},
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug, serde::Serialize, serde::Deserialize)]
+pub enum HistogramChannels {
+	#[default]
+	All,
+	Colors,
+	Luminance,
+}
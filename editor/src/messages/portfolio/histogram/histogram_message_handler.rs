@@ -0,0 +1,147 @@
+use super::HistogramChannels;
+use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
+use crate::messages::prelude::*;
+use crate::messages::tool::tool_messages::tool_prelude::*;
+use crate::node_graph_executor::introspected_output;
+use graphene_core::raster::color::Color;
+use graphene_core::raster::histogram::Histogram;
+use graphene_core::raster::image::ImageFrameTable;
+use graphene_core::{ArtboardGroupTable, GraphicElement, GraphicGroupTable, RasterFrame};
+use std::any::Any;
+use std::sync::Arc;
+
+/// The maximum number of pixels sampled from each image, which keeps the histogram responsive for large images while adjustments are being made.
+const MAX_SAMPLES_PER_IMAGE: usize = 1 << 16;
+
+/// The histogram UI shows the tonal distribution of the raster content of the selected layer, or the whole document.
+#[derive(Default, Debug, Clone)]
+pub struct HistogramMessageHandler {
+	/// Sets whether or not the histogram is drawn.
+	pub histogram_view_open: bool,
+	histogram: Option<Histogram>,
+	viewing_channels: HistogramChannels,
+}
+
+impl MessageHandler<HistogramMessage, ()> for HistogramMessageHandler {
+	fn process_message(&mut self, message: HistogramMessage, responses: &mut VecDeque<Message>, _data: ()) {
+		match message {
+			HistogramMessage::ToggleOpen => {
+				self.histogram_view_open = !self.histogram_view_open;
+				// Run the graph to grab the data
+				if self.histogram_view_open {
+					responses.add(NodeGraphMessage::RunDocumentGraph);
+				}
+				// Update checked UI state for open
+				responses.add(MenuBarMessage::SendLayout);
+				self.update_layout(responses);
+			}
+
+			HistogramMessage::UpdateLayout { mut inspect_result } => {
+				if !self.histogram_view_open {
+					return;
+				}
+				self.histogram = inspect_result.take_data().as_ref().and_then(compute_histogram);
+				self.update_layout(responses)
+			}
+
+			HistogramMessage::ViewChannels { channels } => {
+				self.viewing_channels = channels;
+				self.update_layout(responses);
+			}
+		}
+	}
+
+	fn actions(&self) -> ActionList {
+		actions!(HistogramMessage;)
+	}
+}
+
+impl HistogramMessageHandler {
+	fn update_layout(&mut self, responses: &mut VecDeque<Message>) {
+		responses.add(FrontendMessage::UpdateHistogramState { open: self.histogram_view_open });
+		if !self.histogram_view_open {
+			return;
+		}
+
+		let entries = [HistogramChannels::All, HistogramChannels::Colors, HistogramChannels::Luminance]
+			.into_iter()
+			.map(|channels| {
+				RadioEntryData::new(format!("{channels:?}"))
+					.label(format!("{channels:?}"))
+					.on_update(move |_| HistogramMessage::ViewChannels { channels }.into())
+			})
+			.collect();
+		let channels = vec![RadioInput::new(entries).selected_index(Some(self.viewing_channels as u32)).widget_holder()];
+
+		let layout = match self.histogram.as_ref().filter(|histogram| histogram.sample_count() > 0) {
+			Some(histogram) => {
+				let show_colors = self.viewing_channels != HistogramChannels::Luminance;
+				let show_luminance = self.viewing_channels != HistogramChannels::Colors;
+				let channel = |show: bool, bins: &[u32]| if show { histogram.normalized(bins) } else { Vec::new() };
+
+				let chart = HistogramLabel::new()
+					.red(channel(show_colors, &histogram.red))
+					.green(channel(show_colors, &histogram.green))
+					.blue(channel(show_colors, &histogram.blue))
+					.luminance(channel(show_luminance, &histogram.luminance))
+					.widget_holder();
+				let samples = TextLabel::new(format!("{} pixels sampled", histogram.sample_count())).italic(true).widget_holder();
+
+				vec![
+					LayoutGroup::Row { widgets: channels },
+					LayoutGroup::Row { widgets: vec![chart] },
+					LayoutGroup::Row { widgets: vec![samples] },
+				]
+			}
+			None => vec![LayoutGroup::Row {
+				widgets: vec![TextLabel::new("No raster data").widget_holder()],
+			}],
+		};
+
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout { layout }),
+			layout_target: LayoutTarget::Histogram,
+		});
+	}
+}
+
+pub(crate) fn compute_histogram(introspected_data: &Arc<dyn Any + Send + Sync + 'static>) -> Option<Histogram> {
+	let mut histogram = Histogram::default();
+
+	// The types which may contain raster data are tried in turn
+	let introspected_data = introspected_data.as_ref();
+	if let Some(image_frames) = introspected_output::<ImageFrameTable<Color>>(introspected_data) {
+		accumulate_image_frames(&mut histogram, image_frames);
+	} else if let Some(graphic_group) = introspected_output::<GraphicGroupTable>(introspected_data) {
+		accumulate_graphic_group(&mut histogram, graphic_group);
+	} else if let Some(artboards) = introspected_output::<ArtboardGroupTable>(introspected_data) {
+		accumulate_artboards(&mut histogram, artboards);
+	} else {
+		return None;
+	}
+
+	Some(histogram)
+}
+
+fn accumulate_image_frames(histogram: &mut Histogram, image_frames: &ImageFrameTable<Color>) {
+	for image_frame in image_frames.instances() {
+		histogram.accumulate(image_frame.instance, MAX_SAMPLES_PER_IMAGE);
+	}
+}
+
+fn accumulate_graphic_group(histogram: &mut Histogram, graphic_group: &GraphicGroupTable) {
+	for element in graphic_group.instances() {
+		match element.instance {
+			GraphicElement::GraphicGroup(graphic_group) => accumulate_graphic_group(histogram, graphic_group),
+			GraphicElement::RasterFrame(RasterFrame::ImageFrame(image_frames)) => accumulate_image_frames(histogram, image_frames),
+			// GPU textures and vector data aren't rasterized for the histogram
+			GraphicElement::RasterFrame(RasterFrame::TextureFrame(_)) | GraphicElement::VectorData(_) => {}
+		}
+	}
+}
+
+fn accumulate_artboards(histogram: &mut Histogram, artboards: &ArtboardGroupTable) {
+	for artboard in artboards.instances() {
+		accumulate_graphic_group(histogram, &artboard.instance.graphic_group);
+	}
+}
@@ -0,0 +1,7 @@
+mod histogram_message;
+mod histogram_message_handler;
+
+#[doc(inline)]
+pub use histogram_message::*;
+#[doc(inline)]
+pub use histogram_message_handler::*;
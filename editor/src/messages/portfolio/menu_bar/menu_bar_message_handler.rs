@@ -2,21 +2,37 @@ use crate::messages::debug::utility_types::MessageLoggingVerbosity;
 use crate::messages::input_mapper::utility_types::macros::action_keys;
 use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::portfolio::document::utility_types::clipboards::Clipboard;
-use crate::messages::portfolio::document::utility_types::misc::{AlignAggregate, AlignAxis, FlipAxis, GroupFolderType};
+use crate::messages::portfolio::document::utility_types::misc::{AlignAggregate, AlignAxis, AlignReference, FlipAxis, GroupFolderType};
 use crate::messages::prelude::*;
+use graphene_core::raster::histogram::AutoAdjustment;
 use graphene_std::vector::misc::BooleanOperation;
 
 #[derive(Debug, Clone, Default)]
 pub struct MenuBarMessageHandler {
 	pub has_active_document: bool,
 	pub rulers_visible: bool,
+	pub view_flipped: bool,
 	pub node_graph_open: bool,
 	pub has_selected_nodes: bool,
 	pub has_selected_layers: bool,
+	pub has_solo_layer: bool,
 	pub has_selection_history: (bool, bool),
 	pub spreadsheet_view_open: bool,
+	pub histogram_view_open: bool,
+	pub align_panel_open: bool,
+	pub navigator_panel_open: bool,
+	pub timeline_panel_open: bool,
+	pub comments_panel_open: bool,
+	pub swatches_panel_open: bool,
+	pub node_assets_panel_open: bool,
+	pub bulk_edit_panel_open: bool,
+	pub graph_find_panel_open: bool,
+	pub diagnostics_panel_open: bool,
+	pub profiler_panel_open: bool,
 	pub message_logging_verbosity: MessageLoggingVerbosity,
 	pub reset_node_definitions_on_open: bool,
+	/// The names of the documents most recently opened from or saved to files, newest first.
+	pub recent_files: Vec<String>,
 }
 
 impl MessageHandler<MenuBarMessage, ()> for MenuBarMessageHandler {
@@ -69,6 +85,31 @@ impl LayoutHolder for MenuBarMessageHandler {
 							action: MenuBarEntry::create_action(|_| PortfolioMessage::OpenDocument.into()),
 							..MenuBarEntry::default()
 						},
+						MenuBarEntry {
+							label: "Open Recent".into(),
+							icon: Some("FolderOpen".into()),
+							action: MenuBarEntry::no_action(),
+							disabled: self.recent_files.is_empty(),
+							children: MenuBarEntryChildren(vec![
+								self.recent_files
+									.iter()
+									.enumerate()
+									.map(|(index, name)| MenuBarEntry {
+										label: name.clone(),
+										icon: Some("File".into()),
+										action: MenuBarEntry::create_action(move |_| PortfolioMessage::OpenRecentFile { index }.into()),
+										..MenuBarEntry::default()
+									})
+									.collect(),
+								vec![MenuBarEntry {
+									label: "Clear Recent".into(),
+									icon: Some("Trash".into()),
+									action: MenuBarEntry::create_action(|_| PortfolioMessage::ClearRecentFiles.into()),
+									..MenuBarEntry::default()
+								}],
+							]),
+							..MenuBarEntry::default()
+						},
 						MenuBarEntry {
 							label: "Open Demo Artwork…".into(),
 							icon: Some("Image".into()),
@@ -94,14 +135,23 @@ impl LayoutHolder for MenuBarMessageHandler {
 							..MenuBarEntry::default()
 						},
 					],
-					vec![MenuBarEntry {
-						label: "Save".into(),
-						icon: Some("Save".into()),
-						shortcut: action_keys!(DocumentMessageDiscriminant::SaveDocument),
-						action: MenuBarEntry::create_action(|_| DocumentMessage::SaveDocument.into()),
-						disabled: no_active_document,
-						..MenuBarEntry::default()
-					}],
+					vec![
+						MenuBarEntry {
+							label: "Save".into(),
+							icon: Some("Save".into()),
+							shortcut: action_keys!(DocumentMessageDiscriminant::SaveDocument),
+							action: MenuBarEntry::create_action(|_| DocumentMessage::SaveDocument.into()),
+							disabled: no_active_document,
+							..MenuBarEntry::default()
+						},
+						MenuBarEntry {
+							label: "Save as Template".into(),
+							icon: Some("File".into()),
+							action: MenuBarEntry::create_action(|_| DocumentMessage::SaveAsTemplate.into()),
+							disabled: no_active_document,
+							..MenuBarEntry::default()
+						},
+					],
 					vec![
 						MenuBarEntry {
 							label: "Import…".into(),
@@ -194,6 +244,22 @@ impl LayoutHolder for MenuBarMessageHandler {
 							..MenuBarEntry::default()
 						},
 					],
+					vec![
+						MenuBarEntry {
+							label: "Find and Replace…".into(),
+							icon: Some("NodeText".into()),
+							shortcut: action_keys!(DialogMessageDiscriminant::RequestFindReplaceDialog),
+							action: MenuBarEntry::create_action(|_| DialogMessage::RequestFindReplaceDialog.into()),
+							disabled: no_active_document,
+							..MenuBarEntry::default()
+						},
+						MenuBarEntry {
+							label: "Replace Font…".into(),
+							action: MenuBarEntry::create_action(|_| DialogMessage::RequestReplaceFontDialog.into()),
+							disabled: no_active_document,
+							..MenuBarEntry::default()
+						},
+					],
 					vec![MenuBarEntry {
 						label: "Convert to Infinite Canvas".into(),
 						icon: Some("Artboard".into()),
@@ -255,6 +321,13 @@ impl LayoutHolder for MenuBarMessageHandler {
 							disabled: no_active_document || !has_selected_layers,
 							..MenuBarEntry::default()
 						},
+						MenuBarEntry {
+							label: "Exit Solo".into(),
+							icon: Some("EyeShow".into()),
+							action: MenuBarEntry::create_action(|_| DocumentMessage::ExitSolo.into()),
+							disabled: no_active_document || !self.has_solo_layer,
+							..MenuBarEntry::default()
+						},
 					],
 					vec![
 						MenuBarEntry {
@@ -360,7 +433,14 @@ impl LayoutHolder for MenuBarMessageHandler {
 											.map(|(axis, aggregate, icon, name)| MenuBarEntry {
 												label: name.into(),
 												icon: Some(icon.into()),
-												action: MenuBarEntry::create_action(move |_| DocumentMessage::AlignSelectedLayers { axis, aggregate }.into()),
+												action: MenuBarEntry::create_action(move |_| {
+													DocumentMessage::AlignSelectedLayers {
+														axis,
+														aggregate,
+														reference: AlignReference::Selection,
+													}
+													.into()
+												}),
 												disabled: no_active_document || !has_selected_layers,
 												..MenuBarEntry::default()
 											})
@@ -408,6 +488,30 @@ impl LayoutHolder for MenuBarMessageHandler {
 							}]),
 							..MenuBarEntry::default()
 						},
+						MenuBarEntry {
+							label: "Transform Each…".into(),
+							action: MenuBarEntry::create_action(|_| DialogMessage::RequestTransformEachDialog.into()),
+							disabled: no_active_document || !has_selected_layers,
+							..MenuBarEntry::default()
+						},
+						MenuBarEntry {
+							label: "Auto Adjust".into(),
+							icon: Some("NodeColorCorrection".into()),
+							action: MenuBarEntry::no_action(),
+							disabled: no_active_document || !has_selected_layers,
+							children: MenuBarEntryChildren(vec![
+								AutoAdjustment::list()
+									.into_iter()
+									.map(|adjustment| MenuBarEntry {
+										label: adjustment.to_string(),
+										action: MenuBarEntry::create_action(move |_| DocumentMessage::AutoAdjustSelectedLayer { adjustment }.into()),
+										disabled: no_active_document || !has_selected_layers,
+										..MenuBarEntry::default()
+									})
+									.collect(),
+							]),
+							..MenuBarEntry::default()
+						},
 						MenuBarEntry {
 							label: "Boolean".into(),
 							icon: Some("BooleanSubtractFront".into()),
@@ -507,6 +611,28 @@ impl LayoutHolder for MenuBarMessageHandler {
 							disabled: no_active_document || node_graph_open,
 							..MenuBarEntry::default()
 						},
+						MenuBarEntry {
+							label: "Rotate Clockwise".into(),
+							shortcut: action_keys!(NavigationMessageDiscriminant::CanvasTiltClockwise),
+							action: MenuBarEntry::create_action(|_| NavigationMessage::CanvasTiltClockwise.into()),
+							disabled: no_active_document || node_graph_open,
+							..MenuBarEntry::default()
+						},
+						MenuBarEntry {
+							label: "Rotate Counterclockwise".into(),
+							shortcut: action_keys!(NavigationMessageDiscriminant::CanvasTiltCounterclockwise),
+							action: MenuBarEntry::create_action(|_| NavigationMessage::CanvasTiltCounterclockwise.into()),
+							disabled: no_active_document || node_graph_open,
+							..MenuBarEntry::default()
+						},
+						MenuBarEntry {
+							label: "Flip Horizontally".into(),
+							icon: Some(if self.view_flipped { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+							shortcut: action_keys!(NavigationMessageDiscriminant::CanvasFlip),
+							action: MenuBarEntry::create_action(|_| NavigationMessage::CanvasFlip.into()),
+							disabled: no_active_document || node_graph_open,
+							..MenuBarEntry::default()
+						},
 					],
 					vec![
 						MenuBarEntry {
@@ -558,6 +684,13 @@ impl LayoutHolder for MenuBarMessageHandler {
 							..MenuBarEntry::default()
 						},
 					],
+					vec![MenuBarEntry {
+						label: "Present".into(),
+						shortcut: action_keys!(DocumentMessageDiscriminant::StartPresentation),
+						action: MenuBarEntry::create_action(|_| DocumentMessage::StartPresentation.into()),
+						disabled: no_active_document || node_graph_open,
+						..MenuBarEntry::default()
+					}],
 					vec![MenuBarEntry {
 						label: "Rulers".into(),
 						icon: Some(if self.rulers_visible { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
@@ -573,6 +706,83 @@ impl LayoutHolder for MenuBarMessageHandler {
 						disabled: no_active_document,
 						..MenuBarEntry::default()
 					}],
+					vec![MenuBarEntry {
+						label: "Window: Histogram".into(),
+						icon: Some(if self.histogram_view_open { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+						action: MenuBarEntry::create_action(|_| HistogramMessage::ToggleOpen.into()),
+						disabled: no_active_document,
+						..MenuBarEntry::default()
+					}],
+					vec![MenuBarEntry {
+						label: "Window: Align".into(),
+						icon: Some(if self.align_panel_open { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+						action: MenuBarEntry::create_action(|_| AlignPanelMessage::ToggleOpen.into()),
+						disabled: no_active_document,
+						..MenuBarEntry::default()
+					}],
+					vec![MenuBarEntry {
+						label: "Window: Navigator".into(),
+						icon: Some(if self.navigator_panel_open { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+						action: MenuBarEntry::create_action(|_| NavigatorPanelMessage::ToggleOpen.into()),
+						disabled: no_active_document,
+						..MenuBarEntry::default()
+					}],
+					vec![MenuBarEntry {
+						label: "Window: Timeline".into(),
+						icon: Some(if self.timeline_panel_open { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+						action: MenuBarEntry::create_action(|_| TimelinePanelMessage::ToggleOpen.into()),
+						disabled: no_active_document,
+						..MenuBarEntry::default()
+					}],
+					vec![MenuBarEntry {
+						label: "Window: Comments".into(),
+						icon: Some(if self.comments_panel_open { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+						action: MenuBarEntry::create_action(|_| CommentsPanelMessage::ToggleOpen.into()),
+						disabled: no_active_document,
+						..MenuBarEntry::default()
+					}],
+					vec![MenuBarEntry {
+						label: "Window: Swatches".into(),
+						icon: Some(if self.swatches_panel_open { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+						action: MenuBarEntry::create_action(|_| SwatchesPanelMessage::ToggleOpen.into()),
+						disabled: no_active_document,
+						..MenuBarEntry::default()
+					}],
+					vec![MenuBarEntry {
+						label: "Window: Node Assets".into(),
+						icon: Some(if self.node_assets_panel_open { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+						action: MenuBarEntry::create_action(|_| NodeAssetsPanelMessage::ToggleOpen.into()),
+						disabled: no_active_document,
+						..MenuBarEntry::default()
+					}],
+					vec![MenuBarEntry {
+						label: "Window: Bulk Edit".into(),
+						icon: Some(if self.bulk_edit_panel_open { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+						action: MenuBarEntry::create_action(|_| BulkEditPanelMessage::ToggleOpen.into()),
+						disabled: no_active_document,
+						..MenuBarEntry::default()
+					}],
+					vec![MenuBarEntry {
+						label: "Window: Find in Graph".into(),
+						icon: Some(if self.graph_find_panel_open { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+						action: MenuBarEntry::create_action(|_| GraphFindPanelMessage::ToggleOpen.into()),
+						disabled: no_active_document,
+						..MenuBarEntry::default()
+					}],
+					vec![MenuBarEntry {
+						label: "Window: Diagnostics".into(),
+						icon: Some(if self.diagnostics_panel_open { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+						action: MenuBarEntry::create_action(|_| DiagnosticsPanelMessage::ToggleOpen.into()),
+						disabled: no_active_document,
+						..MenuBarEntry::default()
+					}],
+					vec![MenuBarEntry {
+						label: "Window: Profiler".into(),
+						icon: Some(if self.profiler_panel_open { "CheckboxChecked" } else { "CheckboxUnchecked" }.into()),
+						action: MenuBarEntry::create_action(|_| ProfilerPanelMessage::ToggleOpen.into()),
+						disabled: no_active_document,
+						..MenuBarEntry::default()
+					}],
 				]),
 			),
 			MenuBarEntry::new_root(
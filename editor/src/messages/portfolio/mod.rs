@@ -1,9 +1,20 @@
 mod portfolio_message;
 mod portfolio_message_handler;
 
+pub mod align_panel;
+pub mod bulk_edit_panel;
+pub mod comments_panel;
+pub mod diagnostics_panel;
 pub mod document;
+pub mod graph_find_panel;
+pub mod histogram;
 pub mod menu_bar;
+pub mod navigator_panel;
+pub mod node_assets_panel;
+pub mod profiler_panel;
 pub mod spreadsheet;
+pub mod swatches_panel;
+pub mod timeline_panel;
 pub mod utility_types;
 
 #[doc(inline)]
@@ -0,0 +1,7 @@
+mod navigator_panel_message;
+mod navigator_panel_message_handler;
+
+#[doc(inline)]
+pub use navigator_panel_message::*;
+#[doc(inline)]
+pub use navigator_panel_message_handler::*;
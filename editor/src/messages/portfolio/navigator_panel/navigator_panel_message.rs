@@ -0,0 +1,16 @@
+use crate::messages::prelude::*;
+use glam::DVec2;
+
+/// The Navigator panel shows an overview of the whole document with the area visible in the viewport, which can be dragged to pan the view.
+#[impl_message(Message, PortfolioMessage, NavigatorPanel)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum NavigatorPanelMessage {
+	ToggleOpen,
+
+	UpdateLayout,
+
+	/// Pans the viewport so the given point, in document space, is at its center.
+	CenterViewOn {
+		position: DVec2,
+	},
+}
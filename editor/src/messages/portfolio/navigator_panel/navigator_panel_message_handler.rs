@@ -0,0 +1,127 @@
+use crate::messages::input_mapper::utility_types::macros::action_keys;
+use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
+use crate::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
+use crate::messages::prelude::*;
+use crate::messages::tool::tool_messages::tool_prelude::*;
+
+pub struct NavigatorPanelMessageData<'a> {
+	pub document: Option<&'a DocumentMessageHandler>,
+	pub ipp: &'a InputPreprocessorMessageHandler,
+}
+
+/// The Navigator panel shows a zoomed-out overview of the whole document with a rectangle marking the area visible in the viewport.
+#[derive(Default, Debug, Clone)]
+pub struct NavigatorPanelMessageHandler {
+	/// Sets whether or not the Navigator panel is shown.
+	pub navigator_panel_open: bool,
+}
+
+impl MessageHandler<NavigatorPanelMessage, NavigatorPanelMessageData<'_>> for NavigatorPanelMessageHandler {
+	fn process_message(&mut self, message: NavigatorPanelMessage, responses: &mut VecDeque<Message>, data: NavigatorPanelMessageData) {
+		let NavigatorPanelMessageData { document, ipp } = data;
+
+		match message {
+			NavigatorPanelMessage::ToggleOpen => {
+				self.navigator_panel_open = !self.navigator_panel_open;
+				responses.add(FrontendMessage::UpdateNavigatorPanelState { open: self.navigator_panel_open });
+
+				// Update checked UI state for open
+				responses.add(MenuBarMessage::SendLayout);
+			}
+			NavigatorPanelMessage::UpdateLayout => {}
+			NavigatorPanelMessage::CenterViewOn { position } => {
+				let Some(document) = document else { return };
+
+				let viewport_position = document.metadata().document_to_viewport.transform_point2(position);
+				responses.add(NavigationMessage::CanvasPan {
+					delta: ipp.viewport_bounds.center() - viewport_position,
+				});
+				responses.add(PortfolioMessage::UpdateDocumentWidgets);
+			}
+		}
+
+		self.update_layout(document, ipp, responses);
+	}
+
+	fn actions(&self) -> ActionList {
+		actions!(NavigatorPanelMessage;)
+	}
+}
+
+impl NavigatorPanelMessageHandler {
+	fn update_layout(&self, document: Option<&DocumentMessageHandler>, ipp: &InputPreprocessorMessageHandler, responses: &mut VecDeque<Message>) {
+		// This is sent whenever the view or the artwork changes, so skip all work while the panel is hidden
+		if !self.navigator_panel_open {
+			return;
+		}
+
+		let zoom = document.map(|document| document.navigation_handler.snapped_zoom(document.document_ptz.zoom()));
+		let widgets = vec![
+			IconButton::new("ZoomOut", 24)
+				.tooltip("Zoom Out")
+				.tooltip_shortcut(action_keys!(NavigationMessageDiscriminant::CanvasZoomDecrease))
+				.on_update(|_| NavigationMessage::CanvasZoomDecrease { center_on_mouse: false }.into())
+				.disabled(document.is_none())
+				.widget_holder(),
+			NumberInput::new(zoom.map(|zoom| zoom * 100.))
+				.unit("%")
+				.min(0.000001)
+				.max(1000000.)
+				.range_min(Some(1.))
+				.range_max(Some(800.))
+				.mode_range()
+				.display_decimal_places(0)
+				.tooltip("Zoom")
+				.on_update(|number_input: &NumberInput| {
+					if let Some(value) = number_input.value {
+						NavigationMessage::CanvasZoomSet { zoom_factor: value / 100. }.into()
+					} else {
+						Message::NoOp
+					}
+				})
+				.on_commit(|_| PortfolioMessage::UpdateDocumentWidgets.into())
+				.disabled(document.is_none())
+				.widget_holder(),
+			IconButton::new("ZoomIn", 24)
+				.tooltip("Zoom In")
+				.tooltip_shortcut(action_keys!(NavigationMessageDiscriminant::CanvasZoomIncrease))
+				.on_update(|_| NavigationMessage::CanvasZoomIncrease { center_on_mouse: false }.into())
+				.disabled(document.is_none())
+				.widget_holder(),
+		];
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout::new(vec![LayoutGroup::Row { widgets }])),
+			layout_target: LayoutTarget::NavigatorPanel,
+		});
+
+		let Some(document) = document else {
+			responses.add(FrontendMessage::UpdateNavigatorPanelOverview {
+				layers: Vec::new(),
+				bounds: None,
+				viewport: Vec::new(),
+			});
+			return;
+		};
+
+		// Each artboard's thumbnail only shows its own content, but the thumbnail of a layer outside an artboard includes all the layers below it in the stack
+		let metadata = document.metadata();
+		let root_layers = LayerNodeIdentifier::ROOT_PARENT.children(metadata).collect::<Vec<_>>();
+		let is_artboard = |layer: &LayerNodeIdentifier| document.network_interface.is_artboard(&layer.to_node(), &[]);
+		let mut layers = root_layers.iter().filter(|layer| is_artboard(layer)).map(|layer| layer.to_node()).collect::<Vec<_>>();
+		if let Some(topmost_non_artboard) = root_layers.iter().find(|layer| !is_artboard(layer)) {
+			layers.push(topmost_non_artboard.to_node());
+		}
+		// Draw from the bottom of the stack up
+		layers.reverse();
+
+		let viewport_size = ipp.viewport_bounds.size();
+		let viewport_to_document = metadata.document_to_viewport.inverse();
+		let viewport = [DVec2::ZERO, DVec2::new(viewport_size.x, 0.), viewport_size, DVec2::new(0., viewport_size.y)]
+			.into_iter()
+			.map(|corner| viewport_to_document.transform_point2(corner).into())
+			.collect();
+		let bounds = document.network_interface.document_bounds_document_space(true).map(|[min, max]| [min.into(), max.into()]);
+
+		responses.add(FrontendMessage::UpdateNavigatorPanelOverview { layers, bounds, viewport });
+	}
+}
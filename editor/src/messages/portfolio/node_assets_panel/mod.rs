@@ -0,0 +1,7 @@
+mod node_assets_panel_message;
+mod node_assets_panel_message_handler;
+
+#[doc(inline)]
+pub use node_assets_panel_message::*;
+#[doc(inline)]
+pub use node_assets_panel_message_handler::*;
@@ -0,0 +1,37 @@
+use crate::messages::portfolio::document::utility_types::network_interface::NodeTemplate;
+use crate::messages::prelude::*;
+use graph_craft::document::NodeId;
+
+/// The Node Assets panel is the library of nodes saved for reuse in any document, whose instances follow the edits made to them.
+#[impl_message(Message, PortfolioMessage, NodeAssetsPanel)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum NodeAssetsPanelMessage {
+	ToggleOpen,
+
+	UpdateLayout,
+
+	/// Adds a version of a node asset to the library, creating the asset if there isn't one with this ID yet.
+	/// The node it was saved from becomes an instance of the asset, and every instance in the open documents is updated to the new version.
+	SaveAsset {
+		id: u64,
+		name: String,
+		node_id: NodeId,
+		node_template: NodeTemplate,
+	},
+	InsertAsset {
+		id: u64,
+	},
+	/// Saves a copy of an earlier version of the asset as its newest version, updating every instance to it.
+	RestoreAssetVersion {
+		id: u64,
+		version: u32,
+	},
+	RenameAsset {
+		id: u64,
+		name: String,
+	},
+	/// Removes the asset from the library. Its instances keep their contents, but no longer follow the asset.
+	DeleteAsset {
+		id: u64,
+	},
+}
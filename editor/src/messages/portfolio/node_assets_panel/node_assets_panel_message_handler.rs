@@ -0,0 +1,195 @@
+use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
+use crate::messages::portfolio::document::utility_types::network_interface::{NodeAssetReference, NodeTemplate};
+use crate::messages::portfolio::utility_types::NodeAsset;
+use crate::messages::prelude::*;
+use crate::messages::tool::tool_messages::tool_prelude::*;
+
+pub struct NodeAssetsPanelMessageData<'a> {
+	pub documents: &'a mut HashMap<DocumentId, DocumentMessageHandler>,
+	pub active_document_id: Option<DocumentId>,
+}
+
+/// The Node Assets panel is the library of nodes saved for reuse in any document, whose instances follow the edits made to them.
+#[derive(Debug, Clone, Default)]
+pub struct NodeAssetsPanelMessageHandler {
+	/// Sets whether or not the Node Assets panel is shown.
+	pub node_assets_panel_open: bool,
+	/// The assets in the library, which are shared by all documents and kept with the saved session.
+	pub assets: Vec<NodeAsset>,
+}
+
+impl MessageHandler<NodeAssetsPanelMessage, NodeAssetsPanelMessageData<'_>> for NodeAssetsPanelMessageHandler {
+	fn process_message(&mut self, message: NodeAssetsPanelMessage, responses: &mut VecDeque<Message>, data: NodeAssetsPanelMessageData) {
+		let NodeAssetsPanelMessageData { documents, active_document_id } = data;
+
+		match message {
+			NodeAssetsPanelMessage::ToggleOpen => {
+				self.node_assets_panel_open = !self.node_assets_panel_open;
+
+				// Update checked UI state for open
+				responses.add(MenuBarMessage::SendLayout);
+			}
+			NodeAssetsPanelMessage::UpdateLayout => {}
+			NodeAssetsPanelMessage::SaveAsset { id, name, node_id, node_template } => {
+				if !self.assets.iter().any(|asset| asset.id == id) {
+					self.assets.push(NodeAsset { id, name, versions: Vec::new() });
+				}
+				let Some(node_template) = self.add_version(id, node_template) else { return };
+
+				responses.add(DocumentMessage::AddTransaction);
+				responses.add(NodeGraphMessage::SetNodeAsset {
+					node_id,
+					asset: node_template.persistent_node_metadata.asset,
+				});
+				Self::update_instances(node_template, documents, active_document_id, responses);
+			}
+			NodeAssetsPanelMessage::InsertAsset { id } => {
+				let Some(node_template) = self.assets.iter().find(|asset| asset.id == id).and_then(|asset| asset.versions.last()) else {
+					return;
+				};
+				responses.add(NodeGraphMessage::InsertNodeAsset { node_template: node_template.clone() });
+			}
+			NodeAssetsPanelMessage::RestoreAssetVersion { id, version } => {
+				let Some(node_template) = self.assets.iter().find(|asset| asset.id == id).and_then(|asset| asset.versions.get(version as usize)).cloned() else {
+					return;
+				};
+				let Some(node_template) = self.add_version(id, node_template) else { return };
+
+				responses.add(DocumentMessage::AddTransaction);
+				Self::update_instances(node_template, documents, active_document_id, responses);
+			}
+			NodeAssetsPanelMessage::RenameAsset { id, name } => {
+				if let Some(asset) = self.assets.iter_mut().find(|asset| asset.id == id) {
+					asset.name = name;
+				}
+				responses.add(PortfolioMessage::SaveSession);
+			}
+			NodeAssetsPanelMessage::DeleteAsset { id } => {
+				self.assets.retain(|asset| asset.id != id);
+				responses.add(PortfolioMessage::SaveSession);
+			}
+		}
+
+		self.update_layout(active_document_id.is_some(), responses);
+	}
+
+	fn actions(&self) -> ActionList {
+		actions!(NodeAssetsPanelMessage;)
+	}
+}
+
+impl NodeAssetsPanelMessageHandler {
+	/// Appends a version to the asset, returning it linked to the asset so it can be given to the instances.
+	fn add_version(&mut self, id: u64, mut node_template: NodeTemplate) -> Option<NodeTemplate> {
+		let asset = self.assets.iter_mut().find(|asset| asset.id == id)?;
+		node_template.persistent_node_metadata.asset = Some(NodeAssetReference {
+			id,
+			version: asset.versions.len() as u32,
+		});
+		asset.versions.push(node_template.clone());
+		Some(node_template)
+	}
+
+	/// Brings every instance of the asset in the open documents up to date with the version. The active document is updated through a message so the change can be undone.
+	fn update_instances(node_template: NodeTemplate, documents: &mut HashMap<DocumentId, DocumentMessageHandler>, active_document_id: Option<DocumentId>, responses: &mut VecDeque<Message>) {
+		let Some(NodeAssetReference { id, .. }) = node_template.persistent_node_metadata.asset else {
+			return;
+		};
+
+		for (document_id, document) in documents.iter_mut() {
+			if Some(*document_id) == active_document_id {
+				continue;
+			}
+			let instances = document.network_interface.node_asset_instances(id);
+			for (node_id, network_path) in &instances {
+				document.network_interface.update_node_asset_instance(node_id, network_path, &node_template);
+			}
+			if !instances.is_empty() {
+				document.set_save_state(false);
+			}
+		}
+
+		responses.add(NodeGraphMessage::UpdateNodeAssetInstances { node_template });
+		responses.add(NodeGraphMessage::RunDocumentGraph);
+		responses.add(NodeGraphMessage::SendGraph);
+		responses.add(PortfolioMessage::SaveSession);
+	}
+
+	fn update_layout(&self, has_active_document: bool, responses: &mut VecDeque<Message>) {
+		responses.add(FrontendMessage::UpdateNodeAssetsPanelState { open: self.node_assets_panel_open });
+		if !self.node_assets_panel_open {
+			return;
+		}
+
+		let mut layout = vec![LayoutGroup::Row {
+			widgets: vec![
+				TextButton::new("Save Selected Node")
+					.tooltip("Save the selected node, which must be made of other nodes, as a new asset that can be inserted into any document")
+					.disabled(!has_active_document)
+					.on_update(|_| NodeGraphMessage::SaveSelectedAsNodeAsset { asset_id: None }.into())
+					.widget_holder(),
+			],
+		}];
+
+		if self.assets.is_empty() {
+			layout.push(LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new("There are no node assets yet. Merge nodes into a single node, select it, and save it to reuse it in any document.")
+						.multiline(true)
+						.widget_holder(),
+				],
+			});
+		}
+
+		for asset in &self.assets {
+			let id = asset.id;
+			let versions = asset
+				.versions
+				.iter()
+				.enumerate()
+				.map(|(version, _)| {
+					let version = version as u32;
+					MenuListEntry::new(format!("{version}"))
+						.label(format!("Version {}", version + 1))
+						.on_update(move |_| NodeAssetsPanelMessage::RestoreAssetVersion { id, version }.into())
+				})
+				.rev()
+				.collect();
+
+			layout.push(LayoutGroup::Row {
+				widgets: vec![
+					TextInput::new(&asset.name)
+						.tooltip("Asset Name")
+						.on_update(move |text_input: &TextInput| NodeAssetsPanelMessage::RenameAsset { id, name: text_input.value.clone() }.into())
+						.widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					DropdownInput::new(vec![versions])
+						.selected_index(Some(0))
+						.tooltip("The asset's current version. Choosing an earlier one saves a copy of it as the newest version, which every instance is updated to.")
+						.widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					TextButton::new("Insert")
+						.tooltip("Insert an instance of the asset into the graph")
+						.disabled(!has_active_document)
+						.on_update(move |_| NodeAssetsPanelMessage::InsertAsset { id }.into())
+						.widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					IconButton::new("Resync", 16)
+						.tooltip("Save the selected node as the newest version of the asset, updating every instance of it in the open documents")
+						.disabled(!has_active_document)
+						.on_update(move |_| NodeGraphMessage::SaveSelectedAsNodeAsset { asset_id: Some(id) }.into())
+						.widget_holder(),
+					IconButton::new("Trash", 16)
+						.tooltip("Delete Asset (its instances keep their contents)")
+						.on_update(move |_| NodeAssetsPanelMessage::DeleteAsset { id }.into())
+						.widget_holder(),
+				],
+			});
+		}
+
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout { layout }),
+			layout_target: LayoutTarget::NodeAssetsPanel,
+		});
+	}
+}
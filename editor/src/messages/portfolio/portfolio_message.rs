@@ -2,7 +2,9 @@ use super::document::utility_types::document_metadata::LayerNodeIdentifier;
 use super::utility_types::PanelType;
 use crate::messages::frontend::utility_types::{ExportBounds, FileType};
 use crate::messages::portfolio::document::utility_types::clipboards::Clipboard;
+use crate::messages::portfolio::document::utility_types::print::{PrintJob, PrintSettings};
 use crate::messages::prelude::*;
+use graph_craft::document::NodeId;
 use graphene_core::Color;
 use graphene_core::raster::Image;
 use graphene_core::text::Font;
@@ -17,6 +19,28 @@ pub enum PortfolioMessage {
 	Document(DocumentMessage),
 	#[child]
 	Spreadsheet(SpreadsheetMessage),
+	#[child]
+	Histogram(HistogramMessage),
+	#[child]
+	AlignPanel(AlignPanelMessage),
+	#[child]
+	NavigatorPanel(NavigatorPanelMessage),
+	#[child]
+	TimelinePanel(TimelinePanelMessage),
+	#[child]
+	CommentsPanel(CommentsPanelMessage),
+	#[child]
+	SwatchesPanel(SwatchesPanelMessage),
+	#[child]
+	NodeAssetsPanel(NodeAssetsPanelMessage),
+	#[child]
+	BulkEditPanel(BulkEditPanelMessage),
+	#[child]
+	GraphFindPanel(GraphFindPanelMessage),
+	#[child]
+	DiagnosticsPanel(DiagnosticsPanelMessage),
+	#[child]
+	ProfilerPanel(ProfilerPanelMessage),
 
 	// Messages
 	DocumentPassMessage {
@@ -54,16 +78,24 @@ pub enum PortfolioMessage {
 		preview_url: String,
 		data: Vec<u8>,
 	},
+	/// Sent by the frontend when a font used by a document can't be found.
+	FontUnavailable {
+		font: Font,
+	},
 	// ImaginateCheckServerStatus,
 	// ImaginatePollServerStatus,
 	// ImaginateServerHostname,
 	Import,
+	ClearRecentFiles,
 	LoadDocumentResources {
 		document_id: DocumentId,
 	},
 	LoadFont {
 		font: Font,
 	},
+	LoadSession {
+		session: String,
+	},
 	NewDocumentWithName {
 		name: String,
 	},
@@ -73,6 +105,9 @@ pub enum PortfolioMessage {
 		document_name: String,
 		document_serialized_content: String,
 	},
+	OpenRecentFile {
+		index: usize,
+	},
 	ToggleResetNodesToDefinitionsOnOpen,
 	OpenDocumentFileWithId {
 		document_id: DocumentId,
@@ -102,7 +137,21 @@ pub enum PortfolioMessage {
 		mouse: Option<(f64, f64)>,
 		parent_and_insert_index: Option<(LayerNodeIdentifier, usize)>,
 	},
+	PasteGeoJson {
+		name: Option<String>,
+		geojson: String,
+		mouse: Option<(f64, f64)>,
+	},
 	PrevDocument,
+	/// Evaluates the output of the node at the given document node path in the active document, to show the value carried by a wire in a tooltip.
+	ProbeNode {
+		node_path: Vec<NodeId>,
+	},
+	RememberRecentFile {
+		name: String,
+		content: String,
+	},
+	SaveSession,
 	SetActivePanel {
 		panel: PanelType,
 	},
@@ -118,6 +167,15 @@ pub enum PortfolioMessage {
 		scale_factor: f64,
 		bounds: ExportBounds,
 		transparent_background: bool,
+		include_bleed: bool,
+		print_settings: PrintSettings,
+	},
+	SubmitPrintExport {
+		name: String,
+		pixels: Vec<u8>,
+		width: u32,
+		height: u32,
+		print_job: PrintJob,
 	},
 	SubmitActiveGraphRender,
 	SubmitGraphRender {
@@ -1,9 +1,20 @@
+use super::align_panel::{AlignPanelMessageData, AlignPanelMessageHandler};
+use super::bulk_edit_panel::{BulkEditPanelMessageData, BulkEditPanelMessageHandler};
+use super::comments_panel::{CommentsPanelMessageData, CommentsPanelMessageHandler};
+use super::diagnostics_panel::{DiagnosticsPanelMessageData, DiagnosticsPanelMessageHandler};
 use super::document::utility_types::document_metadata::LayerNodeIdentifier;
 use super::document::utility_types::network_interface::{self, InputConnector, OutputConnector};
+use super::graph_find_panel::{GraphFindPanelMessageData, GraphFindPanelMessageHandler};
+use super::histogram::HistogramMessageHandler;
+use super::navigator_panel::{NavigatorPanelMessageData, NavigatorPanelMessageHandler};
+use super::node_assets_panel::{NodeAssetsPanelMessageData, NodeAssetsPanelMessageHandler};
+use super::profiler_panel::{ProfilerPanelMessageData, ProfilerPanelMessageHandler};
 use super::spreadsheet::SpreadsheetMessageHandler;
-use super::utility_types::{PanelType, PersistentData};
+use super::swatches_panel::{SwatchesPanelMessageData, SwatchesPanelMessageHandler};
+use super::timeline_panel::{TimelinePanelMessageData, TimelinePanelMessageHandler};
+use super::utility_types::{PanelType, PersistentData, RecentFile, Session};
 use crate::application::generate_uuid;
-use crate::consts::DEFAULT_DOCUMENT_NAME;
+use crate::consts::{DEFAULT_DOCUMENT_NAME, MAX_RECENT_FILES};
 use crate::messages::animation::TimingInformation;
 use crate::messages::debug::utility_types::MessageLoggingVerbosity;
 use crate::messages::dialog::simple_dialogs;
@@ -11,8 +22,10 @@ use crate::messages::frontend::utility_types::FrontendDocumentDetails;
 use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::portfolio::document::DocumentMessageData;
 use crate::messages::portfolio::document::node_graph::document_node_definitions::resolve_document_node_type;
+use crate::messages::portfolio::document::node_graph::node_versions;
 use crate::messages::portfolio::document::utility_types::clipboards::{Clipboard, CopyBufferEntry, INTERNAL_CLIPBOARD_COUNT};
 use crate::messages::portfolio::document::utility_types::nodes::SelectedNodes;
+use crate::messages::portfolio::document::utility_types::print;
 use crate::messages::preferences::SelectionMode;
 use crate::messages::prelude::*;
 use crate::messages::tool::utility_types::{HintData, HintGroup, ToolType};
@@ -21,9 +34,11 @@ use bezier_rs::Subpath;
 use glam::IVec2;
 use graph_craft::document::value::TaggedValue;
 use graph_craft::document::{DocumentNodeImplementation, NodeId, NodeInput};
+use graphene_core::application_io::FilePath;
 use graphene_core::text::{Font, TypesettingConfig};
 use graphene_std::vector::style::{Fill, FillType, Gradient};
 use graphene_std::vector::{VectorData, VectorDataTable};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::vec;
 
 pub struct PortfolioMessageData<'a> {
@@ -34,6 +49,7 @@ pub struct PortfolioMessageData<'a> {
 	pub reset_node_definitions_on_open: bool,
 	pub timing_information: TimingInformation,
 	pub animation: &'a AnimationMessageHandler,
+	pub tool_message_handler: &'a ToolMessageHandler,
 }
 
 #[derive(Debug, Default)]
@@ -49,8 +65,34 @@ pub struct PortfolioMessageHandler {
 	pub selection_mode: SelectionMode,
 	/// The spreadsheet UI allows for instance data to be previewed.
 	pub spreadsheet: SpreadsheetMessageHandler,
+	/// The histogram UI shows the tonal distribution of the inspected raster content.
+	pub histogram: HistogramMessageHandler,
+	/// The Align panel lines up and distributes the selected layers.
+	pub align_panel: AlignPanelMessageHandler,
+	/// The Navigator panel shows an overview of the whole document and the area visible in the viewport.
+	pub navigator_panel: NavigatorPanelMessageHandler,
+	/// The Timeline panel scrubs through the animation and shows the keyframes of animated node inputs.
+	pub timeline_panel: TimelinePanelMessageHandler,
+	/// The Comments panel lists the threads of the comment pins left on the canvas while reviewing the document.
+	pub comments_panel: CommentsPanelMessageHandler,
+	/// The Swatches panel lists the named colors saved with the document and extracts a palette from the colors used most in its artwork.
+	pub swatches_panel: SwatchesPanelMessageHandler,
+	/// The Node Assets panel is the library of nodes saved for reuse in any document.
+	pub node_assets_panel: NodeAssetsPanelMessageHandler,
+	/// The Bulk Edit panel lists every node of a chosen type in the graph for editing their parameters together.
+	pub bulk_edit_panel: BulkEditPanelMessageHandler,
+	/// The Find in Graph panel searches the nodes of the graph for text and replaces it in their inputs.
+	pub graph_find_panel: GraphFindPanelMessageHandler,
+	/// The Diagnostics panel lists the nodes which have consistently exceeded the document's performance budget, also warned about in the status bar.
+	pub diagnostics_panel: DiagnosticsPanelMessageHandler,
+	/// The Profiler panel lists the time spent in each node of the viewed network in the most recent render, and can show those times over the nodes in the graph.
+	pub profiler_panel: ProfilerPanelMessageHandler,
 	device_pixel_ratio: Option<f64>,
 	pub reset_node_definitions_on_open: bool,
+	/// The documents most recently opened from or saved to files, newest first.
+	recent_files: Vec<RecentFile>,
+	/// The workspace session which was most recently saved, so it's only saved again once something has changed.
+	saved_session: Option<Session>,
 }
 
 impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMessageHandler {
@@ -63,6 +105,7 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 			reset_node_definitions_on_open,
 			timing_information,
 			animation,
+			tool_message_handler,
 		} = data;
 
 		match message {
@@ -70,21 +113,37 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 			PortfolioMessage::MenuBar(message) => {
 				self.menu_bar_message_handler.has_active_document = false;
 				self.menu_bar_message_handler.rulers_visible = false;
+				self.menu_bar_message_handler.view_flipped = false;
 				self.menu_bar_message_handler.node_graph_open = false;
 				self.menu_bar_message_handler.has_selected_nodes = false;
 				self.menu_bar_message_handler.has_selected_layers = false;
+				self.menu_bar_message_handler.has_solo_layer = false;
 				self.menu_bar_message_handler.has_selection_history = (false, false);
 				self.menu_bar_message_handler.spreadsheet_view_open = self.spreadsheet.spreadsheet_view_open;
+				self.menu_bar_message_handler.histogram_view_open = self.histogram.histogram_view_open;
+				self.menu_bar_message_handler.align_panel_open = self.align_panel.align_panel_open;
+				self.menu_bar_message_handler.navigator_panel_open = self.navigator_panel.navigator_panel_open;
+				self.menu_bar_message_handler.timeline_panel_open = self.timeline_panel.timeline_panel_open;
+				self.menu_bar_message_handler.comments_panel_open = self.comments_panel.comments_panel_open;
+				self.menu_bar_message_handler.swatches_panel_open = self.swatches_panel.swatches_panel_open;
+				self.menu_bar_message_handler.node_assets_panel_open = self.node_assets_panel.node_assets_panel_open;
+				self.menu_bar_message_handler.bulk_edit_panel_open = self.bulk_edit_panel.bulk_edit_panel_open;
+				self.menu_bar_message_handler.graph_find_panel_open = self.graph_find_panel.graph_find_panel_open;
+				self.menu_bar_message_handler.diagnostics_panel_open = self.diagnostics_panel.diagnostics_panel_open;
+				self.menu_bar_message_handler.profiler_panel_open = self.profiler_panel.profiler_panel_open;
 				self.menu_bar_message_handler.message_logging_verbosity = message_logging_verbosity;
 				self.menu_bar_message_handler.reset_node_definitions_on_open = reset_node_definitions_on_open;
+				self.menu_bar_message_handler.recent_files = self.recent_files.iter().map(|file| file.name.clone()).collect();
 
 				if let Some(document) = self.active_document_id.and_then(|document_id| self.documents.get_mut(&document_id)) {
 					self.menu_bar_message_handler.has_active_document = true;
 					self.menu_bar_message_handler.rulers_visible = document.rulers_visible;
+					self.menu_bar_message_handler.view_flipped = document.document_ptz.flip();
 					self.menu_bar_message_handler.node_graph_open = document.is_graph_overlay_open();
 					let selected_nodes = document.network_interface.selected_nodes();
 					self.menu_bar_message_handler.has_selected_nodes = selected_nodes.selected_nodes().next().is_some();
 					self.menu_bar_message_handler.has_selected_layers = selected_nodes.selected_visible_layers(&document.network_interface).next().is_some();
+					self.menu_bar_message_handler.has_solo_layer = document.node_graph_handler.solo_layer.is_some();
 					self.menu_bar_message_handler.has_selection_history = {
 						let metadata = &document.network_interface.document_network_metadata().persistent_metadata;
 						(!metadata.selection_undo_history.is_empty(), !metadata.selection_redo_history.is_empty())
@@ -96,6 +155,57 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 			PortfolioMessage::Spreadsheet(message) => {
 				self.spreadsheet.process_message(message, responses, ());
 			}
+			PortfolioMessage::Histogram(message) => {
+				self.histogram.process_message(message, responses, ());
+			}
+			PortfolioMessage::AlignPanel(message) => {
+				let document = self.active_document_id.and_then(|document_id| self.documents.get(&document_id));
+				self.align_panel.process_message(message, responses, AlignPanelMessageData { document });
+			}
+			PortfolioMessage::NavigatorPanel(message) => {
+				let document = self.active_document_id.and_then(|document_id| self.documents.get(&document_id));
+				self.navigator_panel.process_message(message, responses, NavigatorPanelMessageData { document, ipp });
+			}
+			PortfolioMessage::CommentsPanel(message) => {
+				let document = self.active_document_id.and_then(|document_id| self.documents.get(&document_id));
+				self.comments_panel.process_message(message, responses, CommentsPanelMessageData { document });
+			}
+			PortfolioMessage::SwatchesPanel(message) => {
+				let document = self.active_document_id.and_then(|document_id| self.documents.get(&document_id));
+				self.swatches_panel.process_message(message, responses, SwatchesPanelMessageData { document });
+			}
+			PortfolioMessage::NodeAssetsPanel(message) => {
+				let data = NodeAssetsPanelMessageData {
+					documents: &mut self.documents,
+					active_document_id: self.active_document_id,
+				};
+				self.node_assets_panel.process_message(message, responses, data);
+			}
+			PortfolioMessage::BulkEditPanel(message) => {
+				let document = self.active_document_id.and_then(|document_id| self.documents.get(&document_id));
+				self.bulk_edit_panel.process_message(message, responses, BulkEditPanelMessageData { document });
+			}
+			PortfolioMessage::GraphFindPanel(message) => {
+				let document = self.active_document_id.and_then(|document_id| self.documents.get(&document_id));
+				self.graph_find_panel.process_message(message, responses, GraphFindPanelMessageData { document });
+			}
+			PortfolioMessage::DiagnosticsPanel(message) => {
+				let document = self.active_document_id.and_then(|document_id| self.documents.get(&document_id));
+				self.diagnostics_panel.process_message(message, responses, DiagnosticsPanelMessageData { document });
+			}
+			PortfolioMessage::ProfilerPanel(message) => {
+				let document = self.active_document_id.and_then(|document_id| self.documents.get(&document_id));
+				self.profiler_panel.process_message(message, responses, ProfilerPanelMessageData { document });
+			}
+			PortfolioMessage::TimelinePanel(message) => {
+				let document = self.active_document_id.and_then(|document_id| self.documents.get(&document_id));
+				let data = TimelinePanelMessageData {
+					document,
+					animation,
+					timing_information,
+				};
+				self.timeline_panel.process_message(message, responses, data);
+			}
 			PortfolioMessage::Document(message) => {
 				if let Some(document_id) = self.active_document_id {
 					if let Some(document) = self.documents.get_mut(&document_id) {
@@ -143,6 +253,7 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 						responses.add(PortfolioMessage::AutoSaveDocument { document_id: *document_id });
 					}
 				}
+				responses.add(PortfolioMessage::SaveSession);
 			}
 			PortfolioMessage::AutoSaveDocument { document_id } => {
 				let document = self.documents.get(&document_id).unwrap();
@@ -179,6 +290,10 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 
 				responses.add(PortfolioMessage::DestroyAllDocuments);
 				responses.add(PortfolioMessage::UpdateOpenDocumentsList);
+
+				// Clear the status bar's warning about the slow nodes, and the profiled times, of the closed documents
+				responses.add(DiagnosticsPanelMessage::UpdateLayout);
+				responses.add(ProfilerPanelMessage::UpdateLayout);
 			}
 			PortfolioMessage::CloseAllDocumentsWithConfirmation => {
 				if self.unsaved_document_names().is_empty() {
@@ -203,6 +318,10 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 
 				// Send the new list of document tab names
 				responses.add(PortfolioMessage::UpdateOpenDocumentsList);
+
+				// Clear the status bar's warning about the slow nodes, and the profiled times, of the closed document
+				responses.add(DiagnosticsPanelMessage::UpdateLayout);
+				responses.add(ProfilerPanelMessage::UpdateLayout);
 			}
 			PortfolioMessage::CloseDocumentWithConfirmation { document_id } => {
 				let target_document = self.documents.get(&document_id).unwrap();
@@ -309,15 +428,23 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 			} => {
 				let font = Font::new(font_family, font_style);
 
+				// A font that couldn't be found has been located as a font file, so the text using it no longer needs to be marked
+				if self.persistent_data.unavailable_fonts.remove(&font) {
+					responses.add(DocumentMessage::UpdateMissingFontOverlays);
+					responses.add(DialogMessage::RequestMissingFontsDialog);
+				}
+
 				self.persistent_data.font_cache.insert(font, preview_url, data);
 				self.executor.update_font_cache(self.persistent_data.font_cache.clone());
 				for document_id in self.document_ids.iter() {
 					let inspect_node = self.inspect_node_id();
+					let histogram_node = self.histogram_node_id();
 					let _ = self.executor.submit_node_graph_evaluation(
 						self.documents.get_mut(document_id).expect("Tried to render non-existent document"),
 						ipp.viewport_bounds.size().as_uvec2(),
 						timing_information,
 						inspect_node,
+						histogram_node,
 						true,
 					);
 				}
@@ -326,6 +453,15 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 					responses.add(NodeGraphMessage::RunDocumentGraph);
 				}
 			}
+			PortfolioMessage::FontUnavailable { font } => {
+				let used_by_active_document = self.active_document().is_some_and(|document| document.fonts_in_use().iter().any(|(used, _)| *used == font));
+				self.persistent_data.unavailable_fonts.insert(font);
+
+				if used_by_active_document {
+					responses.add(DocumentMessage::UpdateMissingFontOverlays);
+					responses.add(DialogMessage::RequestMissingFontsDialog);
+				}
+			}
 			// PortfolioMessage::ImaginateCheckServerStatus => {
 			// 	let server_status = self.persistent_data.imaginate.server_status().clone();
 			// 	self.persistent_data.imaginate.poll_server_check();
@@ -364,11 +500,72 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 					document.load_layer_resources(responses);
 				}
 			}
+			PortfolioMessage::ClearRecentFiles => {
+				let keys = self.recent_files.drain(..).map(|file| file.key).collect();
+				responses.add(FrontendMessage::TriggerIndexedDbRemoveRecentFiles { keys });
+
+				responses.add(MenuBarMessage::SendLayout);
+				responses.add(PortfolioMessage::SaveSession);
+			}
 			PortfolioMessage::LoadFont { font } => {
 				if !self.persistent_data.font_cache.loaded_font(&font) {
 					responses.add_front(FrontendMessage::TriggerFontLoad { font });
 				}
 			}
+			PortfolioMessage::LoadSession { session } => {
+				let Ok(session) = serde_json::from_str::<Session>(&session) else {
+					warn!("The saved session could not be restored");
+					return;
+				};
+				let Session {
+					document_views,
+					node_graph_open,
+					open_panels,
+					tools,
+					recent_files,
+					node_assets,
+				} = session.clone();
+
+				for (document_id, ptz) in document_views {
+					if let Some(document) = self.documents.get_mut(&document_id) {
+						document.document_ptz = ptz;
+					}
+				}
+
+				let panel_toggles: [(PanelType, bool, Message); 12] = [
+					(PanelType::Spreadsheet, self.spreadsheet.spreadsheet_view_open, SpreadsheetMessage::ToggleOpen.into()),
+					(PanelType::Histogram, self.histogram.histogram_view_open, HistogramMessage::ToggleOpen.into()),
+					(PanelType::Align, self.align_panel.align_panel_open, AlignPanelMessage::ToggleOpen.into()),
+					(PanelType::Navigator, self.navigator_panel.navigator_panel_open, NavigatorPanelMessage::ToggleOpen.into()),
+					(PanelType::Timeline, self.timeline_panel.timeline_panel_open, TimelinePanelMessage::ToggleOpen.into()),
+					(PanelType::Comments, self.comments_panel.comments_panel_open, CommentsPanelMessage::ToggleOpen.into()),
+					(PanelType::Swatches, self.swatches_panel.swatches_panel_open, SwatchesPanelMessage::ToggleOpen.into()),
+					(PanelType::NodeAssets, self.node_assets_panel.node_assets_panel_open, NodeAssetsPanelMessage::ToggleOpen.into()),
+					(PanelType::BulkEdit, self.bulk_edit_panel.bulk_edit_panel_open, BulkEditPanelMessage::ToggleOpen.into()),
+					(PanelType::GraphFind, self.graph_find_panel.graph_find_panel_open, GraphFindPanelMessage::ToggleOpen.into()),
+					(PanelType::Diagnostics, self.diagnostics_panel.diagnostics_panel_open, DiagnosticsPanelMessage::ToggleOpen.into()),
+					(PanelType::Profiler, self.profiler_panel.profiler_panel_open, ProfilerPanelMessage::ToggleOpen.into()),
+				];
+				for (panel, open, toggle) in panel_toggles {
+					if open_panels.contains(&panel) != open {
+						responses.add(toggle);
+					}
+				}
+
+				self.recent_files = recent_files;
+				self.node_assets_panel.assets = node_assets;
+				responses.add(MenuBarMessage::SendLayout);
+				responses.add(NodeAssetsPanelMessage::UpdateLayout);
+
+				// Tools can only be used, and the view is only shown, while there's an active document
+				if self.active_document().is_some() {
+					responses.add(ToolMessage::RestoreSession { session: tools });
+					responses.add(DocumentMessage::GraphViewOverlay { open: node_graph_open });
+					responses.add(NavigationMessage::CanvasPan { delta: (0., 0.).into() });
+				}
+
+				self.saved_session = Some(session);
+			}
 			PortfolioMessage::NewDocumentWithName { name } => {
 				let mut new_document = DocumentMessageHandler::default();
 				new_document.name = name;
@@ -400,6 +597,11 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 				document_name,
 				document_serialized_content,
 			} => {
+				responses.add(PortfolioMessage::RememberRecentFile {
+					name: document_name.clone(),
+					content: document_serialized_content.clone(),
+				});
+
 				let document_id = DocumentId(generate_uuid());
 				responses.add(PortfolioMessage::OpenDocumentFileWithId {
 					document_id,
@@ -411,6 +613,15 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 				});
 				responses.add(PortfolioMessage::SelectDocument { document_id });
 			}
+			PortfolioMessage::OpenRecentFile { index } => {
+				let Some(file) = self.recent_files.get(index) else { return };
+
+				// The frontend reads the file's content from IndexedDB and opens it as a document file
+				responses.add(FrontendMessage::TriggerOpenRecentFile {
+					name: file.name.clone(),
+					key: file.key,
+				});
+			}
 			PortfolioMessage::ToggleResetNodesToDefinitionsOnOpen => {
 				self.reset_node_definitions_on_open = !self.reset_node_definitions_on_open;
 				responses.add(MenuBarMessage::SendLayout);
@@ -607,6 +818,7 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 						continue;
 					};
 
+					let definition_version = node_metadata.persistent_metadata.definition_version;
 					let inputs_count = node.inputs.len();
 
 					// Upgrade Fill nodes to the format change in #1778
@@ -664,6 +876,20 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 									.network_interface
 									.set_input(&InputConnector::node(*node_id, 3), NodeInput::value(TaggedValue::Gradient(gradient), false), network_path);
 							}
+							Fill::Pattern(_) => {}
+						}
+					}
+
+					// Upgrade the Fill node to add the pattern tile input
+					if reference == "Fill" && inputs_count == 4 {
+						let node_definition = resolve_document_node_type(reference).unwrap();
+						let document_node = node_definition.default_node_template().document_node;
+						document.network_interface.replace_implementation(node_id, network_path, document_node.implementation.clone());
+
+						let old_inputs = document.network_interface.replace_inputs(node_id, document_node.inputs.clone(), network_path);
+
+						for (i, input) in old_inputs.into_iter().enumerate() {
+							document.network_interface.set_input(&InputConnector::node(*node_id, i), input, network_path);
 						}
 					}
 
@@ -742,7 +968,7 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 					}
 
 					// Upgrade Text node to include line height and character spacing, which were previously hardcoded to 1, from https://github.com/GraphiteEditor/Graphite/pull/2016
-					if reference == "Text" && inputs_count != 8 {
+					if reference == "Text" && inputs_count < 8 {
 						let node_definition = resolve_document_node_type(reference).unwrap();
 						let document_node = node_definition.default_node_template().document_node;
 						document.network_interface.replace_implementation(node_id, network_path, document_node.implementation.clone());
@@ -783,6 +1009,33 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 						);
 					}
 
+					// Upgrade Text node to include the horizontal and vertical alignment of its lines
+					if reference == "Text" && inputs_count == 8 {
+						let node_definition = resolve_document_node_type(reference).unwrap();
+						let document_node = node_definition.default_node_template().document_node;
+						document.network_interface.replace_implementation(node_id, network_path, document_node.implementation.clone());
+
+						let old_inputs = document.network_interface.replace_inputs(node_id, document_node.inputs.clone(), network_path);
+
+						for (i, input) in old_inputs.into_iter().enumerate() {
+							document.network_interface.set_input(&InputConnector::node(*node_id, i), input, network_path);
+						}
+					}
+
+					// Upgrade the Load Image node to take a file path, which can be chosen by browsing for a file, instead of a string
+					if reference == "Load Image" {
+						let node = document.network_interface.document_node(node_id, network_path).unwrap();
+						if let Some(TaggedValue::String(path)) = node.inputs.get(1).and_then(|input| input.as_value()) {
+							let path = FilePath(path.clone());
+							let node_definition = resolve_document_node_type(reference).unwrap();
+							let document_node = node_definition.default_node_template().document_node;
+							document.network_interface.replace_implementation(node_id, network_path, document_node.implementation.clone());
+							document
+								.network_interface
+								.set_input(&InputConnector::node(*node_id, 1), NodeInput::value(TaggedValue::FilePath(path), false), network_path);
+						}
+					}
+
 					// Upgrade Sine, Cosine, and Tangent nodes to include a boolean input for whether the output should be in radians, which was previously the only option but is now not the default
 					if (reference == "Sine" || reference == "Cosine" || reference == "Tangent") && inputs_count == 1 {
 						let node_definition = resolve_document_node_type(reference).unwrap();
@@ -828,6 +1081,82 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 							.set_input(&InputConnector::node(*node_id, 3), NodeInput::value(TaggedValue::Bool(true), false), network_path);
 					}
 
+					// Upgrade the Repeat and Circular Repeat nodes to add the per-copy rotation, scale, opacity falloff, and expression inputs
+					if (reference == "Repeat" || reference == "Circular Repeat") && inputs_count == 4 {
+						let node_definition = resolve_document_node_type(reference).unwrap();
+						let document_node = node_definition.default_node_template().document_node;
+						document.network_interface.replace_implementation(node_id, network_path, document_node.implementation.clone());
+
+						let old_inputs = document.network_interface.replace_inputs(node_id, document_node.inputs.clone(), network_path);
+
+						for (i, input) in old_inputs.into_iter().enumerate() {
+							document.network_interface.set_input(&InputConnector::node(*node_id, i), input, network_path);
+						}
+					}
+
+					// Upgrade the Assign Colors node to add the palette input
+					if reference == "Assign Colors" && inputs_count == 8 {
+						let node_definition = resolve_document_node_type(reference).unwrap();
+						let document_node = node_definition.default_node_template().document_node;
+						document.network_interface.replace_implementation(node_id, network_path, document_node.implementation.clone());
+
+						let old_inputs = document.network_interface.replace_inputs(node_id, document_node.inputs.clone(), network_path);
+
+						for (i, input) in old_inputs.into_iter().enumerate() {
+							document.network_interface.set_input(&InputConnector::node(*node_id, i), input, network_path);
+						}
+					}
+
+					// Upgrade the Levels node to add the channel input after the output maximums
+					if reference == "Levels" && inputs_count == 6 {
+						let node_definition = resolve_document_node_type(reference).unwrap();
+						let document_node = node_definition.default_node_template().document_node;
+						document.network_interface.replace_implementation(node_id, network_path, document_node.implementation.clone());
+
+						let old_inputs = document.network_interface.replace_inputs(node_id, document_node.inputs.clone(), network_path);
+
+						for (i, input) in old_inputs.into_iter().enumerate() {
+							document.network_interface.set_input(&InputConnector::node(*node_id, i), input, network_path);
+						}
+					}
+
+					// Upgrade the Selective Color node to add the hue range and CMYK inputs of the custom colors choice after the colors choice
+					if reference == "Selective Color" && inputs_count == 39 {
+						let node_definition = resolve_document_node_type(reference).unwrap();
+						let document_node = node_definition.default_node_template().document_node;
+						document.network_interface.replace_implementation(node_id, network_path, document_node.implementation.clone());
+
+						let old_inputs = document.network_interface.replace_inputs(node_id, document_node.inputs.clone(), network_path);
+
+						for (i, input) in old_inputs.into_iter().enumerate() {
+							document.network_interface.set_input(&InputConnector::node(*node_id, i), input, network_path);
+						}
+					}
+
+					// Upgrade the Artboard node to add the bleed and margin inputs after the clip input
+					if reference == "Artboard" && inputs_count == 6 {
+						let node_definition = resolve_document_node_type(reference).unwrap();
+						let default_definition_node = node_definition.default_node_template();
+						document
+							.network_interface
+							.replace_implementation(node_id, network_path, default_definition_node.document_node.implementation);
+						document
+							.network_interface
+							.replace_implementation_metadata(node_id, network_path, default_definition_node.persistent_node_metadata);
+
+						let old_inputs = document.network_interface.replace_inputs(node_id, default_definition_node.document_node.inputs, network_path);
+
+						for (i, input) in old_inputs.into_iter().enumerate() {
+							document.network_interface.set_input(&InputConnector::node(*node_id, i), input, network_path);
+						}
+
+						// The replaced network resets the label passed to "To Artboard", so restore it from the layer name
+						let label = document.network_interface.display_name(node_id, network_path);
+						document
+							.network_interface
+							.set_input(&InputConnector::node(NodeId(0), 1), NodeInput::value(TaggedValue::String(label), false), &[*node_id]);
+					}
+
 					// Upgrade artboard name being passed as hidden value input to "To Artboard"
 					if reference == "Artboard" && upgrade_from_before_returning_nested_click_targets {
 						let label = document.network_interface.display_name(node_id, network_path);
@@ -862,6 +1191,11 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 							document.network_interface.set_input(&InputConnector::node(*node_id, i + 1), input.clone(), network_path);
 						}
 					}
+
+					// Keep nodes pinned to an older definition version evaluating as before, until the user explicitly upgrades them
+					if definition_version < node_versions::current_version(reference) {
+						node_versions::apply_shims(&mut document.network_interface, node_id, network_path);
+					}
 				}
 
 				// TODO: Eventually remove this document upgrade code
@@ -1020,6 +1354,73 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 					responses.add(FrontendMessage::TriggerDelayedZoomCanvasToFitAll);
 				}
 			}
+			PortfolioMessage::PasteGeoJson { name, geojson, mouse } => {
+				let create_document = self.documents.is_empty();
+
+				if create_document {
+					responses.add(PortfolioMessage::NewDocumentWithName {
+						name: name.clone().unwrap_or("Untitled Document".into()),
+					});
+				}
+
+				responses.add(DocumentMessage::PasteGeoJson { name, geojson, mouse });
+
+				if create_document {
+					// Wait for the document to be rendered so the click targets can be calculated in order to determine the artboard size that will encompass the map
+					responses.add(Message::StartBuffer);
+					responses.add(DocumentMessage::WrapContentInArtboard { place_artboard_at_origin: true });
+
+					responses.add(Message::StartBuffer);
+					responses.add(FrontendMessage::TriggerDelayedZoomCanvasToFitAll);
+				}
+			}
+			PortfolioMessage::RememberRecentFile { name, content } => {
+				let mut hasher = DefaultHasher::new();
+				content.hash(&mut hasher);
+				let key = hasher.finish();
+
+				// The same file opened or saved again moves to the top of the list
+				self.recent_files.retain(|file| file.name != name || file.key != key);
+				self.recent_files.insert(0, RecentFile { name, key });
+				responses.add(FrontendMessage::TriggerIndexedDbWriteRecentFile { key, content });
+
+				// The content of files that fall off the end of the list is removed, unless it's shared with a file that's still listed
+				let removed = self.recent_files.split_off(self.recent_files.len().min(MAX_RECENT_FILES));
+				let keys = removed
+					.into_iter()
+					.map(|file| file.key)
+					.filter(|&key| self.recent_files.iter().all(|file| file.key != key))
+					.collect::<Vec<_>>();
+				if !keys.is_empty() {
+					responses.add(FrontendMessage::TriggerIndexedDbRemoveRecentFiles { keys });
+				}
+
+				responses.add(MenuBarMessage::SendLayout);
+				responses.add(PortfolioMessage::SaveSession);
+			}
+			PortfolioMessage::SaveSession => {
+				let session = Session {
+					document_views: self
+						.document_ids
+						.iter()
+						.filter_map(|document_id| Some((*document_id, self.documents.get(document_id)?.document_ptz)))
+						.collect(),
+					node_graph_open: self.active_document().is_some_and(|document| document.is_graph_overlay_open()),
+					open_panels: self.open_panels(),
+					tools: tool_message_handler.session(),
+					recent_files: self.recent_files.clone(),
+					node_assets: self.node_assets_panel.assets.clone(),
+				};
+				if self.saved_session.as_ref() == Some(&session) {
+					return;
+				}
+
+				match serde_json::to_string(&session) {
+					Ok(serialized) => responses.add(FrontendMessage::TriggerSaveSession { session: serialized }),
+					Err(error) => warn!("The session could not be saved: {error}"),
+				}
+				self.saved_session = Some(session);
+			}
 			PortfolioMessage::PrevDocument => {
 				if let Some(active_document_id) = self.active_document_id {
 					let len = self.document_ids.len();
@@ -1029,6 +1430,7 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 					responses.add(PortfolioMessage::SelectDocument { document_id: prev_id });
 				}
 			}
+			PortfolioMessage::ProbeNode { node_path } => self.executor.probe_node(node_path, responses),
 			PortfolioMessage::SetActivePanel { panel } => {
 				self.active_panel = panel;
 				responses.add(DocumentMessage::SetActivePanel { active_panel: self.active_panel });
@@ -1060,6 +1462,15 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 				responses.add(ToolMessage::InitTools);
 				responses.add(NodeGraphMessage::Init);
 				responses.add(OverlaysMessage::Draw);
+				responses.add(CompareMessage::Refresh);
+				responses.add(CommentsPanelMessage::UpdateLayout);
+				responses.add(SwatchesPanelMessage::UpdateLayout);
+				responses.add(NodeAssetsPanelMessage::UpdateLayout);
+				responses.add(BulkEditPanelMessage::UpdateLayout);
+				responses.add(GraphFindPanelMessage::UpdateLayout);
+				responses.add(DiagnosticsPanelMessage::UpdateLayout);
+				responses.add(ProfilerPanelMessage::UpdateLayout);
+				responses.add(DocumentMessage::UpdateMissingFontOverlays);
 				responses.add(BroadcastEvent::ToolAbort);
 				responses.add(BroadcastEvent::SelectionChanged);
 				responses.add(NavigationMessage::CanvasPan { delta: (0., 0.).into() });
@@ -1075,6 +1486,9 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 					warn!("Tried to read non existant document");
 					return;
 				};
+				responses.add(FrontendMessage::UpdateColorPickerPrintProfile {
+					print_profile: document.print_profile,
+				});
 				if !document.is_loaded {
 					document.is_loaded = true;
 					responses.add(PortfolioMessage::LoadDocumentResources { document_id });
@@ -1088,6 +1502,8 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 				scale_factor,
 				bounds,
 				transparent_background,
+				include_bleed,
+				print_settings,
 			} => {
 				let document = self.active_document_id.and_then(|id| self.documents.get_mut(&id)).expect("Tried to render non-existent document");
 				let export_config = ExportConfig {
@@ -1096,6 +1512,8 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 					scale_factor,
 					bounds,
 					transparent_background,
+					include_bleed,
+					print_settings,
 					..Default::default()
 				};
 				let result = self.executor.submit_document_export(document, export_config);
@@ -1107,6 +1525,21 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 					});
 				}
 			}
+			PortfolioMessage::SubmitPrintExport {
+				name,
+				pixels,
+				width,
+				height,
+				print_job,
+			} => {
+				if pixels.len() != (width as usize) * (height as usize) * 4 {
+					warn!("Print export image data doesn't match its dimensions of {width}x{height}");
+					return;
+				}
+
+				let document = print::print_pdf(&pixels, width, height, &print_job);
+				responses.add(FrontendMessage::TriggerDownloadTextFile { document, name });
+			}
 			PortfolioMessage::SubmitActiveGraphRender => {
 				if let Some(document_id) = self.active_document_id {
 					responses.add(PortfolioMessage::SubmitGraphRender { document_id, ignore_hash: false });
@@ -1114,13 +1547,15 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 			}
 			PortfolioMessage::SubmitGraphRender { document_id, ignore_hash } => {
 				let inspect_node = self.inspect_node_id();
-				let result = self.executor.submit_node_graph_evaluation(
-					self.documents.get_mut(&document_id).expect("Tried to render non-existent document"),
-					ipp.viewport_bounds.size().as_uvec2(),
-					timing_information,
-					inspect_node,
-					ignore_hash,
-				);
+				let histogram_node = self.histogram_node_id();
+				let document = self.documents.get_mut(&document_id).expect("Tried to render non-existent document");
+
+				// The animated and driven inputs are evaluated at the current time when the graph is updated
+				document.keyframes.set_time(timing_information.animation_time.as_secs_f64());
+
+				let result = self
+					.executor
+					.submit_node_graph_evaluation(document, ipp.viewport_bounds.size().as_uvec2(), timing_information, inspect_node, histogram_node, ignore_hash);
 
 				if let Err(description) = result {
 					responses.add(DialogMessage::DisplayDialogError {
@@ -1128,6 +1563,11 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 						description,
 					});
 				}
+
+				// Move the Timeline panel's playhead along with the animation
+				if self.timeline_panel.timeline_panel_open {
+					responses.add(TimelinePanelMessage::UpdateLayout);
+				}
 			}
 			PortfolioMessage::ToggleRulers => {
 				if let Some(document) = self.active_document_mut() {
@@ -1166,6 +1606,11 @@ impl MessageHandler<PortfolioMessage, PortfolioMessageData<'_>> for PortfolioMes
 	}
 
 	fn actions(&self) -> ActionList {
+		// Presenting takes over the whole window, so only the document's presentation actions are available
+		if let Some(document) = self.active_document().filter(|document| document.is_presenting()) {
+			return document.actions();
+		}
+
 		let mut common = actions!(PortfolioMessageDiscriminant;
 			CloseActiveDocumentWithConfirmation,
 			CloseAllDocuments,
@@ -1269,6 +1714,27 @@ impl PortfolioMessageHandler {
 		responses.add(PortfolioMessage::UpdateOpenDocumentsList);
 	}
 
+	/// The panels which are open, out of those that can be shown and hidden.
+	fn open_panels(&self) -> Vec<PanelType> {
+		[
+			(PanelType::Spreadsheet, self.spreadsheet.spreadsheet_view_open),
+			(PanelType::Histogram, self.histogram.histogram_view_open),
+			(PanelType::Align, self.align_panel.align_panel_open),
+			(PanelType::Navigator, self.navigator_panel.navigator_panel_open),
+			(PanelType::Timeline, self.timeline_panel.timeline_panel_open),
+			(PanelType::Comments, self.comments_panel.comments_panel_open),
+			(PanelType::Swatches, self.swatches_panel.swatches_panel_open),
+			(PanelType::NodeAssets, self.node_assets_panel.node_assets_panel_open),
+			(PanelType::BulkEdit, self.bulk_edit_panel.bulk_edit_panel_open),
+			(PanelType::GraphFind, self.graph_find_panel.graph_find_panel_open),
+			(PanelType::Diagnostics, self.diagnostics_panel.diagnostics_panel_open),
+			(PanelType::Profiler, self.profiler_panel.profiler_panel_open),
+		]
+		.into_iter()
+		.filter_map(|(panel, open)| open.then_some(panel))
+		.collect()
+	}
+
 	/// Returns an iterator over the open documents in order.
 	pub fn ordered_document_iterator(&self) -> impl Iterator<Item = &DocumentMessageHandler> {
 		self.document_ids.iter().map(|id| self.documents.get(id).expect("document id was not found in the document hashmap"))
@@ -1302,19 +1768,39 @@ impl PortfolioMessageHandler {
 
 	/// Get the id of the node that should be used as the target for the spreadsheet
 	pub fn inspect_node_id(&self) -> Option<NodeId> {
-		// Spreadsheet not open, skipping
+		// A node waiting to be baked takes priority, since its output must be captured before it can be embedded
+		if let Some(baking_node) = self.active_document().and_then(|document| document.node_graph_handler.baking_node) {
+			return Some(baking_node);
+		}
+		// Likewise for the image waiting to be analyzed by an automatic adjustment
+		if let Some((source, _, _)) = self.active_document().and_then(|document| document.node_graph_handler.auto_adjusting) {
+			return Some(source);
+		}
+
+		// The spreadsheet isn't open, skipping
 		if !self.spreadsheet.spreadsheet_view_open {
 			return None;
 		}
 
 		let document = self.documents.get(&self.active_document_id?)?;
 		let selected_nodes = document.network_interface.selected_nodes().0;
+		let [node_id] = selected_nodes.as_slice() else { return None };
+		Some(*node_id)
+	}
 
-		// Selected nodes != 1, skipping
-		if selected_nodes.len() != 1 {
+	/// The node whose output is shown in the Histogram panel, which is inspected separately from the one shown in the spreadsheet.
+	pub fn histogram_node_id(&self) -> Option<NodeId> {
+		// The histogram isn't open, skipping
+		if !self.histogram.histogram_view_open {
 			return None;
 		}
 
-		selected_nodes.first().copied()
+		let document = self.documents.get(&self.active_document_id?)?;
+		let selected_nodes = document.network_interface.selected_nodes().0;
+		match selected_nodes.as_slice() {
+			[node_id] => Some(*node_id),
+			// Selected nodes != 1, so the histogram falls back to the node feeding the document's export to show the whole document
+			_ => document.network_interface.document_network().exports.first().and_then(|export| export.as_node()),
+		}
 	}
 }
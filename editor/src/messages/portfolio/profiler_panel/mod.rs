@@ -0,0 +1,7 @@
+mod profiler_panel_message;
+mod profiler_panel_message_handler;
+
+#[doc(inline)]
+pub use profiler_panel_message::*;
+#[doc(inline)]
+pub use profiler_panel_message_handler::*;
@@ -0,0 +1,33 @@
+use crate::messages::prelude::*;
+use graph_craft::document::NodeId;
+
+/// The Profiler panel shows the time spent in each node of the viewed network in the most recent render, so the slow nodes of heavy documents can be found.
+#[impl_message(Message, PortfolioMessage, ProfilerPanel)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum ProfilerPanelMessage {
+	ToggleOpen,
+
+	UpdateLayout,
+
+	/// Sorts the nodes by this column, or reverses their order if they're already sorted by it.
+	SetSortColumn {
+		column: ProfilerColumn,
+	},
+	/// Shows or hides the time spent in each node, as badges and a heatmap coloring, in the node graph.
+	SetGraphOverlay {
+		visible: bool,
+	},
+	/// Selects this node of the viewed network and focuses the graph on it.
+	SelectNode {
+		node_id: NodeId,
+	},
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub enum ProfilerColumn {
+	Node,
+	#[default]
+	Time,
+	Evaluations,
+	Status,
+}
@@ -0,0 +1,201 @@
+use super::ProfilerColumn;
+use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
+use crate::messages::portfolio::document::node_graph::utility_types::FrontendNodeProfile;
+use crate::messages::portfolio::document::utility_types::node_profile::{CacheStatus, NodeProfileEntry};
+use crate::messages::prelude::*;
+use crate::messages::tool::tool_messages::tool_prelude::*;
+use graph_craft::document::NodeId;
+
+pub struct ProfilerPanelMessageData<'a> {
+	pub document: Option<&'a DocumentMessageHandler>,
+}
+
+/// The Profiler panel lists the nodes of the network viewed in the graph with the time each took in the most recent render, sortable by any column.
+/// While it's open, the same times can be shown over the nodes in the graph as millisecond badges and a heatmap coloring.
+#[derive(Debug, Clone, Default)]
+pub struct ProfilerPanelMessageHandler {
+	/// Sets whether or not the Profiler panel is shown.
+	pub profiler_panel_open: bool,
+	sort_column: ProfilerColumn,
+	sort_ascending: bool,
+	graph_overlay: bool,
+}
+
+impl MessageHandler<ProfilerPanelMessage, ProfilerPanelMessageData<'_>> for ProfilerPanelMessageHandler {
+	fn process_message(&mut self, message: ProfilerPanelMessage, responses: &mut VecDeque<Message>, data: ProfilerPanelMessageData) {
+		let ProfilerPanelMessageData { document } = data;
+
+		match message {
+			ProfilerPanelMessage::ToggleOpen => {
+				self.profiler_panel_open = !self.profiler_panel_open;
+
+				// Update checked UI state for open
+				responses.add(MenuBarMessage::SendLayout);
+			}
+			ProfilerPanelMessage::UpdateLayout => {}
+			ProfilerPanelMessage::SetSortColumn { column } => {
+				if self.sort_column == column {
+					self.sort_ascending = !self.sort_ascending;
+				} else {
+					self.sort_column = column;
+					// Names read best from A to Z, while the slowest nodes are the ones worth seeing first
+					self.sort_ascending = column == ProfilerColumn::Node;
+				}
+			}
+			ProfilerPanelMessage::SetGraphOverlay { visible } => self.graph_overlay = visible,
+			ProfilerPanelMessage::SelectNode { node_id } => {
+				responses.add(NodeGraphMessage::SelectedNodesSet { nodes: vec![node_id] });
+				responses.add(DocumentMessage::GraphViewOverlay { open: true });
+				responses.add(NavigationMessage::FitViewportToSelection);
+			}
+		}
+
+		self.update_layout(document, responses);
+	}
+
+	fn actions(&self) -> ActionList {
+		actions!(ProfilerPanelMessage;)
+	}
+}
+
+impl ProfilerPanelMessageHandler {
+	/// The profiled nodes of the network viewed in the graph, with their names, in the order of the sorted column.
+	fn profiled_nodes(&self, document: &DocumentMessageHandler) -> Vec<(NodeId, String, NodeProfileEntry)> {
+		let network_interface = &document.network_interface;
+		let network_path = document.selection_network_path();
+		let Some(network) = network_interface.nested_network(network_path) else { return Vec::new() };
+
+		let mut nodes = document
+			.node_profile
+			.network_profile(network_path)
+			.into_iter()
+			.filter(|(node_id, _)| network.nodes.contains_key(node_id))
+			.map(|(node_id, entry)| (node_id, network_interface.display_name(&node_id, network_path), entry))
+			.collect::<Vec<_>>();
+
+		nodes.sort_by(|(a_id, a_name, a), (b_id, b_name, b)| {
+			let order = match self.sort_column {
+				ProfilerColumn::Node => a_name.cmp(b_name),
+				ProfilerColumn::Time => a.milliseconds.total_cmp(&b.milliseconds),
+				ProfilerColumn::Evaluations => a.evaluations.cmp(&b.evaluations),
+				ProfilerColumn::Status => (a.status as u8).cmp(&(b.status as u8)),
+			};
+			let order = if self.sort_ascending { order } else { order.reverse() };
+			order.then(a_id.cmp(b_id))
+		});
+		nodes
+	}
+
+	fn update_layout(&self, document: Option<&DocumentMessageHandler>, responses: &mut VecDeque<Message>) {
+		let nodes = document.map(|document| self.profiled_nodes(document)).unwrap_or_default();
+
+		// The overlay belongs to the panel, so closing the panel also clears it from the graph
+		let profile = (self.profiler_panel_open && self.graph_overlay).then(|| {
+			let slowest = nodes.iter().map(|(_, _, entry)| entry.milliseconds).fold(0., f64::max);
+			nodes
+				.iter()
+				.map(|(node_id, _, entry)| FrontendNodeProfile {
+					node_id: *node_id,
+					milliseconds: entry.milliseconds,
+					heat: if slowest > 0. { entry.milliseconds / slowest } else { 0. },
+					status: entry.status,
+				})
+				.collect()
+		});
+		responses.add(FrontendMessage::UpdateNodeGraphProfile { profile });
+
+		responses.add(FrontendMessage::UpdateProfilerPanelState { open: self.profiler_panel_open });
+		if !self.profiler_panel_open {
+			return;
+		}
+
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout {
+				layout: self.layout(document, &nodes),
+			}),
+			layout_target: LayoutTarget::ProfilerPanel,
+		});
+	}
+
+	fn layout(&self, document: Option<&DocumentMessageHandler>, nodes: &[(NodeId, String, NodeProfileEntry)]) -> Vec<LayoutGroup> {
+		let mut layout = vec![LayoutGroup::Row {
+			widgets: vec![
+				CheckboxInput::new(self.graph_overlay)
+					.disabled(document.is_none())
+					.tooltip("Show the time spent in each node over it in the graph, colored from cool to hot by how slow it is")
+					.on_update(|checkbox_input: &CheckboxInput| ProfilerPanelMessage::SetGraphOverlay { visible: checkbox_input.checked }.into())
+					.widget_holder(),
+				TextLabel::new("Show in Graph").widget_holder(),
+			],
+		}];
+
+		if nodes.is_empty() {
+			layout.push(LayoutGroup::Row {
+				widgets: vec![TextLabel::new("No nodes of this network were part of the last render").italic(true).widget_holder()],
+			});
+			return layout;
+		}
+
+		let heading = |column: ProfilerColumn, name: &str, tooltip: &str| {
+			let arrow = match (self.sort_column == column, self.sort_ascending) {
+				(false, _) => "",
+				(true, true) => " ▲",
+				(true, false) => " ▼",
+			};
+			TextButton::new(format!("{name}{arrow}"))
+				.flush(true)
+				.tooltip(format!("{tooltip}\n\nClick to sort by this column, or again to reverse the order"))
+				.on_update(move |_| ProfilerPanelMessage::SetSortColumn { column }.into())
+				.widget_holder()
+		};
+		let mut rows = vec![vec![
+			heading(ProfilerColumn::Node, "Node", "The node of the network viewed in the graph"),
+			heading(
+				ProfilerColumn::Time,
+				"Time",
+				"The time spent evaluating the node, including the nodes inside it, in the most recent render",
+			),
+			heading(
+				ProfilerColumn::Evaluations,
+				"Calls",
+				"The number of times the node, or the nodes inside it, were evaluated in the most recent render",
+			),
+			heading(
+				ProfilerColumn::Status,
+				"Status",
+				"Whether the node was evaluated, or its output was reused from a cache instead of being evaluated again",
+			),
+		]];
+
+		rows.extend(nodes.iter().map(|(node_id, name, entry)| {
+			let node_id = *node_id;
+			let status_tooltip = match entry.status {
+				CacheStatus::Evaluated => "Every part of this node was evaluated in the most recent render",
+				CacheStatus::PartlyCached => "Some parts of this node were evaluated while others reused their output from an earlier render",
+				CacheStatus::Cached => "This node's output was reused from an earlier render, so it wasn't evaluated",
+			};
+			vec![
+				TextButton::new(name)
+					.flush(true)
+					.tooltip("Select this node and focus the graph on it")
+					.on_update(move |_| ProfilerPanelMessage::SelectNode { node_id }.into())
+					.widget_holder(),
+				TextLabel::new(format!("{:.2} ms", entry.milliseconds)).widget_holder(),
+				TextLabel::new(entry.evaluations.to_string()).widget_holder(),
+				TextLabel::new(entry.status.label()).tooltip(status_tooltip).widget_holder(),
+			]
+		}));
+
+		let total_milliseconds = nodes.iter().map(|(_, _, entry)| entry.milliseconds).sum::<f64>();
+		let total_evaluations = nodes.iter().map(|(_, _, entry)| entry.evaluations).sum::<u64>();
+		rows.push(vec![
+			TextLabel::new("Total").bold(true).widget_holder(),
+			TextLabel::new(format!("{total_milliseconds:.2} ms")).bold(true).widget_holder(),
+			TextLabel::new(total_evaluations.to_string()).bold(true).widget_holder(),
+			TextLabel::new("").widget_holder(),
+		]);
+
+		layout.push(LayoutGroup::Table { rows });
+		layout
+	}
+}
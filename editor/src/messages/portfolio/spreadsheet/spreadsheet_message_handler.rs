@@ -2,11 +2,10 @@ use super::VectorDataDomain;
 use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
 use crate::messages::prelude::*;
 use crate::messages::tool::tool_messages::tool_prelude::*;
+use crate::node_graph_executor::introspected_output;
 use graph_craft::document::NodeId;
-use graphene_core::Context;
 use graphene_core::GraphicGroupTable;
 use graphene_core::instances::Instances;
-use graphene_core::memo::IORecord;
 use graphene_core::vector::{VectorData, VectorDataTable};
 use graphene_core::{Artboard, ArtboardGroupTable, GraphicElement};
 use std::any::Any;
@@ -108,22 +107,14 @@ struct LayoutData<'a> {
 }
 
 fn generate_layout(introspected_data: &Arc<dyn std::any::Any + Send + Sync + 'static>, data: &mut LayoutData) -> Option<Vec<LayoutGroup>> {
-	// We simply try random types. TODO: better strategy.
-	#[allow(clippy::manual_map)]
-	if let Some(io) = introspected_data.downcast_ref::<IORecord<Context, ArtboardGroupTable>>() {
-		Some(io.output.layout_with_breadcrumb(data))
-	} else if let Some(io) = introspected_data.downcast_ref::<IORecord<(), ArtboardGroupTable>>() {
-		Some(io.output.layout_with_breadcrumb(data))
-	} else if let Some(io) = introspected_data.downcast_ref::<IORecord<Context, VectorDataTable>>() {
-		Some(io.output.layout_with_breadcrumb(data))
-	} else if let Some(io) = introspected_data.downcast_ref::<IORecord<(), VectorDataTable>>() {
-		Some(io.output.layout_with_breadcrumb(data))
-	} else if let Some(io) = introspected_data.downcast_ref::<IORecord<Context, GraphicGroupTable>>() {
-		Some(io.output.layout_with_breadcrumb(data))
-	} else if let Some(io) = introspected_data.downcast_ref::<IORecord<(), GraphicGroupTable>>() {
-		Some(io.output.layout_with_breadcrumb(data))
+	// We simply try the types which can be shown in the spreadsheet
+	let introspected_data = introspected_data.as_ref();
+	if let Some(artboards) = introspected_output::<ArtboardGroupTable>(introspected_data) {
+		Some(artboards.layout_with_breadcrumb(data))
+	} else if let Some(vector_data) = introspected_output::<VectorDataTable>(introspected_data) {
+		Some(vector_data.layout_with_breadcrumb(data))
 	} else {
-		None
+		introspected_output::<GraphicGroupTable>(introspected_data).map(|graphic_group| graphic_group.layout_with_breadcrumb(data))
 	}
 }
 
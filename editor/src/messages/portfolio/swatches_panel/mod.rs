@@ -0,0 +1,7 @@
+mod swatches_panel_message;
+mod swatches_panel_message_handler;
+
+#[doc(inline)]
+pub use swatches_panel_message::*;
+#[doc(inline)]
+pub use swatches_panel_message_handler::*;
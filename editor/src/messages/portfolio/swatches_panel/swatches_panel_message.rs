@@ -0,0 +1,21 @@
+use crate::messages::prelude::*;
+use graphene_core::Color;
+
+/// The Swatches panel lists the named colors saved with the document, and builds a palette from the colors used most in the artwork.
+#[impl_message(Message, PortfolioMessage, SwatchesPanel)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum SwatchesPanelMessage {
+	ToggleOpen,
+
+	UpdateLayout,
+
+	/// Remembers a color chosen in a color picker so it's offered first among the recent colors of every color picker.
+	AddRecentColor {
+		color: Color,
+	},
+
+	/// Sets how many of the most used colors are added as swatches when the palette is extracted.
+	SetExtractCount {
+		count: u32,
+	},
+}
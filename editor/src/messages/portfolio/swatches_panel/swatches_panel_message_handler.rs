@@ -0,0 +1,149 @@
+use crate::consts::MAX_RECENT_COLORS;
+use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
+use crate::messages::portfolio::document::utility_types::swatches::{self, Swatch};
+use crate::messages::prelude::*;
+use crate::messages::tool::tool_messages::tool_prelude::*;
+use graphene_core::Color;
+
+pub struct SwatchesPanelMessageData<'a> {
+	pub document: Option<&'a DocumentMessageHandler>,
+}
+
+/// The Swatches panel lists the named colors saved with the document, and builds a palette from the colors used most in the artwork.
+#[derive(Debug, Clone)]
+pub struct SwatchesPanelMessageHandler {
+	/// Sets whether or not the Swatches panel is shown.
+	pub swatches_panel_open: bool,
+	/// How many of the most used colors in the document are added as swatches when the palette is extracted.
+	extract_count: u32,
+	/// The colors most recently chosen in color pickers, most recent first, which are shared by all documents.
+	recent_colors: Vec<Color>,
+}
+
+impl Default for SwatchesPanelMessageHandler {
+	fn default() -> Self {
+		Self {
+			swatches_panel_open: false,
+			extract_count: 8,
+			recent_colors: Vec::new(),
+		}
+	}
+}
+
+impl MessageHandler<SwatchesPanelMessage, SwatchesPanelMessageData<'_>> for SwatchesPanelMessageHandler {
+	fn process_message(&mut self, message: SwatchesPanelMessage, responses: &mut VecDeque<Message>, data: SwatchesPanelMessageData) {
+		let SwatchesPanelMessageData { document } = data;
+
+		match message {
+			SwatchesPanelMessage::ToggleOpen => {
+				self.swatches_panel_open = !self.swatches_panel_open;
+
+				// Update checked UI state for open
+				responses.add(MenuBarMessage::SendLayout);
+			}
+			SwatchesPanelMessage::UpdateLayout => {}
+			SwatchesPanelMessage::AddRecentColor { color } => {
+				self.recent_colors.retain(|&recent| !swatches::colors_match(recent, color));
+				self.recent_colors.insert(0, color);
+				self.recent_colors.truncate(MAX_RECENT_COLORS);
+			}
+			SwatchesPanelMessage::SetExtractCount { count } => {
+				self.extract_count = count;
+			}
+		}
+
+		self.update_layout(document, responses);
+	}
+
+	fn actions(&self) -> ActionList {
+		actions!(SwatchesPanelMessage;)
+	}
+}
+
+impl SwatchesPanelMessageHandler {
+	fn update_layout(&self, document: Option<&DocumentMessageHandler>, responses: &mut VecDeque<Message>) {
+		// Color pickers offer the swatches even while the panel is closed
+		responses.add(FrontendMessage::UpdateColorPickerSwatches {
+			swatches: document.map(|document| document.swatches.clone()).unwrap_or_default(),
+			recent_colors: self.recent_colors.clone(),
+		});
+
+		responses.add(FrontendMessage::UpdateSwatchesPanelState { open: self.swatches_panel_open });
+		if !self.swatches_panel_open {
+			return;
+		}
+
+		let count = self.extract_count;
+		let mut layout = vec![LayoutGroup::Row {
+			widgets: vec![
+				NumberInput::new(Some(count as f64))
+					.label("Colors")
+					.int()
+					.min(1.)
+					.max(64.)
+					.min_width(100)
+					.disabled(document.is_none())
+					.tooltip("How many of the colors used most often in the document's fills and strokes are added as swatches")
+					.on_update(|number_input: &NumberInput| {
+						SwatchesPanelMessage::SetExtractCount {
+							count: number_input.value.unwrap_or(8.) as u32,
+						}
+						.into()
+					})
+					.widget_holder(),
+				Separator::new(SeparatorType::Related).widget_holder(),
+				TextButton::new("Extract Palette")
+					.tooltip("Add the colors used most often in the document's fills and strokes as swatches, skipping colors already saved as swatches")
+					.disabled(document.is_none())
+					.on_update(move |_| DocumentMessage::ExtractPaletteSwatches { count }.into())
+					.widget_holder(),
+			],
+		}];
+
+		let swatches = document.map(|document| document.swatches.as_slice()).unwrap_or_default();
+		if swatches.is_empty() {
+			layout.push(LayoutGroup::Row {
+				widgets: vec![
+					TextLabel::new("This document has no swatches yet. Extract a palette from the colors used in the artwork to start one.")
+						.multiline(true)
+						.widget_holder(),
+				],
+			});
+		}
+
+		for (index, swatch) in swatches.iter().enumerate() {
+			let (recolored, renamed) = (swatch.clone(), swatch.clone());
+
+			layout.push(LayoutGroup::Row {
+				widgets: vec![
+					ColorInput::new(FillChoice::Solid(swatch.color))
+						.allow_none(false)
+						.on_update(move |color_input: &ColorInput| {
+							let color = color_input.value.as_solid().unwrap_or(recolored.color);
+							let swatch = Swatch::new(recolored.name.clone(), color);
+							DocumentMessage::UpdateSwatch { index, swatch }.into()
+						})
+						.widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					TextInput::new(&swatch.name)
+						.tooltip("Swatch Name")
+						.on_update(move |text_input: &TextInput| {
+							let swatch = Swatch::new(text_input.value.clone(), renamed.color);
+							DocumentMessage::UpdateSwatch { index, swatch }.into()
+						})
+						.widget_holder(),
+					Separator::new(SeparatorType::Related).widget_holder(),
+					IconButton::new("Trash", 16)
+						.tooltip("Delete Swatch")
+						.on_update(move |_| DocumentMessage::DeleteSwatch { index }.into())
+						.widget_holder(),
+				],
+			});
+		}
+
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout { layout }),
+			layout_target: LayoutTarget::SwatchesPanel,
+		});
+	}
+}
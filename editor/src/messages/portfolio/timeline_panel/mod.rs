@@ -0,0 +1,7 @@
+mod timeline_panel_message;
+mod timeline_panel_message_handler;
+
+#[doc(inline)]
+pub use timeline_panel_message::*;
+#[doc(inline)]
+pub use timeline_panel_message_handler::*;
@@ -0,0 +1,19 @@
+use crate::messages::prelude::*;
+
+/// The Timeline panel scrubs through the animation and lists the keyframes of every animated node input.
+#[impl_message(Message, PortfolioMessage, TimelinePanel)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum TimelinePanelMessage {
+	ToggleOpen,
+
+	UpdateLayout,
+
+	/// Moves the playhead to the given time in seconds.
+	SetTime {
+		time: f64,
+	},
+	/// Sets the length in seconds of the range shown by the time slider.
+	SetDuration {
+		duration: f64,
+	},
+}
@@ -0,0 +1,156 @@
+use crate::messages::animation::TimingInformation;
+use crate::messages::input_mapper::utility_types::macros::action_keys;
+use crate::messages::layout::utility_types::layout_widget::{Layout, LayoutGroup, LayoutTarget, WidgetLayout};
+use crate::messages::prelude::*;
+use crate::messages::tool::tool_messages::tool_prelude::*;
+
+pub struct TimelinePanelMessageData<'a> {
+	pub document: Option<&'a DocumentMessageHandler>,
+	pub animation: &'a AnimationMessageHandler,
+	pub timing_information: TimingInformation,
+}
+
+/// The Timeline panel scrubs through the animation and lists the keyframes of every animated node input.
+#[derive(Debug, Clone)]
+pub struct TimelinePanelMessageHandler {
+	/// Sets whether or not the Timeline panel is shown.
+	pub timeline_panel_open: bool,
+	/// The length in seconds of the range shown by the time slider.
+	duration: f64,
+}
+
+impl Default for TimelinePanelMessageHandler {
+	fn default() -> Self {
+		Self {
+			timeline_panel_open: false,
+			duration: 10.,
+		}
+	}
+}
+
+impl MessageHandler<TimelinePanelMessage, TimelinePanelMessageData<'_>> for TimelinePanelMessageHandler {
+	fn process_message(&mut self, message: TimelinePanelMessage, responses: &mut VecDeque<Message>, data: TimelinePanelMessageData) {
+		let TimelinePanelMessageData {
+			document,
+			animation,
+			timing_information,
+		} = data;
+
+		match message {
+			TimelinePanelMessage::ToggleOpen => {
+				self.timeline_panel_open = !self.timeline_panel_open;
+
+				// Update checked UI state for open
+				responses.add(MenuBarMessage::SendLayout);
+			}
+			TimelinePanelMessage::UpdateLayout => {}
+			TimelinePanelMessage::SetTime { time } => {
+				responses.add(AnimationMessage::SetAnimationTime(time.max(0.)));
+				// Show the values that the animated inputs take on at the new time
+				responses.add(PropertiesPanelMessage::Refresh);
+				return;
+			}
+			TimelinePanelMessage::SetDuration { duration } => self.duration = duration,
+		}
+
+		self.update_layout(document, animation, timing_information, responses);
+	}
+
+	fn actions(&self) -> ActionList {
+		actions!(TimelinePanelMessage;)
+	}
+}
+
+impl TimelinePanelMessageHandler {
+	fn update_layout(&self, document: Option<&DocumentMessageHandler>, animation: &AnimationMessageHandler, timing_information: TimingInformation, responses: &mut VecDeque<Message>) {
+		responses.add(FrontendMessage::UpdateTimelinePanelState { open: self.timeline_panel_open });
+		// This is sent on every frame of playback, so skip all work while the panel is hidden
+		if !self.timeline_panel_open {
+			return;
+		}
+
+		let time = timing_information.animation_time.as_secs_f64();
+		let is_playing = animation.is_playing();
+
+		let mut layout = vec![LayoutGroup::Row {
+			widgets: vec![
+				IconButton::new("PlaybackToStart", 24)
+					.tooltip("Restart Animation")
+					.tooltip_shortcut(action_keys!(AnimationMessageDiscriminant::RestartAnimation))
+					.on_update(|_| AnimationMessage::RestartAnimation.into())
+					.widget_holder(),
+				IconButton::new(if is_playing { "PlaybackPause" } else { "PlaybackPlay" }, 24)
+					.tooltip(if is_playing { "Pause Animation" } else { "Play Animation" })
+					.tooltip_shortcut(action_keys!(AnimationMessageDiscriminant::ToggleLivePreview))
+					.on_update(|_| AnimationMessage::ToggleLivePreview.into())
+					.widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				NumberInput::new(Some(time))
+					.label("Time")
+					.unit(" s")
+					.min(0.)
+					.range_min(Some(0.))
+					.range_max(Some(self.duration))
+					.mode_range()
+					.tooltip("Drag to scrub through the animation")
+					.on_update(|number_input: &NumberInput| {
+						TimelinePanelMessage::SetTime {
+							time: number_input.value.unwrap_or_default(),
+						}
+						.into()
+					})
+					.disabled(document.is_none())
+					.widget_holder(),
+				Separator::new(SeparatorType::Unrelated).widget_holder(),
+				NumberInput::new(Some(self.duration))
+					.label("Duration")
+					.unit(" s")
+					.min(0.1)
+					.tooltip("The length of the time range that can be scrubbed through")
+					.on_update(|number_input: &NumberInput| {
+						TimelinePanelMessage::SetDuration {
+							duration: number_input.value.unwrap_or(10.),
+						}
+						.into()
+					})
+					.widget_holder(),
+			],
+		}];
+
+		let animated_inputs = document.map(|document| document.keyframes.animated_inputs().collect::<Vec<_>>()).unwrap_or_default();
+		if animated_inputs.is_empty() {
+			layout.push(LayoutGroup::Row {
+				widgets: vec![TextLabel::new("Animate a node input by adding a keyframe with the diamond button beside it in the Properties panel").widget_holder()],
+			});
+		}
+
+		for animated_input in animated_inputs {
+			let Some(document) = document else { break };
+			let network_interface = &document.network_interface;
+			let (node_id, network_path) = (animated_input.node_id, animated_input.network_path.as_slice());
+
+			// Skip inputs whose node has since been deleted
+			let Some(input_name) = network_interface.input_name(&node_id, animated_input.input_index, network_path) else {
+				continue;
+			};
+			let label = format!("{}: {input_name}", network_interface.display_name(&node_id, network_path));
+
+			let mut widgets = vec![TextLabel::new(label).min_width(160).widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
+			widgets.extend(animated_input.keyframes.iter().map(|keyframe| {
+				let keyframe_time = keyframe.time;
+				let is_current = animated_input.keyframe_at(time).is_some_and(|index| animated_input.keyframes[index].time == keyframe_time);
+
+				IconButton::new(if is_current { "KeyframeSet" } else { "KeyframeUnset" }, 24)
+					.tooltip(format!("Jump to Keyframe at {keyframe_time:.2} s"))
+					.on_update(move |_| TimelinePanelMessage::SetTime { time: keyframe_time }.into())
+					.widget_holder()
+			}));
+			layout.push(LayoutGroup::Row { widgets });
+		}
+
+		responses.add(LayoutMessage::SendLayout {
+			layout: Layout::WidgetLayout(WidgetLayout { layout }),
+			layout_target: LayoutTarget::TimelinePanel,
+		});
+	}
+}
@@ -1,8 +1,14 @@
-use graphene_std::text::FontCache;
+use crate::messages::portfolio::document::utility_types::misc::{DocumentId, PTZ};
+use crate::messages::portfolio::document::utility_types::network_interface::NodeTemplate;
+use crate::messages::tool::utility_types::ToolSession;
+use graphene_std::text::{Font, FontCache};
+use std::collections::HashSet;
 
 #[derive(Debug, Default)]
 pub struct PersistentData {
 	pub font_cache: FontCache,
+	/// The fonts used by documents which couldn't be found, whose text is drawn with the default font until they're substituted or located.
+	pub unavailable_fonts: HashSet<Font>,
 	pub use_vello: bool,
 	// pub imaginate: ImaginatePersistentData,
 }
@@ -40,21 +46,78 @@ pub enum KeyboardPlatformLayout {
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub enum PanelType {
+	Align,
+	BulkEdit,
+	Comments,
+	Diagnostics,
 	#[default]
 	Document,
+	GraphFind,
+	Histogram,
 	Layers,
+	Navigator,
+	NodeAssets,
+	Profiler,
 	Properties,
 	Spreadsheet,
+	Swatches,
+	Timeline,
 }
 
 impl From<String> for PanelType {
 	fn from(value: String) -> Self {
 		match value.as_str() {
+			"Align" => PanelType::Align,
+			"BulkEdit" => PanelType::BulkEdit,
+			"Comments" => PanelType::Comments,
+			"Diagnostics" => PanelType::Diagnostics,
 			"Document" => PanelType::Document,
+			"GraphFind" => PanelType::GraphFind,
+			"Histogram" => PanelType::Histogram,
 			"Layers" => PanelType::Layers,
+			"Navigator" => PanelType::Navigator,
+			"NodeAssets" => PanelType::NodeAssets,
+			"Profiler" => PanelType::Profiler,
 			"Properties" => PanelType::Properties,
 			"Spreadsheet" => PanelType::Spreadsheet,
+			"Swatches" => PanelType::Swatches,
+			"Timeline" => PanelType::Timeline,
 			_ => panic!("Unknown panel type: {}", value),
 		}
 	}
 }
+
+/// The state of the workspace around the open documents, which is saved as it changes and restored when the editor is next opened so the session continues where it left off.
+/// The open documents themselves are auto-saved separately.
+#[derive(PartialEq, Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Session {
+	/// The view of each open document, which isn't otherwise kept up to date in its auto-save since navigating the canvas doesn't change the document.
+	pub document_views: Vec<(DocumentId, PTZ)>,
+	pub node_graph_open: bool,
+	/// The panels which are open, out of those that can be shown and hidden.
+	pub open_panels: Vec<PanelType>,
+	pub tools: ToolSession,
+	/// The documents most recently opened from or saved to files, newest first.
+	pub recent_files: Vec<RecentFile>,
+	/// The library of nodes saved for reuse in any document.
+	pub node_assets: Vec<NodeAsset>,
+}
+
+/// A node made of other nodes, saved to the library so it can be inserted into any document, where its instances follow the edits made to the asset.
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct NodeAsset {
+	pub id: u64,
+	pub name: String,
+	/// Every saved version of the asset's node, oldest first, so the last is the current one.
+	pub versions: Vec<NodeTemplate>,
+}
+
+/// A document that was recently opened from or saved to a file, which is kept so it can be reopened from the File menu.
+/// Its content is stored by the frontend in IndexedDB rather than in the session, which only refers to it by key.
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecentFile {
+	pub name: String,
+	/// The hash of the file's content, which it's stored under in IndexedDB, so files with the same name but different content are kept apart.
+	pub key: u64,
+}
@@ -1,6 +1,9 @@
+use crate::messages::dialog::new_document_dialog::document_templates::DocumentTemplate;
+use crate::messages::portfolio::document::node_graph::adjustment_presets::AdjustmentPreset;
 use crate::messages::portfolio::document::node_graph::utility_types::GraphWireStyle;
 use crate::messages::preferences::SelectionMode;
 use crate::messages::prelude::*;
+use graphene_core::raster::curve::Curve;
 
 #[impl_message(Message, Preferences)]
 #[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -16,6 +19,11 @@ pub enum PreferencesMessage {
 	ModifyLayout { zoom_with_scroll: bool },
 	GraphWireStyle { style: GraphWireStyle },
 	ViewportZoomWheelRate { rate: f64 },
+	PressureCurve { curve: Curve },
+	SaveDocumentTemplate { template: DocumentTemplate },
+	DeleteDocumentTemplate { name: String },
+	SaveAdjustmentPreset { preset: AdjustmentPreset },
+	DeleteAdjustmentPreset { reference: String, name: String },
 	// ImaginateRefreshFrequency { seconds: f64 },
 	// ImaginateServerHostname { hostname: String },
 }
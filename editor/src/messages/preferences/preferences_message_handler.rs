@@ -1,9 +1,12 @@
 use crate::consts::VIEWPORT_ZOOM_WHEEL_RATE;
+use crate::messages::dialog::new_document_dialog::document_templates::DocumentTemplate;
 use crate::messages::input_mapper::key_mapping::MappingVariant;
+use crate::messages::portfolio::document::node_graph::adjustment_presets::AdjustmentPreset;
 use crate::messages::portfolio::document::node_graph::utility_types::GraphWireStyle;
 use crate::messages::preferences::SelectionMode;
 use crate::messages::prelude::*;
 use graph_craft::wasm_application_io::EditorPreferences;
+use graphene_core::raster::curve::Curve;
 
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
 pub struct PreferencesMessageHandler {
@@ -15,6 +18,15 @@ pub struct PreferencesMessageHandler {
 	pub vector_meshes: bool,
 	pub graph_wire_style: GraphWireStyle,
 	pub viewport_zoom_wheel_rate: f64,
+	/// Remaps the pressure reported by a stylus before any tool uses it, since tablets vary widely in how hard they must be pressed to reach a given pressure.
+	#[serde(default)]
+	pub pressure_curve: Curve,
+	/// The templates saved by the user from their own documents, which are offered in the New Document dialog.
+	#[serde(default)]
+	pub document_templates: Vec<DocumentTemplate>,
+	/// The presets saved by the user from the values of their adjustment nodes, which are offered in the presets dropdown of nodes of the same kind.
+	#[serde(default)]
+	pub adjustment_presets: Vec<AdjustmentPreset>,
 }
 
 impl PreferencesMessageHandler {
@@ -45,6 +57,9 @@ impl Default for PreferencesMessageHandler {
 			vector_meshes: false,
 			graph_wire_style: GraphWireStyle::default(),
 			viewport_zoom_wheel_rate: VIEWPORT_ZOOM_WHEEL_RATE,
+			pressure_curve: Curve::default(),
+			document_templates: Vec::new(),
+			adjustment_presets: Vec::new(),
 		}
 	}
 }
@@ -72,7 +87,12 @@ impl MessageHandler<PreferencesMessage, ()> for PreferencesMessageHandler {
 				refresh_dialog(responses);
 				responses.add(KeyMappingMessage::ModifyMapping(MappingVariant::Default));
 
-				*self = Self::default()
+				// Saved templates and presets are the user's own work rather than a preference, so they're kept
+				*self = Self {
+					document_templates: std::mem::take(&mut self.document_templates),
+					adjustment_presets: std::mem::take(&mut self.adjustment_presets),
+					..Self::default()
+				}
 			}
 
 			// Per-preference messages
@@ -96,10 +116,31 @@ impl MessageHandler<PreferencesMessage, ()> for PreferencesMessageHandler {
 			PreferencesMessage::GraphWireStyle { style } => {
 				self.graph_wire_style = style;
 				responses.add(NodeGraphMessage::SendGraph);
+				responses.add(NodeGraphMessage::UpdateGraphBarRight);
 			}
 			PreferencesMessage::ViewportZoomWheelRate { rate } => {
 				self.viewport_zoom_wheel_rate = rate;
 			}
+			PreferencesMessage::PressureCurve { curve } => {
+				self.pressure_curve = curve;
+			}
+			PreferencesMessage::SaveDocumentTemplate { template } => {
+				// A template saved with the name of an existing one replaces it
+				self.document_templates.retain(|existing| existing.name != template.name);
+				self.document_templates.push(template);
+			}
+			PreferencesMessage::DeleteDocumentTemplate { name } => {
+				self.document_templates.retain(|template| template.name != name);
+			}
+			PreferencesMessage::SaveAdjustmentPreset { preset } => {
+				self.adjustment_presets.retain(|existing| existing.reference != preset.reference || existing.name != preset.name);
+				self.adjustment_presets.push(preset);
+				responses.add(PropertiesPanelMessage::Refresh);
+			}
+			PreferencesMessage::DeleteAdjustmentPreset { reference, name } => {
+				self.adjustment_presets.retain(|preset| preset.reference != reference || preset.name != name);
+				responses.add(PropertiesPanelMessage::Refresh);
+			}
 		}
 		// TODO: Reenable when Imaginate is restored (and move back up one line since the auto-formatter doesn't like it in that block)
 		// PreferencesMessage::ImaginateRefreshFrequency { seconds } => {
@@ -6,8 +6,12 @@ pub use crate::messages::animation::{AnimationMessage, AnimationMessageDiscrimin
 pub use crate::messages::broadcast::{BroadcastMessage, BroadcastMessageDiscriminant, BroadcastMessageHandler};
 pub use crate::messages::debug::{DebugMessage, DebugMessageDiscriminant, DebugMessageHandler};
 pub use crate::messages::dialog::export_dialog::{ExportDialogMessage, ExportDialogMessageData, ExportDialogMessageDiscriminant, ExportDialogMessageHandler};
+pub use crate::messages::dialog::find_replace_dialog::{FindReplaceDialogMessage, FindReplaceDialogMessageData, FindReplaceDialogMessageDiscriminant, FindReplaceDialogMessageHandler};
+pub use crate::messages::dialog::missing_fonts_dialog::{MissingFontsDialogMessage, MissingFontsDialogMessageData, MissingFontsDialogMessageDiscriminant, MissingFontsDialogMessageHandler};
 pub use crate::messages::dialog::new_document_dialog::{NewDocumentDialogMessage, NewDocumentDialogMessageDiscriminant, NewDocumentDialogMessageHandler};
 pub use crate::messages::dialog::preferences_dialog::{PreferencesDialogMessage, PreferencesDialogMessageData, PreferencesDialogMessageDiscriminant, PreferencesDialogMessageHandler};
+pub use crate::messages::dialog::replace_font_dialog::{ReplaceFontDialogMessage, ReplaceFontDialogMessageData, ReplaceFontDialogMessageDiscriminant, ReplaceFontDialogMessageHandler};
+pub use crate::messages::dialog::transform_each_dialog::{TransformEachDialogMessage, TransformEachDialogMessageDiscriminant, TransformEachDialogMessageHandler};
 pub use crate::messages::dialog::{DialogMessage, DialogMessageData, DialogMessageDiscriminant, DialogMessageHandler};
 pub use crate::messages::frontend::{FrontendMessage, FrontendMessageDiscriminant};
 pub use crate::messages::globals::{GlobalsMessage, GlobalsMessageDiscriminant, GlobalsMessageHandler};
@@ -15,14 +19,26 @@ pub use crate::messages::input_mapper::key_mapping::{KeyMappingMessage, KeyMappi
 pub use crate::messages::input_mapper::{InputMapperMessage, InputMapperMessageData, InputMapperMessageDiscriminant, InputMapperMessageHandler};
 pub use crate::messages::input_preprocessor::{InputPreprocessorMessage, InputPreprocessorMessageData, InputPreprocessorMessageDiscriminant, InputPreprocessorMessageHandler};
 pub use crate::messages::layout::{LayoutMessage, LayoutMessageDiscriminant, LayoutMessageHandler};
+pub use crate::messages::portfolio::align_panel::{AlignPanelMessage, AlignPanelMessageDiscriminant};
+pub use crate::messages::portfolio::bulk_edit_panel::{BulkEditPanelMessage, BulkEditPanelMessageDiscriminant};
+pub use crate::messages::portfolio::comments_panel::{CommentsPanelMessage, CommentsPanelMessageDiscriminant};
+pub use crate::messages::portfolio::diagnostics_panel::{DiagnosticsPanelMessage, DiagnosticsPanelMessageDiscriminant};
+pub use crate::messages::portfolio::document::compare::{CompareMessage, CompareMessageData, CompareMessageDiscriminant, CompareMessageHandler};
 pub use crate::messages::portfolio::document::graph_operation::{GraphOperationMessage, GraphOperationMessageData, GraphOperationMessageDiscriminant, GraphOperationMessageHandler};
 pub use crate::messages::portfolio::document::navigation::{NavigationMessage, NavigationMessageData, NavigationMessageDiscriminant, NavigationMessageHandler};
 pub use crate::messages::portfolio::document::node_graph::{NodeGraphMessage, NodeGraphMessageDiscriminant, NodeGraphMessageHandler};
 pub use crate::messages::portfolio::document::overlays::{OverlaysMessage, OverlaysMessageData, OverlaysMessageDiscriminant, OverlaysMessageHandler};
 pub use crate::messages::portfolio::document::properties_panel::{PropertiesPanelMessage, PropertiesPanelMessageDiscriminant, PropertiesPanelMessageHandler};
 pub use crate::messages::portfolio::document::{DocumentMessage, DocumentMessageData, DocumentMessageDiscriminant, DocumentMessageHandler};
+pub use crate::messages::portfolio::graph_find_panel::{GraphFindPanelMessage, GraphFindPanelMessageDiscriminant};
+pub use crate::messages::portfolio::histogram::{HistogramMessage, HistogramMessageDiscriminant};
 pub use crate::messages::portfolio::menu_bar::{MenuBarMessage, MenuBarMessageDiscriminant, MenuBarMessageHandler};
+pub use crate::messages::portfolio::navigator_panel::{NavigatorPanelMessage, NavigatorPanelMessageDiscriminant};
+pub use crate::messages::portfolio::node_assets_panel::{NodeAssetsPanelMessage, NodeAssetsPanelMessageDiscriminant};
+pub use crate::messages::portfolio::profiler_panel::{ProfilerPanelMessage, ProfilerPanelMessageDiscriminant};
 pub use crate::messages::portfolio::spreadsheet::{SpreadsheetMessage, SpreadsheetMessageDiscriminant};
+pub use crate::messages::portfolio::swatches_panel::{SwatchesPanelMessage, SwatchesPanelMessageDiscriminant};
+pub use crate::messages::portfolio::timeline_panel::{TimelinePanelMessage, TimelinePanelMessageDiscriminant};
 pub use crate::messages::portfolio::{PortfolioMessage, PortfolioMessageData, PortfolioMessageDiscriminant, PortfolioMessageHandler};
 pub use crate::messages::preferences::{PreferencesMessage, PreferencesMessageDiscriminant, PreferencesMessageHandler};
 pub use crate::messages::tool::transform_layer::{TransformLayerMessage, TransformLayerMessageDiscriminant, TransformLayerMessageHandler};
@@ -34,6 +50,7 @@ pub use crate::messages::broadcast::broadcast_event::{BroadcastEvent, BroadcastE
 pub use crate::messages::message::{Message, MessageDiscriminant};
 pub use crate::messages::tool::tool_messages::artboard_tool::{ArtboardToolMessage, ArtboardToolMessageDiscriminant};
 pub use crate::messages::tool::tool_messages::brush_tool::{BrushToolMessage, BrushToolMessageDiscriminant};
+pub use crate::messages::tool::tool_messages::comment_tool::{CommentToolMessage, CommentToolMessageDiscriminant};
 pub use crate::messages::tool::tool_messages::ellipse_tool::{EllipseToolMessage, EllipseToolMessageDiscriminant};
 pub use crate::messages::tool::tool_messages::eyedropper_tool::{EyedropperToolMessage, EyedropperToolMessageDiscriminant};
 pub use crate::messages::tool::tool_messages::fill_tool::{FillToolMessage, FillToolMessageDiscriminant};
@@ -287,6 +287,24 @@ pub fn get_blend_mode(layer: LayerNodeIdentifier, network_interface: &NodeNetwor
 	Some(*blend_mode)
 }
 
+/// Get the distance the background of an artboard layer extends past its edges
+pub fn get_artboard_bleed(layer: LayerNodeIdentifier, network_interface: &NodeNetworkInterface) -> Option<f64> {
+	let node = network_interface.document_network().nodes.get(&layer.to_node())?;
+	let TaggedValue::F64(bleed) = node.inputs.get(6)?.as_value()? else {
+		return None;
+	};
+	Some(*bleed)
+}
+
+/// Get the distance of the safe area guide inset from the edges of an artboard layer
+pub fn get_artboard_margin(layer: LayerNodeIdentifier, network_interface: &NodeNetworkInterface) -> Option<f64> {
+	let node = network_interface.document_network().nodes.get(&layer.to_node())?;
+	let TaggedValue::F64(margin) = node.inputs.get(7)?.as_value()? else {
+		return None;
+	};
+	Some(*margin)
+}
+
 /// Get the current opacity of a layer from the closest Opacity node.
 /// This may differ from the actual opacity contained within the data type reaching this layer, because that actual opacity may be:
 /// - Multiplied with additional opacity nodes earlier in the chain
@@ -342,6 +360,8 @@ pub fn get_text(layer: LayerNodeIdentifier, network_interface: &NodeNetworkInter
 	let Some(&TaggedValue::F64(character_spacing)) = inputs[5].as_value() else { return None };
 	let Some(&TaggedValue::OptionalF64(max_width)) = inputs[6].as_value() else { return None };
 	let Some(&TaggedValue::OptionalF64(max_height)) = inputs[7].as_value() else { return None };
+	let Some(&TaggedValue::TextAlign(align)) = inputs[8].as_value() else { return None };
+	let Some(&TaggedValue::VerticalAlign(vertical_align)) = inputs[9].as_value() else { return None };
 
 	let typesetting = TypesettingConfig {
 		font_size,
@@ -349,6 +369,8 @@ pub fn get_text(layer: LayerNodeIdentifier, network_interface: &NodeNetworkInter
 		max_width,
 		character_spacing,
 		max_height,
+		align,
+		vertical_align,
 	};
 	Some((text, font, typesetting))
 }
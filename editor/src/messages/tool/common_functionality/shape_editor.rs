@@ -787,7 +787,8 @@ impl ShapeState {
 			let delta_transform = if in_viewport_space {
 				transform_to_viewport_space
 			} else {
-				DAffine2::from_angle(document.document_ptz.tilt()) * transform_to_document_space
+				let flip = DAffine2::from_scale(DVec2::new(if document.document_ptz.flip() { -1. } else { 1. }, 1.));
+				flip * DAffine2::from_angle(document.document_ptz.tilt()) * transform_to_document_space
 			};
 			let delta = delta_transform.inverse().transform_vector2(delta);
 
@@ -302,6 +302,21 @@ impl SnapManager {
 			best_point = Some(point);
 		}
 
+		// Fall back to the nearest pixel corner so geometry lands on whole pixels even where there's nothing else to snap to
+		if best_point.is_none() && !constrained && document.snapping_state.target_enabled(SnapTarget::Grid(GridSnapTarget::Pixel)) {
+			let pixel_corner = point.document_point.round();
+			best_point = Some(SnappedPoint {
+				snapped_point_document: pixel_corner,
+				source: point.source,
+				target: SnapTarget::Grid(GridSnapTarget::Pixel),
+				source_bounds: point.quad,
+				distance: point.document_point.distance(pixel_corner),
+				// The farthest any point can be from its nearest pixel corner
+				tolerance: std::f64::consts::FRAC_1_SQRT_2,
+				..Default::default()
+			});
+		}
+
 		best_point.unwrap_or(SnappedPoint::infinite_snap(point.document_point))
 	}
 
@@ -446,7 +461,8 @@ impl SnapManager {
 
 	pub fn draw_overlays(&mut self, snap_data: SnapData, overlay_context: &mut OverlayContext) {
 		let to_viewport = snap_data.document.metadata().document_to_viewport;
-		if let Some(ind) = &self.indicator {
+		// Snapping to the pixel grid is constant while it's enabled, so it isn't worth pointing out
+		if let Some(ind) = self.indicator.as_ref().filter(|ind| ind.target != SnapTarget::Grid(GridSnapTarget::Pixel)) {
 			for layer in &ind.outline_layers {
 				let &Some(layer) = layer else { continue };
 				overlay_context.outline(snap_data.document.metadata().layer_outline(layer), snap_data.document.metadata().transform_to_viewport(layer));
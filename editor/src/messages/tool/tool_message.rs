@@ -1,4 +1,4 @@
-use super::utility_types::ToolType;
+use super::utility_types::{ToolSession, ToolType};
 use crate::messages::preferences::SelectionMode;
 use crate::messages::prelude::*;
 use graphene_core::raster::color::Color;
@@ -22,6 +22,8 @@ pub enum ToolMessage {
 	Fill(FillToolMessage),
 	#[child]
 	Gradient(GradientToolMessage),
+	#[child]
+	Comment(CommentToolMessage),
 
 	#[child]
 	Path(PathToolMessage),
@@ -65,6 +67,7 @@ pub enum ToolMessage {
 	ActivateToolText,
 	ActivateToolFill,
 	ActivateToolGradient,
+	ActivateToolComment,
 
 	ActivateToolPath,
 	ActivateToolPen,
@@ -87,6 +90,9 @@ pub enum ToolMessage {
 	Redo,
 	RefreshToolOptions,
 	ResetColors,
+	RestoreSession {
+		session: ToolSession,
+	},
 	SelectPrimaryColor {
 		color: Color,
 	},
@@ -1,5 +1,5 @@
 use super::common_functionality::shape_editor::ShapeState;
-use super::utility_types::{ToolActionHandlerData, ToolFsmState, tool_message_to_tool_type};
+use super::utility_types::{ToolActionHandlerData, ToolFsmState, ToolSession, tool_message_to_tool_type, tool_option_key};
 use crate::application::generate_uuid;
 use crate::messages::layout::utility_types::widget_prelude::*;
 use crate::messages::portfolio::document::overlays::utility_types::OverlayProvider;
@@ -26,6 +26,8 @@ pub struct ToolMessageHandler {
 	pub transform_layer_handler: TransformLayerMessageHandler,
 	pub shape_editor: ShapeState,
 	pub tool_is_active: bool,
+	/// The most recent change to each tool option, which is saved with the workspace session.
+	option_updates: Vec<ToolMessage>,
 }
 
 impl MessageHandler<ToolMessage, ToolMessageData<'_>> for ToolMessageHandler {
@@ -53,6 +55,7 @@ impl MessageHandler<ToolMessage, ToolMessageData<'_>> for ToolMessageHandler {
 			ToolMessage::ActivateToolText => responses.add_front(ToolMessage::ActivateTool { tool_type: ToolType::Text }),
 			ToolMessage::ActivateToolFill => responses.add_front(ToolMessage::ActivateTool { tool_type: ToolType::Fill }),
 			ToolMessage::ActivateToolGradient => responses.add_front(ToolMessage::ActivateTool { tool_type: ToolType::Gradient }),
+			ToolMessage::ActivateToolComment => responses.add_front(ToolMessage::ActivateTool { tool_type: ToolType::Comment }),
 
 			ToolMessage::ActivateToolPath => responses.add_front(ToolMessage::ActivateTool { tool_type: ToolType::Path }),
 			ToolMessage::ActivateToolPen => responses.add_front(ToolMessage::ActivateTool { tool_type: ToolType::Pen }),
@@ -214,6 +217,46 @@ impl MessageHandler<ToolMessage, ToolMessageData<'_>> for ToolMessageHandler {
 
 				document_data.update_working_colors(responses); // TODO: Make this an event
 			}
+			ToolMessage::RestoreSession { session } => {
+				let ToolSession {
+					active_tool,
+					primary_color,
+					secondary_color,
+					option_updates,
+				} = session;
+
+				let document_data = &mut self.tool_state.document_tool_data;
+				document_data.primary_color = primary_color.unwrap_or(document_data.primary_color);
+				document_data.secondary_color = secondary_color.unwrap_or(document_data.secondary_color);
+				document_data.update_working_colors(responses);
+
+				// Each option change is given directly to its tool, since tools otherwise only receive messages while they are active
+				let tool_data = &mut self.tool_state.tool_data;
+				for option_update in option_updates {
+					let Some(key) = tool_option_key(&option_update) else { continue };
+					let Some(tool) = tool_data.tools.get_mut(&tool_message_to_tool_type(&option_update)) else {
+						continue;
+					};
+
+					let mut data = ToolActionHandlerData {
+						document: &mut *document,
+						document_id,
+						global_tool_data: &self.tool_state.document_tool_data,
+						input,
+						font_cache,
+						shape_editor: &mut self.shape_editor,
+						node_graph,
+						preferences,
+					};
+					tool.process_message(option_update.clone(), responses, &mut data);
+
+					self.option_updates.retain(|existing| tool_option_key(existing).as_ref() != Some(&key));
+					self.option_updates.push(option_update);
+				}
+
+				responses.add(ToolMessage::ActivateTool { tool_type: active_tool });
+				responses.add(ToolMessage::RefreshToolOptions);
+			}
 			ToolMessage::SelectPrimaryColor { color } => {
 				let document_data = &mut self.tool_state.document_tool_data;
 				document_data.primary_color = color;
@@ -285,6 +328,10 @@ impl MessageHandler<ToolMessage, ToolMessageData<'_>> for ToolMessageHandler {
 								tool.process_message(ToolMessage::UpdateHints, responses, &mut data)
 							}
 						} else {
+							if let Some(key) = tool_option_key(&tool_message) {
+								self.option_updates.retain(|existing| tool_option_key(existing).as_ref() != Some(&key));
+								self.option_updates.push(tool_message.clone());
+							}
 							tool.process_message(tool_message, responses, &mut data);
 						}
 					}
@@ -302,6 +349,7 @@ impl MessageHandler<ToolMessage, ToolMessageData<'_>> for ToolMessageHandler {
 			ActivateToolText,
 			ActivateToolFill,
 			ActivateToolGradient,
+			ActivateToolComment,
 
 			ActivateToolPath,
 			ActivateToolPen,
@@ -326,3 +374,16 @@ impl MessageHandler<ToolMessage, ToolMessageData<'_>> for ToolMessageHandler {
 		list
 	}
 }
+
+impl ToolMessageHandler {
+	/// The state of the tools to be saved with the workspace session.
+	pub fn session(&self) -> ToolSession {
+		let document_data = &self.tool_state.document_tool_data;
+		ToolSession {
+			active_tool: self.tool_state.tool_data.active_tool_type,
+			primary_color: Some(document_data.primary_color),
+			secondary_color: Some(document_data.secondary_color),
+			option_updates: self.option_updates.clone(),
+		}
+	}
+}
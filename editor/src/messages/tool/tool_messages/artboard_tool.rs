@@ -341,6 +341,8 @@ impl Fsm for ArtboardToolFsmState {
 							dimensions: (start.round() - end.round()).abs().as_ivec2(),
 							background: graphene_core::Color::WHITE,
 							clip: false,
+							bleed: 0.,
+							margin: 0.,
 						},
 					})
 				}
@@ -451,6 +453,9 @@ impl Fsm for ArtboardToolFsmState {
 					return ArtboardToolFsmState::Ready { hovered };
 				}
 
+				// Mirror the horizontal direction while the view is flipped
+				let delta_x = if document.document_ptz.flip() { -delta_x } else { delta_x };
+
 				let resize = input.keyboard.key(resize);
 				let resize_opposite_corner = input.keyboard.key(resize_opposite_corner);
 				let [existing_top_left, existing_bottom_right] = bounds.bounds;
@@ -470,13 +475,22 @@ impl Fsm for ArtboardToolFsmState {
 
 				// Swap and negate coordinates as needed to match the resize direction that's closest to the current tilt angle
 				let tilt = (document.document_ptz.tilt() + std::f64::consts::TAU) % std::f64::consts::TAU;
-				let (delta_x, delta_y, opposite_x, opposite_y) = match ((tilt + std::f64::consts::FRAC_PI_4) / std::f64::consts::FRAC_PI_2).floor() as i32 % 4 {
+				let quadrant = ((tilt + std::f64::consts::FRAC_PI_4) / std::f64::consts::FRAC_PI_2).floor() as i32 % 4;
+				let (delta_x, delta_y, mut opposite_x, mut opposite_y) = match quadrant {
 					0 => (delta_x, delta_y, false, false),
 					1 => (delta_y, -delta_x, false, true),
 					2 => (-delta_x, -delta_y, true, true),
 					3 => (-delta_y, delta_x, true, false),
 					_ => unreachable!(),
 				};
+				// While flipped, the edge on the right side of the viewport is the opposite edge in document space
+				if document.document_ptz.flip() {
+					if quadrant % 2 == 0 {
+						opposite_x = !opposite_x;
+					} else {
+						opposite_y = !opposite_y;
+					}
+				}
 
 				let size = existing_bottom_right - existing_top_left;
 				let enlargement = DVec2::new(
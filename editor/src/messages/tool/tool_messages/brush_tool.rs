@@ -18,6 +18,33 @@ pub enum DrawMode {
 	Draw = 0,
 	Erase,
 	Restore,
+	Clone,
+}
+
+/// The node that a brush layer's strokes are stored in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum StrokesNode {
+	/// The legacy Brush node, which paints into an image of a fixed resolution. It's only painted into by layers which already use it.
+	Brush,
+	/// The Brush Strokes node, which rasterizes the strokes at the resolution of each render, so they stay resolution independent until export. New layers use it.
+	#[default]
+	BrushStrokes,
+}
+
+impl StrokesNode {
+	fn reference(self) -> &'static str {
+		match self {
+			Self::Brush => "Brush",
+			Self::BrushStrokes => "Brush Strokes",
+		}
+	}
+
+	fn strokes_input_index(self) -> usize {
+		match self {
+			Self::Brush => 2,
+			Self::BrushStrokes => 1,
+		}
+	}
 }
 
 #[derive(Default)]
@@ -28,7 +55,6 @@ pub struct BrushTool {
 }
 
 pub struct BrushOptions {
-	legacy_warning_was_shown: bool,
 	diameter: f64,
 	hardness: f64,
 	flow: f64,
@@ -36,12 +62,12 @@ pub struct BrushOptions {
 	color: ToolColorOptions,
 	blend_mode: BlendMode,
 	draw_mode: DrawMode,
+	clone_aligned: bool,
 }
 
 impl Default for BrushOptions {
 	fn default() -> Self {
 		Self {
-			legacy_warning_was_shown: false,
 			diameter: DEFAULT_BRUSH_SIZE,
 			hardness: 0.,
 			flow: 100.,
@@ -49,6 +75,7 @@ impl Default for BrushOptions {
 			color: ToolColorOptions::default(),
 			blend_mode: BlendMode::Normal,
 			draw_mode: DrawMode::Draw,
+			clone_aligned: true,
 		}
 	}
 }
@@ -61,7 +88,7 @@ pub enum BrushToolMessage {
 	WorkingColorChanged,
 
 	// Tool-specific messages
-	DragStart,
+	DragStart { set_clone_source: Key },
 	DragStop,
 	PointerMove,
 	UpdateOptions(BrushToolMessageOptionsUpdate),
@@ -71,6 +98,7 @@ pub enum BrushToolMessage {
 pub enum BrushToolMessageOptionsUpdate {
 	BlendMode(BlendMode),
 	ChangeDiameter(f64),
+	CloneAligned(bool),
 	Color(Option<Color>),
 	ColorType(ToolColorType),
 	Diameter(f64),
@@ -79,7 +107,6 @@ pub enum BrushToolMessageOptionsUpdate {
 	Hardness(f64),
 	Spacing(f64),
 	WorkingColors(Option<Color>, Option<Color>),
-	NoDisplayLegacyWarning,
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -142,12 +169,24 @@ impl LayoutHolder for BrushTool {
 
 		widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
 
-		let draw_mode_entries: Vec<_> = [DrawMode::Draw, DrawMode::Erase, DrawMode::Restore]
+		let draw_mode_entries: Vec<_> = [DrawMode::Draw, DrawMode::Erase, DrawMode::Restore, DrawMode::Clone]
 			.into_iter()
 			.map(|draw_mode| RadioEntryData::new(format!("{draw_mode:?}")).on_update(move |_| BrushToolMessage::UpdateOptions(BrushToolMessageOptionsUpdate::DrawMode(draw_mode)).into()))
 			.collect();
 		widgets.push(RadioInput::new(draw_mode_entries).selected_index(Some(self.options.draw_mode as u32)).widget_holder());
 
+		if self.options.draw_mode == DrawMode::Clone {
+			let clone_aligned_tooltip = "Keep the same offset between the brush and the clone source across strokes, instead of restarting from the clone source with each stroke";
+			widgets.push(Separator::new(SeparatorType::Related).widget_holder());
+			widgets.push(
+				CheckboxInput::new(self.options.clone_aligned)
+					.on_update(|&CheckboxInput { checked, .. }| BrushToolMessage::UpdateOptions(BrushToolMessageOptionsUpdate::CloneAligned(checked)).into())
+					.tooltip(clone_aligned_tooltip)
+					.widget_holder(),
+			);
+			widgets.push(TextLabel::new("Aligned").tooltip(clone_aligned_tooltip).widget_holder());
+		}
+
 		widgets.push(Separator::new(SeparatorType::Unrelated).widget_holder());
 
 		widgets.append(&mut self.options.color.create_widgets(
@@ -177,6 +216,7 @@ impl LayoutHolder for BrushTool {
 			DropdownInput::new(blend_mode_entries)
 				.selected_index(self.options.blend_mode.index_in_list().map(|index| index as u32))
 				.tooltip("The blend mode used with the background when performing a brush stroke. Only used in draw mode.")
+				.searchable(true)
 				.disabled(self.options.draw_mode != DrawMode::Draw)
 				.widget_holder(),
 		);
@@ -205,6 +245,7 @@ impl<'a> MessageHandler<ToolMessage, &mut ToolActionHandlerData<'a>> for BrushTo
 				self.options.diameter = self.options.diameter.max(1.);
 				self.send_layout(responses, LayoutTarget::ToolOptions);
 			}
+			BrushToolMessageOptionsUpdate::CloneAligned(clone_aligned) => self.options.clone_aligned = clone_aligned,
 			BrushToolMessageOptionsUpdate::Diameter(diameter) => self.options.diameter = diameter,
 			BrushToolMessageOptionsUpdate::DrawMode(draw_mode) => self.options.draw_mode = draw_mode,
 			BrushToolMessageOptionsUpdate::Hardness(hardness) => self.options.hardness = hardness,
@@ -219,7 +260,6 @@ impl<'a> MessageHandler<ToolMessage, &mut ToolActionHandlerData<'a>> for BrushTo
 				self.options.color.primary_working_color = primary;
 				self.options.color.secondary_working_color = secondary;
 			}
-			BrushToolMessageOptionsUpdate::NoDisplayLegacyWarning => self.options.legacy_warning_was_shown = true,
 		}
 
 		self.send_layout(responses, LayoutTarget::ToolOptions);
@@ -256,7 +296,13 @@ impl ToolTransition for BrushTool {
 struct BrushToolData {
 	strokes: Vec<BrushStroke>,
 	layer: Option<LayerNodeIdentifier>,
+	/// The node in the layer being painted which its strokes are stored in.
+	strokes_node: StrokesNode,
 	transform: DAffine2,
+	/// The layer space position that clone stamp strokes sample from, set by Alt-clicking.
+	clone_source: Option<DVec2>,
+	/// The offset between the brush and the clone source which is kept across strokes while the clone mode is aligned.
+	clone_aligned_offset: Option<DVec2>,
 }
 
 impl BrushToolData {
@@ -276,12 +322,16 @@ impl BrushToolData {
 			let Some(reference) = document.network_interface.reference(&node_id, &[]) else {
 				continue;
 			};
-			if *reference == Some("Brush".to_string()) && node_id != layer.to_node() {
-				let points_input = node.inputs.get(2)?;
+			let strokes_node = [StrokesNode::Brush, StrokesNode::BrushStrokes]
+				.into_iter()
+				.find(|strokes_node| reference.as_deref() == Some(strokes_node.reference()));
+			if let Some(strokes_node) = strokes_node.filter(|_| node_id != layer.to_node()) {
+				let points_input = node.inputs.get(strokes_node.strokes_input_index())?;
 				let Some(TaggedValue::BrushStrokes(strokes)) = points_input.as_value() else {
 					continue;
 				};
 				self.strokes.clone_from(strokes);
+				self.strokes_node = strokes_node;
 
 				return Some(layer);
 			} else if *reference == Some("Transform".to_string()) {
@@ -295,10 +345,33 @@ impl BrushToolData {
 		None
 	}
 
+	fn layer_position(&self, document: &DocumentMessageHandler, layer: LayerNodeIdentifier, viewport_position: DVec2) -> DVec2 {
+		let position = document
+			.network_interface
+			.document_metadata()
+			.downstream_transform_to_viewport(layer)
+			.inverse()
+			.transform_point2(viewport_position);
+		self.transform.inverse().transform_point2(position)
+	}
+
+	/// Finds the offset from the start of a new clone stamp stroke to the position it samples from, or `None` if no clone source has been set.
+	fn clone_offset(&mut self, stroke_start: DVec2, aligned: bool) -> Option<DVec2> {
+		let offset = self.clone_source? - stroke_start;
+		if !aligned {
+			return Some(offset);
+		}
+
+		Some(*self.clone_aligned_offset.get_or_insert(offset))
+	}
+
 	fn update_strokes(&self, responses: &mut VecDeque<Message>) {
 		let Some(layer) = self.layer else { return };
 		let strokes = self.strokes.clone();
-		responses.add(GraphOperationMessage::Brush { layer, strokes });
+		match self.strokes_node {
+			StrokesNode::Brush => responses.add(GraphOperationMessage::Brush { layer, strokes }),
+			StrokesNode::BrushStrokes => responses.add(GraphOperationMessage::BrushStrokes { layer, strokes }),
+		}
 	}
 }
 
@@ -311,34 +384,23 @@ impl Fsm for BrushToolFsmState {
 			document, global_tool_data, input, ..
 		} = tool_action_data;
 
-		if !tool_options.legacy_warning_was_shown {
-			responses.add(DialogMessage::DisplayDialogError {
-				title: "Unsupported tool".into(),
-				description: "
-					The current Brush tool is a legacy feature with\n\
-					significant quality and performance limitations.\n\
-					It will be replaced soon by a new implementation.\n\
-					"
-				.trim()
-				.into(),
-			});
-			responses.add(BrushToolMessage::UpdateOptions(BrushToolMessageOptionsUpdate::NoDisplayLegacyWarning));
-		}
-
 		let ToolMessage::Brush(event) = event else { return self };
 		match (self, event) {
-			(BrushToolFsmState::Ready, BrushToolMessage::DragStart) => {
+			(BrushToolFsmState::Ready, BrushToolMessage::DragStart { set_clone_source }) if tool_options.draw_mode == DrawMode::Clone && input.keyboard.key(set_clone_source) => {
+				if let Some(layer) = tool_data.load_existing_strokes(document) {
+					tool_data.clone_source = Some(tool_data.layer_position(document, layer, input.mouse.position));
+					tool_data.clone_aligned_offset = None;
+				}
+				tool_data.strokes.clear();
+
+				BrushToolFsmState::Ready
+			}
+			(BrushToolFsmState::Ready, BrushToolMessage::DragStart { set_clone_source }) => {
 				responses.add(DocumentMessage::StartTransaction);
 				let loaded_layer = tool_data.load_existing_strokes(document);
 
 				if let Some(layer) = loaded_layer {
-					let pos = document
-						.network_interface
-						.document_metadata()
-						.downstream_transform_to_viewport(layer)
-						.inverse()
-						.transform_point2(input.mouse.position);
-					let layer_position = tool_data.transform.inverse().transform_point2(pos);
+					let layer_position = tool_data.layer_position(document, layer, input.mouse.position);
 					let layer_document_scale = document.metadata().downstream_transform_to_viewport(layer) * tool_data.transform;
 
 					// TODO: Also scale it based on the input image ('Background' input).
@@ -347,22 +409,45 @@ impl Fsm for BrushToolFsmState {
 						.max((layer_document_scale.matrix2 * glam::DVec2::X).length())
 						.max((layer_document_scale.matrix2 * glam::DVec2::Y).length());
 
+					// Clone stamp strokes can't be drawn until a clone source has been picked
+					let clone_offset = match tool_options.draw_mode {
+						DrawMode::Clone => match tool_data.clone_offset(layer_position, tool_options.clone_aligned) {
+							Some(offset) => Some(offset),
+							None => {
+								responses.add(DocumentMessage::AbortTransaction);
+								tool_data.strokes.clear();
+								return BrushToolFsmState::Ready;
+							}
+						},
+						_ => None,
+					};
+
 					// Start a new stroke with a single sample
 					let blend_mode = match tool_options.draw_mode {
 						DrawMode::Draw => tool_options.blend_mode,
 						DrawMode::Erase => BlendMode::Erase,
 						DrawMode::Restore => BlendMode::Restore,
+						DrawMode::Clone => BlendMode::Normal,
+					};
+					// The clone stamp's brush texture only serves as a mask for the sampled pixels
+					let color = match tool_options.draw_mode {
+						DrawMode::Clone => Color::WHITE,
+						_ => tool_options.color.active_color().unwrap_or_default(),
 					};
 					tool_data.strokes.push(BrushStroke {
-						trace: vec![BrushInputSample { position: layer_position }],
+						trace: vec![BrushInputSample {
+							position: layer_position,
+							pressure: input.pressure.unwrap_or(1.),
+						}],
 						style: BrushStyle {
-							color: tool_options.color.active_color().unwrap_or_default(),
+							color,
 							diameter: tool_options.diameter / layer_scale,
 							hardness: tool_options.hardness,
 							flow: tool_options.flow,
 							spacing: tool_options.spacing,
 							blend_mode,
 						},
+						clone_offset,
 					});
 
 					tool_data.update_strokes(responses);
@@ -373,23 +458,19 @@ impl Fsm for BrushToolFsmState {
 					new_brush_layer(document, responses);
 					responses.add(NodeGraphMessage::RunDocumentGraph);
 					responses.add(Message::StartBuffer);
-					responses.add(BrushToolMessage::DragStart);
+					responses.add(BrushToolMessage::DragStart { set_clone_source });
 					BrushToolFsmState::Ready
 				}
 			}
 
 			(BrushToolFsmState::Drawing, BrushToolMessage::PointerMove) => {
 				if let Some(layer) = tool_data.layer {
+					let layer_position = tool_data.layer_position(document, layer, input.mouse.position);
 					if let Some(stroke) = tool_data.strokes.last_mut() {
-						let layer_position = document
-							.network_interface
-							.document_metadata()
-							.downstream_transform_to_viewport(layer)
-							.inverse()
-							.transform_point2(input.mouse.position);
-						let layer_position = tool_data.transform.inverse().transform_point2(layer_position);
-
-						stroke.trace.push(BrushInputSample { position: layer_position })
+						stroke.trace.push(BrushInputSample {
+							position: layer_position,
+							pressure: input.pressure.unwrap_or(1.),
+						})
 					}
 				}
 				tool_data.update_strokes(responses);
@@ -428,6 +509,7 @@ impl Fsm for BrushToolFsmState {
 		let hint_data = match self {
 			BrushToolFsmState::Ready => HintData(vec![
 				HintGroup(vec![HintInfo::mouse(MouseMotion::LmbDrag, "Draw")]),
+				HintGroup(vec![HintInfo::keys_and_mouse([Key::Alt], MouseMotion::Lmb, "Set Clone Source (Clone Mode)")]),
 				HintGroup(vec![HintInfo::multi_keys([[Key::BracketLeft], [Key::BracketRight]], "Shrink/Grow Brush")]),
 			]),
 			BrushToolFsmState::Drawing => HintData(vec![HintGroup(vec![HintInfo::mouse(MouseMotion::Rmb, ""), HintInfo::keys([Key::Escape], "Cancel").prepend_slash()])]),
@@ -444,7 +526,9 @@ impl Fsm for BrushToolFsmState {
 fn new_brush_layer(document: &DocumentMessageHandler, responses: &mut VecDeque<Message>) -> LayerNodeIdentifier {
 	responses.add(DocumentMessage::DeselectAllLayers);
 
-	let brush_node = resolve_document_node_type("Brush").expect("Brush node does not exist").default_node_template();
+	let brush_node = resolve_document_node_type(StrokesNode::BrushStrokes.reference())
+		.expect("Brush Strokes node does not exist")
+		.default_node_template();
 
 	let id = NodeId::new();
 	responses.add(GraphOperationMessage::NewCustomLayer {
@@ -0,0 +1,133 @@
+use super::tool_prelude::*;
+use crate::consts::{COLOR_OVERLAY_BLUE, COLOR_OVERLAY_GRAY, COLOR_OVERLAY_WHITE};
+use crate::messages::portfolio::document::overlays::utility_types::{OverlayContext, Pivot};
+use crate::messages::portfolio::document::utility_types::comments::CommentAnchor;
+
+/// The radius in viewport pixels of the circle drawn for each comment pin, which is also the distance within which clicking selects an existing pin.
+const PIN_RADIUS: f64 = 10.;
+
+#[derive(Default)]
+pub struct CommentTool {
+	fsm_state: CommentToolFsmState,
+}
+
+#[impl_message(Message, ToolMessage, Comment)]
+#[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum CommentToolMessage {
+	// Standard messages
+	Abort,
+	Overlays(OverlayContext),
+
+	// Tool-specific messages
+	PlacePin,
+}
+
+impl ToolMetadata for CommentTool {
+	fn icon_name(&self) -> String {
+		"GeneralCommentTool".into()
+	}
+	fn tooltip(&self) -> String {
+		"Comment Tool".into()
+	}
+	fn tool_type(&self) -> crate::messages::tool::utility_types::ToolType {
+		ToolType::Comment
+	}
+}
+
+impl LayoutHolder for CommentTool {
+	fn layout(&self) -> Layout {
+		Layout::WidgetLayout(WidgetLayout::default())
+	}
+}
+
+impl<'a> MessageHandler<ToolMessage, &mut ToolActionHandlerData<'a>> for CommentTool {
+	fn process_message(&mut self, message: ToolMessage, responses: &mut VecDeque<Message>, tool_data: &mut ToolActionHandlerData<'a>) {
+		self.fsm_state.process_event(message, &mut (), tool_data, &(), responses, true);
+	}
+
+	advertise_actions!(CommentToolMessageDiscriminant;
+		PlacePin,
+	);
+}
+
+impl ToolTransition for CommentTool {
+	fn event_to_message_map(&self) -> EventToMessageMap {
+		EventToMessageMap {
+			tool_abort: Some(CommentToolMessage::Abort.into()),
+			overlay_provider: Some(|overlay_context| CommentToolMessage::Overlays(overlay_context).into()),
+			..Default::default()
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum CommentToolFsmState {
+	#[default]
+	Ready,
+}
+
+impl Fsm for CommentToolFsmState {
+	type ToolData = ();
+	type ToolOptions = ();
+
+	fn transition(self, event: ToolMessage, _tool_data: &mut Self::ToolData, handler_data: &mut ToolActionHandlerData, _tool_options: &Self::ToolOptions, responses: &mut VecDeque<Message>) -> Self {
+		let ToolActionHandlerData { document, input, .. } = handler_data;
+
+		let ToolMessage::Comment(event) = event else { return self };
+		match event {
+			// Comment pins are only shown on the canvas while reviewing with this tool, so they don't get in the way of editing the artwork
+			CommentToolMessage::Overlays(mut overlay_context) => {
+				let metadata = document.metadata();
+				for pin in document.comments.pins() {
+					let Some(position) = pin.document_position(metadata) else { continue };
+					let position = metadata.document_to_viewport.transform_point2(position);
+
+					let color = if pin.resolved { COLOR_OVERLAY_GRAY } else { COLOR_OVERLAY_BLUE };
+					overlay_context.circle(position, PIN_RADIUS, Some(color), Some(COLOR_OVERLAY_WHITE));
+					overlay_context.text(
+						&pin.number.to_string(),
+						COLOR_OVERLAY_WHITE,
+						None,
+						DAffine2::from_translation(position),
+						0.,
+						[Pivot::Middle, Pivot::Middle],
+					);
+				}
+			}
+			CommentToolMessage::PlacePin => {
+				let viewport_position = input.mouse.position;
+
+				// Clicking an existing pin opens its thread instead of dropping a new pin on top of it
+				if let Some(number) = document.comments.pin_at(document.metadata(), viewport_position, PIN_RADIUS) {
+					responses.add(CommentsPanelMessage::FocusComment { number });
+					responses.add(CommentsPanelMessage::UpdateLayout);
+					return self;
+				}
+
+				let metadata = document.metadata();
+				let document_position = metadata.document_to_viewport.inverse().transform_point2(viewport_position);
+				let anchor = match document.click(input) {
+					Some(layer) => CommentAnchor::Layer {
+						layer,
+						offset: metadata.transform_to_document(layer).inverse().transform_point2(document_position),
+					},
+					None => CommentAnchor::Document(document_position),
+				};
+				responses.add(DocumentMessage::AddComment { anchor });
+			}
+			CommentToolMessage::Abort => {}
+		}
+
+		self
+	}
+
+	fn update_hints(&self, responses: &mut VecDeque<Message>) {
+		let hint_data = HintData(vec![HintGroup(vec![HintInfo::mouse(MouseMotion::Lmb, "Drop Comment Pin")])]);
+
+		responses.add(FrontendMessage::UpdateInputHints { hint_data });
+	}
+
+	fn update_cursor(&self, responses: &mut VecDeque<Message>) {
+		responses.add(FrontendMessage::UpdateMouseCursor { cursor: MouseCursorIcon::Crosshair });
+	}
+}
@@ -1,5 +1,6 @@
 pub mod artboard_tool;
 pub mod brush_tool;
+pub mod comment_tool;
 pub mod ellipse_tool;
 pub mod eyedropper_tool;
 pub mod fill_tool;
@@ -106,6 +106,43 @@ pub trait Fsm {
 	}
 }
 
+/// The state of the tools which is saved with the rest of the workspace session, so it's restored the next time the editor is opened.
+#[derive(PartialEq, Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ToolSession {
+	pub active_tool: ToolType,
+	pub primary_color: Option<Color>,
+	pub secondary_color: Option<Color>,
+	/// The most recent change to each tool option, which are replayed in order to restore the options.
+	pub option_updates: Vec<ToolMessage>,
+}
+
+/// Identifies which tool option a message changes, if it's a change that's restored with the session, so only the most recent change to each option needs to be kept.
+pub fn tool_option_key(message: &ToolMessage) -> Option<String> {
+	use serde_json::Value;
+
+	// Messages serialize as nested objects named by their variants, like `{ "Pen": { "UpdateOptions": { "LineWeight": 5 } } }`
+	let Value::Object(tool) = serde_json::to_value(message).ok()? else { return None };
+	let (tool_name, tool_message) = tool.into_iter().next()?;
+	let Value::Object(tool_message) = tool_message else { return None };
+	let (message_name, update) = tool_message.into_iter().next()?;
+	if !matches!(message_name.as_str(), "UpdateOptions" | "SelectOptions") {
+		return None;
+	}
+	let option_name = match update {
+		Value::Object(update) => update.into_iter().next()?.0,
+		Value::String(option_name) => option_name,
+		_ => return None,
+	};
+
+	// The working colors are sent to every tool whenever they change, so they are restored separately
+	if option_name == "WorkingColors" {
+		return None;
+	}
+
+	Some(format!("{tool_name}.{option_name}"))
+}
+
 #[derive(Debug, Clone)]
 pub struct DocumentToolData {
 	pub primary_color: Color,
@@ -321,6 +358,7 @@ pub enum ToolType {
 	Eyedropper,
 	Fill,
 	Gradient,
+	Comment,
 
 	// Vector tool group
 	Path,
@@ -360,6 +398,7 @@ fn list_tools_in_groups() -> Vec<Vec<ToolAvailability>> {
 			ToolAvailability::Available(Box::<eyedropper_tool::EyedropperTool>::default()),
 			ToolAvailability::Available(Box::<fill_tool::FillTool>::default()),
 			ToolAvailability::Available(Box::<gradient_tool::GradientTool>::default()),
+			ToolAvailability::Available(Box::<comment_tool::CommentTool>::default()),
 		],
 		vec![
 			// Vector tool group
@@ -397,6 +436,7 @@ pub fn tool_message_to_tool_type(tool_message: &ToolMessage) -> ToolType {
 		ToolMessage::Eyedropper(_) => ToolType::Eyedropper,
 		ToolMessage::Fill(_) => ToolType::Fill,
 		ToolMessage::Gradient(_) => ToolType::Gradient,
+		ToolMessage::Comment(_) => ToolType::Comment,
 
 		// Vector tool group
 		ToolMessage::Path(_) => ToolType::Path,
@@ -430,6 +470,7 @@ pub fn tool_type_to_activate_tool_message(tool_type: ToolType) -> ToolMessageDis
 		ToolType::Eyedropper => ToolMessageDiscriminant::ActivateToolEyedropper,
 		ToolType::Fill => ToolMessageDiscriminant::ActivateToolFill,
 		ToolType::Gradient => ToolMessageDiscriminant::ActivateToolGradient,
+		ToolType::Comment => ToolMessageDiscriminant::ActivateToolComment,
 
 		// Vector tool group
 		ToolType::Path => ToolMessageDiscriminant::ActivateToolPath,
@@ -1,21 +1,35 @@
 use crate::consts::FILE_SAVE_SUFFIX;
 use crate::messages::frontend::utility_types::{ExportBounds, FileType};
+use crate::messages::portfolio::document::node_graph::node_properties;
+use crate::messages::portfolio::document::node_graph::utility_types::WireProbeValue;
+use crate::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
+use crate::messages::portfolio::document::utility_types::drivers::node_output_value;
+use crate::messages::portfolio::document::utility_types::keyframes::AnimatedValues;
+use crate::messages::portfolio::document::utility_types::misc::PreviewQuality;
+use crate::messages::portfolio::document::utility_types::network_interface::{InputConnector, NodeNetworkInterface};
+use crate::messages::portfolio::document::utility_types::print::{PrintJob, PrintSettings};
 use crate::messages::prelude::*;
+use crate::messages::tool::common_functionality::graph_modification_utils;
 use glam::{DAffine2, DVec2, UVec2};
+use graph_craft::ProtoNodeIdentifier;
+use graph_craft::concrete;
 use graph_craft::document::value::{RenderOutput, TaggedValue};
-use graph_craft::document::{DocumentNode, DocumentNodeImplementation, NodeId, NodeInput, generate_uuid};
+use graph_craft::document::{DocumentNode, DocumentNodeImplementation, NodeId, NodeInput, NodeNetwork, generate_uuid};
 use graph_craft::proto::GraphErrors;
 use graph_craft::wasm_application_io::EditorPreferences;
 use graphene_core::application_io::{NodeGraphUpdateMessage, RenderConfig};
+use graphene_core::raster::cmyk::PrintProfile;
 use graphene_core::renderer::RenderSvgSegmentList;
-use graphene_core::renderer::{GraphicElementRendered, RenderParams, SvgRender};
+use graphene_core::renderer::{GraphicElementRendered, Quad, RenderParams, SvgRender};
 use graphene_core::text::FontCache;
 use graphene_core::transform::Footprint;
 use graphene_core::vector::style::ViewMode;
+use graphene_core::{ArtboardGroupTable, Context, GraphicGroupTable};
 use graphene_std::application_io::TimingInformation;
 use graphene_std::renderer::{RenderMetadata, format_transform_matrix};
 use graphene_std::vector::VectorData;
 use interpreted_executor::dynamic_executor::ResolvedDocumentNodeTypesDelta;
+use interpreted_executor::node_timing::PathTiming;
 
 mod runtime_io;
 pub use runtime_io::NodeRuntimeIO;
@@ -33,11 +47,14 @@ pub struct ExecutionRequest {
 pub struct ExecutionResponse {
 	execution_id: u64,
 	result: Result<TaggedValue, String>,
-	responses: VecDeque<FrontendMessage>,
 	transform: DAffine2,
 	vector_modify: HashMap<NodeId, VectorData>,
-	/// The resulting value from the temporary inspected during execution
-	inspect_result: Option<InspectResult>,
+	/// The resulting values from the nodes temporarily inspected during execution
+	inspect_results: Vec<InspectResult>,
+	/// The Noise Pattern node previewed in its properties and the SVG of its output, if the graph changed since the last execution, which is empty if it couldn't be rendered
+	node_preview: Option<(NodeId, String)>,
+	/// The time spent evaluating the proto nodes during the execution, summed up by their document node paths
+	node_timings: HashMap<Box<[NodeId]>, PathTiming>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -50,7 +67,14 @@ pub struct CompilationResponse {
 pub enum NodeGraphUpdate {
 	ExecutionResponse(ExecutionResponse),
 	CompilationResponse(CompilationResponse),
+	/// The layer thumbnails rendered in the background since the last execution of the graph.
+	ThumbnailResponse(VecDeque<FrontendMessage>),
 	NodeGraphUpdateMessage(NodeGraphUpdateMessage),
+	/// The evaluated output of the node at the given document node path, summarized for the wire probe tooltip.
+	ProbeResponse {
+		node_path: Vec<NodeId>,
+		value: WireProbeValue,
+	},
 }
 
 #[derive(Debug)]
@@ -59,11 +83,31 @@ pub struct NodeGraphExecutor {
 	futures: HashMap<u64, ExecutionContext>,
 	node_graph_hash: u64,
 	old_inspect_node: Option<NodeId>,
+	/// The node whose output is shown in the Histogram panel, which falls back to the document's export when the spreadsheet's node doesn't.
+	old_histogram_panel_node: Option<NodeId>,
+	/// The node whose output is shown as a histogram above the properties of the selected tonal adjustment node, inspected alongside the node requested by the panels.
+	old_histogram_node: Option<NodeId>,
+	/// The node whose output is previewed as a thumbnail in the properties of the selected node, inspected alongside the node requested by the panels.
+	old_vector_preview_node: Option<NodeId>,
+	/// The nodes whose outputs are read by the document's drivers, inspected alongside the node requested by the panels.
+	old_driver_read_nodes: Vec<NodeId>,
+	old_preview_node: Option<NodeId>,
+	old_solo_layer: Option<LayerNodeIdentifier>,
+	old_provisional_inputs: HashMap<(NodeId, usize), TaggedValue>,
+	old_animated_values: AnimatedValues,
+	old_preview_quality: PreviewQuality,
+	old_soft_proof: Option<PrintProfile>,
+	/// The outputs of the nodes probed since the graph was last executed, by their document node paths, so each is only evaluated once per execution.
+	probe_cache: HashMap<Vec<NodeId>, WireProbeValue>,
+	/// The nodes whose outputs have been requested from the runtime for a probe but not received yet.
+	pending_probes: HashSet<Vec<NodeId>>,
 }
 
 #[derive(Debug, Clone)]
 struct ExecutionContext {
 	export_config: Option<ExportConfig>,
+	/// The size of the artwork rendered at the document's resolution for the Pixels view mode, and the transform from those pixels to the viewport.
+	pixel_preview: Option<(UVec2, DAffine2)>,
 }
 
 impl Default for NodeGraphExecutor {
@@ -73,6 +117,18 @@ impl Default for NodeGraphExecutor {
 			runtime_io: NodeRuntimeIO::new(),
 			node_graph_hash: 0,
 			old_inspect_node: None,
+			old_histogram_panel_node: None,
+			old_histogram_node: None,
+			old_vector_preview_node: None,
+			old_driver_read_nodes: Vec::new(),
+			old_preview_node: None,
+			old_solo_layer: None,
+			old_provisional_inputs: HashMap::new(),
+			old_animated_values: AnimatedValues::new(),
+			old_preview_quality: PreviewQuality::default(),
+			old_soft_proof: None,
+			probe_cache: HashMap::new(),
+			pending_probes: HashSet::new(),
 		}
 	}
 }
@@ -90,6 +146,18 @@ impl NodeGraphExecutor {
 			runtime_io: NodeRuntimeIO::with_channels(request_sender, response_receiver),
 			node_graph_hash: 0,
 			old_inspect_node: None,
+			old_histogram_panel_node: None,
+			old_histogram_node: None,
+			old_vector_preview_node: None,
+			old_driver_read_nodes: Vec::new(),
+			old_preview_node: None,
+			old_solo_layer: None,
+			old_provisional_inputs: HashMap::new(),
+			old_animated_values: AnimatedValues::new(),
+			old_preview_quality: PreviewQuality::default(),
+			old_soft_proof: None,
+			probe_cache: HashMap::new(),
+			pending_probes: HashSet::new(),
 		};
 		(node_runtime, node_executor)
 	}
@@ -106,6 +174,19 @@ impl NodeGraphExecutor {
 		self.runtime_io.send(GraphRuntimeRequest::FontCacheUpdate(font_cache)).expect("Failed to send font cache update");
 	}
 
+	/// Gets the output of the node at the given document node path for the wire probe, evaluating it only if it hasn't been since the graph was last executed.
+	/// The value is sent back with [`NodeGraphMessage::UpdateWireProbe`].
+	pub fn probe_node(&mut self, node_path: Vec<NodeId>, responses: &mut VecDeque<Message>) {
+		if let Some(value) = self.probe_cache.get(&node_path) {
+			responses.add(NodeGraphMessage::UpdateWireProbe { node_path, value: value.clone() });
+			return;
+		}
+
+		if self.pending_probes.insert(node_path.clone()) {
+			self.runtime_io.send(GraphRuntimeRequest::ProbeRequest(node_path)).expect("Failed to send probe request");
+		}
+	}
+
 	pub fn update_editor_preferences(&self, editor_preferences: EditorPreferences) {
 		self.runtime_io
 			.send(GraphRuntimeRequest::EditorPreferencesUpdate(editor_preferences))
@@ -121,40 +202,194 @@ impl NodeGraphExecutor {
 		let instrumented = Instrumented::new(&mut network);
 
 		self.runtime_io
-			.send(GraphRuntimeRequest::GraphUpdate(GraphUpdate { network, inspect_node: None }))
+			.send(GraphRuntimeRequest::GraphUpdate(GraphUpdate {
+				network,
+				inspect_nodes: Vec::new(),
+				preview_node: None,
+			}))
 			.map_err(|e| e.to_string())?;
 		Ok(instrumented)
 	}
 
 	/// Update the cached network if necessary.
-	fn update_node_graph(&mut self, document: &mut DocumentMessageHandler, inspect_node: Option<NodeId>, ignore_hash: bool) -> Result<(), String> {
+	fn update_node_graph(&mut self, document: &mut DocumentMessageHandler, inspect_node: Option<NodeId>, histogram_panel_node: Option<NodeId>, ignore_hash: bool) -> Result<(), String> {
 		let network_hash = document.network_interface.document_network().current_hash();
-		// Refresh the graph when it changes or the inspect node changes
-		if network_hash != self.node_graph_hash || self.old_inspect_node != inspect_node || ignore_hash {
-			let network = document.network_interface.document_network().clone();
+		let solo_layer = document.node_graph_handler.solo_layer;
+		let provisional_inputs = &document.node_graph_handler.provisional_inputs;
+		let animated_values = Self::animated_values(document);
+		let histogram_node = node_properties::histogram_inspect_node(&document.network_interface);
+		let vector_preview_node = document.vector_preview_inspect_node();
+		let driver_read_nodes = document.drivers.read_nodes(&document.network_interface);
+		let preview_node = node_properties::noise_preview_node(&document.network_interface);
+		let preview_quality = document.preview_quality;
+		let soft_proof = document.soft_proof_profile();
+		// Refresh the graph when it changes, an inspected or preview node changes, a layer is soloed, an input value is previewed, animated, or driven, or the preview quality or soft proof changes
+		if network_hash != self.node_graph_hash
+			|| self.old_inspect_node != inspect_node
+			|| self.old_histogram_panel_node != histogram_panel_node
+			|| self.old_histogram_node != histogram_node
+			|| self.old_vector_preview_node != vector_preview_node
+			|| self.old_driver_read_nodes != driver_read_nodes
+			|| self.old_preview_node != preview_node
+			|| self.old_solo_layer != solo_layer
+			|| self.old_provisional_inputs != *provisional_inputs
+			|| self.old_animated_values != animated_values
+			|| self.old_preview_quality != preview_quality
+			|| self.old_soft_proof != soft_proof
+			|| ignore_hash
+		{
+			let mut network = document.network_interface.document_network().clone();
 			self.old_inspect_node = inspect_node;
+			self.old_histogram_panel_node = histogram_panel_node;
+			self.old_histogram_node = histogram_node;
+			self.old_vector_preview_node = vector_preview_node;
+			document.drivers.retain_node_outputs(&driver_read_nodes);
+			self.old_driver_read_nodes.clone_from(&driver_read_nodes);
+			self.old_preview_node = preview_node;
+			self.old_solo_layer = solo_layer;
+			self.old_provisional_inputs.clone_from(provisional_inputs);
+			self.old_preview_quality = preview_quality;
+			self.old_soft_proof = soft_proof;
 			self.node_graph_hash = network_hash;
 
+			// Animated and driven values replace those of the inputs only in the network that's rendered, so the document keeps the values the user set
+			Self::apply_animated_values(&mut network, &animated_values);
+			self.old_animated_values = animated_values;
+
+			// Previewed values replace those of the inputs only in the network that's rendered, so the document and its history are left untouched
+			for (&(node_id, input_index), value) in provisional_inputs {
+				let Some(input) = network.nodes.get_mut(&node_id).and_then(|node| node.inputs.get_mut(input_index)) else {
+					continue;
+				};
+				if input.as_value().is_some() {
+					*input = NodeInput::value(value.clone(), false);
+				}
+			}
+
+			// Layers outside the soloed layer are only hidden in the network that's rendered, so their own visibility is kept for when the solo ends
+			let metadata = document.metadata();
+			for layer in metadata.all_layers().filter(|&layer| document.node_graph_handler.hidden_by_solo(layer, metadata)) {
+				if let Some(node) = network.nodes.get_mut(&layer.to_node()) {
+					node.visible = false;
+				}
+			}
+
+			// Expensive nodes are only lowered in quality in the network that's rendered, so exports, which send the document's own network, stay at full quality
+			if preview_quality != PreviewQuality::Full {
+				Self::reduce_preview_quality(&mut network, &document.network_interface, &mut Vec::new(), preview_quality);
+			}
+
+			// Printed colors are only simulated in the network that's rendered, so exports keep the document's RGB colors
+			if let Some(print_profile) = soft_proof {
+				Self::append_soft_proof(&mut network, &mut document.network_interface, print_profile);
+			}
+
+			// Each node is only inspected once, even if it's requested for more than one purpose
+			let mut inspect_nodes = Vec::new();
+			let requested_nodes = [inspect_node, histogram_panel_node, histogram_node, vector_preview_node].into_iter().flatten();
+			for node_id in requested_nodes.chain(driver_read_nodes) {
+				if !inspect_nodes.contains(&node_id) {
+					inspect_nodes.push(node_id);
+				}
+			}
+
 			self.runtime_io
-				.send(GraphRuntimeRequest::GraphUpdate(GraphUpdate { network, inspect_node }))
+				.send(GraphRuntimeRequest::GraphUpdate(GraphUpdate { network, inspect_nodes, preview_node }))
 				.map_err(|e| e.to_string())?;
 		}
 		Ok(())
 	}
 
+	/// The values of the document's animated inputs at its current time, followed by its driven inputs since their expressions may read the animated values.
+	fn animated_values(document: &DocumentMessageHandler) -> AnimatedValues {
+		let time = document.keyframes.time();
+		let mut animated_values = document.keyframes.values_at(&document.network_interface, time);
+		document.drivers.resolve(&document.network_interface, time, &mut animated_values);
+		animated_values
+	}
+
+	/// Replaces the values of the animated inputs, which may be in networks nested inside the nodes of this one.
+	fn apply_animated_values(network: &mut NodeNetwork, animated_values: &AnimatedValues) {
+		for ((network_path, node_id, input_index), value) in animated_values {
+			let Some(input) = network
+				.nested_network_mut(network_path)
+				.and_then(|network| network.nodes.get_mut(node_id))
+				.and_then(|node| node.inputs.get_mut(*input_index))
+			else {
+				continue;
+			};
+			if input.as_value().is_some() {
+				*input = NodeInput::value(value.clone(), false);
+			}
+		}
+	}
+
+	/// Lowers the inputs controlling how much work each expensive node does, in this network and those nested inside its nodes.
+	fn reduce_preview_quality(network: &mut NodeNetwork, network_interface: &NodeNetworkInterface, network_path: &mut Vec<NodeId>, quality: PreviewQuality) {
+		for (node_id, node) in network.nodes.iter_mut() {
+			if let Some(reference) = network_interface.reference(node_id, network_path).cloned().flatten() {
+				for (input_index, input) in node.inputs.iter_mut().enumerate() {
+					if let Some(value) = input.as_non_exposed_value().and_then(|value| quality.reduced_input(&reference, input_index, value)) {
+						*input = NodeInput::value(value, false);
+					}
+				}
+			}
+
+			if let DocumentNodeImplementation::Network(nested_network) = &mut node.implementation {
+				network_path.push(*node_id);
+				Self::reduce_preview_quality(nested_network, network_interface, network_path, quality);
+				network_path.pop();
+			}
+		}
+	}
+
+	/// Passes the artwork output by the network through a Soft Proof node, which recolors it as it would look printed.
+	/// Outputs other than a group or artboards are left alone, since there's no artwork to recolor.
+	fn append_soft_proof(network: &mut NodeNetwork, network_interface: &mut NodeNetworkInterface, print_profile: PrintProfile) {
+		let output_type = network_interface.input_type(&InputConnector::Export(0), &[]).0.nested_type().clone();
+		if output_type != concrete!(GraphicGroupTable) && output_type != concrete!(ArtboardGroupTable) {
+			return;
+		}
+		let Some(export) = network.exports.first_mut().filter(|export| matches!(export, NodeInput::Node { .. })) else {
+			return;
+		};
+
+		let soft_proof_id = NodeId(generate_uuid());
+		let artwork = std::mem::replace(export, NodeInput::node(soft_proof_id, 0));
+		network.nodes.insert(
+			soft_proof_id,
+			DocumentNode {
+				inputs: vec![artwork, NodeInput::value(TaggedValue::PrintProfile(print_profile), false)],
+				implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::raster::adjustments::SoftProofNode")),
+				manual_composition: Some(concrete!(Context)),
+				..Default::default()
+			},
+		);
+	}
+
 	/// Adds an evaluate request for whatever current network is cached.
 	pub(crate) fn submit_current_node_graph_evaluation(&mut self, document: &mut DocumentMessageHandler, viewport_resolution: UVec2, time: TimingInformation) -> Result<(), String> {
+		let pixel_preview = Self::pixel_preview_footprint(document, viewport_resolution);
+
+		#[cfg(any(feature = "resvg", feature = "vello"))]
+		let export_format = graphene_core::application_io::ExportFormat::Canvas;
+		#[cfg(not(any(feature = "resvg", feature = "vello")))]
+		let export_format = graphene_core::application_io::ExportFormat::Svg;
+		// The pixel preview is rasterized by the frontend, so it always needs an SVG
+		let export_format = if pixel_preview.is_some() {
+			graphene_core::application_io::ExportFormat::Svg
+		} else {
+			export_format
+		};
+
 		let render_config = RenderConfig {
-			viewport: Footprint {
+			viewport: pixel_preview.map(|(footprint, _)| footprint).unwrap_or(Footprint {
 				transform: document.metadata().document_to_viewport,
 				resolution: viewport_resolution,
 				..Default::default()
-			},
+			}),
 			time,
-			#[cfg(any(feature = "resvg", feature = "vello"))]
-			export_format: graphene_core::application_io::ExportFormat::Canvas,
-			#[cfg(not(any(feature = "resvg", feature = "vello")))]
-			export_format: graphene_core::application_io::ExportFormat::Svg,
+			export_format,
 			view_mode: document.view_mode,
 			hide_artboards: false,
 			for_export: false,
@@ -163,11 +398,40 @@ impl NodeGraphExecutor {
 		// Execute the node graph
 		let execution_id = self.queue_execution(render_config);
 
-		self.futures.insert(execution_id, ExecutionContext { export_config: None });
+		let pixel_preview = pixel_preview.map(|(footprint, image_to_viewport)| (footprint.resolution, image_to_viewport));
+		self.futures.insert(execution_id, ExecutionContext { export_config: None, pixel_preview });
 
 		Ok(())
 	}
 
+	/// In the Pixels view mode, the visible part of the document is rendered at one pixel per document unit so the frontend can enlarge those pixels without smoothing.
+	/// Returns the footprint for that render and the transform from its pixels to the viewport.
+	fn pixel_preview_footprint(document: &DocumentMessageHandler, viewport_resolution: UVec2) -> Option<(Footprint, DAffine2)> {
+		if document.view_mode != ViewMode::Pixels {
+			return None;
+		}
+
+		// At or below 100% zoom there are no enlarged pixels to show, so the regular render is already accurate
+		if document.navigation_handler.snapped_zoom(document.document_ptz.zoom()) <= 1. {
+			return None;
+		}
+
+		let document_to_viewport = document.metadata().document_to_viewport;
+		let visible_bounds = (document_to_viewport.inverse() * Quad::from_box([DVec2::ZERO, viewport_resolution.as_dvec2()])).bounding_box();
+		let [min, max] = visible_bounds.map(|corner| corner.floor());
+		let resolution = (max + DVec2::ONE - min).as_uvec2();
+		if resolution.cmpeq(UVec2::ZERO).any() {
+			return None;
+		}
+
+		let footprint = Footprint {
+			transform: DAffine2::from_translation(-min),
+			resolution,
+			..Default::default()
+		};
+		Some((footprint, document_to_viewport * DAffine2::from_translation(min)))
+	}
+
 	/// Evaluates a node graph, computing the entire graph
 	pub fn submit_node_graph_evaluation(
 		&mut self,
@@ -175,9 +439,10 @@ impl NodeGraphExecutor {
 		viewport_resolution: UVec2,
 		time: TimingInformation,
 		inspect_node: Option<NodeId>,
+		histogram_panel_node: Option<NodeId>,
 		ignore_hash: bool,
 	) -> Result<(), String> {
-		self.update_node_graph(document, inspect_node, ignore_hash)?;
+		self.update_node_graph(document, inspect_node, histogram_panel_node, ignore_hash)?;
 		self.submit_current_node_graph_evaluation(document, viewport_resolution, time)?;
 
 		Ok(())
@@ -185,13 +450,23 @@ impl NodeGraphExecutor {
 
 	/// Evaluates a node graph for export
 	pub fn submit_document_export(&mut self, document: &mut DocumentMessageHandler, mut export_config: ExportConfig) -> Result<(), String> {
-		let network = document.network_interface.document_network().clone();
+		let mut network = document.network_interface.document_network().clone();
+		Self::apply_animated_values(&mut network, &Self::animated_values(document));
 
 		// Calculate the bounding box of the region to be exported
 		let bounds = match export_config.bounds {
 			ExportBounds::AllArtwork => document.network_interface.document_bounds_document_space(!export_config.transparent_background),
 			ExportBounds::Selection => document.network_interface.selected_bounds_document_space(!export_config.transparent_background, &[]),
-			ExportBounds::Artboard(id) => document.metadata().bounding_box_document(id),
+			ExportBounds::Artboard(id) => document.metadata().bounding_box_document(id).map(|[min, max]| {
+				let bleed = if export_config.include_bleed {
+					graph_modification_utils::get_artboard_bleed(id, &document.network_interface).unwrap_or_default()
+				} else {
+					0.
+				};
+				export_config.bleed = bleed;
+				[min - bleed, max + bleed]
+			}),
+			ExportBounds::Slice(id) => document.metadata().bounding_box_document(id),
 		}
 		.ok_or_else(|| "No bounding box".to_string())?;
 		let size = bounds[1] - bounds[0];
@@ -213,10 +488,19 @@ impl NodeGraphExecutor {
 
 		// Execute the node graph
 		self.runtime_io
-			.send(GraphRuntimeRequest::GraphUpdate(GraphUpdate { network, inspect_node: None }))
+			.send(GraphRuntimeRequest::GraphUpdate(GraphUpdate {
+				network,
+				inspect_nodes: Vec::new(),
+				preview_node: None,
+			}))
 			.map_err(|e| e.to_string())?;
 		let execution_id = self.queue_execution(render_config);
-		let execution_context = ExecutionContext { export_config: Some(export_config) };
+		// The next render of the viewport sends its own network again, with its preview quality and other changes only meant for the viewport
+		self.node_graph_hash = 0;
+		let execution_context = ExecutionContext {
+			export_config: Some(export_config),
+			pixel_preview: None,
+		};
 		self.futures.insert(execution_id, execution_context);
 
 		Ok(())
@@ -236,6 +520,8 @@ impl NodeGraphExecutor {
 			file_name,
 			size,
 			scale_factor,
+			print_settings,
+			bleed,
 			..
 		} = export_config;
 
@@ -247,6 +533,14 @@ impl NodeGraphExecutor {
 
 		if file_type == FileType::Svg {
 			responses.add(FrontendMessage::TriggerDownloadTextFile { document: svg, name });
+		} else if file_type == FileType::Pdf {
+			let size = (size * scale_factor).into();
+			let print_job = PrintJob {
+				settings: print_settings,
+				bleed,
+				scale_factor,
+			};
+			responses.add(FrontendMessage::TriggerRasterizeForPrint { svg, name, size, print_job });
 		} else {
 			let mime = file_type.to_mime().to_string();
 			let size = (size * scale_factor).into();
@@ -263,10 +557,11 @@ impl NodeGraphExecutor {
 					let ExecutionResponse {
 						execution_id,
 						result,
-						responses: existing_responses,
 						transform,
 						vector_modify,
-						inspect_result,
+						inspect_results,
+						node_preview,
+						node_timings,
 					} = execution_response;
 
 					responses.add(OverlaysMessage::Draw);
@@ -281,7 +576,6 @@ impl NodeGraphExecutor {
 						}
 					};
 
-					responses.extend(existing_responses.into_iter().map(Into::into));
 					document.network_interface.update_vector_modify(vector_modify);
 
 					let execution_context = self.futures.remove(&execution_id).ok_or_else(|| "Invalid generation ID".to_string())?;
@@ -289,16 +583,102 @@ impl NodeGraphExecutor {
 						// Special handling for exporting the artwork
 						self.export(node_graph_output, export_config, responses)?
 					} else {
-						self.process_node_graph_output(node_graph_output, transform, responses)?
+						// Store this render as a snapshot if one was requested, before the output is consumed
+						if document.compare_handler.capture_pending {
+							match &node_graph_output {
+								TaggedValue::RenderOutput(RenderOutput {
+									data: graphene_std::wasm_application_io::RenderOutputType::Svg(svg),
+									..
+								}) => responses.add(CompareMessage::StoreSnapshot {
+									svg: svg.clone(),
+									render_to_document: transform.inverse(),
+								}),
+								_ => responses.add(CompareMessage::CaptureUnsupported),
+							}
+						}
+
+						self.process_node_graph_output(node_graph_output, transform, execution_context.pixel_preview, responses)?;
+
+						// Keep the Navigator panel's viewport rectangle and document bounds in sync with the newly rendered view
+						responses.add(NavigatorPanelMessage::UpdateLayout);
+
+						// Keep the compared snapshot aligned with the artwork after the view or active document changes
+						responses.add(CompareMessage::UpdateOverlay);
+
+						// Exports aren't measured, since they render at full quality and don't need to stay interactive
+						document.performance_budget.record_render(&node_timings);
+						responses.add(DiagnosticsPanelMessage::UpdateLayout);
+						document.node_profile.record_render(&node_timings);
+						responses.add(ProfilerPanelMessage::UpdateLayout);
+
+						// The probed values are from the previous render, so the one being shown is evaluated again
+						self.probe_cache.clear();
+						responses.add(NodeGraphMessage::RefreshWireProbe);
+					}
+
+					if let Some((node_id, svg)) = node_preview {
+						responses.add(PropertiesPanelMessage::UpdateNoisePreview { node_id, svg });
 					}
 
-					// Update the spreadsheet on the frontend using the value of the inspect result.
-					if self.old_inspect_node.is_some() {
-						if let Some(inspect_result) = inspect_result {
-							responses.add(SpreadsheetMessage::UpdateLayout { inspect_result });
+					// Drivers read the outputs of this evaluation, so the graph is evaluated again with the driven values when they change
+					let mut driver_read_nodes_changed = false;
+					for inspect_result in &inspect_results {
+						if !self.old_driver_read_nodes.contains(&inspect_result.inspect_node) {
+							continue;
 						}
+						if let Some(value) = inspect_result.clone().take_data().and_then(|data| node_output_value(data.as_ref())) {
+							driver_read_nodes_changed |= document.drivers.set_node_output(inspect_result.inspect_node, value);
+						}
+					}
+					if driver_read_nodes_changed {
+						responses.add(NodeGraphMessage::RunDocumentGraph);
+						responses.add(PropertiesPanelMessage::Refresh);
+					}
+
+					for inspect_result in inspect_results {
+						// The histogram above the selected tonal adjustment node's properties and the selected node's vector preview have their own inspected nodes
+						if node_properties::histogram_inspect_node(&document.network_interface) == Some(inspect_result.inspect_node) {
+							responses.add(PropertiesPanelMessage::UpdateHistogram {
+								inspect_result: inspect_result.clone(),
+							});
+						}
+						if document.vector_preview_inspect_node() == Some(inspect_result.inspect_node) {
+							responses.add(PropertiesPanelMessage::UpdateVectorPreview {
+								inspect_result: inspect_result.clone(),
+							});
+						}
+
+						if self.old_histogram_panel_node == Some(inspect_result.inspect_node) {
+							responses.add(HistogramMessage::UpdateLayout {
+								inspect_result: inspect_result.clone(),
+							});
+						}
+
+						// Update the spreadsheet on the frontend, or finish baking or adjusting, using the value of the inspect result.
+						if self.old_inspect_node != Some(inspect_result.inspect_node) {
+							continue;
+						}
+						if document.node_graph_handler.baking_node == Some(inspect_result.inspect_node) {
+							responses.add(NodeGraphMessage::CompleteBake {
+								inspect_result: inspect_result.clone(),
+							});
+						}
+						if document.node_graph_handler.auto_adjusting.is_some_and(|(source, _, _)| source == inspect_result.inspect_node) {
+							responses.add(NodeGraphMessage::CompleteAutoAdjust {
+								inspect_result: inspect_result.clone(),
+							});
+						}
+						responses.add(SpreadsheetMessage::UpdateLayout { inspect_result });
 					}
 				}
+				NodeGraphUpdate::ThumbnailResponse(thumbnail_responses) => {
+					responses.extend(thumbnail_responses.into_iter().map(Into::into));
+				}
+				NodeGraphUpdate::ProbeResponse { node_path, value } => {
+					self.pending_probes.remove(&node_path);
+					self.probe_cache.insert(node_path.clone(), value.clone());
+					responses.add(NodeGraphMessage::UpdateWireProbe { node_path, value });
+				}
 				// NodeGraphUpdate::NodeGraphUpdateMessage(NodeGraphUpdateMessage::ImaginateStatusUpdate) => {
 				// 	responses.add(DocumentMessage::PropertiesPanel(PropertiesPanelMessage::Refresh));
 				// }
@@ -351,14 +731,29 @@ impl NodeGraphExecutor {
 		responses.add(FrontendMessage::UpdateDocumentArtwork { svg });
 	}
 
-	fn process_node_graph_output(&mut self, node_graph_output: TaggedValue, transform: DAffine2, responses: &mut VecDeque<Message>) -> Result<(), String> {
+	fn process_node_graph_output(&mut self, node_graph_output: TaggedValue, transform: DAffine2, pixel_preview: Option<(UVec2, DAffine2)>, responses: &mut VecDeque<Message>) -> Result<(), String> {
 		let mut render_output_metadata = RenderMetadata::default();
 		match node_graph_output {
-			TaggedValue::RenderOutput(render_output) => {
+			TaggedValue::RenderOutput(mut render_output) => {
 				match render_output.data {
 					graphene_std::wasm_application_io::RenderOutputType::Svg(svg) => {
-						// Send to frontend
-						responses.add(FrontendMessage::UpdateDocumentArtwork { svg });
+						if let Some((resolution, image_to_viewport)) = pixel_preview {
+							// The footprints were relative to the pixel preview image instead of the viewport, which the layer transforms must be based on
+							for footprint in render_output.metadata.upstream_footprints.values_mut() {
+								footprint.transform = image_to_viewport * footprint.transform;
+							}
+
+							// Send to frontend
+							responses.add(FrontendMessage::UpdateDocumentArtworkPixelPreview {
+								svg,
+								width: resolution.x,
+								height: resolution.y,
+								transform: format_transform_matrix(image_to_viewport),
+							});
+						} else {
+							// Send to frontend
+							responses.add(FrontendMessage::UpdateDocumentArtwork { svg });
+						}
 					}
 					graphene_std::wasm_application_io::RenderOutputType::CanvasFrame(frame) => {
 						let matrix = format_transform_matrix(frame.transform);
@@ -1,5 +1,7 @@
 use super::*;
+use crate::consts::{LAYER_THUMBNAIL_RESOLUTION, LAYER_THUMBNAILS_PER_RUN, NOISE_PREVIEW_SIZE};
 use crate::messages::frontend::utility_types::{ExportBounds, FileType};
+use crate::messages::portfolio::document::node_graph::utility_types::WireProbeValue;
 use glam::{DAffine2, DVec2};
 use graph_craft::concrete;
 use graph_craft::document::value::TaggedValue;
@@ -9,10 +11,14 @@ use graph_craft::proto::GraphErrors;
 use graph_craft::wasm_application_io::EditorPreferences;
 use graphene_core::application_io::{NodeGraphUpdateMessage, NodeGraphUpdateSender, RenderConfig};
 use graphene_core::memo::IORecord;
+use graphene_core::raster::color::Color;
+use graphene_core::raster::image::ImageFrameTable;
 use graphene_core::renderer::{GraphicElementRendered, RenderParams, SvgRender};
 use graphene_core::renderer::{RenderSvgSegmentList, SvgSegment};
 use graphene_core::text::FontCache;
+use graphene_core::transform::Footprint;
 use graphene_core::vector::style::ViewMode;
+use graphene_core::{Artboard, GraphicElement, OwnedContextImpl};
 use graphene_std::Context;
 use graphene_std::vector::{VectorData, VectorDataTable};
 use graphene_std::wasm_application_io::{WasmApplicationIo, WasmEditorApi};
@@ -41,13 +47,19 @@ pub struct NodeRuntime {
 	node_graph_errors: GraphErrors,
 	monitor_nodes: Vec<Vec<NodeId>>,
 
-	/// Which node is inspected and which monitor node is used (if any) for the current execution
-	inspect_state: Option<InspectState>,
+	/// Which nodes are inspected and the monitor node used for each in the current execution
+	inspect_states: Vec<InspectState>,
+	/// The Noise Pattern node whose output is previewed in its properties, which is rendered again after each change to the graph.
+	preview_node: Option<NodeId>,
 
 	// TODO: Remove, it doesn't need to be persisted anymore
 	/// The current renders of the thumbnails for layer nodes.
 	thumbnail_renders: HashMap<NodeId, Vec<SvgSegment>>,
+	/// The layers whose thumbnails are waiting to be rendered in the background, once no execution of the graph is pending, with the path to the monitor node of each.
+	thumbnail_queue: VecDeque<(NodeId, Vec<NodeId>)>,
 	vector_modify: HashMap<NodeId, VectorData>,
+	/// The configuration of the most recent render of the viewport, whose context the probed nodes are evaluated with.
+	last_render_config: Option<RenderConfig>,
 }
 
 /// Messages passed from the editor thread to the node runtime thread.
@@ -57,13 +69,17 @@ pub enum GraphRuntimeRequest {
 	ExecutionRequest(ExecutionRequest),
 	FontCacheUpdate(FontCache),
 	EditorPreferencesUpdate(EditorPreferences),
+	/// Evaluates the output of the node at the given document node path, as it was in the most recent render, to show the value carried by a wire.
+	ProbeRequest(Vec<NodeId>),
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct GraphUpdate {
 	pub(super) network: NodeNetwork,
-	/// The node that should be temporary inspected during execution
-	pub(super) inspect_node: Option<NodeId>,
+	/// The nodes that should be temporarily inspected during execution, each with its own monitor node
+	pub(super) inspect_nodes: Vec<NodeId>,
+	/// The Noise Pattern node whose output should be rendered at thumbnail resolution for the preview in its properties
+	pub(super) preview_node: Option<NodeId>,
 }
 
 #[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -73,6 +89,12 @@ pub struct ExportConfig {
 	pub scale_factor: f64,
 	pub bounds: ExportBounds,
 	pub transparent_background: bool,
+	/// Grow the bounds of an exported artboard to include its bleed
+	pub include_bleed: bool,
+	/// The printer's marks and color separation of a PDF export
+	pub print_settings: PrintSettings,
+	/// Width of the bleed included in the bounds, in document pixels
+	pub bleed: f64,
 	pub size: DVec2,
 }
 
@@ -87,6 +109,14 @@ impl InternalNodeGraphUpdateSender {
 	fn send_execution_response(&self, response: ExecutionResponse) {
 		self.0.send(NodeGraphUpdate::ExecutionResponse(response)).expect("Failed to send response")
 	}
+
+	fn send_thumbnail_response(&self, responses: VecDeque<FrontendMessage>) {
+		self.0.send(NodeGraphUpdate::ThumbnailResponse(responses)).expect("Failed to send response")
+	}
+
+	fn send_probe_response(&self, node_path: Vec<NodeId>, value: WireProbeValue) {
+		self.0.send(NodeGraphUpdate::ProbeResponse { node_path, value }).expect("Failed to send response")
+	}
 }
 
 impl NodeGraphUpdateSender for InternalNodeGraphUpdateSender {
@@ -120,8 +150,11 @@ impl NodeRuntime {
 			monitor_nodes: Vec::new(),
 
 			thumbnail_renders: Default::default(),
+			thumbnail_queue: Default::default(),
 			vector_modify: Default::default(),
-			inspect_state: None,
+			inspect_states: Vec::new(),
+			preview_node: None,
+			last_render_config: None,
 		}
 	}
 
@@ -143,15 +176,19 @@ impl NodeRuntime {
 		let mut preferences = None;
 		let mut graph = None;
 		let mut execution = None;
+		let mut probes = Vec::new();
 		for request in self.receiver.try_iter() {
 			match request {
 				GraphRuntimeRequest::GraphUpdate(_) => graph = Some(request),
 				GraphRuntimeRequest::ExecutionRequest(_) => execution = Some(request),
 				GraphRuntimeRequest::FontCacheUpdate(_) => font = Some(request),
 				GraphRuntimeRequest::EditorPreferencesUpdate(_) => preferences = Some(request),
+				GraphRuntimeRequest::ProbeRequest(_) => probes.push(request),
 			}
 		}
-		let requests = [font, preferences, graph, execution].into_iter().flatten();
+		let idle = font.is_none() && preferences.is_none() && graph.is_none() && execution.is_none();
+		// Probes come last so they see the graph as it was just rendered
+		let requests = [font, preferences, graph, execution].into_iter().flatten().chain(probes);
 
 		for request in requests {
 			match request {
@@ -182,9 +219,14 @@ impl NodeRuntime {
 						let _ = self.update_network(graph).await;
 					}
 				}
-				GraphRuntimeRequest::GraphUpdate(GraphUpdate { mut network, inspect_node }) => {
-					// Insert the monitor node to manage the inspection
-					self.inspect_state = inspect_node.map(|inspect| InspectState::monitor_inspect_node(&mut network, inspect));
+				GraphRuntimeRequest::GraphUpdate(GraphUpdate {
+					mut network,
+					inspect_nodes,
+					preview_node,
+				}) => {
+					// Insert the monitor nodes to manage the inspections
+					self.inspect_states = inspect_nodes.into_iter().map(|inspect| InspectState::monitor_inspect_node(&mut network, inspect)).collect();
+					self.preview_node = preview_node;
 
 					self.old_graph = Some(network.clone());
 					self.node_graph_errors.clear();
@@ -197,25 +239,54 @@ impl NodeRuntime {
 				}
 				GraphRuntimeRequest::ExecutionRequest(ExecutionRequest { execution_id, render_config, .. }) => {
 					let transform = render_config.viewport.transform;
+					if !render_config.for_export {
+						self.last_render_config = Some(render_config);
+					}
 
+					// Time only this render, not the thumbnails and previews evaluated since the last one
+					self.executor.take_node_timings();
 					let result = self.execute_network(render_config).await;
-					let mut responses = VecDeque::new();
+					let node_timings = self.executor.take_node_timings();
+					let graph_changed = self.update_thumbnails;
 					// TODO: Only process monitor nodes if the graph has changed, not when only the Footprint changes
-					self.process_monitor_nodes(&mut responses, self.update_thumbnails);
+					self.process_monitor_nodes(self.update_thumbnails);
 					self.update_thumbnails = false;
 
-					// Resolve the result from the inspection by accessing the monitor node
-					let inspect_result = self.inspect_state.and_then(|state| state.access(&self.executor));
+					// Resolve the results from the inspections by accessing the monitor nodes
+					let inspect_results = self.inspect_states.iter().filter_map(|state| state.access(&self.executor)).collect();
+
+					// The preview only depends on the node's own inputs, so it's left alone when just the view has changed
+					let node_preview = match self.preview_node {
+						Some(node_id) if graph_changed => Some((node_id, self.render_noise_preview(node_id).await.unwrap_or_default())),
+						_ => None,
+					};
 
 					self.sender.send_execution_response(ExecutionResponse {
 						execution_id,
 						result,
-						responses,
 						transform,
 						vector_modify: self.vector_modify.clone(),
-						inspect_result,
+						inspect_results,
+						node_preview,
+						node_timings,
 					});
 				}
+				GraphRuntimeRequest::ProbeRequest(node_path) => {
+					let value = self.probe_node(&node_path).await;
+					self.sender.send_probe_response(node_path, value);
+				}
+			}
+		}
+
+		// Thumbnails are only rendered while the graph has nothing else to do, so they never hold up the artwork in the viewport
+		if idle && !self.thumbnail_queue.is_empty() {
+			let mut responses = VecDeque::new();
+			for _ in 0..LAYER_THUMBNAILS_PER_RUN {
+				let Some((layer, monitor_node_path)) = self.thumbnail_queue.pop_front() else { break };
+				self.render_thumbnail(layer, &monitor_node_path, &mut responses).await;
+			}
+			if !responses.is_empty() {
+				self.sender.send_thumbnail_response(responses);
 			}
 		}
 	}
@@ -262,13 +333,13 @@ impl NodeRuntime {
 	}
 
 	/// Updates state data
-	pub fn process_monitor_nodes(&mut self, responses: &mut VecDeque<FrontendMessage>, update_thumbnails: bool) {
+	pub fn process_monitor_nodes(&mut self, update_thumbnails: bool) {
 		// TODO: Consider optimizing this since it's currently O(m*n^2), with a sort it could be made O(m * n*log(n))
 		self.thumbnail_renders.retain(|id, _| self.monitor_nodes.iter().any(|monitor_node_path| monitor_node_path.contains(id)));
 
 		for monitor_node_path in &self.monitor_nodes {
-			// Skip the inspect monitor node
-			if self.inspect_state.is_some_and(|inspect_state| monitor_node_path.last().copied() == Some(inspect_state.monitor_node)) {
+			// Skip the inspect monitor nodes
+			if self.inspect_states.iter().any(|inspect_state| monitor_node_path.last().copied() == Some(inspect_state.monitor_node)) {
 				continue;
 			}
 			// The monitor nodes are located within a document node, and are thus children in that network, so this gets the parent document node's ID
@@ -287,10 +358,11 @@ impl NodeRuntime {
 				continue;
 			};
 
-			if let Some(io) = introspected_data.downcast_ref::<IORecord<Context, graphene_core::GraphicElement>>() {
-				Self::process_graphic_element(&mut self.thumbnail_renders, parent_network_node_id, &io.output, responses, update_thumbnails)
-			} else if let Some(io) = introspected_data.downcast_ref::<IORecord<Context, graphene_core::Artboard>>() {
-				Self::process_graphic_element(&mut self.thumbnail_renders, parent_network_node_id, &io.output, responses, update_thumbnails)
+			// Queue the thumbnails of layers to be rendered in the background
+			if introspected_data.is::<IORecord<Context, GraphicElement>>() || introspected_data.is::<IORecord<Context, Artboard>>() {
+				if update_thumbnails && !self.thumbnail_queue.iter().any(|(layer, _)| *layer == parent_network_node_id) {
+					self.thumbnail_queue.push_back((parent_network_node_id, monitor_node_path.clone()));
+				}
 			// Insert the vector modify if we are dealing with vector data
 			} else if let Some(record) = introspected_data.downcast_ref::<IORecord<Context, VectorDataTable>>() {
 				self.vector_modify.insert(parent_network_node_id, record.output.one_instance().instance.clone());
@@ -300,44 +372,125 @@ impl NodeRuntime {
 		}
 	}
 
-	// If this is `GraphicElement` data:
-	// Regenerate click targets and thumbnails for the layers in the graph, modifying the state and updating the UI.
-	fn process_graphic_element(
-		thumbnail_renders: &mut HashMap<NodeId, Vec<SvgSegment>>,
-		parent_network_node_id: NodeId,
-		graphic_element: &impl GraphicElementRendered,
-		responses: &mut VecDeque<FrontendMessage>,
-		update_thumbnails: bool,
-	) {
-		// RENDER THUMBNAIL
-
-		if !update_thumbnails {
+	/// Renders the thumbnail of a layer by evaluating its content again against a small footprint fitted to its bounds, so it shows only the layer's own contribution at the resolution of the thumbnail.
+	async fn render_thumbnail(&mut self, layer: NodeId, monitor_node_path: &[NodeId], responses: &mut VecDeque<FrontendMessage>) {
+		// The layer may have been removed from the graph since it was queued
+		let Ok(introspected_data) = self.executor.introspect(monitor_node_path) else { return };
+
+		// The bounds from the last execution of the graph place the thumbnail footprint
+		let (bounds, is_artboard) = if let Some(io) = introspected_data.downcast_ref::<IORecord<Context, GraphicElement>>() {
+			(io.output.bounding_box(DAffine2::IDENTITY), false)
+		} else if let Some(io) = introspected_data.downcast_ref::<IORecord<Context, Artboard>>() {
+			(io.output.bounding_box(DAffine2::IDENTITY), true)
+		} else {
 			return;
+		};
+
+		let footprint = bounds.map(|[min, max]| {
+			let size = (max - min).max(DVec2::splat(f64::EPSILON));
+			let scale = LAYER_THUMBNAIL_RESOLUTION / size.max_element();
+			Footprint {
+				transform: DAffine2::from_scale(DVec2::splat(scale)) * DAffine2::from_translation(-min),
+				resolution: (size * scale).ceil().max(DVec2::ONE).as_uvec2(),
+				..Default::default()
+			}
+		});
+		let context = OwnedContextImpl::default().with_footprint(footprint.unwrap_or_default()).into_context();
+
+		let svg = if is_artboard {
+			let Some(artboard) = self.executor.evaluate_path::<Context, Artboard>(monitor_node_path, context).await else {
+				return;
+			};
+			Self::render_thumbnail_svg(&artboard)
+		} else {
+			let Some(graphic_element) = self.executor.evaluate_path::<Context, GraphicElement>(monitor_node_path, context).await else {
+				return;
+			};
+			Self::render_thumbnail_svg(&graphic_element)
+		};
+
+		let old_thumbnail_svg = self.thumbnail_renders.entry(layer).or_default();
+		if old_thumbnail_svg != &svg {
+			responses.push_back(FrontendMessage::UpdateNodeThumbnail {
+				id: layer,
+				value: svg.to_svg_string(),
+			});
+			*old_thumbnail_svg = svg;
+		}
+	}
+
+	/// Renders the output of a Noise Pattern node by evaluating it again against a footprint covering the square at the origin at thumbnail resolution, so it's quick enough to redraw while its parameters are scrubbed.
+	async fn render_noise_preview(&self, node_id: NodeId) -> Option<String> {
+		let footprint = Footprint {
+			transform: DAffine2::from_scale(DVec2::splat(LAYER_THUMBNAIL_RESOLUTION / NOISE_PREVIEW_SIZE)),
+			resolution: DVec2::splat(LAYER_THUMBNAIL_RESOLUTION).as_uvec2(),
+			..Default::default()
+		};
+		let context = OwnedContextImpl::default().with_footprint(footprint).into_context();
+
+		// The node isn't part of the compiled graph if nothing uses its output
+		let image_frame = self.executor.evaluate_path::<Context, ImageFrameTable<Color>>(&[node_id], context).await?;
+
+		let bounds = [DVec2::ZERO, DVec2::splat(NOISE_PREVIEW_SIZE)];
+		let render_params = RenderParams::new(ViewMode::Normal, Some(bounds), true, false, false);
+		let mut render = SvgRender::new();
+		image_frame.render_svg(&mut render, &render_params);
+
+		render.format_svg(bounds[0], bounds[1]);
+		Some(render.svg.to_svg_string())
+	}
+
+	/// Evaluates the output of a node with the context of the most recent render and summarizes it for the wire probe tooltip.
+	async fn probe_node(&self, node_path: &[NodeId]) -> WireProbeValue {
+		let Some(render_config) = self.last_render_config else {
+			return WireProbeValue::unavailable("The graph hasn't been rendered yet");
+		};
+		let context = OwnedContextImpl::default()
+			.with_footprint(render_config.viewport)
+			.with_real_time(render_config.time.time)
+			.with_animation_time(render_config.time.animation_time.as_secs_f64())
+			.into_context();
+
+		// The node isn't part of the compiled graph if nothing uses its output
+		match self.executor.evaluate_path_tagged_value::<Context>(node_path, context).await {
+			Ok(value) => {
+				let thumbnail = match &value {
+					TaggedValue::VectorData(table) => Self::render_probe_thumbnail(table),
+					TaggedValue::GraphicGroup(table) => Self::render_probe_thumbnail(table),
+					TaggedValue::ArtboardGroup(table) => Self::render_probe_thumbnail(table),
+					// Raster images are left out, since their thumbnails would embed the whole image each time the value is probed
+					_ => None,
+				};
+				WireProbeValue {
+					thumbnail,
+					..WireProbeValue::from_tagged_value(&value)
+				}
+			}
+			Err(error) => {
+				log::trace!("Could not probe node {node_path:?}: {error}");
+				WireProbeValue::unavailable("This value isn't evaluated on its own")
+			}
 		}
+	}
+
+	/// Renders a graphical value shown by a wire probe into an SVG, or `None` if it's empty.
+	fn render_probe_thumbnail(graphic: &impl GraphicElementRendered) -> Option<String> {
+		graphic.bounding_box(DAffine2::IDENTITY)?;
+		Some(Self::render_thumbnail_svg(graphic).to_svg_string())
+	}
 
+	/// Renders a `GraphicElement` into an SVG fitted to its bounds, with a viewbox and outer <svg>...</svg> wrapper tag.
+	fn render_thumbnail_svg(graphic_element: &impl GraphicElementRendered) -> Vec<SvgSegment> {
 		let bounds = graphic_element.bounding_box(DAffine2::IDENTITY);
 
-		// Render the thumbnail from a `GraphicElement` into an SVG string
 		let render_params = RenderParams::new(ViewMode::Normal, bounds, true, false, false);
 		let mut render = SvgRender::new();
 		graphic_element.render_svg(&mut render, &render_params);
 
-		// And give the SVG a viewbox and outer <svg>...</svg> wrapper tag
 		let [min, max] = bounds.unwrap_or_default();
 		render.format_svg(min, max);
 
-		// UPDATE FRONTEND THUMBNAIL
-
-		let new_thumbnail_svg = render.svg;
-		let old_thumbnail_svg = thumbnail_renders.entry(parent_network_node_id).or_default();
-
-		if old_thumbnail_svg != &new_thumbnail_svg {
-			responses.push_back(FrontendMessage::UpdateNodeThumbnail {
-				id: parent_network_node_id,
-				value: new_thumbnail_svg.to_svg_string(),
-			});
-			*old_thumbnail_svg = new_thumbnail_svg;
-		}
+		render.svg
 	}
 }
 
@@ -389,6 +542,14 @@ impl InspectResult {
 	}
 }
 
+/// The output of an inspected node if it's of type `T`, whether the node was called with a context or with nothing.
+pub fn introspected_output<T: 'static>(introspected_data: &dyn std::any::Any) -> Option<&T> {
+	if let Some(io) = introspected_data.downcast_ref::<IORecord<Context, T>>() {
+		return Some(&io.output);
+	}
+	introspected_data.downcast_ref::<IORecord<(), T>>().map(|io| &io.output)
+}
+
 // This is very ugly but is required to be inside a message
 impl PartialEq for InspectResult {
 	fn eq(&self, other: &Self) -> bool {
@@ -35,6 +35,8 @@ export function createInputManager(editor: Editor, dialog: DialogState, portfoli
 	let viewportPointerInteractionOngoing = false;
 	let textToolInteractiveInputElement = undefined as undefined | HTMLDivElement;
 	let canvasFocused = true;
+	// Positions of the fingers touching the viewport, used to tilt the canvas with a two-finger twist gesture
+	const viewportTouches = new Map<number, [number, number]>();
 
 	// Event listeners
 
@@ -47,6 +49,7 @@ export function createInputManager(editor: Editor, dialog: DialogState, portfoli
 		{ target: window, eventName: "pointermove", action: (e: PointerEvent) => onPointerMove(e) },
 		{ target: window, eventName: "pointerdown", action: (e: PointerEvent) => onPointerDown(e) },
 		{ target: window, eventName: "pointerup", action: (e: PointerEvent) => onPointerUp(e) },
+		{ target: window, eventName: "pointercancel", action: (e: PointerEvent) => viewportTouches.delete(e.pointerId) },
 		{ target: window, eventName: "mousedown", action: (e: MouseEvent) => onMouseDown(e) },
 		{ target: window, eventName: "mouseup", action: (e: MouseEvent) => onPotentialDoubleClick(e) },
 		{ target: window, eventName: "wheel", action: (e: WheelEvent) => onWheelScroll(e), options: { passive: false } },
@@ -124,7 +127,7 @@ export function createInputManager(editor: Editor, dialog: DialogState, portfoli
 		}
 
 		if (get(dialog).visible && key === "Escape") {
-			dialog.dismissDialog();
+			dialog.cancelDialog();
 		}
 	}
 
@@ -144,6 +147,11 @@ export function createInputManager(editor: Editor, dialog: DialogState, portfoli
 	function onPointerMove(e: PointerEvent) {
 		if (!e.buttons) viewportPointerInteractionOngoing = false;
 
+		if (viewportTouches.has(e.pointerId) && viewportTouches.size === 2) {
+			onTwistGesture(e);
+			return;
+		}
+
 		// Don't redirect pointer movement to the backend if there's no ongoing interaction and it's over a floating menu, or the graph overlay, on top of the canvas
 		// TODO: A better approach is to pass along a boolean to the backend's input preprocessor so it can know if it's being occluded by the GUI.
 		// TODO: This would allow it to properly decide to act on removing hover focus from something that was hovered in the canvas before moving over the GUI.
@@ -160,9 +168,29 @@ export function createInputManager(editor: Editor, dialog: DialogState, portfoli
 		}
 
 		const modifiers = makeKeyboardModifiersBitfield(e);
+		editor.handle.onPointerPressure(pointerPressure(e));
 		editor.handle.onMouseMove(e.clientX, e.clientY, e.buttons, modifiers);
 	}
 
+	// Only pens report meaningful pressure, since browsers report a fixed pressure of 0.5 for mouse buttons
+	function pointerPressure(e: PointerEvent): number | undefined {
+		return e.pointerType === "pen" ? e.pressure : undefined;
+	}
+
+	// Tilts the canvas by the change in angle of the line between the two fingers touching the viewport
+	function onTwistGesture(e: PointerEvent) {
+		const [[startAX, startAY], [startBX, startBY]] = [...viewportTouches.values()];
+		viewportTouches.set(e.pointerId, [e.clientX, e.clientY]);
+		const [[endAX, endAY], [endBX, endBY]] = [...viewportTouches.values()];
+
+		const startAngle = Math.atan2(startBY - startAY, startBX - startAX);
+		const endAngle = Math.atan2(endBY - endAY, endBX - endAX);
+		// Wrap the difference into the range -π to π so crossing the ±π boundary doesn't spin the canvas all the way around
+		const angle = Math.atan2(Math.sin(endAngle - startAngle), Math.cos(endAngle - startAngle));
+
+		if (angle !== 0) editor.handle.tiltCanvasBy(angle);
+	}
+
 	function onMouseDown(e: MouseEvent) {
 		// Block middle mouse button auto-scroll mode (the circlar gizmo that appears and allows quick scrolling by moving the cursor above or below it)
 		if (e.button === BUTTON_MIDDLE) e.preventDefault();
@@ -171,12 +199,19 @@ export function createInputManager(editor: Editor, dialog: DialogState, portfoli
 	function onPointerDown(e: PointerEvent) {
 		const { target } = e;
 		const isTargetingCanvas = target instanceof Element && (target.closest("[data-viewport]") || target.closest("[data-node-graph]"));
+
+		if (e.pointerType === "touch" && target instanceof Element && target.closest("[data-viewport]")) {
+			viewportTouches.set(e.pointerId, [e.clientX, e.clientY]);
+
+			// The second finger begins a twist gesture instead of another click
+			if (viewportTouches.size > 1) return;
+		}
 		const inDialog = target instanceof Element && target.closest("[data-dialog] [data-floating-menu-content]");
 		const inContextMenu = target instanceof Element && target.closest("[data-context-menu]");
 		const inTextInput = target === textToolInteractiveInputElement;
 
 		if (get(dialog).visible && !inDialog) {
-			dialog.dismissDialog();
+			dialog.cancelDialog();
 			e.preventDefault();
 			e.stopPropagation();
 		}
@@ -189,6 +224,7 @@ export function createInputManager(editor: Editor, dialog: DialogState, portfoli
 
 		if (viewportPointerInteractionOngoing) {
 			const modifiers = makeKeyboardModifiersBitfield(e);
+			editor.handle.onPointerPressure(pointerPressure(e));
 			editor.handle.onMouseDown(e.clientX, e.clientY, e.buttons, modifiers);
 		}
 	}
@@ -200,6 +236,8 @@ export function createInputManager(editor: Editor, dialog: DialogState, portfoli
 		// TODO: <https://stackoverflow.com/questions/57102502/preventing-mouse-fourth-and-fifth-buttons-from-navigating-back-forward-in-browse>
 		if (e.button === BUTTON_BACK || e.button === BUTTON_FORWARD) e.preventDefault();
 
+		viewportTouches.delete(e.pointerId);
+
 		if (!e.buttons) viewportPointerInteractionOngoing = false;
 
 		if (textToolInteractiveInputElement) return;
@@ -264,6 +302,7 @@ export function createInputManager(editor: Editor, dialog: DialogState, portfoli
 	async function onBeforeUnload(e: BeforeUnloadEvent) {
 		const activeDocument = get(portfolio).documents[get(portfolio).activeDocumentIndex];
 		if (activeDocument && !activeDocument.isAutoSaved) editor.handle.triggerAutoSave(activeDocument.id);
+		editor.handle.saveSession();
 
 		// Skip the message if the editor crashed, since work is already lost
 		if (await editor.handle.hasCrashed()) return;
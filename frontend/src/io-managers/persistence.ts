@@ -5,17 +5,25 @@ import { type Editor } from "@graphite/editor";
 import {
 	TriggerIndexedDbWriteDocument,
 	TriggerIndexedDbRemoveDocument,
+	TriggerIndexedDbWriteRecentFile,
+	TriggerIndexedDbRemoveRecentFiles,
 	TriggerSavePreferences,
 	TriggerLoadPreferences,
 	TriggerLoadFirstAutoSaveDocument,
 	TriggerLoadRestAutoSaveDocuments,
+	TriggerLoadSession,
+	TriggerOpenRecentFile,
 	TriggerSaveActiveDocument,
+	TriggerSaveSession,
 } from "@graphite/messages";
 import { type PortfolioState } from "@graphite/state-providers/portfolio";
 
 const graphiteStore = createStore("graphite", "store");
 
 export function createPersistenceManager(editor: Editor, portfolio: PortfolioState) {
+	// Resolves once the auto-saved documents have been reopened, since the session refers to them
+	let restDocumentsLoaded: Promise<void> = Promise.resolve();
+
 	// DOCUMENTS
 
 	async function storeDocumentOrder() {
@@ -139,6 +147,40 @@ export function createPersistenceManager(editor: Editor, portfolio: PortfolioSta
 		}
 	}
 
+	// RECENT FILES
+
+	async function storeRecentFile(key: string, content: string) {
+		await update<Record<string, string>>(
+			"recent_files",
+			(old) => {
+				const recentFiles = old || {};
+				recentFiles[key] = content;
+				return recentFiles;
+			},
+			graphiteStore,
+		);
+	}
+
+	async function removeRecentFiles(keys: string[]) {
+		await update<Record<string, string>>(
+			"recent_files",
+			(old) => {
+				const recentFiles = old || {};
+				keys.forEach((key) => delete recentFiles[key]);
+				return recentFiles;
+			},
+			graphiteStore,
+		);
+	}
+
+	async function openRecentFile(name: string, key: string) {
+		const recentFiles = await get<Record<string, string>>("recent_files", graphiteStore);
+		const content = recentFiles?.[key];
+		if (content === undefined) return;
+
+		editor.handle.openDocumentFile(name, content);
+	}
+
 	// PREFERENCES
 
 	async function savePreferences(preferences: TriggerSavePreferences["preferences"]) {
@@ -152,6 +194,21 @@ export function createPersistenceManager(editor: Editor, portfolio: PortfolioSta
 		editor.handle.loadPreferences(JSON.stringify(preferences));
 	}
 
+	// SESSION
+
+	async function saveSession(session: string) {
+		await set("session", session, graphiteStore);
+	}
+
+	async function loadSession() {
+		await restDocumentsLoaded;
+
+		const session = await get<string>("session", graphiteStore);
+		if (!session) return;
+
+		editor.handle.loadSession(session);
+	}
+
 	// FRONTEND MESSAGE SUBSCRIPTIONS
 
 	// Subscribe to process backend events
@@ -171,7 +228,23 @@ export function createPersistenceManager(editor: Editor, portfolio: PortfolioSta
 		await loadFirstDocument();
 	});
 	editor.subscriptions.subscribeJsMessage(TriggerLoadRestAutoSaveDocuments, async () => {
-		await loadRestDocuments();
+		restDocumentsLoaded = loadRestDocuments();
+		await restDocumentsLoaded;
+	});
+	editor.subscriptions.subscribeJsMessage(TriggerIndexedDbWriteRecentFile, async (writeRecentFile) => {
+		await storeRecentFile(writeRecentFile.key, writeRecentFile.content);
+	});
+	editor.subscriptions.subscribeJsMessage(TriggerIndexedDbRemoveRecentFiles, async (removeRecentFilesMessage) => {
+		await removeRecentFiles(removeRecentFilesMessage.keys);
+	});
+	editor.subscriptions.subscribeJsMessage(TriggerOpenRecentFile, async (triggerOpenRecentFile) => {
+		await openRecentFile(triggerOpenRecentFile.name, triggerOpenRecentFile.key);
+	});
+	editor.subscriptions.subscribeJsMessage(TriggerSaveSession, async (triggerSaveSession) => {
+		await saveSession(triggerSaveSession.session);
+	});
+	editor.subscriptions.subscribeJsMessage(TriggerLoadSession, async () => {
+		await loadSession();
 	});
 	editor.subscriptions.subscribeJsMessage(TriggerSaveActiveDocument, async (triggerSaveActiveDocument) => {
 		const documentId = String(triggerSaveActiveDocument.documentId);
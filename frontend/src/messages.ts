@@ -40,6 +40,11 @@ export class UpdateClickTargets extends JsMessage {
 	readonly clickTargets!: FrontendClickTargets | undefined;
 }
 
+export class UpdateCustomNodeTypes extends JsMessage {
+	@Type(() => FrontendNode)
+	readonly nodeTypes!: FrontendNodeType[];
+}
+
 const ContextTupleToVec2 = Transform((data) => {
 	if (data.obj.contextMenuInformation === undefined) return undefined;
 	const contextMenuCoordinates = { x: data.obj.contextMenuInformation.contextMenuCoordinates[0], y: data.obj.contextMenuInformation.contextMenuCoordinates[1] };
@@ -48,6 +53,8 @@ const ContextTupleToVec2 = Transform((data) => {
 		contextMenuData = { nodeId: contextMenuData.ToggleLayer.nodeId, currentlyIsNode: contextMenuData.ToggleLayer.currentlyIsNode };
 	} else if (contextMenuData.CreateNode !== undefined) {
 		contextMenuData = { type: "CreateNode", compatibleType: contextMenuData.CreateNode.compatibleType };
+	} else if (contextMenuData.InspectWire !== undefined) {
+		contextMenuData = { type: "InspectWire", pinned: contextMenuData.InspectWire.pinned };
 	}
 	return { contextMenuCoordinates, contextMenuData };
 });
@@ -103,9 +110,17 @@ export class UpdateNodeGraph extends JsMessage {
 	@Type(() => FrontendNodeWire)
 	readonly wires!: FrontendNodeWire[];
 
-	readonly wiresDirectNotGridAligned!: boolean;
+	@Type(() => FrontendGraphFrame)
+	readonly frames!: FrontendGraphFrame[];
+
+	@Type(() => FrontendGraphNote)
+	readonly notes!: FrontendGraphNote[];
+
+	readonly wireStyle!: GraphWireStyle;
 }
 
+export type GraphWireStyle = "Direct" | "GridAligned" | "Straight";
+
 export class UpdateNodeGraphTransform extends JsMessage {
 	readonly transform!: NodeGraphTransform;
 }
@@ -125,6 +140,10 @@ export class UpdateNodeThumbnail extends JsMessage {
 	readonly value!: string;
 }
 
+export class UpdateNodeGraphProfile extends JsMessage {
+	readonly profile!: FrontendNodeProfile[] | undefined;
+}
+
 export class UpdateNodeGraphSelection extends JsMessage {
 	@Type(() => BigInt)
 	readonly selected!: bigint[];
@@ -139,6 +158,25 @@ export class UpdateWirePathInProgress extends JsMessage {
 	readonly wirePath!: WirePath | undefined;
 }
 
+const WireProbeTupleToVec2 = Transform(({ obj: { wireProbe } }) => {
+	if (wireProbe === undefined) return undefined;
+	return { ...wireProbe, position: { x: wireProbe.position[0], y: wireProbe.position[1] } };
+});
+
+export class UpdateWireProbe extends JsMessage {
+	@WireProbeTupleToVec2
+	readonly wireProbe!: FrontendWireProbe | undefined;
+}
+
+const PinnedWireProbesTupleToVec2 = Transform(({ obj: { pinnedWireProbes } }) =>
+	pinnedWireProbes.map((pinnedWireProbe: any) => ({ ...pinnedWireProbe, position: { x: pinnedWireProbe.position[0], y: pinnedWireProbe.position[1] } })),
+);
+
+export class UpdatePinnedWireProbes extends JsMessage {
+	@PinnedWireProbesTupleToVec2
+	readonly pinnedWireProbes!: FrontendPinnedWireProbe[];
+}
+
 // Allows the auto save system to use a string for the id rather than a BigInt.
 // IndexedDb does not allow for BigInts as primary keys.
 // TypeScript does not allow subclasses to change the type of class variables in subclasses.
@@ -184,7 +222,36 @@ export type FrontendClickTargets = {
 
 export type ContextMenuInformation = {
 	contextMenuCoordinates: XY;
-	contextMenuData: "CreateNode" | { type: "CreateNode"; compatibleType: string } | { nodeId: bigint; currentlyIsNode: boolean };
+	contextMenuData: "CreateNode" | { type: "CreateNode"; compatibleType: string } | { type: "InspectWire"; pinned: boolean } | { nodeId: bigint; currentlyIsNode: boolean };
+};
+
+export type WireProbeValue = {
+	typeName: string;
+	details: [string, string][];
+	color: string | undefined;
+	thumbnail: string | undefined;
+};
+
+export type FrontendWireProbe = {
+	position: XY;
+	inspected: boolean;
+	value: WireProbeValue | undefined;
+};
+
+export type FrontendPinnedWireProbe = {
+	// Kept in the form it's sent in, so it can be passed back to identify the wire when it's unpinned
+	inputConnector: unknown;
+	position: XY;
+	value: WireProbeValue | undefined;
+};
+
+export type CacheStatus = "Evaluated" | "PartlyCached" | "Cached";
+
+export type FrontendNodeProfile = {
+	nodeId: bigint;
+	milliseconds: number;
+	heat: number;
+	status: CacheStatus;
 };
 
 export type FrontendGraphDataType = "General" | "Raster" | "VectorData" | "Number" | "Group" | "Artboard";
@@ -290,6 +357,8 @@ export class FrontendNode {
 
 	readonly visible!: boolean;
 
+	readonly bypassed!: boolean;
+
 	readonly unlocked!: boolean;
 
 	readonly errors!: string | undefined;
@@ -335,12 +404,40 @@ export class FrontendNodeWire {
 	readonly dashed!: boolean;
 }
 
+export class FrontendGraphFrame {
+	readonly id!: bigint;
+
+	readonly title!: string;
+
+	readonly color!: string;
+
+	@TupleToVec2
+	readonly position!: XY;
+
+	@TupleToVec2
+	readonly dimensions!: XY;
+}
+
+export class FrontendGraphNote {
+	readonly id!: bigint;
+
+	readonly text!: string;
+
+	@TupleToVec2
+	readonly position!: XY;
+
+	@TupleToVec2
+	readonly dimensions!: XY;
+}
+
 export class FrontendNodeType {
 	readonly name!: string;
 
 	readonly category!: string;
 
 	readonly inputTypes!: string[];
+
+	readonly outputTypes!: string[];
 }
 
 export class NodeGraphTransform {
@@ -376,6 +473,19 @@ export class TriggerIndexedDbRemoveDocument extends JsMessage {
 	documentId!: string;
 }
 
+export class TriggerIndexedDbWriteRecentFile extends JsMessage {
+	// Use a string since IndexedDB can not use BigInts for keys
+	@Transform(({ value }: { value: bigint }) => value.toString())
+	key!: string;
+
+	content!: string;
+}
+
+export class TriggerIndexedDbRemoveRecentFiles extends JsMessage {
+	@Transform(({ value }: { value: bigint[] }) => value.map((key) => key.toString()))
+	keys!: string[];
+}
+
 export class UpdateInputHints extends JsMessage {
 	@Type(() => HintInfo)
 	readonly hintData!: HintData;
@@ -413,11 +523,19 @@ export type HSVA = { h: number; s: number; v: number; a: number };
 export type HSV = { h: number; s: number; v: number };
 export type RGBA = { r: number; g: number; b: number; a: number };
 export type RGB = { r: number; g: number; b: number };
+export type HSL = { h: number; s: number; l: number };
+// Lightness (0-1), chroma (0 to about 0.37 within sRGB), and hue (0-1 of a full turn)
+export type OKLCH = { l: number; c: number; h: number };
+
+// How the color of a gradient stop is blended into the color of the stop after it
+export type GradientInterpolation = "Linear" | "OkLab" | "OkLch" | "Stepped";
+
+export type GradientStop = { position: number; color: Color; interpolation: GradientInterpolation; midpoint: number };
 
 export class Gradient {
-	readonly stops!: { position: number; color: Color }[];
+	readonly stops!: GradientStop[];
 
-	constructor(stops: { position: number; color: Color }[]) {
+	constructor(stops: GradientStop[]) {
 		this.stops = stops;
 	}
 
@@ -425,16 +543,42 @@ export class Gradient {
 		if (this.stops.length === 1) {
 			return `linear-gradient(to right, ${this.stops[0].color.toHexOptionalAlpha()} 0%, ${this.stops[0].color.toHexOptionalAlpha()} 100%)`;
 		}
-		const pieces = this.stops.map((stop) => `${stop.color.toHexOptionalAlpha()} ${stop.position * 100}%`);
-		return `linear-gradient(to right, ${pieces.join(", ")})`;
+		return `linear-gradient(to right, ${this.colorStopsCSS((color) => color.toHexOptionalAlpha())})`;
 	}
 
 	toLinearGradientCSSNoAlpha(): string {
 		if (this.stops.length === 1) {
 			return `linear-gradient(to right, ${this.stops[0].color.toHexNoAlpha()} 0%, ${this.stops[0].color.toHexNoAlpha()} 100%)`;
 		}
-		const pieces = this.stops.map((stop) => `${stop.color.toHexNoAlpha()} ${stop.position * 100}%`);
-		return `linear-gradient(to right, ${pieces.join(", ")})`;
+		return `linear-gradient(to right, ${this.colorStopsCSS((color) => color.toHexNoAlpha())})`;
+	}
+
+	// The CSS color stops, with extra stops approximating each segment which isn't blended linearly between its stops
+	colorStopsCSS(hex: (color: Color) => string | undefined): string {
+		const pieces = this.stops.flatMap((stop, index) => {
+			const piece = `${hex(stop.color)} ${stop.position * 100}%`;
+			const next = this.stops[index + 1];
+			if (!next) return [piece];
+
+			const midpoint = Math.max(0.01, Math.min(0.99, stop.midpoint));
+			const along = (t: number): number => (stop.position + (next.position - stop.position) * t) * 100;
+
+			if (stop.interpolation === "Stepped") return [piece, `${hex(stop.color)} ${along(midpoint)}%`, `${hex(next.color)} ${along(midpoint)}%`];
+
+			if (stop.interpolation === "OkLab" || stop.interpolation === "OkLch") {
+				const space = stop.interpolation === "OkLab" ? "oklab" : "oklch";
+				const samples = [0.25, 0.5, 0.75].map((t) => {
+					const bent = midpoint === 0.5 ? t : Math.pow(t, Math.log(0.5) / Math.log(midpoint));
+					return `color-mix(in ${space}, ${hex(stop.color)}, ${hex(next.color)} ${bent * 100}%) ${along(t)}%`;
+				});
+				return [piece, ...samples];
+			}
+
+			// A CSS color interpolation hint bends the blend the same way as the midpoint
+			if (midpoint !== 0.5) return [piece, `${along(midpoint)}%`];
+			return [piece];
+		});
+		return pieces.join(", ");
 	}
 
 	firstColor(): Color | undefined {
@@ -667,6 +811,46 @@ export class Color {
 		return { h: hsva.h * 360, s: hsva.s * 100, v: hsva.v * 100, a: hsva.a * 100 };
 	}
 
+	// https://bottosson.github.io/posts/oklab/
+	toOKLCH(): OKLCH | undefined {
+		if (this.none) return undefined;
+
+		const [r, g, b] = [this.red, this.green, this.blue].map((channel) => (channel <= 0.04045 ? channel / 12.92 : ((channel + 0.055) / 1.055) ** 2.4));
+
+		const long = Math.cbrt(0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b);
+		const medium = Math.cbrt(0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b);
+		const short = Math.cbrt(0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b);
+
+		const l = 0.2104542553 * long + 0.793617785 * medium - 0.0040720468 * short;
+		const labA = 1.9779984951 * long - 2.428592205 * medium + 0.4505937099 * short;
+		const labB = 0.0259040371 * long + 0.7827717662 * medium - 0.808675766 * short;
+
+		const c = Math.hypot(labA, labB);
+		const h = (((Math.atan2(labB, labA) / (2 * Math.PI)) % 1) + 1) % 1;
+		return { l, c, h };
+	}
+
+	// Colors outside the sRGB gamut are clamped into it
+	static fromOKLCH({ l, c, h }: OKLCH, alpha: number): Color {
+		const labA = c * Math.cos(h * 2 * Math.PI);
+		const labB = c * Math.sin(h * 2 * Math.PI);
+
+		const long = (l + 0.3963377774 * labA + 0.2158037573 * labB) ** 3;
+		const medium = (l - 0.1055613458 * labA - 0.0638541728 * labB) ** 3;
+		const short = (l - 0.0894841775 * labA - 1.291485548 * labB) ** 3;
+
+		const linear = [
+			4.0767416621 * long - 3.3077115913 * medium + 0.2309699292 * short,
+			-1.2684380046 * long + 2.6097574011 * medium - 0.3413193965 * short,
+			-0.0041960863 * long - 0.7034186147 * medium + 1.707614701 * short,
+		];
+		const [r, g, b] = linear.map((channel) => {
+			const clamped = Math.max(0, Math.min(1, channel));
+			return clamped <= 0.0031308 ? clamped * 12.92 : 1.055 * clamped ** (1 / 2.4) - 0.055;
+		});
+		return new Color(r, g, b, alpha);
+	}
+
 	opaque(): Color | undefined {
 		if (this.none) return undefined;
 
@@ -690,6 +874,18 @@ export class Color {
 		return linearR * 0.2126 + linearG * 0.7152 + linearB * 0.0722;
 	}
 
+	// The WCAG 2 contrast ratio against an opaque background color, from 1 (no contrast) to 21 (black on white), with any transparency of this color blended over the background
+	contrastRatio(background: Color): number | undefined {
+		if (this.none || background.none) return undefined;
+
+		const blend = (channel: number, backgroundChannel: number): number => channel * this.alpha + backgroundChannel * (1 - this.alpha);
+		const foreground = new Color(blend(this.red, background.red), blend(this.green, background.green), blend(this.blue, background.blue), 1);
+
+		const foregroundLuminance = foreground.luminance() || 0;
+		const backgroundLuminance = background.luminance() || 0;
+		return (Math.max(foregroundLuminance, backgroundLuminance) + 0.05) / (Math.min(foregroundLuminance, backgroundLuminance) + 0.05);
+	}
+
 	contrastingColor(): "black" | "white" {
 		if (this.none) return "black";
 
@@ -712,10 +908,32 @@ export class DisplayDialog extends JsMessage {
 	readonly icon!: IconName;
 }
 
+export class UpdateCompareSnapshotArtwork extends JsMessage {
+	readonly svg!: string;
+}
+
+export class UpdateCompareSnapshotState extends JsMessage {
+	readonly visible!: boolean;
+
+	readonly transform!: string;
+
+	readonly swipePosition!: number | undefined;
+}
+
 export class UpdateDocumentArtwork extends JsMessage {
 	readonly svg!: string;
 }
 
+export class UpdateDocumentArtworkPixelPreview extends JsMessage {
+	readonly svg!: string;
+
+	readonly width!: number;
+
+	readonly height!: number;
+
+	readonly transform!: string;
+}
+
 export class UpdateDocumentScrollbars extends JsMessage {
 	@TupleToVec2
 	readonly position!: XY;
@@ -775,6 +993,83 @@ export class UpdateGraphFadeArtwork extends JsMessage {
 	readonly percentage!: number;
 }
 
+export class UpdateAlignPanelState extends JsMessage {
+	readonly open!: boolean;
+}
+
+export class UpdateNavigatorPanelState extends JsMessage {
+	readonly open!: boolean;
+}
+
+export class UpdateNavigatorPanelOverview extends JsMessage {
+	@Type(() => BigInt)
+	readonly layers!: bigint[];
+
+	readonly bounds!: [[number, number], [number, number]] | undefined;
+
+	readonly viewport!: [number, number][];
+}
+
+export class UpdatePresentationState extends JsMessage {
+	readonly active!: boolean;
+
+	readonly frame!: [[number, number], [number, number]] | undefined;
+}
+
+export class UpdateHistogramState extends JsMessage {
+	readonly open!: boolean;
+}
+
+export class UpdateCommentsPanelState extends JsMessage {
+	readonly open!: boolean;
+}
+
+export class UpdateNodeAssetsPanelState extends JsMessage {
+	readonly open!: boolean;
+}
+
+export class UpdateBulkEditPanelState extends JsMessage {
+	readonly open!: boolean;
+}
+
+export class UpdateGraphFindPanelState extends JsMessage {
+	readonly open!: boolean;
+}
+
+export class UpdateDiagnosticsPanelState extends JsMessage {
+	readonly open!: boolean;
+}
+
+export class UpdateProfilerPanelState extends JsMessage {
+	readonly open!: boolean;
+}
+
+export class UpdateSwatchesPanelState extends JsMessage {
+	readonly open!: boolean;
+}
+
+type RawColor = { red: number; green: number; blue: number; alpha: number };
+
+export type ColorSwatch = { name: string; color: Color };
+
+export class UpdateColorPickerSwatches extends JsMessage {
+	@Transform(({ value }: { value: { name: string; color: RawColor }[] }) => value.map(({ name, color }) => ({ name, color: new Color(color.red, color.green, color.blue, color.alpha) })))
+	readonly swatches!: ColorSwatch[];
+
+	@Transform(({ value }: { value: RawColor[] }) => value.map((color) => new Color(color.red, color.green, color.blue, color.alpha)))
+	readonly recentColors!: Color[];
+}
+
+export type PrintProfile = "Coated" | "Uncoated" | "Newsprint";
+
+export class UpdateColorPickerPrintProfile extends JsMessage {
+	readonly printProfile!: PrintProfile | undefined;
+}
+
+export class UpdateTimelinePanelState extends JsMessage {
+	readonly open!: boolean;
+}
+
 export class UpdateSpreadsheetState extends JsMessage {
 	readonly open!: boolean;
 
@@ -791,6 +1086,8 @@ export class TriggerLoadRestAutoSaveDocuments extends JsMessage {}
 
 export class TriggerLoadPreferences extends JsMessage {}
 
+export class TriggerLoadSession extends JsMessage {}
+
 export class TriggerFetchAndOpenDocument extends JsMessage {
 	readonly name!: string;
 
@@ -799,6 +1096,13 @@ export class TriggerFetchAndOpenDocument extends JsMessage {
 
 export class TriggerOpenDocument extends JsMessage {}
 
+export class TriggerOpenRecentFile extends JsMessage {
+	readonly name!: string;
+
+	@Transform(({ value }: { value: bigint }) => value.toString())
+	readonly key!: string;
+}
+
 export class TriggerImport extends JsMessage {}
 
 export class TriggerPaste extends JsMessage {}
@@ -816,6 +1120,23 @@ export class TriggerDownloadImage extends JsMessage {
 	readonly size!: XY;
 }
 
+export type PrintJob = {
+	settings: { cropMarks: boolean; registrationMarks: boolean; printProfile: PrintProfile | undefined };
+	bleed: number;
+	scaleFactor: number;
+};
+
+export class TriggerRasterizeForPrint extends JsMessage {
+	readonly svg!: string;
+
+	readonly name!: string;
+
+	@TupleToVec2
+	readonly size!: XY;
+
+	readonly printJob!: PrintJob;
+}
+
 export class TriggerDownloadTextFile extends JsMessage {
 	readonly document!: string;
 
@@ -826,6 +1147,10 @@ export class TriggerSavePreferences extends JsMessage {
 	readonly preferences!: Record<string, unknown>;
 }
 
+export class TriggerSaveSession extends JsMessage {
+	readonly session!: string;
+}
+
 export class TriggerSaveActiveDocument extends JsMessage {
 	readonly documentId!: bigint;
 }
@@ -858,8 +1183,12 @@ export class DisplayEditableTextbox extends JsMessage {
 	readonly maxWidth!: undefined | number;
 
 	readonly maxHeight!: undefined | number;
+
+	readonly align!: TextAlign;
 }
 
+export type TextAlign = "Left" | "Center" | "Right";
+
 export class DisplayEditableTextboxTransform extends JsMessage {
 	readonly transform!: number[];
 }
@@ -887,6 +1216,8 @@ export class LayerPanelEntry {
 
 	childrenPresent!: boolean;
 
+	adjustment!: boolean;
+
 	expanded!: boolean;
 
 	@Transform(({ value }: { value: bigint }) => Number(value))
@@ -907,6 +1238,10 @@ export class LayerPanelEntry {
 	ancestorOfSelected!: boolean;
 
 	descendantOfSelected!: boolean;
+
+	soloed!: boolean;
+
+	hiddenBySolo!: boolean;
 }
 
 export class DisplayDialogDismiss extends JsMessage {}
@@ -922,6 +1257,11 @@ export class TriggerFontLoad extends JsMessage {
 	font!: Font;
 }
 
+export class TriggerLocateFontFile extends JsMessage {
+	@Type(() => Font)
+	font!: Font;
+}
+
 export class TriggerVisitLink extends JsMessage {
 	url!: string;
 }
@@ -954,6 +1294,8 @@ export abstract class WidgetProps {
 export class CheckboxInput extends WidgetProps {
 	checked!: boolean;
 
+	indeterminate!: boolean;
+
 	disabled!: boolean;
 
 	icon!: IconName;
@@ -967,9 +1309,12 @@ export class ColorInput extends WidgetProps {
 		if (value instanceof Gradient) return value;
 		const gradient = value["Gradient"];
 		if (gradient) {
-			const stops = gradient.map(([position, color]: [number, color: { red: number; green: number; blue: number; alpha: number }]) => ({
+			const segments: { interpolation: GradientInterpolation; midpoint: number }[] = gradient.segments;
+			const stops = gradient.stops.map(([position, color]: [number, color: { red: number; green: number; blue: number; alpha: number }], index: number) => ({
 				position,
 				color: new Color(color.red, color.green, color.blue, color.alpha),
+				interpolation: segments[index]?.interpolation || "Linear",
+				midpoint: segments[index]?.midpoint ?? 0.5,
 			}));
 			return new Gradient(stops);
 		}
@@ -988,6 +1333,10 @@ export class ColorInput extends WidgetProps {
 
 	allowNone!: boolean;
 
+	eyedropper!: boolean;
+
+	contrastCheck!: boolean;
+
 	// allowTransparency!: boolean; // TODO: Implement
 
 	@Transform(({ value }: { value: string }) => value || undefined)
@@ -1081,6 +1430,19 @@ export class DropdownInput extends WidgetProps {
 
 	interactive!: boolean;
 
+	searchable!: boolean;
+
+	disabled!: boolean;
+
+	@Transform(({ value }: { value: string }) => value || undefined)
+	tooltip!: string | undefined;
+}
+
+export class FilePathInput extends WidgetProps {
+	value!: string;
+
+	accept!: string;
+
 	disabled!: boolean;
 
 	@Transform(({ value }: { value: string }) => value || undefined)
@@ -1139,6 +1501,7 @@ export class ImageButton extends WidgetProps {
 
 export type NumberInputIncrementBehavior = "Add" | "Multiply" | "Callback" | "None";
 export type NumberInputMode = "Increment" | "Range";
+export type LengthUnit = "Pixel" | "Millimeter" | "Centimeter" | "Inch" | "Point";
 
 export class NumberInput extends WidgetProps {
 	// Label
@@ -1170,6 +1533,10 @@ export class NumberInput extends WidgetProps {
 
 	unitIsHiddenWhenEditing!: boolean;
 
+	lengthUnit!: LengthUnit | undefined;
+
+	unitScale!: number;
+
 	// Mode behavior
 
 	mode!: NumberInputMode;
@@ -1182,6 +1549,10 @@ export class NumberInput extends WidgetProps {
 
 	rangeMax!: number | undefined;
 
+	scrubLabel!: boolean;
+
+	previewWhileDragging!: boolean;
+
 	// Styling
 
 	minWidth!: number;
@@ -1228,6 +1599,23 @@ export class RadioInput extends WidgetProps {
 	minWidth!: number;
 }
 
+export class RangeSliderInput extends WidgetProps {
+	value!: [number, number];
+
+	min!: number;
+
+	max!: number;
+
+	unit!: string;
+
+	displayDecimalPlaces!: number;
+
+	disabled!: boolean;
+
+	@Transform(({ value }: { value: string }) => value || undefined)
+	tooltip!: string | undefined;
+}
+
 export type SeparatorDirection = "Horizontal" | "Vertical";
 export type SeparatorType = "Related" | "Unrelated" | "Section";
 
@@ -1327,6 +1715,32 @@ export class TextInput extends WidgetProps {
 	tooltip!: string | undefined;
 }
 
+export class DashPatternLabel extends WidgetProps {
+	lengths!: number[];
+
+	offset!: number;
+
+	disabled!: boolean;
+
+	@Transform(({ value }: { value: string }) => value || undefined)
+	tooltip!: string | undefined;
+}
+
+export class HistogramLabel extends WidgetProps {
+	red!: number[];
+
+	green!: number[];
+
+	blue!: number[];
+
+	luminance!: number[];
+
+	disabled!: boolean;
+
+	@Transform(({ value }: { value: string }) => value || undefined)
+	tooltip!: string | undefined;
+}
+
 export class TextLabel extends WidgetProps {
 	// Body
 	value!: string;
@@ -1348,10 +1762,23 @@ export class TextLabel extends WidgetProps {
 
 	@Transform(({ value }: { value: string }) => value || undefined)
 	tooltip!: string | undefined;
+
+	contextMenu!: MenuListEntry[][];
+
+	scrubsNumberInput!: boolean;
 }
 
 export type PivotPosition = "None" | "TopLeft" | "TopCenter" | "TopRight" | "CenterLeft" | "Center" | "CenterRight" | "BottomLeft" | "BottomCenter" | "BottomRight";
 
+export class ThumbnailButton extends WidgetProps {
+	svg!: string;
+
+	disabled!: boolean;
+
+	@Transform(({ value }: { value: string }) => value || undefined)
+	tooltip!: string | undefined;
+}
+
 export class PivotInput extends WidgetProps {
 	position!: PivotPosition;
 
@@ -1365,8 +1792,11 @@ const widgetSubTypes = [
 	{ value: CheckboxInput, name: "CheckboxInput" },
 	{ value: ColorInput, name: "ColorInput" },
 	{ value: CurveInput, name: "CurveInput" },
+	{ value: DashPatternLabel, name: "DashPatternLabel" },
 	{ value: DropdownInput, name: "DropdownInput" },
+	{ value: FilePathInput, name: "FilePathInput" },
 	{ value: FontInput, name: "FontInput" },
+	{ value: HistogramLabel, name: "HistogramLabel" },
 	{ value: IconButton, name: "IconButton" },
 	{ value: ImageButton, name: "ImageButton" },
 	{ value: IconLabel, name: "IconLabel" },
@@ -1376,12 +1806,14 @@ const widgetSubTypes = [
 	{ value: PivotInput, name: "PivotInput" },
 	{ value: PopoverButton, name: "PopoverButton" },
 	{ value: RadioInput, name: "RadioInput" },
+	{ value: RangeSliderInput, name: "RangeSliderInput" },
 	{ value: Separator, name: "Separator" },
 	{ value: WorkingColorsInput, name: "WorkingColorsInput" },
 	{ value: TextAreaInput, name: "TextAreaInput" },
 	{ value: TextButton, name: "TextButton" },
 	{ value: TextInput, name: "TextInput" },
 	{ value: TextLabel, name: "TextLabel" },
+	{ value: ThumbnailButton, name: "ThumbnailButton" },
 ] as const;
 
 type WidgetSubTypes = (typeof widgetSubTypes)[number];
@@ -1509,7 +1941,7 @@ export function isWidgetTable(layoutTable: LayoutGroup): layoutTable is WidgetTa
 	return Boolean((layoutTable as WidgetTable)?.tableWidgets);
 }
 
-export type WidgetSection = { name: string; description: string; visible: boolean; pinned: boolean; id: bigint; layout: LayoutGroup[] };
+export type WidgetSection = { name: string; description: string; visible: boolean; bypassed: boolean; pinned: boolean; id: bigint; nodeControls: boolean; layout: LayoutGroup[] };
 export function isWidgetSection(layoutRow: LayoutGroup): layoutRow is WidgetSection {
 	return Boolean((layoutRow as WidgetSection)?.layout);
 }
@@ -1553,8 +1985,10 @@ function createLayoutGroup(layoutGroup: any): LayoutGroup {
 			name: layoutGroup.section.name,
 			description: layoutGroup.section.description,
 			visible: layoutGroup.section.visible,
+			bypassed: layoutGroup.section.bypassed,
 			pinned: layoutGroup.section.pinned,
 			id: layoutGroup.section.id,
+			nodeControls: layoutGroup.section.nodeControls,
 			layout: layoutGroup.section.layout.map(createLayoutGroup),
 		};
 		return result;
@@ -1593,12 +2027,36 @@ export class UpdateMenuBarLayout extends JsMessage {
 	layout!: MenuBarEntry[];
 }
 
+export class UpdateAlignPanelLayout extends WidgetDiffUpdate {}
+
+export class UpdateHistogramLayout extends WidgetDiffUpdate {}
+
+export class UpdateNavigatorPanelLayout extends WidgetDiffUpdate {}
+
 export class UpdateNodeGraphControlBarLayout extends WidgetDiffUpdate {}
 
+export class UpdateProfilerPanelLayout extends WidgetDiffUpdate {}
+
 export class UpdatePropertyPanelSectionsLayout extends WidgetDiffUpdate {}
 
 export class UpdateSpreadsheetLayout extends WidgetDiffUpdate {}
 
+export class UpdateCommentsPanelLayout extends WidgetDiffUpdate {}
+
+export class UpdateSwatchesPanelLayout extends WidgetDiffUpdate {}
+
+export class UpdateNodeAssetsPanelLayout extends WidgetDiffUpdate {}
+
+export class UpdateBulkEditPanelLayout extends WidgetDiffUpdate {}
+
+export class UpdateGraphFindPanelLayout extends WidgetDiffUpdate {}
+
+export class UpdateDiagnosticsPanelLayout extends WidgetDiffUpdate {}
+
+export class UpdateStatusBarLayout extends WidgetDiffUpdate {}
+
+export class UpdateTimelinePanelLayout extends WidgetDiffUpdate {}
+
 export class UpdateToolOptionsLayout extends WidgetDiffUpdate {}
 
 export class UpdateToolShelfLayout extends WidgetDiffUpdate {}
@@ -1644,15 +2102,22 @@ export const messageMakers: Record<string, MessageMaker> = {
 	TriggerFetchAndOpenDocument,
 	TriggerFontLoad,
 	TriggerImport,
+	TriggerLocateFontFile,
 	TriggerIndexedDbRemoveDocument,
+	TriggerIndexedDbRemoveRecentFiles,
 	TriggerIndexedDbWriteDocument,
+	TriggerIndexedDbWriteRecentFile,
 	TriggerLoadFirstAutoSaveDocument,
 	TriggerLoadPreferences,
 	TriggerLoadRestAutoSaveDocuments,
+	TriggerLoadSession,
 	TriggerOpenDocument,
+	TriggerOpenRecentFile,
 	TriggerPaste,
+	TriggerRasterizeForPrint,
 	TriggerSaveActiveDocument,
 	TriggerSavePreferences,
+	TriggerSaveSession,
 	TriggerTextCommit,
 	TriggerTextCopy,
 	TriggerUpgradeDocumentToVectorManipulationFormat,
@@ -1660,11 +2125,15 @@ export const messageMakers: Record<string, MessageMaker> = {
 	UpdateActiveDocument,
 	UpdateBox,
 	UpdateClickTargets,
+	UpdateCompareSnapshotArtwork,
+	UpdateCompareSnapshotState,
 	UpdateContextMenuInformation,
+	UpdateCustomNodeTypes,
 	UpdateDialogButtons,
 	UpdateDialogColumn1,
 	UpdateDialogColumn2,
 	UpdateDocumentArtwork,
+	UpdateDocumentArtworkPixelPreview,
 	UpdateDocumentBarLayout,
 	UpdateDocumentLayerDetails,
 	UpdateDocumentLayerStructureJs,
@@ -1675,7 +2144,32 @@ export const messageMakers: Record<string, MessageMaker> = {
 	UpdateEyedropperSamplingState,
 	UpdateGraphFadeArtwork,
 	UpdateGraphViewOverlay,
+	UpdateAlignPanelLayout,
+	UpdateAlignPanelState,
+	UpdateHistogramLayout,
+	UpdateHistogramState,
+	UpdateNavigatorPanelLayout,
+	UpdateNavigatorPanelOverview,
+	UpdateNavigatorPanelState,
 	UpdateSpreadsheetState,
+	UpdateTimelinePanelLayout,
+	UpdateTimelinePanelState,
+	UpdateCommentsPanelLayout,
+	UpdateCommentsPanelState,
+	UpdateSwatchesPanelLayout,
+	UpdateSwatchesPanelState,
+	UpdateNodeAssetsPanelLayout,
+	UpdateNodeAssetsPanelState,
+	UpdateBulkEditPanelLayout,
+	UpdateBulkEditPanelState,
+	UpdateGraphFindPanelLayout,
+	UpdateGraphFindPanelState,
+	UpdateDiagnosticsPanelLayout,
+	UpdateDiagnosticsPanelState,
+	UpdateProfilerPanelLayout,
+	UpdateProfilerPanelState,
+	UpdateColorPickerPrintProfile,
+	UpdateColorPickerSwatches,
 	UpdateImportReorderIndex,
 	UpdateImportsExports,
 	UpdateInputHints,
@@ -1686,15 +2180,20 @@ export const messageMakers: Record<string, MessageMaker> = {
 	UpdateMouseCursor,
 	UpdateNodeGraph,
 	UpdateNodeGraphControlBarLayout,
+	UpdateNodeGraphProfile,
 	UpdateNodeGraphSelection,
 	UpdateNodeGraphTransform,
 	UpdateNodeThumbnail,
 	UpdateOpenDocumentsList,
+	UpdatePresentationState,
 	UpdatePropertyPanelSectionsLayout,
 	UpdateSpreadsheetLayout,
+	UpdateStatusBarLayout,
 	UpdateToolOptionsLayout,
 	UpdateToolShelfLayout,
 	UpdateWirePathInProgress,
+	UpdateWireProbe,
+	UpdatePinnedWireProbes,
 	UpdateWorkingColorsLayout,
 } as const;
 export type JsMessageType = keyof typeof messageMakers;
@@ -26,6 +26,18 @@ export function createDialogState(editor: Editor) {
 		});
 	}
 
+	// Closes the dialog when the user clicks outside of it or presses Escape, which the editor is told about so it can clean up after the dialog
+	function cancelDialog() {
+		let crashed = false;
+		update((state) => {
+			crashed = state.panicDetails !== "";
+			return state;
+		});
+
+		dismissDialog();
+		if (!crashed) editor.handle.dialogDismissed();
+	}
+
 	// Creates a crash dialog from JS once the editor has panicked.
 	// Normal dialogs are created in the Rust backend, but for the crash dialog, the editor has panicked so it cannot respond to widget callbacks.
 	function createCrashDialog(panicDetails: string) {
@@ -81,6 +93,7 @@ export function createDialogState(editor: Editor) {
 	return {
 		subscribe,
 		dismissDialog,
+		cancelDialog,
 		createCrashDialog,
 	};
 }
@@ -17,6 +17,15 @@ import {
 	UpdateGraphFadeArtwork,
 } from "@graphite/messages";
 
+// The largest radius, in viewport pixels around the cursor, that can be averaged when sampling a color from the viewport
+export const MAX_COLOR_SAMPLING_RADIUS = 10;
+
+// A color input's request to sample the color of the next viewport click, answered with its sRGB channels from 0 to 1 or undefined if canceled
+export type ColorSamplingRequest = {
+	currentColor: string;
+	resolve: (rgb: [number, number, number] | undefined) => void;
+};
+
 // eslint-disable-next-line @typescript-eslint/explicit-function-return-type
 export function createDocumentState(editor: Editor) {
 	const state = writable({
@@ -30,6 +39,9 @@ export function createDocumentState(editor: Editor) {
 		// Graph view overlay
 		graphViewOverlayOpen: false,
 		fadeArtwork: 100,
+		// Viewport color sampling for the eyedropper of color inputs
+		colorSamplingRequest: undefined as ColorSamplingRequest | undefined,
+		colorSamplingRadius: 0,
 	});
 	const { subscribe, update } = state;
 
@@ -101,8 +113,35 @@ export function createDocumentState(editor: Editor) {
 		});
 	});
 
+	// Enter the viewport color sampling mode, replacing (and canceling) any sampling already in progress
+	function sampleViewportColor(currentColor: string): Promise<[number, number, number] | undefined> {
+		return new Promise((resolve) => {
+			update((state) => {
+				state.colorSamplingRequest?.resolve(undefined);
+				state.colorSamplingRequest = { currentColor, resolve };
+				return state;
+			});
+		});
+	}
+	function finishColorSampling(rgb: [number, number, number] | undefined) {
+		update((state) => {
+			state.colorSamplingRequest?.resolve(rgb);
+			state.colorSamplingRequest = undefined;
+			return state;
+		});
+	}
+	function setColorSamplingRadius(radius: number) {
+		update((state) => {
+			state.colorSamplingRadius = Math.max(0, Math.min(MAX_COLOR_SAMPLING_RADIUS, Math.round(radius)));
+			return state;
+		});
+	}
+
 	return {
 		subscribe,
+		sampleViewportColor,
+		finishColorSampling,
+		setColorSamplingRadius,
 	};
 }
 export type DocumentState = ReturnType<typeof createDocumentState>;
@@ -1,7 +1,8 @@
 import { writable } from "svelte/store";
 
 import { type Editor } from "@graphite/editor";
-import { TriggerFontLoad } from "@graphite/messages";
+import { TriggerFontLoad, TriggerLocateFontFile } from "@graphite/messages";
+import { upload } from "@graphite/utility-functions/files";
 
 // eslint-disable-next-line @typescript-eslint/explicit-function-return-type
 export function createFontsState(editor: Editor) {
@@ -86,9 +87,15 @@ export function createFontsState(editor: Editor) {
 			const response = await (await fetch(url)).arrayBuffer();
 			editor.handle.onFontLoad(triggerFontLoad.font.fontFamily, triggerFontLoad.font.fontStyle, url, new Uint8Array(response));
 		} else {
-			editor.handle.errorDialog("Failed to load font", `The font ${triggerFontLoad.font.fontFamily} with style ${triggerFontLoad.font.fontStyle} does not exist`);
+			editor.handle.onFontUnavailable(triggerFontLoad.font.fontFamily, triggerFontLoad.font.fontStyle);
 		}
 	});
+	editor.subscriptions.subscribeJsMessage(TriggerLocateFontFile, async (triggerLocateFontFile) => {
+		const file = await upload(".ttf,.otf,.woff,.woff2", "data");
+		// The located file is loaded in place of the missing font, with a local URL used to preview it while editing text
+		const url = URL.createObjectURL(new Blob([file.content], { type: file.type }));
+		editor.handle.onFontLoad(triggerLocateFontFile.font.fontFamily, triggerLocateFontFile.font.fontStyle, url, file.content);
+	});
 
 	return {
 		subscribe,
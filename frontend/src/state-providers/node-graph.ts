@@ -1,29 +1,38 @@
 import { writable } from "svelte/store";
 
 import { type Editor } from "@graphite/editor";
-import type { FrontendGraphOutput, FrontendGraphInput } from "@graphite/messages";
+import type { FrontendGraphOutput, FrontendGraphInput, GraphWireStyle } from "@graphite/messages";
 import {
 	type Box,
 	type FrontendClickTargets,
+	type FrontendGraphFrame,
+	type FrontendGraphNote,
 	type ContextMenuInformation,
 	type FrontendNode,
 	type FrontendNodeWire as FrontendNodeWire,
 	type FrontendNodeType,
+	type FrontendNodeProfile,
+	type FrontendWireProbe,
+	type FrontendPinnedWireProbe,
 	type WirePath,
 	SendUIMetadata,
 	UpdateBox,
 	UpdateClickTargets,
 	UpdateContextMenuInformation,
+	UpdateCustomNodeTypes,
 	UpdateInSelectedNetwork,
 	UpdateImportReorderIndex,
 	UpdateExportReorderIndex,
 	UpdateImportsExports,
 	UpdateLayerWidths,
 	UpdateNodeGraph,
+	UpdateNodeGraphProfile,
 	UpdateNodeGraphSelection,
 	UpdateNodeGraphTransform,
 	UpdateNodeThumbnail,
 	UpdateWirePathInProgress,
+	UpdateWireProbe,
+	UpdatePinnedWireProbes,
 } from "@graphite/messages";
 
 // eslint-disable-next-line @typescript-eslint/explicit-function-return-type
@@ -41,11 +50,17 @@ export function createNodeGraphState(editor: Editor) {
 		addExport: undefined as { x: number; y: number } | undefined,
 		nodes: new Map<bigint, FrontendNode>(),
 		wires: [] as FrontendNodeWire[],
-		wiresDirectNotGridAligned: false,
+		frames: [] as FrontendGraphFrame[],
+		notes: [] as FrontendGraphNote[],
+		wireStyle: "Direct" as GraphWireStyle,
 		wirePathInProgress: undefined as WirePath | undefined,
+		wireProbe: undefined as FrontendWireProbe | undefined,
+		pinnedWireProbes: [] as FrontendPinnedWireProbe[],
 		nodeDescriptions: new Map<string, string>(),
 		nodeTypes: [] as FrontendNodeType[],
+		customNodeTypes: [] as FrontendNodeType[],
 		thumbnails: new Map<bigint, string>(),
+		profile: new Map<bigint, FrontendNodeProfile>(),
 		selected: [] as bigint[],
 		transform: { scale: 1, x: 0, y: 0 },
 		inSelectedNetwork: true,
@@ -61,6 +76,12 @@ export function createNodeGraphState(editor: Editor) {
 			return state;
 		});
 	});
+	editor.subscriptions.subscribeJsMessage(UpdateCustomNodeTypes, (updateCustomNodeTypes) => {
+		update((state) => {
+			state.customNodeTypes = updateCustomNodeTypes.nodeTypes;
+			return state;
+		});
+	});
 	editor.subscriptions.subscribeJsMessage(UpdateBox, (updateBox) => {
 		update((state) => {
 			state.box = updateBox.box;
@@ -122,7 +143,9 @@ export function createNodeGraphState(editor: Editor) {
 				state.nodes.set(node.id, node);
 			});
 			state.wires = updateNodeGraph.wires;
-			state.wiresDirectNotGridAligned = updateNodeGraph.wiresDirectNotGridAligned;
+			state.frames = updateNodeGraph.frames;
+			state.notes = updateNodeGraph.notes;
+			state.wireStyle = updateNodeGraph.wireStyle;
 			return state;
 		});
 	});
@@ -144,12 +167,30 @@ export function createNodeGraphState(editor: Editor) {
 			return state;
 		});
 	});
+	editor.subscriptions.subscribeJsMessage(UpdateNodeGraphProfile, (updateNodeGraphProfile) => {
+		update((state) => {
+			state.profile = new Map((updateNodeGraphProfile.profile || []).map((nodeProfile) => [nodeProfile.nodeId, nodeProfile]));
+			return state;
+		});
+	});
 	editor.subscriptions.subscribeJsMessage(UpdateWirePathInProgress, (updateWirePathInProgress) => {
 		update((state) => {
 			state.wirePathInProgress = updateWirePathInProgress.wirePath;
 			return state;
 		});
 	});
+	editor.subscriptions.subscribeJsMessage(UpdateWireProbe, (updateWireProbe) => {
+		update((state) => {
+			state.wireProbe = updateWireProbe.wireProbe;
+			return state;
+		});
+	});
+	editor.subscriptions.subscribeJsMessage(UpdatePinnedWireProbes, (updatePinnedWireProbes) => {
+		update((state) => {
+			state.pinnedWireProbes = updatePinnedWireProbes.pinnedWireProbes;
+			return state;
+		});
+	});
 
 	return {
 		subscribe,
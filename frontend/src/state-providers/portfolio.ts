@@ -10,6 +10,7 @@ import {
 	TriggerDownloadTextFile,
 	TriggerImport,
 	TriggerOpenDocument,
+	TriggerRasterizeForPrint,
 	TriggerUpgradeDocumentToVectorManipulationFormat,
 	UpdateActiveDocument,
 	UpdateOpenDocumentsList,
@@ -17,9 +18,37 @@ import {
 	defaultWidgetLayout,
 	patchWidgetLayout,
 	UpdateSpreadsheetLayout,
+	UpdateHistogramState,
+	UpdateHistogramLayout,
+	UpdateAlignPanelState,
+	UpdateAlignPanelLayout,
+	UpdateNavigatorPanelState,
+	UpdateNavigatorPanelLayout,
+	UpdateNavigatorPanelOverview,
+	UpdateTimelinePanelState,
+	UpdateTimelinePanelLayout,
+	UpdateCommentsPanelState,
+	UpdateCommentsPanelLayout,
+	UpdateSwatchesPanelState,
+	UpdateSwatchesPanelLayout,
+	UpdateNodeAssetsPanelState,
+	UpdateNodeAssetsPanelLayout,
+	UpdateBulkEditPanelState,
+	UpdateBulkEditPanelLayout,
+	UpdateGraphFindPanelState,
+	UpdateGraphFindPanelLayout,
+	UpdateDiagnosticsPanelState,
+	UpdateDiagnosticsPanelLayout,
+	UpdateProfilerPanelState,
+	UpdateProfilerPanelLayout,
+	UpdateColorPickerPrintProfile,
+	UpdateColorPickerSwatches,
+	type ColorSwatch,
+	type PrintProfile,
+	type Color,
 } from "@graphite/messages";
 import { downloadFileText, downloadFileBlob, upload } from "@graphite/utility-functions/files";
-import { extractPixelData, rasterizeSVG } from "@graphite/utility-functions/rasterization";
+import { extractPixelData, rasterizeSVG, rasterizeSVGCanvas } from "@graphite/utility-functions/rasterization";
 
 // eslint-disable-next-line @typescript-eslint/explicit-function-return-type
 export function createPortfolioState(editor: Editor) {
@@ -30,6 +59,32 @@ export function createPortfolioState(editor: Editor) {
 		spreadsheetOpen: false,
 		spreadsheetNode: BigInt(0) as bigint | undefined,
 		spreadsheetWidgets: defaultWidgetLayout(),
+		histogramOpen: false,
+		histogramWidgets: defaultWidgetLayout(),
+		alignPanelOpen: false,
+		alignPanelWidgets: defaultWidgetLayout(),
+		navigatorPanelOpen: false,
+		navigatorPanelWidgets: defaultWidgetLayout(),
+		navigatorPanelOverview: undefined as UpdateNavigatorPanelOverview | undefined,
+		timelinePanelOpen: false,
+		timelinePanelWidgets: defaultWidgetLayout(),
+		commentsPanelOpen: false,
+		commentsPanelWidgets: defaultWidgetLayout(),
+		swatchesPanelOpen: false,
+		swatchesPanelWidgets: defaultWidgetLayout(),
+		nodeAssetsPanelOpen: false,
+		nodeAssetsPanelWidgets: defaultWidgetLayout(),
+		bulkEditPanelOpen: false,
+		bulkEditPanelWidgets: defaultWidgetLayout(),
+		graphFindPanelOpen: false,
+		graphFindPanelWidgets: defaultWidgetLayout(),
+		diagnosticsPanelOpen: false,
+		diagnosticsPanelWidgets: defaultWidgetLayout(),
+		profilerPanelOpen: false,
+		profilerPanelWidgets: defaultWidgetLayout(),
+		colorPickerSwatches: [] as ColorSwatch[],
+		recentColors: [] as Color[],
+		printProfile: undefined as PrintProfile | undefined,
 	});
 
 	// Set up message subscriptions on creation
@@ -68,7 +123,7 @@ export function createPortfolioState(editor: Editor) {
 		editor.handle.openDocumentFile(data.filename, data.content);
 	});
 	editor.subscriptions.subscribeJsMessage(TriggerImport, async () => {
-		const data = await upload("image/*", "both");
+		const data = await upload("image/*,.geojson,.graphite-node", "both");
 
 		if (data.type.includes("svg")) {
 			const svg = new TextDecoder().decode(data.content.data);
@@ -76,6 +131,16 @@ export function createPortfolioState(editor: Editor) {
 			return;
 		}
 
+		if (data.filename.endsWith(".geojson")) {
+			editor.handle.pasteGeoJson(data.filename, data.content.text);
+			return;
+		}
+
+		if (data.filename.endsWith(".graphite-node")) {
+			editor.handle.importCustomNode(data.content.text);
+			return;
+		}
+
 		// In case the user accidentally uploads a Graphite file, open it instead of failing to import it
 		if (data.filename.endsWith(".graphite")) {
 			editor.handle.openDocumentFile(data.filename, data.content.text);
@@ -104,6 +169,21 @@ export function createPortfolioState(editor: Editor) {
 			// Fail silently if there's an error rasterizing the SVG, such as a zero-sized image
 		}
 	});
+	editor.subscriptions.subscribeJsMessage(TriggerRasterizeForPrint, async (triggerRasterizeForPrint) => {
+		const { svg, name, size, printJob } = triggerRasterizeForPrint;
+
+		// Rasterize the SVG onto white paper, then hand the pixels back to be laid out as a PDF page
+		try {
+			const canvas = await rasterizeSVGCanvas(svg, size.x, size.y, "white");
+			const context = canvas.getContext("2d");
+			if (!context) return;
+
+			const imageData = context.getImageData(0, 0, canvas.width, canvas.height);
+			editor.handle.exportPrint(name, new Uint8Array(imageData.data.buffer), imageData.width, imageData.height, printJob);
+		} catch {
+			// Fail silently if there's an error rasterizing the SVG, such as a zero-sized image
+		}
+	});
 	editor.subscriptions.subscribeJsMessage(TriggerUpgradeDocumentToVectorManipulationFormat, async (triggerUpgradeDocumentToVectorManipulationFormat) => {
 		// TODO: Eventually remove this document upgrade code
 		const { documentId, documentName, documentIsAutoSaved, documentIsSaved, documentSerializedContent } = triggerUpgradeDocumentToVectorManipulationFormat;
@@ -125,6 +205,182 @@ export function createPortfolioState(editor: Editor) {
 		});
 	});
 
+	editor.subscriptions.subscribeJsMessage(UpdateHistogramState, async (updateHistogramState) => {
+		update((state) => {
+			state.histogramOpen = updateHistogramState.open;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateHistogramLayout, (updateHistogramLayout) => {
+		update((state) => {
+			patchWidgetLayout(state.histogramWidgets, updateHistogramLayout);
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateAlignPanelState, async (updateAlignPanelState) => {
+		update((state) => {
+			state.alignPanelOpen = updateAlignPanelState.open;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateAlignPanelLayout, (updateAlignPanelLayout) => {
+		update((state) => {
+			patchWidgetLayout(state.alignPanelWidgets, updateAlignPanelLayout);
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateNavigatorPanelState, async (updateNavigatorPanelState) => {
+		update((state) => {
+			state.navigatorPanelOpen = updateNavigatorPanelState.open;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateNavigatorPanelLayout, (updateNavigatorPanelLayout) => {
+		update((state) => {
+			patchWidgetLayout(state.navigatorPanelWidgets, updateNavigatorPanelLayout);
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateNavigatorPanelOverview, (updateNavigatorPanelOverview) => {
+		update((state) => {
+			state.navigatorPanelOverview = updateNavigatorPanelOverview;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateTimelinePanelState, async (updateTimelinePanelState) => {
+		update((state) => {
+			state.timelinePanelOpen = updateTimelinePanelState.open;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateTimelinePanelLayout, (updateTimelinePanelLayout) => {
+		update((state) => {
+			patchWidgetLayout(state.timelinePanelWidgets, updateTimelinePanelLayout);
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateCommentsPanelState, async (updateCommentsPanelState) => {
+		update((state) => {
+			state.commentsPanelOpen = updateCommentsPanelState.open;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateCommentsPanelLayout, (updateCommentsPanelLayout) => {
+		update((state) => {
+			patchWidgetLayout(state.commentsPanelWidgets, updateCommentsPanelLayout);
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateSwatchesPanelState, async (updateSwatchesPanelState) => {
+		update((state) => {
+			state.swatchesPanelOpen = updateSwatchesPanelState.open;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateSwatchesPanelLayout, (updateSwatchesPanelLayout) => {
+		update((state) => {
+			patchWidgetLayout(state.swatchesPanelWidgets, updateSwatchesPanelLayout);
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateNodeAssetsPanelState, async (updateNodeAssetsPanelState) => {
+		update((state) => {
+			state.nodeAssetsPanelOpen = updateNodeAssetsPanelState.open;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateNodeAssetsPanelLayout, (updateNodeAssetsPanelLayout) => {
+		update((state) => {
+			patchWidgetLayout(state.nodeAssetsPanelWidgets, updateNodeAssetsPanelLayout);
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateBulkEditPanelState, async (updateBulkEditPanelState) => {
+		update((state) => {
+			state.bulkEditPanelOpen = updateBulkEditPanelState.open;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateBulkEditPanelLayout, (updateBulkEditPanelLayout) => {
+		update((state) => {
+			patchWidgetLayout(state.bulkEditPanelWidgets, updateBulkEditPanelLayout);
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateGraphFindPanelState, async (updateGraphFindPanelState) => {
+		update((state) => {
+			state.graphFindPanelOpen = updateGraphFindPanelState.open;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateGraphFindPanelLayout, (updateGraphFindPanelLayout) => {
+		update((state) => {
+			patchWidgetLayout(state.graphFindPanelWidgets, updateGraphFindPanelLayout);
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateDiagnosticsPanelState, async (updateDiagnosticsPanelState) => {
+		update((state) => {
+			state.diagnosticsPanelOpen = updateDiagnosticsPanelState.open;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateDiagnosticsPanelLayout, (updateDiagnosticsPanelLayout) => {
+		update((state) => {
+			patchWidgetLayout(state.diagnosticsPanelWidgets, updateDiagnosticsPanelLayout);
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateProfilerPanelState, async (updateProfilerPanelState) => {
+		update((state) => {
+			state.profilerPanelOpen = updateProfilerPanelState.open;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateProfilerPanelLayout, (updateProfilerPanelLayout) => {
+		update((state) => {
+			patchWidgetLayout(state.profilerPanelWidgets, updateProfilerPanelLayout);
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateColorPickerSwatches, (updateColorPickerSwatches) => {
+		update((state) => {
+			state.colorPickerSwatches = updateColorPickerSwatches.swatches;
+			state.recentColors = updateColorPickerSwatches.recentColors;
+			return state;
+		});
+	});
+
+	editor.subscriptions.subscribeJsMessage(UpdateColorPickerPrintProfile, (updateColorPickerPrintProfile) => {
+		update((state) => {
+			state.printProfile = updateColorPickerPrintProfile.printProfile;
+			return state;
+		});
+	});
+
 	return {
 		subscribe,
 	};
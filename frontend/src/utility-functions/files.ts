@@ -16,7 +16,9 @@ export function downloadFileBlob(filename: string, blob: Blob) {
 }
 
 export function downloadFileText(filename: string, text: string) {
-	const type = filename.endsWith(".svg") ? "image/svg+xml;charset=utf-8" : "text/plain;charset=utf-8";
+	let type = "text/plain;charset=utf-8";
+	if (filename.endsWith(".svg")) type = "image/svg+xml;charset=utf-8";
+	if (filename.endsWith(".pdf")) type = "application/pdf";
 
 	const blob = new Blob([text], { type });
 	downloadFileBlob(filename, blob);
@@ -59,3 +61,24 @@ export async function upload<T extends "text" | "data" | "both">(acceptedExtensi
 }
 export type UploadResult<T> = { filename: string; type: string; content: UploadResultType<T> };
 type UploadResultType<T> = T extends "text" ? string : T extends "data" ? Uint8Array : T extends "both" ? { text: string; data: Uint8Array } : never;
+
+// Encodes a file as a data URL which also records the file's name, in the `name` parameter that precedes the base64 payload
+export async function fileToDataURL(filename: string, type: string, data: Uint8Array): Promise<string> {
+	const dataURL = await new Promise<string>((resolve, reject) => {
+		const reader = new FileReader();
+		reader.addEventListener("load", () => resolve(String(reader.result)), { once: true });
+		reader.addEventListener("error", () => reject(reader.error), { once: true });
+		reader.readAsDataURL(new Blob([data], { type: type || "application/octet-stream" }));
+	});
+
+	return dataURL.replace(";base64,", `;name=${encodeURIComponent(filename)};base64,`);
+}
+
+// The file name recorded by `fileToDataURL()`, or undefined if the path isn't a data URL
+export function dataURLFilename(path: string): string | undefined {
+	if (!path.startsWith("data:")) return undefined;
+
+	const metadata = path.slice("data:".length, path.indexOf(","));
+	const name = metadata.split(";").find((parameter) => parameter.startsWith("name="));
+	return name ? decodeURIComponent(name.slice("name=".length)) : "";
+}
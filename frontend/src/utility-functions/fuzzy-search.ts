@@ -0,0 +1,35 @@
+// Scores how well the query matches the text as a case-insensitive subsequence, or returns `undefined` if some query character can't be found in order.
+// Matches are rewarded for runs of consecutive characters and for landing at the start of the text or of a word within it, so "gb" ranks "Gaussian Blur" above "Merge Boolean".
+export function fuzzyScore(query: string, text: string): number | undefined {
+	const needle = query.toLowerCase();
+	const haystack = text.toLowerCase();
+	if (needle.length === 0) return 0;
+
+	let score = 0;
+	let textIndex = 0;
+	let previousMatchIndex = -2;
+
+	for (const character of needle) {
+		const matchIndex = haystack.indexOf(character, textIndex);
+		if (matchIndex === -1) return undefined;
+
+		score += 1;
+		if (matchIndex === previousMatchIndex + 1) score += 4;
+		if (matchIndex === 0) score += 6;
+		else if (isWordStart(text, matchIndex)) score += 3;
+
+		previousMatchIndex = matchIndex;
+		textIndex = matchIndex + 1;
+	}
+
+	// Prefer shorter texts, where the query covers more of the whole
+	return score - (haystack.length - needle.length) * 0.01;
+}
+
+function isWordStart(text: string, index: number): boolean {
+	const previous = text[index - 1];
+	const current = text[index];
+	if (!/[a-z0-9]/i.test(previous)) return true;
+	// Boundaries within identifiers like "VectorData"
+	return previous === previous.toLowerCase() && current !== current.toLowerCase();
+}
@@ -110,11 +110,14 @@ import CheckboxUnchecked from "@graphite-frontend/assets/icon-16px-solid/checkbo
 import CloseAll from "@graphite-frontend/assets/icon-16px-solid/close-all.svg";
 import Close from "@graphite-frontend/assets/icon-16px-solid/close.svg";
 import Code from "@graphite-frontend/assets/icon-16px-solid/code.svg";
+import CompareSnapshot from "@graphite-frontend/assets/icon-16px-solid/compare-snapshot.svg";
 import Copy from "@graphite-frontend/assets/icon-16px-solid/copy.svg";
 import Credits from "@graphite-frontend/assets/icon-16px-solid/credits.svg";
 import CustomColor from "@graphite-frontend/assets/icon-16px-solid/custom-color.svg";
 import Cut from "@graphite-frontend/assets/icon-16px-solid/cut.svg";
 import DeselectAll from "@graphite-frontend/assets/icon-16px-solid/deselect-all.svg";
+import DistributeHorizontalGap from "@graphite-frontend/assets/icon-16px-solid/distribute-horizontal-gap.svg";
+import DistributeVerticalGap from "@graphite-frontend/assets/icon-16px-solid/distribute-vertical-gap.svg";
 import Edit from "@graphite-frontend/assets/icon-16px-solid/edit.svg";
 import Empty from "@graphite-frontend/assets/icon-16px-solid/empty.svg";
 import EyeHidden from "@graphite-frontend/assets/icon-16px-solid/eye-hidden.svg";
@@ -141,6 +144,9 @@ import HistoryRedo from "@graphite-frontend/assets/icon-16px-solid/history-redo.
 import HistoryUndo from "@graphite-frontend/assets/icon-16px-solid/history-undo.svg";
 import IconsGrid from "@graphite-frontend/assets/icon-16px-solid/icons-grid.svg";
 import Image from "@graphite-frontend/assets/icon-16px-solid/image.svg";
+import KeyframeAnimated from "@graphite-frontend/assets/icon-16px-solid/keyframe-animated.svg";
+import KeyframeSet from "@graphite-frontend/assets/icon-16px-solid/keyframe-set.svg";
+import KeyframeUnset from "@graphite-frontend/assets/icon-16px-solid/keyframe-unset.svg";
 import Layer from "@graphite-frontend/assets/icon-16px-solid/layer.svg";
 import License from "@graphite-frontend/assets/icon-16px-solid/license.svg";
 import NewLayer from "@graphite-frontend/assets/icon-16px-solid/new-layer.svg";
@@ -230,11 +236,14 @@ const SOLID_16PX = {
 	Close: { svg: Close, size: 16 },
 	CloseAll: { svg: CloseAll, size: 16 },
 	Code: { svg: Code, size: 16 },
+	CompareSnapshot: { svg: CompareSnapshot, size: 16 },
 	Copy: { svg: Copy, size: 16 },
 	Credits: { svg: Credits, size: 16 },
 	CustomColor: { svg: CustomColor, size: 16 },
 	Cut: { svg: Cut, size: 16 },
 	DeselectAll: { svg: DeselectAll, size: 16 },
+	DistributeHorizontalGap: { svg: DistributeHorizontalGap, size: 16 },
+	DistributeVerticalGap: { svg: DistributeVerticalGap, size: 16 },
 	Edit: { svg: Edit, size: 16 },
 	Empty: { svg: Empty, size: 16 },
 	Eyedropper: { svg: Eyedropper, size: 16 },
@@ -261,6 +270,9 @@ const SOLID_16PX = {
 	HistoryUndo: { svg: HistoryUndo, size: 16 },
 	IconsGrid: { svg: IconsGrid, size: 16 },
 	Image: { svg: Image, size: 16 },
+	KeyframeAnimated: { svg: KeyframeAnimated, size: 16 },
+	KeyframeSet: { svg: KeyframeSet, size: 16 },
+	KeyframeUnset: { svg: KeyframeUnset, size: 16 },
 	Layer: { svg: Layer, size: 16 },
 	License: { svg: License, size: 16 },
 	NewLayer: { svg: NewLayer, size: 16 },
@@ -362,6 +374,7 @@ const TWO_TONE_16PX = {
 
 // 24px Two-Tone
 import GeneralArtboardTool from "@graphite-frontend/assets/icon-24px-two-tone/general-artboard-tool.svg";
+import GeneralCommentTool from "@graphite-frontend/assets/icon-24px-two-tone/general-comment-tool.svg";
 import GeneralEyedropperTool from "@graphite-frontend/assets/icon-24px-two-tone/general-eyedropper-tool.svg";
 import GeneralFillTool from "@graphite-frontend/assets/icon-24px-two-tone/general-fill-tool.svg";
 import GeneralGradientTool from "@graphite-frontend/assets/icon-24px-two-tone/general-gradient-tool.svg";
@@ -386,6 +399,7 @@ import VectorTextTool from "@graphite-frontend/assets/icon-24px-two-tone/vector-
 
 const TWO_TONE_24PX = {
 	GeneralArtboardTool: { svg: GeneralArtboardTool, size: 24 },
+	GeneralCommentTool: { svg: GeneralCommentTool, size: 24 },
 	GeneralEyedropperTool: { svg: GeneralEyedropperTool, size: 24 },
 	GeneralFillTool: { svg: GeneralFillTool, size: 24 },
 	GeneralGradientTool: { svg: GeneralGradientTool, size: 24 },
@@ -0,0 +1,72 @@
+// Renders the small subset of Markdown used by node graph sticky notes: headings, bulleted and numbered lists, paragraphs, and inline code, bold, italic, and links.
+// All text is HTML-escaped before formatting is applied, so the result is safe to insert with `{@html}`.
+
+function escapeHtml(text: string): string {
+	return text.replace(/&/g, "&amp;").replace(/</g, "&lt;").replace(/>/g, "&gt;").replace(/"/g, "&quot;").replace(/'/g, "&#39;");
+}
+
+function renderInline(text: string): string {
+	// Set aside code spans so their contents aren't formatted
+	const codeSpans: string[] = [];
+	let html = escapeHtml(text).replace(/`([^`]+)`/g, (_, code: string) => {
+		codeSpans.push(`<code>${code}</code>`);
+		return `\u0000${codeSpans.length - 1}\u0000`;
+	});
+
+	html = html
+		.replace(/\[([^\]]+)\]\((https?:\/\/[^\s)]+)\)/g, `<a href="$2" target="_blank" rel="noopener noreferrer">$1</a>`)
+		.replace(/\*\*(.+?)\*\*/g, "<strong>$1</strong>")
+		.replace(/__(.+?)__/g, "<strong>$1</strong>")
+		.replace(/\*(.+?)\*/g, "<em>$1</em>")
+		.replace(/\b_(.+?)_\b/g, "<em>$1</em>");
+
+	// eslint-disable-next-line no-control-regex
+	return html.replace(/\u0000(\d+)\u0000/g, (_, index: string) => codeSpans[Number(index)]);
+}
+
+export function renderMarkdown(source: string): string {
+	const blocks: string[] = [];
+	let paragraph: string[] = [];
+	let list: { ordered: boolean; items: string[] } | undefined = undefined;
+
+	const flushParagraph = () => {
+		if (paragraph.length > 0) blocks.push(`<p>${paragraph.map(renderInline).join("<br />")}</p>`);
+		paragraph = [];
+	};
+	const flushList = () => {
+		if (list) {
+			const tag = list.ordered ? "ol" : "ul";
+			blocks.push(`<${tag}>${list.items.map((item) => `<li>${renderInline(item)}</li>`).join("")}</${tag}>`);
+		}
+		list = undefined;
+	};
+
+	source.split(/\r?\n/).forEach((line) => {
+		const heading = line.match(/^(#{1,3})\s+(.*)$/);
+		const bullet = line.match(/^\s*[-*+]\s+(.*)$/);
+		const numbered = line.match(/^\s*\d+[.)]\s+(.*)$/);
+
+		if (heading) {
+			flushParagraph();
+			flushList();
+			const level = heading[1].length;
+			blocks.push(`<h${level}>${renderInline(heading[2])}</h${level}>`);
+		} else if (bullet || numbered) {
+			flushParagraph();
+			const ordered = Boolean(numbered);
+			if (list && list.ordered !== ordered) flushList();
+			if (!list) list = { ordered, items: [] };
+			list.items.push((bullet || numbered)?.[1] || "");
+		} else if (line.trim() === "") {
+			flushParagraph();
+			flushList();
+		} else {
+			flushList();
+			paragraph.push(line.trim());
+		}
+	});
+	flushParagraph();
+	flushList();
+
+	return blocks.join("");
+}
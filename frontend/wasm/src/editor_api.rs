@@ -11,12 +11,16 @@ use editor::consts::FILE_SAVE_SUFFIX;
 use editor::messages::input_mapper::utility_types::input_keyboard::ModifierKeys;
 use editor::messages::input_mapper::utility_types::input_mouse::{EditorMouseState, ScrollDelta, ViewportBounds};
 use editor::messages::portfolio::document::utility_types::document_metadata::LayerNodeIdentifier;
+use editor::messages::portfolio::document::utility_types::misc::{self, LengthUnit};
 use editor::messages::portfolio::document::utility_types::network_interface::{ImportOrExport, NodeTemplate};
+use editor::messages::portfolio::document::utility_types::swatches::Swatch;
 use editor::messages::portfolio::utility_types::Platform;
 use editor::messages::prelude::*;
 use editor::messages::tool::tool_messages::tool_prelude::WidgetId;
 use graph_craft::document::NodeId;
+use graphene_core::raster::cmyk::PrintProfile;
 use graphene_core::raster::color::Color;
+use graphene_core::text::Font;
 use serde::Serialize;
 use serde_wasm_bindgen::{self, from_value};
 use std::cell::RefCell;
@@ -244,6 +248,19 @@ impl EditorHandle {
 		self.dispatch(message);
 	}
 
+	#[wasm_bindgen(js_name = loadSession)]
+	pub fn load_session(&self, session: String) {
+		let message = PortfolioMessage::LoadSession { session };
+
+		self.dispatch(message);
+	}
+
+	#[wasm_bindgen(js_name = saveSession)]
+	pub fn save_session(&self) {
+		let message = PortfolioMessage::SaveSession;
+		self.dispatch(message);
+	}
+
 	#[wasm_bindgen(js_name = selectDocument)]
 	pub fn select_document(&self, document_id: u64) {
 		let document_id = DocumentId(document_id);
@@ -263,6 +280,13 @@ impl EditorHandle {
 		self.dispatch(message);
 	}
 
+	/// Lets the editor know the dialog was closed by the user without using one of its buttons
+	#[wasm_bindgen(js_name = dialogDismissed)]
+	pub fn dialog_dismissed(&self) {
+		let message = DialogMessage::Dismissed;
+		self.dispatch(message);
+	}
+
 	#[wasm_bindgen(js_name = demoArtworkDialog)]
 	pub fn demo_artwork_dialog(&self) {
 		let message = DialogMessage::RequestDemoArtworkDialog;
@@ -350,6 +374,13 @@ impl EditorHandle {
 		self.dispatch(message);
 	}
 
+	/// Stylus pressure from 0 to 1 of the following pointer event, or `undefined` for pointing devices without pressure sensitivity
+	#[wasm_bindgen(js_name = onPointerPressure)]
+	pub fn on_pointer_pressure(&self, pressure: Option<f64>) {
+		let message = InputPreprocessorMessage::PointerPressure { pressure };
+		self.dispatch(message);
+	}
+
 	/// Mouse scrolling within the screenspace bounds of the viewport
 	#[wasm_bindgen(js_name = onWheelScroll)]
 	pub fn on_wheel_scroll(&self, x: f64, y: f64, mouse_keys: u8, wheel_delta_x: f64, wheel_delta_y: f64, wheel_delta_z: f64, modifiers: u8) {
@@ -442,6 +473,16 @@ impl EditorHandle {
 		Ok(())
 	}
 
+	/// A font used by a document couldn't be found
+	#[wasm_bindgen(js_name = onFontUnavailable)]
+	pub fn on_font_unavailable(&self, font_family: String, font_style: String) -> Result<(), JsValue> {
+		let font = Font::new(font_family, font_style);
+		let message = PortfolioMessage::FontUnavailable { font };
+		self.dispatch(message);
+
+		Ok(())
+	}
+
 	/// A text box was changed
 	#[wasm_bindgen(js_name = updateBounds)]
 	pub fn update_bounds(&self, new_text: String) -> Result<(), JsValue> {
@@ -490,6 +531,32 @@ impl EditorHandle {
 		Ok(())
 	}
 
+	/// Save a color chosen in a color picker as a named swatch of the active document, with values on a scale from 0 to 1.
+	#[wasm_bindgen(js_name = addSwatch)]
+	pub fn add_swatch(&self, name: String, red: f32, green: f32, blue: f32, alpha: f32) -> Result<(), JsValue> {
+		let Some(color) = Color::from_rgbaf32(red, green, blue, alpha) else {
+			return Err(Error::new("Invalid color").into());
+		};
+
+		let message = DocumentMessage::AddSwatch { swatch: Swatch::new(name, color) };
+		self.dispatch(message);
+
+		Ok(())
+	}
+
+	/// Remember a color chosen in a color picker so it's offered among the recent colors, with values on a scale from 0 to 1.
+	#[wasm_bindgen(js_name = addRecentColor)]
+	pub fn add_recent_color(&self, red: f32, green: f32, blue: f32, alpha: f32) -> Result<(), JsValue> {
+		let Some(color) = Color::from_rgbaf32(red, green, blue, alpha) else {
+			return Err(Error::new("Invalid color").into());
+		};
+
+		let message = SwatchesPanelMessage::AddRecentColor { color };
+		self.dispatch(message);
+
+		Ok(())
+	}
+
 	/// Paste layers from a serialized json representation
 	#[wasm_bindgen(js_name = pasteSerializedData)]
 	pub fn paste_serialized_data(&self, data: String) {
@@ -559,6 +626,13 @@ impl EditorHandle {
 		self.dispatch(message);
 	}
 
+	/// Pans the viewport to center on a point in document space, such as one dragged to in the Navigator panel
+	#[wasm_bindgen(js_name = navigatorCenterViewOn)]
+	pub fn navigator_center_view_on(&self, x: f64, y: f64) {
+		let message = NavigatorPanelMessage::CenterViewOn { position: (x, y).into() };
+		self.dispatch(message);
+	}
+
 	/// Translates document (in viewport coords)
 	#[wasm_bindgen(js_name = panCanvasByFraction)]
 	pub fn pan_canvas_by_fraction(&self, delta_x: f64, delta_y: f64) {
@@ -566,6 +640,20 @@ impl EditorHandle {
 		self.dispatch(message);
 	}
 
+	/// Tilts the canvas by an angle as seen in the viewport, such as from a two-finger twist gesture
+	#[wasm_bindgen(js_name = tiltCanvasBy)]
+	pub fn tilt_canvas_by(&self, angle_radians: f64) {
+		let message = NavigationMessage::CanvasTiltBy { angle_radians };
+		self.dispatch(message);
+	}
+
+	/// Ends the presentation of the document, such as when the browser leaves full screen on its own
+	#[wasm_bindgen(js_name = stopPresentation)]
+	pub fn stop_presentation(&self) {
+		let message = DocumentMessage::StopPresentation;
+		self.dispatch(message);
+	}
+
 	/// Snaps the import/export edges to a grid space when the scroll bar is released
 	#[wasm_bindgen(js_name = setGridAlignedEdges)]
 	pub fn set_grid_aligned_edges(&self) {
@@ -580,6 +668,66 @@ impl EditorHandle {
 		self.dispatch(message);
 	}
 
+	/// Expand the selected subnetwork nodes, putting their contents in their place
+	#[wasm_bindgen(js_name = expandSelectedNodes)]
+	pub fn expand_nodes(&self) {
+		let message = NodeGraphMessage::ExpandSelectedNodes;
+		self.dispatch(message);
+	}
+
+	/// Download the selected node as a `.graphite-node` file
+	#[wasm_bindgen(js_name = exportSelectedNode)]
+	pub fn export_selected_node(&self) {
+		let message = NodeGraphMessage::ExportSelectedNode;
+		self.dispatch(message);
+	}
+
+	/// Add the node saved in a `.graphite-node` file to the node catalog of the active document
+	#[wasm_bindgen(js_name = importCustomNode)]
+	pub fn import_custom_node(&self, content: String) {
+		let message = DocumentMessage::ImportCustomNode { content };
+		self.dispatch(message);
+	}
+
+	/// Move the divider between the compared snapshot and the live artwork, as a fraction of the viewport width
+	#[wasm_bindgen(js_name = setCompareSwipePosition)]
+	pub fn set_compare_swipe_position(&self, position: f64) {
+		let message = CompareMessage::SetSwipePosition { position };
+		self.dispatch(message);
+	}
+
+	/// Freeze the current output of a node into a Bake node inserted after it
+	#[wasm_bindgen(js_name = bakeNode)]
+	pub fn bake_node(&self, node_id: u64) {
+		let message = NodeGraphMessage::BakeNode { node_id: NodeId(node_id) };
+		self.dispatch(message);
+	}
+
+	/// Show the value carried by the wire whose context menu is open in a tooltip
+	#[wasm_bindgen(js_name = inspectContextMenuWire)]
+	pub fn inspect_context_menu_wire(&self) {
+		let message = NodeGraphMessage::InspectContextMenuWire;
+		self.dispatch(message);
+	}
+
+	/// Pin a readout of the value carried by the wire whose context menu is open along the wire, or unpin it if it's already pinned
+	#[wasm_bindgen(js_name = pinContextMenuWire)]
+	pub fn pin_context_menu_wire(&self) {
+		let message = NodeGraphMessage::PinContextMenuWire;
+		self.dispatch(message);
+	}
+
+	/// Remove the readout pinned along the wire ending at the given input
+	#[wasm_bindgen(js_name = unpinWireProbe)]
+	pub fn unpin_wire_probe(&self, input_connector: JsValue) -> Result<(), JsValue> {
+		let Ok(input_connector) = from_value(input_connector) else {
+			return Err(Error::new("Could not unpin the wire probe since its input connector is invalid").into());
+		};
+		let message = NodeGraphMessage::SetWireProbePinned { input_connector, pinned: false };
+		self.dispatch(message);
+		Ok(())
+	}
+
 	/// Creates a new document node in the node graph
 	#[wasm_bindgen(js_name = createNode)]
 	pub fn create_node(&self, node_type: String, x: i32, y: i32) {
@@ -632,6 +780,30 @@ impl EditorHandle {
 		self.dispatch(message);
 	}
 
+	/// Imports a GeoJSON document as a map layer
+	#[wasm_bindgen(js_name = pasteGeoJson)]
+	pub fn paste_geojson(&self, name: Option<String>, geojson: String, mouse_x: Option<f64>, mouse_y: Option<f64>) {
+		let mouse = mouse_x.and_then(|x| mouse_y.map(|y| (x, y)));
+		let message = PortfolioMessage::PasteGeoJson { name, geojson, mouse };
+		self.dispatch(message);
+	}
+
+	/// Lays out the artwork rasterized for a print export as a PDF and downloads it
+	#[wasm_bindgen(js_name = exportPrint)]
+	pub fn export_print(&self, name: String, image_data: Vec<u8>, width: u32, height: u32, print_job: JsValue) -> Result<(), JsValue> {
+		let print_job = from_value(print_job).map_err(|error| Error::new(&format!("Invalid print job: {error}")))?;
+		let message = PortfolioMessage::SubmitPrintExport {
+			name,
+			pixels: image_data,
+			width,
+			height,
+			print_job,
+		};
+		self.dispatch(message);
+
+		Ok(())
+	}
+
 	#[wasm_bindgen(js_name = pasteSvg)]
 	pub fn paste_svg(&self, name: Option<String>, svg: String, mouse_x: Option<f64>, mouse_y: Option<f64>, insert_parent_id: Option<u64>, insert_index: Option<usize>) {
 		let mouse = mouse_x.and_then(|x| mouse_y.map(|y| (x, y)));
@@ -661,12 +833,43 @@ impl EditorHandle {
 		self.dispatch(message);
 	}
 
+	/// Toggle whether a node is bypassed, passing its primary input through in place of its output, given its node ID
+	#[wasm_bindgen(js_name = toggleNodeBypass)]
+	pub fn toggle_node_bypass(&self, id: u64) {
+		let node_id = NodeId(id);
+		let message = NodeGraphMessage::ToggleBypass { node_id };
+		self.dispatch(message);
+	}
+
+	/// Solo a layer given its node ID, hiding all other layers until it's toggled again
+	#[wasm_bindgen(js_name = toggleLayerSoloLayerPanel)]
+	pub fn toggle_layer_solo_layer_panel(&self, id: u64) {
+		let node_id = NodeId(id);
+		let message = NodeGraphMessage::ToggleSolo { node_id };
+		self.dispatch(message);
+	}
+
 	/// Pin or unpin a node given its node ID
 	#[wasm_bindgen(js_name = setNodePinned)]
 	pub fn set_node_pinned(&self, id: u64, pinned: bool) {
 		self.dispatch(DocumentMessage::SetNodePinned { node_id: NodeId(id), pinned });
 	}
 
+	/// Copy the values of a node's inputs to the clipboard, given its node ID
+	#[wasm_bindgen(js_name = copyNodeParameterValues)]
+	pub fn copy_node_parameter_values(&self, id: u64) {
+		self.dispatch(NodeGraphMessage::CopyParameterValues { node_id: NodeId(id) });
+	}
+
+	/// Paste input values copied from another node of the same type into a node, given its node ID
+	#[wasm_bindgen(js_name = pasteNodeParameterValues)]
+	pub fn paste_node_parameter_values(&self, id: u64, serialized_parameters: String) {
+		self.dispatch(NodeGraphMessage::PasteParameterValues {
+			node_id: NodeId(id),
+			serialized_parameters,
+		});
+	}
+
 	/// Delete a layer or node given its node ID
 	#[wasm_bindgen(js_name = deleteNode)]
 	pub fn delete_node(&self, id: u64) {
@@ -721,6 +924,42 @@ impl EditorHandle {
 		self.dispatch(message);
 	}
 
+	/// Set the title shown at the top of a node graph frame
+	#[wasm_bindgen(js_name = setFrameTitle)]
+	pub fn set_frame_title(&self, frame_id: u64, title: String) {
+		self.dispatch(NodeGraphMessage::SetFrameTitle { frame_id, title });
+	}
+
+	/// Set the color of a node graph frame from a 6-character RGB hex string (without a # prefix)
+	#[wasm_bindgen(js_name = setFrameColor)]
+	pub fn set_frame_color(&self, frame_id: u64, color: String) -> Result<(), JsValue> {
+		let Some(color) = Color::from_rgb_str(&color) else {
+			return Err(Error::new("Invalid color").into());
+		};
+
+		self.dispatch(NodeGraphMessage::SetFrameColor { frame_id, color });
+
+		Ok(())
+	}
+
+	/// Delete a node graph frame, leaving the nodes it contains in place
+	#[wasm_bindgen(js_name = deleteFrame)]
+	pub fn delete_frame(&self, frame_id: u64) {
+		self.dispatch(NodeGraphMessage::DeleteFrame { frame_id });
+	}
+
+	/// Set the markdown text of a node graph sticky note
+	#[wasm_bindgen(js_name = setNoteText)]
+	pub fn set_note_text(&self, note_id: u64, text: String) {
+		self.dispatch(NodeGraphMessage::SetNoteText { note_id, text });
+	}
+
+	/// Delete a node graph sticky note
+	#[wasm_bindgen(js_name = deleteNote)]
+	pub fn delete_note(&self, note_id: u64) {
+		self.dispatch(NodeGraphMessage::DeleteNote { note_id });
+	}
+
 	// #[wasm_bindgen(js_name = injectImaginatePollServerStatus)]
 	// pub fn inject_imaginate_poll_server_status(&self) {
 	// 	self.dispatch(PortfolioMessage::ImaginatePollServerStatus);
@@ -813,6 +1052,7 @@ impl EditorHandle {
 				glam::UVec2::ONE,
 				Default::default(),
 				None,
+				None,
 				true,
 			)
 			.unwrap();
@@ -947,17 +1187,22 @@ impl EditorHandle {
 
 #[wasm_bindgen(js_name = evaluateMathExpression)]
 pub fn evaluate_math_expression(expression: &str) -> Option<f64> {
-	let value = math_parser::evaluate(expression)
-		.inspect_err(|err| error!("Math parser error on \"{expression}\": {err}"))
-		.ok()?
-		.0
-		.inspect_err(|err| error!("Math evaluate error on \"{expression}\": {err} "))
-		.ok()?;
-	let Some(real) = value.as_real() else {
-		error!("{value} was not a real; skipping.");
-		return None;
-	};
-	Some(real)
+	misc::evaluate_math_expression(expression)
+}
+
+/// Evaluates a math expression typed into a length field shown in the given unit, where numbers may be suffixed by any unit (like "2cm"), and returns the length in pixels.
+#[wasm_bindgen(js_name = evaluateLengthExpression)]
+pub fn evaluate_length_expression(expression: &str, length_unit: JsValue) -> Option<f64> {
+	let length_unit: LengthUnit = from_value(length_unit).inspect_err(|err| error!("Invalid length unit: {err}")).ok()?;
+	length_unit.evaluate_expression(expression)
+}
+
+/// Separates a color, given with gamma-encoded sRGB channels, into the cyan, magenta, yellow, and black ink coverages (each from 0 to 1) that print it under the given printing condition.
+#[wasm_bindgen(js_name = colorToCmyk)]
+pub fn color_to_cmyk(red: f32, green: f32, blue: f32, print_profile: JsValue) -> Option<Vec<f32>> {
+	let print_profile: PrintProfile = from_value(print_profile).inspect_err(|err| error!("Invalid print profile: {err}")).ok()?;
+	let color = Color::from_rgbaf32(red, green, blue, 1.)?.to_linear_srgb();
+	Some(print_profile.separate(color).to_array().to_vec())
 }
 
 /// Helper function for calling JS's `requestAnimationFrame` with the given closure
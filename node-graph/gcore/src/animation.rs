@@ -1,4 +1,11 @@
-use crate::{Ctx, ExtractAnimationTime, ExtractTime};
+use crate::instances::Instances;
+#[cfg(feature = "alloc")]
+use crate::raster::adjustments::{curve_lookup_table, sample_lookup_table};
+use crate::raster::curve::Curve;
+use crate::renderer::GraphicElementRendered;
+use crate::vector::VectorDataTable;
+use crate::{Ctx, ExtractAnimationTime, ExtractTime, GraphicElement, GraphicGroupTable};
+use glam::{DAffine2, DVec2};
 
 const DAY: f64 = 1000. * 3600. * 24.;
 
@@ -26,6 +33,31 @@ impl core::fmt::Display for RealTimeMode {
 	}
 }
 
+/// The motion which the Animate Characters node staggers across each character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, dyn_any::DynAny, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CharacterAnimation {
+	/// Each character appears all at once when its turn comes.
+	#[default]
+	Typewriter,
+	/// Each character bobs up and down, with the wave traveling along the text.
+	Wave,
+	/// Each character grows from nothing about its center while fading in.
+	ScaleIn,
+	/// Each character's opacity and scale follow the curve over the course of its animation.
+	Custom,
+}
+impl core::fmt::Display for CharacterAnimation {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			CharacterAnimation::Typewriter => write!(f, "Typewriter"),
+			CharacterAnimation::Wave => write!(f, "Wave"),
+			CharacterAnimation::ScaleIn => write!(f, "Scale In"),
+			CharacterAnimation::Custom => write!(f, "Custom"),
+		}
+	}
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AnimationTimeMode {
 	AnimationTime,
@@ -52,6 +84,61 @@ fn animation_time(ctx: impl Ctx + ExtractAnimationTime) -> f64 {
 	ctx.try_animation_time().unwrap_or_default()
 }
 
+/// Staggers an animation across the elements of the input, each of which is treated as one character of text decomposed into separate glyphs. Every character starts its animation a fixed delay after the one before it.
+#[cfg(feature = "alloc")]
+#[node_macro::node(category("Animation"))]
+fn animate_characters<Data: Clone + Into<GraphicElement>>(
+	_: impl Ctx,
+	#[implementations(VectorDataTable, GraphicGroupTable)] mut characters: Instances<Data>,
+	/// The current time in seconds, usually supplied by the Animation Time node.
+	time: f64,
+	animation: CharacterAnimation,
+	/// The delay in seconds between the start of one character's animation and the next.
+	#[default(0.05)]
+	stagger: f64,
+	/// How long in seconds each character's animation takes, or the period of one cycle of the wave.
+	#[default(0.5)]
+	duration: f64,
+	/// The distance in pixels the characters rise and fall by in the wave.
+	#[default(10.)]
+	amplitude: f64,
+	/// Maps each character's progress through its animation to its opacity and scale, used by the custom animation.
+	curve: Curve,
+) -> Instances<Data> {
+	let duration = duration.max(f64::EPSILON);
+	let lookup_table = (animation == CharacterAnimation::Custom).then(|| curve_lookup_table(&curve));
+
+	for (index, character) in characters.instances_mut().enumerate() {
+		let local_time = time - index as f64 * stagger;
+		let progress = (local_time / duration).clamp(0., 1.);
+
+		let (rise, scale, opacity) = match animation {
+			CharacterAnimation::Typewriter => (0., 1., if local_time >= 0. { 1. } else { 0. }),
+			CharacterAnimation::Wave => (amplitude * (core::f64::consts::TAU * local_time / duration).sin(), 1., 1.),
+			CharacterAnimation::ScaleIn => {
+				let eased = progress * progress * (3. - 2. * progress);
+				(0., eased, eased)
+			}
+			CharacterAnimation::Custom => {
+				let amount = lookup_table.as_deref().map_or(progress, |lookup_table| sample_lookup_table(lookup_table, progress as f32) as f64);
+				(0., amount, amount)
+			}
+		};
+
+		if scale != 1. {
+			// Keep the scale invertible so the character can still be measured and selected while it is hidden
+			let scale = DVec2::splat(scale.max(1e-6));
+			let glyph: GraphicElement = character.instance.clone().into();
+			let center = glyph.bounding_box(*character.transform).map_or(character.transform.translation, |[min, max]| (min + max) / 2.);
+			*character.transform = DAffine2::from_translation(center) * DAffine2::from_scale(scale) * DAffine2::from_translation(-center) * *character.transform;
+		}
+		*character.transform = DAffine2::from_translation(DVec2::new(0., -rise)) * *character.transform;
+		character.alpha_blending.opacity *= opacity as f32;
+	}
+
+	characters
+}
+
 // These nodes require more sophistcated algorithms for giving the correct result
 
 // #[node_macro::node(category("Animation"))]
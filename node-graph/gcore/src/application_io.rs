@@ -214,6 +214,17 @@ impl<T: ApplicationIo> ApplicationIo for &T {
 	}
 }
 
+/// The path or URL of a file to be loaded with [`ApplicationIo::load_resource`], which the editor lets the user choose by browsing for the file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, DynAny)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FilePath(pub String);
+
+impl AsRef<str> for FilePath {
+	fn as_ref(&self) -> &str {
+		&self.0
+	}
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ApplicationError {
 	NotFound,
@@ -7,7 +7,7 @@ use crate::uuid::NodeId;
 use crate::vector::{VectorData, VectorDataTable};
 use crate::{CloneVarArgs, Color, Context, Ctx, ExtractAll, OwnedContextImpl};
 use dyn_any::DynAny;
-use glam::{DAffine2, IVec2};
+use glam::{DAffine2, DVec2, IVec2};
 use std::hash::Hash;
 
 pub mod renderer;
@@ -225,7 +225,7 @@ impl serde::Serialize for RasterFrame {
 }
 
 /// Some [`ArtboardData`] with some optional clipping bounds that can be exported.
-#[derive(Clone, Debug, Hash, PartialEq, DynAny)]
+#[derive(Clone, Debug, PartialEq, DynAny)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Artboard {
 	pub graphic_group: GraphicGroupTable,
@@ -234,6 +234,25 @@ pub struct Artboard {
 	pub dimensions: IVec2,
 	pub background: Color,
 	pub clip: bool,
+	/// Distance the background extends past the edges of the artboard, so artwork reaching the edges can be trimmed after printing without leaving slivers of paper.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub bleed: f64,
+	/// Distance inset from the edges of the artboard which marks the safe area that important artwork should stay within. It's shown as a guide and isn't rendered.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub margin: f64,
+}
+
+impl Hash for Artboard {
+	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+		self.graphic_group.hash(state);
+		self.label.hash(state);
+		self.location.hash(state);
+		self.dimensions.hash(state);
+		self.background.hash(state);
+		self.clip.hash(state);
+		self.bleed.to_bits().hash(state);
+		self.margin.to_bits().hash(state);
+	}
 }
 
 impl Default for Artboard {
@@ -251,8 +270,16 @@ impl Artboard {
 			dimensions: dimensions.abs(),
 			background: Color::WHITE,
 			clip: false,
+			bleed: 0.,
+			margin: 0.,
 		}
 	}
+
+	/// The corners of the artboard grown outward by its bleed.
+	pub fn bleed_bounds(&self) -> [DVec2; 2] {
+		let bleed = DVec2::splat(self.bleed.max(0.));
+		[self.location.as_dvec2() - bleed, (self.location + self.dimensions).as_dvec2() + bleed]
+	}
 }
 
 // TODO: Eventually remove this migration document upgrade code
@@ -387,6 +414,32 @@ async fn flatten_group(_: impl Ctx, group: GraphicGroupTable, fully_flatten: boo
 	output
 }
 
+/// Outputs the value captured when the source was baked, so long as the node is frozen. The source is left connected but isn't evaluated while frozen, allowing a heavy procedural graph to be skipped and later unfrozen.
+#[node_macro::node(category("General"))]
+async fn bake<T, C: Send + 'n + Clone>(
+	#[implementations(Context)] ctx: C,
+	#[implementations(
+		Context -> GraphicGroupTable,
+		Context -> VectorDataTable,
+		Context -> ImageFrameTable<Color>,
+	)]
+	source: impl Node<C, Output = T>,
+	#[implementations(
+		GraphicGroupTable,
+		VectorDataTable,
+		ImageFrameTable<Color>,
+	)]
+	#[widget(ParsedWidgetOverride::Hidden)]
+	baked: T,
+	#[default(true)] frozen: bool,
+) -> T {
+	if frozen {
+		return baked;
+	}
+
+	source.eval(ctx).await
+}
+
 #[node_macro::node(category(""))]
 async fn to_artboard<Data: Into<GraphicGroupTable> + 'n>(
 	ctx: impl ExtractAll + CloneVarArgs + Ctx,
@@ -402,6 +455,8 @@ async fn to_artboard<Data: Into<GraphicGroupTable> + 'n>(
 	dimensions: IVec2,
 	background: Color,
 	clip: bool,
+	#[min(0.)] bleed: f64,
+	#[min(0.)] margin: f64,
 ) -> Artboard {
 	let footprint = ctx.try_footprint().copied();
 	let mut new_ctx = OwnedContextImpl::from(ctx);
@@ -418,6 +473,8 @@ async fn to_artboard<Data: Into<GraphicGroupTable> + 'n>(
 		dimensions: dimensions.abs(),
 		background,
 		clip,
+		bleed: bleed.max(0.),
+		margin: margin.max(0.),
 	}
 }
 
@@ -5,7 +5,7 @@ use crate::raster::image::ImageFrameTable;
 use crate::raster::{BlendMode, Image};
 use crate::transform::{Footprint, Transform};
 use crate::uuid::{NodeId, generate_uuid};
-use crate::vector::style::{Fill, Stroke, ViewMode};
+use crate::vector::style::{Fill, PaintOrder, Stroke, StrokeAlign, ViewMode};
 use crate::vector::{PointId, VectorDataTable};
 use crate::{Artboard, ArtboardGroupTable, Color, GraphicElement, GraphicGroupTable, RasterFrame};
 use base64::Engine;
@@ -424,46 +424,134 @@ impl GraphicElementRendered for VectorDataTable {
 			let multiplied_transform = render.transform * *instance.transform;
 			// Only consider strokes with non-zero weight, since default strokes with zero weight would prevent assigning the correct stroke transform
 			let has_real_stroke = instance.instance.style.stroke().filter(|stroke| stroke.weight() > 0.);
-			let set_stroke_transform = has_real_stroke.map(|stroke| stroke.transform).filter(|transform| transform.matrix2.determinant() != 0.);
+			let set_stroke_transform = has_real_stroke.as_ref().map(|stroke| stroke.transform).filter(|transform| transform.matrix2.determinant() != 0.);
 			let applied_stroke_transform = set_stroke_transform.unwrap_or(*instance.transform);
 			let element_transform = set_stroke_transform.map(|stroke_transform| multiplied_transform * stroke_transform.inverse());
 			let element_transform = element_transform.unwrap_or(DAffine2::IDENTITY);
 			let layer_bounds = instance.instance.bounding_box().unwrap_or_default();
 			let transformed_bounds = instance.instance.bounding_box_with_transform(applied_stroke_transform).unwrap_or_default();
 
+			// An inside or outside stroke, one with dashes fitted to the path, or one with markers, is drawn along its own paths separately from the fill
+			let separate_stroke =
+				has_real_stroke.filter(|stroke| (stroke.align != StrokeAlign::Center || stroke.has_fitted_dashes() || stroke.has_markers()) && render_params.view_mode != ViewMode::Outline);
+
 			let mut path = String::new();
+			let mut stroke_paths: Vec<(String, Stroke)> = Vec::new();
+			let mut marker_path = String::new();
 			for subpath in instance.instance.stroke_bezier_paths() {
 				let _ = subpath.subpath_to_svg(&mut path, applied_stroke_transform);
-			}
 
-			render.leaf_tag("path", |attributes| {
-				attributes.push("d", path);
-				let matrix = format_transform_matrix(element_transform);
-				if !matrix.is_empty() {
-					attributes.push("transform", matrix);
+				if let Some(stroke) = &separate_stroke {
+					let mut subpath = subpath.clone();
+					subpath.apply_transform(applied_stroke_transform);
+					for marker in stroke.marker_subpaths(&subpath) {
+						let _ = marker.subpath_to_svg(&mut marker_path, DAffine2::IDENTITY);
+					}
+					for (part, part_stroke) in stroke.dash_subpath(stroke.align_subpath(subpath)) {
+						let mut part_path = String::new();
+						let _ = part.subpath_to_svg(&mut part_path, DAffine2::IDENTITY);
+
+						// Consecutive parts with the same dash pattern share one path element
+						match stroke_paths.last_mut() {
+							Some((last_path, last_stroke)) if *last_stroke == part_stroke => last_path.push_str(&part_path),
+							_ => stroke_paths.push((part_path, part_stroke)),
+						}
+					}
 				}
+			}
+			let matrix = format_transform_matrix(element_transform);
+
+			let Some(separate_stroke) = separate_stroke else {
+				render.leaf_tag("path", |attributes| {
+					attributes.push("d", path);
+					if !matrix.is_empty() {
+						attributes.push("transform", matrix);
+					}
 
-				let defs = &mut attributes.0.svg_defs;
+					let defs = &mut attributes.0.svg_defs;
 
-				let fill_and_stroke = instance
-					.instance
-					.style
-					.render(render_params.view_mode, defs, element_transform, applied_stroke_transform, layer_bounds, transformed_bounds);
-				attributes.push_val(fill_and_stroke);
+					let fill_and_stroke = instance
+						.instance
+						.style
+						.render(render_params.view_mode, defs, element_transform, applied_stroke_transform, layer_bounds, transformed_bounds);
+					attributes.push_val(fill_and_stroke);
 
-				if instance.alpha_blending.opacity < 1. {
-					attributes.push("opacity", instance.alpha_blending.opacity.to_string());
-				}
+					if instance.alpha_blending.opacity < 1. {
+						attributes.push("opacity", instance.alpha_blending.opacity.to_string());
+					}
 
-				if instance.alpha_blending.blend_mode != BlendMode::default() {
-					attributes.push("style", instance.alpha_blending.blend_mode.render());
-				}
-			});
+					if instance.alpha_blending.blend_mode != BlendMode::default() {
+						attributes.push("style", instance.alpha_blending.blend_mode.render());
+					}
+				});
+				continue;
+			};
+
+			// Group the fill and stroke so the opacity and blend mode apply to both together
+			render.parent_tag(
+				"g",
+				|attributes| {
+					if instance.alpha_blending.opacity < 1. {
+						attributes.push("opacity", instance.alpha_blending.opacity.to_string());
+					}
+
+					if instance.alpha_blending.blend_mode != BlendMode::default() {
+						attributes.push("style", instance.alpha_blending.blend_mode.render());
+					}
+				},
+				|render| {
+					let render_fill = |render: &mut SvgRender| {
+						render.leaf_tag("path", |attributes| {
+							attributes.push("d", path.clone());
+							if !matrix.is_empty() {
+								attributes.push("transform", matrix.clone());
+							}
+
+							let defs = &mut attributes.0.svg_defs;
+
+							let mut fill_style = instance.instance.style.clone();
+							fill_style.clear_stroke();
+							let fill = fill_style.render(render_params.view_mode, defs, element_transform, applied_stroke_transform, layer_bounds, transformed_bounds);
+							attributes.push_val(fill);
+						});
+					};
+					let render_stroke = |render: &mut SvgRender| {
+						for (stroke_path, stroke) in &stroke_paths {
+							render.leaf_tag("path", |attributes| {
+								attributes.push("d", stroke_path.clone());
+								if !matrix.is_empty() {
+									attributes.push("transform", matrix.clone());
+								}
+
+								attributes.push_val(format!(r#" fill="none"{}"#, stroke.render()));
+							});
+						}
+						if !marker_path.is_empty() {
+							render.leaf_tag("path", |attributes| {
+								attributes.push("d", marker_path.clone());
+								if !matrix.is_empty() {
+									attributes.push("transform", matrix.clone());
+								}
+
+								attributes.push_val(separate_stroke.render_marker_fill());
+							});
+						}
+					};
+
+					if separate_stroke.paint_order == PaintOrder::StrokeBelow {
+						render_stroke(render);
+						render_fill(render);
+					} else {
+						render_fill(render);
+						render_stroke(render);
+					}
+				},
+			);
 		}
 	}
 
 	#[cfg(feature = "vello")]
-	fn render_to_vello(&self, scene: &mut Scene, parent_transform: DAffine2, _: &mut RenderContext, render_params: &RenderParams) {
+	fn render_to_vello(&self, scene: &mut Scene, parent_transform: DAffine2, context: &mut RenderContext, render_params: &RenderParams) {
 		use crate::consts::{LAYER_OUTLINE_STROKE_COLOR, LAYER_OUTLINE_STROKE_WEIGHT};
 		use crate::vector::style::{GradientType, LineCap, LineJoin};
 		use vello::kurbo::{Cap, Join};
@@ -472,7 +560,7 @@ impl GraphicElementRendered for VectorDataTable {
 		for instance in self.instances() {
 			let multiplied_transform = parent_transform * *instance.transform;
 			let has_real_stroke = instance.instance.style.stroke().filter(|stroke| stroke.weight() > 0.);
-			let set_stroke_transform = has_real_stroke.map(|stroke| stroke.transform).filter(|transform| transform.matrix2.determinant() != 0.);
+			let set_stroke_transform = has_real_stroke.as_ref().map(|stroke| stroke.transform).filter(|transform| transform.matrix2.determinant() != 0.);
 			let applied_stroke_transform = set_stroke_transform.unwrap_or(multiplied_transform);
 			let element_transform = set_stroke_transform.map(|stroke_transform| multiplied_transform * stroke_transform.inverse());
 			let element_transform = element_transform.unwrap_or(DAffine2::IDENTITY);
@@ -484,6 +572,32 @@ impl GraphicElementRendered for VectorDataTable {
 				subpath.to_vello_path(applied_stroke_transform, &mut path);
 			}
 
+			// An inside or outside stroke, or one with dashes fitted to the path, is drawn along its own paths separately from the fill
+			let stroke_paths = has_real_stroke
+				.as_ref()
+				.filter(|stroke| (stroke.align != StrokeAlign::Center || stroke.has_fitted_dashes()) && render_params.view_mode != ViewMode::Outline)
+				.map(|stroke| {
+					let mut stroke_paths = Vec::new();
+					for mut subpath in instance.instance.stroke_bezier_paths() {
+						subpath.apply_transform(applied_stroke_transform);
+						for (part, part_stroke) in stroke.dash_subpath(stroke.align_subpath(subpath)) {
+							let mut part_path = kurbo::BezPath::new();
+							part.to_vello_path(DAffine2::IDENTITY, &mut part_path);
+							stroke_paths.push((part_path, part_stroke));
+						}
+					}
+					stroke_paths
+				});
+			let mut marker_path = kurbo::BezPath::new();
+			if let Some(stroke) = has_real_stroke.as_ref().filter(|_| render_params.view_mode != ViewMode::Outline) {
+				for mut subpath in instance.instance.stroke_bezier_paths() {
+					subpath.apply_transform(applied_stroke_transform);
+					for marker in stroke.marker_subpaths(&subpath) {
+						marker.to_vello_path(DAffine2::IDENTITY, &mut marker_path);
+					}
+				}
+			}
+
 			// If we're using opacity or a blend mode, we need to push a layer
 			let blend_mode = match render_params.view_mode {
 				ViewMode::Outline => peniko::Mix::Normal,
@@ -522,6 +636,55 @@ impl GraphicElementRendered for VectorDataTable {
 					scene.stroke(&outline_stroke, kurbo::Affine::new(element_transform.to_cols_array()), outline_color, None, &path);
 				}
 				_ => {
+					let stroke_below = has_real_stroke.as_ref().is_some_and(|stroke| stroke.paint_order == PaintOrder::StrokeBelow);
+					let render_stroke = |scene: &mut Scene| {
+						let Some(stroke) = instance.instance.style.stroke() else { return };
+						let color = match stroke.color {
+							Some(color) => peniko::Color::new([color.r(), color.g(), color.b(), color.a()]),
+							None => peniko::Color::TRANSPARENT,
+						};
+						let to_kurbo_stroke = |stroke: &Stroke| {
+							let cap = match stroke.line_cap {
+								LineCap::Butt => Cap::Butt,
+								LineCap::Round => Cap::Round,
+								LineCap::Square => Cap::Square,
+							};
+							let join = match stroke.line_join {
+								LineJoin::Miter => Join::Miter,
+								LineJoin::Bevel => Join::Bevel,
+								LineJoin::Round => Join::Round,
+							};
+							kurbo::Stroke {
+								width: stroke.weight,
+								miter_limit: stroke.line_join_miter_limit,
+								join,
+								start_cap: cap,
+								end_cap: cap,
+								dash_pattern: stroke.dash_lengths.iter().copied().collect(),
+								dash_offset: stroke.dash_offset,
+							}
+						};
+
+						// Draw the stroke if it's visible
+						if stroke.weight > 0. {
+							let stroke_transform = kurbo::Affine::new(element_transform.to_cols_array());
+							match &stroke_paths {
+								Some(stroke_paths) => {
+									for (part_path, part_stroke) in stroke_paths {
+										scene.stroke(&to_kurbo_stroke(part_stroke), stroke_transform, color, None, part_path);
+									}
+								}
+								None => scene.stroke(&to_kurbo_stroke(&stroke), stroke_transform, color, None, &path),
+							}
+							if !marker_path.is_empty() {
+								scene.fill(peniko::Fill::NonZero, stroke_transform, color, None, &marker_path);
+							}
+						}
+					};
+
+					if stroke_below {
+						render_stroke(scene);
+					}
 					match instance.instance.style.fill() {
 						Fill::Solid(color) => {
 							let fill = peniko::Brush::Solid(peniko::Color::new([color.r(), color.g(), color.b(), color.a()]));
@@ -529,7 +692,7 @@ impl GraphicElementRendered for VectorDataTable {
 						}
 						Fill::Gradient(gradient) => {
 							let mut stops = peniko::ColorStops::new();
-							for &(offset, color) in &gradient.stops {
+							for (offset, color) in gradient.stops.rendered_stops() {
 								stops.push(peniko::ColorStop {
 									offset: offset as f32,
 									color: peniko::color::DynamicColor::from_alpha_color(peniko::Color::new([color.r(), color.g(), color.b(), color.a()])),
@@ -570,38 +733,20 @@ impl GraphicElementRendered for VectorDataTable {
 							let brush_transform = kurbo::Affine::new((inverse_element_transform * parent_transform).to_cols_array());
 							scene.fill(peniko::Fill::NonZero, kurbo::Affine::new(element_transform.to_cols_array()), &fill, Some(brush_transform), &path);
 						}
+						Fill::Pattern(pattern) => {
+							// Clip to the shape, then draw a copy of the tile in each cell of the grid that overlaps it
+							scene.push_layer(peniko::Mix::Clip, 1., kurbo::Affine::new(element_transform.to_cols_array()), &path);
+							let tile = pattern.tile_in_cell();
+							for cell_transform in pattern.cell_transforms(layer_bounds) {
+								tile.render_to_vello(scene, multiplied_transform * cell_transform, context, render_params);
+							}
+							scene.pop_layer();
+						}
 						Fill::None => {}
 					};
 
-					if let Some(stroke) = instance.instance.style.stroke() {
-						let color = match stroke.color {
-							Some(color) => peniko::Color::new([color.r(), color.g(), color.b(), color.a()]),
-							None => peniko::Color::TRANSPARENT,
-						};
-						let cap = match stroke.line_cap {
-							LineCap::Butt => Cap::Butt,
-							LineCap::Round => Cap::Round,
-							LineCap::Square => Cap::Square,
-						};
-						let join = match stroke.line_join {
-							LineJoin::Miter => Join::Miter,
-							LineJoin::Bevel => Join::Bevel,
-							LineJoin::Round => Join::Round,
-						};
-						let stroke = kurbo::Stroke {
-							width: stroke.weight,
-							miter_limit: stroke.line_join_miter_limit,
-							join,
-							start_cap: cap,
-							end_cap: cap,
-							dash_pattern: stroke.dash_lengths.into(),
-							dash_offset: stroke.dash_offset,
-						};
-
-						// Draw the stroke if it's visible
-						if stroke.width > 0. {
-							scene.stroke(&stroke, kurbo::Affine::new(element_transform.to_cols_array()), color, None, &path);
-						}
+					if !stroke_below {
+						render_stroke(scene);
 					}
 				}
 			}
@@ -625,7 +770,16 @@ impl GraphicElementRendered for VectorDataTable {
 				// We use the full line width here to account for different styles of line caps
 				let offset = DVec2::splat(stroke_width * scale.x.max(scale.y) * miter_limit);
 
-				instance.instance.bounding_box_with_transform(transform * *instance.transform).map(|[a, b]| [a - offset, b + offset])
+				// An outside stroke grows outward from the path, so the bounds are taken around the path it's centered on
+				let bounds = match instance.instance.style.stroke().filter(|stroke| stroke.align == StrokeAlign::Outside) {
+					Some(stroke) => instance
+						.instance
+						.stroke_bezier_paths()
+						.filter_map(|subpath| stroke.outer_subpath(subpath).bounding_box_with_transform(transform * *instance.transform))
+						.reduce(Quad::combine_bounds),
+					None => instance.instance.bounding_box_with_transform(transform * *instance.transform),
+				};
+				bounds.map(|[a, b]| [a - offset, b + offset])
 			})
 			.reduce(Quad::combine_bounds)
 	}
@@ -644,8 +798,13 @@ impl GraphicElementRendered for VectorDataTable {
 					subpath
 				};
 
+				let stroke = instance.style.stroke();
 				let click_targets = instance
 					.stroke_bezier_paths()
+					.map(|subpath| match &stroke {
+						Some(stroke) => stroke.outer_subpath(subpath),
+						None => subpath,
+					})
 					.map(fill)
 					.map(|subpath| ClickTarget::new(subpath, stroke_width))
 					.collect::<Vec<ClickTarget>>();
@@ -670,7 +829,12 @@ impl GraphicElementRendered for VectorDataTable {
 				}
 				subpath
 			};
-			click_targets.extend(instance.instance.stroke_bezier_paths().map(fill).map(|subpath| {
+			let stroke = instance.instance.style.stroke();
+			let outer_subpath = |subpath| match &stroke {
+				Some(stroke) => stroke.outer_subpath(subpath),
+				None => subpath,
+			};
+			click_targets.extend(instance.instance.stroke_bezier_paths().map(outer_subpath).map(fill).map(|subpath| {
 				let mut click_target = ClickTarget::new(subpath, stroke_width);
 				click_target.apply_transform(*instance.transform);
 				click_target
@@ -692,16 +856,17 @@ impl GraphicElementRendered for VectorDataTable {
 impl GraphicElementRendered for Artboard {
 	fn render_svg(&self, render: &mut SvgRender, render_params: &RenderParams) {
 		if !render_params.hide_artboards {
-			// Background
+			// Background, which extends outward into the bleed
+			let [min, max] = self.bleed_bounds();
 			render.leaf_tag("rect", |attributes| {
 				attributes.push("fill", format!("#{}", self.background.to_rgb_hex_srgb_from_gamma()));
 				if self.background.a() < 1. {
 					attributes.push("fill-opacity", ((self.background.a() * 1000.).round() / 1000.).to_string());
 				}
-				attributes.push("x", self.location.x.min(self.location.x + self.dimensions.x).to_string());
-				attributes.push("y", self.location.y.min(self.location.y + self.dimensions.y).to_string());
-				attributes.push("width", self.dimensions.x.abs().to_string());
-				attributes.push("height", self.dimensions.y.abs().to_string());
+				attributes.push("x", min.x.to_string());
+				attributes.push("y", min.y.to_string());
+				attributes.push("width", (max - min).x.to_string());
+				attributes.push("height", (max - min).y.to_string());
 			});
 		}
 
@@ -720,10 +885,15 @@ impl GraphicElementRendered for Artboard {
 					let id = format!("artboard-{}", generate_uuid());
 					let selector = format!("url(#{id})");
 
+					// Artwork reaching into the bleed is kept so it can be trimmed after printing
+					let bleed = self.bleed.max(0.);
 					write!(
 						&mut attributes.0.svg_defs,
-						r##"<clipPath id="{id}"><rect x="0" y="0" width="{}" height="{}"/></clipPath>"##,
-						self.dimensions.x, self.dimensions.y,
+						r##"<clipPath id="{id}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"##,
+						-bleed,
+						-bleed,
+						self.dimensions.x as f64 + bleed * 2.,
+						self.dimensions.y as f64 + bleed * 2.,
 					)
 					.unwrap();
 					attributes.push("clip-path", selector);
@@ -742,7 +912,7 @@ impl GraphicElementRendered for Artboard {
 
 		// Render background
 		let color = peniko::Color::new([self.background.r(), self.background.g(), self.background.b(), self.background.a()]);
-		let [a, b] = [self.location.as_dvec2(), self.location.as_dvec2() + self.dimensions.as_dvec2()];
+		let [a, b] = self.bleed_bounds();
 		let rect = kurbo::Rect::new(a.x.min(b.x), a.y.min(b.y), a.x.max(b.x), a.y.max(b.y));
 		let blend_mode = peniko::BlendMode::new(peniko::Mix::Clip, peniko::Compose::SrcOver);
 
@@ -762,7 +932,7 @@ impl GraphicElementRendered for Artboard {
 	}
 
 	fn bounding_box(&self, transform: DAffine2) -> Option<[DVec2; 2]> {
-		let artboard_bounds = (transform * Quad::from_box([self.location.as_dvec2(), self.location.as_dvec2() + self.dimensions.as_dvec2()])).bounding_box();
+		let artboard_bounds = (transform * Quad::from_box(self.bleed_bounds())).bounding_box();
 		if self.clip {
 			Some(artboard_bounds)
 		} else {
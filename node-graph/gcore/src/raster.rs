@@ -17,10 +17,16 @@ pub mod bbox;
 pub mod brightness_contrast;
 #[cfg(not(target_arch = "spirv"))]
 pub mod brush_cache;
+#[cfg(not(target_arch = "spirv"))]
+pub mod cmyk;
 pub mod color;
 #[cfg(not(target_arch = "spirv"))]
 pub mod curve;
 pub mod discrete_srgb;
+#[cfg(not(target_arch = "spirv"))]
+pub mod histogram;
+#[cfg(not(target_arch = "spirv"))]
+pub mod statistics;
 
 pub use adjustments::*;
 
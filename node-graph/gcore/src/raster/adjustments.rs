@@ -1,15 +1,16 @@
 #![allow(clippy::too_many_arguments)]
 
+use crate::raster::cmyk::PrintProfile;
 #[cfg(feature = "alloc")]
-use crate::raster::curve::{Curve, CurveManipulatorGroup, ValueMapperNode};
+use crate::raster::curve::{Curve, CurveManipulatorGroup, Curves, ValueMapperNode};
 #[cfg(feature = "alloc")]
 use crate::raster::image::{Image, ImageFrameTable};
 use crate::raster::{Channel, Color, Pixel};
 use crate::registry::types::{Angle, Percentage, SignedPercentage};
 use crate::vector::VectorDataTable;
-use crate::vector::style::GradientStops;
+use crate::vector::style::{Fill, GradientStops};
+use crate::{ArtboardGroupTable, GraphicElement, GraphicGroupTable, RasterFrame};
 use crate::{Ctx, Node};
-use crate::{GraphicElement, GraphicGroupTable};
 use core::cmp::Ordering;
 use core::fmt::Debug;
 use dyn_any::DynAny;
@@ -288,6 +289,7 @@ fn luminance<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut input: T,
 	luminance_calc: LuminanceCalculation,
@@ -312,6 +314,7 @@ fn extract_channel<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut input: T,
 	channel: RedGreenBlueAlpha,
@@ -335,6 +338,7 @@ fn make_opaque<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut input: T,
 ) -> T {
@@ -347,18 +351,42 @@ fn make_opaque<T: Adjust<Color>>(
 	input
 }
 
+/// The color channels adjusted by the [`levels`] node: all of them together, or a single one.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, DynAny)]
+pub enum LevelsChannel {
+	#[default]
+	Composite,
+	Red,
+	Green,
+	Blue,
+}
+
+impl core::fmt::Display for LevelsChannel {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			LevelsChannel::Composite => write!(f, "RGB"),
+			LevelsChannel::Red => write!(f, "Red"),
+			LevelsChannel::Green => write!(f, "Green"),
+			LevelsChannel::Blue => write!(f, "Blue"),
+		}
+	}
+}
+
 // Aims for interoperable compatibility with:
 // https://www.adobe.com/devnet-apps/photoshop/fileformatashtml/#:~:text=%27%20%3D%20Brightness/Contrast-,%27levl%27%20%3D%20Levels,-%27curv%27%20%3D%20Curves
 //
 // Algorithm from:
 // https://stackoverflow.com/questions/39510072/algorithm-for-adjustment-of-image-levels
-#[node_macro::node(category("Raster: Adjustment"))]
+#[node_macro::node(category("Raster: Adjustment"), properties("levels_properties"))]
 fn levels<T: Adjust<Color>>(
 	_: impl Ctx,
 	#[implementations(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut image: T,
 	#[default(0.)] shadows: Percentage,
@@ -366,9 +394,11 @@ fn levels<T: Adjust<Color>>(
 	#[default(100.)] highlights: Percentage,
 	#[default(0.)] output_minimums: Percentage,
 	#[default(100.)] output_maximums: Percentage,
+	channel: LevelsChannel,
 ) -> T {
-	image.adjust(|color| {
-		let color = color.to_gamma_srgb();
+	image.adjust(|original| {
+		let original = original.to_gamma_srgb();
+		let color = original;
 
 		// Input Range (Range: 0-1)
 		let input_shadows = (shadows / 100.) as f32;
@@ -407,6 +437,14 @@ fn levels<T: Adjust<Color>>(
 		// Output levels (Range: 0-1)
 		let color = color.map_rgb(|c| c * (output_maximums - output_minimums) + output_minimums);
 
+		// Only the chosen channel is adjusted, and the others keep their original values
+		let color = match channel {
+			LevelsChannel::Composite => color,
+			LevelsChannel::Red => Color::from_rgbaf32_unchecked(color.r(), original.g(), original.b(), original.a()),
+			LevelsChannel::Green => Color::from_rgbaf32_unchecked(original.r(), color.g(), original.b(), original.a()),
+			LevelsChannel::Blue => Color::from_rgbaf32_unchecked(original.r(), original.g(), color.b(), original.a()),
+		};
+
 		color.to_linear_srgb()
 	});
 	image
@@ -426,6 +464,7 @@ async fn black_and_white<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut image: T,
 	#[default(Color::BLACK)] tint: Color,
@@ -498,6 +537,7 @@ async fn hue_saturation<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut input: T,
 	hue_shift: Angle,
@@ -532,6 +572,7 @@ async fn invert<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut input: T,
 ) -> T {
@@ -554,6 +595,7 @@ async fn threshold<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut image: T,
 	#[default(50.)] min_luminance: Percentage,
@@ -739,6 +781,51 @@ where
 	}
 }
 
+impl Adjust<Color> for GraphicGroupTable {
+	fn adjust(&mut self, map_fn: impl Fn(&Color) -> Color) {
+		adjust_graphic_group(self, &map_fn);
+	}
+}
+impl Adjust<Color> for ArtboardGroupTable {
+	fn adjust(&mut self, map_fn: impl Fn(&Color) -> Color) {
+		for instance in self.instances_mut() {
+			instance.instance.background.adjust(&map_fn);
+			adjust_graphic_group(&mut instance.instance.graphic_group, &map_fn);
+		}
+	}
+}
+
+/// Applies the color mapping to every raster and vector element nested within a group, so an adjustment layer affects all the layers below it.
+fn adjust_graphic_group(graphic_group: &mut GraphicGroupTable, map_fn: &dyn Fn(&Color) -> Color) {
+	for instance in graphic_group.instances_mut() {
+		match instance.instance {
+			GraphicElement::GraphicGroup(graphic_group) => adjust_graphic_group(graphic_group, map_fn),
+			GraphicElement::VectorData(vector_data) => {
+				for vector_instance in vector_data.instances_mut() {
+					let style = &mut vector_instance.instance.style;
+
+					let mut fill = style.fill().clone();
+					match &mut fill {
+						Fill::None => {}
+						Fill::Solid(color) => color.adjust(map_fn),
+						Fill::Gradient(gradient) => gradient.stops.adjust(map_fn),
+						Fill::Pattern(pattern) => adjust_graphic_group(&mut pattern.tile, map_fn),
+					}
+					style.set_fill(fill);
+
+					if let Some(mut stroke) = style.stroke() {
+						stroke.color.adjust(map_fn);
+						style.set_stroke(stroke);
+					}
+				}
+			}
+			GraphicElement::RasterFrame(RasterFrame::ImageFrame(image)) => image.adjust(map_fn),
+			// GPU textures can't be adjusted on the CPU
+			GraphicElement::RasterFrame(RasterFrame::TextureFrame(_)) => {}
+		}
+	}
+}
+
 #[inline(always)]
 pub fn blend_colors(foreground: Color, background: Color, blend_mode: BlendMode, opacity: f64) -> Color {
 	let target_color = match blend_mode {
@@ -762,6 +849,7 @@ async fn gradient_map<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut image: T,
 	gradient: GradientStops,
@@ -790,6 +878,7 @@ async fn vibrance<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut image: T,
 	vibrance: SignedPercentage,
@@ -1080,6 +1169,7 @@ async fn channel_mixer<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut image: T,
 
@@ -1200,6 +1290,8 @@ pub enum SelectiveColorChoice {
 	Whites,
 	Neutrals,
 	Blacks,
+	/// The hues within a range around a chosen center hue.
+	Custom,
 }
 
 impl core::fmt::Display for SelectiveColorChoice {
@@ -1214,6 +1306,7 @@ impl core::fmt::Display for SelectiveColorChoice {
 			SelectiveColorChoice::Whites => write!(f, "Whites"),
 			SelectiveColorChoice::Neutrals => write!(f, "Neutrals"),
 			SelectiveColorChoice::Blacks => write!(f, "Blacks"),
+			SelectiveColorChoice::Custom => write!(f, "Custom"),
 		}
 	}
 }
@@ -1231,6 +1324,7 @@ async fn selective_color<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut image: T,
 	mode: RelativeAbsolute,
@@ -1271,6 +1365,17 @@ async fn selective_color<T: Adjust<Color>>(
 	#[name("(Blacks) Yellow")] k_y: f64,
 	#[name("(Blacks) Black")] k_k: f64,
 	_colors: SelectiveColorChoice,
+	#[name("(Custom) Hue Center")]
+	#[range((0., 360.))]
+	custom_hue_center: Angle,
+	#[name("(Custom) Hue Width")]
+	#[default(60.)]
+	#[range((0., 360.))]
+	custom_hue_width: Angle,
+	#[name("(Custom) Cyan")] u_c: f64,
+	#[name("(Custom) Magenta")] u_m: f64,
+	#[name("(Custom) Yellow")] u_y: f64,
+	#[name("(Custom) Black")] u_k: f64,
 ) -> T {
 	image.adjust(|color| {
 		let color = color.to_gamma_srgb();
@@ -1284,6 +1389,14 @@ async fn selective_color<T: Adjust<Color>>(
 		let max_channel = max(r, g, b);
 		let min_channel = min(r, g, b);
 
+		// How far the pixel's hue is from the center of the custom range, as a fraction of half the range's width, where 1 is at its edge
+		let custom_hue_distance = {
+			let hue = color.to_hsla()[0] * 360.;
+			let distance = (hue - custom_hue_center as f32).rem_euclid(360.);
+			let distance = distance.min(360. - distance);
+			distance / (custom_hue_width as f32 / 2.).max(f32::EPSILON)
+		};
+
 		let pixel_color_range = |choice| match choice {
 			SelectiveColorChoice::Reds => max_channel == r,
 			SelectiveColorChoice::Yellows => min_channel == b,
@@ -1294,6 +1407,8 @@ async fn selective_color<T: Adjust<Color>>(
 			SelectiveColorChoice::Whites => r > 0.5 && g > 0.5 && b > 0.5,
 			SelectiveColorChoice::Neutrals => r > 0. && g > 0. && b > 0. && r < 1. && g < 1. && b < 1.,
 			SelectiveColorChoice::Blacks => r < 0.5 && g < 0.5 && b < 0.5,
+			// Grays have no hue, so they're never part of a hue range
+			SelectiveColorChoice::Custom => max_channel > min_channel && custom_hue_distance < 1.,
 		};
 
 		let color_parameter_group_scale_factor_rgb = max(r, g, b) - med(r, g, b);
@@ -1315,6 +1430,7 @@ async fn selective_color<T: Adjust<Color>>(
 			(SelectiveColorChoice::Whites, (w_c as f32, w_m as f32, w_y as f32, w_k as f32)),
 			(SelectiveColorChoice::Neutrals, (n_c as f32, n_m as f32, n_y as f32, n_k as f32)),
 			(SelectiveColorChoice::Blacks, (k_c as f32, k_m as f32, k_y as f32, k_k as f32)),
+			(SelectiveColorChoice::Custom, (u_c as f32, u_m as f32, u_y as f32, u_k as f32)),
 		]
 		.into_iter()
 		.fold((0., 0., 0.), |acc, (color_parameter_group, (c, m, y, k))| {
@@ -1333,6 +1449,8 @@ async fn selective_color<T: Adjust<Color>>(
 				SelectiveColorChoice::Whites => min(r, g, b) * 2. - 1.,
 				SelectiveColorChoice::Neutrals => 1. - ((max(r, g, b) - 0.5).abs() + (min(r, g, b) - 0.5).abs()),
 				SelectiveColorChoice::Blacks => 1. - max(r, g, b) * 2.,
+				// Scaled by saturation like the fixed hue groups, and feathered toward the edges of the range so it blends smoothly into the unaffected hues
+				SelectiveColorChoice::Custom => (max_channel - min_channel) * (1. - custom_hue_distance),
 			};
 
 			let offset_r = ((c + k * (c + 1.)) * slope_r).clamp(-r, -r + 1.) * color_parameter_group_scale_factor;
@@ -1349,6 +1467,241 @@ async fn selective_color<T: Adjust<Color>>(
 	image
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, DynAny)]
+pub enum TonalRange {
+	Shadows,
+	#[default]
+	Midtones,
+	Highlights,
+}
+
+impl core::fmt::Display for TonalRange {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			TonalRange::Shadows => write!(f, "Shadows"),
+			TonalRange::Midtones => write!(f, "Midtones"),
+			TonalRange::Highlights => write!(f, "Highlights"),
+		}
+	}
+}
+
+// Aims for interoperable compatibility with:
+// https://www.adobe.com/devnet-apps/photoshop/fileformatashtml/#:~:text=%27blnc%27%20%3D%20Color%20Balance
+//
+// Algorithm based on:
+// https://gitlab.gnome.org/GNOME/gimp/-/blob/master/app/operations/gimpoperationcolorbalance.c
+#[node_macro::node(category("Raster: Adjustment"), properties("color_balance_properties"))]
+async fn color_balance<T: Adjust<Color>>(
+	_: impl Ctx,
+	#[implementations(
+		Color,
+		ImageFrameTable<Color>,
+		GradientStops,
+		GraphicGroupTable,
+	)]
+	mut image: T,
+	_tonal_range: TonalRange,
+	#[name("(Shadows) Cyan–Red")] shadows_cyan_red: SignedPercentage,
+	#[name("(Shadows) Magenta–Green")] shadows_magenta_green: SignedPercentage,
+	#[name("(Shadows) Yellow–Blue")] shadows_yellow_blue: SignedPercentage,
+	#[name("(Midtones) Cyan–Red")] midtones_cyan_red: SignedPercentage,
+	#[name("(Midtones) Magenta–Green")] midtones_magenta_green: SignedPercentage,
+	#[name("(Midtones) Yellow–Blue")] midtones_yellow_blue: SignedPercentage,
+	#[name("(Highlights) Cyan–Red")] highlights_cyan_red: SignedPercentage,
+	#[name("(Highlights) Magenta–Green")] highlights_magenta_green: SignedPercentage,
+	#[name("(Highlights) Yellow–Blue")] highlights_yellow_blue: SignedPercentage,
+	#[default(true)] preserve_luminosity: bool,
+) -> T {
+	// The shift of each channel in the shadows, midtones, and highlights, from -1 (toward cyan, magenta, or yellow) to 1 (toward red, green, or blue)
+	let shifts = [
+		[shadows_cyan_red, midtones_cyan_red, highlights_cyan_red],
+		[shadows_magenta_green, midtones_magenta_green, highlights_magenta_green],
+		[shadows_yellow_blue, midtones_yellow_blue, highlights_yellow_blue],
+	]
+	.map(|shifts| shifts.map(|shift| shift as f32 / 100.));
+
+	image.adjust(|color| {
+		let color = color.to_gamma_srgb();
+		let (r, g, b, a) = color.components();
+		let lightness = color.to_hsla()[2];
+
+		// How strongly the pixel belongs to each tonal range, based on its lightness, with the ranges overlapping so they blend smoothly into each other
+		// Shadows fade into midtones around a lightness of one third, and midtones into highlights around two thirds, across a span of a quarter
+		const SPAN: f32 = 0.25;
+		const BOUNDARY: f32 = 0.333;
+		const SCALE: f32 = 0.7;
+		let weights = [
+			((lightness - BOUNDARY) / -SPAN + 0.5).clamp(0., 1.) * SCALE,
+			((lightness - BOUNDARY) / SPAN + 0.5).clamp(0., 1.) * ((lightness + BOUNDARY - 1.) / -SPAN + 0.5).clamp(0., 1.) * SCALE,
+			((lightness + BOUNDARY - 1.) / SPAN + 0.5).clamp(0., 1.) * SCALE,
+		];
+		let balance = |channel: f32, shifts: [f32; 3]| (channel + shifts.iter().zip(weights).map(|(shift, weight)| shift * weight).sum::<f32>()).clamp(0., 1.);
+
+		let balanced = Color::from_rgbaf32_unchecked(balance(r, shifts[0]), balance(g, shifts[1]), balance(b, shifts[2]), a);
+
+		// Keep the original lightness so only the hue and saturation of the pixel are changed
+		let balanced = if preserve_luminosity {
+			let [hue, saturation, _, alpha] = balanced.to_hsla();
+			Color::from_hsla(hue, saturation, lightness, alpha)
+		} else {
+			balanced
+		};
+
+		balanced.to_linear_srgb()
+	});
+	image
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, DynAny)]
+pub enum HueBand {
+	#[default]
+	Reds,
+	Oranges,
+	Yellows,
+	Greens,
+	Aquas,
+	Blues,
+	Purples,
+	Magentas,
+}
+
+impl HueBand {
+	pub const ALL: [HueBand; 8] = [
+		HueBand::Reds,
+		HueBand::Oranges,
+		HueBand::Yellows,
+		HueBand::Greens,
+		HueBand::Aquas,
+		HueBand::Blues,
+		HueBand::Purples,
+		HueBand::Magentas,
+	];
+
+	/// The hue (in degrees) at the center of each band, where the band has its full effect before fading into its neighbors.
+	const CENTERS: [f32; 8] = [0., 30., 60., 120., 180., 240., 270., 300.];
+
+	/// The two neighboring bands that a hue (from 0 to 1) falls between, with how strongly it belongs to each, where the two weights add up to 1.
+	fn weights(hue: f32) -> [(usize, f32); 2] {
+		let degrees = hue * 360.;
+		let index = Self::CENTERS.iter().rposition(|&center| center <= degrees).unwrap_or(0);
+		let next = (index + 1) % Self::CENTERS.len();
+		let next_center = if next == 0 { 360. } else { Self::CENTERS[next] };
+		let toward_next = ((degrees - Self::CENTERS[index]) / (next_center - Self::CENTERS[index])).clamp(0., 1.);
+		[(index, 1. - toward_next), (next, toward_next)]
+	}
+
+	/// The band that the hue of a color (with linear channels) belongs to the most.
+	pub fn from_color(color: Color) -> Self {
+		let [(index, weight), (next, _)] = Self::weights(color.to_gamma_srgb().to_hsla()[0]);
+		Self::ALL[if weight >= 0.5 { index } else { next }]
+	}
+
+	/// A vivid color (with linear channels) at the center of the band.
+	pub fn color(&self) -> Color {
+		Color::from_hsla(Self::CENTERS[*self as usize] / 360., 1., 0.5, 1.).to_linear_srgb()
+	}
+}
+
+impl core::fmt::Display for HueBand {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			HueBand::Reds => write!(f, "Reds"),
+			HueBand::Oranges => write!(f, "Oranges"),
+			HueBand::Yellows => write!(f, "Yellows"),
+			HueBand::Greens => write!(f, "Greens"),
+			HueBand::Aquas => write!(f, "Aquas"),
+			HueBand::Blues => write!(f, "Blues"),
+			HueBand::Purples => write!(f, "Purples"),
+			HueBand::Magentas => write!(f, "Magentas"),
+		}
+	}
+}
+
+// Aims for interoperable compatibility with the HSL panel of Lightroom, which splits the hues into the same eight overlapping bands
+#[node_macro::node(name("HSL"), category("Raster: Adjustment"), properties("hsl_properties"))]
+async fn hsl<T: Adjust<Color>>(
+	_: impl Ctx,
+	#[implementations(
+		Color,
+		ImageFrameTable<Color>,
+		GradientStops,
+		GraphicGroupTable,
+	)]
+	mut image: T,
+	_band: HueBand,
+	#[name("(Reds) Hue")] reds_hue: Angle,
+	#[name("(Reds) Saturation")] reds_saturation: SignedPercentage,
+	#[name("(Reds) Lightness")] reds_lightness: SignedPercentage,
+	#[name("(Oranges) Hue")] oranges_hue: Angle,
+	#[name("(Oranges) Saturation")] oranges_saturation: SignedPercentage,
+	#[name("(Oranges) Lightness")] oranges_lightness: SignedPercentage,
+	#[name("(Yellows) Hue")] yellows_hue: Angle,
+	#[name("(Yellows) Saturation")] yellows_saturation: SignedPercentage,
+	#[name("(Yellows) Lightness")] yellows_lightness: SignedPercentage,
+	#[name("(Greens) Hue")] greens_hue: Angle,
+	#[name("(Greens) Saturation")] greens_saturation: SignedPercentage,
+	#[name("(Greens) Lightness")] greens_lightness: SignedPercentage,
+	#[name("(Aquas) Hue")] aquas_hue: Angle,
+	#[name("(Aquas) Saturation")] aquas_saturation: SignedPercentage,
+	#[name("(Aquas) Lightness")] aquas_lightness: SignedPercentage,
+	#[name("(Blues) Hue")] blues_hue: Angle,
+	#[name("(Blues) Saturation")] blues_saturation: SignedPercentage,
+	#[name("(Blues) Lightness")] blues_lightness: SignedPercentage,
+	#[name("(Purples) Hue")] purples_hue: Angle,
+	#[name("(Purples) Saturation")] purples_saturation: SignedPercentage,
+	#[name("(Purples) Lightness")] purples_lightness: SignedPercentage,
+	#[name("(Magentas) Hue")] magentas_hue: Angle,
+	#[name("(Magentas) Saturation")] magentas_saturation: SignedPercentage,
+	#[name("(Magentas) Lightness")] magentas_lightness: SignedPercentage,
+) -> T {
+	// The hue shift (as a fraction of a turn), and the saturation and lightness shifts (from -1 to 1), of each band
+	let shifts = [
+		[reds_hue, reds_saturation, reds_lightness],
+		[oranges_hue, oranges_saturation, oranges_lightness],
+		[yellows_hue, yellows_saturation, yellows_lightness],
+		[greens_hue, greens_saturation, greens_lightness],
+		[aquas_hue, aquas_saturation, aquas_lightness],
+		[blues_hue, blues_saturation, blues_lightness],
+		[purples_hue, purples_saturation, purples_lightness],
+		[magentas_hue, magentas_saturation, magentas_lightness],
+	]
+	.map(|[hue, saturation, lightness]| [(hue / 360.).clamp(-0.5, 0.5) as f32, saturation as f32 / 100., lightness as f32 / 100.]);
+
+	image.adjust(|color| {
+		let color = color.to_gamma_srgb();
+		let (r, g, b, _) = color.components();
+		let [hue, saturation, lightness, alpha] = color.to_hsla();
+
+		// Grays have no hue of their own, so the adjustment fades out as the pixel's chroma approaches zero
+		let chroma = r.max(g).max(b) - r.min(g).min(b);
+		let mut shift = [0.; 3];
+		for (band, weight) in HueBand::weights(hue) {
+			for (total, band_shift) in shift.iter_mut().zip(shifts[band]) {
+				*total += band_shift * weight * chroma;
+			}
+		}
+		let [hue_shift, saturation_shift, lightness_shift] = shift;
+
+		// Positive shifts move the value toward its maximum and negative shifts toward zero, in proportion to the room left
+		let shift_toward = |value: f32, shift: f32| if shift >= 0. { value + (1. - value) * shift } else { value * (1. + shift) };
+
+		Color::from_hsla((hue + hue_shift + 1.) % 1., shift_toward(saturation, saturation_shift), shift_toward(lightness, lightness_shift), alpha).to_linear_srgb()
+	});
+	image
+}
+
+/// Simulates how the colors would look printed in CMYK under the printing condition, by separating them into inks and mixing those inks on the paper.
+/// The editor appends this to the end of the graph rendered in the viewport while soft-proofing, so it isn't offered to be placed by the user.
+#[node_macro::node(category(""))]
+async fn soft_proof<T: Adjust<Color>>(_: impl Ctx, #[implementations(GraphicGroupTable, ArtboardGroupTable)] mut content: T, print_profile: PrintProfile) -> T {
+	content.adjust(|color| print_profile.soft_proof(*color));
+	content
+}
+
 pub(super) trait MultiplyAlpha {
 	fn multiply_alpha(&mut self, factor: f64);
 }
@@ -1396,6 +1749,7 @@ async fn posterize<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut input: T,
 	#[default(4)]
@@ -1429,6 +1783,7 @@ async fn exposure<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut input: T,
 	exposure: f64,
@@ -1453,13 +1808,13 @@ async fn exposure<T: Adjust<Color>>(
 
 const WINDOW_SIZE: usize = 1024;
 
+/// Samples the curve at evenly spaced inputs from 0 to 1 to build a lookup table of its outputs.
 #[cfg(feature = "alloc")]
-#[node_macro::node(category(""))]
-fn generate_curves<C: Channel + crate::raster::Linear>(_: impl Ctx, curve: Curve, #[implementations(f32, f64)] _target_format: C) -> ValueMapperNode<C> {
+pub(crate) fn curve_lookup_table(curve: &Curve) -> Vec<f64> {
 	use bezier_rs::{Bezier, TValue};
 
 	let [mut pos, mut param]: [[f32; 2]; 2] = [[0.; 2], curve.first_handle];
-	let mut lut = vec![C::from_f64(0.); WINDOW_SIZE];
+	let mut lut = vec![0.; WINDOW_SIZE];
 	let end = CurveManipulatorGroup {
 		anchor: [1.; 2],
 		handles: [curve.last_handle, [0.; 2]],
@@ -1485,13 +1840,60 @@ fn generate_curves<C: Channel + crate::raster::Linear>(_: impl Ctx, curve: Curve
 					// Fall back to a very bad approximation if Bezier-rs fails
 					.unwrap_or_else(|| (x - x0) / (x3 - x0) * (y3 - y0) + y0)
 			};
-			lut[index] = C::from_f64(y);
+			lut[index] = y;
 		}
 
 		pos = sample.anchor;
 		param = sample.handles[1];
 	}
-	ValueMapperNode::new(lut)
+	lut
+}
+
+/// Looks up the output for an input in the range 0-1, interpolating between the two nearest samples of the lookup table.
+#[cfg(feature = "alloc")]
+pub(crate) fn sample_lookup_table(lut: &[f64], input: f32) -> f32 {
+	let floating_sample_index = input.clamp(0., 1.) * (lut.len() - 1) as f32;
+	let index = floating_sample_index.floor() as usize;
+	let [a, b] = [lut[index], lut[(index + 1).min(lut.len() - 1)]].map(|sample| sample as f32);
+	(a + (b - a) * floating_sample_index.fract()).clamp(0., 1.)
+}
+
+#[cfg(feature = "alloc")]
+#[node_macro::node(category(""))]
+fn generate_curves<C: Channel + crate::raster::Linear>(_: impl Ctx, curve: Curve, #[implementations(f32, f64)] _target_format: C) -> ValueMapperNode<C> {
+	ValueMapperNode::new(curve_lookup_table(&curve).into_iter().map(C::from_f64).collect())
+}
+
+// Aims for interoperable compatibility with:
+// https://www.adobe.com/devnet-apps/photoshop/fileformatashtml/#:~:text=levl%27%20%3D%20Levels-,%27curv%27%20%3D%20Curves,-%27expA%27%20%3D%20Exposure
+// https://www.adobe.com/devnet-apps/photoshop/fileformatashtml/#:~:text=Max%20input%20range-,Curves,-Curves%20settings%20files
+#[cfg(feature = "alloc")]
+#[node_macro::node(category("Raster: Adjustment"))]
+fn curves<T: Adjust<Color>>(
+	_: impl Ctx,
+	#[implementations(
+		Color,
+		ImageFrameTable<Color>,
+		GradientStops,
+		GraphicGroupTable,
+	)]
+	mut image: T,
+	curves: Curves,
+) -> T {
+	let composite = curve_lookup_table(&curves.composite);
+	let [red, green, blue] = [&curves.red, &curves.green, &curves.blue].map(curve_lookup_table);
+
+	image.adjust(|color| {
+		let color = color.to_gamma_srgb();
+
+		let (r, g, b, a) = color.components();
+
+		// Each channel's own curve is applied first, then the composite curve is applied to the result
+		let [r, g, b] = [(r, &red), (g, &green), (b, &blue)].map(|(channel, lut)| sample_lookup_table(&composite, sample_lookup_table(lut, channel)));
+
+		Color::from_rgbaf32_unchecked(r, g, b, a).to_linear_srgb()
+	});
+	image
 }
 
 #[cfg(feature = "alloc")]
@@ -1502,6 +1904,7 @@ fn color_overlay<T: Adjust<Color>>(
 		Color,
 		ImageFrameTable<Color>,
 		GradientStops,
+		GraphicGroupTable,
 	)]
 	mut image: T,
 	#[default(Color::BLACK)] color: Color,
@@ -1550,8 +1953,11 @@ fn color_overlay<T: Adjust<Color>>(
 #[cfg(test)]
 mod test {
 	use crate::raster::adjustments::BlendMode;
+	use crate::raster::curve::{CurveManipulatorGroup, Curves};
 	use crate::raster::image::{Image, ImageFrameTable};
-	use crate::{Color, Node};
+	use crate::vector::style::Fill;
+	use crate::vector::{VectorData, VectorDataTable};
+	use crate::{Color, GraphicElement, GraphicGroupTable, Node, RasterFrame};
 	use std::pin::Pin;
 
 	#[derive(Clone)]
@@ -1582,4 +1988,142 @@ mod test {
 		// The output should just be the original green and alpha channels (as we multiply them by 1 and other channels by 0)
 		assert_eq!(result.data[0], Color::from_rgbaf32_unchecked(0., image_color.g(), 0., image_color.a()));
 	}
+
+	#[tokio::test]
+	async fn invert_graphic_group() {
+		let mut vector_data = VectorData::default();
+		vector_data.style.set_fill(Fill::Solid(Color::RED));
+		let image = Image::new(1, 1, Color::BLACK);
+
+		let mut nested_group = GraphicGroupTable::default();
+		nested_group.push(GraphicElement::VectorData(VectorDataTable::new(vector_data)));
+		let mut graphic_group = GraphicGroupTable::new(GraphicElement::GraphicGroup(nested_group));
+		graphic_group.push(GraphicElement::RasterFrame(RasterFrame::ImageFrame(ImageFrameTable::new(image))));
+
+		let result = super::invert((), graphic_group).await;
+		let mut elements = result.instances();
+
+		// Elements nested inside other groups are also adjusted
+		let GraphicElement::GraphicGroup(nested_group) = elements.next().unwrap().instance else {
+			panic!("Expected a group")
+		};
+		let GraphicElement::VectorData(vector_data) = nested_group.instances().next().unwrap().instance else {
+			panic!("Expected vector data")
+		};
+		assert_eq!(vector_data.instances().next().unwrap().instance.style.fill(), &Fill::Solid(super::invert((), Color::RED).await));
+
+		let GraphicElement::RasterFrame(RasterFrame::ImageFrame(image)) = elements.next().unwrap().instance else {
+			panic!("Expected an image")
+		};
+		assert_eq!(image.instances().next().unwrap().instance.data[0], super::invert((), Color::BLACK).await);
+	}
+
+	#[test]
+	fn levels_per_channel() {
+		let color = Color::from_rgbaf32_unchecked(0.2, 0.2, 0.2, 1.);
+
+		// Raise the black point of only the green channel, which darkens it
+		let result = super::levels((), color, 10., 50., 100., 0., 100., super::LevelsChannel::Green);
+
+		assert!(result.g() < color.g());
+		assert!((result.r() - color.r()).abs() < 1e-3);
+		assert!((result.b() - color.b()).abs() < 1e-3);
+		assert_eq!(result.a(), color.a());
+	}
+
+	#[test]
+	fn levels_midtones_reach_target() {
+		for (level, target) in [(0.25, 0.5), (0.7, 0.4), (0.5, 0.5)] {
+			let midtones = crate::raster::histogram::levels_midtones(level, target) * 100.;
+			let color = Color::from_rgbaf32_unchecked(level as f32, 0., 0., 1.).to_linear_srgb();
+
+			let result = super::levels((), color, 0., midtones, 100., 0., 100., super::LevelsChannel::Composite);
+
+			assert!((result.to_gamma_srgb().r() - target as f32).abs() < 1e-3);
+		}
+	}
+
+	#[test]
+	fn curves_per_channel() {
+		let color = Color::from_rgbaf32_unchecked(0.2, 0.2, 0.2, 1.);
+
+		// Raise the midtones of only the red channel
+		let mut curves = Curves::default();
+		curves.red.manipulator_groups.push(CurveManipulatorGroup {
+			anchor: [0.5, 0.75],
+			handles: [[0.4, 0.65], [0.6, 0.85]],
+		});
+
+		let result = super::curves((), color, curves);
+
+		assert!(result.r() > color.r() + 0.1);
+		// The default composite, green, and blue curves leave the other channels unchanged
+		assert!((result.g() - color.g()).abs() < 1e-3);
+		assert!((result.b() - color.b()).abs() < 1e-3);
+		assert_eq!(result.a(), color.a());
+	}
+
+	#[tokio::test]
+	async fn hsl_bands() {
+		let orange = Color::from_rgbaf32_unchecked(1., 0.5, 0., 1.).to_linear_srgb();
+		assert_eq!(super::HueBand::from_color(orange), super::HueBand::Oranges);
+
+		// Fully desaturate the oranges
+		let adjust = |color| {
+			super::hsl(
+				(),
+				color,
+				super::HueBand::Oranges,
+				0.,
+				0.,
+				0.,
+				0.,
+				-100.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+				0.,
+			)
+		};
+
+		// The oranges become gray, while blues are outside the band and stay as they are
+		let result = adjust(orange).await.to_gamma_srgb();
+		assert!((result.r() - result.b()).abs() < 1e-3);
+		let blue = Color::from_rgbaf32_unchecked(0., 0., 1., 1.);
+		let result = adjust(blue).await;
+		assert!((result.b() - 1.).abs() < 1e-3 && result.r() < 1e-3);
+	}
+
+	#[tokio::test]
+	async fn color_balance_midtones() {
+		let gray = Color::from_rgbaf32_unchecked(0.5, 0.5, 0.5, 1.).to_linear_srgb();
+		let balance = |preserve_luminosity| super::color_balance((), gray, super::TonalRange::Midtones, 0., 0., 0., 50., 0., 0., 0., 0., 0., preserve_luminosity);
+
+		// Shifting the midtones toward red only raises the red channel of a mid gray
+		let result = balance(false).await.to_gamma_srgb();
+		assert!(result.r() > 0.6);
+		assert!((result.g() - 0.5).abs() < 1e-3);
+		assert!((result.b() - 0.5).abs() < 1e-3);
+
+		// Preserving luminosity keeps its lightness while still tinting it red
+		let result = balance(true).await.to_gamma_srgb();
+		assert!((result.to_hsla()[2] - 0.5).abs() < 1e-3);
+		assert!(result.r() > result.g() + 0.1);
+		assert!((result.g() - result.b()).abs() < 1e-3);
+	}
 }
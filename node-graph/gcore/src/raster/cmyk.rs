@@ -0,0 +1,202 @@
+use super::Color;
+use dyn_any::DynAny;
+
+/// A color as the coverage of cyan, magenta, yellow, and black ink, each from 0 (no ink) to 1 (solid ink), as it's stored in a file sent to print.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cmyk {
+	pub cyan: f32,
+	pub magenta: f32,
+	pub yellow: f32,
+	pub black: f32,
+}
+
+impl Cmyk {
+	/// The sum of the four ink coverages, which a press can only put down up to its total ink limit.
+	pub fn total_ink(&self) -> f32 {
+		self.cyan + self.magenta + self.yellow + self.black
+	}
+
+	pub fn to_array(&self) -> [f32; 4] {
+		[self.cyan, self.magenta, self.yellow, self.black]
+	}
+}
+
+/// The printing condition that a CMYK document is separated into inks for, and that its colors are simulated with when soft-proofing.
+///
+/// Rather than reading ICC profiles, each condition is characterized by the measurements such a profile is built from:
+/// the color of the bare paper, the color of each solid ink printed on it, how much the printed dots spread (dot gain), and the total ink limit.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, DynAny)]
+pub enum PrintProfile {
+	/// Offset printing on coated paper, similar to the FOGRA39 and GRACoL conditions.
+	#[default]
+	Coated,
+	/// Offset printing on uncoated paper, similar to the FOGRA29 condition.
+	Uncoated,
+	/// Cold-set web printing on newsprint, similar to the IFRA26 condition.
+	Newsprint,
+}
+
+/// The measured appearance of a printing condition, with colors in gamma-encoded sRGB.
+struct PrintCondition {
+	paper: [f32; 3],
+	/// The color of solid cyan, magenta, yellow, and black ink printed on the paper.
+	inks: [[f32; 3]; 4],
+	/// How much the coverage of a 50% dot grows when printed.
+	dot_gain: f32,
+	/// The most ink, as the sum of the four coverages, which the press can put down in one spot.
+	total_ink_limit: f32,
+}
+
+impl PrintProfile {
+	pub const ALL: [PrintProfile; 3] = [PrintProfile::Coated, PrintProfile::Uncoated, PrintProfile::Newsprint];
+
+	fn condition(&self) -> PrintCondition {
+		match self {
+			PrintProfile::Coated => PrintCondition {
+				paper: [0.97, 0.97, 0.96],
+				inks: [[0., 0.62, 0.88], [0.9, 0., 0.49], [1., 0.93, 0.], [0.14, 0.12, 0.13]],
+				dot_gain: 0.15,
+				total_ink_limit: 3.3,
+			},
+			PrintProfile::Uncoated => PrintCondition {
+				paper: [0.96, 0.95, 0.93],
+				inks: [[0.25, 0.6, 0.8], [0.85, 0.3, 0.55], [1., 0.92, 0.35], [0.25, 0.24, 0.24]],
+				dot_gain: 0.22,
+				total_ink_limit: 3.,
+			},
+			PrintProfile::Newsprint => PrintCondition {
+				paper: [0.86, 0.85, 0.8],
+				inks: [[0.35, 0.6, 0.75], [0.8, 0.4, 0.55], [0.95, 0.88, 0.45], [0.3, 0.3, 0.3]],
+				dot_gain: 0.3,
+				total_ink_limit: 2.4,
+			},
+		}
+	}
+
+	/// The ink coverages that print the color (with linear channels) under this condition.
+	///
+	/// The color is separated with black replacing the gray shared by all three channels, then the cyan, magenta, and yellow are reduced to stay within the total ink limit,
+	/// and finally each coverage is lowered to compensate for the dot gain the press will add back.
+	pub fn separate(&self, color: Color) -> Cmyk {
+		let condition = self.condition();
+		let [red, green, blue] = {
+			let color = color.to_gamma_srgb();
+			[color.r(), color.g(), color.b()].map(|channel| channel.clamp(0., 1.))
+		};
+
+		let black = 1. - red.max(green).max(blue);
+		let [mut cyan, mut magenta, mut yellow] = if black < 1. {
+			[red, green, blue].map(|channel| (1. - channel - black) / (1. - black))
+		} else {
+			[0.; 3]
+		};
+
+		let colored_ink = cyan + magenta + yellow;
+		if colored_ink + black > condition.total_ink_limit && colored_ink > 0. {
+			let scale = ((condition.total_ink_limit - black) / colored_ink).max(0.);
+			cyan *= scale;
+			magenta *= scale;
+			yellow *= scale;
+		}
+
+		let [cyan, magenta, yellow, black] = [cyan, magenta, yellow, black].map(|coverage| condition.compensate_dot_gain(coverage));
+		Cmyk { cyan, magenta, yellow, black }
+	}
+
+	/// The color (with linear channels) that the ink coverages appear as when printed under this condition.
+	///
+	/// Each ink filters the light reflected by the paper beneath it in proportion to the area its dots cover after the dot gain.
+	pub fn appearance(&self, cmyk: Cmyk) -> Color {
+		let condition = self.condition();
+		let paper = condition.paper.map(Color::srgb_to_linear);
+
+		let mut reflected = paper;
+		for (coverage, ink) in cmyk.to_array().into_iter().zip(condition.inks) {
+			let coverage = condition.gain(coverage.clamp(0., 1.));
+			for channel in 0..3 {
+				let transmittance = (Color::srgb_to_linear(ink[channel]) / paper[channel]).min(1.);
+				reflected[channel] *= 1. - coverage * (1. - transmittance);
+			}
+		}
+
+		Color::from_rgbaf32_unchecked(reflected[0], reflected[1], reflected[2], 1.)
+	}
+
+	/// The color (with linear channels) simulating how the color would look printed under this condition, keeping its alpha.
+	pub fn soft_proof(&self, color: Color) -> Color {
+		self.appearance(self.separate(color)).with_alpha(color.a())
+	}
+}
+
+impl PrintCondition {
+	/// The coverage of a dot after it spreads on the press, which grows the most at 50% and not at all for bare paper or solid ink.
+	fn gain(&self, coverage: f32) -> f32 {
+		coverage + 4. * self.dot_gain * coverage * (1. - coverage)
+	}
+
+	/// The coverage which grows to the given coverage once printed, undoing [`Self::gain`].
+	fn compensate_dot_gain(&self, printed: f32) -> f32 {
+		if self.dot_gain <= 0. {
+			return printed;
+		}
+		let spread = 1. + 4. * self.dot_gain;
+		(spread - (spread * spread - 16. * self.dot_gain * printed).max(0.).sqrt()) / (8. * self.dot_gain)
+	}
+}
+
+impl core::fmt::Display for PrintProfile {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			PrintProfile::Coated => write!(f, "Coated"),
+			PrintProfile::Uncoated => write!(f, "Uncoated"),
+			PrintProfile::Newsprint => write!(f, "Newsprint"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn dot_gain_compensation_round_trips() {
+		let condition = PrintProfile::Uncoated.condition();
+		for coverage in [0., 0.1, 0.5, 0.9, 1.] {
+			assert!((condition.gain(condition.compensate_dot_gain(coverage)) - coverage).abs() < 1e-5);
+		}
+	}
+
+	#[test]
+	fn separation_limits_total_ink() {
+		let profile = PrintProfile::Newsprint;
+
+		let white = profile.separate(Color::WHITE);
+		assert!(white.total_ink() < 1e-5);
+
+		let black = profile.separate(Color::BLACK);
+		assert!((black.black - 1.).abs() < 1e-5);
+		assert!(black.cyan.max(black.magenta).max(black.yellow) < 1e-5);
+
+		// A dark brown needs more ink than newsprint can take, so its colored inks are reduced
+		let brown = profile.separate(Color::from_rgbaf32_unchecked(0.2, 0.05, 0., 1.).to_linear_srgb());
+		let condition = profile.condition();
+		let printed = [brown.cyan, brown.magenta, brown.yellow, brown.black].map(|coverage| condition.gain(coverage));
+		assert!(printed.iter().sum::<f32>() <= condition.total_ink_limit + 1e-4);
+	}
+
+	#[test]
+	fn soft_proof_dulls_vivid_colors() {
+		let red = Color::from_rgbaf32_unchecked(1., 0., 0., 0.5);
+		let proofed = PrintProfile::Uncoated.soft_proof(red);
+		assert_eq!(proofed.a(), 0.5);
+		assert!(proofed.r() < red.r());
+		assert!(proofed.g() > red.g());
+
+		// Paper white is darker than the white of the screen
+		let paper = PrintProfile::Newsprint.soft_proof(Color::WHITE);
+		assert!(paper.r() < 1. && paper.b() < paper.r());
+	}
+}
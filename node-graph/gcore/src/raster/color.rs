@@ -876,6 +876,84 @@ impl Color {
 		[hue, saturation, lightness, self.alpha]
 	}
 
+	// https://bottosson.github.io/posts/oklab/
+	/// Convert a [Color] with linear channels to the perceptual OKLab color space, returning its lightness, green-red axis, blue-yellow axis, and alpha.
+	pub fn to_oklab(&self) -> [f32; 4] {
+		let long = (0.4122214708 * self.red + 0.5363325363 * self.green + 0.0514459929 * self.blue).cbrt();
+		let medium = (0.2119034982 * self.red + 0.6806995451 * self.green + 0.1073969566 * self.blue).cbrt();
+		let short = (0.0883024619 * self.red + 0.2817188376 * self.green + 0.6299787005 * self.blue).cbrt();
+
+		[
+			0.2104542553 * long + 0.7936177850 * medium - 0.0040720468 * short,
+			1.9779984951 * long - 2.4285922050 * medium + 0.4505937099 * short,
+			0.0259040371 * long + 0.7827717662 * medium - 0.8086757660 * short,
+			self.alpha,
+		]
+	}
+
+	/// Creates a color with linear channels from OKLab lightness, green-red axis, blue-yellow axis, and alpha, clamping it into the sRGB gamut.
+	pub fn from_oklab(lightness: f32, a: f32, b: f32, alpha: f32) -> Color {
+		let long = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+		let medium = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+		let short = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+		Color::from_rgbaf32_unchecked(
+			(4.0767416621 * long - 3.3077115913 * medium + 0.2309699292 * short).clamp(0., 1.),
+			(-1.2684380046 * long + 2.6097574011 * medium - 0.3413193965 * short).clamp(0., 1.),
+			(-0.0041960863 * long - 0.7034186147 * medium + 1.7076147010 * short).clamp(0., 1.),
+			alpha,
+		)
+	}
+
+	/// Interpolates between two colors with linear channels in the OKLab color space, which keeps the lightness even and avoids the muddy midtones of mixing RGB channels.
+	///
+	/// T must be between 0 and 1.
+	pub fn lerp_oklab(&self, other: &Color, t: f32) -> Self {
+		let [start, end] = [self.to_oklab(), other.to_oklab()];
+		let [lightness, a, b, alpha] = core::array::from_fn(|index| start[index] + (end[index] - start[index]) * t);
+		Color::from_oklab(lightness, a, b, alpha)
+	}
+
+	/// Convert a [Color] with linear channels to OKLCH, the polar form of OKLab, returning its lightness, chroma, hue (as a fraction of a full turn), and alpha.
+	pub fn to_oklch(&self) -> [f32; 4] {
+		let [lightness, a, b, alpha] = self.to_oklab();
+		let chroma = (a * a + b * b).sqrt();
+		#[cfg(not(target_arch = "spirv"))]
+		let hue = (b.atan2(a) / core::f32::consts::TAU).rem_euclid(1.);
+		#[cfg(target_arch = "spirv")]
+		let hue = (b.atan2(a) / core::f32::consts::TAU).rem_euclid(&1.);
+
+		[lightness, chroma, hue, alpha]
+	}
+
+	/// Creates a color with linear channels from OKLCH lightness, chroma, hue (as a fraction of a full turn), and alpha, clamping it into the sRGB gamut.
+	pub fn from_oklch(lightness: f32, chroma: f32, hue: f32, alpha: f32) -> Color {
+		let angle = hue * core::f32::consts::TAU;
+		Color::from_oklab(lightness, chroma * angle.cos(), chroma * angle.sin(), alpha)
+	}
+
+	/// Interpolates between two colors with linear channels in the OKLCH color space, which sweeps the hue around the color wheel the shorter way so the colors between stay as vivid as the ends.
+	///
+	/// T must be between 0 and 1.
+	pub fn lerp_oklch(&self, other: &Color, t: f32) -> Self {
+		let [start, end] = [self.to_oklch(), other.to_oklch()];
+
+		// A gray has no hue of its own, so it takes on the hue of the other color rather than sweeping from red
+		const ACHROMATIC: f32 = 1e-4;
+		let start_hue = if start[1] < ACHROMATIC { end[2] } else { start[2] };
+		let end_hue = if end[1] < ACHROMATIC { start_hue } else { end[2] };
+
+		let mut hue_difference = end_hue - start_hue;
+		if hue_difference > 0.5 {
+			hue_difference -= 1.;
+		} else if hue_difference < -0.5 {
+			hue_difference += 1.;
+		}
+
+		let lerp = |index: usize| start[index] + (end[index] - start[index]) * t;
+		Color::from_oklch(lerp(0), lerp(1), start_hue + hue_difference * t, lerp(3))
+	}
+
 	// TODO: Readd formatting
 
 	/// Creates a color from a 8-character RGBA hex string (without a # prefix).
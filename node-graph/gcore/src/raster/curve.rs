@@ -24,6 +24,41 @@ impl Default for Curve {
 	}
 }
 
+#[cfg(feature = "alloc")]
+impl Curve {
+	/// The output of the curve for an input in the range 0-1, for when only a few values are needed rather than a whole lookup table of them.
+	pub fn evaluate(&self, input: f64) -> f64 {
+		use bezier_rs::{Bezier, TValue};
+
+		let x = input.clamp(0., 1.);
+		let [mut pos, mut param]: [[f32; 2]; 2] = [[0.; 2], self.first_handle];
+		let end = CurveManipulatorGroup {
+			anchor: [1.; 2],
+			handles: [self.last_handle, [0.; 2]],
+		};
+		for sample in self.manipulator_groups.iter().chain(core::iter::once(&end)) {
+			if x <= sample.anchor[0] as f64 {
+				let [x0, y0, x1, y1, x2, y2, x3, y3] = [pos[0], pos[1], param[0], param[1], sample.handles[0][0], sample.handles[0][1], sample.anchor[0], sample.anchor[1]].map(f64::from);
+				if x <= x0 || x3 <= x0 {
+					return y0.clamp(0., 1.);
+				}
+
+				let bezier = Bezier::from_cubic_coordinates(x0, y0, x1, y1, x2, y2, x3, y3);
+				let y = bezier
+					.find_tvalues_for_x(x)
+					.next()
+					.map(|t| bezier.evaluate(TValue::Parametric(t.clamp(0., 1.))).y)
+					.unwrap_or_else(|| (x - x0) / (x3 - x0) * (y3 - y0) + y0);
+				return y.clamp(0., 1.);
+			}
+
+			pos = sample.anchor;
+			param = sample.handles[1];
+		}
+		1.
+	}
+}
+
 impl std::hash::Hash for Curve {
 	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
 		self.manipulator_groups.hash(state);
@@ -31,6 +66,58 @@ impl std::hash::Hash for Curve {
 	}
 }
 
+/// One of the curves of the [`Curves`] adjustment: the composite curve which affects all color channels, or a curve for an individual channel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, DynAny, specta::Type)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CurveChannel {
+	#[default]
+	Composite,
+	Red,
+	Green,
+	Blue,
+}
+
+impl core::fmt::Display for CurveChannel {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			CurveChannel::Composite => write!(f, "RGB"),
+			CurveChannel::Red => write!(f, "Red"),
+			CurveChannel::Green => write!(f, "Green"),
+			CurveChannel::Blue => write!(f, "Blue"),
+		}
+	}
+}
+
+/// The curves of the Curves adjustment, with a composite curve applied to every color channel after each channel's own curve.
+#[derive(Debug, Clone, Default, PartialEq, Hash, DynAny, specta::Type)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Curves {
+	pub composite: Curve,
+	pub red: Curve,
+	pub green: Curve,
+	pub blue: Curve,
+}
+
+impl Curves {
+	pub fn curve(&self, channel: CurveChannel) -> &Curve {
+		match channel {
+			CurveChannel::Composite => &self.composite,
+			CurveChannel::Red => &self.red,
+			CurveChannel::Green => &self.green,
+			CurveChannel::Blue => &self.blue,
+		}
+	}
+
+	pub fn curve_mut(&mut self, channel: CurveChannel) -> &mut Curve {
+		match channel {
+			CurveChannel::Composite => &mut self.composite,
+			CurveChannel::Red => &mut self.red,
+			CurveChannel::Green => &mut self.green,
+			CurveChannel::Blue => &mut self.blue,
+		}
+	}
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, DynAny, specta::Type)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CurveManipulatorGroup {
@@ -205,3 +292,31 @@ where
 		val
 	}
 }
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+	use super::*;
+
+	#[test]
+	fn default_curve_is_identity() {
+		let curve = Curve::default();
+		for input in [0., 0.25, 0.5, 0.9, 1.] {
+			assert!((curve.evaluate(input) - input).abs() < 1e-3, "{input} mapped to {}", curve.evaluate(input));
+		}
+	}
+
+	#[test]
+	fn evaluate_passes_through_anchors() {
+		let curve = Curve {
+			manipulator_groups: vec![CurveManipulatorGroup {
+				anchor: [0.5, 0.2],
+				handles: [[0.4, 0.15], [0.6, 0.25]],
+			}],
+			..Default::default()
+		};
+		assert!((curve.evaluate(0.5) - 0.2).abs() < 1e-3);
+		assert!(curve.evaluate(0.25) < 0.25);
+		assert_eq!(curve.evaluate(-1.), 0.);
+		assert_eq!(curve.evaluate(2.), 1.);
+	}
+}
@@ -0,0 +1,272 @@
+use crate::raster::adjustments::LevelsChannel;
+use crate::raster::color::Color;
+use crate::raster::image::Image;
+
+/// The number of bins in each channel of a [`Histogram`], one for every 8-bit level.
+pub const HISTOGRAM_BINS: usize = 256;
+
+/// The fraction of the pixels at each end of a channel which is allowed to become pure black or white when an automatic adjustment finds its black and white points,
+/// so a few stray pixels don't keep the rest of the image from being stretched.
+pub const AUTO_ADJUSTMENT_CLIP: f64 = 0.001;
+
+/// The tonal distribution of an image, counting how many pixels fall into each brightness level for the red, green, blue, and luminance channels.
+/// Levels are measured in gamma (sRGB) space with unassociated alpha, which matches how channel values are presented to the user.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+	pub red: Vec<u32>,
+	pub green: Vec<u32>,
+	pub blue: Vec<u32>,
+	pub luminance: Vec<u32>,
+}
+
+impl Default for Histogram {
+	fn default() -> Self {
+		Self {
+			red: vec![0; HISTOGRAM_BINS],
+			green: vec![0; HISTOGRAM_BINS],
+			blue: vec![0; HISTOGRAM_BINS],
+			luminance: vec![0; HISTOGRAM_BINS],
+		}
+	}
+}
+
+impl Histogram {
+	/// Computes the histogram of an image, sampling at most roughly `max_samples` evenly distributed pixels to remain fast for large images.
+	pub fn from_image(image: &Image<Color>, max_samples: usize) -> Self {
+		let mut histogram = Self::default();
+		histogram.accumulate(image, max_samples);
+		histogram
+	}
+
+	/// Adds the pixels of another image to this histogram, sampling at most roughly `max_samples` evenly distributed pixels.
+	/// Fully transparent pixels are skipped since they don't contribute any visible color.
+	pub fn accumulate(&mut self, image: &Image<Color>, max_samples: usize) {
+		let pixel_count = image.data.len();
+		if pixel_count == 0 {
+			return;
+		}
+
+		let stride = pixel_count.div_ceil(max_samples.max(1)).max(1);
+		for pixel in image.data.iter().step_by(stride) {
+			if pixel.a() <= 0. {
+				continue;
+			}
+
+			let color = pixel.to_unassociated_alpha().to_gamma_srgb();
+			self.red[Self::bin(color.r())] += 1;
+			self.green[Self::bin(color.g())] += 1;
+			self.blue[Self::bin(color.b())] += 1;
+			self.luminance[Self::bin(color.luminance_rec_601())] += 1;
+		}
+	}
+
+	/// The number of pixels which were counted in the histogram.
+	pub fn sample_count(&self) -> u32 {
+		self.luminance.iter().sum()
+	}
+
+	/// Scales the bin counts of a channel to the 0 to 1 range relative to the tallest bin among all channels, suitable for display.
+	pub fn normalized(&self, channel: &[u32]) -> Vec<f64> {
+		let tallest = [&self.red, &self.green, &self.blue, &self.luminance].into_iter().flatten().copied().max().unwrap_or_default().max(1);
+		channel.iter().map(|&count| count as f64 / tallest as f64).collect()
+	}
+
+	/// The levels from 0 to 1 which have no more than the `clip` fraction of the counted pixels below and above them in the channel, or `None` if no pixels were counted.
+	pub fn clipped_range(channel: &[u32], clip: f64) -> Option<(f64, f64)> {
+		let total = channel.iter().map(|&count| count as u64).sum::<u64>();
+		if total == 0 {
+			return None;
+		}
+		let clipped = (total as f64 * clip.clamp(0., 0.5)) as u64;
+		let level = |bin: usize| bin as f64 / (HISTOGRAM_BINS - 1) as f64;
+
+		// The number of bins skipped from one end before more than the clipped pixels have been counted
+		let skipped_from_end = |bins: &mut dyn Iterator<Item = u32>| {
+			let (mut passed, mut skipped) = (0, 0);
+			for count in bins {
+				passed += count as u64;
+				if passed > clipped {
+					break;
+				}
+				skipped += 1;
+			}
+			skipped
+		};
+		let black = skipped_from_end(&mut channel.iter().copied());
+		let white = channel.len() - 1 - skipped_from_end(&mut channel.iter().rev().copied());
+		Some((level(black), level(white.max(black))))
+	}
+
+	/// The mean level of a channel once its range between the black and white points is stretched to fill the range from 0 to 1, or `None` if no pixels were counted.
+	pub fn stretched_mean(channel: &[u32], black: f64, white: f64) -> Option<f64> {
+		let total = channel.iter().map(|&count| count as u64).sum::<u64>();
+		if total == 0 {
+			return None;
+		}
+		let range = (white - black).max(f64::EPSILON);
+		let sum = channel
+			.iter()
+			.enumerate()
+			.map(|(bin, &count)| ((bin as f64 / (HISTOGRAM_BINS - 1) as f64 - black) / range).clamp(0., 1.) * count as f64)
+			.sum::<f64>();
+		Some(sum / total as f64)
+	}
+
+	fn bin(value: f32) -> usize {
+		((value.clamp(0., 1.) * (HISTOGRAM_BINS - 1) as f32).round() as usize).min(HISTOGRAM_BINS - 1)
+	}
+}
+
+/// A one-click adjustment which analyzes the histogram of an image to choose the settings of Levels nodes that improve its tonal range.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AutoAdjustment {
+	/// Stretches the tones of all the channels together so the darkest become black and the lightest become white, without changing the hues.
+	Contrast,
+	/// Stretches the tones of each channel on its own, which also removes color casts from the shadows and highlights.
+	Levels,
+	/// Stretches each channel like [`AutoAdjustment::Levels`], and also balances the midtones of the channels to neutralize a color cast throughout.
+	Color,
+}
+
+/// The input range chosen for one Levels node by an [`AutoAdjustment`], with levels given as percentages like the node's inputs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoLevels {
+	pub channel: LevelsChannel,
+	pub shadows: f64,
+	pub midtones: f64,
+	pub highlights: f64,
+}
+
+impl AutoAdjustment {
+	pub fn list() -> [AutoAdjustment; 3] {
+		[AutoAdjustment::Contrast, AutoAdjustment::Levels, AutoAdjustment::Color]
+	}
+
+	pub fn tooltip(&self) -> &'static str {
+		match self {
+			AutoAdjustment::Contrast => "Stretch the tones of all the channels together so the darkest become black and the lightest become white, keeping the hues unchanged",
+			AutoAdjustment::Levels => "Stretch the tones of the red, green, and blue channels each on their own, which also removes color casts from the shadows and highlights",
+			AutoAdjustment::Color => "Stretch the tones of each channel and balance their midtones to neutralize a color cast throughout the image",
+		}
+	}
+
+	/// The settings of the Levels nodes which make up this adjustment for an image with the histogram, or nothing if it has no visible pixels.
+	pub fn levels(&self, histogram: &Histogram) -> Vec<AutoLevels> {
+		let percentage = |level: f64| level * 100.;
+		let channels = [(LevelsChannel::Red, &histogram.red), (LevelsChannel::Green, &histogram.green), (LevelsChannel::Blue, &histogram.blue)];
+
+		if *self == AutoAdjustment::Contrast {
+			// The channels are combined rather than using the luminance, so that no single channel of a saturated color gets clipped
+			let combined = (0..HISTOGRAM_BINS).map(|bin| histogram.red[bin] + histogram.green[bin] + histogram.blue[bin]).collect::<Vec<_>>();
+			let Some((black, white)) = Histogram::clipped_range(&combined, AUTO_ADJUSTMENT_CLIP) else {
+				return Vec::new();
+			};
+			return vec![AutoLevels {
+				channel: LevelsChannel::Composite,
+				shadows: percentage(black),
+				midtones: 50.,
+				highlights: percentage(white),
+			}];
+		}
+
+		let Some(ranges) = channels
+			.iter()
+			.map(|(_, bins)| {
+				let (black, white) = Histogram::clipped_range(bins, AUTO_ADJUSTMENT_CLIP)?;
+				Some((black, white, Histogram::stretched_mean(bins, black, white)?))
+			})
+			.collect::<Option<Vec<_>>>()
+		else {
+			return Vec::new();
+		};
+		let neutral = ranges.iter().map(|(_, _, mean)| mean).sum::<f64>() / ranges.len() as f64;
+
+		channels
+			.iter()
+			.zip(ranges)
+			.map(|((channel, _), (black, white, mean))| AutoLevels {
+				channel: *channel,
+				shadows: percentage(black),
+				midtones: if *self == AutoAdjustment::Color { percentage(levels_midtones(mean, neutral)) } else { 50. },
+				highlights: percentage(white),
+			})
+			.collect()
+	}
+}
+
+/// The midtones level, from 0 to 1, at which the Levels node (with its full input and output ranges) moves the given level to the target level, undoing how the node turns its midtones into a gamma.
+pub fn levels_midtones(level: f64, target: f64) -> f64 {
+	if level <= 0. || level >= 1. || target <= 0. || target >= 1. {
+		return 0.5;
+	}
+	// The Levels node raises each level to the power of one over the gamma
+	let gamma = (level.ln() / target.ln()).clamp(0.01, 10.);
+	if gamma > 1. { (1. - (gamma - 1.) / 9.) / 2. } else { 1. - gamma / 2. }
+}
+
+impl core::fmt::Display for AutoAdjustment {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			AutoAdjustment::Contrast => write!(f, "Auto Contrast"),
+			AutoAdjustment::Levels => write!(f, "Auto Levels"),
+			AutoAdjustment::Color => write!(f, "Auto Color"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn histogram_counts() {
+		let mut image = Image::new(2, 2, Color::BLACK);
+		image.data[0] = Color::WHITE;
+		image.data[1] = Color::TRANSPARENT;
+
+		let histogram = Histogram::from_image(&image, usize::MAX);
+		assert_eq!(histogram.sample_count(), 3);
+		assert_eq!(histogram.red[0], 2);
+		assert_eq!(histogram.red[HISTOGRAM_BINS - 1], 1);
+		assert_eq!(histogram.luminance[HISTOGRAM_BINS - 1], 1);
+		assert_eq!(histogram.normalized(&histogram.green)[0], 1.);
+	}
+
+	#[test]
+	fn histogram_downsampling() {
+		let image = Image::new(100, 100, Color::WHITE);
+
+		let histogram = Histogram::from_image(&image, 100);
+		assert_eq!(histogram.sample_count(), 100);
+	}
+
+	#[test]
+	fn auto_adjustment_ranges() {
+		// A dim image whose red channel is brighter than the others, which has a few stray black pixels
+		let mut image = Image::new(100, 100, Color::from_rgbaf32_unchecked(0.5, 0.3, 0.3, 1.).to_linear_srgb());
+		for (index, pixel) in image.data.iter_mut().enumerate() {
+			let shade = (index % 100) as f32 / 99.;
+			*pixel = Color::from_rgbaf32_unchecked(0.3 + 0.3 * shade, 0.2 + 0.2 * shade, 0.2 + 0.2 * shade, 1.).to_linear_srgb();
+		}
+		image.data[0] = Color::BLACK;
+		let histogram = Histogram::from_image(&image, usize::MAX);
+
+		let contrast = AutoAdjustment::Contrast.levels(&histogram);
+		assert_eq!(contrast.len(), 1);
+		assert_eq!(contrast[0].channel, LevelsChannel::Composite);
+		// The stray black pixel is clipped rather than keeping the black point at 0
+		assert!((contrast[0].shadows - 20.).abs() < 1. && (contrast[0].highlights - 60.).abs() < 1.);
+
+		let levels = AutoAdjustment::Levels.levels(&histogram);
+		assert_eq!(
+			levels.iter().map(|levels| levels.channel).collect::<Vec<_>>(),
+			[LevelsChannel::Red, LevelsChannel::Green, LevelsChannel::Blue]
+		);
+		assert!((levels[0].shadows - 30.).abs() < 1. && (levels[0].highlights - 60.).abs() < 1.);
+		assert!((levels[1].shadows - 20.).abs() < 1. && (levels[1].highlights - 40.).abs() < 1.);
+		assert!(levels.iter().all(|levels| levels.midtones == 50.));
+
+		assert!(AutoAdjustment::Color.levels(&Histogram::default()).is_empty());
+	}
+}
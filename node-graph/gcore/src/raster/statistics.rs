@@ -0,0 +1,191 @@
+use crate::raster::color::Color;
+use crate::raster::image::Image;
+use dyn_any::DynAny;
+
+/// The number of levels each gamma-encoded channel is divided into when grouping similar colors to find the dominant one.
+const DOMINANT_COLOR_LEVELS: usize = 16;
+
+/// Values summarizing the colors of an image, measured over its visible pixels.
+/// Colors are given with linear channels and unassociated alpha, and are fully opaque.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ImageStatistics {
+	/// The mean of the pixel colors, where each pixel counts in proportion to its opacity.
+	pub average: Color,
+	/// The mean of the most common group of similar pixel colors.
+	pub dominant: Color,
+	/// The color of the pixel with the lowest luminance.
+	pub darkest: Color,
+	/// The color of the pixel with the highest luminance.
+	pub lightest: Color,
+	/// The lowest, mean, and highest luminance from 0 to 1, measured in gamma (sRGB) space like the levels of a histogram.
+	pub min_luminance: f32,
+	pub average_luminance: f32,
+	pub max_luminance: f32,
+	/// The number of pixels which were measured.
+	pub sample_count: u32,
+}
+
+impl ImageStatistics {
+	/// Measures the images, sampling at most roughly `max_samples` evenly distributed pixels of each to remain fast for large images.
+	/// Fully transparent pixels are skipped since they don't contribute any visible color.
+	pub fn from_images<'a>(images: impl IntoIterator<Item = &'a Image<Color>>, max_samples: usize) -> Self {
+		let mut statistics = Self {
+			min_luminance: f32::INFINITY,
+			max_luminance: f32::NEG_INFINITY,
+			..Default::default()
+		};
+		let mut sum = [0.; 3];
+		let mut total_alpha = 0.;
+		let mut luminance_sum = 0.;
+		let mut groups = vec![([0.; 3], 0.); DOMINANT_COLOR_LEVELS.pow(3)];
+
+		for image in images {
+			let stride = image.data.len().div_ceil(max_samples.max(1)).max(1);
+			for pixel in image.data.iter().step_by(stride) {
+				let alpha = pixel.a();
+				if alpha <= 0. {
+					continue;
+				}
+
+				// Pixels are stored with associated alpha, so their channels are already weighted by their opacity
+				let channels = [pixel.r(), pixel.g(), pixel.b()];
+				let color = pixel.to_unassociated_alpha().with_alpha(1.);
+				let gamma = color.to_gamma_srgb();
+				let luminance = gamma.luminance_rec_601();
+
+				sum.iter_mut().zip(channels).for_each(|(sum, channel)| *sum += channel);
+				total_alpha += alpha;
+				luminance_sum += luminance;
+				statistics.sample_count += 1;
+
+				if luminance < statistics.min_luminance {
+					statistics.min_luminance = luminance;
+					statistics.darkest = color;
+				}
+				if luminance > statistics.max_luminance {
+					statistics.max_luminance = luminance;
+					statistics.lightest = color;
+				}
+
+				let level = |channel: f32| ((channel.clamp(0., 1.) * DOMINANT_COLOR_LEVELS as f32) as usize).min(DOMINANT_COLOR_LEVELS - 1);
+				let group = &mut groups[(level(gamma.r()) * DOMINANT_COLOR_LEVELS + level(gamma.g())) * DOMINANT_COLOR_LEVELS + level(gamma.b())];
+				group.0.iter_mut().zip(channels).for_each(|(sum, channel)| *sum += channel);
+				group.1 += alpha;
+			}
+		}
+
+		if statistics.sample_count == 0 {
+			return Self::default();
+		}
+
+		let mean = |sum: [f32; 3], alpha: f32| Color::from_rgbaf32_unchecked(sum[0] / alpha, sum[1] / alpha, sum[2] / alpha, 1.);
+		statistics.average = mean(sum, total_alpha);
+		statistics.average_luminance = luminance_sum / statistics.sample_count as f32;
+		if let Some((sum, alpha)) = groups.into_iter().max_by(|a, b| a.1.total_cmp(&b.1)) {
+			statistics.dominant = mean(sum, alpha);
+		}
+
+		statistics
+	}
+
+	pub fn color(&self, statistic: ColorStatistic) -> Color {
+		match statistic {
+			ColorStatistic::Average => self.average,
+			ColorStatistic::Dominant => self.dominant,
+			ColorStatistic::Darkest => self.darkest,
+			ColorStatistic::Lightest => self.lightest,
+		}
+	}
+
+	pub fn luminance(&self, statistic: LuminanceStatistic) -> f32 {
+		match statistic {
+			LuminanceStatistic::Minimum => self.min_luminance,
+			LuminanceStatistic::Average => self.average_luminance,
+			LuminanceStatistic::Maximum => self.max_luminance,
+		}
+	}
+}
+
+/// Which color of an image is measured by the Image Color node.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, DynAny)]
+pub enum ColorStatistic {
+	#[default]
+	Average,
+	Dominant,
+	Darkest,
+	Lightest,
+}
+
+impl ColorStatistic {
+	pub fn list() -> [ColorStatistic; 4] {
+		[ColorStatistic::Average, ColorStatistic::Dominant, ColorStatistic::Darkest, ColorStatistic::Lightest]
+	}
+}
+
+impl core::fmt::Display for ColorStatistic {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			ColorStatistic::Average => write!(f, "Average"),
+			ColorStatistic::Dominant => write!(f, "Dominant"),
+			ColorStatistic::Darkest => write!(f, "Darkest"),
+			ColorStatistic::Lightest => write!(f, "Lightest"),
+		}
+	}
+}
+
+/// Which luminance of an image is measured by the Image Luminance node.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, DynAny)]
+pub enum LuminanceStatistic {
+	Minimum,
+	#[default]
+	Average,
+	Maximum,
+}
+
+impl LuminanceStatistic {
+	pub fn list() -> [LuminanceStatistic; 3] {
+		[LuminanceStatistic::Minimum, LuminanceStatistic::Average, LuminanceStatistic::Maximum]
+	}
+}
+
+impl core::fmt::Display for LuminanceStatistic {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			LuminanceStatistic::Minimum => write!(f, "Minimum"),
+			LuminanceStatistic::Average => write!(f, "Average"),
+			LuminanceStatistic::Maximum => write!(f, "Maximum"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn image_statistics() {
+		let red = Color::from_rgbaf32_unchecked(1., 0., 0., 1.);
+		let mut image = Image::new(4, 1, red);
+		image.data[1] = Color::BLACK;
+		image.data[2] = Color::WHITE;
+		image.data[3] = Color::TRANSPARENT;
+
+		let statistics = ImageStatistics::from_images([&image], usize::MAX);
+		assert_eq!(statistics.sample_count, 3);
+		assert_eq!(statistics.darkest, Color::BLACK);
+		assert_eq!(statistics.lightest, Color::WHITE);
+		assert_eq!((statistics.min_luminance, statistics.max_luminance), (0., 1.));
+		assert!((statistics.average.r() - 2. / 3.).abs() < 1e-5 && (statistics.average.g() - 1. / 3.).abs() < 1e-5);
+
+		// Similar shades of red outnumber the other colors
+		image.data[1] = Color::from_rgbaf32_unchecked(0.98, 0., 0., 1.);
+		let statistics = ImageStatistics::from_images([&image], usize::MAX);
+		assert!(statistics.dominant.r() > 0.95 && statistics.dominant.g() == 0.);
+
+		assert_eq!(ImageStatistics::from_images([&Image::new(2, 2, Color::TRANSPARENT)], usize::MAX), ImageStatistics::default());
+	}
+}
@@ -1,6 +1,8 @@
 use crate::vector::PointId;
 use bezier_rs::{ManipulatorGroup, Subpath};
-use glam::DVec2;
+use core::fmt::{self, Display};
+use dyn_any::DynAny;
+use glam::{DAffine2, DVec2};
 use rustybuzz::ttf_parser::{GlyphId, OutlineBuilder};
 use rustybuzz::{GlyphBuffer, UnicodeBuffer};
 
@@ -12,12 +14,45 @@ struct Builder {
 	ascender: f64,
 	scale: f64,
 	id: PointId,
+	/// The index into `other_subpaths` after the last glyph of each finished line, along with the width of that line.
+	lines: Vec<(usize, f64)>,
+	/// The width of the current line up to the end of its last glyph that isn't a space.
+	line_width: f64,
 }
 
 impl Builder {
 	fn point(&self, x: f32, y: f32) -> DVec2 {
 		self.text_cursor + self.offset + DVec2::new(x as f64, self.ascender - y as f64) * self.scale
 	}
+
+	fn new_line(&mut self, line_height: f64) {
+		self.lines.push((self.other_subpaths.len(), self.line_width));
+		self.line_width = 0.;
+		self.text_cursor = DVec2::new(0., self.text_cursor.y + line_height);
+	}
+
+	/// Moves the glyphs of each line to their horizontal alignment within the max width (or the widest line if there is none),
+	/// and moves every line to the vertical alignment within the max height.
+	fn align_lines(mut self, typesetting: TypesettingConfig, line_height: f64) -> Vec<Subpath<PointId>> {
+		let block_width = typesetting.max_width.unwrap_or_else(|| self.lines.iter().map(|&(_, width)| width).fold(0., f64::max));
+		let block_height = self.lines.len() as f64 * line_height;
+		let vertical_offset = typesetting
+			.max_height
+			.map_or(0., |max_height| (max_height - block_height).max(0.) * typesetting.vertical_align.factor());
+
+		let mut start = 0;
+		for &(end, width) in &self.lines {
+			let offset = DVec2::new((block_width - width).max(0.) * typesetting.align.factor(), vertical_offset);
+			if offset != DVec2::ZERO {
+				for subpath in &mut self.other_subpaths[start..end] {
+					subpath.apply_transform(DAffine2::from_translation(offset));
+				}
+			}
+			start = end;
+		}
+
+		self.other_subpaths
+	}
 }
 
 impl OutlineBuilder for Builder {
@@ -77,6 +112,66 @@ fn wrap_word(max_width: Option<f64>, glyph_buffer: &GlyphBuffer, font_size: f64,
 	false
 }
 
+/// How each line of text is placed horizontally within the max width, or within the widest line if there is no max width.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum TextAlign {
+	#[default]
+	Left,
+	Center,
+	Right,
+}
+
+impl TextAlign {
+	fn factor(self) -> f64 {
+		match self {
+			TextAlign::Left => 0.,
+			TextAlign::Center => 0.5,
+			TextAlign::Right => 1.,
+		}
+	}
+}
+
+impl Display for TextAlign {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			TextAlign::Left => write!(f, "Left"),
+			TextAlign::Center => write!(f, "Center"),
+			TextAlign::Right => write!(f, "Right"),
+		}
+	}
+}
+
+/// How the block of text is placed vertically within the max height. Has no effect without a max height.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum VerticalAlign {
+	#[default]
+	Top,
+	Middle,
+	Bottom,
+}
+
+impl VerticalAlign {
+	fn factor(self) -> f64 {
+		match self {
+			VerticalAlign::Top => 0.,
+			VerticalAlign::Middle => 0.5,
+			VerticalAlign::Bottom => 1.,
+		}
+	}
+}
+
+impl Display for VerticalAlign {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			VerticalAlign::Top => write!(f, "Top"),
+			VerticalAlign::Middle => write!(f, "Middle"),
+			VerticalAlign::Bottom => write!(f, "Bottom"),
+		}
+	}
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, serde::Serialize, serde::Deserialize)]
 pub struct TypesettingConfig {
 	pub font_size: f64,
@@ -84,6 +179,8 @@ pub struct TypesettingConfig {
 	pub character_spacing: f64,
 	pub max_width: Option<f64>,
 	pub max_height: Option<f64>,
+	pub align: TextAlign,
+	pub vertical_align: VerticalAlign,
 }
 
 impl Default for TypesettingConfig {
@@ -94,6 +191,8 @@ impl Default for TypesettingConfig {
 			character_spacing: 1.,
 			max_width: None,
 			max_height: None,
+			align: TextAlign::Left,
+			vertical_align: VerticalAlign::Top,
 		}
 	}
 }
@@ -112,28 +211,30 @@ pub fn to_path(str: &str, buzz_face: Option<rustybuzz::Face>, typesetting: Types
 		ascender: (buzz_face.ascender() as f64 / buzz_face.height() as f64) * typesetting.font_size / scale,
 		scale,
 		id: PointId::ZERO,
+		lines: Vec::new(),
+		line_width: 0.,
 	};
 
-	for line in str.split('\n') {
+	'lines: for line in str.split('\n') {
 		for (index, word) in SplitWordsIncludingSpaces::new(line).enumerate() {
 			push_str(&mut buffer, word);
 			let glyph_buffer = rustybuzz::shape(&buzz_face, &[], buffer);
 
 			// Don't wrap the first word
 			if index != 0 && wrap_word(typesetting.max_width, &glyph_buffer, scale, typesetting.character_spacing, builder.text_cursor.x, space_glyph) {
-				builder.text_cursor = DVec2::new(0., builder.text_cursor.y + line_height);
+				builder.new_line(line_height);
 			}
 
 			for (glyph_position, glyph_info) in glyph_buffer.glyph_positions().iter().zip(glyph_buffer.glyph_infos()) {
 				let glyph_id = GlyphId(glyph_info.glyph_id as u16);
 				if let Some(max_width) = typesetting.max_width {
 					if space_glyph != Some(glyph_id) && builder.text_cursor.x + (glyph_position.x_advance as f64 * builder.scale * typesetting.character_spacing) >= max_width {
-						builder.text_cursor = DVec2::new(0., builder.text_cursor.y + line_height);
+						builder.new_line(line_height);
 					}
 				}
 				// Clip when the height is exceeded
 				if typesetting.max_height.is_some_and(|max_height| builder.text_cursor.y > max_height - line_height) {
-					return builder.other_subpaths;
+					break 'lines;
 				}
 
 				builder.offset = DVec2::new(glyph_position.x_offset as f64, glyph_position.y_offset as f64) * builder.scale;
@@ -143,15 +244,23 @@ pub fn to_path(str: &str, buzz_face: Option<rustybuzz::Face>, typesetting: Types
 				}
 
 				builder.text_cursor += DVec2::new(glyph_position.x_advance as f64 * typesetting.character_spacing, glyph_position.y_advance as f64) * builder.scale;
+				if space_glyph != Some(glyph_id) {
+					builder.line_width = builder.text_cursor.x;
+				}
 			}
 
 			buffer = glyph_buffer.clear();
 		}
 
-		builder.text_cursor = DVec2::new(0., builder.text_cursor.y + line_height);
+		builder.new_line(line_height);
+	}
+
+	// The line that was clipped by the max height still needs to be aligned
+	if builder.lines.last().is_none_or(|&(end, _)| end < builder.other_subpaths.len()) {
+		builder.new_line(line_height);
 	}
 
-	builder.other_subpaths
+	builder.align_lines(typesetting, line_height)
 }
 
 pub fn bounding_box(str: &str, buzz_face: Option<&rustybuzz::Face>, typesetting: TypesettingConfig, for_clipping_test: bool) -> DVec2 {
@@ -2,10 +2,14 @@ use crate::application_io::TextureFrameTable;
 use crate::instances::Instances;
 use crate::raster::bbox::AxisAlignedBbox;
 use crate::raster::image::ImageFrameTable;
+use crate::registry::types::{Angle, SeedValue};
+use crate::renderer::GraphicElementRendered;
 use crate::vector::VectorDataTable;
 use crate::{Artboard, ArtboardGroupTable, CloneVarArgs, Color, Context, Ctx, ExtractAll, GraphicGroupTable, OwnedContextImpl};
 use core::f64;
+use dyn_any::DynAny;
 use glam::{DAffine2, DMat2, DVec2};
+use rand::{Rng, SeedableRng};
 
 pub trait Transform {
 	fn transform(&self) -> DAffine2;
@@ -208,6 +212,152 @@ fn replace_transform<Data, TransformInput: Transform>(
 	data
 }
 
+/// The probability distribution that random values are drawn from.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum RandomDistribution {
+	/// Every value between the minimum and maximum is equally likely.
+	#[default]
+	Uniform,
+	/// Values cluster around the middle of the range in a bell curve, with the range spanning three standard deviations to either side.
+	Normal,
+}
+
+impl RandomDistribution {
+	/// Draws a random value between `min` and `max`.
+	pub fn sample(self, rng: &mut impl Rng, min: f64, max: f64) -> f64 {
+		match self {
+			RandomDistribution::Uniform => min + rng.random::<f64>() * (max - min),
+			RandomDistribution::Normal => {
+				// Box-Muller transform from two uniform samples to one standard normal sample
+				let standard_normal = (-2. * (1. - rng.random::<f64>()).ln()).sqrt() * (f64::consts::TAU * rng.random::<f64>()).cos();
+
+				let mean = (min + max) / 2.;
+				let standard_deviation = (max - min) / 6.;
+				(mean + standard_normal * standard_deviation).clamp(min.min(max), min.max(max))
+			}
+		}
+	}
+}
+
+impl core::fmt::Display for RandomDistribution {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			RandomDistribution::Uniform => write!(f, "Uniform"),
+			RandomDistribution::Normal => write!(f, "Normal"),
+		}
+	}
+}
+
+/// Moves, rotates, and scales each element by a random amount within the given ranges. Rotation and scale are applied about the element's own origin.
+#[node_macro::node(category("General"), properties("randomize_transform_properties"))]
+fn randomize_transform<Data>(
+	_: impl Ctx,
+	#[implementations(VectorDataTable, GraphicGroupTable, ImageFrameTable<Color>)] mut elements: Instances<Data>,
+	distribution: RandomDistribution,
+	translation_min: DVec2,
+	translation_max: DVec2,
+	rotation_min: Angle,
+	rotation_max: Angle,
+	#[default(1., 1.)] scale_min: DVec2,
+	#[default(1., 1.)] scale_max: DVec2,
+	seed: SeedValue,
+) -> Instances<Data> {
+	let mut rng = rand::rngs::StdRng::seed_from_u64(seed.into());
+	let mut sample = |min: f64, max: f64| distribution.sample(&mut rng, min, max);
+
+	for element in elements.instances_mut() {
+		let translation = DVec2::new(sample(translation_min.x, translation_max.x), sample(translation_min.y, translation_max.y));
+		let rotation = sample(rotation_min, rotation_max).to_radians();
+		let scale = DVec2::new(sample(scale_min.x, scale_max.x), sample(scale_min.y, scale_max.y));
+
+		*element.transform = DAffine2::from_translation(translation) * *element.transform * DAffine2::from_scale_angle_translation(scale, rotation, DVec2::ZERO);
+	}
+
+	elements
+}
+
+/// How content is scaled to fit within a target rectangle.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum FitMode {
+	/// Scales uniformly so the whole content fits inside the target.
+	#[default]
+	Contain,
+	/// Scales uniformly so the content covers the whole target, overflowing it along one axis.
+	Cover,
+	/// Scales each axis independently so the content exactly matches the target.
+	Stretch,
+}
+
+impl core::fmt::Display for FitMode {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			FitMode::Contain => write!(f, "Contain"),
+			FitMode::Cover => write!(f, "Cover"),
+			FitMode::Stretch => write!(f, "Stretch"),
+		}
+	}
+}
+
+/// Scales and moves the content so its bounds fit within a target rectangle, or within the bounds of the reference graphic when one is connected.
+#[node_macro::node(category("General"), properties("fit_to_bounds_properties"))]
+fn fit_to_bounds<Data>(
+	_: impl Ctx,
+	#[implementations(VectorDataTable, GraphicGroupTable, ImageFrameTable<Color>)] mut content: Instances<Data>,
+	/// The graphic whose bounds the content is fit within. When empty, the target rectangle is used instead.
+	#[expose]
+	reference: GraphicGroupTable,
+	target_position: DVec2,
+	#[default(100., 100.)] target_size: DVec2,
+	fit: FitMode,
+	/// The space kept between the content and each edge of the target.
+	padding: f64,
+	/// Where the content sits within the target when their proportions differ, from (0, 0) at the top left to (1, 1) at the bottom right.
+	#[default(0.5, 0.5)]
+	alignment: DVec2,
+) -> Instances<Data>
+where
+	Instances<Data>: GraphicElementRendered,
+{
+	let Some([content_min, content_max]) = content.bounding_box(DAffine2::IDENTITY) else {
+		return content;
+	};
+	let [target_min, target_max] = reference
+		.bounding_box(DAffine2::IDENTITY)
+		.unwrap_or([target_position.min(target_position + target_size), target_position.max(target_position + target_size)]);
+
+	let target_min = target_min + padding;
+	let target_size = (target_max - padding - target_min).max(DVec2::ZERO);
+	let content_size = content_max - content_min;
+
+	// An axis along which the content has no size, like a horizontal line's height, can't be scaled to fit
+	let axis_scale = |target: f64, content: f64| (content.abs() > f64::EPSILON).then(|| target / content);
+	let (scale_x, scale_y) = (axis_scale(target_size.x, content_size.x), axis_scale(target_size.y, content_size.y));
+	let uniform_scale = |choose: fn(f64, f64) -> f64| {
+		let scale = match (scale_x, scale_y) {
+			(Some(x), Some(y)) => choose(x, y),
+			(Some(scale), None) | (None, Some(scale)) => scale,
+			(None, None) => 1.,
+		};
+		DVec2::splat(scale)
+	};
+	let scale = match fit {
+		FitMode::Contain => uniform_scale(f64::min),
+		FitMode::Cover => uniform_scale(f64::max),
+		FitMode::Stretch => DVec2::new(scale_x.unwrap_or(1.), scale_y.unwrap_or(1.)),
+	};
+
+	let offset = target_min + (target_size - content_size * scale) * alignment.clamp(DVec2::ZERO, DVec2::ONE);
+	let modification = DAffine2::from_translation(offset) * DAffine2::from_scale(scale) * DAffine2::from_translation(-content_min);
+
+	for instance in content.instances_mut() {
+		*instance.transform = modification * *instance.transform;
+	}
+
+	content
+}
+
 #[node_macro::node(category("Debug"))]
 async fn boundless_footprint<T: 'n + 'static>(
 	ctx: impl Ctx + CloneVarArgs + ExtractAll,
@@ -0,0 +1,554 @@
+//! Encoders for QR codes and 1D barcodes, producing the grid or row of dark and light modules which the generator nodes turn into shapes.
+
+use super::misc::{BarcodeFormat, QrErrorCorrection};
+
+/// Error correction codewords per block, indexed by error correction level and then by version (index 0 is unused).
+const QR_ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+	[
+		0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28, 30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+	],
+	[
+		0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+	],
+	[
+		0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30, 30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+	],
+	[
+		0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+	],
+];
+
+/// Number of error correction blocks, indexed by error correction level and then by version (index 0 is unused).
+const QR_ECC_BLOCKS: [[u8; 41]; 4] = [
+	[
+		0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13, 14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+	],
+	[
+		0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21, 23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+	],
+	[
+		0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29, 34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+	],
+	[
+		0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32, 35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+	],
+];
+
+/// The square grid of modules of a QR code, where `true` is dark, not including its quiet zone.
+pub struct QrCode {
+	pub size: usize,
+	modules: Vec<bool>,
+	/// Modules belonging to the finder, timing, and alignment patterns and the format and version information, which aren't masked or filled with data.
+	function: Vec<bool>,
+}
+
+impl QrCode {
+	/// Encodes the text as UTF-8 bytes in the smallest version of QR code which fits it at the given error correction level,
+	/// or returns `None` if it's too long for even the largest version.
+	pub fn encode(text: &str, error_correction: QrErrorCorrection) -> Option<Self> {
+		let data = text.as_bytes();
+		let level = error_correction as usize;
+
+		let version = (1..=40).find(|&version| {
+			let capacity_bits = data_codewords(version, level) * 8;
+			4 + byte_count_bits(version) + data.len() * 8 <= capacity_bits
+		})?;
+
+		// Byte mode segment, followed by the terminator and padding
+		let capacity_bits = data_codewords(version, level) * 8;
+		let mut bits = BitBuffer::default();
+		bits.push(0b0100, 4);
+		bits.push(data.len() as u32, byte_count_bits(version));
+		for &byte in data {
+			bits.push(byte as u32, 8);
+		}
+		bits.push(0, (capacity_bits - bits.0.len()).min(4));
+		bits.push(0, (8 - bits.0.len() % 8) % 8);
+		for &pad in [0xEC, 0x11].iter().cycle().take((capacity_bits - bits.0.len()) / 8) {
+			bits.push(pad, 8);
+		}
+		let codewords = bits.0.chunks(8).map(|byte| byte.iter().fold(0_u8, |acc, &bit| (acc << 1) | bit as u8)).collect::<Vec<_>>();
+
+		let size = version * 4 + 17;
+		let mut qr_code = Self {
+			size,
+			modules: vec![false; size * size],
+			function: vec![false; size * size],
+		};
+		qr_code.draw_function_patterns(version);
+		qr_code.draw_codewords(&add_error_correction(&codewords, version, level));
+
+		// Keep the mask which leaves the fewest patterns that could confuse a scanner
+		let mask = (0..8)
+			.min_by_key(|&mask| {
+				qr_code.apply_mask(mask);
+				qr_code.draw_format_bits(level, mask);
+				let penalty = qr_code.penalty();
+				qr_code.apply_mask(mask);
+				penalty
+			})
+			.unwrap_or_default();
+		qr_code.apply_mask(mask);
+		qr_code.draw_format_bits(level, mask);
+
+		Some(qr_code)
+	}
+
+	pub fn get(&self, x: usize, y: usize) -> bool {
+		self.modules[y * self.size + x]
+	}
+
+	fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+		self.modules[y * self.size + x] = dark;
+		self.function[y * self.size + x] = true;
+	}
+
+	fn draw_function_patterns(&mut self, version: usize) {
+		let size = self.size;
+
+		// Timing patterns
+		for i in 0..size {
+			self.set_function(6, i, i % 2 == 0);
+			self.set_function(i, 6, i % 2 == 0);
+		}
+
+		// Finder patterns, each with its light separator
+		for (center_x, center_y) in [(3, 3), (size - 4, 3), (3, size - 4)] {
+			for dy in -4_isize..=4 {
+				for dx in -4_isize..=4 {
+					let (x, y) = (center_x as isize + dx, center_y as isize + dy);
+					if (0..size as isize).contains(&x) && (0..size as isize).contains(&y) {
+						let distance = dx.abs().max(dy.abs());
+						self.set_function(x as usize, y as usize, distance != 2 && distance != 4);
+					}
+				}
+			}
+		}
+
+		// Alignment patterns, except where they would overlap the finder patterns
+		let positions = alignment_pattern_positions(version);
+		let last = positions.len().saturating_sub(1);
+		for (i, &center_x) in positions.iter().enumerate() {
+			for (j, &center_y) in positions.iter().enumerate() {
+				if (i == 0 && j == 0) || (i == 0 && j == last) || (i == last && j == 0) {
+					continue;
+				}
+				for dy in -2_isize..=2 {
+					for dx in -2_isize..=2 {
+						let (x, y) = ((center_x as isize + dx) as usize, (center_y as isize + dy) as usize);
+						self.set_function(x, y, dx.abs().max(dy.abs()) != 1);
+					}
+				}
+			}
+		}
+
+		// Reserve the format information areas, which are drawn once the mask is chosen
+		self.draw_format_bits(0, 0);
+
+		// Version information
+		if version >= 7 {
+			let mut remainder = version as u32;
+			for _ in 0..12 {
+				remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1F25);
+			}
+			let bits = ((version as u32) << 12) | remainder;
+			for i in 0..18 {
+				let dark = (bits >> i) & 1 != 0;
+				let (a, b) = (size - 11 + i % 3, i / 3);
+				self.set_function(a, b, dark);
+				self.set_function(b, a, dark);
+			}
+		}
+	}
+
+	fn draw_format_bits(&mut self, level: usize, mask: usize) {
+		let size = self.size;
+
+		// The error correction levels are numbered in the order M, L, H, Q in the format information
+		let level_bits = [1, 0, 3, 2][level];
+		let data = (level_bits << 3) | mask as u32;
+		let mut remainder = data;
+		for _ in 0..10 {
+			remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+		}
+		let bits = ((data << 10) | remainder) ^ 0x5412;
+		let bit = |i: usize| (bits >> i) & 1 != 0;
+
+		// Around the top left finder pattern
+		for i in 0..=5 {
+			self.set_function(8, i, bit(i));
+		}
+		self.set_function(8, 7, bit(6));
+		self.set_function(8, 8, bit(7));
+		self.set_function(7, 8, bit(8));
+		for i in 9..15 {
+			self.set_function(14 - i, 8, bit(i));
+		}
+
+		// Split between the other two finder patterns
+		for i in 0..8 {
+			self.set_function(size - 1 - i, 8, bit(i));
+		}
+		for i in 8..15 {
+			self.set_function(8, size - 15 + i, bit(i));
+		}
+		self.set_function(8, size - 8, true);
+	}
+
+	/// Fills the modules outside the function patterns with the codewords, in the zigzag order of columns two modules wide from the bottom right corner.
+	fn draw_codewords(&mut self, codewords: &[u8]) {
+		let size = self.size as isize;
+		let mut bit_index = 0;
+
+		let mut right = size - 1;
+		while right >= 1 {
+			// Skip over the vertical timing pattern
+			if right == 6 {
+				right = 5;
+			}
+			for vertical in 0..size {
+				for offset in 0..2 {
+					let x = (right - offset) as usize;
+					let upward = (right + 1) & 2 == 0;
+					let y = if upward { size - 1 - vertical } else { vertical } as usize;
+					if !self.function[y * self.size + x] && bit_index < codewords.len() * 8 {
+						self.modules[y * self.size + x] = (codewords[bit_index / 8] >> (7 - bit_index % 8)) & 1 != 0;
+						bit_index += 1;
+					}
+				}
+			}
+			right -= 2;
+		}
+	}
+
+	/// Inverts the data modules matching the mask pattern, so applying the same mask twice undoes it.
+	fn apply_mask(&mut self, mask: usize) {
+		for y in 0..self.size {
+			for x in 0..self.size {
+				let invert = match mask {
+					0 => (x + y) % 2 == 0,
+					1 => y % 2 == 0,
+					2 => x % 3 == 0,
+					3 => (x + y) % 3 == 0,
+					4 => (x / 3 + y / 2) % 2 == 0,
+					5 => x * y % 2 + x * y % 3 == 0,
+					6 => (x * y % 2 + x * y % 3) % 2 == 0,
+					_ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+				};
+				let index = y * self.size + x;
+				self.modules[index] ^= invert && !self.function[index];
+			}
+		}
+	}
+
+	/// Scores the patterns which make a code harder to scan: long runs and blocks of one color, shapes resembling the finder patterns, and an imbalance of dark and light.
+	fn penalty(&self) -> usize {
+		let size = self.size;
+		let mut penalty = 0;
+
+		let lines = (0..size).flat_map(|i| [(0..size).map(|j| self.get(j, i)).collect::<Vec<_>>(), (0..size).map(|j| self.get(i, j)).collect::<Vec<_>>()]);
+		for line in lines {
+			// Runs of five or more modules of the same color
+			let mut run = 1;
+			for j in 1..=size {
+				if j < size && line[j] == line[j - 1] {
+					run += 1;
+				} else {
+					if run >= 5 {
+						penalty += run - 2;
+					}
+					run = 1;
+				}
+			}
+
+			// Dark-light-dark-dark-dark-light-dark patterns with four light modules on either side, where the edge of the code counts as light
+			const FINDER_LIKE: [bool; 7] = [true, false, true, true, true, false, true];
+			let module = |j: isize| j >= 0 && (j as usize) < size && line[j as usize];
+			for start in 0..=(size - 7) as isize {
+				if (0..7).all(|k| module(start + k) == FINDER_LIKE[k as usize]) {
+					let light_before = (1..=4).all(|k| !module(start - k));
+					let light_after = (7..11).all(|k| !module(start + k));
+					if light_before || light_after {
+						penalty += 40;
+					}
+				}
+			}
+		}
+
+		// Two by two blocks of the same color
+		for y in 0..size - 1 {
+			for x in 0..size - 1 {
+				let color = self.get(x, y);
+				if color == self.get(x + 1, y) && color == self.get(x, y + 1) && color == self.get(x + 1, y + 1) {
+					penalty += 3;
+				}
+			}
+		}
+
+		// The proportion of dark modules straying from half
+		let dark = self.modules.iter().filter(|&&dark| dark).count();
+		let total = size * size;
+		let deviation = (dark * 20).abs_diff(total * 10);
+		penalty += deviation.div_ceil(total).saturating_sub(1) * 10;
+
+		penalty
+	}
+}
+
+#[derive(Default)]
+struct BitBuffer(Vec<bool>);
+
+impl BitBuffer {
+	fn push(&mut self, value: u32, length: usize) {
+		self.0.extend((0..length).rev().map(|i| (value >> i) & 1 != 0));
+	}
+}
+
+fn byte_count_bits(version: usize) -> usize {
+	if version <= 9 { 8 } else { 16 }
+}
+
+/// The number of modules of a version which are left for the data and error correction codewords, after the function patterns.
+fn raw_data_modules(version: usize) -> usize {
+	let mut modules = (16 * version + 128) * version + 64;
+	if version >= 2 {
+		let alignment_count = version / 7 + 2;
+		modules -= (25 * alignment_count - 10) * alignment_count - 55;
+		if version >= 7 {
+			modules -= 36;
+		}
+	}
+	modules
+}
+
+fn data_codewords(version: usize, level: usize) -> usize {
+	raw_data_modules(version) / 8 - QR_ECC_CODEWORDS_PER_BLOCK[level][version] as usize * QR_ECC_BLOCKS[level][version] as usize
+}
+
+fn alignment_pattern_positions(version: usize) -> Vec<usize> {
+	if version == 1 {
+		return Vec::new();
+	}
+	let count = version / 7 + 2;
+	let step = (version * 8 + count * 3 + 5) / (count * 4 - 4) * 2;
+	let size = version * 4 + 17;
+	let mut positions = (0..count - 1).map(|i| size - 7 - i * step).collect::<Vec<_>>();
+	positions.push(6);
+	positions.reverse();
+	positions
+}
+
+/// Splits the data codewords into blocks, appends the Reed-Solomon error correction codewords to each, and interleaves the blocks.
+fn add_error_correction(data: &[u8], version: usize, level: usize) -> Vec<u8> {
+	let block_count = QR_ECC_BLOCKS[level][version] as usize;
+	let ecc_length = QR_ECC_CODEWORDS_PER_BLOCK[level][version] as usize;
+	let raw_codewords = raw_data_modules(version) / 8;
+	let short_block_count = block_count - raw_codewords % block_count;
+	let short_block_length = raw_codewords / block_count;
+	let divisor = reed_solomon_divisor(ecc_length);
+
+	let mut blocks = Vec::with_capacity(block_count);
+	let mut start = 0;
+	for i in 0..block_count {
+		let length = short_block_length - ecc_length + if i < short_block_count { 0 } else { 1 };
+		let mut block = data[start..start + length].to_vec();
+		start += length;
+		let ecc = reed_solomon_remainder(&block, &divisor);
+		// Short blocks get a placeholder so all blocks line up when interleaving
+		if i < short_block_count {
+			block.push(0);
+		}
+		block.extend(ecc);
+		blocks.push(block);
+	}
+
+	let mut result = Vec::with_capacity(raw_codewords);
+	for i in 0..blocks[0].len() {
+		for (j, block) in blocks.iter().enumerate() {
+			if i != short_block_length - ecc_length || j >= short_block_count {
+				result.push(block[i]);
+			}
+		}
+	}
+	result
+}
+
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+	let mut result = vec![0; degree];
+	result[degree - 1] = 1;
+	let mut root = 1;
+	for _ in 0..degree {
+		for j in 0..degree {
+			result[j] = gf_multiply(result[j], root);
+			if j + 1 < degree {
+				result[j] ^= result[j + 1];
+			}
+		}
+		root = gf_multiply(root, 0x02);
+	}
+	result
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+	let mut result = vec![0; divisor.len()];
+	for &byte in data {
+		let factor = byte ^ result.remove(0);
+		result.push(0);
+		for (remainder, &coefficient) in result.iter_mut().zip(divisor) {
+			*remainder ^= gf_multiply(coefficient, factor);
+		}
+	}
+	result
+}
+
+/// Multiplies two elements of the Galois field GF(2^8) with the QR code's reducing polynomial.
+fn gf_multiply(x: u8, y: u8) -> u8 {
+	let mut z: u32 = 0;
+	for i in (0..8).rev() {
+		z = (z << 1) ^ ((z >> 7) * 0x11D);
+		z ^= ((y as u32 >> i) & 1) * x as u32;
+	}
+	z as u8
+}
+
+/// Bar and space widths of the Code 128 symbols, in modules, where the last is the stop pattern.
+const CODE_128_PATTERNS: [&str; 107] = [
+	"212222", "222122", "222221", "121223", "121322", "131222", "122213", "122312", "132212", "221213", "221312", "231212", "112232", "122132", "122231", "113222", "123122", "123221", "223211",
+	"221132", "221231", "213212", "223112", "312131", "311222", "321122", "321221", "312212", "322112", "322211", "212123", "212321", "232121", "111323", "131123", "131321", "112313", "132113",
+	"132311", "211313", "231113", "231311", "112133", "112331", "132131", "113123", "113321", "133121", "313121", "211331", "231131", "213113", "213311", "213131", "311123", "311321", "331121",
+	"312113", "312311", "332111", "314111", "221411", "431111", "111224", "111422", "121124", "121421", "141122", "141221", "112214", "112412", "122114", "122411", "142112", "142211", "241211",
+	"221114", "413111", "241112", "134111", "111242", "121142", "121241", "114212", "124112", "124211", "411212", "421112", "421211", "212141", "214121", "412121", "111143", "111341", "131141",
+	"114113", "114311", "411113", "411311", "113141", "114131", "311141", "411131", "211412", "211214", "211232", "2331112",
+];
+const CODE_128_START_B: usize = 104;
+const CODE_128_STOP: usize = 106;
+
+/// The left-hand odd parity digit patterns of EAN-13, where the right-hand patterns are their inverse and the even parity patterns are those reversed.
+const EAN_L_PATTERNS: [u8; 10] = [0b0001101, 0b0011001, 0b0010011, 0b0111101, 0b0100011, 0b0110001, 0b0101111, 0b0111011, 0b0110111, 0b0001011];
+/// Which of the six left-hand digits use even parity, encoding the leading digit of an EAN-13 code.
+const EAN_EVEN_PARITY: [u8; 10] = [0b000000, 0b001011, 0b001101, 0b001110, 0b010011, 0b011001, 0b011100, 0b010101, 0b010110, 0b011010];
+
+/// Encodes the text as a row of modules where `true` is a dark bar, not including the quiet zones,
+/// or returns `None` if it contains characters the format can't represent or a wrong check digit.
+pub fn encode_barcode(text: &str, format: BarcodeFormat) -> Option<Vec<bool>> {
+	match format {
+		BarcodeFormat::Code128 => encode_code_128(text),
+		BarcodeFormat::Ean13 => encode_ean_13(text),
+		// A UPC-A code is an EAN-13 code with a leading zero
+		BarcodeFormat::UpcA => {
+			if !matches!(text.len(), 11 | 12) {
+				return None;
+			}
+			encode_ean_13(&format!("0{text}"))
+		}
+	}
+}
+
+/// Code 128 using its character set B, which covers printable ASCII.
+fn encode_code_128(text: &str) -> Option<Vec<bool>> {
+	let values = text
+		.chars()
+		.map(|character| (' '..='~').contains(&character).then(|| character as usize - ' ' as usize))
+		.collect::<Option<Vec<_>>>()?;
+
+	let checksum = values.iter().enumerate().fold(CODE_128_START_B, |sum, (i, value)| sum + (i + 1) * value) % 103;
+
+	let symbols = [CODE_128_START_B].into_iter().chain(values).chain([checksum, CODE_128_STOP]);
+	let mut modules = Vec::new();
+	for symbol in symbols {
+		for (i, width) in CODE_128_PATTERNS[symbol].bytes().enumerate() {
+			modules.extend(std::iter::repeat_n(i % 2 == 0, (width - b'0') as usize));
+		}
+	}
+	Some(modules)
+}
+
+/// EAN-13 from its first 12 digits, with the check digit calculated, or all 13 digits, with the check digit verified.
+fn encode_ean_13(text: &str) -> Option<Vec<bool>> {
+	let mut digits = text.chars().map(|character| character.to_digit(10).map(|digit| digit as u8)).collect::<Option<Vec<_>>>()?;
+	if !matches!(digits.len(), 12 | 13) {
+		return None;
+	}
+
+	let weighted_sum = digits[..12].iter().enumerate().map(|(i, &digit)| digit as u32 * if i % 2 == 0 { 1 } else { 3 }).sum::<u32>();
+	let check_digit = ((10 - weighted_sum % 10) % 10) as u8;
+	if digits.len() == 13 && digits[12] != check_digit {
+		return None;
+	}
+	digits.truncate(12);
+	digits.push(check_digit);
+
+	let mut modules = Vec::with_capacity(95);
+	let mut push_bits = |bits: u8, length: usize| modules.extend((0..length).rev().map(|i| (bits >> i) & 1 != 0));
+
+	push_bits(0b101, 3);
+	let parity = EAN_EVEN_PARITY[digits[0] as usize];
+	for (i, &digit) in digits[1..7].iter().enumerate() {
+		let pattern = EAN_L_PATTERNS[digit as usize];
+		let even = (parity >> (5 - i)) & 1 != 0;
+		let pattern = if even { (!pattern & 0x7F).reverse_bits() >> 1 } else { pattern };
+		push_bits(pattern, 7);
+	}
+	push_bits(0b01010, 5);
+	for &digit in &digits[7..] {
+		push_bits(!EAN_L_PATTERNS[digit as usize] & 0x7F, 7);
+	}
+	push_bits(0b101, 3);
+
+	Some(modules)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn qr_code_picks_the_smallest_version() {
+		// Version 1 holds up to 17 bytes at the low error correction level and 7 at the high level
+		assert_eq!(QrCode::encode("https://graphite.", QrErrorCorrection::Low).unwrap().size, 21);
+		assert_eq!(QrCode::encode("https://graphite.", QrErrorCorrection::High).unwrap().size, 29);
+		assert!(QrCode::encode(&"a".repeat(3000), QrErrorCorrection::High).is_none());
+	}
+
+	#[test]
+	fn qr_code_has_finder_patterns_and_the_dark_module() {
+		let qr_code = QrCode::encode("Graphite", QrErrorCorrection::Medium).unwrap();
+		let size = qr_code.size;
+		for (x, y) in [(0, 0), (size - 7, 0), (0, size - 7)] {
+			assert!((0..7).all(|i| qr_code.get(x + i, y) && qr_code.get(x, y + i)));
+			assert!(qr_code.get(x + 3, y + 3));
+			assert!(!qr_code.get(x + 1, y + 1));
+		}
+		assert!(qr_code.get(8, size - 8));
+	}
+
+	#[test]
+	fn codewords_fill_every_data_module() {
+		for version in 1..=40 {
+			for level in 0..4 {
+				let blocks = QR_ECC_BLOCKS[level][version] as usize;
+				assert!(data_codewords(version, level) >= blocks, "version {version} level {level}");
+				assert_eq!(add_error_correction(&vec![0; data_codewords(version, level)], version, level).len(), raw_data_modules(version) / 8);
+			}
+		}
+	}
+
+	#[test]
+	fn code_128_symbols_are_eleven_modules_wide() {
+		for pattern in &CODE_128_PATTERNS[..CODE_128_STOP] {
+			assert_eq!(pattern.bytes().map(|width| (width - b'0') as usize).sum::<usize>(), 11);
+		}
+		// Start, two characters, checksum, and the 13 module stop pattern
+		assert_eq!(encode_barcode("Hi", BarcodeFormat::Code128).unwrap().len(), 11 * 4 + 13);
+		assert!(encode_barcode("é", BarcodeFormat::Code128).is_none());
+	}
+
+	#[test]
+	fn ean_13_check_digit() {
+		let with_check_digit = encode_barcode("4006381333931", BarcodeFormat::Ean13).unwrap();
+		assert_eq!(with_check_digit.len(), 95);
+		assert_eq!(encode_barcode("400638133393", BarcodeFormat::Ean13).unwrap(), with_check_digit);
+		assert!(encode_barcode("4006381333932", BarcodeFormat::Ean13).is_none());
+		assert!(encode_barcode("036000291452", BarcodeFormat::UpcA).is_some());
+	}
+}
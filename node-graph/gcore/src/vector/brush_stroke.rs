@@ -60,22 +60,41 @@ pub struct BrushInputSample {
 	// The position of the sample in layer space, in pixels.
 	// The origin of layer space is not specified.
 	pub position: DVec2,
-	// Future work: pressure, stylus angle, etc.
+	// The stylus pressure from 0 to 1, which is 1 for input devices without pressure sensitivity.
+	#[cfg_attr(feature = "serde", serde(default = "full_pressure"))]
+	pub pressure: f64,
+	// Future work: stylus angle, etc.
+}
+
+fn full_pressure() -> f64 {
+	1.
 }
 
 impl Hash for BrushInputSample {
 	fn hash<H: Hasher>(&self, state: &mut H) {
 		self.position.x.to_bits().hash(state);
 		self.position.y.to_bits().hash(state);
+		self.pressure.to_bits().hash(state);
 	}
 }
 
 /// The parameters for a single stroke brush.
-#[derive(Clone, Debug, PartialEq, Hash, Default, DynAny)]
+#[derive(Clone, Debug, PartialEq, Default, DynAny)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BrushStroke {
 	pub style: BrushStyle,
 	pub trace: Vec<BrushInputSample>,
+	/// When set, this is a clone stamp stroke which paints the pixels found at this offset (in layer space) from each point of the stroke, instead of the brush color.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub clone_offset: Option<DVec2>,
+}
+
+impl Hash for BrushStroke {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.style.hash(state);
+		self.trace.hash(state);
+		self.clone_offset.map(|offset| (offset.x.to_bits(), offset.y.to_bits())).hash(state);
+	}
 }
 
 impl BrushStroke {
@@ -92,6 +111,11 @@ impl BrushStroke {
 	}
 
 	pub fn compute_blit_points(&self) -> Vec<DVec2> {
+		self.compute_blit_samples().into_iter().map(|sample| sample.position).collect()
+	}
+
+	/// Like [`Self::compute_blit_points`], but also interpolates the pressure of the user inputs at each blit point.
+	pub fn compute_blit_samples(&self) -> Vec<BrushInputSample> {
 		// We always travel in a straight line towards the next user input,
 		// placing a blit point every time we travelled our spacing distance.
 		let spacing_dist = self.style.spacing / 100. * self.style.diameter;
@@ -101,12 +125,14 @@ impl BrushStroke {
 		};
 
 		let mut cur_pos = first_sample.position;
-		let mut result = vec![cur_pos];
+		let mut cur_pressure = first_sample.pressure;
+		let mut result = vec![first_sample.clone()];
 		let mut dist_until_next_blit = spacing_dist;
 		for sample in &self.trace[1..] {
 			// Travel to the next sample.
 			let delta = sample.position - cur_pos;
-			let mut dist_left = delta.length();
+			let total_dist = delta.length();
+			let mut dist_left = total_dist;
 			let unit_step = delta / dist_left;
 
 			while dist_left >= dist_until_next_blit {
@@ -115,13 +141,15 @@ impl BrushStroke {
 				dist_left -= dist_until_next_blit;
 
 				// Blit.
-				result.push(cur_pos);
+				let pressure = cur_pressure + (sample.pressure - cur_pressure) * (1. - dist_left / total_dist);
+				result.push(BrushInputSample { position: cur_pos, pressure });
 				dist_until_next_blit = spacing_dist;
 			}
 
 			// Take the partial step to land at the sample.
 			dist_until_next_blit -= dist_left;
 			cur_pos = sample.position;
+			cur_pressure = sample.pressure;
 		}
 
 		result
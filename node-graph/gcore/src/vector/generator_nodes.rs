@@ -1,10 +1,13 @@
-use super::misc::{ArcType, AsU64, GridType};
+use super::barcode::{QrCode, encode_barcode};
+use super::geojson::{GeoShape, parse_geojson};
+use super::misc::{ArcType, AsU64, BarcodeFormat, GridType, MapProjection, PatternType, QrErrorCorrection};
 use super::{PointId, SegmentId, StrokeId};
-use crate::Ctx;
-use crate::registry::types::Angle;
+use crate::registry::types::{Angle, Percentage};
+use crate::vector::style::Fill;
 use crate::vector::{HandleId, VectorData, VectorDataTable};
+use crate::{Color, Ctx};
 use bezier_rs::Subpath;
-use glam::DVec2;
+use glam::{DAffine2, DVec2};
 
 trait CornerRadius {
 	fn generate(self, size: DVec2, clamped: bool) -> VectorDataTable;
@@ -240,6 +243,202 @@ fn grid<T: GridSpacing>(
 	VectorDataTable::new(vector_data)
 }
 
+/// Draws the shapes of a checkerboard, stripes, polka dots, or bricks pattern covering the given number of cells, to be used as a tile or background.
+#[node_macro::node(category("Vector: Shape"))]
+#[allow(clippy::too_many_arguments)]
+fn pattern_shapes(
+	_: impl Ctx,
+	_primary: (),
+	pattern_type: PatternType,
+	/// The width and height of each cell of the pattern.
+	#[default(20.)]
+	#[min(0.)]
+	cell_size: f64,
+	rotation: Angle,
+	/// The proportion of each cell taken up by the width of a stripe, the diameter of a dot, or a brick, where the rest is mortar.
+	#[default(50.)]
+	#[range((0., 100.))]
+	size: Percentage,
+	#[default(10)] columns: u32,
+	#[default(10)] rows: u32,
+	#[default(Color::BLACK)] color: Color,
+) -> VectorDataTable {
+	let size = (size / 100.).clamp(0., 1.);
+	let (columns, rows) = (columns as f64, rows as f64);
+	let rectangle = |left: f64, top: f64, right: f64, bottom: f64| {
+		// Shapes along the edges are cut off where the pattern ends
+		let (left, right) = (left.max(0.), right.min(columns));
+		(right > left && bottom > top).then(|| Subpath::new_rect(DVec2::new(left, top), DVec2::new(right, bottom)))
+	};
+
+	let mut subpaths = Vec::new();
+	for row in 0..rows as u32 {
+		let top = row as f64;
+		let offset = pattern_type.row_offset(top);
+		match pattern_type {
+			PatternType::Checkerboard => {
+				subpaths.extend((row % 2..columns as u32).step_by(2).filter_map(|column| rectangle(column as f64, top, column as f64 + 1., top + 1.)));
+			}
+			// Stripes run the full height of the pattern, so they are drawn once rather than per row
+			PatternType::Stripes if row == 0 => {
+				subpaths.extend((0..columns as u32).filter_map(|column| rectangle(column as f64, 0., column as f64 + size, rows)));
+			}
+			PatternType::Stripes => {}
+			PatternType::PolkaDots => {
+				// Dots in shifted rows which would hang over the edge are left out
+				let centers = (0..columns as u32).map(|column| column as f64 + 0.5 - offset).filter(|&center| center > 0.);
+				subpaths.extend(centers.map(|center| Subpath::new_ellipse(DVec2::new(center, top + 0.5) - size / 2., DVec2::new(center, top + 0.5) + size / 2.)));
+			}
+			PatternType::Bricks => {
+				let mortar = (1. - size) / 2.;
+				let bricks = (0..=(columns as u32).div_ceil(2)).map(|brick| brick as f64 * 2. - offset);
+				subpaths.extend(bricks.filter_map(|left| rectangle(left + mortar, top + mortar, left + 2. - mortar, top + 1. - mortar)));
+			}
+		}
+	}
+
+	let transform = DAffine2::from_angle(rotation.to_radians()) * DAffine2::from_scale(DVec2::splat(cell_size));
+	for subpath in &mut subpaths {
+		subpath.apply_transform(transform);
+	}
+
+	let mut vector_data = VectorData::from_subpaths(subpaths, false);
+	vector_data.style.set_fill(Fill::Solid(color));
+	VectorDataTable::new(vector_data)
+}
+
+/// Draws a QR code encoding the text, with its top left corner at the outer edge of the quiet zone. The code grows with the length of the text.
+#[node_macro::node(category("Vector: Shape"))]
+fn qr_code(
+	_: impl Ctx,
+	_primary: (),
+	text: String,
+	/// How much of the code can be damaged or covered while it still scans.
+	error_correction: QrErrorCorrection,
+	/// The width and height of each square module of the code.
+	#[default(4.)]
+	#[min(0.)]
+	module_size: f64,
+	/// The width of the light margin around the code, in modules, which must be kept clear of other artwork for scanners to find the code. The standard calls for 4.
+	#[default(4)]
+	quiet_zone: u32,
+	#[default(Color::BLACK)] color: Color,
+) -> VectorDataTable {
+	let Some(qr_code) = QrCode::encode(&text, error_correction) else {
+		return VectorDataTable::new(VectorData::empty());
+	};
+
+	// Each horizontal run of dark modules becomes one rectangle
+	let mut subpaths = Vec::new();
+	for y in 0..qr_code.size {
+		let mut x = 0;
+		while x < qr_code.size {
+			if !qr_code.get(x, y) {
+				x += 1;
+				continue;
+			}
+			let start = x;
+			while x < qr_code.size && qr_code.get(x, y) {
+				x += 1;
+			}
+			subpaths.push(Subpath::new_rect(DVec2::new(start as f64, y as f64), DVec2::new(x as f64, y as f64 + 1.)));
+		}
+	}
+
+	let transform = DAffine2::from_scale(DVec2::splat(module_size)) * DAffine2::from_translation(DVec2::splat(quiet_zone as f64));
+	for subpath in &mut subpaths {
+		subpath.apply_transform(transform);
+	}
+
+	let mut vector_data = VectorData::from_subpaths(subpaths, false);
+	vector_data.style.set_fill(Fill::Solid(color));
+	VectorDataTable::new(vector_data)
+}
+
+/// Draws a 1D barcode encoding the text, with its top left corner at the outer edge of the left quiet zone. Nothing is drawn if the text has characters the format can't encode, or a wrong check digit.
+#[node_macro::node(category("Vector: Shape"))]
+fn barcode(
+	_: impl Ctx,
+	_primary: (),
+	text: String,
+	format: BarcodeFormat,
+	/// The width of the narrowest bar or space.
+	#[default(2.)]
+	#[min(0.)]
+	bar_width: f64,
+	#[default(60.)]
+	#[min(0.)]
+	height: f64,
+	/// The width of the light margin on each side of the bars, in multiples of the narrowest bar, which must be kept clear of other artwork for scanners to read the code.
+	#[default(10)]
+	quiet_zone: u32,
+	#[default(Color::BLACK)] color: Color,
+) -> VectorDataTable {
+	let Some(modules) = encode_barcode(&text, format) else {
+		return VectorDataTable::new(VectorData::empty());
+	};
+
+	let mut subpaths = Vec::new();
+	let mut x = 0;
+	while x < modules.len() {
+		if !modules[x] {
+			x += 1;
+			continue;
+		}
+		let start = x;
+		while x < modules.len() && modules[x] {
+			x += 1;
+		}
+		let left = (quiet_zone as usize + start) as f64 * bar_width;
+		let right = (quiet_zone as usize + x) as f64 * bar_width;
+		subpaths.push(Subpath::new_rect(DVec2::new(left, 0.), DVec2::new(right, height)));
+	}
+
+	let mut vector_data = VectorData::from_subpaths(subpaths, false);
+	vector_data.style.set_fill(Fill::Solid(color));
+	VectorDataTable::new(vector_data)
+}
+
+/// Draws the features of a GeoJSON document as a map, with polygons as closed paths, lines as open paths, and points as small circles.
+#[node_macro::node(name("GeoJSON"), category("Vector: Shape"))]
+fn geojson(
+	_: impl Ctx,
+	_primary: (),
+	#[name("GeoJSON")] geojson: String,
+	projection: MapProjection,
+	/// The longitude and latitude, in degrees, placed at the origin.
+	center: DVec2,
+	/// The width of a degree of longitude.
+	#[default(10.)]
+	#[min(0.)]
+	scale: f64,
+	#[default(2.)]
+	#[min(0.)]
+	point_radius: f64,
+) -> VectorDataTable {
+	let Some(shapes) = parse_geojson(&geojson) else {
+		return VectorDataTable::new(VectorData::empty());
+	};
+
+	// North is up on the page, where the y-axis points down
+	let origin = projection.project(center);
+	let project = |coordinate: DVec2| (projection.project(coordinate) - origin) * DVec2::new(scale, -scale);
+
+	let subpaths = shapes
+		.iter()
+		.map(|shape| match shape {
+			GeoShape::Point(position) => {
+				let center = project(*position);
+				Subpath::new_ellipse(center - point_radius, center + point_radius)
+			}
+			GeoShape::LineString(positions) => Subpath::from_anchors(positions.iter().copied().map(project), false),
+			GeoShape::Ring(positions) => Subpath::from_anchors(positions.iter().copied().map(project), true),
+		})
+		.collect::<Vec<_>>();
+
+	VectorDataTable::new(VectorData::from_subpaths(subpaths, false))
+}
+
 #[test]
 fn isometric_grid_test() {
 	// Doesn't crash with weird angles
@@ -259,3 +458,29 @@ fn isometric_grid_test() {
 		);
 	}
 }
+
+#[test]
+fn pattern_shapes_test() {
+	let count = |pattern_type| {
+		pattern_shapes((), (), pattern_type, 10., 0., 50., 4, 3, Color::BLACK)
+			.one_instance()
+			.instance
+			.stroke_bezier_paths()
+			.count()
+	};
+	assert_eq!(count(PatternType::Checkerboard), 6);
+	assert_eq!(count(PatternType::Stripes), 4);
+	// Shifted rows have one dot fewer
+	assert_eq!(count(PatternType::PolkaDots), 4 + 3 + 4);
+	// Shifted rows begin and end with half bricks
+	assert_eq!(count(PatternType::Bricks), 2 + 3 + 2);
+
+	// The shapes match the cells the texture covers
+	for pattern_type in PatternType::list() {
+		assert!(pattern_type.covers(DVec2::new(0.4, 0.5), 0.5));
+	}
+	assert!(!PatternType::Checkerboard.covers(DVec2::new(1.5, 0.5), 0.5));
+	assert!(!PatternType::Stripes.covers(DVec2::new(0.75, 0.5), 0.5));
+	assert!(!PatternType::PolkaDots.covers(DVec2::new(0.5, 1.5), 0.5));
+	assert!(PatternType::Bricks.covers(DVec2::new(0., 1.5), 0.5));
+}
@@ -0,0 +1,128 @@
+//! Reading the geometry of GeoJSON (RFC 7946) documents, for drawing maps as vector paths.
+
+use glam::DVec2;
+use serde_json::Value;
+
+/// A piece of geometry from a GeoJSON document, with positions given as longitude and latitude in degrees.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeoShape {
+	Point(DVec2),
+	LineString(Vec<DVec2>),
+	/// A closed ring of a polygon, without the repeated first position which closes it in the document.
+	Ring(Vec<DVec2>),
+}
+
+impl GeoShape {
+	pub fn positions(&self) -> &[DVec2] {
+		match self {
+			GeoShape::Point(position) => std::slice::from_ref(position),
+			GeoShape::LineString(positions) | GeoShape::Ring(positions) => positions,
+		}
+	}
+}
+
+/// Collects the geometry of every feature in a GeoJSON document, which may be a feature collection, a single feature, or a bare geometry object.
+/// Returns `None` if the text isn't valid JSON. Objects of an unknown type and malformed positions are skipped.
+pub fn parse_geojson(text: &str) -> Option<Vec<GeoShape>> {
+	let value = serde_json::from_str::<Value>(text).ok()?;
+	let mut shapes = Vec::new();
+	collect_shapes(&value, &mut shapes);
+	Some(shapes)
+}
+
+/// The smallest and largest longitude and latitude of all the positions, or `None` if there are none.
+pub fn geojson_bounds(shapes: &[GeoShape]) -> Option<[DVec2; 2]> {
+	let mut positions = shapes.iter().flat_map(GeoShape::positions);
+	let first = *positions.next()?;
+	Some(positions.fold([first, first], |[min, max], &position| [min.min(position), max.max(position)]))
+}
+
+fn collect_shapes(value: &Value, shapes: &mut Vec<GeoShape>) {
+	let coordinates = value.get("coordinates");
+	let Some(kind) = value.get("type").and_then(Value::as_str) else { return };
+
+	match kind {
+		"FeatureCollection" => {
+			for feature in value.get("features").and_then(Value::as_array).into_iter().flatten() {
+				collect_shapes(feature, shapes);
+			}
+		}
+		"Feature" => {
+			if let Some(geometry) = value.get("geometry") {
+				collect_shapes(geometry, shapes);
+			}
+		}
+		"GeometryCollection" => {
+			for geometry in value.get("geometries").and_then(Value::as_array).into_iter().flatten() {
+				collect_shapes(geometry, shapes);
+			}
+		}
+		"Point" => shapes.extend(coordinates.and_then(position).map(GeoShape::Point)),
+		"MultiPoint" => shapes.extend(array(coordinates).filter_map(position).map(GeoShape::Point)),
+		"LineString" => shapes.extend(coordinates.and_then(line).map(GeoShape::LineString)),
+		"MultiLineString" => shapes.extend(array(coordinates).filter_map(line).map(GeoShape::LineString)),
+		"Polygon" => shapes.extend(array(coordinates).filter_map(ring).map(GeoShape::Ring)),
+		"MultiPolygon" => shapes.extend(array(coordinates).flat_map(|polygon| array(Some(polygon))).filter_map(ring).map(GeoShape::Ring)),
+		_ => {}
+	}
+}
+
+fn array(value: Option<&Value>) -> impl Iterator<Item = &Value> {
+	value.and_then(Value::as_array).into_iter().flatten()
+}
+
+/// A position is an array of the longitude, the latitude, and optionally the altitude, which is ignored.
+fn position(value: &Value) -> Option<DVec2> {
+	let coordinates = value.as_array()?;
+	let longitude = coordinates.first()?.as_f64()?;
+	let latitude = coordinates.get(1)?.as_f64()?;
+	Some(DVec2::new(longitude, latitude))
+}
+
+fn line(value: &Value) -> Option<Vec<DVec2>> {
+	let positions = value.as_array()?.iter().map(position).collect::<Option<Vec<_>>>()?;
+	(positions.len() >= 2).then_some(positions)
+}
+
+fn ring(value: &Value) -> Option<Vec<DVec2>> {
+	let mut positions = line(value)?;
+	if positions.len() > 1 && positions.first() == positions.last() {
+		positions.pop();
+	}
+	(positions.len() >= 3).then_some(positions)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn features_and_geometry_types() {
+		let text = r#"{
+			"type": "FeatureCollection",
+			"features": [
+				{ "type": "Feature", "properties": {}, "geometry": { "type": "Point", "coordinates": [2.35, 48.85, 35] } },
+				{ "type": "Feature", "properties": {}, "geometry": { "type": "LineString", "coordinates": [[0, 0], [1, 1], [2, 0]] } },
+				{ "type": "Feature", "properties": {}, "geometry": {
+					"type": "MultiPolygon",
+					"coordinates": [[[[0, 0], [4, 0], [4, 4], [0, 0]]], [[[10, 10], [12, 10], [12, 12], [10, 10]], [[11, 11], [11.5, 11], [11.5, 11.5], [11, 11]]]]
+				} },
+				{ "type": "Feature", "properties": {}, "geometry": null }
+			]
+		}"#;
+		let shapes = parse_geojson(text).unwrap();
+
+		assert_eq!(shapes[0], GeoShape::Point(DVec2::new(2.35, 48.85)));
+		assert!(matches!(&shapes[1], GeoShape::LineString(positions) if positions.len() == 3));
+		// Three rings, including the hole, each without its closing position
+		assert_eq!(shapes[2..].iter().filter(|shape| matches!(shape, GeoShape::Ring(positions) if positions.len() == 3)).count(), 3);
+		assert_eq!(geojson_bounds(&shapes), Some([DVec2::new(0., 0.), DVec2::new(12., 48.85)]));
+	}
+
+	#[test]
+	fn invalid_input() {
+		assert_eq!(parse_geojson("not json"), None);
+		assert_eq!(parse_geojson(r#"{ "type": "Polygon", "coordinates": [[[0, 0], [1]]] }"#), Some(Vec::new()));
+		assert_eq!(geojson_bounds(&[]), None);
+	}
+}
@@ -1,4 +1,5 @@
 use dyn_any::DynAny;
+use glam::DVec2;
 
 /// Represents different ways of calculating the centroid.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
@@ -101,3 +102,230 @@ pub enum ArcType {
 	Closed,
 	PieSlice,
 }
+
+/// The repeating motif drawn by the pattern nodes, laid out on a grid of cells which are each one unit wide and tall.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum PatternType {
+	/// Squares alternating between the motif and the background, like a chessboard.
+	#[default]
+	Checkerboard,
+	/// Vertical bands, one per cell.
+	Stripes,
+	/// A circle in each cell, with every other row shifted over by half a cell.
+	PolkaDots,
+	/// Bricks two cells wide and one cell tall, laid with every other row shifted over by half a brick and separated by mortar.
+	Bricks,
+}
+
+impl PatternType {
+	pub fn list() -> [PatternType; 4] {
+		[PatternType::Checkerboard, PatternType::Stripes, PatternType::PolkaDots, PatternType::Bricks]
+	}
+
+	/// How far over, in cells, the given row of the pattern is shifted.
+	pub fn row_offset(&self, row: f64) -> f64 {
+		let odd = row.floor().rem_euclid(2.) >= 1.;
+		match self {
+			PatternType::PolkaDots if odd => 0.5,
+			PatternType::Bricks if odd => 1.,
+			_ => 0.,
+		}
+	}
+
+	/// Whether the point, measured in cells, lands on the motif rather than the background.
+	/// The size, from 0 to 1, is the proportion of its cell taken up by the width of a stripe, the diameter of a dot, or a brick, where the rest is mortar. It's ignored by checkerboards.
+	pub fn covers(&self, point: DVec2, size: f64) -> bool {
+		let x = point.x + self.row_offset(point.y);
+		let across = point.y.rem_euclid(1.);
+		match self {
+			PatternType::Checkerboard => (x.floor() + point.y.floor()).rem_euclid(2.) < 1.,
+			PatternType::Stripes => x.rem_euclid(1.) < size,
+			PatternType::PolkaDots => (DVec2::new(x.rem_euclid(1.), across) - 0.5).length() < size / 2.,
+			PatternType::Bricks => {
+				// The mortar is equally thick between the rows and between the bricks in a row
+				let mortar = (1. - size) / 2.;
+				let along = x.rem_euclid(2.);
+				along > mortar && along < 2. - mortar && across > mortar && across < 1. - mortar
+			}
+		}
+	}
+}
+
+impl core::fmt::Display for PatternType {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			PatternType::Checkerboard => write!(f, "Checkerboard"),
+			PatternType::Stripes => write!(f, "Stripes"),
+			PatternType::PolkaDots => write!(f, "Polka Dots"),
+			PatternType::Bricks => write!(f, "Bricks"),
+		}
+	}
+}
+
+/// How the longitudes and latitudes of a map are flattened onto the page.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum MapProjection {
+	/// Longitude and latitude are used directly as the horizontal and vertical position, which stretches the map wider away from the equator.
+	#[default]
+	Equirectangular,
+	/// The projection of web maps, which preserves the shapes of small areas but enlarges the areas near the poles.
+	Mercator,
+}
+
+impl MapProjection {
+	/// The latitude beyond which the Mercator projection is cut off, as web maps do, making the whole world a square.
+	const MERCATOR_LATITUDE_LIMIT: f64 = 85.051_128_779_806_59;
+
+	/// Projects a longitude and latitude in degrees onto a plane with the y-axis pointing north, in units matching a degree of longitude.
+	pub fn project(self, coordinate: DVec2) -> DVec2 {
+		match self {
+			MapProjection::Equirectangular => coordinate,
+			MapProjection::Mercator => {
+				let latitude = coordinate.y.clamp(-Self::MERCATOR_LATITUDE_LIMIT, Self::MERCATOR_LATITUDE_LIMIT).to_radians();
+				let y = (std::f64::consts::FRAC_PI_4 + latitude / 2.).tan().ln().to_degrees();
+				DVec2::new(coordinate.x, y)
+			}
+		}
+	}
+}
+
+/// How much of a QR code can be damaged or covered while it still scans, where more error correction makes the code larger for the same text.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum QrErrorCorrection {
+	/// Recovers from about 7% of the code being damaged.
+	Low,
+	/// Recovers from about 15% of the code being damaged.
+	#[default]
+	Medium,
+	/// Recovers from about 25% of the code being damaged.
+	Quartile,
+	/// Recovers from about 30% of the code being damaged, leaving room for a logo over its center.
+	High,
+}
+
+impl QrErrorCorrection {
+	pub fn list() -> [QrErrorCorrection; 4] {
+		[QrErrorCorrection::Low, QrErrorCorrection::Medium, QrErrorCorrection::Quartile, QrErrorCorrection::High]
+	}
+}
+
+impl core::fmt::Display for QrErrorCorrection {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			QrErrorCorrection::Low => write!(f, "Low (7%)"),
+			QrErrorCorrection::Medium => write!(f, "Medium (15%)"),
+			QrErrorCorrection::Quartile => write!(f, "Quartile (25%)"),
+			QrErrorCorrection::High => write!(f, "High (30%)"),
+		}
+	}
+}
+
+/// The symbology of a 1D barcode, which determines the text it can encode.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum BarcodeFormat {
+	/// Any printable ASCII text, common on shipping labels and tickets.
+	#[default]
+	Code128,
+	/// A 13 digit product number, used on retail packaging worldwide.
+	Ean13,
+	/// A 12 digit product number, used on retail packaging in North America.
+	UpcA,
+}
+
+impl BarcodeFormat {
+	pub fn list() -> [BarcodeFormat; 3] {
+		[BarcodeFormat::Code128, BarcodeFormat::Ean13, BarcodeFormat::UpcA]
+	}
+}
+
+impl core::fmt::Display for BarcodeFormat {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			BarcodeFormat::Code128 => write!(f, "Code 128"),
+			BarcodeFormat::Ean13 => write!(f, "EAN-13"),
+			BarcodeFormat::UpcA => write!(f, "UPC-A"),
+		}
+	}
+}
+
+/// A column of a table holding a number for each row, which an instancer can map onto an attribute of the copy it places for that row.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum RowValueColumn {
+	/// Leaves the attribute at its default for every copy.
+	#[default]
+	None,
+	/// The position of the row in the table, counting from 0.
+	Index,
+	/// The opacity of the row, from 0 to 1.
+	Opacity,
+	/// The average scale factor of the row's transform.
+	Scale,
+	/// The rotation of the row's transform, in degrees.
+	Rotation,
+	/// The width of the row's bounding box.
+	Width,
+	/// The height of the row's bounding box.
+	Height,
+}
+
+impl RowValueColumn {
+	pub fn list() -> [RowValueColumn; 7] {
+		[
+			RowValueColumn::None,
+			RowValueColumn::Index,
+			RowValueColumn::Opacity,
+			RowValueColumn::Scale,
+			RowValueColumn::Rotation,
+			RowValueColumn::Width,
+			RowValueColumn::Height,
+		]
+	}
+}
+
+impl core::fmt::Display for RowValueColumn {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			RowValueColumn::None => write!(f, "None"),
+			RowValueColumn::Index => write!(f, "Index"),
+			RowValueColumn::Opacity => write!(f, "Opacity"),
+			RowValueColumn::Scale => write!(f, "Scale"),
+			RowValueColumn::Rotation => write!(f, "Rotation"),
+			RowValueColumn::Width => write!(f, "Width"),
+			RowValueColumn::Height => write!(f, "Height"),
+		}
+	}
+}
+
+/// A column of a table holding a color for each row, which an instancer can use to tint the copy it places for that row.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum RowColorColumn {
+	/// Keeps the original colors of every copy.
+	#[default]
+	None,
+	/// The fill color of the row.
+	Fill,
+	/// The stroke color of the row.
+	Stroke,
+}
+
+impl RowColorColumn {
+	pub fn list() -> [RowColorColumn; 3] {
+		[RowColorColumn::None, RowColorColumn::Fill, RowColorColumn::Stroke]
+	}
+}
+
+impl core::fmt::Display for RowColorColumn {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			RowColorColumn::None => write!(f, "None"),
+			RowColorColumn::Fill => write!(f, "Fill"),
+			RowColorColumn::Stroke => write!(f, "Stroke"),
+		}
+	}
+}
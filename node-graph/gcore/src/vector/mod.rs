@@ -1,6 +1,8 @@
 mod algorithms;
+mod barcode;
 pub mod brush_stroke;
 pub mod generator_nodes;
+pub mod geojson;
 pub mod misc;
 pub mod style;
 mod vector_data;
@@ -1,8 +1,10 @@
 //! Contains stylistic options for SVG elements.
 
-use crate::Color;
 use crate::consts::{LAYER_OUTLINE_STROKE_COLOR, LAYER_OUTLINE_STROKE_WEIGHT};
-use crate::renderer::format_transform_matrix;
+use crate::renderer::{GraphicElementRendered, Quad, RenderParams, RenderSvgSegmentList, SvgRender, format_transform_matrix};
+use crate::vector::PointId;
+use crate::{Color, GraphicGroupTable};
+use bezier_rs::{Bezier, Join, Subpath, TValue};
 use dyn_any::DynAny;
 use glam::{DAffine2, DVec2};
 use std::fmt::{self, Display, Write};
@@ -14,25 +16,146 @@ pub enum GradientType {
 	Radial,
 }
 
+/// How the colors of two neighboring gradient stops are blended across the span between them.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug, Hash, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
+pub enum GradientInterpolation {
+	/// Mixes the RGB channels of the colors, as SVG and CSS gradients do.
+	#[default]
+	Linear,
+	/// Mixes the colors in the perceptual OKLab color space, which keeps saturated complements from passing through a muddy gray.
+	OkLab,
+	/// Mixes the colors in the OKLCH color space, which sweeps the hue around the color wheel so the colors in between stay as saturated as the stops.
+	OkLch,
+	/// Holds the color of the first stop up to the midpoint, then switches to the color of the second stop with a hard edge.
+	Stepped,
+}
+
+impl GradientInterpolation {
+	pub fn label(&self) -> &'static str {
+		match self {
+			Self::Linear => "Linear",
+			Self::OkLab => "OKLab",
+			Self::OkLch => "OKLCH",
+			Self::Stepped => "Stepped",
+		}
+	}
+}
+
+/// The blending options for the span between a gradient stop and the one after it.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
+pub struct GradientSegment {
+	pub interpolation: GradientInterpolation,
+	/// The fraction of the way (in the range 0 to 1) from the first stop to the second where their colors are mixed evenly.
+	pub midpoint: f64,
+}
+
+impl Default for GradientSegment {
+	fn default() -> Self {
+		Self {
+			interpolation: GradientInterpolation::Linear,
+			midpoint: 0.5,
+		}
+	}
+}
+
+impl std::hash::Hash for GradientSegment {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.interpolation.hash(state);
+		self.midpoint.to_bits().hash(state);
+	}
+}
+
+impl GradientSegment {
+	/// Whether the colors are mixed in the way renderers blend between gradient stops, so the segment can be drawn without extra stops.
+	pub fn is_linear(&self) -> bool {
+		self.interpolation == GradientInterpolation::Linear && self.midpoint == 0.5
+	}
+
+	/// The segment running the other way, for when the gradient is reversed.
+	pub fn reversed(&self) -> Self {
+		Self {
+			interpolation: self.interpolation,
+			midpoint: 1. - self.midpoint,
+		}
+	}
+
+	/// The color at `t` (in the range 0 to 1) of the way from the start color to the end color.
+	pub fn blend(&self, start: Color, end: Color, t: f64) -> Color {
+		let midpoint = self.midpoint.clamp(0.01, 0.99);
+
+		match self.interpolation {
+			GradientInterpolation::Stepped => {
+				if t < midpoint {
+					start
+				} else {
+					end
+				}
+			}
+			interpolation => {
+				// Bends the blend so it's half-way between the colors at the midpoint, like CSS color interpolation hints
+				let t = if midpoint == 0.5 { t } else { t.powf(0.5_f64.ln() / midpoint.ln()) };
+				let t = t.clamp(0., 1.) as f32;
+
+				match interpolation {
+					GradientInterpolation::OkLab => start.to_linear_srgb().lerp_oklab(&end.to_linear_srgb(), t).to_gamma_srgb(),
+					GradientInterpolation::OkLch => start.to_linear_srgb().lerp_oklch(&end.to_linear_srgb(), t).to_gamma_srgb(),
+					_ => start.lerp(&end, t),
+				}
+			}
+		}
+	}
+}
+
+/// The number of stops a curved segment is approximated with by renderers which only blend linearly between stops.
+const GRADIENT_SEGMENT_SAMPLES: usize = 16;
+
 // TODO: Someday we could switch this to a Box[T] to avoid over-allocation
 // TODO: Use linear not gamma colors
 /// A list of colors associated with positions (in the range 0 to 1) along a gradient.
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
-pub struct GradientStops(Vec<(f64, Color)>);
+#[derive(Debug, Clone, PartialEq, serde::Serialize, DynAny, specta::Type)]
+pub struct GradientStops {
+	stops: Vec<(f64, Color)>,
+	/// How the color of each stop is blended into the color of the stop after it, with an entry for each stop (where the last one is unused).
+	segments: Vec<GradientSegment>,
+}
+
+impl<'de> serde::Deserialize<'de> for GradientStops {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		use serde::Deserialize;
+
+		// TODO: Eventually remove this migration document upgrade code
+		// Gradients saved before segments could be blended differently are only a list of stops
+		#[derive(serde::Deserialize)]
+		#[serde(untagged)]
+		enum EitherFormat {
+			Stops(Vec<(f64, Color)>),
+			WithSegments { stops: Vec<(f64, Color)>, segments: Vec<GradientSegment> },
+		}
+
+		let (stops, segments) = match EitherFormat::deserialize(deserializer)? {
+			EitherFormat::Stops(stops) => (stops, Vec::new()),
+			EitherFormat::WithSegments { stops, segments } => (stops, segments),
+		};
+		let mut stops = Self { stops, segments };
+		stops.fill_segments();
+		Ok(stops)
+	}
+}
 
 impl std::hash::Hash for GradientStops {
 	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-		self.0.len().hash(state);
-		self.0.iter().for_each(|(position, color)| {
+		self.stops.len().hash(state);
+		self.stops.iter().for_each(|(position, color)| {
 			position.to_bits().hash(state);
 			color.hash(state);
 		});
+		(0..self.stops.len()).for_each(|index| self.segment(index).hash(state));
 	}
 }
 
 impl Default for GradientStops {
 	fn default() -> Self {
-		Self(vec![(0., Color::BLACK), (1., Color::WHITE)])
+		Self::new(vec![(0., Color::BLACK), (1., Color::WHITE)])
 	}
 }
 
@@ -41,7 +164,7 @@ impl IntoIterator for GradientStops {
 	type IntoIter = std::vec::IntoIter<(f64, Color)>;
 
 	fn into_iter(self) -> Self::IntoIter {
-		self.0.into_iter()
+		self.stops.into_iter()
 	}
 }
 
@@ -50,7 +173,7 @@ impl<'a> IntoIterator for &'a GradientStops {
 	type IntoIter = std::slice::Iter<'a, (f64, Color)>;
 
 	fn into_iter(self) -> Self::IntoIter {
-		self.0.iter()
+		self.stops.iter()
 	}
 }
 
@@ -58,7 +181,7 @@ impl std::ops::Index<usize> for GradientStops {
 	type Output = (f64, Color);
 
 	fn index(&self, index: usize) -> &Self::Output {
-		&self.0[index]
+		&self.stops[index]
 	}
 }
 
@@ -66,57 +189,140 @@ impl std::ops::Deref for GradientStops {
 	type Target = Vec<(f64, Color)>;
 
 	fn deref(&self) -> &Self::Target {
-		&self.0
+		&self.stops
 	}
 }
 
 impl std::ops::DerefMut for GradientStops {
 	fn deref_mut(&mut self) -> &mut Self::Target {
-		&mut self.0
+		&mut self.stops
 	}
 }
 
 impl GradientStops {
 	pub fn new(stops: Vec<(f64, Color)>) -> Self {
-		let mut stops = Self(stops);
+		Self::new_with_segments(stops, Vec::new())
+	}
+
+	/// Creates the gradient from its stops and how each one is blended into the next, where missing segments are linear.
+	pub fn new_with_segments(stops: Vec<(f64, Color)>, segments: Vec<GradientSegment>) -> Self {
+		let mut stops = Self { stops, segments };
 		stops.sort();
 		stops
 	}
 
+	/// The blending options between the stop at the index and the one after it.
+	pub fn segment(&self, index: usize) -> GradientSegment {
+		self.segments.get(index).copied().unwrap_or_default()
+	}
+
+	pub fn segments(&self) -> impl Iterator<Item = GradientSegment> + '_ {
+		(0..self.stops.len()).map(|index| self.segment(index))
+	}
+
+	pub fn set_segment(&mut self, index: usize, segment: GradientSegment) {
+		self.fill_segments();
+		if let Some(existing) = self.segments.get_mut(index) {
+			*existing = segment;
+		}
+	}
+
+	/// Matches the segments to the stops, since stops may have been added or removed through the list without them.
+	fn fill_segments(&mut self) {
+		self.segments.resize(self.stops.len(), GradientSegment::default());
+	}
+
+	/// Inserts a stop at the index, which keeps the blending of the segment it splits on both of its sides.
+	pub fn insert(&mut self, index: usize, stop: (f64, Color)) {
+		self.fill_segments();
+		let segment = index.checked_sub(1).map(|previous| self.segment(previous)).unwrap_or_default();
+		self.stops.insert(index, stop);
+		self.segments.insert(index, segment);
+	}
+
+	/// Removes the stop at the index, which joins its neighbors with the blending of the segment before it.
+	pub fn remove(&mut self, index: usize) -> (f64, Color) {
+		self.fill_segments();
+		self.segments.remove(index);
+		self.stops.remove(index)
+	}
+
 	pub fn evaluate(&self, t: f64) -> Color {
-		if self.0.is_empty() {
+		if self.stops.is_empty() {
 			return Color::BLACK;
 		}
 
-		if t <= self.0[0].0 {
-			return self.0[0].1;
+		if t <= self.stops[0].0 {
+			return self.stops[0].1;
 		}
-		if t >= self.0[self.0.len() - 1].0 {
-			return self.0[self.0.len() - 1].1;
+		if t >= self.stops[self.stops.len() - 1].0 {
+			return self.stops[self.stops.len() - 1].1;
 		}
 
-		for i in 0..self.0.len() - 1 {
-			let (t1, c1) = self.0[i];
-			let (t2, c2) = self.0[i + 1];
+		for i in 0..self.stops.len() - 1 {
+			let (t1, c1) = self.stops[i];
+			let (t2, c2) = self.stops[i + 1];
 			if t >= t1 && t <= t2 {
 				let normalized_t = (t - t1) / (t2 - t1);
-				return c1.lerp(&c2, normalized_t as f32);
+				return self.segment(i).blend(c1, c2, normalized_t);
 			}
 		}
 
 		Color::BLACK
 	}
 
+	/// The stops to give renderers which only blend linearly between stops, with extra stops added to approximate segments with other blending options.
+	pub fn rendered_stops(&self) -> Vec<(f64, Color)> {
+		let mut rendered = Vec::with_capacity(self.stops.len());
+
+		for (index, &(position, color)) in self.stops.iter().enumerate() {
+			rendered.push((position, color));
+
+			let Some(&(next_position, next_color)) = self.stops.get(index + 1) else { break };
+			let segment = self.segment(index);
+			if segment.is_linear() {
+				continue;
+			}
+
+			if segment.interpolation == GradientInterpolation::Stepped {
+				// Two stops at the same position make a hard edge
+				let edge = position + (next_position - position) * segment.midpoint.clamp(0.01, 0.99);
+				rendered.extend([(edge, color), (edge, next_color)]);
+				continue;
+			}
+
+			rendered.extend((1..GRADIENT_SEGMENT_SAMPLES).map(|sample| {
+				let t = sample as f64 / GRADIENT_SEGMENT_SAMPLES as f64;
+				(position + (next_position - position) * t, segment.blend(color, next_color, t))
+			}));
+		}
+
+		rendered
+	}
+
 	pub fn sort(&mut self) {
-		self.0.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+		self.fill_segments();
+		let mut paired = std::mem::take(&mut self.stops).into_iter().zip(std::mem::take(&mut self.segments)).collect::<Vec<_>>();
+		paired.sort_by(|a, b| a.0.0.partial_cmp(&b.0.0).unwrap());
+		(self.stops, self.segments) = paired.into_iter().unzip();
 	}
 
 	pub fn reversed(&self) -> Self {
-		Self(self.0.iter().rev().map(|(position, color)| (1. - position, *color)).collect())
+		Self {
+			stops: self.stops.iter().rev().map(|(position, color)| (1. - position, *color)).collect(),
+			// Each segment now follows the stop which came after it
+			segments: (0..self.stops.len())
+				.rev()
+				.map(|index| index.checked_sub(1).map(|previous| self.segment(previous).reversed()).unwrap_or_default())
+				.collect(),
+		}
 	}
 
 	pub fn map_colors<F: Fn(&Color) -> Color>(&self, f: F) -> Self {
-		Self(self.0.iter().map(|(position, color)| (*position, f(color))).collect())
+		Self {
+			stops: self.stops.iter().map(|(position, color)| (*position, f(color))).collect(),
+			segments: self.segments.clone(),
+		}
 	}
 }
 
@@ -147,14 +353,12 @@ impl Default for Gradient {
 
 impl core::hash::Hash for Gradient {
 	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-		self.stops.0.len().hash(state);
+		self.stops.hash(state);
 		[].iter()
 			.chain(self.start.to_array().iter())
 			.chain(self.end.to_array().iter())
 			.chain(self.transform.to_cols_array().iter())
-			.chain(self.stops.0.iter().map(|(position, _)| position))
 			.for_each(|x| x.to_bits().hash(state));
-		self.stops.0.iter().for_each(|(_, color)| color.hash(state));
 		self.gradient_type.hash(state);
 	}
 }
@@ -177,16 +381,16 @@ impl Gradient {
 		let transform = self.transform;
 		let stops = self
 			.stops
-			.0
 			.iter()
-			.zip(other.stops.0.iter())
+			.zip(other.stops.iter())
 			.map(|((a_pos, a_color), (b_pos, b_color))| {
 				let position = a_pos + (b_pos - a_pos) * time;
 				let color = a_color.lerp(b_color, time as f32);
 				(position, color)
 			})
 			.collect::<Vec<_>>();
-		let stops = GradientStops::new(stops);
+		let segments = (if time < 0.5 { &self.stops } else { &other.stops }).segments().collect();
+		let stops = GradientStops::new_with_segments(stops, segments);
 		let gradient_type = if time < 0.5 { self.gradient_type } else { other.gradient_type };
 
 		Self {
@@ -206,7 +410,7 @@ impl Gradient {
 		let transformed_bound_transform = element_transform * DAffine2::from_scale_angle_translation(transformed_bounds[1] - transformed_bounds[0], 0., transformed_bounds[0]);
 
 		let mut stop = String::new();
-		for (position, color) in self.stops.0.iter() {
+		for (position, color) in self.stops.rendered_stops().iter() {
 			stop.push_str("<stop");
 			if *position != 0. {
 				let _ = write!(stop, r#" offset="{}""#, (position * 1_000_000.).round() / 1_000_000.);
@@ -267,37 +471,126 @@ impl Gradient {
 			return None;
 		}
 
-		// Compute the color of the inserted stop
-		let get_color = |index: usize, time: f64| match (self.stops.0[index].1, self.stops.0.get(index + 1).map(|(_, c)| *c)) {
-			// Lerp between the nearest colors if applicable
-			(a, Some(b)) => a.lerp(
-				&b,
-				((time - self.stops.0[index].0) / self.stops.0.get(index + 1).map(|end| end.0 - self.stops.0[index].0).unwrap_or_default()) as f32,
-			),
-			// Use the start or the end color if applicable
-			(v, _) => v,
-		};
-
 		// Compute the correct index to keep the positions in order
 		let mut index = 0;
-		while self.stops.0.len() > index && self.stops.0[index].0 <= new_position {
+		while self.stops.len() > index && self.stops[index].0 <= new_position {
 			index += 1;
 		}
 
-		let new_color = get_color(index - 1, new_position);
-
-		// Insert the new stop
-		self.stops.0.insert(index, (new_position, new_color));
+		// Insert the new stop with the color the gradient already has at its position
+		let new_color = self.stops.evaluate(new_position);
+		self.stops.insert(index, (new_position, new_color));
 
 		Some(index)
 	}
 }
 
+/// The most tiles drawn for a single pattern fill, which keeps a tiny tile inside a huge shape from stalling the renderer.
+const MAX_PATTERN_TILES: usize = 10_000;
+
+/// A graphic repeated in a grid of tiles to fill the inside of a shape.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize, DynAny, specta::Type)]
+pub struct Pattern {
+	/// The graphic drawn in each tile, which is supplied by the Fill node's pattern tile input rather than stored with the fill.
+	#[specta(skip)]
+	pub tile: GraphicGroupTable,
+	/// The gap between neighboring tiles, in addition to the size of the tile graphic's bounding box.
+	pub spacing: DVec2,
+	/// The rotation of the grid of tiles, in degrees.
+	pub rotation: f64,
+	/// The offset of the grid of tiles from the origin of the layer.
+	pub offset: DVec2,
+}
+
+impl core::hash::Hash for Pattern {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.tile.hash(state);
+		[self.spacing.x, self.spacing.y, self.rotation, self.offset.x, self.offset.y]
+			.iter()
+			.for_each(|x| x.to_bits().hash(state));
+	}
+}
+
+impl Pattern {
+	/// The bounding box of the tile graphic, or `None` if there's nothing to draw.
+	fn tile_bounds(&self) -> Option<[DVec2; 2]> {
+		self.tile.bounding_box(DAffine2::IDENTITY).filter(|[min, max]| (*max - *min).cmpgt(DVec2::ZERO).all())
+	}
+
+	/// The size of each cell in the grid of tiles, which is the tile graphic's bounding box enlarged by the spacing.
+	pub fn cell_size(&self) -> Option<DVec2> {
+		let [min, max] = self.tile_bounds()?;
+		Some((max - min + self.spacing).max(DVec2::splat(1e-3)))
+	}
+
+	/// The transform from the pattern's grid, where the first cell starts at the origin, to the space of the filled layer.
+	pub fn grid_transform(&self) -> DAffine2 {
+		DAffine2::from_angle_translation(self.rotation.to_radians(), self.offset)
+	}
+
+	/// The tile graphic moved so its bounding box starts at the origin of its cell.
+	pub fn tile_in_cell(&self) -> GraphicGroupTable {
+		let mut tile = self.tile.clone();
+		let Some([min, _]) = self.tile_bounds() else { return tile };
+
+		for instance in tile.instances_mut() {
+			*instance.transform = DAffine2::from_translation(-min) * *instance.transform;
+		}
+		tile
+	}
+
+	/// The transforms that place a copy of [`Self::tile_in_cell`] in each cell of the grid overlapping the given bounds of the filled layer.
+	pub fn cell_transforms(&self, bounds: [DVec2; 2]) -> Vec<DAffine2> {
+		let Some(cell_size) = self.cell_size() else { return Vec::new() };
+		let grid_transform = self.grid_transform();
+
+		// Find the range of cells covering the layer's bounds once they're mapped into the grid's space
+		let [min, max] = (grid_transform.inverse() * Quad::from_box(bounds)).bounding_box();
+		let start = (min / cell_size).floor();
+		let end = (max / cell_size).ceil();
+		let count = (end - start).max(DVec2::ZERO);
+		if count.x * count.y > MAX_PATTERN_TILES as f64 {
+			return Vec::new();
+		}
+
+		let (start, end) = (start.as_ivec2(), end.as_ivec2());
+		(start.y..end.y)
+			.flat_map(|y| (start.x..end.x).map(move |x| (x, y)))
+			.map(|(x, y)| grid_transform * DAffine2::from_translation(DVec2::new(x as f64, y as f64) * cell_size))
+			.collect()
+	}
+
+	/// Adds the pattern def through mutating the first argument, returning the pattern ID.
+	fn render_defs(&self, svg_defs: &mut String, stroke_transform: DAffine2) -> Option<u64> {
+		let cell_size = self.cell_size()?;
+
+		let mut render = SvgRender::new();
+		self.tile_in_cell().render_svg(&mut render, &RenderParams::default());
+		svg_defs.push_str(&render.svg_defs);
+
+		let pattern_id = crate::uuid::generate_uuid();
+
+		let matrix = format_transform_matrix(stroke_transform * self.grid_transform());
+		let pattern_transform = if matrix.is_empty() { String::new() } else { format!(r#" patternTransform="{}""#, matrix) };
+
+		let _ = write!(
+			svg_defs,
+			r#"<pattern id="{}" patternUnits="userSpaceOnUse" width="{}" height="{}"{pattern_transform}>{}</pattern>"#,
+			pattern_id,
+			cell_size.x,
+			cell_size.y,
+			render.svg.to_svg_string()
+		);
+
+		Some(pattern_id)
+	}
+}
+
 /// Describes the fill of a layer.
 ///
-/// Can be None, a solid [Color], or a linear/radial [Gradient].
+/// Can be None, a solid [Color], a linear/radial [Gradient], or a tiled [Pattern].
 ///
-/// In the future we'll probably also add a pattern fill. This will probably be named "Paint" in the future.
+/// This will probably be named "Paint" in the future.
 #[repr(C)]
 #[derive(Default, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, DynAny, Hash, specta::Type)]
 pub enum Fill {
@@ -305,6 +598,7 @@ pub enum Fill {
 	None,
 	Solid(Color),
 	Gradient(Gradient),
+	Pattern(Pattern),
 }
 
 impl Fill {
@@ -327,7 +621,8 @@ impl Fill {
 			Self::None => Color::BLACK,
 			Self::Solid(color) => *color,
 			// TODO: Should correctly sample the gradient the equation here: https://svgwg.org/svg2-draft/pservers.html#Gradients
-			Self::Gradient(Gradient { stops, .. }) => stops.0[0].1,
+			Self::Gradient(Gradient { stops, .. }) => stops[0].1,
+			Self::Pattern(_) => Color::BLACK,
 		}
 	}
 
@@ -340,17 +635,25 @@ impl Fill {
 			(Self::Solid(a), Self::Solid(b)) => Self::Solid(a.lerp(b, time as f32)),
 			(Self::Solid(a), Self::Gradient(b)) => {
 				let mut solid_to_gradient = b.clone();
-				solid_to_gradient.stops.0.iter_mut().for_each(|(_, color)| *color = *a);
+				solid_to_gradient.stops.iter_mut().for_each(|(_, color)| *color = *a);
 				let a = &solid_to_gradient;
 				Self::Gradient(a.lerp(b, time))
 			}
 			(Self::Gradient(a), Self::Solid(b)) => {
 				let mut gradient_to_solid = a.clone();
-				gradient_to_solid.stops.0.iter_mut().for_each(|(_, color)| *color = *b);
+				gradient_to_solid.stops.iter_mut().for_each(|(_, color)| *color = *b);
 				let b = &gradient_to_solid;
 				Self::Gradient(a.lerp(b, time))
 			}
 			(Self::Gradient(a), Self::Gradient(b)) => Self::Gradient(a.lerp(b, time)),
+			// Patterns can't be blended, so they switch over halfway through
+			(Self::Pattern(_), _) | (_, Self::Pattern(_)) => {
+				if time < 0.5 {
+					self.clone()
+				} else {
+					other.clone()
+				}
+			}
 			_ => Self::None,
 		}
 	}
@@ -370,6 +673,10 @@ impl Fill {
 				let gradient_id = gradient.render_defs(svg_defs, element_transform, stroke_transform, bounds, transformed_bounds);
 				format!(r##" fill="url('#{gradient_id}')""##)
 			}
+			Self::Pattern(pattern) => match pattern.render_defs(svg_defs, stroke_transform) {
+				Some(pattern_id) => format!(r##" fill="url('#{pattern_id}')""##),
+				None => r#" fill="none""#.to_string(),
+			},
 		}
 	}
 
@@ -388,6 +695,14 @@ impl Fill {
 			_ => None,
 		}
 	}
+
+	/// Extract a pattern from the fill
+	pub fn as_pattern(&self) -> Option<&Pattern> {
+		match self {
+			Self::Pattern(pattern) => Some(pattern),
+			_ => None,
+		}
+	}
 }
 
 impl From<Color> for Fill {
@@ -408,6 +723,12 @@ impl From<Gradient> for Fill {
 	}
 }
 
+impl From<Pattern> for Fill {
+	fn from(pattern: Pattern) -> Fill {
+		Fill::Pattern(pattern)
+	}
+}
+
 /// Describes the fill of a layer, but unlike [`Fill`], this doesn't store a [`Gradient`] directly but just its [`GradientStops`].
 ///
 /// Can be None, a solid [Color], or a linear/radial [Gradient].
@@ -456,6 +777,8 @@ impl From<Fill> for FillChoice {
 			Fill::None => FillChoice::None,
 			Fill::Solid(color) => FillChoice::Solid(color),
 			Fill::Gradient(gradient) => FillChoice::Gradient(gradient.stops),
+			// A pattern has no colors of its own to choose from
+			Fill::Pattern(_) => FillChoice::None,
 		}
 	}
 }
@@ -508,6 +831,113 @@ impl Display for LineJoin {
 	}
 }
 
+/// Where the stroke is placed relative to the path of a closed shape. Open paths are always stroked along their center.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum StrokeAlign {
+	#[default]
+	Center,
+	Inside,
+	Outside,
+}
+
+impl Display for StrokeAlign {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			StrokeAlign::Center => write!(f, "Center"),
+			StrokeAlign::Inside => write!(f, "Inside"),
+			StrokeAlign::Outside => write!(f, "Outside"),
+		}
+	}
+}
+
+/// Whether the stroke is painted over the fill of its shape, or beneath it so the fill covers the inner half of the stroke.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum PaintOrder {
+	#[default]
+	StrokeAbove,
+	StrokeBelow,
+}
+
+/// The size of a stroke marker relative to the stroke weight.
+const STROKE_MARKER_SCALE: f64 = 3.;
+
+/// A shape drawn in the stroke color at the start, end, or in-between anchors of a path, which is sized relative to the stroke weight.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, Hash, DynAny, specta::Type)]
+pub enum StrokeMarker {
+	#[default]
+	None,
+	Arrow,
+	Circle,
+	Square,
+	Diamond,
+}
+
+impl Display for StrokeMarker {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			StrokeMarker::None => write!(f, "None"),
+			StrokeMarker::Arrow => write!(f, "Arrow"),
+			StrokeMarker::Circle => write!(f, "Circle"),
+			StrokeMarker::Square => write!(f, "Square"),
+			StrokeMarker::Diamond => write!(f, "Diamond"),
+		}
+	}
+}
+
+impl StrokeMarker {
+	/// The outline of the marker centered on the origin (or with its tip there, for an arrow) and pointing toward positive X, for a stroke of the given weight.
+	fn shape(&self, weight: f64) -> Option<Subpath<PointId>> {
+		let size = weight * STROKE_MARKER_SCALE;
+		let half = size / 2.;
+
+		Some(match self {
+			StrokeMarker::None => return None,
+			StrokeMarker::Arrow => Subpath::from_anchors_linear([DVec2::ZERO, DVec2::new(-size, half), DVec2::new(-size, -half)], true),
+			StrokeMarker::Circle => Subpath::new_ellipse(DVec2::splat(-half), DVec2::splat(half)),
+			StrokeMarker::Square => Subpath::new_rect(DVec2::splat(-half), DVec2::splat(half)),
+			StrokeMarker::Diamond => Subpath::from_anchors_linear([DVec2::new(half, 0.), DVec2::new(0., half), DVec2::new(-half, 0.), DVec2::new(0., -half)], true),
+		})
+	}
+}
+
+/// Splits a subpath at each anchor where its direction changes abruptly. A closed subpath is rotated to begin at one of its corners, so the parts between corners are all open.
+fn split_at_corners(subpath: &Subpath<PointId>) -> Vec<Subpath<PointId>> {
+	let segments = subpath.iter().collect::<Vec<_>>();
+	let is_corner = |incoming: &Bezier, outgoing: &Bezier| {
+		let [incoming, outgoing] = [incoming.tangent(TValue::Parametric(1.)), outgoing.tangent(TValue::Parametric(0.))];
+		incoming.length_squared() == 0. || outgoing.length_squared() == 0. || incoming.angle_to(outgoing).abs() > 1e-3
+	};
+
+	// The index of each segment which begins at a corner
+	let mut corners = (1..segments.len()).filter(|&index| is_corner(&segments[index - 1], &segments[index])).collect::<Vec<_>>();
+	if subpath.closed() {
+		let Some(&last) = segments.last() else { return vec![subpath.clone()] };
+		if is_corner(last, &segments[0]) {
+			corners.insert(0, 0);
+		}
+		// A closed subpath without any corners is kept whole
+		if corners.is_empty() {
+			return vec![subpath.clone()];
+		}
+	} else {
+		corners.insert(0, 0);
+	}
+
+	let start = corners[0];
+	let end = if subpath.closed() { start + segments.len() } else { segments.len() };
+	corners.push(end);
+	corners
+		.windows(2)
+		.map(|bounds| {
+			let beziers = (bounds[0]..bounds[1]).map(|index| segments[index % segments.len()]).collect::<Vec<_>>();
+			Subpath::from_beziers(&beziers, false)
+		})
+		.collect()
+}
+
 fn daffine2_identity() -> DAffine2 {
 	DAffine2::IDENTITY
 }
@@ -521,9 +951,26 @@ pub struct Stroke {
 	pub weight: f64,
 	pub dash_lengths: Vec<f64>,
 	pub dash_offset: f64,
+	/// Stretches the dash pattern between each pair of corners so every corner is centered on a dash.
+	#[serde(default)]
+	pub dash_align_corners: bool,
+	/// Stretches the dash pattern so it repeats a whole number of times along each subpath, instead of being cut off at the end.
+	#[serde(default)]
+	pub dash_fit_length: bool,
 	pub line_cap: LineCap,
 	pub line_join: LineJoin,
 	pub line_join_miter_limit: f64,
+	#[serde(default)]
+	pub align: StrokeAlign,
+	#[serde(default)]
+	pub paint_order: PaintOrder,
+	#[serde(default)]
+	pub start_marker: StrokeMarker,
+	/// The marker placed at every anchor besides the ends of open paths.
+	#[serde(default)]
+	pub mid_marker: StrokeMarker,
+	#[serde(default)]
+	pub end_marker: StrokeMarker,
 	#[serde(default = "daffine2_identity")]
 	pub transform: DAffine2,
 	#[serde(default)]
@@ -537,9 +984,16 @@ impl core::hash::Hash for Stroke {
 		self.dash_lengths.len().hash(state);
 		self.dash_lengths.iter().for_each(|length| length.to_bits().hash(state));
 		self.dash_offset.to_bits().hash(state);
+		self.dash_align_corners.hash(state);
+		self.dash_fit_length.hash(state);
 		self.line_cap.hash(state);
 		self.line_join.hash(state);
 		self.line_join_miter_limit.to_bits().hash(state);
+		self.align.hash(state);
+		self.paint_order.hash(state);
+		self.start_marker.hash(state);
+		self.mid_marker.hash(state);
+		self.end_marker.hash(state);
 		self.non_scaling.hash(state);
 	}
 }
@@ -562,9 +1016,16 @@ impl Stroke {
 			weight,
 			dash_lengths: Vec::new(),
 			dash_offset: 0.,
+			dash_align_corners: false,
+			dash_fit_length: false,
 			line_cap: LineCap::Butt,
 			line_join: LineJoin::Miter,
 			line_join_miter_limit: 4.,
+			align: StrokeAlign::Center,
+			paint_order: PaintOrder::StrokeAbove,
+			start_marker: StrokeMarker::None,
+			mid_marker: StrokeMarker::None,
+			end_marker: StrokeMarker::None,
 			transform: DAffine2::IDENTITY,
 			non_scaling: false,
 		}
@@ -576,9 +1037,16 @@ impl Stroke {
 			weight: self.weight + (other.weight - self.weight) * time,
 			dash_lengths: self.dash_lengths.iter().zip(other.dash_lengths.iter()).map(|(a, b)| a + (b - a) * time).collect(),
 			dash_offset: self.dash_offset + (other.dash_offset - self.dash_offset) * time,
+			dash_align_corners: if time < 0.5 { self.dash_align_corners } else { other.dash_align_corners },
+			dash_fit_length: if time < 0.5 { self.dash_fit_length } else { other.dash_fit_length },
 			line_cap: if time < 0.5 { self.line_cap } else { other.line_cap },
 			line_join: if time < 0.5 { self.line_join } else { other.line_join },
 			line_join_miter_limit: self.line_join_miter_limit + (other.line_join_miter_limit - self.line_join_miter_limit) * time,
+			align: if time < 0.5 { self.align } else { other.align },
+			paint_order: if time < 0.5 { self.paint_order } else { other.paint_order },
+			start_marker: if time < 0.5 { self.start_marker } else { other.start_marker },
+			mid_marker: if time < 0.5 { self.mid_marker } else { other.mid_marker },
+			end_marker: if time < 0.5 { self.end_marker } else { other.end_marker },
 			transform: DAffine2::from_mat2_translation(
 				time * self.transform.matrix2 + (1. - time) * other.transform.matrix2,
 				self.transform.translation * time + other.transform.translation * (1. - time),
@@ -621,6 +1089,128 @@ impl Stroke {
 		self.line_join_miter_limit as f32
 	}
 
+	/// Offsets a closed subpath by half the stroke weight toward its inside or outside, according to the stroke alignment, so a stroke centered on the result covers only that side of the original path.
+	pub fn align_subpath(&self, subpath: Subpath<PointId>) -> Subpath<PointId> {
+		let outward = match self.align {
+			StrokeAlign::Center => return subpath,
+			StrokeAlign::Inside => false,
+			StrokeAlign::Outside => true,
+		};
+		if !subpath.closed() || self.weight <= 0. {
+			return subpath;
+		}
+
+		// Offsetting by a positive distance moves toward the left of the direction of travel, which is the inside of a subpath wound with a positive signed area
+		let anchors = subpath.anchors();
+		let signed_area: f64 = anchors.iter().zip(anchors.iter().cycle().skip(1)).map(|(a, b)| a.perp_dot(*b)).sum();
+		let distance = if (signed_area > 0.) == outward { -self.weight / 2. } else { self.weight / 2. };
+
+		let join = match self.line_join {
+			LineJoin::Miter => Join::Miter(Some(self.line_join_miter_limit)),
+			LineJoin::Bevel => Join::Bevel,
+			LineJoin::Round => Join::Round,
+		};
+		subpath.offset(distance, join)
+	}
+
+	/// The subpath an outside stroke is centered on, which it extends past by half its weight, or the subpath itself for other alignments, which never extend further than that.
+	pub fn outer_subpath(&self, subpath: Subpath<PointId>) -> Subpath<PointId> {
+		match self.align {
+			StrokeAlign::Outside => self.align_subpath(subpath),
+			_ => subpath,
+		}
+	}
+
+	pub fn has_markers(&self) -> bool {
+		[self.start_marker, self.mid_marker, self.end_marker].iter().any(|marker| *marker != StrokeMarker::None)
+	}
+
+	/// The shapes of the markers along a subpath, to be filled with the stroke color. The start and end markers are only placed on open subpaths, while the mid marker is placed on every other anchor.
+	pub fn marker_subpaths(&self, subpath: &Subpath<PointId>) -> Vec<Subpath<PointId>> {
+		if !self.has_markers() || self.weight <= 0. {
+			return Vec::new();
+		}
+		let segments = subpath.iter().collect::<Vec<_>>();
+		let (Some(first), Some(last)) = (segments.first(), segments.last()) else { return Vec::new() };
+
+		// Each marker along with where it's placed and the direction it points, which is away from the path at its start
+		let mut placements = Vec::new();
+		if !subpath.closed() {
+			placements.push((self.start_marker, first.start, -first.tangent(TValue::Parametric(0.))));
+			placements.push((self.end_marker, last.end, last.tangent(TValue::Parametric(1.))));
+		}
+		let mid_anchors = if subpath.closed() { segments.len() } else { segments.len() - 1 };
+		for index in 0..mid_anchors {
+			let (incoming, outgoing) = (segments[index], segments[(index + 1) % segments.len()]);
+			let direction = incoming.tangent(TValue::Parametric(1.)) + outgoing.tangent(TValue::Parametric(0.));
+			placements.push((self.mid_marker, incoming.end, direction));
+		}
+
+		placements
+			.into_iter()
+			.filter_map(|(marker, position, direction)| {
+				let mut shape = marker.shape(self.weight)?;
+				shape.apply_transform(DAffine2::from_angle_translation(direction.y.atan2(direction.x), position));
+				Some(shape)
+			})
+			.collect()
+	}
+
+	/// Whether the dash pattern is adjusted separately for each part of the path, which requires stroking those parts individually instead of with one shared pattern.
+	pub fn has_fitted_dashes(&self) -> bool {
+		(self.dash_align_corners || self.dash_fit_length) && self.dash_lengths.iter().sum::<f64>() > 0.
+	}
+
+	/// Splits a subpath into the parts that are each stroked with their own copy of this stroke, with its dash pattern stretched to fit that part as set by the dash options.
+	pub fn dash_subpath(&self, subpath: Subpath<PointId>) -> Vec<(Subpath<PointId>, Stroke)> {
+		if !self.has_fitted_dashes() || subpath.len_segments() == 0 {
+			return vec![(subpath, self.clone())];
+		}
+
+		// An odd number of lengths repeats with the dash and gap roles swapped, so the full period covers the list twice
+		let mut pattern = self.dash_lengths.iter().map(|length| length.max(0.)).collect::<Vec<_>>();
+		if pattern.len() % 2 == 1 {
+			pattern.extend_from_within(..);
+		}
+		let period: f64 = pattern.iter().sum();
+		let first_dash = pattern[0];
+
+		let closed = subpath.closed();
+		let parts = if self.dash_align_corners { split_at_corners(&subpath) } else { vec![subpath] };
+		let part_count = parts.len();
+
+		parts
+			.into_iter()
+			.enumerate()
+			.map(|(index, part)| {
+				let length = part.length(None);
+
+				// Corners are centered on a dash, so a part between two corners begins and ends halfway through one. The ends of open paths begin or end with a whole dash instead.
+				let (start_offset, end_extent) = if self.dash_align_corners {
+					let starts_at_corner = closed || index > 0;
+					let ends_at_corner = closed || index + 1 < part_count;
+					let start = if starts_at_corner { first_dash / 2. } else { 0. };
+					let end = if ends_at_corner { first_dash / 2. } else { first_dash };
+					(start, end - start)
+				} else if closed {
+					// A closed path wraps around seamlessly after a whole number of periods
+					(0., 0.)
+				} else {
+					(0., first_dash)
+				};
+
+				let repeats = ((length - end_extent) / period).round().max(if end_extent > 0. { 0. } else { 1. });
+				let scale = if repeats * period + end_extent > 0. { length / (repeats * period + end_extent) } else { 1. };
+
+				let mut stroke = self.clone();
+				stroke.dash_lengths = pattern.iter().map(|length| length * scale).collect();
+				// The dash offset shifts the fitted pattern along the part without changing how it's stretched
+				stroke.dash_offset = (start_offset + self.dash_offset).rem_euclid(period) * scale;
+				(part, stroke)
+			})
+			.collect()
+	}
+
 	/// Provide the SVG attributes for the stroke.
 	pub fn render(&self) -> String {
 		// Don't render a stroke at all if it would be invisible
@@ -660,6 +1250,9 @@ impl Stroke {
 		if let Some(line_join_miter_limit) = line_join_miter_limit {
 			let _ = write!(&mut attributes, r#" stroke-miterlimit="{}""#, line_join_miter_limit);
 		}
+		if self.paint_order == PaintOrder::StrokeBelow {
+			let _ = write!(&mut attributes, r#" paint-order="stroke""#);
+		}
 		// Add vector-effect attribute to make strokes non-scaling
 		if self.non_scaling {
 			let _ = write!(&mut attributes, r#" vector-effect="non-scaling-stroke""#);
@@ -667,6 +1260,17 @@ impl Stroke {
 		attributes
 	}
 
+	/// Provide the SVG attributes for filling the markers with the stroke color.
+	pub fn render_marker_fill(&self) -> String {
+		let Some(color) = self.color else { return r#" fill="none""#.to_string() };
+
+		let mut attributes = format!(r##" fill="#{}""##, color.to_rgb_hex_srgb_from_gamma());
+		if color.a() < 1. {
+			let _ = write!(&mut attributes, r#" fill-opacity="{}""#, (color.a() * 1000.).round() / 1000.);
+		}
+		attributes
+	}
+
 	pub fn with_color(mut self, color: &Option<Color>) -> Option<Self> {
 		self.color = *color;
 
@@ -691,11 +1295,33 @@ impl Stroke {
 			})
 	}
 
+	pub fn with_paint_order(mut self, paint_order: PaintOrder) -> Self {
+		self.paint_order = paint_order;
+		self
+	}
+
+	pub fn with_markers(mut self, start_marker: StrokeMarker, mid_marker: StrokeMarker, end_marker: StrokeMarker) -> Self {
+		self.start_marker = start_marker;
+		self.mid_marker = mid_marker;
+		self.end_marker = end_marker;
+		self
+	}
+
 	pub fn with_dash_offset(mut self, dash_offset: f64) -> Self {
 		self.dash_offset = dash_offset;
 		self
 	}
 
+	pub fn with_dash_align_corners(mut self, dash_align_corners: bool) -> Self {
+		self.dash_align_corners = dash_align_corners;
+		self
+	}
+
+	pub fn with_dash_fit_length(mut self, dash_fit_length: bool) -> Self {
+		self.dash_fit_length = dash_fit_length;
+		self
+	}
+
 	pub fn with_line_cap(mut self, line_cap: LineCap) -> Self {
 		self.line_cap = line_cap;
 		self
@@ -711,6 +1337,11 @@ impl Stroke {
 		self
 	}
 
+	pub fn with_align(mut self, align: StrokeAlign) -> Self {
+		self.align = align;
+		self
+	}
+
 	pub fn with_non_scaling(mut self, non_scaling: bool) -> Self {
 		self.non_scaling = non_scaling;
 		self
@@ -725,9 +1356,16 @@ impl Default for Stroke {
 			color: Some(Color::from_rgba8_srgb(0, 0, 0, 255)),
 			dash_lengths: Vec::new(),
 			dash_offset: 0.,
+			dash_align_corners: false,
+			dash_fit_length: false,
 			line_cap: LineCap::Butt,
 			line_join: LineJoin::Miter,
 			line_join_miter_limit: 4.,
+			align: StrokeAlign::Center,
+			paint_order: PaintOrder::StrokeAbove,
+			start_marker: StrokeMarker::None,
+			mid_marker: StrokeMarker::None,
+			end_marker: StrokeMarker::None,
 			transform: DAffine2::IDENTITY,
 			non_scaling: false,
 		}
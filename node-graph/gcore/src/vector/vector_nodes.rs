@@ -1,5 +1,5 @@
-use super::misc::CentroidType;
-use super::style::{Fill, Gradient, GradientStops, Stroke};
+use super::misc::{CentroidType, RowColorColumn, RowValueColumn};
+use super::style::{Fill, Gradient, GradientStops, GradientType, PaintOrder, Stroke, StrokeAlign, StrokeMarker};
 use super::{PointId, SegmentDomain, SegmentId, StrokeId, VectorData, VectorDataTable};
 use crate::instances::{InstanceMut, Instances};
 use crate::registry::types::{Angle, Fraction, IntegerCount, Length, Percentage, PixelLength, SeedValue};
@@ -8,9 +8,12 @@ use crate::transform::{Footprint, Transform, TransformMut};
 use crate::vector::PointDomain;
 use crate::vector::style::{LineCap, LineJoin};
 use crate::{CloneVarArgs, Color, Context, Ctx, ExtractAll, GraphicElement, GraphicGroupTable, OwnedContextImpl};
-use bezier_rs::{Cap, Join, ManipulatorGroup, Subpath, SubpathTValue, TValue};
+use bezier_rs::{Bezier, Cap, Join, ManipulatorGroup, Subpath, SubpathTValue, TValue};
 use core::f64::consts::PI;
 use glam::{DAffine2, DVec2};
+use math_parser::ast;
+use math_parser::context::{EvalContext, NothingMap, ValueProvider};
+use math_parser::value::{Number, Value};
 use rand::{Rng, SeedableRng};
 
 /// Implemented for types that can be converted to an iterator of vector data.
@@ -60,12 +63,18 @@ async fn assign_colors<T>(
 	#[widget(ParsedWidgetOverride::Custom = "assign_colors_repeat_every")]
 	/// The number of elements to span across the gradient before repeating. A 0 value will span the entire gradient once.
 	repeat_every: u32,
+	/// The colors to select from instead of the gradient, if any are given.
+	palette: Vec<Color>,
 ) -> T
 where
 	T: VectorDataTableIterMut + 'n + Send,
 {
 	let length = vector_group.vector_iter_mut().count();
 	let gradient = if reverse { gradient.reversed() } else { gradient };
+	let mut palette = palette;
+	if reverse {
+		palette.reverse();
+	}
 
 	let mut rng = rand::rngs::StdRng::seed_from_u64(seed.into());
 
@@ -79,7 +88,10 @@ where
 			},
 		};
 
-		let color = gradient.evaluate(factor);
+		let color = match palette.len() {
+			0 => gradient.evaluate(factor),
+			len => palette[((factor * (len - 1) as f64).round() as usize).min(len - 1)],
+		};
 
 		if fill {
 			vector_data.instance.style.set_fill(Fill::Solid(color));
@@ -124,11 +136,16 @@ async fn fill<F: Into<Fill> + 'n + Send, V>(
 	fill: F,
 	_backup_color: Option<Color>,
 	_backup_gradient: Gradient,
+	/// The graphic repeated in a grid of tiles when the fill is a pattern.
+	pattern_tile: GraphicGroupTable,
 ) -> V
 where
 	V: VectorDataTableIterMut + 'n + Send,
 {
-	let fill: Fill = fill.into();
+	let mut fill: Fill = fill.into();
+	if let Fill::Pattern(pattern) = &mut fill {
+		pattern.tile = pattern_tile;
+	}
 	for vector in vector_data.vector_iter_mut() {
 		let mut fill = fill.clone();
 		if let Fill::Gradient(gradient) = &mut fill {
@@ -159,10 +176,16 @@ async fn stroke<C: Into<Option<Color>> + 'n + Send, V>(
 	#[default(2.)]
 	/// The stroke weight.
 	weight: f64,
+	/// Whether the stroke of a closed path is centered on the path, or placed entirely inside or outside of the shape.
+	align: StrokeAlign,
 	/// The stroke dash lengths. Each length forms a distance in a pattern where the first length is a dash, the second is a gap, and so on. If the list is an odd length, the pattern repeats with solid-gap roles reversed.
 	dash_lengths: Vec<f64>,
 	/// The offset distance from the starting point of the dash pattern.
 	dash_offset: f64,
+	/// Stretch the dash pattern between corners so that each corner is centered on a dash.
+	dash_align_corners: bool,
+	/// Stretch the dash pattern so it repeats a whole number of times along the path, so it isn't cut off at the end.
+	dash_fit_length: bool,
 	/// The shape of the stroke at open endpoints.
 	line_cap: crate::vector::style::LineCap,
 	/// The curvature of the bent stroke at sharp corners.
@@ -170,6 +193,14 @@ async fn stroke<C: Into<Option<Color>> + 'n + Send, V>(
 	#[default(4.)]
 	/// The threshold for when a miter-joined stroke is converted to a bevel-joined stroke when a sharp angle becomes pointier than this ratio.
 	miter_limit: f64,
+	/// Whether the stroke is painted over the fill, or beneath it so the fill covers the inner half of the stroke.
+	paint_order: PaintOrder,
+	/// The shape drawn at the start of open paths.
+	start_marker: StrokeMarker,
+	/// The shape drawn at every anchor besides the ends of open paths.
+	mid_marker: StrokeMarker,
+	/// The shape drawn at the end of open paths.
+	end_marker: StrokeMarker,
 ) -> Instances<V>
 where
 	Instances<V>: VectorDataTableIterMut + 'n + Send,
@@ -179,9 +210,16 @@ where
 		weight,
 		dash_lengths,
 		dash_offset,
+		dash_align_corners,
+		dash_fit_length,
 		line_cap,
 		line_join,
 		line_join_miter_limit: miter_limit,
+		align,
+		paint_order,
+		start_marker,
+		mid_marker,
+		end_marker,
 		transform: DAffine2::IDENTITY,
 		non_scaling: false,
 	};
@@ -194,6 +232,90 @@ where
 	vector_data
 }
 
+/// The variables available to the per-copy expression of the repeat nodes.
+/// `n` is the zero-based copy index, `count` is the total number of copies, and `t` is the copy index normalized to the 0 to 1 range.
+struct RepeatIndexContext {
+	index: f64,
+	count: f64,
+}
+
+impl ValueProvider for RepeatIndexContext {
+	fn get_value(&self, name: &str) -> Option<Value> {
+		match name {
+			"n" | "N" => Some(Value::from_f64(self.index)),
+			"count" => Some(Value::from_f64(self.count)),
+			"t" => Some(Value::from_f64(if self.count > 1. { self.index / (self.count - 1.) } else { 0. })),
+			_ => None,
+		}
+	}
+}
+
+/// The per-copy adjustments shared by the repeat nodes, which are applied to each copy about its own center before it's moved into place.
+struct RepeatIncrements {
+	rotation: f64,
+	scale: f64,
+	opacity_falloff: f64,
+	expression: Option<ast::Node>,
+}
+
+impl RepeatIncrements {
+	fn new(rotation: Angle, scale: f64, opacity_falloff: Percentage, expression: &str) -> Self {
+		let expression = if expression.trim().is_empty() {
+			None
+		} else {
+			match ast::Node::try_parse_from_str(expression) {
+				Ok((node, _unit)) => Some(node),
+				Err(e) => {
+					warn!("Invalid per-copy expression: `{expression}`\n{e:?}");
+					None
+				}
+			}
+		};
+
+		Self {
+			rotation: rotation.to_radians(),
+			scale,
+			opacity_falloff: opacity_falloff / 100.,
+			expression,
+		}
+	}
+
+	/// Evaluates the per-copy expression as a scale multiplier, falling back to 1 if there is no expression or it can't be evaluated.
+	fn expression_scale(&self, index: usize, count: usize) -> f64 {
+		let Some(expression) = &self.expression else { return 1. };
+
+		let context = EvalContext::new(
+			RepeatIndexContext {
+				index: index as f64,
+				count: count as f64,
+			},
+			NothingMap,
+		);
+		match expression.eval(&context) {
+			Ok(Value::Number(Number::Real(value))) => value,
+			Ok(Value::Number(Number::Complex(value))) => value.re,
+			Err(e) => {
+				warn!("Per-copy expression evaluation error: {e:?}");
+				1.
+			}
+		}
+	}
+
+	/// The transform which rotates and scales the copy at the given index about the `center` of the original content.
+	fn transform(&self, index: usize, count: usize, center: DVec2) -> DAffine2 {
+		let rotation = self.rotation * index as f64;
+		let scale = self.scale.powi(index as i32) * self.expression_scale(index, count);
+
+		DAffine2::from_translation(center) * DAffine2::from_scale_angle_translation(DVec2::splat(scale), rotation, DVec2::ZERO) * DAffine2::from_translation(-center)
+	}
+
+	/// The opacity multiplier for the copy at the given index, fading linearly from full opacity at the first copy to the falloff amount at the last copy.
+	fn opacity(&self, index: usize, count: usize) -> f32 {
+		let progress = if count > 1 { index as f64 / (count - 1) as f64 } else { 0. };
+		(1. - self.opacity_falloff * progress).clamp(0., 1.) as f32
+	}
+}
+
 #[node_macro::node(category("Vector"), path(graphene_core::vector))]
 async fn repeat<I: 'n + Send>(
 	_: impl Ctx,
@@ -204,6 +326,16 @@ async fn repeat<I: 'n + Send>(
 	direction: DVec2,
 	angle: Angle,
 	#[default(4)] instances: IntegerCount,
+	/// The additional rotation applied to each successive copy about its own center.
+	rotation_increment: Angle,
+	/// The factor each successive copy is scaled by relative to the previous copy, about its own center.
+	#[default(1.)]
+	scale_increment: f64,
+	/// The amount of opacity lost by the last copy, fading linearly from the first copy.
+	#[range((0., 100.))]
+	opacity_falloff: Percentage,
+	/// An optional math expression evaluated for each copy to produce an extra scale multiplier. It may use "n" (the copy index), "count" (the number of copies), and "t" (the copy index from 0 to 1), such as "1 + 0.5 * sin(n)".
+	index_expression: String,
 ) -> GraphicGroupTable
 where
 	Instances<I>: GraphicElementRendered,
@@ -211,6 +343,7 @@ where
 	let angle = angle.to_radians();
 	let instances = instances.max(1);
 	let total = (instances - 1) as f64;
+	let increments = RepeatIncrements::new(rotation_increment, scale_increment, opacity_falloff, &index_expression);
 
 	let mut result_table = GraphicGroupTable::default();
 
@@ -222,12 +355,14 @@ where
 		let angle = index as f64 * angle / total;
 		let translation = index as f64 * direction / total;
 		let modification = DAffine2::from_translation(center) * DAffine2::from_angle(angle) * DAffine2::from_translation(translation) * DAffine2::from_translation(-center);
+		let increment = increments.transform(index as usize, instances as usize, center);
 
 		let mut new_graphic_element = instance.to_graphic_element().clone();
 		new_graphic_element.new_ids_from_hash(Some(crate::uuid::NodeId(index as u64)));
 
 		let new_instance = result_table.push(new_graphic_element);
-		*new_instance.transform = modification;
+		*new_instance.transform = modification * increment;
+		new_instance.alpha_blending.opacity *= increments.opacity(index as usize, instances as usize);
 	}
 
 	result_table
@@ -241,11 +376,22 @@ async fn circular_repeat<I: 'n + Send>(
 	angle_offset: Angle,
 	#[default(5)] radius: f64,
 	#[default(5)] instances: IntegerCount,
+	/// The additional rotation applied to each successive copy about its own center.
+	rotation_increment: Angle,
+	/// The factor each successive copy is scaled by relative to the previous copy, about its own center.
+	#[default(1.)]
+	scale_increment: f64,
+	/// The amount of opacity lost by the last copy, fading linearly from the first copy.
+	#[range((0., 100.))]
+	opacity_falloff: Percentage,
+	/// An optional math expression evaluated for each copy to produce an extra scale multiplier. It may use "n" (the copy index), "count" (the number of copies), and "t" (the copy index from 0 to 1), such as "1 + 0.5 * sin(n)".
+	index_expression: String,
 ) -> GraphicGroupTable
 where
 	Instances<I>: GraphicElementRendered,
 {
 	let instances = instances.max(1);
+	let increments = RepeatIncrements::new(rotation_increment, scale_increment, opacity_falloff, &index_expression);
 
 	let mut result_table = GraphicGroupTable::default();
 
@@ -257,12 +403,14 @@ where
 	for index in 0..instances {
 		let rotation = DAffine2::from_angle((std::f64::consts::TAU / instances as f64) * index as f64 + angle_offset.to_radians());
 		let modification = DAffine2::from_translation(center) * rotation * DAffine2::from_translation(base_transform);
+		let increment = increments.transform(index as usize, instances as usize, center);
 
 		let mut new_graphic_element = instance.to_graphic_element().clone();
 		new_graphic_element.new_ids_from_hash(Some(crate::uuid::NodeId(index as u64)));
 
 		let new_instance = result_table.push(new_graphic_element);
-		*new_instance.transform = modification;
+		*new_instance.transform = modification * increment;
+		new_instance.alpha_blending.opacity *= increments.opacity(index as usize, instances as usize);
 	}
 
 	result_table
@@ -337,6 +485,85 @@ where
 	result_table
 }
 
+#[node_macro::node(category("Vector"), path(graphene_core::vector), properties("instance_on_rows_properties"))]
+async fn instance_on_rows<I: 'n + Send>(
+	_: impl Ctx,
+	/// The table whose rows each receive one copy of the instance, placed at the center of the row's bounding box.
+	table: VectorDataTable,
+	#[expose]
+	#[implementations(VectorDataTable, GraphicGroupTable)]
+	instance: Instances<I>,
+	/// The column of the table whose color is applied to each copy.
+	color_column: RowColorColumn,
+	/// The column of the table which, multiplied by the scale factor, scales each copy.
+	scale_column: RowValueColumn,
+	#[default(1.)] scale_factor: f64,
+	/// The column of the table which, multiplied by the rotation factor, rotates each copy in degrees.
+	rotation_column: RowValueColumn,
+	#[default(1.)] rotation_factor: f64,
+) -> GraphicGroupTable
+where
+	Instances<I>: GraphicElementRendered,
+{
+	let instance_bounding_box = instance.bounding_box(DAffine2::IDENTITY).unwrap_or_default();
+	let instance_center = -0.5 * (instance_bounding_box[0] + instance_bounding_box[1]);
+
+	let mut result_table = GraphicGroupTable::default();
+
+	for (index, row) in table.instances().enumerate() {
+		let Some([min, max]) = row.instance.bounding_box_with_transform(*row.transform) else { continue };
+
+		let row_value = |column: RowValueColumn| match column {
+			RowValueColumn::None => None,
+			RowValueColumn::Index => Some(index as f64),
+			RowValueColumn::Opacity => Some(row.alpha_blending.opacity as f64),
+			RowValueColumn::Scale => Some(row.transform.matrix2.determinant().abs().sqrt()),
+			RowValueColumn::Rotation => Some(row.transform.matrix2.x_axis.y.atan2(row.transform.matrix2.x_axis.x).to_degrees()),
+			RowValueColumn::Width => Some(max.x - min.x),
+			RowValueColumn::Height => Some(max.y - min.y),
+		};
+		let scale = row_value(scale_column).map_or(1., |value| value * scale_factor);
+		let rotation = row_value(rotation_column).map_or(0., |value| value * rotation_factor);
+
+		let color = match color_column {
+			RowColorColumn::None => None,
+			RowColorColumn::Fill => match row.instance.style.fill() {
+				Fill::None => None,
+				fill => Some(fill.color()),
+			},
+			RowColorColumn::Stroke => row.instance.style.stroke().and_then(|stroke| stroke.color()),
+		};
+
+		let mut new_graphic_element = instance.to_graphic_element().clone();
+		new_graphic_element.new_ids_from_hash(Some(crate::uuid::NodeId(index as u64)));
+		if let Some(color) = color {
+			recolor_graphic_element(&mut new_graphic_element, color);
+		}
+
+		let new_instance = result_table.push(new_graphic_element);
+		*new_instance.transform = DAffine2::from_scale_angle_translation(DVec2::splat(scale), rotation.to_radians(), (min + max) / 2.) * DAffine2::from_translation(instance_center);
+	}
+
+	result_table
+}
+
+/// Sets the fill of the vector elements in a graphic element to a solid color, or their stroke color for those without a fill.
+fn recolor_graphic_element(graphic_element: &mut GraphicElement, color: Color) {
+	fn recolor(vector_data: InstanceMut<VectorData>, color: Color) {
+		if *vector_data.instance.style.fill() != Fill::None {
+			vector_data.instance.style.set_fill(Fill::Solid(color));
+		} else if let Some(stroke) = vector_data.instance.style.stroke().and_then(|stroke| stroke.with_color(&Some(color))) {
+			vector_data.instance.style.set_stroke(stroke);
+		}
+	}
+
+	match graphic_element {
+		GraphicElement::VectorData(vector_data) => vector_data.vector_iter_mut().for_each(|vector_data| recolor(vector_data, color)),
+		GraphicElement::GraphicGroup(graphic_group) => graphic_group.vector_iter_mut().for_each(|vector_data| recolor(vector_data, color)),
+		GraphicElement::RasterFrame(_) => {}
+	}
+}
+
 #[node_macro::node(category("Vector"), path(graphene_core::vector))]
 async fn mirror<I: 'n + Send>(
 	_: impl Ctx,
@@ -1046,6 +1273,239 @@ async fn solidify_stroke(_: impl Ctx, vector_data: VectorDataTable) -> VectorDat
 	result
 }
 
+/// Gives a shape pseudo-3D depth by extruding it along a direction, with the shape in front and its sides drawn in darker shades of its fill color, as if lit from the top left.
+#[node_macro::node(category("Vector"), path(graphene_core::vector))]
+async fn extrude(
+	_: impl Ctx,
+	vector_data: VectorDataTable,
+	/// The direction the sides recede in, where 0° is to the right and 90° is down.
+	#[default(45.)]
+	direction: Angle,
+	#[default(20.)]
+	#[min(0.)]
+	depth: Length,
+	/// The length of a lighter rim along the front of the sides, like the edge of a beveled shape.
+	#[min(0.)]
+	bevel: Length,
+	/// How much darker the sides are than the front, for the sides facing away from the light.
+	#[default(50.)]
+	#[range((0., 100.))]
+	shading: Percentage,
+) -> VectorDataTable {
+	/// The number of pieces each curved segment is split into, so the shading of its side changes along the curve.
+	const CURVE_PIECES: usize = 8;
+
+	if depth <= 0. {
+		return vector_data;
+	}
+
+	let vector_data_transform = vector_data.transform();
+	let vector_data = vector_data.one_instance().instance;
+
+	let offset = DVec2::from_angle(direction.to_radians()) * depth;
+	let bevel = (bevel.max(0.) / depth).min(1.);
+	let shading = (shading / 100.).clamp(0., 1.) as f32;
+	let to_light = DVec2::new(-1., -1.).normalize();
+	let color = vector_data.style.fill().color();
+
+	// Each side face is a segment swept between two depths, stored with the distance to sort by and its shaded color
+	let mut faces = Vec::new();
+	for mut subpath in vector_data.stroke_bezier_paths() {
+		subpath.apply_transform(vector_data_transform);
+
+		// The winding of a closed subpath tells which side of each segment is its outside, while either side of an open subpath may be seen
+		let winding = subpath.closed().then(|| {
+			let points = subpath
+				.iter_closed()
+				.flat_map(|bezier| bezier.compute_lookup_table(Some(CURVE_PIECES), None).collect::<Vec<_>>())
+				.collect::<Vec<_>>();
+			let doubled_area = points.iter().zip(points.iter().cycle().skip(1)).map(|(a, b)| a.perp_dot(*b)).sum::<f64>();
+			doubled_area.signum()
+		});
+
+		let pieces = subpath.iter().flat_map(|bezier| {
+			let count = if bezier.is_linear() { 1 } else { CURVE_PIECES };
+			(0..count).map(move |i| bezier.trim(TValue::Parametric(i as f64 / count as f64), TValue::Parametric((i + 1) as f64 / count as f64)))
+		});
+		for piece in pieces {
+			let chord = piece.end() - piece.start();
+			if chord.length_squared() < f64::EPSILON {
+				continue;
+			}
+			let mut normal = match winding {
+				Some(winding) => DVec2::new(chord.y, -chord.x) * winding,
+				None => chord.perp(),
+			}
+			.normalize();
+
+			match winding {
+				// Sides facing away from the direction of extrusion are hidden behind the front
+				Some(_) if normal.dot(offset) <= 0. => continue,
+				None if normal.dot(offset) < 0. => normal = -normal,
+				_ => {}
+			}
+
+			let darkness = shading * (0.625 - 0.375 * normal.dot(to_light) as f32);
+			let side_color = color.map_rgb(|channel| channel * (1. - darkness));
+			let rim_color = side_color.lerp(&Color::WHITE.with_alpha(side_color.a()), 0.5);
+
+			let mut sweep = |from: f64, to: f64, fill: Color| {
+				if to <= from {
+					return;
+				}
+				let near = piece.translate(offset * from);
+				let far = piece.translate(offset * to);
+				let face = Subpath::from_beziers(
+					&[
+						near,
+						Bezier::from_linear_dvec2(near.end(), far.end()),
+						far.reverse(),
+						Bezier::from_linear_dvec2(far.start(), near.start()),
+					],
+					true,
+				);
+				let distance = (piece.evaluate(TValue::Parametric(0.5)) + offset * (from + to) / 2.).dot(offset);
+				faces.push((distance, face, fill));
+			};
+			sweep(0., bevel, rim_color);
+			sweep(bevel, 1., side_color);
+		}
+	}
+
+	// Paint the farthest faces first so the nearer ones cover them
+	faces.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+	let mut result = VectorDataTable::empty();
+	for (_, mut face, fill) in faces {
+		face.apply_transform(vector_data_transform.inverse());
+		let mut side = VectorData::from_subpath(face);
+		side.style.set_fill(Fill::Solid(fill));
+		*result.push(side).transform = vector_data_transform;
+	}
+	*result.push(vector_data.clone()).transform = vector_data_transform;
+
+	result
+}
+
+/// Casts a flat-design long shadow beneath the shape by sweeping its silhouette in a direction, optionally fading out towards the end.
+#[node_macro::node(category("Vector"), path(graphene_core::vector))]
+async fn long_shadow(
+	_: impl Ctx,
+	vector_data: VectorDataTable,
+	/// The direction the shadow is cast in, where 0° is to the right and 90° is down.
+	#[default(45.)]
+	direction: Angle,
+	#[default(100.)]
+	#[min(0.)]
+	length: Length,
+	/// How much the shadow fades out towards its end, where 100% makes its tip fully transparent.
+	#[default(100.)]
+	#[range((0., 100.))]
+	fade: Percentage,
+	#[default(Color::BLACK)] color: Color,
+) -> VectorDataTable {
+	if length <= 0. {
+		return vector_data;
+	}
+
+	let vector_data_transform = vector_data.transform();
+	let vector_data = vector_data.one_instance().instance;
+
+	let direction = DVec2::from_angle(direction.to_radians());
+	let offset = direction * length;
+	let to_direction_space = DAffine2::from_angle(-direction.to_angle());
+
+	// Every face is wound the same way, so with the nonzero fill rule their overlaps are filled just once and the translucent shadow has no darker seams
+	let mut faces = Vec::new();
+	let mut projections = Vec::new();
+	for mut subpath in vector_data.stroke_bezier_paths() {
+		subpath.apply_transform(vector_data_transform);
+
+		if subpath.closed() {
+			let points = subpath
+				.iter_closed()
+				.flat_map(|bezier| bezier.compute_lookup_table(Some(8), None).collect::<Vec<_>>())
+				.collect::<Vec<_>>();
+			projections.extend(points.iter().map(|point| point.dot(direction)));
+
+			let doubled_area = points.iter().zip(points.iter().cycle().skip(1)).map(|(a, b)| a.perp_dot(*b)).sum::<f64>();
+			let mut cap = if doubled_area < 0. { subpath.reverse() } else { subpath.clone() };
+			faces.push(cap.clone());
+			cap.apply_transform(DAffine2::from_translation(offset));
+			faces.push(cap);
+		}
+
+		// Split the segments where they turn back across the direction of the shadow, so each swept face is a simple shape
+		for bezier in subpath.iter() {
+			projections.extend([bezier.start(), bezier.end()].map(|point| point.dot(direction)));
+
+			let [_, turns] = bezier.apply_transformation(|point| to_direction_space.transform_point2(point)).local_extrema();
+			let mut splits = turns.filter(|&t| t > 0. && t < 1.).collect::<Vec<_>>();
+			splits.sort_by(f64::total_cmp);
+			splits.insert(0, 0.);
+			splits.push(1.);
+
+			for pair in splits.windows(2) {
+				let piece = bezier.trim(TValue::Parametric(pair[0]), TValue::Parametric(pair[1]));
+				let winding = (piece.end() - piece.start()).perp_dot(offset);
+				if winding.abs() < f64::EPSILON {
+					continue;
+				}
+				let near = if winding > 0. { piece } else { piece.reverse() };
+				let far = near.translate(offset);
+				let face = Subpath::from_beziers(
+					&[
+						near,
+						Bezier::from_linear_dvec2(near.end(), far.end()),
+						far.reverse(),
+						Bezier::from_linear_dvec2(far.start(), near.start()),
+					],
+					true,
+				);
+				faces.push(face);
+			}
+		}
+	}
+
+	if faces.is_empty() {
+		return VectorDataTable::new(vector_data.clone());
+	}
+
+	let inverse_transform = vector_data_transform.inverse();
+	for face in &mut faces {
+		face.apply_transform(inverse_transform);
+	}
+	let mut shadow = VectorData::from_subpaths(faces, false);
+
+	let fade = (fade / 100.).clamp(0., 1.) as f32;
+	if fade > 0. {
+		// The shadow is its full color where it leaves the far side of the shape, fading out until its tip
+		let far_side = projections.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+		let start = inverse_transform.transform_point2(direction * far_side);
+		let end = inverse_transform.transform_point2(direction * (far_side + length));
+
+		let [min, max] = shadow.nonzero_bounding_box();
+		let normalize = |point: DVec2| (point - min) / (max - min);
+		let end_color = color.with_alpha(color.a() * (1. - fade));
+		shadow.style.set_fill(Fill::Gradient(Gradient::new(
+			normalize(start),
+			color,
+			normalize(end),
+			end_color,
+			DAffine2::IDENTITY,
+			GradientType::Linear,
+		)));
+	} else {
+		shadow.style.set_fill(Fill::Solid(color));
+	}
+
+	let mut result = VectorDataTable::empty();
+	*result.push(shadow).transform = vector_data_transform;
+	*result.push(vector_data.clone()).transform = vector_data_transform;
+
+	result
+}
+
 #[node_macro::node(category("Vector"), path(graphene_core::vector))]
 async fn flatten_vector_elements(_: impl Ctx, graphic_group_input: GraphicGroupTable) -> VectorDataTable {
 	// A node based solution to support passing through vector data could be a network node with a cache node connected to
@@ -1237,6 +1697,217 @@ async fn sample_points(_: impl Ctx, vector_data: VectorDataTable, spacing: f64,
 	result
 }
 
+/// Resamples each subpath into a polyline of points evenly spaced by arc length, replacing the original anchors and handles.
+#[node_macro::node(category("Vector"), path(graphene_core::vector))]
+async fn resample_path(
+	_: impl Ctx,
+	/// The path to resample.
+	vector_data: VectorDataTable,
+	/// The target distance between neighboring points, which is widened or narrowed slightly so the points always reach the end of each subpath.
+	#[default(10.)]
+	#[min(0.01)]
+	spacing: Length,
+	/// The number of points to place along each subpath. If nonzero, this is used instead of the spacing.
+	count: IntegerCount,
+	/// Preserve the sharp corners of the path by always placing a point at each one, spacing the points evenly between corners.
+	keep_corners: bool,
+) -> VectorDataTable {
+	// Limit the smallest spacing to something sensible to avoid freezing the application.
+	let spacing = spacing.max(0.01);
+
+	let vector_data_transform = vector_data.transform();
+	let vector_data = vector_data.one_instance().instance;
+
+	let mut result = VectorData::empty();
+	result.style = vector_data.style.clone();
+	result.style.set_stroke_transform(DAffine2::IDENTITY);
+
+	for mut subpath in vector_data.stroke_bezier_paths() {
+		subpath.apply_transform(vector_data_transform);
+
+		let closed = subpath.closed();
+		let beziers = subpath.iter().collect::<Vec<_>>();
+		if beziers.is_empty() {
+			continue;
+		}
+
+		// Split the subpath into spans of segments which meet at sharp corners
+		let spans = if keep_corners { split_beziers_at_corners(beziers, closed) } else { vec![beziers] };
+
+		let span_lengths = spans.iter().map(|span| span.iter().map(|bezier| bezier.length(None)).collect::<Vec<_>>()).collect::<Vec<_>>();
+		let total_length: f64 = span_lengths.iter().flatten().sum();
+		if total_length <= 0. {
+			continue;
+		}
+
+		// The number of intervals between points along the whole subpath, where an open subpath needs one fewer interval than its number of points
+		let total_intervals = match count {
+			0 => (total_length / spacing).round().max(1.),
+			_ if closed => count as f64,
+			_ => count.saturating_sub(1).max(1) as f64,
+		};
+
+		let mut points = Vec::new();
+		for (span, lengths) in spans.iter().zip(&span_lengths) {
+			let span_length: f64 = lengths.iter().sum();
+			let intervals = (total_intervals * span_length / total_length).round().max(1.) as usize;
+
+			for interval in 0..intervals {
+				let distance = interval as f64 / intervals as f64 * span_length;
+				points.push(position_along_beziers(span, lengths, distance));
+			}
+		}
+		if !closed {
+			if let Some(last) = spans.last().and_then(|span| span.last()) {
+				points.push(last.end);
+			}
+		}
+
+		let mut resampled = Subpath::from_anchors_linear(points, closed);
+		resampled.apply_transform(vector_data_transform.inverse());
+		result.append_subpath(resampled, false);
+	}
+
+	let mut result_table = VectorDataTable::new(result);
+	*result_table.transform_mut() = vector_data_transform;
+	result_table
+}
+
+/// Finds the position at the given arc length distance along a chain of connected Bézier segments with precomputed lengths.
+fn position_along_beziers(beziers: &[Bezier], lengths: &[f64], distance: f64) -> DVec2 {
+	let mut remaining = distance;
+	for (bezier, &length) in beziers.iter().zip(lengths) {
+		if remaining <= length && length > 0. {
+			let parametric_t = bezier.euclidean_to_parametric_with_total_length(remaining / length, 0.001, length);
+			return bezier.evaluate(TValue::Parametric(parametric_t));
+		}
+		remaining -= length;
+	}
+
+	beziers.last().map(|bezier| bezier.end).unwrap_or_default()
+}
+
+/// Splits a chain of connected Bézier segments into spans wherever two neighboring segments meet at a sharp corner.
+/// A closed chain is rotated to begin at its first corner so no span wraps around past a corner.
+fn split_beziers_at_corners(beziers: Vec<Bezier>, closed: bool) -> Vec<Vec<Bezier>> {
+	let is_corner = |incoming: &Bezier, outgoing: &Bezier| {
+		let incoming = incoming.tangent(TValue::Parametric(1.)).normalize_or_zero();
+		let outgoing = outgoing.tangent(TValue::Parametric(0.)).normalize_or_zero();
+		incoming != DVec2::ZERO && outgoing != DVec2::ZERO && incoming.angle_to(outgoing).abs() > 1e-3
+	};
+
+	let start = if closed {
+		(0..beziers.len()).find(|&i| is_corner(&beziers[(i + beziers.len() - 1) % beziers.len()], &beziers[i])).unwrap_or(0)
+	} else {
+		0
+	};
+	let beziers = beziers[start..].iter().chain(&beziers[..start]).copied().collect::<Vec<_>>();
+
+	let mut spans = Vec::new();
+	let mut span = vec![beziers[0]];
+	for window in beziers.windows(2) {
+		if is_corner(&window[0], &window[1]) {
+			spans.push(std::mem::take(&mut span));
+		}
+		span.push(window[1]);
+	}
+	spans.push(span);
+
+	spans
+}
+
+/// Outputs only the anchor points of the path which form sharp corners, discarding the smooth anchors and all segments.
+/// The turning angle at each anchor is measured between its incoming and outgoing directions, and junctions of three or more segments are always considered corners.
+#[node_macro::node(category("Vector"), path(graphene_core::vector))]
+async fn corner_points(
+	_: impl Ctx,
+	/// The path to find the corners of.
+	vector_data: VectorDataTable,
+	/// The smallest change in direction, in degrees, at which an anchor is considered a corner rather than smooth.
+	#[range((0., 180.))]
+	#[default(10.)]
+	angle_threshold: Angle,
+) -> VectorDataTable {
+	let vector_data_transform = vector_data.transform();
+	let vector_data = vector_data.one_instance().instance;
+
+	let mut result = VectorData::empty();
+	result.style = vector_data.style.clone();
+
+	let turning_angles = anchor_turning_angles(vector_data, vector_data_transform);
+	for ((&id, &position), turning_angle) in vector_data.point_domain.ids().iter().zip(vector_data.point_domain.positions()).zip(turning_angles) {
+		if turning_angle.is_some_and(|turning_angle| turning_angle >= angle_threshold.to_radians()) {
+			result.point_domain.push(id, position);
+		}
+	}
+
+	let mut result_table = VectorDataTable::new(result);
+	*result_table.transform_mut() = vector_data_transform;
+	result_table
+}
+
+/// Classifies every anchor point of the path as a corner or smooth, in the order of its points.
+/// Each value is the turning angle in degrees at a corner, or 0 for a smooth anchor or an open endpoint.
+#[node_macro::node(category("Vector"), path(graphene_core::vector))]
+async fn corner_angles(
+	_: impl Ctx,
+	/// The path to find the corners of.
+	vector_data: VectorDataTable,
+	/// The smallest change in direction, in degrees, at which an anchor is considered a corner rather than smooth.
+	#[range((0., 180.))]
+	#[default(10.)]
+	angle_threshold: Angle,
+) -> Vec<f64> {
+	let vector_data_transform = vector_data.transform();
+	let vector_data = vector_data.one_instance().instance;
+
+	anchor_turning_angles(vector_data, vector_data_transform)
+		.into_iter()
+		.map(|turning_angle| match turning_angle {
+			Some(turning_angle) if turning_angle >= angle_threshold.to_radians() => turning_angle.to_degrees(),
+			_ => 0.,
+		})
+		.collect()
+}
+
+/// Computes the turning angle in radians at each point, in the order of the point domain.
+/// Points with two connected segments get the angle between their incoming and outgoing directions, junctions of three or more segments get a half turn (π), and open endpoints or unconnected points get `None`.
+fn anchor_turning_angles(vector_data: &VectorData, transform: DAffine2) -> Vec<Option<f64>> {
+	// The tangent direction leaving a point along a segment, nudged inward for the case of a handle lying on top of its anchor
+	fn direction_away(bezier: &Bezier, from_start: bool) -> DVec2 {
+		let (t, nudged_t, sign) = if from_start { (0., 0.001, 1.) } else { (1., 0.999, -1.) };
+		let tangent = bezier.tangent(TValue::Parametric(t));
+		let tangent = if tangent.length_squared() > 1e-12 {
+			tangent
+		} else {
+			bezier.tangent(TValue::Parametric(nudged_t))
+		};
+		(sign * tangent).normalize_or_zero()
+	}
+
+	let mut directions = vec![Vec::new(); vector_data.point_domain.ids().len()];
+
+	let segments = vector_data
+		.segment_bezier_iter()
+		.zip(vector_data.segment_domain.start_point())
+		.zip(vector_data.segment_domain.end_point());
+	for (((_, bezier, _, _), &start), &end) in segments {
+		let bezier = bezier.apply_transformation(|point| transform.transform_point2(point));
+		directions[start].push(direction_away(&bezier, true));
+		directions[end].push(direction_away(&bezier, false));
+	}
+
+	directions
+		.into_iter()
+		.map(|directions| match directions[..] {
+			[first, second] if first != DVec2::ZERO && second != DVec2::ZERO => Some(PI - first.angle_to(second).abs()),
+			[_, _] => Some(0.),
+			[_, _, _, ..] => Some(PI),
+			_ => None,
+		})
+		.collect()
+}
+
 /// Determines the position of a point on the path, given by its progress from 0 to 1 along the path.
 /// If multiple subpaths make up the path, the whole number part of the progress value selects the subpath and the decimal part determines the position along it.
 #[node_macro::node(name("Position on Path"), category("Vector"), path(graphene_core::vector))]
@@ -1770,6 +2441,7 @@ async fn centroid(ctx: impl Ctx + CloneVarArgs + ExtractAll, vector_data: impl N
 mod test {
 	use super::*;
 	use crate::Node;
+	use crate::vector::style::Pattern;
 	use bezier_rs::Bezier;
 	use std::pin::Pin;
 
@@ -1792,7 +2464,18 @@ mod test {
 	async fn repeat() {
 		let direction = DVec2::X * 1.5;
 		let instances = 3;
-		let repeated = super::repeat(Footprint::default(), vector_node(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)), direction, 0., instances).await;
+		let repeated = super::repeat(
+			Footprint::default(),
+			vector_node(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)),
+			direction,
+			0.,
+			instances,
+			0.,
+			1.,
+			0.,
+			String::new(),
+		)
+		.await;
 		let vector_data = super::flatten_vector_elements(Footprint::default(), repeated).await;
 		let vector_data = vector_data.instances().next().unwrap().instance;
 		assert_eq!(vector_data.region_bezier_paths().count(), 3);
@@ -1800,11 +2483,134 @@ mod test {
 			assert!((subpath.manipulator_groups()[0].anchor - direction * index as f64 / (instances - 1) as f64).length() < 1e-5);
 		}
 	}
+	#[tokio::test]
+	async fn stroke_align() {
+		let square = Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.));
+		let stroked = super::stroke(
+			Footprint::default(),
+			vector_node(square.clone()),
+			Color::BLACK,
+			2.,
+			StrokeAlign::Outside,
+			vec![],
+			0.,
+			false,
+			false,
+			LineCap::Butt,
+			LineJoin::Miter,
+			4.,
+			PaintOrder::StrokeAbove,
+			StrokeMarker::None,
+			StrokeMarker::None,
+			StrokeMarker::None,
+		)
+		.await;
+		let stroke = stroked.instances().next().unwrap().instance.style.stroke().unwrap();
+		assert_eq!(stroke.align, StrokeAlign::Outside);
+
+		// The offset is independent of the winding direction of the path
+		for subpath in [square.clone(), square.reverse()] {
+			let [min, max] = stroke.align_subpath(subpath.clone()).bounding_box().unwrap();
+			assert!(min.abs_diff_eq(DVec2::splat(-1.), 1e-5) && max.abs_diff_eq(DVec2::splat(11.), 1e-5));
+
+			let [min, max] = stroke.clone().with_align(StrokeAlign::Inside).align_subpath(subpath).bounding_box().unwrap();
+			assert!(min.abs_diff_eq(DVec2::splat(1.), 1e-5) && max.abs_diff_eq(DVec2::splat(9.), 1e-5));
+		}
+
+		// Open paths keep their centered stroke
+		let line = Subpath::new_line(DVec2::ZERO, DVec2::X * 10.);
+		assert_eq!(stroke.align_subpath(line.clone()), line);
+	}
+	#[test]
+	fn stroke_fitted_dashes() {
+		let is_whole = |value: f64| (value - value.round()).abs() < 1e-5;
+
+		// Each side of the square is centered on a dash at both of its corners
+		let stroke = Stroke::new(Some(Color::BLACK), 1.).with_dash_lengths("2 2").unwrap().with_dash_align_corners(true);
+		let parts = stroke.dash_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		assert_eq!(parts.len(), 4);
+		for (part, part_stroke) in parts {
+			let period: f64 = part_stroke.dash_lengths.iter().sum();
+			assert!(is_whole(part.length(None) / period));
+			assert!((part_stroke.dash_offset - part_stroke.dash_lengths[0] / 2.).abs() < 1e-5);
+		}
+
+		// An open path begins and ends with a whole dash
+		let stroke = Stroke::new(Some(Color::BLACK), 1.).with_dash_lengths("3 1").unwrap().with_dash_fit_length(true);
+		let parts = stroke.dash_subpath(Subpath::new_line(DVec2::ZERO, DVec2::X * 10.));
+		assert_eq!(parts.len(), 1);
+		let (part, part_stroke) = &parts[0];
+		let period: f64 = part_stroke.dash_lengths.iter().sum();
+		assert!(is_whole((part.length(None) - part_stroke.dash_lengths[0]) / period));
+		assert_eq!(part_stroke.dash_offset, 0.);
+
+		// The dash offset is stretched along with the pattern
+		let parts = stroke.with_dash_offset(1.).dash_subpath(Subpath::new_line(DVec2::ZERO, DVec2::X * 10.));
+		let (_, part_stroke) = &parts[0];
+		assert!((part_stroke.dash_offset - part_stroke.dash_lengths[0] / 3.).abs() < 1e-5);
+	}
+	#[test]
+	fn stroke_markers() {
+		let stroke = Stroke::new(Some(Color::BLACK), 2.).with_markers(StrokeMarker::Arrow, StrokeMarker::Circle, StrokeMarker::None);
+		let path = Subpath::from_anchors_linear([DVec2::ZERO, DVec2::X * 10., DVec2::new(10., 10.)], false);
+		let markers = stroke.marker_subpaths(&path);
+
+		// The start arrow has its tip on the first anchor and points away from the path, and the middle anchor gets a circle
+		assert_eq!(markers.len(), 2);
+		let [min, max] = markers[0].bounding_box().unwrap();
+		assert!(min.abs_diff_eq(DVec2::new(0., -3.), 1e-5) && max.abs_diff_eq(DVec2::new(6., 3.), 1e-5));
+		let [min, max] = markers[1].bounding_box().unwrap();
+		assert!(min.abs_diff_eq(DVec2::new(7., -3.), 1e-5) && max.abs_diff_eq(DVec2::new(13., 3.), 1e-5));
+
+		// Closed paths only get mid markers, at every anchor
+		let square = Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.));
+		assert_eq!(stroke.marker_subpaths(&square).len(), 4);
+	}
+	#[tokio::test]
+	async fn pattern_fill() {
+		let tile = vector_node(Subpath::new_rect(DVec2::splat(5.), DVec2::splat(13.)));
+		let pattern = Pattern {
+			spacing: DVec2::splat(2.),
+			..Default::default()
+		};
+		let filled = super::fill(
+			Footprint::default(),
+			vector_node(Subpath::new_rect(DVec2::ZERO, DVec2::splat(20.))),
+			Fill::Pattern(pattern),
+			None,
+			Gradient::default(),
+			tile.into(),
+		)
+		.await;
+		let pattern = filled.instances().next().unwrap().instance.style.fill().as_pattern().unwrap();
+
+		// Each 8 px tile plus the 2 px spacing forms a 10 px cell, so a 20 px square is covered by a 2x2 grid of cells
+		assert_eq!(pattern.cell_size(), Some(DVec2::splat(10.)));
+		let cell_transforms = pattern.cell_transforms([DVec2::ZERO, DVec2::splat(20.)]);
+		assert_eq!(cell_transforms.len(), 4);
+		assert!(cell_transforms.iter().any(|transform| transform.translation.abs_diff_eq(DVec2::splat(10.), 1e-5)));
+
+		// The tile is moved to the start of its cell
+		let [min, _] = pattern.tile_in_cell().bounding_box(DAffine2::IDENTITY).unwrap();
+		assert!(min.abs_diff_eq(DVec2::ZERO, 1e-5));
+	}
+
 	#[tokio::test]
 	async fn repeat_transform_position() {
 		let direction = DVec2::new(12., 10.);
 		let instances = 8;
-		let repeated = super::repeat(Footprint::default(), vector_node(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)), direction, 0., instances).await;
+		let repeated = super::repeat(
+			Footprint::default(),
+			vector_node(Subpath::new_rect(DVec2::ZERO, DVec2::ONE)),
+			direction,
+			0.,
+			instances,
+			0.,
+			1.,
+			0.,
+			String::new(),
+		)
+		.await;
 		let vector_data = super::flatten_vector_elements(Footprint::default(), repeated).await;
 		let vector_data = vector_data.instances().next().unwrap().instance;
 		assert_eq!(vector_data.region_bezier_paths().count(), 8);
@@ -1814,7 +2620,7 @@ mod test {
 	}
 	#[tokio::test]
 	async fn circular_repeat() {
-		let repeated = super::circular_repeat(Footprint::default(), vector_node(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE)), 45., 4., 8).await;
+		let repeated = super::circular_repeat(Footprint::default(), vector_node(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE)), 45., 4., 8, 0., 1., 0., String::new()).await;
 		let vector_data = super::flatten_vector_elements(Footprint::default(), repeated).await;
 		let vector_data = vector_data.instances().next().unwrap().instance;
 		assert_eq!(vector_data.region_bezier_paths().count(), 8);
@@ -1829,6 +2635,31 @@ mod test {
 		}
 	}
 	#[tokio::test]
+	async fn circular_repeat_increments() {
+		let repeated = super::circular_repeat(
+			Footprint::default(),
+			vector_node(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE)),
+			0.,
+			4.,
+			4,
+			0.,
+			2.,
+			75.,
+			"n + 1".to_string(),
+		)
+		.await;
+		assert_eq!(repeated.instances().count(), 4);
+
+		for (index, instance) in repeated.instances().enumerate() {
+			let expected_scale = 2_f64.powi(index as i32) * (index as f64 + 1.);
+			let actual_scale = instance.transform.matrix2.determinant().abs().sqrt();
+			assert!((actual_scale - expected_scale).abs() < 1e-5, "Expected scale {expected_scale} found {actual_scale}");
+
+			let expected_opacity = 1. - 0.75 * index as f32 / 3.;
+			assert!((instance.alpha_blending.opacity - expected_opacity).abs() < 1e-5);
+		}
+	}
+	#[tokio::test]
 	async fn bounding_box() {
 		let bounding_box = super::bounding_box((), vector_node(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE))).await;
 		let bounding_box = bounding_box.instances().next().unwrap().instance;
@@ -1875,6 +2706,29 @@ mod test {
 		}
 	}
 	#[tokio::test]
+	async fn instance_on_rows() {
+		let mut table = vector_node(Subpath::new_rect(DVec2::NEG_ONE * 10., DVec2::ONE * 10.));
+		table.push(VectorData::from_subpath(Subpath::new_rect(DVec2::splat(40.), DVec2::splat(44.))));
+		table.get_mut(0).unwrap().instance.style.set_fill(Fill::Solid(Color::RED));
+
+		let mut instance = vector_node(Subpath::new_rect(DVec2::NEG_ONE, DVec2::ONE));
+		instance.get_mut(0).unwrap().instance.style.set_fill(Fill::Solid(Color::BLACK));
+
+		let instance_on_rows = super::instance_on_rows(Footprint::default(), table, instance, RowColorColumn::Fill, RowValueColumn::Width, 0.5, RowValueColumn::Index, 90.).await;
+		assert_eq!(instance_on_rows.len(), 2);
+
+		let expected = [(DVec2::ZERO, 10., 0., Color::RED), (DVec2::splat(42.), 2., 90., Color::BLACK)];
+		for (row, (translation, scale, degrees, color)) in instance_on_rows.instances().zip(expected) {
+			let (actual_scale, actual_angle, actual_translation) = row.transform.to_scale_angle_translation();
+			assert!(actual_translation.abs_diff_eq(translation, 1e-6), "Expected {translation} found {actual_translation}");
+			assert!(actual_scale.abs_diff_eq(DVec2::splat(scale), 1e-6), "Expected {scale} found {actual_scale}");
+			assert!((actual_angle - degrees.to_radians()).abs() < 1e-6, "Expected {degrees} found {}", actual_angle.to_degrees());
+
+			let GraphicElement::VectorData(vector_data) = row.instance else { panic!("Expected vector data") };
+			assert_eq!(vector_data.one_instance().instance.style.fill(), &Fill::Solid(color));
+		}
+	}
+	#[tokio::test]
 	async fn sample_points() {
 		let path = Subpath::from_bezier(&Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::ZERO, DVec2::X * 100., DVec2::X * 100.));
 		let sample_points = super::sample_points(Footprint::default(), vector_node(path), 30., 0., 0., false, vec![100.]).await;
@@ -1885,6 +2739,43 @@ mod test {
 		}
 	}
 	#[tokio::test]
+	async fn resample_path() {
+		let resampled = super::resample_path(Footprint::default(), vector_node(Subpath::new_rect(DVec2::ZERO, DVec2::new(100., 50.))), 20., 0, false).await;
+		let resampled = resampled.instances().next().unwrap().instance;
+		assert_eq!(resampled.point_domain.positions().len(), 15);
+		let positions = resampled.point_domain.positions();
+		for (index, pair) in positions.windows(2).enumerate() {
+			let distance = pair[0].distance(pair[1]);
+			assert!(index == 7 || index == 12 || (distance - 20.).abs() < 1e-3, "Expected even spacing but found {distance}");
+		}
+
+		let resampled = super::resample_path(Footprint::default(), vector_node(Subpath::new_rect(DVec2::ZERO, DVec2::new(100., 50.))), 10., 12, true).await;
+		let resampled = resampled.instances().next().unwrap().instance;
+		for corner in [DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., 50.), DVec2::new(0., 50.)] {
+			assert!(
+				resampled.point_domain.positions().iter().any(|position| position.distance(corner) < 1e-3),
+				"Expected a point at the corner {corner}"
+			);
+		}
+	}
+	#[tokio::test]
+	async fn corner_points() {
+		let mut subpath = Subpath::new_rect(DVec2::ZERO, DVec2::ONE * 100.);
+		subpath.insert(SubpathTValue::Parametric { segment_index: 0, t: 0.5 });
+
+		let corners = super::corner_points(Footprint::default(), vector_node(subpath.clone()), 10.).await;
+		let corners = corners.instances().next().unwrap().instance;
+		assert_eq!(corners.point_domain.positions(), &[DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., 100.), DVec2::new(0., 100.)]);
+		assert_eq!(corners.segment_domain.ids().len(), 0);
+
+		let angles = super::corner_angles(Footprint::default(), vector_node(subpath), 10.).await;
+		assert_eq!(angles.len(), 5);
+		assert_eq!(angles.iter().filter(|&&angle| angle == 0.).count(), 1);
+		for angle in angles.into_iter().filter(|&angle| angle != 0.) {
+			assert!((angle - 90.).abs() < 1e-5, "Expected a right angle corner but found {angle}");
+		}
+	}
+	#[tokio::test]
 	async fn adaptive_spacing() {
 		let path = Subpath::from_bezier(&Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::ZERO, DVec2::X * 100., DVec2::X * 100.));
 		let sample_points = super::sample_points(Footprint::default(), vector_node(path), 18., 45., 10., true, vec![100.]).await;
@@ -1950,6 +2841,41 @@ mod test {
 		);
 	}
 
+	#[tokio::test]
+	async fn extrude_square() {
+		let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		square.style.set_fill(Fill::Solid(Color::WHITE));
+		let extruded = super::extrude(Footprint::default(), VectorDataTable::new(square), 45., 5., 0., 50.).await;
+
+		// Only the right and bottom sides face the direction of extrusion, and the front is drawn last
+		assert_eq!(extruded.len(), 3);
+		let front = extruded.instances().last().unwrap().instance;
+		assert_eq!(front.style.fill().color(), Color::WHITE);
+		for side in extruded.instances().take(2) {
+			let shade = side.instance.style.fill().color();
+			assert!(shade.r() < 1. && shade.r() > 0.);
+			let [min, max] = side.instance.bounding_box().unwrap();
+			assert!(max.x > 10. || max.y > 10.);
+			assert!(min.x >= 0. && min.y >= 0.);
+		}
+	}
+
+	#[tokio::test]
+	async fn long_shadow_square() {
+		let square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		let shadowed = super::long_shadow(Footprint::default(), VectorDataTable::new(square), 0., 20., 0., Color::BLACK).await;
+
+		// The shadow is drawn first, beneath the shape, and covers the square swept 20 units to the right
+		assert_eq!(shadowed.len(), 2);
+		let shadow = shadowed.instances().next().unwrap().instance;
+		assert_eq!(shadow.style.fill().color(), Color::BLACK);
+		assert_eq!(shadow.bounding_box(), Some([DVec2::ZERO, DVec2::new(30., 10.)]));
+
+		let square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		let faded = super::long_shadow(Footprint::default(), VectorDataTable::new(square), 0., 20., 100., Color::BLACK).await;
+		assert!(matches!(faded.instances().next().unwrap().instance.style.fill(), Fill::Gradient(_)));
+	}
+
 	#[tokio::test]
 	async fn bevel_rect() {
 		let source = Subpath::new_rect(DVec2::ZERO, DVec2::ONE * 100.);
@@ -146,6 +146,9 @@ pub struct DocumentNode {
 	/// Represents the eye icon for hiding/showing the node in the graph UI. When hidden, a node gets replaced with an identity node during the graph flattening step.
 	#[cfg_attr(feature = "serde", serde(default = "return_true"))]
 	pub visible: bool,
+	/// Represents the bypass toggle for muting the node in the graph UI, separately from its visibility. When bypassed, a node passes its primary input through in place of its output during the graph flattening step.
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub bypassed: bool,
 	/// When two different proto nodes hash to the same value (e.g. two value nodes each containing `2_u32` or two multiply nodes that have the same node IDs as input), the duplicates are removed.
 	/// See [`crate::proto::ProtoNetwork::generate_stable_node_ids`] for details.
 	/// However sometimes this is not desirable, for example in the case of a [`graphene_core::memo::MonitorNode`] that needs to be accessed outside of the graph.
@@ -188,6 +191,7 @@ impl Default for DocumentNode {
 			manual_composition: Default::default(),
 			implementation: Default::default(),
 			visible: true,
+			bypassed: false,
 			skip_deduplication: Default::default(),
 			original_location: OriginalLocation::default(),
 		}
@@ -951,9 +955,9 @@ impl NodeNetwork {
 			warn!("The node which was supposed to be flattened does not exist in the network, id {node_id} network {self:#?}");
 			return;
 		};
-		// If the node is hidden, replace it with an identity node
+		// If the node is hidden or bypassed, replace it with an identity node
 		let identity_node = DocumentNodeImplementation::ProtoNode("graphene_core::ops::IdentityNode".into());
-		if !node.visible && node.implementation != identity_node {
+		if (!node.visible || node.bypassed) && node.implementation != identity_node {
 			node.implementation = identity_node;
 
 			// Connect layer node to the graphic group below
@@ -1418,6 +1422,33 @@ mod test {
 		assert_eq!(flat_network, network);
 	}
 
+	#[test]
+	fn flatten_bypassed() {
+		let mut network = NodeNetwork {
+			exports: vec![NodeInput::node(NodeId(1), 0)],
+			nodes: [(
+				NodeId(1),
+				DocumentNode {
+					inputs: vec![NodeInput::network(concrete!(u32), 0), NodeInput::value(TaggedValue::U32(2), false)],
+					implementation: DocumentNodeImplementation::Network(add_network()),
+					bypassed: true,
+					..Default::default()
+				},
+			)]
+			.into_iter()
+			.collect(),
+			..Default::default()
+		};
+		network.populate_dependants();
+		network.flatten_with_fns(NodeId(1), |self_id, inner_id| NodeId(self_id.0 * 10 + inner_id.0), gen_node_id);
+
+		// The bypassed node is replaced by an identity node passing through its primary input
+		let node = &network.nodes[&NodeId(1)];
+		assert_eq!(network.nodes.len(), 1);
+		assert_eq!(node.implementation, DocumentNodeImplementation::ProtoNode("graphene_core::ops::IdentityNode".into()));
+		assert_eq!(node.inputs, vec![NodeInput::network(concrete!(u32), 0)]);
+	}
+
 	#[test]
 	fn resolve_proto_node_add() {
 		let document_node = DocumentNode {
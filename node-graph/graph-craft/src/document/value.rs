@@ -94,6 +94,19 @@ macro_rules! tagged_value {
 					_ => Err(format!("Cannot convert {:?} to TaggedValue", DynAny::type_name(input.as_ref()))),
 				}
 			}
+			/// Attempts to downcast the dynamic type to a tagged value like [`Self::try_from_any`], but gives the value back if it isn't one of the tagged value types
+			pub fn try_from_dynany(input: DAny<'a>) -> Result<Self, DAny<'a>> {
+				use dyn_any::downcast;
+				use std::any::TypeId;
+
+				match DynAny::type_id(input.as_ref()) {
+					x if x == TypeId::of::<()>() => Ok(TaggedValue::None),
+					$( x if x == TypeId::of::<$ty>() => Ok(TaggedValue::$identifier(*downcast(input).unwrap())), )*
+					x if x == TypeId::of::<RenderOutput>() => Ok(TaggedValue::RenderOutput(*downcast(input).unwrap())),
+					x if x == TypeId::of::<graphene_core::SurfaceFrame>() => Ok(TaggedValue::SurfaceFrame(*downcast(input).unwrap())),
+					_ => Err(input),
+				}
+			}
 			/// Attempts to downcast the dynamic type to a tagged value
 			pub fn try_from_std_any_ref(input: &(dyn std::any::Any)) -> Result<Self, String> {
 				use std::any::TypeId;
@@ -171,6 +184,7 @@ tagged_value! {
 	GraphicElement(graphene_core::GraphicElement),
 	Artboard(graphene_core::Artboard),
 	String(String),
+	FilePath(graphene_core::application_io::FilePath),
 	U32(u32),
 	U64(u64),
 	// TODO: Eventually remove this alias document upgrade code
@@ -205,7 +219,9 @@ tagged_value! {
 	XY(graphene_core::ops::XY),
 	RedGreenBlue(graphene_core::raster::RedGreenBlue),
 	RealTimeMode(graphene_core::animation::RealTimeMode),
+	CharacterAnimation(graphene_core::animation::CharacterAnimation),
 	RedGreenBlueAlpha(graphene_core::raster::RedGreenBlueAlpha),
+	LevelsChannel(graphene_core::raster::LevelsChannel),
 	NoiseType(graphene_core::raster::NoiseType),
 	FractalType(graphene_core::raster::FractalType),
 	CellularDistanceFunction(graphene_core::raster::CellularDistanceFunction),
@@ -213,10 +229,26 @@ tagged_value! {
 	DomainWarpType(graphene_core::raster::DomainWarpType),
 	RelativeAbsolute(graphene_core::raster::RelativeAbsolute),
 	SelectiveColorChoice(graphene_core::raster::SelectiveColorChoice),
+	TonalRange(graphene_core::raster::TonalRange),
+	ColorStatistic(graphene_core::raster::statistics::ColorStatistic),
+	LuminanceStatistic(graphene_core::raster::statistics::LuminanceStatistic),
+	HueBand(graphene_core::raster::HueBand),
+	PrintProfile(graphene_core::raster::cmyk::PrintProfile),
 	GridType(graphene_core::vector::misc::GridType),
 	ArcType(graphene_core::vector::misc::ArcType),
+	RowValueColumn(graphene_core::vector::misc::RowValueColumn),
+	RowColorColumn(graphene_core::vector::misc::RowColorColumn),
+	PatternType(graphene_core::vector::misc::PatternType),
+	QrErrorCorrection(graphene_core::vector::misc::QrErrorCorrection),
+	BarcodeFormat(graphene_core::vector::misc::BarcodeFormat),
+	MapProjection(graphene_core::vector::misc::MapProjection),
+	RandomDistribution(graphene_core::transform::RandomDistribution),
+	FitMode(graphene_core::transform::FitMode),
 	LineCap(graphene_core::vector::style::LineCap),
 	LineJoin(graphene_core::vector::style::LineJoin),
+	StrokeAlign(graphene_core::vector::style::StrokeAlign),
+	PaintOrder(graphene_core::vector::style::PaintOrder),
+	StrokeMarker(graphene_core::vector::style::StrokeMarker),
 	FillType(graphene_core::vector::style::FillType),
 	FillChoice(graphene_core::vector::style::FillChoice),
 	Gradient(graphene_core::vector::style::Gradient),
@@ -228,10 +260,13 @@ tagged_value! {
 	#[cfg_attr(feature = "serde", serde(alias = "ManipulatorGroupIds"))]
 	PointIds(Vec<graphene_core::vector::PointId>),
 	Font(graphene_core::text::Font),
+	TextAlign(graphene_core::text::TextAlign),
+	VerticalAlign(graphene_core::text::VerticalAlign),
 	BrushStrokes(Vec<graphene_core::vector::brush_stroke::BrushStroke>),
 	BrushCache(BrushCache),
 	DocumentNode(DocumentNode),
 	Curve(graphene_core::raster::curve::Curve),
+	Curves(graphene_core::raster::curve::Curves),
 	Footprint(graphene_core::transform::Footprint),
 	Palette(Vec<Color>),
 	VectorModification(Box<graphene_core::vector::VectorModification>),
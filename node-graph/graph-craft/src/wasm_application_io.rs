@@ -290,6 +290,18 @@ impl ApplicationIo for WasmApplicationIo {
 				let data = self.resources.get(&path).ok_or(ApplicationError::NotFound)?.clone();
 				Ok(Box::pin(async move { Ok(data.clone()) }) as ResourceFuture)
 			}
+			// Browsers don't reveal where a file chosen in an open dialog is on disk, so such files are embedded in the document as data URLs instead
+			"data" => {
+				use base64::Engine;
+
+				let (metadata, payload) = url.path().split_once(',').ok_or(ApplicationError::InvalidUrl)?;
+				let data = if metadata.ends_with(";base64") {
+					base64::engine::general_purpose::STANDARD.decode(payload).map_err(|_| ApplicationError::InvalidUrl)?
+				} else {
+					payload.as_bytes().to_vec()
+				};
+				Ok(Box::pin(async move { Ok(Arc::from(data)) }) as ResourceFuture)
+			}
 			_ => Err(ApplicationError::NotFound),
 		}
 	}
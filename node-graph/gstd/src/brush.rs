@@ -1,5 +1,5 @@
 use crate::raster::{BlendImageTupleNode, ExtendImageToBoundsNode, blend_image_closure};
-use glam::{DAffine2, DVec2};
+use glam::{DAffine2, DVec2, UVec2};
 use graph_craft::generic::FnNode;
 use graph_craft::proto::FutureWrapperNode;
 use graphene_core::raster::adjustments::blend_colors;
@@ -11,7 +11,7 @@ use graphene_core::transform::{Transform, TransformMut};
 use graphene_core::value::{ClonedNode, CopiedNode, ValueNode};
 use graphene_core::vector::VectorDataTable;
 use graphene_core::vector::brush_stroke::{BrushStroke, BrushStyle};
-use graphene_core::{Ctx, GraphicElement, Node};
+use graphene_core::{Ctx, ExtractFootprint, GraphicElement, Node};
 
 #[node_macro::node(category("Debug"))]
 fn vector_points(_: impl Ctx, vector_data: VectorDataTable) -> Vec<DVec2> {
@@ -136,6 +136,30 @@ where
 	target
 }
 
+/// Replaces the color of each pixel in a stroke texture with the pixel of `source` found at the given offset in layer space, keeping the stroke's coverage.
+/// This is how clone stamp strokes paint copies of other areas of the image.
+fn clone_stamp(mut stroke_texture: ImageFrameTable<Color>, source: &ImageFrameTable<Color>, offset: DVec2) -> ImageFrameTable<Color> {
+	let stroke_transform = stroke_texture.transform();
+	let image = stroke_texture.one_instance_mut().instance;
+	let pixel_to_layer = stroke_transform * DAffine2::from_scale(DVec2::new(image.width as f64, image.height as f64).recip());
+
+	for y in 0..image.height {
+		for x in 0..image.width {
+			let pixel = image.get_mut(x as usize, y as usize);
+			let coverage = pixel.a();
+			if coverage <= 0. {
+				continue;
+			}
+
+			let sample_position = pixel_to_layer.transform_point2(DVec2::new(x as f64 + 0.5, y as f64 + 0.5)) + offset;
+			let sampled = source.sample(sample_position, DVec2::ONE).unwrap_or(Color::TRANSPARENT);
+			*pixel = sampled.multiplied_alpha(coverage);
+		}
+	}
+
+	stroke_texture
+}
+
 pub async fn create_brush_texture(brush_style: &BrushStyle) -> Image<Color> {
 	let stamp = brush_stamp_generator(brush_style.diameter, brush_style.color, brush_style.hardness, brush_style.flow);
 	let transform = DAffine2::from_scale_angle_translation(DVec2::splat(brush_style.diameter), 0., -DVec2::splat(brush_style.diameter / 2.));
@@ -277,6 +301,12 @@ async fn brush(_: impl Ctx, image_frame_table: ImageFrameTable<Color>, bounds: I
 			cache.cache_results(core::mem::take(&mut draw_strokes), actual_image.clone(), stroke_texture.clone());
 		}
 
+		// Clone stamp strokes use their stroke texture as a mask for the pixels sampled from the image painted so far
+		let stroke_texture = match stroke.clone_offset {
+			Some(offset) => clone_stamp(stroke_texture, &actual_image, offset),
+			None => stroke_texture,
+		};
+
 		// TODO: Is this the correct way to do opacity in blending?
 		actual_image = blend_with_mode(actual_image, stroke_texture, stroke.style.blend_mode, (stroke.style.color.a() * 100.) as f64);
 	}
@@ -330,6 +360,107 @@ async fn brush(_: impl Ctx, image_frame_table: ImageFrameTable<Color>, bounds: I
 	actual_image
 }
 
+/// Replaces the color of each pixel covered by a clone stamp stroke with the pixel painted so far at the given offset in pixels, keeping the stroke's coverage.
+fn clone_stamp_pixels(stroke_image: &mut Image<Color>, painted: &Image<Color>, offset: DVec2) {
+	for y in 0..stroke_image.height {
+		for x in 0..stroke_image.width {
+			let pixel = stroke_image.get_mut(x as usize, y as usize);
+			let coverage = pixel.a();
+			if coverage <= 0. {
+				continue;
+			}
+
+			let source = (DVec2::new(x as f64, y as f64) + offset).round();
+			let sampled = (source.x >= 0. && source.y >= 0.).then(|| painted.get_pixel(source.x as u32, source.y as u32)).flatten();
+			*pixel = sampled.unwrap_or(Color::TRANSPARENT).multiplied_alpha(coverage);
+		}
+	}
+}
+
+/// Paints brush strokes into an image rasterized at the resolution of the current render, so the strokes stay sharp at any zoom level until export.
+/// Each stamp along a stroke is scaled by the stylus pressure recorded at that point. Clone stamp strokes paint copies of the strokes below them.
+#[node_macro::node(category("Raster"))]
+async fn brush_strokes(ctx: impl Ctx + ExtractFootprint, _primary: (), strokes: Vec<BrushStroke>) -> ImageFrameTable<Color> {
+	let footprint = ctx.footprint();
+
+	let Some(stroke_bounds) = strokes.iter().map(|stroke| stroke.bounding_box()).reduce(|a, b| a.union(&b)) else {
+		return ImageFrameTable::one_empty_image();
+	};
+	let mut bounds = footprint.viewport_bounds_in_local_space().intersect(&stroke_bounds);
+
+	// Clone stamp strokes in view copy from areas which may be out of view, so those are painted too, starting from the topmost stroke since it may copy what the ones below it cloned
+	for stroke in strokes.iter().rev() {
+		let Some(offset) = stroke.clone_offset else { continue };
+		let cloned = bounds.intersect(&stroke.bounding_box());
+		if cloned.size().x > 0. && cloned.size().y > 0. {
+			let source = AxisAlignedBbox {
+				start: cloned.start + offset,
+				end: cloned.end + offset,
+			};
+			bounds = bounds.union(&source.intersect(&stroke_bounds));
+		}
+	}
+	let size = bounds.size();
+
+	// If the strokes would not be visible, return an empty image
+	if size.x <= 0. || size.y <= 0. {
+		return ImageFrameTable::one_empty_image();
+	}
+
+	let footprint_scale = footprint.scale();
+	let width = (size.x * footprint_scale.x).ceil() as u32;
+	let height = (size.y * footprint_scale.y).ceil() as u32;
+	let pixel_size = size / DVec2::new(width as f64, height as f64);
+	let pixel_to_layer = DAffine2::from_translation(bounds.start) * DAffine2::from_scale(pixel_size);
+
+	let mut image = Image::new(width, height, Color::TRANSPARENT);
+	for stroke in &strokes {
+		// Stamps are first combined into the stroke so they don't stack up where they overlap, then the stroke is blended with the strokes below it
+		let mut stroke_image = Image::new(width, height, Color::TRANSPARENT);
+
+		for sample in stroke.compute_blit_samples() {
+			let pressure = sample.pressure.clamp(0., 1.);
+			let diameter = stroke.style.diameter * pressure;
+			if diameter <= 0. {
+				continue;
+			}
+
+			let mut stamp = brush_stamp_generator(diameter, stroke.style.color, stroke.style.hardness, stroke.style.flow);
+			*stamp.transform_mut() = DAffine2::from_translation(sample.position) * stamp.transform();
+
+			// Only visit the pixels covered by this stamp
+			let radius = DVec2::splat(diameter / 2.);
+			let start = pixel_to_layer.inverse().transform_point2(sample.position - radius).floor().max(DVec2::ZERO).as_uvec2();
+			let end = pixel_to_layer.inverse().transform_point2(sample.position + radius).ceil().as_uvec2().min(UVec2::new(width, height));
+
+			for y in start.y..end.y {
+				for x in start.x..end.x {
+					// Sample at the pixel's center rather than its corner
+					let position = pixel_to_layer.transform_point2(DVec2::new(x as f64, y as f64) + 0.5);
+					let Some(color) = stamp.sample(position, pixel_size) else { continue };
+
+					let pixel = stroke_image.get_mut(x as usize, y as usize);
+					*pixel = blend_colors(color, *pixel, BlendMode::Normal, 1.);
+				}
+			}
+		}
+
+		if let Some(offset) = stroke.clone_offset {
+			clone_stamp_pixels(&mut stroke_image, &image, offset / pixel_size);
+		}
+
+		for (pixel, stroke_pixel) in image.data.iter_mut().zip(stroke_image.data) {
+			*pixel = blend_colors(stroke_pixel, *pixel, stroke.style.blend_mode, 1.);
+		}
+	}
+
+	let mut result = ImageFrameTable::new(image);
+	*result.transform_mut() = DAffine2::from_translation(bounds.start) * DAffine2::from_scale(size);
+	*result.one_instance_mut().alpha_blending = Default::default();
+
+	result
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -353,7 +484,7 @@ mod test {
 			ImageFrameTable::<Color>::default(),
 			ImageFrameTable::<Color>::default(),
 			vec![BrushStroke {
-				trace: vec![crate::vector::brush_stroke::BrushInputSample { position: DVec2::ZERO }],
+				trace: vec![crate::vector::brush_stroke::BrushInputSample { position: DVec2::ZERO, pressure: 1. }],
 				style: BrushStyle {
 					color: Color::BLACK,
 					diameter: 20.,
@@ -362,10 +493,64 @@ mod test {
 					spacing: 20.,
 					blend_mode: BlendMode::Normal,
 				},
+				clone_offset: None,
 			}],
 			BrushCache::new_proto(),
 		)
 		.await;
 		assert_eq!(image.width(), 20);
 	}
+
+	#[tokio::test]
+	async fn test_brush_strokes_render_resolution() {
+		use graphene_core::OwnedContextImpl;
+		use graphene_core::transform::Footprint;
+		use graphene_core::vector::brush_stroke::BrushInputSample;
+
+		let stroke = BrushStroke {
+			trace: vec![BrushInputSample {
+				position: DVec2::splat(100.),
+				pressure: 1.,
+			}],
+			style: BrushStyle { diameter: 20., ..Default::default() },
+			clone_offset: None,
+		};
+		let footprint = Footprint {
+			transform: DAffine2::from_scale(DVec2::splat(2.)),
+			..Default::default()
+		};
+		let image = brush_strokes(OwnedContextImpl::default().with_footprint(footprint).into_context(), (), vec![stroke]).await;
+
+		// The 20 px stroke is rasterized at twice the resolution since the render is zoomed in by 2x
+		assert_eq!(image.width(), 40);
+		assert_eq!(image.transform(), DAffine2::from_translation(DVec2::splat(90.)) * DAffine2::from_scale(DVec2::splat(20.)));
+	}
+
+	#[test]
+	fn test_clone_stamp() {
+		let mut source_image = Image::new(4, 1, Color::BLACK);
+		source_image.data[3] = Color::WHITE;
+		let mut source = ImageFrameTable::new(source_image);
+		*source.transform_mut() = DAffine2::from_scale(DVec2::new(4., 1.));
+
+		let mut mask = ImageFrameTable::new(Image::new(1, 1, Color::WHITE.multiplied_alpha(0.5)));
+		*mask.transform_mut() = DAffine2::IDENTITY;
+
+		// The mask covers the first pixel, which samples the white pixel three pixels to its right
+		let cloned = clone_stamp(mask, &source, DVec2::new(3., 0.));
+		assert_eq!(cloned.one_instance().instance.data[0], Color::WHITE.multiplied_alpha(0.5));
+	}
+
+	#[test]
+	fn test_clone_stamp_pixels() {
+		let mut painted = Image::new(4, 1, Color::BLACK);
+		painted.data[3] = Color::WHITE;
+		let mut stroke_image = Image::new(4, 1, Color::TRANSPARENT);
+		stroke_image.data[0] = Color::WHITE.multiplied_alpha(0.5);
+
+		// The covered first pixel samples the white pixel three pixels to its right, while the uncovered pixels are left transparent
+		clone_stamp_pixels(&mut stroke_image, &painted, DVec2::new(3., 0.));
+		assert_eq!(stroke_image.data[0], Color::WHITE.multiplied_alpha(0.5));
+		assert_eq!(stroke_image.data[1], Color::TRANSPARENT);
+	}
 }
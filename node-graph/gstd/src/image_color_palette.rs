@@ -1,6 +1,11 @@
 use graphene_core::raster::image::ImageFrameTable;
+use graphene_core::raster::statistics::{ColorStatistic, ImageStatistics, LuminanceStatistic};
+use graphene_core::registry::types::Percentage;
 use graphene_core::{Color, Ctx};
 
+/// The most pixels measured in each image by the image statistics nodes, which are spread evenly across it.
+const STATISTICS_MAX_SAMPLES: usize = 1 << 18;
+
 #[node_macro::node(category("Raster"))]
 async fn image_color_palette(
 	_: impl Ctx,
@@ -61,6 +66,18 @@ async fn image_color_palette(
 	palette
 }
 
+/// Measures the average, dominant, darkest, or lightest color of the visible pixels in the image, to be used elsewhere in the graph, like for matching a background to a photo.
+#[node_macro::node(category("Raster"))]
+async fn image_color(_: impl Ctx, image: ImageFrameTable<Color>, statistic: ColorStatistic) -> Color {
+	ImageStatistics::from_images(image.instances().map(|instance| instance.instance), STATISTICS_MAX_SAMPLES).color(statistic)
+}
+
+/// Measures the lowest, average, or highest luminance of the visible pixels in the image, as a percentage in the same gamma-encoded scale as the input levels of the Levels node.
+#[node_macro::node(category("Raster"))]
+async fn image_luminance(_: impl Ctx, image: ImageFrameTable<Color>, statistic: LuminanceStatistic) -> Percentage {
+	ImageStatistics::from_images(image.instances().map(|instance| instance.instance), STATISTICS_MAX_SAMPLES).luminance(statistic) as f64 * 100.
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -6,7 +6,9 @@ use graphene_core::raster::image::{Image, ImageFrameTable};
 use graphene_core::raster::{
 	Alpha, AlphaMut, Bitmap, BitmapMut, CellularDistanceFunction, CellularReturnType, DomainWarpType, FractalType, Linear, LinearChannel, Luminance, NoiseType, Pixel, RGBMut, RedGreenBlue, Sample,
 };
+use graphene_core::registry::types::{Angle, Percentage, SeedValue};
 use graphene_core::transform::{Transform, TransformMut};
+use graphene_core::vector::misc::PatternType;
 use graphene_core::{AlphaBlending, Color, Ctx, ExtractFootprint, GraphicElement, Node};
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
@@ -479,7 +481,7 @@ fn noise_pattern(
 	ctx: impl ExtractFootprint + Ctx,
 	_primary: (),
 	clip: bool,
-	seed: u32,
+	seed: SeedValue,
 	scale: f64,
 	noise_type: NoiseType,
 	domain_warp_type: DomainWarpType,
@@ -624,6 +626,77 @@ fn noise_pattern(
 	result
 }
 
+/// Fills the visible area with a repeating checkerboard, stripes, polka dots, or bricks texture in the color, over the background color.
+#[node_macro::node(category("Raster"))]
+#[allow(clippy::too_many_arguments)]
+fn pattern_texture(
+	ctx: impl ExtractFootprint + Ctx,
+	_primary: (),
+	clip: bool,
+	pattern_type: PatternType,
+	/// The width and height of each cell of the pattern.
+	#[default(20.)]
+	#[min(0.)]
+	cell_size: f64,
+	rotation: Angle,
+	/// The proportion of each cell taken up by the width of a stripe, the diameter of a dot, or a brick, where the rest is mortar.
+	#[default(50.)]
+	#[range((0., 100.))]
+	size: Percentage,
+	#[default(Color::BLACK)] color: Color,
+	#[default(Color::WHITE)] background: Color,
+) -> ImageFrameTable<Color> {
+	let footprint = ctx.footprint();
+	let viewport_bounds = footprint.viewport_bounds_in_local_space();
+
+	let mut size_in_document = viewport_bounds.size();
+	let mut offset = viewport_bounds.start;
+	if clip {
+		// TODO: Remove "clip" entirely (and its arbitrary 100x100 clipping square) once we have proper resolution-aware layer clipping
+		const CLIPPING_SQUARE_SIZE: f64 = 100.;
+		let image_bounds = Bbox::from_transform(DAffine2::from_scale(DVec2::splat(CLIPPING_SQUARE_SIZE))).to_axis_aligned_bbox();
+		let intersection = viewport_bounds.intersect(&image_bounds);
+
+		offset = (intersection.start - image_bounds.start).max(DVec2::ZERO);
+		size_in_document = intersection.size();
+	}
+
+	// If the image would not be visible, return an empty image
+	if size_in_document.x <= 0. || size_in_document.y <= 0. || cell_size <= 0. {
+		return ImageFrameTable::one_empty_image();
+	}
+
+	let footprint_scale = footprint.scale();
+	let width = (size_in_document.x * footprint_scale.x) as u32;
+	let height = (size_in_document.y * footprint_scale.y) as u32;
+
+	// Maps the position of a pixel to its position in the cells of the pattern
+	let pixel_to_pattern = DAffine2::from_scale(DVec2::splat(1. / cell_size))
+		* DAffine2::from_angle(-rotation.to_radians())
+		* DAffine2::from_translation(offset)
+		* DAffine2::from_scale(size_in_document / DVec2::new(width as f64, height as f64));
+	let size = size / 100.;
+
+	// Each pixel is sampled at four points so the edges of the motif are smoothed
+	const SAMPLES: [DVec2; 4] = [DVec2::new(0.25, 0.25), DVec2::new(0.75, 0.25), DVec2::new(0.25, 0.75), DVec2::new(0.75, 0.75)];
+	let mut image = Image::new(width, height, background);
+	for y in 0..height {
+		for x in 0..width {
+			let pixel = DVec2::new(x as f64, y as f64);
+			let covered = SAMPLES.iter().filter(|&&sample| pattern_type.covers(pixel_to_pattern.transform_point2(pixel + sample), size)).count();
+			if covered > 0 {
+				*image.get_pixel_mut(x, y).unwrap() = background.lerp(&color, covered as f32 / SAMPLES.len() as f32);
+			}
+		}
+	}
+
+	let mut result = ImageFrameTable::new(image);
+	*result.transform_mut() = DAffine2::from_translation(offset) * DAffine2::from_scale(size_in_document);
+	*result.one_instance_mut().alpha_blending = AlphaBlending::default();
+
+	result
+}
+
 #[node_macro::node(category("Raster"))]
 fn mandelbrot(ctx: impl ExtractFootprint + Send) -> ImageFrameTable<Color> {
 	let footprint = ctx.footprint();
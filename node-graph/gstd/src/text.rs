@@ -1,8 +1,8 @@
 use crate::vector::{VectorData, VectorDataTable};
 use graph_craft::wasm_application_io::WasmEditorApi;
 use graphene_core::Ctx;
-use graphene_core::text::TypesettingConfig;
 pub use graphene_core::text::{Font, FontCache, bounding_box, load_face, to_path};
+use graphene_core::text::{TextAlign, TypesettingConfig, VerticalAlign};
 
 #[node_macro::node(category(""))]
 fn text<'i: 'n>(
@@ -15,6 +15,8 @@ fn text<'i: 'n>(
 	#[default(1.)] character_spacing: f64,
 	#[default(None)] max_width: Option<f64>,
 	#[default(None)] max_height: Option<f64>,
+	align: TextAlign,
+	vertical_align: VerticalAlign,
 ) -> VectorDataTable {
 	let buzz_face = editor.font_cache.get(&font_name).map(|data| load_face(data));
 
@@ -24,6 +26,8 @@ fn text<'i: 'n>(
 		character_spacing,
 		max_width,
 		max_height,
+		align,
+		vertical_align,
 	};
 
 	let result = VectorData::from_subpaths(to_path(&text, buzz_face, typesetting), false);
@@ -1,11 +1,14 @@
+use crate::node_cache::{CachedNode, SideEvaluation, SideEvaluationFlag};
 use crate::node_registry;
+use crate::node_timing::{NodeTiming, PathTiming, TimedNode};
 use dyn_any::StaticType;
-use graph_craft::Type;
 use graph_craft::document::NodeId;
 use graph_craft::document::value::{TaggedValue, UpcastAsRefNode, UpcastNode};
 use graph_craft::graphene_compiler::Executor;
 use graph_craft::proto::{ConstructionArgs, GraphError, LocalFuture, NodeContainer, ProtoNetwork, ProtoNode, SharedNodeContainer, TypeErasedBox, TypingContext};
 use graph_craft::proto::{GraphErrorType, GraphErrors};
+use graph_craft::{Type, concrete};
+use graphene_core::Context;
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::panic::UnwindSafe;
@@ -97,6 +100,48 @@ impl DynamicExecutor {
 		self.tree.introspect(node_path)
 	}
 
+	/// Takes the time spent evaluating each node since the timings were last taken, summed up by the document node path of the proto nodes.
+	/// Nodes which weren't evaluated in the meantime are included with no time, so it can be told which ones were skipped.
+	pub fn take_node_timings(&self) -> HashMap<Path, PathTiming> {
+		self.tree.take_timings()
+	}
+
+	/// Evaluates the node at the given document node path with a call argument of its own, such as a context with a different footprint than the graph was last executed with. The node path must match the document node path.
+	/// Its output and those of the nodes it evaluates aren't cached, so they don't evict the outputs cached for the render.
+	pub async fn evaluate_path<I, O>(&self, node_path: &[NodeId], input: I) -> Option<O>
+	where
+		I: StaticType + 'static + Send + Sync,
+		O: StaticType + 'static,
+	{
+		let (id, _) = self.tree.source_map().get(node_path)?;
+		SideEvaluation::new(self.tree.eval(*id, input), self.tree.side_evaluation.clone()).await
+	}
+
+	/// Evaluates the node at the given document node path like [`evaluate_path`](Self::evaluate_path), but converts its output to a [`TaggedValue`] so it can leave the node graph.
+	/// Fails without evaluating the node if it takes a different type of call argument than the one given.
+	pub async fn evaluate_path_tagged_value<I>(&self, node_path: &[NodeId], input: I) -> Result<TaggedValue, String>
+	where
+		I: StaticType + 'static + Send + Sync,
+	{
+		use futures::FutureExt;
+
+		let (id, _) = self.tree.source_map().get(node_path).ok_or("Node not found in executor")?;
+		let call_argument = self.typing_context.type_of(*id).map(|node_io| &node_io.call_argument);
+		if call_argument != Some(&Type::new::<I>()) {
+			return Err(format!("Node is called with {} rather than {}", call_argument.cloned().unwrap_or_default(), Type::new::<I>()));
+		}
+
+		let node = self.tree.get(*id).ok_or("Node not found in executor")?;
+		let output = std::panic::AssertUnwindSafe(SideEvaluation::new(node.eval(Box::new(input)), self.tree.side_evaluation.clone()))
+			.catch_unwind()
+			.await
+			.map_err(|e| {
+				Box::leak(e);
+				"Node evaluation panicked".to_string()
+			})?;
+		TaggedValue::try_from_any(output)
+	}
+
 	pub fn input_type(&self) -> Option<Type> {
 		self.typing_context.type_of(self.output).map(|node_io| node_io.call_argument.clone())
 	}
@@ -178,6 +223,10 @@ pub struct BorrowTree {
 	nodes: HashMap<NodeId, (SharedNodeContainer, Path)>,
 	/// A hashmap from the document path to the proto node ID.
 	source_map: HashMap<Path, (NodeId, NodeTypes)>,
+	/// The time spent evaluating each of the nodes constructed from other nodes, which are wrapped in a [`TimedNode`] to measure it.
+	timings: HashMap<NodeId, Arc<NodeTiming>>,
+	/// Set while the nodes are evaluated for something other than the render, which their [`CachedNode`] caches don't store the outputs of.
+	side_evaluation: SideEvaluationFlag,
 }
 
 impl BorrowTree {
@@ -189,7 +238,11 @@ impl BorrowTree {
 		Ok(nodes)
 	}
 
-	/// Pushes new nodes into the tree and return orphaned nodes
+	/// Pushes new nodes into the tree and return orphaned nodes.
+	///
+	/// Since a proto node's ID is a hash of its construction and the IDs of its inputs, only the nodes whose inputs changed, and the nodes downstream of them, are new.
+	/// Those are marked dirty by being constructed from scratch with empty caches. Every other node is kept along with its [`CachedNode`] cache,
+	/// so changing one input value only re-evaluates the nodes which depend on it.
 	pub async fn update(&mut self, proto_network: ProtoNetwork, typing_context: &TypingContext) -> Result<(Vec<Path>, HashSet<NodeId>), GraphErrors> {
 		let mut old_nodes: HashSet<_> = self.nodes.keys().copied().collect();
 		let mut new_nodes: Vec<_> = Vec::new();
@@ -303,6 +356,7 @@ impl BorrowTree {
 	/// - Returns `None` if the node is not found in the `nodes` HashMap.
 	pub fn free_node(&mut self, id: NodeId) -> Option<Path> {
 		let (_, path) = self.nodes.remove(&id)?;
+		self.timings.remove(&id);
 		if self.source_map.get(&path)?.0 == id {
 			self.source_map.remove(&path);
 			return Some(path);
@@ -393,13 +447,50 @@ impl BorrowTree {
 				let construction_nodes = self.node_deps(&ids);
 				let constructor = typing_context.constructor(id).ok_or_else(|| vec![GraphError::new(&proto_node, GraphErrorType::NoConstructor)])?;
 				let node = constructor(construction_nodes).await;
-				let node = NodeContainer::new(node);
+				let timing = Arc::new(NodeTiming::default());
+				let mut node = NodeContainer::new(Box::new(TimedNode::new(NodeContainer::new(node), timing.clone())));
+				// The cache goes outside of the timing so a node whose cached output is reused counts as not being evaluated
+				if Self::is_cacheable(id, &proto_node, typing_context) {
+					node = NodeContainer::new(Box::new(CachedNode::new(node, self.side_evaluation.clone())));
+				}
+				self.timings.insert(id, timing);
 				self.store_node(node, id, path.into());
 			}
 		};
 		Ok(())
 	}
 
+	/// Whether the output of a node can be reused for as long as it's called with the same context, which is the case when its output can be stored as a [`TaggedValue`].
+	/// Nodes which skip deduplication, like monitor nodes, are evaluated every time because they're observed from outside the graph.
+	/// A brush cache is left out since each copy of one is a separate cache, so a reused copy wouldn't hold the strokes drawn since.
+	fn is_cacheable(id: NodeId, proto_node: &ProtoNode, typing_context: &TypingContext) -> bool {
+		if proto_node.skip_deduplication {
+			return false;
+		}
+		let Some(node_io) = typing_context.type_of(id) else { return false };
+
+		let output = match &node_io.return_value {
+			Type::Future(output) => output.as_ref(),
+			output => output,
+		};
+		if node_io.call_argument != concrete!(Context) || !matches!(output, Type::Concrete(_)) {
+			return false;
+		}
+		let Some(value) = TaggedValue::from_type(output) else { return false };
+		!matches!(value, TaggedValue::BrushCache(_))
+	}
+
+	/// Takes the time spent evaluating each node since the timings were last taken, summed up by the document node path of the proto nodes.
+	pub fn take_timings(&self) -> HashMap<Path, PathTiming> {
+		let mut timings = HashMap::<Path, PathTiming>::new();
+		for (id, timing) in &self.timings {
+			let (duration, evaluations) = timing.take();
+			let Some((_, path)) = self.nodes.get(id) else { continue };
+			timings.entry(path.clone()).or_default().add(duration, evaluations);
+		}
+		timings
+	}
+
 	/// Returns the source map of the borrow tree
 	pub fn source_map(&self) -> &HashMap<Path, (NodeId, NodeTypes)> {
 		&self.source_map
@@ -422,4 +513,52 @@ mod test {
 		let result = futures::executor::block_on(tree.eval(NodeId(0), ()));
 		assert_eq!(result, Some(2u32));
 	}
+
+	#[test]
+	fn only_nodes_downstream_of_a_changed_input_are_evaluated() {
+		use futures::executor::block_on;
+		use graph_craft::ProtoNodeIdentifier;
+		use graph_craft::document::{DocumentNode, DocumentNodeImplementation, NodeInput, NodeNetwork};
+		use graph_craft::graphene_compiler::Compiler;
+
+		let add = |augend: NodeInput, addend: NodeInput| DocumentNode {
+			inputs: vec![augend, addend],
+			manual_composition: Some(concrete!(Context)),
+			implementation: DocumentNodeImplementation::ProtoNode(ProtoNodeIdentifier::new("graphene_core::ops::AddNode")),
+			..Default::default()
+		};
+		let value = |value: f64| NodeInput::value(TaggedValue::F64(value), false);
+
+		// Node 2 adds up the chain of nodes 0 and 1 and the separate node 3, where the value added by node 1 is the one being changed
+		let proto_network = |addend: f64| {
+			let mut network = NodeNetwork {
+				exports: vec![NodeInput::node(NodeId(2), 0)],
+				nodes: [
+					(NodeId(0), add(value(1.), value(2.))),
+					(NodeId(1), add(NodeInput::node(NodeId(0), 0), value(addend))),
+					(NodeId(3), add(value(5.), value(6.))),
+					(NodeId(2), add(NodeInput::node(NodeId(1), 0), NodeInput::node(NodeId(3), 0))),
+				]
+				.into_iter()
+				.collect(),
+				..Default::default()
+			};
+			network.generate_node_paths(&[]);
+			Compiler {}.compile_single(network).expect("Graph should be generated")
+		};
+		let execute = |executor: &DynamicExecutor| block_on(executor.tree.eval::<Context, f64>(executor.output, None));
+
+		let mut executor = block_on(DynamicExecutor::new(proto_network(10.))).expect("The network should type check");
+		assert_eq!(execute(&executor), Some(24.));
+		executor.take_node_timings();
+
+		block_on(executor.update(proto_network(20.))).expect("The network should type check");
+		assert_eq!(execute(&executor), Some(34.));
+		let timings = executor.take_node_timings();
+		let evaluations = |node_id: u64| timings.get(&[NodeId(node_id)][..]).map_or(0, |timing| timing.evaluations);
+		assert_eq!(evaluations(1), 1, "The node with the changed input should be evaluated again");
+		assert_eq!(evaluations(2), 1, "The node downstream of the changed input should be evaluated again");
+		assert_eq!(evaluations(0), 0, "The node upstream of the changed input should keep its cached output");
+		assert_eq!(evaluations(3), 0, "The node unrelated to the changed input should keep its cached output");
+	}
 }
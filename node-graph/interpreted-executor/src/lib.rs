@@ -1,5 +1,7 @@
 pub mod dynamic_executor;
+pub mod node_cache;
 pub mod node_registry;
+pub mod node_timing;
 pub mod util;
 
 #[cfg(test)]
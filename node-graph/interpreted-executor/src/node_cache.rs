@@ -0,0 +1,187 @@
+use dyn_any::DynAny;
+use graph_craft::document::value::TaggedValue;
+use graph_craft::proto::{Any, FutureAny, SharedNodeContainer};
+use graphene_core::{Context, ExtractVarArgs, Node};
+use std::any::TypeId;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::Poll;
+
+/// The number of contexts a [`CachedNode`] keeps its output for, so evaluating it for the viewport and for something else in between doesn't evict either.
+const CACHED_CONTEXTS: usize = 4;
+
+/// Wraps a node of the [`BorrowTree`](crate::dynamic_executor::BorrowTree) to reuse its output for the most recent contexts it was called with.
+///
+/// A proto node's ID is a hash of its construction, which includes the IDs of the nodes it takes as inputs, so the same node is only kept in the tree
+/// while nothing upstream of it has changed. Together with the hash of the context it's called with, that makes its cache keyed by the hash of all its inputs.
+/// Changing an input value only replaces the nodes downstream of it, which start out with empty caches, while every other node keeps returning its cached output.
+///
+/// Outputs are kept behind an [`Arc`] so large ones, like graphics or raster data, are stored once and only copied for the node using them when they're reused.
+pub struct CachedNode {
+	node: SharedNodeContainer,
+	/// The hashes of the contexts the node was most recently evaluated with and its output for each, most recent last
+	cache: Arc<Mutex<VecDeque<(u64, Arc<TaggedValue>)>>>,
+	/// Shared by all the nodes of a [`BorrowTree`](crate::dynamic_executor::BorrowTree), set while it's evaluated for something other than the render
+	side_evaluation: SideEvaluationFlag,
+}
+
+impl CachedNode {
+	pub fn new(node: SharedNodeContainer, side_evaluation: SideEvaluationFlag) -> Self {
+		Self {
+			node,
+			cache: Default::default(),
+			side_evaluation,
+		}
+	}
+
+	/// The hash of the context a node was called with, if it's one which can be hashed reliably.
+	/// The variadic arguments of a context are only hashed by their address, which may be reused by another context's arguments after being freed, so those aren't cached.
+	fn context_hash(context: &Context) -> Option<u64> {
+		if matches!(context.varargs_len(), Ok(len) if len > 0) {
+			return None;
+		}
+
+		let mut hasher = DefaultHasher::new();
+		context.hash(&mut hasher);
+		Some(hasher.finish())
+	}
+}
+
+impl<'i> Node<'i, Any<'i>> for CachedNode {
+	type Output = FutureAny<'i>;
+
+	fn eval(&'i self, input: Any<'i>) -> Self::Output {
+		if DynAny::type_id(input.as_ref()) != TypeId::of::<Context>() {
+			return self.node.eval(input);
+		}
+		let Ok(context) = dyn_any::downcast::<Context>(input) else {
+			unreachable!("The type of the context was just checked")
+		};
+		let Some(hash) = Self::context_hash(&context) else { return self.node.eval(context) };
+
+		// Only the shared output is taken while the cache is locked, and it's copied for the node using it afterwards
+		let cached = self.cache.lock().unwrap().iter().find(|(cached_hash, _)| *cached_hash == hash).map(|(_, output)| output.clone());
+		if let Some(output) = cached {
+			return Box::pin(async move { TaggedValue::clone(&output).to_dynany() });
+		}
+
+		let future = self.node.eval(context);
+		let cache = self.cache.clone();
+		let side_evaluation = self.side_evaluation.clone();
+		Box::pin(async move {
+			// An output which isn't one of the tagged values is passed on without being cached
+			let output = match TaggedValue::try_from_dynany(future.await) {
+				Ok(output) => output,
+				Err(output) => return output,
+			};
+			if side_evaluation.is_set() {
+				return output.to_dynany();
+			}
+
+			let output = Arc::new(output);
+			{
+				let mut cache = cache.lock().unwrap();
+				if cache.len() == CACHED_CONTEXTS {
+					cache.pop_front();
+				}
+				cache.push_back((hash, output.clone()));
+			}
+			TaggedValue::clone(&output).to_dynany()
+		})
+	}
+
+	fn reset(&self) {
+		self.cache.lock().unwrap().clear();
+		self.node.reset()
+	}
+
+	fn node_name(&self) -> &'static str {
+		self.node.node_name()
+	}
+
+	fn serialize(&self) -> Option<Arc<dyn std::any::Any + Send + Sync>> {
+		self.node.serialize()
+	}
+}
+
+/// Whether the nodes of a [`BorrowTree`](crate::dynamic_executor::BorrowTree) are being evaluated for something other than the render, in which case they read from the caches without filling them.
+///
+/// The flag belongs to the tree rather than to the thread polling it, and is only set while a side evaluation is being polled, so a render polled in between isn't affected.
+/// Two evaluations of the same tree mustn't be polled at the same time from different threads, which the runtime avoids by awaiting each evaluation before starting the next, and which is checked when a side evaluation is polled.
+#[derive(Clone, Debug, Default)]
+pub struct SideEvaluationFlag(Arc<AtomicBool>);
+
+impl SideEvaluationFlag {
+	fn is_set(&self) -> bool {
+		self.0.load(Ordering::Relaxed)
+	}
+}
+
+/// Evaluates nodes for something other than the render, such as a wire probe or a thumbnail, without their outputs taking the place of the render's in the caches.
+pub struct SideEvaluation<F> {
+	future: Pin<Box<F>>,
+	flag: SideEvaluationFlag,
+}
+
+impl<F> SideEvaluation<F> {
+	pub fn new(future: F, flag: SideEvaluationFlag) -> Self {
+		Self { future: Box::pin(future), flag }
+	}
+}
+
+impl<F: Future> Future for SideEvaluation<F> {
+	type Output = F::Output;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+		let was_set = self.flag.0.swap(true, Ordering::Relaxed);
+		debug_assert!(!was_set, "A side evaluation started while another evaluation of the same tree was being polled");
+		let poll = self.future.as_mut().poll(cx);
+		self.flag.0.store(false, Ordering::Relaxed);
+		poll
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::node_timing::{NodeTiming, TimedNode};
+	use graph_craft::document::value::UpcastNode;
+	use graph_craft::proto::NodeContainer;
+	use graphene_core::OwnedContextImpl;
+	use graphene_core::transform::Footprint;
+
+	#[test]
+	fn output_is_reused_for_the_same_context() {
+		let timing = Arc::new(NodeTiming::default());
+		let value = NodeContainer::new(Box::new(UpcastNode::new(TaggedValue::U32(2).into())));
+		let side_evaluation = SideEvaluationFlag::default();
+		let node = CachedNode::new(NodeContainer::new(Box::new(TimedNode::new(value, timing.clone()))), side_evaluation.clone());
+		let eval = |context: Context| {
+			let output = futures::executor::block_on(node.eval(Box::new(context)));
+			dyn_any::downcast::<u32>(output).ok().map(|output| *output)
+		};
+
+		assert_eq!(eval(None), Some(2));
+		assert_eq!(eval(None), Some(2));
+		assert_eq!(timing.take().1, 1, "The second evaluation should have been taken from the cache");
+
+		let context = OwnedContextImpl::default().with_footprint(Footprint::default()).into_context();
+		assert_eq!(eval(context), Some(2));
+		assert_eq!(eval(None), Some(2));
+		assert_eq!(timing.take().1, 1, "A different context should evaluate the node again without evicting the first one");
+
+		let side_context = OwnedContextImpl::default().with_footprint(Footprint::BOUNDLESS).into_context();
+		let side_output = futures::executor::block_on(SideEvaluation::new(node.eval(Box::new(side_context.clone())), side_evaluation));
+		assert_eq!(dyn_any::downcast::<u32>(side_output).ok().map(|output| *output), Some(2));
+		assert_eq!(eval(side_context), Some(2));
+		assert_eq!(timing.take().1, 2, "A side evaluation shouldn't fill the cache");
+
+		node.reset();
+		assert_eq!(eval(None), Some(2));
+		assert_eq!(timing.take().1, 1, "Resetting the node should clear its cache");
+	}
+}
@@ -215,47 +215,6 @@ fn node_registry() -> HashMap<ProtoNodeIdentifier, HashMap<NodeIOTypes, NodeCons
 			NodeIOTypes::new(concrete!(ImageFrameTable<Color>), concrete!(ImageFrameTable<Color>), vec![fn_type!(f64), fn_type!(f64), fn_type!(bool)]),
 		),
 		// (
-		// 	ProtoNodeIdentifier::new("graphene_core::raster::CurvesNode"),
-		// 	|args| {
-		// 		use graphene_core::raster::curve::Curve;
-		// 		use graphene_core::raster::GenerateCurvesNode;
-		// 		let curve: DowncastBothNode<(), Curve> = DowncastBothNode::new(args[0].clone());
-		// 		Box::pin(async move {
-		// 			let curve = ClonedNode::new(curve.eval(()).await);
-
-		// 			let generate_curves_node = GenerateCurvesNode::new(curve, ClonedNode::new(0_f32));
-		// 			let map_image_frame_node = graphene_std::raster::MapImageNode::new(ValueNode::new(generate_curves_node.eval(())));
-		// 			let map_image_frame_node = FutureWrapperNode::new(map_image_frame_node);
-		// 			let any: DynAnyNode<ImageFrameTable<Luma>, _, _> = graphene_std::any::DynAnyNode::new(map_image_frame_node);
-		// 			any.into_type_erased()
-		// 		})
-		// 	},
-		// 	NodeIOTypes::new(concrete!(ImageFrameTable<Luma>), concrete!(ImageFrameTable<Luma>), vec![fn_type!(graphene_core::raster::curve::Curve)]),
-		// ),
-		// TODO: Use channel split and merge for this instead of using LuminanceMut for the whole color.
-		// (
-		// 	ProtoNodeIdentifier::new("graphene_core::raster::CurvesNode"),
-		// 	|args| {
-		// 		use graphene_core::raster::curve::Curve;
-		// 		use graphene_core::raster::GenerateCurvesNode;
-		// 		let curve: DowncastBothNode<(), Curve> = DowncastBothNode::new(args[0].clone());
-		// 		Box::pin(async move {
-		// 			let curve = ValueNode::new(ClonedNode::new(curve.eval(()).await));
-
-		// 			let generate_curves_node = GenerateCurvesNode::new(FutureWrapperNode::new(curve), FutureWrapperNode::new(ClonedNode::new(0_f32)));
-		// 			let map_image_frame_node = graphene_std::raster::MapImageNode::new(FutureWrapperNode::new(ValueNode::new(generate_curves_node.eval(()))));
-		// 			let map_image_frame_node = FutureWrapperNode::new(map_image_frame_node);
-		// 			let any: DynAnyNode<ImageFrameTable<Color>, _, _> = graphene_std::any::DynAnyNode::new(map_image_frame_node);
-		// 			any.into_type_erased()
-		// 		})
-		// 	},
-		// 	NodeIOTypes::new(
-		// 		concrete!(ImageFrameTable<Color>),
-		// 		concrete!(ImageFrameTable<Color>),
-		// 		vec![fn_type!(graphene_core::raster::curve::Curve)],
-		// 	),
-		// ),
-		// (
 		// 	ProtoNodeIdentifier::new("graphene_std::raster::ImaginateNode"),
 		// 	|args: Vec<graph_craft::proto::SharedNodeContainer>| {
 		// 		Box::pin(async move {
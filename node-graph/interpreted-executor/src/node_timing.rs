@@ -0,0 +1,139 @@
+use graph_craft::proto::{Any, FutureAny, SharedNodeContainer};
+use graphene_core::Node;
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use web_time::Instant;
+
+thread_local! {
+	/// The time spent polling the nodes called by the node which is currently being polled, which doesn't count towards that node's own time.
+	static NESTED_TIME: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+/// The time a node has spent evaluating since its timing was last taken, not including the time spent in the nodes it called.
+#[derive(Debug, Default)]
+pub struct NodeTiming {
+	nanoseconds: AtomicU64,
+	evaluations: AtomicU64,
+}
+
+impl NodeTiming {
+	fn add(&self, duration: Duration) {
+		self.nanoseconds.fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+	}
+
+	/// Returns the accumulated time and the number of times the node was evaluated, and starts accumulating again from zero.
+	pub fn take(&self) -> (Duration, u64) {
+		let nanoseconds = self.nanoseconds.swap(0, Ordering::Relaxed);
+		let evaluations = self.evaluations.swap(0, Ordering::Relaxed);
+		(Duration::from_nanos(nanoseconds), evaluations)
+	}
+}
+
+/// The time spent evaluating the proto nodes with the same document node path since their timings were last taken.
+/// A proto node goes unevaluated when the node using its output was itself left unevaluated, or returned a cached output instead of evaluating it.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PathTiming {
+	/// The time spent in the proto nodes, not including the time spent in the nodes they called
+	pub duration: Duration,
+	/// The number of times any of the proto nodes were evaluated
+	pub evaluations: u64,
+	/// The number of timed proto nodes with this path
+	pub proto_nodes: u32,
+	/// The number of those proto nodes which were evaluated at least once
+	pub evaluated_proto_nodes: u32,
+}
+
+impl PathTiming {
+	pub fn add(&mut self, duration: Duration, evaluations: u64) {
+		self.duration += duration;
+		self.evaluations += evaluations;
+		self.proto_nodes += 1;
+		if evaluations > 0 {
+			self.evaluated_proto_nodes += 1;
+		}
+	}
+}
+
+/// Wraps a node of the [`BorrowTree`](crate::dynamic_executor::BorrowTree) to measure the time spent evaluating it into its [`NodeTiming`].
+pub struct TimedNode {
+	node: SharedNodeContainer,
+	timing: Arc<NodeTiming>,
+}
+
+impl TimedNode {
+	pub fn new(node: SharedNodeContainer, timing: Arc<NodeTiming>) -> Self {
+		Self { node, timing }
+	}
+}
+
+impl<'i> Node<'i, Any<'i>> for TimedNode {
+	type Output = FutureAny<'i>;
+
+	fn eval(&'i self, input: Any<'i>) -> Self::Output {
+		self.timing.evaluations.fetch_add(1, Ordering::Relaxed);
+		Box::pin(TimedFuture {
+			future: self.node.eval(input),
+			timing: &self.timing,
+		})
+	}
+
+	fn reset(&self) {
+		self.node.reset()
+	}
+
+	fn node_name(&self) -> &'static str {
+		self.node.node_name()
+	}
+
+	fn serialize(&self) -> Option<Arc<dyn std::any::Any + Send + Sync>> {
+		self.node.serialize()
+	}
+}
+
+/// Adds the time spent polling the future of a node to its timing, minus the time spent polling the futures of the nodes it awaits in the meantime.
+struct TimedFuture<'i> {
+	future: FutureAny<'i>,
+	timing: &'i NodeTiming,
+}
+
+impl<'i> Future for TimedFuture<'i> {
+	type Output = Any<'i>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let outer = NESTED_TIME.replace(Duration::ZERO);
+		let start = Instant::now();
+
+		let poll = self.future.as_mut().poll(cx);
+
+		let elapsed = start.elapsed();
+		let nested = NESTED_TIME.replace(outer + elapsed);
+		self.timing.add(elapsed.saturating_sub(nested));
+
+		poll
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use graph_craft::document::value::{TaggedValue, UpcastNode};
+	use graph_craft::proto::NodeContainer;
+
+	#[test]
+	fn timing_is_taken_once_per_evaluation() {
+		let timing = Arc::new(NodeTiming::default());
+		let node = TimedNode::new(NodeContainer::new(Box::new(UpcastNode::new(TaggedValue::U32(2).into()))), timing.clone());
+		assert_eq!(timing.take(), (Duration::ZERO, 0), "A node which wasn't evaluated shouldn't be timed");
+
+		let output = futures::executor::block_on(node.eval(Box::new(())));
+		assert_eq!(dyn_any::downcast::<u32>(output).ok().map(|output| *output), Some(2));
+		assert_eq!(timing.take().1, 1, "The evaluation should be counted");
+		assert_eq!(timing.take(), (Duration::ZERO, 0), "The timing should start again from zero once taken");
+	}
+}